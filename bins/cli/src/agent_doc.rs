@@ -21,14 +21,21 @@ const KNOWN_COMMANDS: &[&str] = &[
     "calibrate",
     "clear",
     "config",
+    "doctor",
     "estimate-storage",
+    "export",
+    "import",
     "index",
     "info",
     "init",
     "jobs",
+    "ls",
+    "migrate",
+    "prune",
     "reindex",
     "search",
     "status",
+    "verify",
 ];
 
 // ── Top-level protocol structs ───────────────────────────────────────────────
@@ -209,10 +216,12 @@ fn build_all_commands() -> BTreeMap<&'static str, CommandContract> {
     insert_setup_commands(&mut commands);
     insert_operational_commands(&mut commands);
     insert_pipeline_commands(&mut commands);
+    insert_index_maintenance_commands(&mut commands);
+    insert_index_content_commands(&mut commands);
     commands
 }
 
-/// Setup commands: info, config, init, estimate-storage.
+/// Setup commands: info, config, init, doctor, estimate-storage.
 fn insert_setup_commands(commands: &mut BTreeMap<&'static str, CommandContract>) {
     commands.insert(
         "info",
@@ -234,7 +243,11 @@ fn insert_setup_commands(commands: &mut BTreeMap<&'static str, CommandContract>)
         CommandContract {
             purpose: "Validate runtime config and environment (subcommands: check, show, validate)",
             required_flags: vec![],
-            common_flags: vec!["--path <config-file>", "--overrides-json <json>"],
+            common_flags: vec![
+                "--path <config-file>",
+                "--overrides-json <json>",
+                "--show-diff",
+            ],
             success_signal: SuccessSignal {
                 ndjson_type: "summary",
                 description: "kind:'config', status:'ok'",
@@ -252,7 +265,8 @@ fn insert_setup_commands(commands: &mut BTreeMap<&'static str, CommandContract>)
             common_flags: vec![
                 "--config <path>",
                 "--codebase-root <path>",
-                "--storage-mode <disabled|project|custom:path>",
+                "--storage-mode <disabled|project|user-cache|custom:path>",
+                "--template <local-onnx|local-ollama|local-openai|milvus-openai>",
                 "--force",
             ],
             success_signal: SuccessSignal {
@@ -264,6 +278,21 @@ fn insert_setup_commands(commands: &mut BTreeMap<&'static str, CommandContract>)
             background: false,
         },
     );
+    commands.insert(
+        "doctor",
+        CommandContract {
+            purpose: "Diagnose env, config, embedding, vector DB, and snapshot setup with remediation hints",
+            required_flags: vec![],
+            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'doctor', status:'ok' (any failing check exits non-zero)",
+            },
+            error_codes: vec![],
+            idempotent: true,
+            background: false,
+        },
+    );
     commands.insert(
         "estimate-storage",
         CommandContract {
@@ -325,7 +354,7 @@ fn insert_operational_commands(commands: &mut BTreeMap<&'static str, CommandCont
     );
 }
 
-/// Core pipeline commands: index, search, reindex, clear, status.
+/// Core pipeline commands: index, search, reindex.
 fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContract>) {
     commands.insert(
         "index",
@@ -339,6 +368,8 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
                 "--background",
                 "--embedding-provider <onnx|openai|gemini|voyage|ollama>",
                 "--vector-kernel <hnsw-rs|dfrr>",
+                "--max-file-count <n>",
+                "--ignore <pattern>",
                 "--overrides-json <json>",
             ],
             success_signal: SuccessSignal {
@@ -365,6 +396,11 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
                 "--threshold <f32> (default: 0.0)",
                 "--filter-expr <expr>",
                 "--include-content",
+                "--explain (adds searchDiagnostics.scoreHistogram)",
+                "--group-by-file (best chunk per relativePath)",
+                "--fail-on-empty (exit 2 when no results)",
+                "--exclude-path <glob> (repeatable)",
+                "--exclude-language <language> (repeatable)",
                 "--codebase-root <path>",
                 "--overrides-json <json>",
             ],
@@ -374,7 +410,7 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
             },
             error_codes: vec![
                 "ERR_CORE_INVALID_INPUT",
-                "ERR_CONFIG_INVALID_FILTER_EXPR",
+                "ERR_REQUEST_INVALID_FILTER",
                 "ERR_VECTOR_*",
                 "ERR_CORE_NOT_FOUND",
             ],
@@ -386,7 +422,7 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
         purpose: "Reindex based on Merkle-detected snapshot changes (selective re-embed + upsert)",
         required_flags: vec![],
         common_flags: vec![
-            "--codebase-root <path>", "--config <path>", "--background",
+            "--codebase-root <path>", "--config <path>", "--background", "--ignore <pattern>",
             "--embedding-provider <onnx|openai|gemini|voyage|ollama>", "--overrides-json <json>",
         ],
         success_signal: SuccessSignal { ndjson_type: "summary", description: "kind:'reindex', status:'ok'" },
@@ -394,12 +430,20 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
         idempotent: true,
         background: true,
     });
+}
+
+/// Index maintenance commands: clear, status, verify.
+fn insert_index_maintenance_commands(commands: &mut BTreeMap<&'static str, CommandContract>) {
     commands.insert(
         "clear",
         CommandContract {
             purpose: "Clear the local index and snapshot",
             required_flags: vec![],
-            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            common_flags: vec![
+                "--codebase-root <path>",
+                "--config <path>",
+                "--collection <name>",
+            ],
             success_signal: SuccessSignal {
                 ndjson_type: "summary",
                 description: "kind:'clear', status:'ok'",
@@ -412,7 +456,7 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
     commands.insert(
         "status",
         CommandContract {
-            purpose: "Report local index status (collection info, record count, snapshot state)",
+            purpose: "Report local index status (index state, collection info, record count, snapshot state)",
             required_flags: vec![],
             common_flags: vec!["--codebase-root <path>", "--config <path>"],
             success_signal: SuccessSignal {
@@ -424,6 +468,123 @@ fn insert_pipeline_commands(commands: &mut BTreeMap<&'static str, CommandContrac
             background: false,
         },
     );
+    commands.insert(
+        "verify",
+        CommandContract {
+            purpose: "Check vector/sync snapshots against the vector DB (counts, dimension, dangling docs) without reindexing",
+            required_flags: vec![],
+            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'verify', status:'ok' (status:'drift' exits non-zero)",
+            },
+            error_codes: vec!["ERR_CORE_NOT_FOUND", "ERR_VECTOR_*"],
+            idempotent: true,
+            background: false,
+        },
+    );
+}
+
+/// Index content commands: ls, prune, export, import, migrate.
+fn insert_index_content_commands(commands: &mut BTreeMap<&'static str, CommandContract>) {
+    commands.insert(
+        "ls",
+        CommandContract {
+            purpose: "List indexed files with chunk counts and language, sorted by path",
+            required_flags: vec![],
+            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'ls', status:'ok', files:[{relativePath, chunkCount, language}]",
+            },
+            error_codes: vec!["ERR_CONFIG_*", "ERR_VECTOR_*"],
+            idempotent: true,
+            background: false,
+        },
+    );
+    commands.insert(
+        "prune",
+        CommandContract {
+            purpose: "Delete chunks for indexed files that no longer exist on disk or are now ignored",
+            required_flags: vec![],
+            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'prune', status:'ok', prune:{scannedFiles, indexedFiles, prunedFiles, removedChunks}",
+            },
+            error_codes: vec!["ERR_CONFIG_*", "ERR_VECTOR_*"],
+            idempotent: true,
+            background: false,
+        },
+    );
+    commands.insert(
+        "export",
+        CommandContract {
+            purpose: "Write every indexed document (id, content, metadata, optionally vector) to a JSONL file sorted by id",
+            required_flags: vec!["--out <path>"],
+            common_flags: vec![
+                "--codebase-root <path>",
+                "--config <path>",
+                "--with-vectors",
+            ],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'export', status:'ok', path, export:{collectionName, exportedDocuments}",
+            },
+            error_codes: vec![
+                "ERR_EXPORT_INVALID_SOURCE_ROW",
+                "ERR_CORE_NOT_FOUND",
+                "ERR_VECTOR_*",
+            ],
+            idempotent: true,
+            background: false,
+        },
+    );
+    commands.insert(
+        "import",
+        CommandContract {
+            purpose: "Load documents from an `export --with-vectors` JSONL file without re-embedding; creates the collection when missing",
+            required_flags: vec!["--in <path>"],
+            common_flags: vec!["--codebase-root <path>", "--config <path>"],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'import', status:'ok', import:{collectionName, dimension, importedDocuments, createdCollection}",
+            },
+            error_codes: vec![
+                "ERR_IMPORT_DIMENSION_MISMATCH",
+                "ERR_IMPORT_INVALID_LINE",
+                "ERR_DOMAIN_*",
+                "ERR_VECTOR_*",
+            ],
+            idempotent: true,
+            background: false,
+        },
+    );
+    commands.insert(
+        "migrate",
+        CommandContract {
+            purpose: "Copy the index (ids, vectors, metadata) into another vector DB backend without reindexing",
+            required_flags: vec!["--to <milvus_grpc|milvus_rest|local>"],
+            common_flags: vec![
+                "--codebase-root <path>",
+                "--config <path>",
+                "--vector-db-address <host:port>",
+                "--vector-db-token <token>",
+            ],
+            success_signal: SuccessSignal {
+                ndjson_type: "summary",
+                description: "kind:'migrate', status:'ok', migration:{collectionName, dimension, migratedDocuments, createdCollection}",
+            },
+            error_codes: vec![
+                "ERR_MIGRATE_DIMENSION_MISMATCH",
+                "ERR_MIGRATE_INVALID_SOURCE_ROW",
+                "ERR_CORE_NOT_FOUND",
+                "ERR_VECTOR_*",
+            ],
+            idempotent: false,
+            background: false,
+        },
+    );
 }
 
 // ── NDJSON shapes ────────────────────────────────────────────────────────────
@@ -498,6 +659,7 @@ fn build_exit_codes() -> BTreeMap<u8, &'static str> {
     codes.insert(1, "internal / invariant / serialization");
     codes.insert(2, "invalid input (includes API EXPECTED kind)");
     codes.insert(3, "IO error");
+    codes.insert(75, "transient (retriable) failure; retry may succeed");
     codes
 }
 
@@ -543,13 +705,14 @@ fn build_recovery_table() -> BTreeMap<&'static str, &'static str> {
 
 fn build_filter_syntax() -> FilterSyntax {
     FilterSyntax {
-        syntax: "<field> <op> <quoted_value>",
+        syntax: "<field> <op> <quoted_value> [&& <field> <op> <quoted_value>]...",
         fields: vec!["relativePath", "language", "fileExtension"],
         operators: vec!["==", "!="],
         examples: vec![
             "relativePath == 'src/main.rs'",
             "language != 'rust'",
             "fileExtension == 'rs'",
+            "language == 'rust' && relativePath != 'src/main.rs'",
         ],
     }
 }
@@ -13,7 +13,11 @@ use std::path::PathBuf;
 pub enum Commands {
     /// Validate the build and environment wiring.
     #[cfg(any(debug_assertions, feature = "dev-tools"))]
-    SelfCheck,
+    SelfCheck {
+        /// Run the index/search/clear smokes one after another instead of concurrently.
+        #[arg(long)]
+        serial: bool,
+    },
     /// Show build and version details.
     Info,
     /// Print the machine-readable agent protocol spec (YAML).
@@ -42,16 +46,29 @@ pub enum Commands {
         /// Codebase root (defaults to current directory).
         #[arg(long)]
         codebase_root: Option<PathBuf>,
-        /// Snapshot storage mode: disabled, project, or custom:<path>.
+        /// Snapshot storage mode: disabled, project, user-cache, or custom:<path>.
         #[arg(long)]
         storage_mode: Option<String>,
+        /// Write a commented starter config: local-onnx, local-ollama, local-openai, or milvus-openai.
+        #[arg(long)]
+        template: Option<String>,
         /// Overwrite existing config/manifest when present.
         #[arg(long)]
         force: bool,
     },
+    /// Diagnose common setup problems (env, config, providers, snapshot).
+    #[command(after_help = "Agents: run `sca agent-doc doctor` for this command's protocol spec.")]
+    Doctor {
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+    },
     /// Estimate index storage requirements and local free-space headroom.
     EstimateStorage {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -115,7 +132,7 @@ pub enum Commands {
     /// Index the local codebase.
     #[command(after_help = "Agents: run `sca agent-doc index` for this command's protocol spec.")]
     Index {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -178,6 +195,12 @@ pub enum Commands {
         /// Vector DB auth password.
         #[arg(long)]
         vector_db_password: Option<String>,
+        /// Stop scanning once this many candidate files are found (overrides `sync.maxFiles`).
+        #[arg(long)]
+        max_file_count: Option<u32>,
+        /// Extra ignore pattern layered above config and `.contextignore` (repeatable; `!pattern` re-includes).
+        #[arg(long = "ignore", value_name = "PATTERN")]
+        ignore: Vec<String>,
         /// Use a tighter storage factor (1.25x estimate) for emergency runs.
         #[arg(long, hide = true)]
         danger_close_storage: bool,
@@ -216,7 +239,38 @@ pub enum Commands {
         /// Include content payloads in results.
         #[arg(long)]
         include_content: bool,
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Widen included content by N lines of surrounding source on each side.
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "include_content",
+            conflicts_with = "stdin_batch"
+        )]
+        context_lines: Option<u32>,
+        /// Include a candidate score histogram in search diagnostics (helps pick `--threshold`).
+        #[arg(long, conflicts_with = "stdin_batch")]
+        explain: bool,
+        /// Keep only the best-scoring chunk per file before applying `--top-k`.
+        #[arg(long, conflicts_with = "stdin_batch")]
+        group_by_file: bool,
+        /// Exit with code 2 when no results meet the threshold (output is still printed).
+        #[arg(long, conflicts_with = "stdin_batch")]
+        fail_on_empty: bool,
+        /// Exclude results whose relative path matches this glob (repeatable).
+        #[arg(
+            long = "exclude-path",
+            value_name = "GLOB",
+            conflicts_with = "stdin_batch"
+        )]
+        exclude_paths: Vec<String>,
+        /// Exclude results in this language, e.g. `markdown` (repeatable).
+        #[arg(
+            long = "exclude-language",
+            value_name = "LANGUAGE",
+            conflicts_with = "stdin_batch"
+        )]
+        exclude_languages: Vec<String>,
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -256,12 +310,15 @@ pub enum Commands {
     /// Clear the local index and snapshot.
     #[command(after_help = "Agents: run `sca agent-doc clear` for this command's protocol spec.")]
     Clear {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
         #[arg(long)]
         codebase_root: Option<PathBuf>,
+        /// Clear this collection instead of the one derived for the codebase.
+        #[arg(long)]
+        collection: Option<String>,
         /// Vector DB provider (e.g. `local`, `milvus_grpc`, `milvus_rest`).
         #[arg(long)]
         vector_db_provider: Option<String>,
@@ -290,7 +347,7 @@ pub enum Commands {
     /// Report local index status.
     #[command(after_help = "Agents: run `sca agent-doc status` for this command's protocol spec.")]
     Status {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -321,10 +378,127 @@ pub enum Commands {
         #[arg(long)]
         vector_db_password: Option<String>,
     },
+    /// Verify that local snapshots agree with the vector DB.
+    #[command(after_help = "Agents: run `sca agent-doc verify` for this command's protocol spec.")]
+    Verify {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Vector DB provider (e.g. `local`, `milvus_grpc`, `milvus_rest`).
+        #[arg(long)]
+        vector_db_provider: Option<String>,
+        /// Vector DB address/host.
+        #[arg(long)]
+        vector_db_address: Option<String>,
+        /// Vector DB base URL.
+        #[arg(long)]
+        vector_db_base_url: Option<String>,
+        /// Vector DB database name.
+        #[arg(long)]
+        vector_db_database: Option<String>,
+        /// Vector DB SSL enablement.
+        #[arg(long)]
+        vector_db_ssl: Option<bool>,
+        /// Vector DB auth token.
+        #[arg(long)]
+        vector_db_token: Option<String>,
+        /// Vector DB auth username.
+        #[arg(long)]
+        vector_db_username: Option<String>,
+        /// Vector DB auth password.
+        #[arg(long)]
+        vector_db_password: Option<String>,
+    },
+    /// List indexed files with their chunk counts.
+    #[command(after_help = "Agents: run `sca agent-doc ls` for this command's protocol spec.")]
+    Ls {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+    },
+    /// Remove indexed chunks for files that no longer exist on disk.
+    #[command(after_help = "Agents: run `sca agent-doc prune` for this command's protocol spec.")]
+    Prune {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+    },
+    /// Export every indexed document as JSONL, sorted by id.
+    #[command(after_help = "Agents: run `sca agent-doc export` for this command's protocol spec.")]
+    Export {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Output file (created or truncated).
+        #[arg(long)]
+        out: PathBuf,
+        /// Include each document's dense vector.
+        #[arg(long)]
+        with_vectors: bool,
+    },
+    /// Load documents (with vectors) from a JSONL export without re-embedding.
+    #[command(after_help = "Agents: run `sca agent-doc import` for this command's protocol spec.")]
+    Import {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// JSONL file written by `export --with-vectors`.
+        #[arg(long = "in", value_name = "PATH")]
+        input: PathBuf,
+    },
+    /// Copy the index into another vector DB backend without reindexing.
+    #[command(after_help = "Agents: run `sca agent-doc migrate` for this command's protocol spec.")]
+    Migrate {
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Destination vector DB provider (e.g. `milvus_grpc`, `milvus_rest`).
+        #[arg(long)]
+        to: String,
+        /// Destination vector DB address/host.
+        #[arg(long)]
+        vector_db_address: Option<String>,
+        /// Destination vector DB base URL.
+        #[arg(long)]
+        vector_db_base_url: Option<String>,
+        /// Destination vector DB database name.
+        #[arg(long)]
+        vector_db_database: Option<String>,
+        /// Destination vector DB SSL enablement.
+        #[arg(long)]
+        vector_db_ssl: Option<bool>,
+        /// Destination vector DB auth token.
+        #[arg(long)]
+        vector_db_token: Option<String>,
+        /// Destination vector DB auth username.
+        #[arg(long)]
+        vector_db_username: Option<String>,
+        /// Destination vector DB auth password.
+        #[arg(long)]
+        vector_db_password: Option<String>,
+    },
     /// Reindex based on snapshot changes.
     #[command(after_help = "Agents: run `sca agent-doc reindex` for this command's protocol spec.")]
     Reindex {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -384,10 +558,13 @@ pub enum Commands {
         /// Vector DB auth password.
         #[arg(long)]
         vector_db_password: Option<String>,
+        /// Extra ignore pattern layered above config and `.contextignore` (repeatable; `!pattern` re-includes).
+        #[arg(long = "ignore", value_name = "PATTERN")]
+        ignore: Vec<String>,
     },
     /// Calibrate BQ1 threshold for the local DFRR kernel.
     Calibrate {
-        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin). Defaults to `.context/config.toml` when present.
         #[arg(long)]
         config: Option<PathBuf>,
         /// Codebase root (defaults to current directory).
@@ -433,6 +610,37 @@ pub enum Commands {
         #[arg(long)]
         vector_db_password: Option<String>,
     },
+    /// Embed one text with the configured provider and print vector stats.
+    #[command(hide = true)]
+    Embed {
+        /// Text to embed.
+        #[arg(long, conflicts_with = "stdin")]
+        text: Option<String>,
+        /// Read the text to embed from stdin.
+        #[arg(long, conflicts_with = "text")]
+        stdin: bool,
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Raw JSON config overrides.
+        #[arg(long)]
+        overrides_json: Option<String>,
+    },
+    /// Measure search latency against the local index.
+    #[command(hide = true)]
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommands,
+    },
+    /// Inspect stored index data for debugging.
+    #[command(hide = true)]
+    Debug {
+        #[command(subcommand)]
+        command: DebugCommands,
+    },
     /// Validate a request payload against the request validators.
     #[command(hide = true)]
     ValidateRequest {
@@ -449,30 +657,116 @@ pub enum Commands {
 pub enum ConfigCommands {
     /// Validate config loading, merging, and normalization.
     Check {
-        /// Optional config file path (JSON/TOML).
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin).
         #[arg(long)]
         path: Option<PathBuf>,
         /// Optional JSON overrides (partial config).
         #[arg(long)]
         overrides_json: Option<String>,
+        /// Read the overrides from a `.json` file instead of `--overrides-json`.
+        #[arg(long, conflicts_with = "overrides_json")]
+        overrides_file: Option<PathBuf>,
+        /// Print the fields the overrides changed (secrets redacted).
+        #[arg(long)]
+        show_diff: bool,
     },
     /// Show the effective config after applying overrides.
     Show {
-        /// Optional config file path (JSON/TOML).
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin).
         #[arg(long)]
         path: Option<PathBuf>,
         /// Optional JSON overrides (partial config).
         #[arg(long)]
         overrides_json: Option<String>,
+        /// Read the overrides from a `.json` file instead of `--overrides-json`.
+        #[arg(long, conflicts_with = "overrides_json")]
+        overrides_file: Option<PathBuf>,
+        /// Print the fields the overrides changed (secrets redacted).
+        #[arg(long)]
+        show_diff: bool,
     },
     /// Validate config loading and overrides.
     Validate {
-        /// Optional config file path (JSON/TOML).
+        /// Optional config file path (JSON/TOML, or `-` to read from stdin).
         #[arg(long)]
         path: Option<PathBuf>,
         /// Optional JSON overrides (partial config).
         #[arg(long)]
         overrides_json: Option<String>,
+        /// Read the overrides from a `.json` file instead of `--overrides-json`.
+        #[arg(long, conflicts_with = "overrides_json")]
+        overrides_file: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BenchCommands {
+    /// Run a file of queries repeatedly and report p50/p95/p99 latency (JSON).
+    Search {
+        /// File with one query per line (blank lines and `#` comments are skipped).
+        #[arg(long)]
+        queries_file: PathBuf,
+        /// Number of passes over the queries file.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Optional top-k override.
+        #[arg(long)]
+        top_k: Option<u32>,
+        /// Optional score threshold.
+        #[arg(long)]
+        threshold: Option<f32>,
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Raw JSON config overrides.
+        #[arg(long)]
+        overrides_json: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DebugCommands {
+    /// Print the dimension and L2 norm of the vector stored for a chunk id.
+    GetVector {
+        /// Chunk id (as shown in search results).
+        #[arg(long)]
+        id: String,
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Raw JSON config overrides.
+        #[arg(long)]
+        overrides_json: Option<String>,
+    },
+    /// Print the vector dimension the configured embedding model produces.
+    Dimension {
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Raw JSON config overrides.
+        #[arg(long)]
+        overrides_json: Option<String>,
+    },
+    /// Print the collection name the codebase would index into.
+    CollectionName {
+        /// Optional config file path (JSON/TOML). Defaults to `.context/config.toml` when present.
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Codebase root (defaults to current directory).
+        #[arg(long)]
+        codebase_root: Option<PathBuf>,
+        /// Raw JSON config overrides.
+        #[arg(long)]
+        overrides_json: Option<String>,
     },
 }
 
@@ -740,10 +1034,113 @@ pub fn build_vector_overrides_json(
     build_overrides_json(vector_args, EmbeddingCliOverridesArgs::empty())
 }
 
+/// Layer a `sync.maxFiles` override onto an overrides payload.
+pub fn with_sync_max_files(
+    overrides: Option<String>,
+    max_files: Option<u32>,
+) -> Result<Option<String>, CliError> {
+    let Some(max_files) = max_files else {
+        return Ok(overrides);
+    };
+    with_sync_override(
+        overrides,
+        "maxFiles",
+        serde_json::Value::Number(serde_json::Number::from(max_files)),
+    )
+}
+
+/// Layer `--ignore` patterns onto an overrides payload as
+/// `sync.additionalIgnorePatterns`.
+pub fn with_sync_additional_ignore_patterns(
+    overrides: Option<String>,
+    patterns: &[String],
+) -> Result<Option<String>, CliError> {
+    if patterns.is_empty() {
+        return Ok(overrides);
+    }
+    with_sync_override(
+        overrides,
+        "additionalIgnorePatterns",
+        serde_json::Value::Array(
+            patterns
+                .iter()
+                .map(|pattern| serde_json::Value::String(pattern.clone()))
+                .collect(),
+        ),
+    )
+}
+
+fn with_sync_override(
+    overrides: Option<String>,
+    key: &str,
+    value: serde_json::Value,
+) -> Result<Option<String>, CliError> {
+    let mut root = match overrides {
+        Some(raw) => serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&raw)?,
+        None => serde_json::Map::new(),
+    };
+    let sync = root
+        .entry("sync")
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    if let Some(sync) = sync.as_object_mut() {
+        sync.insert(key.to_owned(), value);
+    }
+    Ok(Some(serde_json::to_string(&serde_json::Value::Object(
+        root,
+    ))?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn max_file_count_merges_into_sync_overrides() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(with_sync_max_files(None, None)?, None);
+
+        let base = Some(r#"{"vectorDb":{"provider":"local"}}"#.to_owned());
+        let overrides = with_sync_max_files(base, Some(500))?
+            .ok_or_else(|| std::io::Error::other("missing overrides payload"))?;
+        let payload: serde_json::Value = serde_json::from_str(&overrides)?;
+        assert_eq!(
+            payload
+                .pointer("/sync/maxFiles")
+                .and_then(serde_json::Value::as_u64),
+            Some(500)
+        );
+        assert_eq!(
+            payload
+                .pointer("/vectorDb/provider")
+                .and_then(serde_json::Value::as_str),
+            Some("local")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_flags_merge_into_sync_overrides() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(with_sync_additional_ignore_patterns(None, &[])?, None);
+
+        let base = with_sync_max_files(None, Some(10))?;
+        let overrides = with_sync_additional_ignore_patterns(
+            base,
+            &["vendor/".to_owned(), "!vendor/keep.rs".to_owned()],
+        )?
+        .ok_or_else(|| std::io::Error::other("missing overrides payload"))?;
+        let payload: serde_json::Value = serde_json::from_str(&overrides)?;
+        assert_eq!(
+            payload.pointer("/sync/additionalIgnorePatterns"),
+            Some(&serde_json::json!(["vendor/", "!vendor/keep.rs"]))
+        );
+        assert_eq!(
+            payload
+                .pointer("/sync/maxFiles")
+                .and_then(serde_json::Value::as_u64),
+            Some(10)
+        );
+        Ok(())
+    }
+
     #[test]
     fn vector_kernel_override_serializes_into_vector_db_payload()
     -> Result<(), Box<dyn std::error::Error>> {
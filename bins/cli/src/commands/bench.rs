@@ -0,0 +1,218 @@
+//! Bench command handler (hidden, for measuring search latency).
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::open_search_session;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Instant;
+
+/// Inputs for `bench search`.
+pub struct BenchSearchInput<'a> {
+    pub config_path: Option<&'a Path>,
+    pub overrides_json: Option<&'a str>,
+    pub codebase_root: &'a Path,
+    pub queries_file: &'a Path,
+    pub iterations: u32,
+    pub top_k: Option<u32>,
+    pub threshold: Option<f32>,
+}
+
+/// Latency percentiles for one bench run, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+struct LatencySummary {
+    p50: f64,
+    p95: f64,
+    p99: f64,
+    mean: f64,
+    max: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BenchSearchReport {
+    status: &'static str,
+    kind: &'static str,
+    queries: usize,
+    iterations: u32,
+    samples: usize,
+    latency_ms: LatencySummary,
+    mean_results: f64,
+}
+
+/// Run every query in the queries file `iterations` times against a warm
+/// search session and report latency percentiles as JSON.
+///
+/// The queries file holds one query per line; blank lines and `#` comments are
+/// skipped. Output is always JSON regardless of `--output`.
+pub fn run_bench_search(
+    mode: OutputMode,
+    input: &BenchSearchInput<'_>,
+) -> Result<CliOutput, CliError> {
+    let queries = read_queries_file(input.queries_file)?;
+    let session =
+        match open_search_session(input.config_path, input.overrides_json, input.codebase_root) {
+            Ok(session) => session,
+            Err(error) => return Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+        };
+
+    let iterations = input.iterations.max(1);
+    let mut latencies_ms = Vec::new();
+    let mut total_results = 0_usize;
+    for _ in 0..iterations {
+        for query in &queries {
+            let started = Instant::now();
+            let output = match session.search(query, input.top_k, input.threshold) {
+                Ok(output) => output,
+                Err(error) => {
+                    return Ok(format_error_output(mode, &error, infra_exit_code(&error)));
+                },
+            };
+            latencies_ms.push(started.elapsed().as_secs_f64() * 1000.0);
+            total_results = total_results.saturating_add(output.results.len());
+        }
+    }
+
+    let report = BenchSearchReport {
+        status: "ok",
+        kind: "benchSearch",
+        queries: queries.len(),
+        iterations,
+        samples: latencies_ms.len(),
+        latency_ms: summarize_latencies(&mut latencies_ms),
+        mean_results: mean(total_results, latencies_ms.len()),
+    };
+    let mut stdout = serde_json::to_string_pretty(&report)?;
+    stdout.push('\n');
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn read_queries_file(path: &Path) -> Result<Vec<String>, CliError> {
+    let contents = std::fs::read_to_string(path)?;
+    let queries: Vec<String> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_owned)
+        .collect();
+    if queries.is_empty() {
+        return Err(CliError::InvalidInput(format!(
+            "queries file has no queries: {}",
+            path.display()
+        )));
+    }
+    Ok(queries)
+}
+
+fn summarize_latencies(samples: &mut [f64]) -> LatencySummary {
+    samples.sort_by(f64::total_cmp);
+    let total: f64 = samples.iter().sum();
+    LatencySummary {
+        p50: percentile(samples, 50),
+        p95: percentile(samples, 95),
+        p99: percentile(samples, 99),
+        mean: if samples.is_empty() {
+            0.0
+        } else {
+            total / count_as_f64(samples.len())
+        },
+        max: samples.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Nearest-rank percentile over ascending `sorted` samples.
+fn percentile(sorted: &[f64], pct: usize) -> f64 {
+    let rank = (sorted.len() * pct).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied().unwrap_or(0.0)
+}
+
+fn mean(total: usize, count: usize) -> f64 {
+    if count == 0 {
+        return 0.0;
+    }
+    count_as_f64(total) / count_as_f64(count)
+}
+
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "bench counts stay far below 2^52; report values are approximate"
+)]
+const fn count_as_f64(value: usize) -> f64 {
+    value as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::run_index;
+    use crate::format::{LogLevel, OutputArgs};
+
+    #[test]
+    fn bench_search_reports_latency_percentiles_for_query_file() -> Result<(), CliError> {
+        let root = std::env::temp_dir().join(format!("sca-bench-search-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::write(
+            root.join("src/lib.rs"),
+            "fn needle() {}\nfn haystack() {}\n",
+        )?;
+        let queries_file = root.join("queries.txt");
+        std::fs::write(&queries_file, "# warm queries\nneedle\n\nhaystack\n")?;
+        let overrides =
+            r#"{"embedding":{"provider":"test","dimension":8},"vectorDb":{"provider":"local"}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let indexed = run_index(mode, None, Some(overrides), &root, true, false, false)?;
+        let output = run_bench_search(
+            mode,
+            &BenchSearchInput {
+                config_path: None,
+                overrides_json: Some(overrides),
+                codebase_root: &root,
+                queries_file: &queries_file,
+                iterations: 3,
+                top_k: Some(2),
+                threshold: None,
+            },
+        );
+        std::fs::remove_dir_all(&root)?;
+        assert_eq!(indexed.exit_code, ExitCode::Ok, "{}", indexed.stdout);
+        let output = output?;
+        assert_eq!(output.exit_code, ExitCode::Ok, "{}", output.stdout);
+
+        let report: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        assert_eq!(report["status"], "ok");
+        assert_eq!(report["kind"], "benchSearch");
+        assert_eq!(report["queries"], 2);
+        assert_eq!(report["iterations"], 3);
+        assert_eq!(report["samples"], 6);
+        for key in ["p50", "p95", "p99", "mean", "max"] {
+            assert!(report["latencyMs"][key].is_f64(), "missing latencyMs.{key}");
+        }
+        assert!(report["meanResults"].is_f64());
+        Ok(())
+    }
+
+    #[test]
+    fn nearest_rank_percentiles_pick_sorted_samples() {
+        let mut samples = vec![4.0, 1.0, 3.0, 2.0];
+        let summary = summarize_latencies(&mut samples);
+        assert_eq!(summary.p50.to_bits(), 2.0_f64.to_bits());
+        assert_eq!(summary.p99.to_bits(), 4.0_f64.to_bits());
+        assert_eq!(summary.mean.to_bits(), 2.5_f64.to_bits());
+    }
+}
@@ -12,8 +12,9 @@ pub fn run_clear(
     config_path: Option<&Path>,
     overrides_json: Option<&str>,
     codebase_root: &Path,
+    collection: Option<&str>,
 ) -> Result<CliOutput, CliError> {
-    let request = match validate_clear_request_for_root(codebase_root) {
+    let request = match validate_clear_request_for_root(codebase_root, collection) {
         Ok(request) => request,
         Err(error) => return Ok(format_error_output(mode, &error, infra_exit_code(&error))),
     };
@@ -0,0 +1,230 @@
+//! Debug command handlers (hidden, for inspecting stored index data).
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{
+    CollectionName, StoredVectorReport, derive_collection_name_local, detect_embedding_dimension,
+    get_vector_local,
+};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Run the `debug get-vector` command.
+pub fn run_get_vector(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    id: &str,
+) -> Result<CliOutput, CliError> {
+    match get_vector_local(config_path, overrides_json, codebase_root, id) {
+        Ok(report) => format_get_vector_output(mode, &report),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+/// Run the `debug collection-name` command.
+pub fn run_collection_name(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    match derive_collection_name_local(config_path, overrides_json, codebase_root) {
+        Ok(name) => format_collection_name_output(mode, &name),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+/// Run the `debug dimension` command.
+pub fn run_dimension(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    // Provider credentials (e.g. `OPENAI_API_KEY`) live outside the `SCA_` prefix.
+    let env: BTreeMap<String, String> = std::env::vars().collect();
+    match detect_embedding_dimension(&env, config_path, overrides_json, codebase_root) {
+        Ok(dimension) => format_dimension_output(mode, dimension),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_dimension_output(mode: OutputMode, dimension: u32) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "dimension",
+            "dimension": dimension,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "dimension": dimension,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format!("{dimension}\n")
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_collection_name_output(
+    mode: OutputMode,
+    name: &CollectionName,
+) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "collection_name",
+            "collectionName": name,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "collectionName": name,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format!("{name}\n")
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_get_vector_output(
+    mode: OutputMode,
+    report: &StoredVectorReport,
+) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "get_vector",
+            "vector": report,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "vector": report,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_get_vector_text(report)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_get_vector_text(report: &StoredVectorReport) -> String {
+    let mut out = String::new();
+    out.push_str("status: ok\n");
+    let _ = writeln!(out, "id: {}", report.id);
+    let _ = writeln!(out, "dimension: {}", report.dimension);
+    let _ = writeln!(out, "l2Norm: {:.6}", report.l2_norm);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::run_index;
+    use crate::format::{LogLevel, OutputArgs};
+    use semantic_code_facade::read_status_local;
+
+    #[test]
+    fn collection_name_matches_the_collection_index_creates() -> Result<(), CliError> {
+        let root = std::env::temp_dir().join(format!("sca-collection-name-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::write(root.join("src/lib.rs"), "fn lib() {}\n")?;
+        let overrides =
+            r#"{"embedding":{"provider":"test","dimension":8},"vectorDb":{"provider":"local"}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let before = run_collection_name(mode, None, Some(overrides), &root);
+        let indexed = run_index(mode, None, Some(overrides), &root, true, false, false);
+        let after = run_collection_name(mode, None, Some(overrides), &root);
+        let status = read_status_local(None, Some(overrides), &root);
+        std::fs::remove_dir_all(&root)?;
+
+        let (before, after) = (before?, after?);
+        assert_eq!(indexed?.exit_code, ExitCode::Ok);
+        assert_eq!(before.exit_code, ExitCode::Ok, "{}", before.stdout);
+        assert_eq!(before.stdout, after.stdout);
+        let status = status.map_err(|error| CliError::InvalidInput(error.to_string()))?;
+        assert_eq!(
+            before.stdout,
+            format!("{}\n", status.manifest.collection_name)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dimension_reports_the_configured_test_embedding() -> Result<(), CliError> {
+        let root = std::env::temp_dir().join(format!("sca-debug-dimension-{}", std::process::id()));
+        std::fs::create_dir_all(&root)?;
+        let overrides = r#"{"embedding":{"provider":"test","dimension":48}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let output = run_dimension(mode, None, Some(overrides), &root);
+        std::fs::remove_dir_all(&root)?;
+
+        let output = output?;
+        assert_eq!(output.exit_code, ExitCode::Ok, "{}", output.stdout);
+        assert_eq!(output.stdout, "48\n");
+        Ok(())
+    }
+}
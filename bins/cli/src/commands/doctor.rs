@@ -0,0 +1,108 @@
+//! Doctor command handler.
+
+use crate::CliOutput;
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use semantic_code_facade::{DoctorReport, run_doctor_local};
+use std::path::Path;
+
+/// Run the doctor command.
+pub fn run_doctor(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    let report = run_doctor_local(config_path, None, codebase_root);
+    format_doctor_output(mode, &report)
+}
+
+fn format_doctor_output(mode: OutputMode, report: &DoctorReport) -> Result<CliOutput, CliError> {
+    let status = if report.has_failures() { "error" } else { "ok" };
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": status,
+            "kind": "doctor",
+            "checks": report.checks,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": status,
+            "checks": report.checks,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_doctor_text(report)
+    };
+
+    // A failing check is a diagnosis, not a command error; surface it via a
+    // non-zero exit so scripts can gate on `sca doctor`.
+    let exit_code = if report.has_failures() {
+        ExitCode::Internal
+    } else {
+        ExitCode::Ok
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code,
+    })
+}
+
+fn format_doctor_text(report: &DoctorReport) -> String {
+    let mut out = String::new();
+    for check in &report.checks {
+        out.push('[');
+        out.push_str(check.status.as_str());
+        out.push_str("] ");
+        out.push_str(&check.name);
+        out.push_str(": ");
+        out.push_str(&check.message);
+        out.push('\n');
+        if let Some(hint) = check.hint.as_deref() {
+            out.push_str("       hint: ");
+            out.push_str(hint);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_facade::{DoctorCheck, DoctorStatus};
+
+    #[test]
+    fn text_output_lists_checks_with_hints() {
+        let report = DoctorReport {
+            checks: vec![
+                DoctorCheck {
+                    name: "env".into(),
+                    status: DoctorStatus::Pass,
+                    message: "environment overrides parsed".into(),
+                    hint: None,
+                },
+                DoctorCheck {
+                    name: "embedding".into(),
+                    status: DoctorStatus::Fail,
+                    message: "OpenAI API key is required".into(),
+                    hint: Some("set OPENAI_API_KEY".into()),
+                },
+            ],
+        };
+
+        let out = format_doctor_text(&report);
+        assert_eq!(
+            out,
+            "[pass] env: environment overrides parsed\n\
+             [fail] embedding: OpenAI API key is required\n       hint: set OPENAI_API_KEY\n"
+        );
+    }
+}
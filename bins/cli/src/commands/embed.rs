@@ -0,0 +1,103 @@
+//! Embed command handler (hidden, for debugging embedding providers).
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{CliEmbedReport, run_embed_local};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Run the embed command.
+pub fn run_embed(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    text: &str,
+) -> Result<CliOutput, CliError> {
+    match run_embed_local(config_path, overrides_json, codebase_root, text) {
+        Ok(report) => format_embed_output(mode, &report),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_embed_output(mode: OutputMode, report: &CliEmbedReport) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "embed",
+            "embedding": report,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "embedding": report,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_embed_text(report)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_embed_text(report: &CliEmbedReport) -> String {
+    let preview = report
+        .preview
+        .iter()
+        .map(|value| format!("{value:.6}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ellipsis = if report.preview.len() < usize::try_from(report.dimension).unwrap_or(usize::MAX)
+    {
+        ", ..."
+    } else {
+        ""
+    };
+
+    let mut out = String::new();
+    out.push_str("status: ok\n");
+    out.push_str("provider: ");
+    out.push_str(&report.provider);
+    out.push('\n');
+    out.push_str("dimension: ");
+    out.push_str(&report.dimension.to_string());
+    out.push('\n');
+    out.push_str("l2Norm: ");
+    let _ = write!(out, "{:.6}", report.l2_norm);
+    out.push('\n');
+    out.push_str("preview: [");
+    out.push_str(&preview);
+    out.push_str(ellipsis);
+    out.push_str("]\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_output_truncates_preview() {
+        let report = CliEmbedReport {
+            provider: "test".into(),
+            dimension: 16,
+            l2_norm: 1.0,
+            preview: vec![0.5, -0.25],
+        };
+
+        let out = format_embed_text(&report);
+        assert!(out.contains("dimension: 16\n"));
+        assert!(out.contains("preview: [0.500000, -0.250000, ...]\n"));
+    }
+}
@@ -27,7 +27,7 @@ pub fn run_estimate_storage(
     };
 
     let mut stderr = String::new();
-    log_info(&mut stderr, "storage estimate completed", mode.no_progress);
+    log_info(&mut stderr, "storage estimate completed", mode);
 
     let stdout = if mode.is_ndjson() {
         format_estimate_ndjson(&estimate)?
@@ -0,0 +1,83 @@
+//! Export command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{ExportOutput, run_export_local};
+use std::path::Path;
+
+/// Run the export command.
+pub fn run_export(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    codebase_root: &Path,
+    out_path: &Path,
+    include_vectors: bool,
+) -> Result<CliOutput, CliError> {
+    match run_export_local(config_path, None, codebase_root, out_path, include_vectors) {
+        Ok(output) => format_export_output(mode, &output, out_path),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_export_output(
+    mode: OutputMode,
+    output: &ExportOutput,
+    out_path: &Path,
+) -> Result<CliOutput, CliError> {
+    let path = out_path.display().to_string();
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "export",
+            "export": output,
+            "path": path,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "export": output,
+            "path": path,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_export_text(output, &path)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_export_text(output: &ExportOutput, path: &str) -> String {
+    format!(
+        "Exported {} documents from {} to {path}\n",
+        output.exported_documents, output.collection_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_output_summarizes_export() {
+        let output = ExportOutput {
+            collection_name: "code_chunks_abc".into(),
+            exported_documents: 2,
+        };
+
+        assert_eq!(
+            format_export_text(&output, "index.jsonl"),
+            "Exported 2 documents from code_chunks_abc to index.jsonl\n"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Import command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{ImportOutput, run_import_local};
+use std::path::Path;
+
+/// Run the import command.
+pub fn run_import(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    codebase_root: &Path,
+    in_path: &Path,
+) -> Result<CliOutput, CliError> {
+    match run_import_local(config_path, None, codebase_root, in_path) {
+        Ok(output) => format_import_output(mode, &output),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_import_output(mode: OutputMode, output: &ImportOutput) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "import",
+            "import": output,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "import": output,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_import_text(output)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_import_text(output: &ImportOutput) -> String {
+    let collection = if output.created_collection {
+        "created"
+    } else {
+        "existing"
+    };
+    format!(
+        "Imported {} documents into {} collection {} (dimension {})\n",
+        output.imported_documents, collection, output.collection_name, output.dimension
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_output_summarizes_import() {
+        let output = ImportOutput {
+            collection_name: "code_chunks_abc".into(),
+            dimension: 384,
+            imported_documents: 2,
+            created_collection: false,
+        };
+
+        assert_eq!(
+            format_import_text(&output),
+            "Imported 2 documents into existing collection code_chunks_abc (dimension 384)\n"
+        );
+    }
+}
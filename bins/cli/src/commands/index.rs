@@ -3,6 +3,7 @@
 use crate::commands::jobs::{format_job_status, spawn_job_runner};
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
+use crate::profile::format_index_profile;
 use crate::vector_kernel::{
     VectorKernelMetadata, resolve_vector_kernel_metadata_std_env, warn_if_experimental,
 };
@@ -79,6 +80,7 @@ fn format_index_output(
             "indexedFiles": output.indexed_files,
             "totalChunks": output.total_chunks,
             "indexStatus": index_status_label(output.status),
+            "failedFiles": output.failed_files,
             "stageStats": stage_stats_json(output),
             "vectorKernel": vector_kernel.as_json(),
         });
@@ -90,10 +92,15 @@ fn format_index_output(
     } else {
         format_index_text(output)
     };
+    let stderr = if mode.profile {
+        format_index_profile(&output.stage_stats)
+    } else {
+        String::new()
+    };
 
     Ok(CliOutput {
         stdout,
-        stderr: String::new(),
+        stderr,
         exit_code: ExitCode::Ok,
     })
 }
@@ -107,6 +114,7 @@ fn format_index_json(
         "indexedFiles": output.indexed_files,
         "totalChunks": output.total_chunks,
         "indexStatus": index_status_label(output.status),
+        "failedFiles": output.failed_files,
         "stageStats": stage_stats_json(output),
         "vectorKernel": vector_kernel.as_json(),
     });
@@ -127,9 +135,14 @@ fn format_index_text(output: &IndexCodebaseOutput) -> String {
     out.push_str("indexStatus: ");
     out.push_str(index_status_label(output.status));
     out.push('\n');
+    out.push_str("failedFiles: ");
+    out.push_str(&output.failed_files.len().to_string());
+    out.push('\n');
     out.push_str("stageStats:\n");
     out.push_str("  scan: files=");
     out.push_str(&output.stage_stats.scan.files.to_string());
+    out.push_str(" skippedBinary=");
+    out.push_str(&output.stage_stats.scan.skipped_binary.to_string());
     out.push_str(" durationMs=");
     out.push_str(&output.stage_stats.scan.duration_ms.to_string());
     out.push('\n');
@@ -137,6 +150,10 @@ fn format_index_text(output: &IndexCodebaseOutput) -> String {
     out.push_str(&output.stage_stats.split.files.to_string());
     out.push_str(" chunks=");
     out.push_str(&output.stage_stats.split.chunks.to_string());
+    out.push_str(" oversized=");
+    out.push_str(&output.stage_stats.split.oversized_chunks.to_string());
+    out.push_str(" droppedSmall=");
+    out.push_str(&output.stage_stats.split.dropped_small.to_string());
     out.push_str(" durationMs=");
     out.push_str(&output.stage_stats.split.duration_ms.to_string());
     out.push('\n');
@@ -144,6 +161,8 @@ fn format_index_text(output: &IndexCodebaseOutput) -> String {
     out.push_str(&output.stage_stats.embed.batches.to_string());
     out.push_str(" chunks=");
     out.push_str(&output.stage_stats.embed.chunks.to_string());
+    out.push_str(" failed=");
+    out.push_str(&output.stage_stats.embed.failed_chunks.to_string());
     out.push_str(" durationMs=");
     out.push_str(&output.stage_stats.embed.duration_ms.to_string());
     out.push('\n');
@@ -151,6 +170,8 @@ fn format_index_text(output: &IndexCodebaseOutput) -> String {
     out.push_str(&output.stage_stats.insert.batches.to_string());
     out.push_str(" chunks=");
     out.push_str(&output.stage_stats.insert.chunks.to_string());
+    out.push_str(" failed=");
+    out.push_str(&output.stage_stats.insert.failed_chunks.to_string());
     out.push_str(" durationMs=");
     out.push_str(&output.stage_stats.insert.duration_ms.to_string());
     out.push('\n');
@@ -168,22 +189,73 @@ fn stage_stats_json(output: &IndexCodebaseOutput) -> serde_json::Value {
     serde_json::json!({
         "scan": {
             "files": output.stage_stats.scan.files,
+            "skippedBinary": output.stage_stats.scan.skipped_binary,
             "durationMs": output.stage_stats.scan.duration_ms,
         },
         "split": {
             "files": output.stage_stats.split.files,
             "chunks": output.stage_stats.split.chunks,
+            "oversizedChunks": output.stage_stats.split.oversized_chunks,
+            "droppedSmall": output.stage_stats.split.dropped_small,
             "durationMs": output.stage_stats.split.duration_ms,
         },
         "embed": {
             "batches": output.stage_stats.embed.batches,
             "chunks": output.stage_stats.embed.chunks,
+            "failedBatches": output.stage_stats.embed.failed_batches,
+            "failedChunks": output.stage_stats.embed.failed_chunks,
             "durationMs": output.stage_stats.embed.duration_ms,
         },
         "insert": {
             "batches": output.stage_stats.insert.batches,
             "chunks": output.stage_stats.insert.chunks,
+            "failedChunks": output.stage_stats.insert.failed_chunks,
             "durationMs": output.stage_stats.insert.duration_ms,
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::{LogLevel, OutputArgs};
+
+    #[test]
+    fn profile_reports_every_index_stage_on_stderr() -> Result<(), CliError> {
+        let root = std::env::temp_dir().join(format!("sca-profile-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::write(root.join("src/lib.rs"), "fn profiled() {}\n")?;
+        let overrides =
+            r#"{"embedding":{"provider":"test","dimension":8},"vectorDb":{"provider":"local"}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: true,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: true,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let output = run_index(mode, None, Some(overrides), &root, true, false, false);
+        std::fs::remove_dir_all(&root)?;
+        let output = output?;
+        assert_eq!(output.exit_code, ExitCode::Ok, "{}", output.stdout);
+
+        for stage in ["scan", "split", "embed", "insert"] {
+            let line = output
+                .stderr
+                .lines()
+                .find(|line| line.trim_start().starts_with(&format!("{stage}:")))
+                .ok_or_else(|| CliError::InvalidInput(format!("missing {stage} in profile")))?;
+            let duration = line
+                .split_whitespace()
+                .find_map(|field| field.strip_prefix("durationMs="))
+                .and_then(|value| value.parse::<u64>().ok());
+            assert!(duration.is_some(), "{line}");
+        }
+        Ok(())
+    }
+}
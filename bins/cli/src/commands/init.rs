@@ -3,7 +3,7 @@
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
 use crate::{CliOutput, format_error_output, infra_exit_code};
-use semantic_code_facade::{CliInitStatus, SnapshotStorageMode, run_init_local};
+use semantic_code_facade::{CliInitStatus, ConfigTemplate, SnapshotStorageMode, run_init_local};
 use std::path::Path;
 
 /// Run the init command.
@@ -12,9 +12,10 @@ pub fn run_init(
     config_path: Option<&Path>,
     codebase_root: &Path,
     storage_mode: Option<SnapshotStorageMode>,
+    template: Option<ConfigTemplate>,
     force: bool,
 ) -> Result<CliOutput, CliError> {
-    let status = match run_init_local(config_path, codebase_root, storage_mode, force) {
+    let status = match run_init_local(config_path, codebase_root, storage_mode, template, force) {
         Ok(status) => status,
         Err(error) => return Ok(format_error_output(mode, &error, infra_exit_code(&error))),
     };
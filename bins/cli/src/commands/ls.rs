@@ -0,0 +1,92 @@
+//! Ls command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{IndexedFileSummary, list_indexed_files_local};
+use std::path::Path;
+
+/// Run the ls command.
+pub fn run_ls(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    match list_indexed_files_local(config_path, None, codebase_root) {
+        Ok(files) => format_ls_output(mode, &files),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_ls_output(mode: OutputMode, files: &[IndexedFileSummary]) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "ls",
+            "count": files.len(),
+            "files": files,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "files": files,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_ls_text(files)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_ls_text(files: &[IndexedFileSummary]) -> String {
+    let mut out = String::new();
+    for file in files {
+        out.push_str(&file.relative_path);
+        out.push('\t');
+        out.push_str(&file.chunk_count.to_string());
+        out.push('\t');
+        out.push_str(file.language.as_deref().unwrap_or("-"));
+        out.push('\n');
+    }
+    out.push_str("files: ");
+    out.push_str(&files.len().to_string());
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_output_lists_one_file_per_line() {
+        let files = vec![
+            IndexedFileSummary {
+                relative_path: "README.md".into(),
+                chunk_count: 2,
+                language: None,
+            },
+            IndexedFileSummary {
+                relative_path: "src/lib.rs".into(),
+                chunk_count: 5,
+                language: Some("rust".into()),
+            },
+        ];
+
+        assert_eq!(
+            format_ls_text(&files),
+            "README.md\t2\t-\nsrc/lib.rs\t5\trust\nfiles: 2\n"
+        );
+    }
+}
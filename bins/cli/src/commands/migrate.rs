@@ -0,0 +1,104 @@
+//! Migrate command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{MigrateOutput, MigrateProgress, run_migrate_local};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Run the migrate command.
+pub fn run_migrate(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    destination_overrides_json: &str,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    let on_progress: Option<Arc<dyn Fn(MigrateProgress) + Send + Sync>> = if mode.no_progress {
+        None
+    } else {
+        Some(Arc::new(report_migrate_progress))
+    };
+    match run_migrate_local(
+        config_path,
+        None,
+        destination_overrides_json,
+        codebase_root,
+        on_progress,
+    ) {
+        Ok(output) => format_migrate_output(mode, &output),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+/// Render batch progress live on stderr; large collections take a while.
+fn report_migrate_progress(progress: MigrateProgress) {
+    eprintln!(
+        "info: migrate: {}/{} documents ({}%)",
+        progress.current, progress.total, progress.percentage
+    );
+}
+
+fn format_migrate_output(mode: OutputMode, output: &MigrateOutput) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "migrate",
+            "migration": output,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "migration": output,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_migrate_text(output)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_migrate_text(output: &MigrateOutput) -> String {
+    let collection = if output.created_collection {
+        "created"
+    } else {
+        "existing"
+    };
+    format!(
+        "Migrated {} documents into {} collection {} (dimension {})\n\
+         Set vectorDb.provider to the destination in your config to use it.\n",
+        output.migrated_documents, collection, output.collection_name, output.dimension
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_output_summarizes_migration() {
+        let output = MigrateOutput {
+            collection_name: "code_chunks_abc".into(),
+            dimension: 384,
+            migrated_documents: 42,
+            created_collection: true,
+        };
+
+        assert_eq!(
+            format_migrate_text(&output),
+            "Migrated 42 documents into created collection code_chunks_abc (dimension 384)\n\
+             Set vectorDb.provider to the destination in your config to use it.\n"
+        );
+    }
+}
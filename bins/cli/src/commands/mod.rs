@@ -1,23 +1,43 @@
 //! Local CLI command handlers.
 
+pub mod bench;
 pub mod calibrate;
 pub mod clear;
+pub mod debug;
+pub mod doctor;
+pub mod embed;
 pub mod estimate_storage;
+pub mod export;
+pub mod import;
 pub mod index;
 pub mod info;
 pub mod init;
 pub mod jobs;
+pub mod ls;
+pub mod migrate;
+pub mod prune;
 pub mod reindex;
 pub mod search;
 pub mod status;
+pub mod verify;
 
+pub use bench::{BenchSearchInput, run_bench_search};
 pub use calibrate::{CalibrateCommandInput, run_calibrate};
 pub use clear::run_clear;
+pub use debug::{run_collection_name, run_dimension, run_get_vector};
+pub use doctor::run_doctor;
+pub use embed::run_embed;
 pub use estimate_storage::run_estimate_storage;
+pub use export::run_export;
+pub use import::run_import;
 pub use index::run_index;
 pub use info::run_info;
 pub use init::run_init;
 pub use jobs::{run_jobs_cancel, run_jobs_run, run_jobs_status};
+pub use ls::run_ls;
+pub use migrate::run_migrate;
+pub use prune::run_prune;
 pub use reindex::run_reindex;
 pub use search::{SearchCommandInput, run_search, run_search_stdin_batch};
 pub use status::run_status;
+pub use verify::run_verify;
@@ -0,0 +1,138 @@
+//! Prune command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{PruneOutput, run_prune_local};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Run the prune command.
+pub fn run_prune(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    match run_prune_local(config_path, overrides_json, codebase_root) {
+        Ok(output) => format_prune_output(mode, &output),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_prune_output(mode: OutputMode, output: &PruneOutput) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": "ok",
+            "kind": "prune",
+            "prune": output,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": "ok",
+            "prune": output,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_prune_text(output)
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code: ExitCode::Ok,
+    })
+}
+
+fn format_prune_text(output: &PruneOutput) -> String {
+    let mut out = String::new();
+    for path in &output.pruned_files {
+        let _ = writeln!(out, "pruned: {path}");
+    }
+    let _ = writeln!(
+        out,
+        "Pruned {} files ({} chunks); {} indexed, {} on disk",
+        output.pruned_files.len(),
+        output.removed_chunks,
+        output.indexed_files,
+        output.scanned_files
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::run_index;
+    use crate::format::{LogLevel, OutputArgs};
+    use semantic_code_facade::list_indexed_files_local;
+
+    #[test]
+    fn text_output_lists_pruned_files() {
+        let output = PruneOutput {
+            scanned_files: 3,
+            indexed_files: 4,
+            pruned_files: vec!["src/old.rs".into()],
+            removed_chunks: 2,
+        };
+
+        assert_eq!(
+            format_prune_text(&output),
+            "pruned: src/old.rs\nPruned 1 files (2 chunks); 4 indexed, 3 on disk\n"
+        );
+    }
+
+    #[test]
+    fn prune_removes_only_chunks_of_deleted_files() -> Result<(), CliError> {
+        let root = std::env::temp_dir().join(format!("sca-prune-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::write(root.join("src/keep.rs"), "fn keep() {}\n")?;
+        std::fs::write(root.join("src/gone.rs"), "fn gone() {}\n")?;
+        let overrides =
+            r#"{"embedding":{"provider":"test","dimension":8},"vectorDb":{"provider":"local"}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: true,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let indexed = run_index(mode, None, Some(overrides), &root, true, false, false)?;
+        std::fs::remove_file(root.join("src/gone.rs"))?;
+        let output = run_prune(mode, None, Some(overrides), &root);
+        let remaining = list_indexed_files_local(None, Some(overrides), &root);
+        std::fs::remove_dir_all(&root)?;
+        assert_eq!(indexed.exit_code, ExitCode::Ok, "{}", indexed.stdout);
+        let output = output?;
+        assert_eq!(output.exit_code, ExitCode::Ok, "{}", output.stdout);
+
+        let report: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        assert_eq!(report.get("status"), Some(&serde_json::json!("ok")));
+        let prune = report.get("prune").cloned().unwrap_or_default();
+        assert_eq!(
+            prune.get("prunedFiles").cloned().unwrap_or_default(),
+            serde_json::json!(["src/gone.rs"])
+        );
+        assert_eq!(prune.get("scannedFiles"), Some(&serde_json::json!(1)));
+        assert_eq!(prune.get("indexedFiles"), Some(&serde_json::json!(2)));
+
+        let remaining: Vec<_> = remaining
+            .map_err(|error| CliError::InvalidInput(error.to_string()))?
+            .into_iter()
+            .map(|file| file.relative_path)
+            .collect();
+        assert_eq!(remaining, vec![Box::<str>::from("src/keep.rs")]);
+        Ok(())
+    }
+}
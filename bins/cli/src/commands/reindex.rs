@@ -3,6 +3,7 @@
 use crate::commands::jobs::{format_job_status, spawn_job_runner};
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
+use crate::profile::format_index_profile;
 use crate::vector_kernel::{
     VectorKernelMetadata, resolve_vector_kernel_metadata_std_env, warn_if_experimental,
 };
@@ -51,14 +52,14 @@ pub fn run_reindex(
     }
 
     match run_reindex_local(config_path, overrides_json, &request) {
-        Ok(output) => format_reindex_output(mode, output, vector_kernel),
+        Ok(output) => format_reindex_output(mode, &output, vector_kernel),
         Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
     }
 }
 
 fn format_reindex_output(
     mode: OutputMode,
-    output: ReindexByChangeOutput,
+    output: &ReindexByChangeOutput,
     vector_kernel: VectorKernelMetadata,
 ) -> Result<CliOutput, CliError> {
     let stdout = if mode.is_ndjson() {
@@ -69,6 +70,7 @@ fn format_reindex_output(
             "added": output.added,
             "removed": output.removed,
             "modified": output.modified,
+            "failedFiles": output.failed_files,
             "vectorKernel": vector_kernel.as_json(),
         });
         let mut out = serde_json::to_string(&payload)?;
@@ -80,6 +82,7 @@ fn format_reindex_output(
             "added": output.added,
             "removed": output.removed,
             "modified": output.modified,
+            "failedFiles": output.failed_files,
             "vectorKernel": vector_kernel.as_json(),
         });
         let mut out = serde_json::to_string_pretty(&payload)?;
@@ -87,14 +90,21 @@ fn format_reindex_output(
         out
     } else {
         format!(
-            "status: ok\nadded: {}\nremoved: {}\nmodified: {}\n",
-            output.added, output.removed, output.modified
+            "status: ok\nadded: {}\nremoved: {}\nmodified: {}\nfailedFiles: {}\n",
+            output.added,
+            output.removed,
+            output.modified,
+            output.failed_files.len()
         )
     };
+    let stderr = match output.stage_stats.as_ref() {
+        Some(stats) if mode.profile => format_index_profile(stats),
+        _ => String::new(),
+    };
 
     Ok(CliOutput {
         stdout,
-        stderr: String::new(),
+        stderr,
         exit_code: ExitCode::Ok,
     })
 }
@@ -2,14 +2,20 @@
 
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
+use crate::output::log_info;
+use crate::profile::format_search_profile;
 use crate::vector_kernel::{
     VectorKernelMetadata, resolve_vector_kernel_metadata_std_env, warn_if_experimental,
 };
 use crate::{CliOutput, format_error_output, infra_exit_code};
-use semantic_code_facade::{SearchOutput, run_search_local, validate_search_request_for_query};
+use semantic_code_facade::{
+    SearchOutput, SearchPhase, SearchQueryOptions, run_search_local,
+    run_search_local_with_progress, validate_search_request_for_query,
+};
 use std::fmt::Write;
 use std::io::{self, BufRead};
 use std::path::Path;
+use std::sync::Arc;
 
 /// Inputs for search command execution.
 pub struct SearchCommandInput<'a> {
@@ -21,17 +27,38 @@ pub struct SearchCommandInput<'a> {
     pub threshold: Option<f32>,
     pub filter_expr: Option<&'a str>,
     pub include_content: bool,
+    pub context_lines: Option<u32>,
+    pub explain: bool,
+    pub group_by_file: bool,
+    pub exclude_paths: &'a [String],
+    pub exclude_languages: &'a [String],
 }
 
 /// Run the search command.
-pub fn run_search(mode: OutputMode, input: &SearchCommandInput<'_>) -> Result<CliOutput, CliError> {
+///
+/// `fail_on_empty` exits with a non-zero code when no results are returned.
+pub fn run_search(
+    mode: OutputMode,
+    input: &SearchCommandInput<'_>,
+    fail_on_empty: bool,
+) -> Result<CliOutput, CliError> {
+    let options = SearchQueryOptions {
+        top_k: input.top_k,
+        threshold: input.threshold,
+        filter_expr: input.filter_expr,
+        include_content: input.include_content,
+        context_lines: input.context_lines,
+        include_histogram: input.explain,
+        group_by_file: input.group_by_file,
+        exclude_paths: input.exclude_paths,
+        exclude_languages: input.exclude_languages,
+    };
     let request = match validate_search_request_for_query(
+        input.config_path,
+        input.overrides_json,
         input.codebase_root,
         input.query,
-        input.top_k,
-        input.threshold,
-        input.filter_expr,
-        input.include_content,
+        &options,
     ) {
         Ok(request) => request,
         Err(error) => return Ok(format_error_output(mode, &error, infra_exit_code(&error))),
@@ -43,16 +70,41 @@ pub fn run_search(mode: OutputMode, input: &SearchCommandInput<'_>) -> Result<Cl
         };
     warn_if_experimental(vector_kernel);
 
-    match run_search_local(input.config_path, input.overrides_json, &request) {
-        Ok(output) => format_search_output(mode, &output, vector_kernel),
+    let result = if mode.no_progress {
+        run_search_local(input.config_path, input.overrides_json, &request)
+    } else {
+        run_search_local_with_progress(
+            input.config_path,
+            input.overrides_json,
+            &request,
+            Arc::new(report_search_phase),
+        )
+    };
+    match result {
+        Ok(output) => format_search_output(mode, &output, vector_kernel, fail_on_empty),
         Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
     }
 }
 
+/// Render search phases live on stderr so slow query embedding does not look like a hang.
+fn report_search_phase(phase: SearchPhase) {
+    eprintln!("info: search: {}", search_phase_label(phase));
+}
+
+const fn search_phase_label(phase: SearchPhase) -> &'static str {
+    match phase {
+        SearchPhase::EmbeddingQuery => "embedding query",
+        SearchPhase::Searching => "searching index",
+        SearchPhase::Reranking => "ranking results",
+        SearchPhase::Done => "done",
+    }
+}
+
 fn format_search_output(
     mode: OutputMode,
     output: &SearchOutput,
     vector_kernel: VectorKernelMetadata,
+    fail_on_empty: bool,
 ) -> Result<CliOutput, CliError> {
     let stdout = if mode.is_ndjson() {
         format_search_ndjson(output, vector_kernel)?
@@ -62,10 +114,25 @@ fn format_search_output(
         format_search_text(output)?
     };
 
+    let mut stderr = String::new();
+    let exit_code = if fail_on_empty && output.results.is_empty() {
+        log_info(
+            &mut stderr,
+            "search returned no results (--fail-on-empty)",
+            mode,
+        );
+        ExitCode::InvalidInput
+    } else {
+        ExitCode::Ok
+    };
+    if mode.profile {
+        stderr.push_str(&format_search_profile(output));
+    }
+
     Ok(CliOutput {
         stdout,
-        stderr: String::new(),
-        exit_code: ExitCode::Ok,
+        stderr,
+        exit_code,
     })
 }
 
@@ -77,9 +144,18 @@ fn format_search_json(
     payload.insert("status".into(), serde_json::Value::String("ok".into()));
     payload.insert("results".into(), serde_json::to_value(&output.results)?);
     payload.insert("vectorKernel".into(), vector_kernel.as_json());
+    if let Some(filter) = output.normalized_filter.as_deref() {
+        payload.insert("normalizedFilter".into(), filter.into());
+    }
     if let Some(stats) = output.stats.as_ref() {
         payload.insert("searchStats".into(), serde_json::to_value(stats)?);
     }
+    if let Some(diagnostics) = output.diagnostics.as_ref() {
+        payload.insert(
+            "searchDiagnostics".into(),
+            serde_json::to_value(diagnostics)?,
+        );
+    }
     let mut out = serde_json::to_string_pretty(&serde_json::Value::Object(payload))?;
     out.push('\n');
     Ok(out)
@@ -111,9 +187,18 @@ fn format_search_ndjson(
         serde_json::Value::from(output.results.len()),
     );
     summary.insert("vectorKernel".into(), vector_kernel.as_json());
+    if let Some(filter) = output.normalized_filter.as_deref() {
+        summary.insert("normalizedFilter".into(), filter.into());
+    }
     if let Some(stats) = output.stats.as_ref() {
         summary.insert("searchStats".into(), serde_json::to_value(stats)?);
     }
+    if let Some(diagnostics) = output.diagnostics.as_ref() {
+        summary.insert(
+            "searchDiagnostics".into(),
+            serde_json::to_value(diagnostics)?,
+        );
+    }
     let line = serde_json::to_string(&serde_json::Value::Object(summary))?;
     out.push_str(&line);
     out.push('\n');
@@ -142,6 +227,24 @@ fn format_search_text(output: &SearchOutput) -> Result<String, CliError> {
         out.push('\n');
     }
 
+    if let Some(histogram) = output
+        .diagnostics
+        .as_ref()
+        .and_then(|diagnostics| diagnostics.score_histogram)
+    {
+        out.push_str("scoreHistogram:\n");
+        for (index, count) in histogram.iter().enumerate() {
+            let upper = if index + 1 == histogram.len() {
+                "1.0".to_owned()
+            } else {
+                format!("0.{}", index + 1)
+            };
+            if let Err(error) = writeln!(&mut out, "  0.{index}-{upper}: {count}") {
+                return Err(CliError::Io(io::Error::other(error.to_string())));
+            }
+        }
+    }
+
     Ok(out)
 }
 
@@ -329,9 +432,18 @@ fn write_batch_result(
     payload.insert("status".into(), serde_json::Value::String("ok".into()));
     payload.insert("results".into(), serde_json::to_value(&output.results)?);
     payload.insert("vectorKernel".into(), vector_kernel.as_json());
+    if let Some(filter) = output.normalized_filter.as_deref() {
+        payload.insert("normalizedFilter".into(), filter.into());
+    }
     if let Some(stats) = output.stats.as_ref() {
         payload.insert("searchStats".into(), serde_json::to_value(stats)?);
     }
+    if let Some(diagnostics) = output.diagnostics.as_ref() {
+        payload.insert(
+            "searchDiagnostics".into(),
+            serde_json::to_value(diagnostics)?,
+        );
+    }
     if let Some(vec) = query_vector {
         payload.insert("queryVector".into(), serde_json::to_value(vec)?);
     }
@@ -356,6 +468,43 @@ fn write_batch_error(writer: &mut impl io::Write, message: &str) -> Result<(), C
 #[cfg(test)]
 mod tests {
     use super::*;
+    use semantic_code_facade::SearchTimings;
+
+    fn hnsw_kernel() -> VectorKernelMetadata {
+        VectorKernelMetadata::new(semantic_code_facade::CliVectorKernelKind::HnswRs)
+    }
+
+    #[test]
+    fn fail_on_empty_sets_non_zero_exit_code_and_keeps_payload() -> Result<(), CliError> {
+        let empty = SearchOutput {
+            results: Vec::new(),
+            stats: None,
+            diagnostics: None,
+            normalized_filter: None,
+            timings: SearchTimings::default(),
+        };
+        let mode = OutputMode::from_args(&crate::format::OutputArgs {
+            output: None,
+            json: true,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: crate::format::LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+
+        let output = format_search_output(mode, &empty, hnsw_kernel(), true)?;
+        assert_eq!(output.exit_code, ExitCode::InvalidInput);
+        let payload: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        assert_eq!(payload["status"], "ok");
+        assert_eq!(payload["results"], serde_json::json!([]));
+
+        let output = format_search_output(mode, &empty, hnsw_kernel(), false)?;
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        Ok(())
+    }
 
     #[test]
     fn ndjson_output_includes_summary_line() -> Result<(), CliError> {
@@ -371,6 +520,9 @@ mod tests {
         let output = SearchOutput {
             results: vec![result],
             stats: None,
+            diagnostics: None,
+            normalized_filter: None,
+            timings: SearchTimings::default(),
         };
         let output = format_search_ndjson(
             &output,
@@ -396,6 +548,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn json_output_echoes_normalized_filter() -> Result<(), CliError> {
+        let output = SearchOutput {
+            results: Vec::new(),
+            stats: None,
+            diagnostics: None,
+            normalized_filter: Some("language == 'rust' && relativePath == 'a.rs'".to_owned()),
+            timings: SearchTimings::default(),
+        };
+        let payload: serde_json::Value =
+            serde_json::from_str(&format_search_json(&output, hnsw_kernel())?)?;
+        assert_eq!(
+            payload.get("normalizedFilter").and_then(|v| v.as_str()),
+            Some("language == 'rust' && relativePath == 'a.rs'")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn output_file_receives_the_stdout_payload() -> Result<(), CliError> {
+        let result: semantic_code_facade::SearchResult =
+            serde_json::from_value(serde_json::json!({
+                "key": {
+                    "relativePath": "src/lib.rs",
+                    "span": { "startLine": 1, "endLine": 2 }
+                },
+                "content": "fn main() {}",
+                "score": 0.42
+            }))?;
+        let output = SearchOutput {
+            results: vec![result],
+            stats: None,
+            diagnostics: None,
+            normalized_filter: None,
+            timings: SearchTimings::default(),
+        };
+        let mode = OutputMode::from_args(&crate::format::OutputArgs {
+            output: Some(crate::format::OutputFormat::Ndjson),
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: crate::format::LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+        let output = format_search_output(mode, &output, hnsw_kernel(), false)?;
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("sca-output-file-{nanos}"));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("search.ndjson");
+        std::fs::write(&path, "stale\n")?;
+
+        crate::output::write_output(&output, Some(&path))?;
+        assert_eq!(std::fs::read_to_string(&path)?, output.stdout);
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
     #[test]
     fn ndjson_summary_includes_search_stats_when_present() -> Result<(), CliError> {
         let output = SearchOutput {
@@ -407,6 +625,9 @@ mod tests {
                 kernel_search_duration_ns: None,
                 index_size: None,
             }),
+            diagnostics: None,
+            normalized_filter: None,
+            timings: SearchTimings::default(),
         };
         let output = format_search_ndjson(
             &output,
@@ -429,6 +650,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn ndjson_summary_includes_search_diagnostics_when_present() -> Result<(), CliError> {
+        let output = SearchOutput {
+            results: Vec::new(),
+            stats: None,
+            diagnostics: Some(semantic_code_facade::SearchDiagnostics {
+                score_histogram: Some([0, 1, 0, 0, 0, 0, 0, 3, 0, 2]),
+            }),
+            normalized_filter: None,
+            timings: SearchTimings::default(),
+        };
+        let output = format_search_ndjson(&output, hnsw_kernel())?;
+        let summary_line = output
+            .lines()
+            .last()
+            .ok_or_else(|| io::Error::other("missing summary line"))?;
+        let summary: serde_json::Value =
+            serde_json::from_str(summary_line).map_err(io::Error::other)?;
+        assert!(summary.get("searchStats").is_none());
+        assert_eq!(
+            summary
+                .get("searchDiagnostics")
+                .and_then(|diagnostics| diagnostics.get("scoreHistogram")),
+            Some(&serde_json::json!([0, 1, 0, 0, 0, 0, 0, 3, 0, 2]))
+        );
+        Ok(())
+    }
+
     #[test]
     fn ndjson_summary_includes_extra_metrics_when_present() -> Result<(), CliError> {
         let mut extra = std::collections::BTreeMap::new();
@@ -443,6 +692,9 @@ mod tests {
                 kernel_search_duration_ns: None,
                 index_size: None,
             }),
+            diagnostics: None,
+            normalized_filter: None,
+            timings: SearchTimings::default(),
         };
         let output = format_search_ndjson(
             &output,
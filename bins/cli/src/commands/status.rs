@@ -3,7 +3,10 @@
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
 use crate::{CliOutput, format_error_output, infra_exit_code};
-use semantic_code_facade::{CliConfigSummary, CliStatus, SnapshotStatus, read_status_local};
+use semantic_code_facade::{
+    CliConfigSummary, CliIndexState, CliStatus, JobKind, JobState, JobSummary, SnapshotStatus,
+    read_status_local,
+};
 use std::path::Path;
 
 /// Run the status command.
@@ -38,10 +41,12 @@ fn format_status_output(mode: OutputMode, status: &CliStatus) -> Result<CliOutpu
 fn format_status_json(status: &CliStatus) -> Result<String, CliError> {
     let payload = serde_json::json!({
         "status": "ok",
+        "indexState": status.index_state,
         "manifest": status.manifest,
         "vectorSnapshot": snapshot_json(&status.vector_snapshot),
         "syncSnapshot": snapshot_json(&status.sync_snapshot),
         "config": config_json(&status.config),
+        "recentJobs": status.recent_jobs,
     });
     let mut out = serde_json::to_string_pretty(&payload)?;
     out.push('\n');
@@ -52,10 +57,12 @@ fn format_status_ndjson(status: &CliStatus) -> Result<String, CliError> {
     let payload = serde_json::json!({
         "type": "summary",
         "status": "ok",
+        "indexState": status.index_state,
         "manifest": status.manifest,
         "vectorSnapshot": snapshot_json(&status.vector_snapshot),
         "syncSnapshot": snapshot_json(&status.sync_snapshot),
         "config": config_json(&status.config),
+        "recentJobs": status.recent_jobs,
     });
     let mut out = serde_json::to_string(&payload)?;
     out.push('\n');
@@ -65,6 +72,11 @@ fn format_status_ndjson(status: &CliStatus) -> Result<String, CliError> {
 fn format_status_text(status: &CliStatus) -> String {
     let mut out = String::new();
     out.push_str("status: ok\n");
+    push_kv(
+        &mut out,
+        "indexState",
+        &index_state_text(status.index_state),
+    );
     out.push_str("collection: ");
     out.push_str(status.manifest.collection_name.as_str());
     out.push('\n');
@@ -77,6 +89,7 @@ fn format_status_text(status: &CliStatus) -> String {
     write_snapshot_text(&mut out, "vector", &status.vector_snapshot);
     write_snapshot_text(&mut out, "sync", &status.sync_snapshot);
     write_config_text(&mut out, &status.config);
+    write_jobs_text(&mut out, &status.recent_jobs);
     out
 }
 
@@ -224,6 +237,48 @@ fn write_config_text(out: &mut String, config: &CliConfigSummary) {
     }
 }
 
+fn write_jobs_text(out: &mut String, jobs: &[JobSummary]) {
+    push_kv(out, "recentJobs", &jobs.len().to_string());
+    for job in jobs {
+        let value = format!(
+            "{} {} {} {}ms",
+            job.id,
+            job_kind_str(job.kind),
+            job_state_str(job.state),
+            job.duration_ms
+        );
+        push_kv(out, "job", &value);
+    }
+}
+
+fn index_state_text(state: CliIndexState) -> String {
+    match state {
+        CliIndexState::NotIndexed => "not indexed".to_string(),
+        CliIndexState::Empty => "empty (no files to index)".to_string(),
+        CliIndexState::Indexed {
+            vectors: Some(vectors),
+        } => format!("indexed ({vectors} vectors)"),
+        CliIndexState::Indexed { vectors: None } => "indexed".to_string(),
+    }
+}
+
+const fn job_kind_str(kind: JobKind) -> &'static str {
+    match kind {
+        JobKind::Index => "index",
+        JobKind::Reindex => "reindex",
+    }
+}
+
+const fn job_state_str(state: JobState) -> &'static str {
+    match state {
+        JobState::Queued => "queued",
+        JobState::Running => "running",
+        JobState::Completed => "completed",
+        JobState::Failed => "failed",
+        JobState::Cancelled => "cancelled",
+    }
+}
+
 fn format_snapshot_storage(config: &CliConfigSummary) -> String {
     let rendered = serde_json::to_string(&config.snapshot_storage)
         .unwrap_or_else(|_| "\"unknown\"".to_string());
@@ -0,0 +1,121 @@
+//! Verify command handler.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::{CliOutput, format_error_output, infra_exit_code};
+use semantic_code_facade::{VerifyDrift, VerifyReport, VerifyStatus, run_verify_local};
+use std::path::Path;
+
+/// Run the verify command.
+pub fn run_verify(
+    mode: OutputMode,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<CliOutput, CliError> {
+    match run_verify_local(config_path, overrides_json, codebase_root) {
+        Ok(report) => format_verify_output(mode, &report),
+        Err(error) => Ok(format_error_output(mode, &error, infra_exit_code(&error))),
+    }
+}
+
+fn format_verify_output(mode: OutputMode, report: &VerifyReport) -> Result<CliOutput, CliError> {
+    let stdout = if mode.is_ndjson() {
+        let payload = serde_json::json!({
+            "type": "summary",
+            "status": status_str(report.status),
+            "kind": "verify",
+            "report": report,
+        });
+        let mut out = serde_json::to_string(&payload)?;
+        out.push('\n');
+        out
+    } else if mode.is_json() {
+        let payload = serde_json::json!({
+            "status": status_str(report.status),
+            "report": report,
+        });
+        let mut out = serde_json::to_string_pretty(&payload)?;
+        out.push('\n');
+        out
+    } else {
+        format_verify_text(report)
+    };
+
+    // Drift is a failed check rather than a failed command; surface it via a
+    // non-zero exit so scripts can gate on `sca verify`.
+    let exit_code = match report.status {
+        VerifyStatus::Ok => ExitCode::Ok,
+        VerifyStatus::Drift => ExitCode::Internal,
+    };
+
+    Ok(CliOutput {
+        stdout,
+        stderr: String::new(),
+        exit_code,
+    })
+}
+
+const fn status_str(status: VerifyStatus) -> &'static str {
+    match status {
+        VerifyStatus::Ok => "ok",
+        VerifyStatus::Drift => "drift",
+    }
+}
+
+fn format_verify_text(report: &VerifyReport) -> String {
+    let mut out = String::new();
+    out.push_str("status: ");
+    out.push_str(status_str(report.status));
+    out.push('\n');
+    out.push_str("collection: ");
+    out.push_str(report.collection_name.as_str());
+    out.push('\n');
+    out.push_str("collectionExists: ");
+    out.push_str(if report.collection_exists {
+        "true"
+    } else {
+        "false"
+    });
+    out.push('\n');
+    out.push_str("vectorCount: ");
+    out.push_str(
+        &report
+            .vector_count
+            .map_or_else(|| "-".to_string(), |v| v.to_string()),
+    );
+    out.push('\n');
+    out.push_str("vectorDimension: ");
+    out.push_str(
+        &report
+            .vector_dimension
+            .map_or_else(|| "-".to_string(), |v| v.to_string()),
+    );
+    out.push('\n');
+    for drift in &report.drift {
+        out.push_str("drift: ");
+        out.push_str(&format_drift_text(drift));
+        out.push('\n');
+    }
+    out
+}
+
+fn format_drift_text(drift: &VerifyDrift) -> String {
+    match drift {
+        VerifyDrift::MissingCollection => "collection missing from vector DB".to_string(),
+        VerifyDrift::CountMismatch { expected, actual } => {
+            format!("count mismatch (snapshot={expected}, vectordb={actual})")
+        },
+        VerifyDrift::DimensionMismatch { expected, actual } => {
+            format!("dimension mismatch (snapshot={expected}, vectordb={actual})")
+        },
+        VerifyDrift::DanglingDocuments { count, sample } => {
+            let sample = sample
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&str>>()
+                .join(", ");
+            format!("{count} dangling documents (sample: {sample})")
+        },
+    }
+}
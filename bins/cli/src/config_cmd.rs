@@ -2,19 +2,24 @@
 
 use crate::error::{CliError, ExitCode};
 use crate::format::OutputMode;
-use crate::output::{CliOutput, format_error_output, format_ndjson_summary, log_info};
+use crate::output::{CliOutput, format_error_output, format_ndjson_summary, log_debug, log_info};
 use crate::resolve::collect_scoped_env;
-use semantic_code_facade::load_effective_config_json;
+use semantic_code_facade::{
+    ConfigFieldChange, InfraError, load_effective_config_diff, load_effective_config_json,
+    load_effective_config_with_warnings,
+};
 use std::collections::BTreeMap;
+use std::fmt::Write;
 use std::path::Path;
 
 pub fn config_check(
     mode: OutputMode,
     path: Option<&Path>,
     overrides_json: Option<&str>,
+    show_diff: bool,
 ) -> Result<CliOutput, CliError> {
     let env = collect_scoped_env("SCA_");
-    config_check_with_env(mode, &env, path, overrides_json)
+    config_check_with_env(mode, &env, path, overrides_json, show_diff)
 }
 
 pub fn config_check_with_env(
@@ -22,32 +27,52 @@ pub fn config_check_with_env(
     env: &BTreeMap<String, String>,
     path: Option<&Path>,
     overrides_json: Option<&str>,
+    show_diff: bool,
 ) -> Result<CliOutput, CliError> {
-    let config_json = match load_effective_config_json(env, path, overrides_json) {
-        Ok(config) => config,
+    let (config_json, warnings) =
+        match load_effective_config_with_warnings(env, path, overrides_json) {
+            Ok(loaded) => loaded,
+            Err(error) => return Ok(format_error_output(mode, &error, ExitCode::InvalidInput)),
+        };
+    let diff = match load_diff(env, path, overrides_json, show_diff) {
+        Ok(diff) => diff,
         Err(error) => return Ok(format_error_output(mode, &error, ExitCode::InvalidInput)),
     };
 
     let mut stderr = String::new();
-    log_info(&mut stderr, "config check completed", mode.no_progress);
+    log_debug(
+        &mut stderr,
+        &format!("{} SCA_* environment variables in scope", env.len()),
+        mode,
+    );
+    // Warnings are non-fatal but always shown, even with --no-progress.
+    for warning in &warnings {
+        if let Err(error) = writeln!(stderr, "warning: {}: {}", warning.field, warning.message) {
+            return Err(CliError::Io(std::io::Error::other(error.to_string())));
+        }
+    }
+    log_info(&mut stderr, "config check completed", mode);
 
     let stdout = if mode.is_ndjson() {
-        format_ndjson_summary("ok", "config", None)
+        format_ndjson_summary("ok", "config", diff_json(diff.as_deref())?)
     } else if mode.is_json() {
         let config_value: serde_json::Value = serde_json::from_str(config_json.trim())?;
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "status": "ok",
             "configPath": path.map(|value| value.to_string_lossy().to_string()),
             "effectiveConfig": config_value,
         });
+        merge_diff_json(&mut payload, diff.as_deref())?;
         let mut output = serde_json::to_string_pretty(&payload)?;
         output.push('\n');
         output
     } else {
-        path.map_or_else(
+        let mut out = path.map_or_else(
             || "status: ok\nconfig: ok\n".to_string(),
             |path| format!("status: ok\nconfig: ok\npath: {}\n", path.to_string_lossy()),
-        )
+        );
+        write_diff_text(&mut out, diff.as_deref())?;
+        out
     };
 
     Ok(CliOutput {
@@ -61,25 +86,31 @@ pub fn config_show(
     mode: OutputMode,
     path: Option<&Path>,
     overrides_json: Option<&str>,
+    show_diff: bool,
 ) -> Result<CliOutput, CliError> {
     let env = collect_scoped_env("SCA_");
     let config_json = match load_effective_config_json(&env, path, overrides_json) {
         Ok(config) => config,
         Err(error) => return Ok(format_error_output(mode, &error, ExitCode::InvalidInput)),
     };
+    let diff = match load_diff(&env, path, overrides_json, show_diff) {
+        Ok(diff) => diff,
+        Err(error) => return Ok(format_error_output(mode, &error, ExitCode::InvalidInput)),
+    };
 
     let mut stderr = String::new();
-    log_info(&mut stderr, "config show completed", mode.no_progress);
+    log_info(&mut stderr, "config show completed", mode);
 
     let stdout = if mode.is_ndjson() {
-        format_ndjson_summary("ok", "config", None)
+        format_ndjson_summary("ok", "config", diff_json(diff.as_deref())?)
     } else if mode.is_json() {
         let config_value: serde_json::Value = serde_json::from_str(config_json.trim())?;
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "status": "ok",
             "configPath": path.map(|value| value.to_string_lossy().to_string()),
             "effectiveConfig": config_value,
         });
+        merge_diff_json(&mut payload, diff.as_deref())?;
         let mut output = serde_json::to_string_pretty(&payload)?;
         output.push('\n');
         output
@@ -87,6 +118,7 @@ pub fn config_show(
         let mut out = String::new();
         out.push_str("status: ok\nconfig:\n");
         out.push_str(&config_json);
+        write_diff_text(&mut out, diff.as_deref())?;
         out
     };
 
@@ -108,7 +140,7 @@ pub fn config_validate(
     }
 
     let mut stderr = String::new();
-    log_info(&mut stderr, "config validate completed", mode.no_progress);
+    log_info(&mut stderr, "config validate completed", mode);
 
     let stdout = if mode.is_ndjson() {
         format_ndjson_summary("ok", "config", None)
@@ -134,6 +166,54 @@ pub fn config_validate(
     })
 }
 
+/// Load the override diff only when `--show-diff` was requested.
+fn load_diff(
+    env: &BTreeMap<String, String>,
+    path: Option<&Path>,
+    overrides_json: Option<&str>,
+    show_diff: bool,
+) -> Result<Option<Vec<ConfigFieldChange>>, InfraError> {
+    if !show_diff {
+        return Ok(None);
+    }
+    load_effective_config_diff(env, path, overrides_json).map(Some)
+}
+
+fn diff_json(diff: Option<&[ConfigFieldChange]>) -> Result<Option<serde_json::Value>, CliError> {
+    diff.map(|changes| Ok(serde_json::json!({ "diff": serde_json::to_value(changes)? })))
+        .transpose()
+}
+
+fn merge_diff_json(
+    payload: &mut serde_json::Value,
+    diff: Option<&[ConfigFieldChange]>,
+) -> Result<(), CliError> {
+    if let (Some(map), Some(changes)) = (payload.as_object_mut(), diff) {
+        map.insert("diff".to_string(), serde_json::to_value(changes)?);
+    }
+    Ok(())
+}
+
+fn write_diff_text(out: &mut String, diff: Option<&[ConfigFieldChange]>) -> Result<(), CliError> {
+    let Some(changes) = diff else {
+        return Ok(());
+    };
+    let written = if changes.is_empty() {
+        writeln!(out, "diff: none")
+    } else {
+        writeln!(out, "diff:").and_then(|()| {
+            changes.iter().try_for_each(|change| {
+                writeln!(
+                    out,
+                    "  {}: {} -> {}",
+                    change.field, change.before, change.after
+                )
+            })
+        })
+    };
+    written.map_err(|error| CliError::Io(std::io::Error::other(error.to_string())))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,9 +248,12 @@ mod tests {
             agent: false,
             no_progress: true,
             interactive: false,
+            profile: false,
             log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
-        let output = config_check_with_env(mode, &env, Some(missing.as_path()), None)?;
+        let output = config_check_with_env(mode, &env, Some(missing.as_path()), None, false)?;
         assert_eq!(output.exit_code, ExitCode::InvalidInput);
         assert!(output.stdout.contains("status: error"));
         Ok(())
@@ -187,9 +270,13 @@ mod tests {
             agent: false,
             no_progress: true,
             interactive: false,
+            profile: false,
             log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
-        let output = config_check_with_env(mode, &env, Some(path.as_path()), Some(overrides))?;
+        let output =
+            config_check_with_env(mode, &env, Some(path.as_path()), Some(overrides), false)?;
         let value: serde_json::Value = serde_json::from_str(output.stdout.trim())?;
         let timeout_ms = value
             .get("effectiveConfig")
@@ -200,4 +287,110 @@ mod tests {
         assert_eq!(timeout_ms, 12345);
         Ok(())
     }
+
+    #[test]
+    fn overrides_file_matches_inline_overrides() -> Result<(), Box<dyn std::error::Error>> {
+        let env = BTreeMap::new();
+        let inline = r#"{"core":{"timeoutMs":12345},"embedding":{"batchSize":64}}"#;
+        let file =
+            std::env::temp_dir().join(format!("sca-overrides-file-{}.json", std::process::id()));
+        std::fs::write(&file, inline)?;
+        let from_file = crate::resolve::resolve_overrides(None, Some(&file));
+        std::fs::remove_file(&file)?;
+        let from_file = from_file?;
+
+        let inline_config = load_effective_config_json(&env, None, Some(inline))?;
+        let file_config = load_effective_config_json(&env, None, from_file.as_deref())?;
+        assert_eq!(file_config, inline_config);
+        assert_ne!(
+            inline_config,
+            load_effective_config_json(&env, None, None)?,
+            "overrides must change the effective config"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn config_check_prints_warnings_to_stderr() -> Result<(), Box<dyn std::error::Error>> {
+        let env = BTreeMap::new();
+        let overrides = r#"{"embedding":{"batchSize":1}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+        let output = config_check_with_env(mode, &env, None, Some(overrides), false)?;
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        assert!(output.stdout.contains("status: ok"));
+        assert!(output.stderr.contains("warning: embedding.batchSize:"));
+        Ok(())
+    }
+
+    #[test]
+    fn config_check_warns_when_env_shadows_a_config_value() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut env = BTreeMap::new();
+        env.insert("SCA_CORE_TIMEOUT_MS".to_string(), "12345".to_string());
+        let overrides = r#"{"core":{"timeoutMs":45000}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+        let output = config_check_with_env(mode, &env, None, Some(overrides), false)?;
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        assert!(output.stderr.contains(
+            "warning: core.timeoutMs: env override replaces config file value 45000 with 12345"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn show_diff_reports_only_the_overridden_field() -> Result<(), Box<dyn std::error::Error>> {
+        let env = BTreeMap::new();
+        let overrides = r#"{"core":{"timeoutMs":12345}}"#;
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: Some(OutputFormat::Json),
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+        let output = config_check_with_env(mode, &env, None, Some(overrides), true)?;
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        let value: serde_json::Value = serde_json::from_str(output.stdout.trim())?;
+        let diff = value
+            .get("diff")
+            .and_then(serde_json::Value::as_array)
+            .ok_or_else(|| std::io::Error::other("missing diff"))?;
+        assert_eq!(diff.len(), 1);
+        let change = diff
+            .first()
+            .ok_or_else(|| std::io::Error::other("missing diff entry"))?;
+        assert_eq!(
+            change.get("field").and_then(serde_json::Value::as_str),
+            Some("core.timeoutMs")
+        );
+        assert_eq!(
+            change.get("after").and_then(serde_json::Value::as_str),
+            Some("12345")
+        );
+        Ok(())
+    }
 }
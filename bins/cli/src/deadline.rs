@@ -0,0 +1,127 @@
+//! Whole-command deadline enforcement for `--timeout-ms`.
+//!
+//! Command handlers are synchronous and drive their own runtimes, so the
+//! selected command runs on a blocking worker while the deadline is awaited
+//! through [`timeout_with_context`]. On expiry the request context is
+//! cancelled and the worker is abandoned; the process exits right after the
+//! error is written.
+
+use crate::error::{CliError, ExitCode};
+use crate::format::OutputMode;
+use crate::output::{CliOutput, format_error_output};
+use semantic_code_facade::InfraError;
+use semantic_code_shared::{
+    CancelReason, ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, timeout_with_context,
+};
+use std::time::Duration;
+
+/// Run `work` under an optional deadline.
+///
+/// Without a deadline the work runs inline. A timeout is reported as a
+/// structured `timeout` error with [`ExitCode::Transient`].
+pub fn run_with_deadline<F>(
+    mode: OutputMode,
+    timeout: Option<Duration>,
+    work: F,
+) -> Result<CliOutput, CliError>
+where
+    F: FnOnce() -> Result<CliOutput, CliError> + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return work();
+    };
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let ctx = RequestContext::new_request();
+    let result = runtime.block_on(timeout_with_context(&ctx, timeout, "cli.command", async {
+        tokio::task::spawn_blocking(work).await.map_err(|error| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                format!("command worker failed: {error}"),
+                ErrorClass::NonRetriable,
+            )
+        })
+    }));
+
+    match result {
+        Ok(output) => output,
+        Err(error) => {
+            let timed_out = error.code == ErrorCode::timeout();
+            if timed_out {
+                ctx.cancel_with_reason(CancelReason::Timeout);
+            } else {
+                ctx.cancel();
+            }
+            // Do not wait for the abandoned worker; it may be stuck on I/O.
+            runtime.shutdown_background();
+            let exit_code = if timed_out {
+                tracing::info!(
+                    timeout_ms = timeout.as_millis(),
+                    "command deadline exceeded"
+                );
+                ExitCode::Transient
+            } else {
+                ExitCode::Internal
+            };
+            Ok(format_error_output(
+                mode,
+                &InfraError::from(error),
+                exit_code,
+            ))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::{LogLevel, OutputArgs};
+
+    fn json_mode() -> OutputMode {
+        OutputMode::from_args(&OutputArgs {
+            output: None,
+            json: true,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        })
+    }
+
+    #[test]
+    fn slow_command_times_out_with_transient_exit_code() -> Result<(), CliError> {
+        let output = run_with_deadline(json_mode(), Some(Duration::from_millis(20)), || {
+            std::thread::sleep(Duration::from_secs(2));
+            Ok(CliOutput {
+                stdout: "late\n".to_owned(),
+                stderr: String::new(),
+                exit_code: ExitCode::Ok,
+            })
+        })?;
+
+        assert_eq!(output.exit_code, ExitCode::Transient);
+        assert!(output.stdout.contains("\"status\": \"error\""));
+        assert!(output.stdout.contains("TIMEOUT"));
+        Ok(())
+    }
+
+    #[test]
+    fn fast_command_completes_within_deadline() -> Result<(), CliError> {
+        let output = run_with_deadline(json_mode(), Some(Duration::from_secs(5)), || {
+            Ok(CliOutput {
+                stdout: "done\n".to_owned(),
+                stderr: String::new(),
+                exit_code: ExitCode::Ok,
+            })
+        })?;
+
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        assert_eq!(output.stdout, "done\n");
+        Ok(())
+    }
+}
@@ -17,56 +17,124 @@ use semantic_code_facade::build_info;
 use semantic_code_facade::validate_request_json;
 #[cfg(any(debug_assertions, feature = "dev-tools"))]
 use semantic_code_facade::{
-    BuildInfo, facade_crate_version, run_clear_smoke, run_index_smoke, run_search_smoke,
+    BuildInfo, InfraError, facade_crate_version, run_clear_smoke, run_index_smoke, run_search_smoke,
 };
 #[cfg(any(debug_assertions, feature = "dev-tools"))]
 use std::collections::BTreeMap;
 
 #[cfg(any(debug_assertions, feature = "dev-tools"))]
-pub fn self_check(mode: OutputMode) -> Result<CliOutput, CliError> {
+pub fn self_check(mode: OutputMode, serial: bool) -> Result<CliOutput, CliError> {
     let env = collect_scoped_env("SCA_");
-    self_check_with_env(mode, &env)
+    self_check_with_env(mode, &env, serial)
+}
+
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+type Smoke = fn() -> Result<(), InfraError>;
+
+/// Self-check smokes in report order.
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+const SMOKES: [(&str, Smoke); 3] = [
+    ("index", run_index_smoke),
+    ("search", run_search_smoke),
+    ("clear", run_clear_smoke),
+];
+
+/// Outcome of a single smoke; `error` is `None` when it passed.
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+struct SmokeStatus {
+    name: &'static str,
+    error: Option<String>,
+}
+
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+impl SmokeStatus {
+    const fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    const fn label(&self) -> &'static str {
+        if self.is_ok() { "ok" } else { "error" }
+    }
 }
 
 #[cfg(any(debug_assertions, feature = "dev-tools"))]
 pub fn self_check_with_env(
     mode: OutputMode,
     env: &BTreeMap<String, String>,
+    serial: bool,
 ) -> Result<CliOutput, CliError> {
     let vector_kernel = match resolve_vector_kernel_metadata_from_env(env) {
         Ok(kernel) => kernel,
         Err(error) => return Ok(format_error_output(mode, &error, ExitCode::InvalidInput)),
     };
 
-    if let Err(error) = run_index_smoke() {
-        return Ok(format_error_output(mode, &error, ExitCode::Internal));
-    }
-    if let Err(error) = run_search_smoke() {
-        return Ok(format_error_output(mode, &error, ExitCode::Internal));
-    }
-    if let Err(error) = run_clear_smoke() {
-        return Ok(format_error_output(mode, &error, ExitCode::Internal));
-    }
+    let checks = run_smokes(&SMOKES, serial);
+    let all_ok = checks.iter().all(SmokeStatus::is_ok);
 
     let build = build_info();
     let facade_version = facade_crate_version();
     let mut stderr = String::new();
 
-    log_info(&mut stderr, "self-check completed", mode.no_progress);
+    if all_ok {
+        log_info(&mut stderr, "self-check completed", mode);
+    } else {
+        log_info(&mut stderr, "self-check failed", mode);
+    }
 
     let stdout = if mode.is_json() {
-        format_self_check_json(&build, facade_version, true, true, true, vector_kernel)?
+        format_self_check_json(&build, facade_version, &checks, vector_kernel)?
     } else {
-        format_self_check_text(&build, facade_version, true, true, true, vector_kernel)
+        format_self_check_text(&build, facade_version, &checks, vector_kernel)
     };
 
     Ok(CliOutput {
         stdout,
         stderr,
-        exit_code: ExitCode::Ok,
+        exit_code: if all_ok {
+            ExitCode::Ok
+        } else {
+            ExitCode::Internal
+        },
     })
 }
 
+/// Run every smoke on its own thread so a failure or panic in one cannot stop
+/// the others. With `serial`, each smoke is joined before the next starts.
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+fn run_smokes(smokes: &[(&'static str, Smoke)], serial: bool) -> Vec<SmokeStatus> {
+    if serial {
+        return smokes
+            .iter()
+            .map(|&(name, smoke)| join_smoke(name, std::thread::spawn(smoke)))
+            .collect();
+    }
+    #[expect(
+        clippy::needless_collect,
+        reason = "every smoke must be spawned before the first join"
+    )]
+    let handles: Vec<_> = smokes
+        .iter()
+        .map(|&(name, smoke)| (name, std::thread::spawn(smoke)))
+        .collect();
+    handles
+        .into_iter()
+        .map(|(name, handle)| join_smoke(name, handle))
+        .collect()
+}
+
+#[cfg(any(debug_assertions, feature = "dev-tools"))]
+fn join_smoke(
+    name: &'static str,
+    handle: std::thread::JoinHandle<Result<(), InfraError>>,
+) -> SmokeStatus {
+    let error = match handle.join() {
+        Ok(Ok(())) => None,
+        Ok(Err(error)) => Some(error.to_string()),
+        Err(_) => Some(format!("{name} smoke panicked")),
+    };
+    SmokeStatus { name, error }
+}
+
 pub fn validate_request(
     kind: ValidateRequestKind,
     input_json: &str,
@@ -77,11 +145,7 @@ pub fn validate_request(
     }
 
     let mut stderr = String::new();
-    log_info(
-        &mut stderr,
-        "request validation completed",
-        mode.no_progress,
-    );
+    log_info(&mut stderr, "request validation completed", mode);
 
     let stdout = if mode.is_ndjson() {
         format_ndjson_summary("ok", kind.as_str(), None)
@@ -108,16 +172,28 @@ pub fn validate_request(
 fn format_self_check_text(
     build: &BuildInfo,
     facade_version: &str,
-    index_ok: bool,
-    search_ok: bool,
-    clear_ok: bool,
+    checks: &[SmokeStatus],
     vector_kernel: crate::vector_kernel::VectorKernelMetadata,
 ) -> String {
+    let status = if checks.iter().all(SmokeStatus::is_ok) {
+        "ok"
+    } else {
+        "error"
+    };
+    let mut lines = String::new();
+    for check in checks {
+        lines.push_str(check.name);
+        lines.push_str(": ");
+        lines.push_str(check.label());
+        if let Some(error) = check.error.as_deref() {
+            lines.push_str(" (");
+            lines.push_str(error);
+            lines.push(')');
+        }
+        lines.push('\n');
+    }
     format!(
-        "status: ok\nenv: ok\nindex: {}\nsearch: {}\nclear: {}\nvectorKernel: {}\nname: {}\nversion: {}\nfacade: {}\nrustc: {}\ntarget: {}\nprofile: {}\ngit: {}{}\n",
-        if index_ok { "ok" } else { "error" },
-        if search_ok { "ok" } else { "error" },
-        if clear_ok { "ok" } else { "error" },
+        "status: {status}\nenv: ok\n{lines}vectorKernel: {}\nname: {}\nversion: {}\nfacade: {}\nrustc: {}\ntarget: {}\nprofile: {}\ngit: {}{}\n",
         vector_kernel.effective_label(),
         build.name,
         build.version,
@@ -134,17 +210,17 @@ fn format_self_check_text(
 fn format_self_check_json(
     build: &BuildInfo,
     facade_version: &str,
-    index_ok: bool,
-    search_ok: bool,
-    clear_ok: bool,
+    checks: &[SmokeStatus],
     vector_kernel: crate::vector_kernel::VectorKernelMetadata,
 ) -> Result<String, CliError> {
-    let payload = serde_json::json!({
-        "status": "ok",
+    let status = if checks.iter().all(SmokeStatus::is_ok) {
+        "ok"
+    } else {
+        "error"
+    };
+    let mut payload = serde_json::json!({
+        "status": status,
         "env": { "status": "ok" },
-        "index": { "status": if index_ok { "ok" } else { "error" } },
-        "search": { "status": if search_ok { "ok" } else { "error" } },
-        "clear": { "status": if clear_ok { "ok" } else { "error" } },
         "vectorKernel": vector_kernel.as_json(),
         "build": {
             "name": build.name,
@@ -157,6 +233,15 @@ fn format_self_check_json(
             "gitDirty": build.git_dirty,
         }
     });
+    if let Some(object) = payload.as_object_mut() {
+        for check in checks {
+            let mut entry = serde_json::json!({ "status": check.label() });
+            if let (Some(error), Some(fields)) = (check.error.as_deref(), entry.as_object_mut()) {
+                fields.insert("error".into(), error.into());
+            }
+            object.insert(check.name.into(), entry);
+        }
+    }
 
     let mut output = serde_json::to_string_pretty(&payload)?;
     output.push('\n');
@@ -177,9 +262,12 @@ mod tests {
             agent: false,
             no_progress: true,
             interactive: false,
+            profile: false,
             log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
-        let output = self_check_with_env(mode, &BTreeMap::new())?;
+        let output = self_check_with_env(mode, &BTreeMap::new(), false)?;
         let value: serde_json::Value = serde_json::from_str(output.stdout.trim())?;
 
         let status = value
@@ -225,6 +313,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(any(debug_assertions, feature = "dev-tools"))]
+    fn concurrent_self_check_reports_every_smoke_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mode = OutputMode::from_args(&OutputArgs {
+            output: Some(OutputFormat::Json),
+            json: false,
+            agent: false,
+            no_progress: true,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
+        });
+        let output = self_check_with_env(mode, &BTreeMap::new(), false)?;
+        assert_eq!(output.exit_code, ExitCode::Ok);
+        let value: serde_json::Value = serde_json::from_str(output.stdout.trim())?;
+        assert_eq!(value.get("status").and_then(|v| v.as_str()), Some("ok"));
+        for check in ["index", "search", "clear"] {
+            let status = value
+                .get(check)
+                .and_then(|entry| entry.get("status"))
+                .and_then(|status| status.as_str());
+            assert_eq!(status, Some("ok"), "{check} smoke did not pass");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "dev-tools"))]
+    fn panicking_smoke_does_not_abort_the_others() {
+        #[expect(clippy::panic, reason = "test smoke panics on purpose")]
+        fn panics() -> Result<(), InfraError> {
+            panic!("boom")
+        }
+        let smokes: [(&'static str, Smoke); 3] = [
+            ("index", run_index_smoke),
+            ("search", panics),
+            ("clear", run_clear_smoke),
+        ];
+        let checks = run_smokes(&smokes, false);
+        let labels: Vec<_> = checks
+            .iter()
+            .map(|check| (check.name, check.label()))
+            .collect();
+        assert_eq!(
+            labels,
+            [("index", "ok"), ("search", "error"), ("clear", "ok")]
+        );
+    }
+
     #[test]
     fn validate_request_errors_are_invalid_input() -> Result<(), Box<dyn std::error::Error>> {
         let mode = OutputMode::from_args(&OutputArgs {
@@ -233,7 +372,10 @@ mod tests {
             agent: false,
             no_progress: true,
             interactive: false,
+            profile: false,
             log_level: LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
         let output = validate_request(ValidateRequestKind::Search, "{bad", mode)?;
         assert_eq!(output.exit_code, ExitCode::InvalidInput);
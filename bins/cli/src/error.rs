@@ -6,6 +6,8 @@ pub enum ExitCode {
     InvalidInput = 2,
     Io = 3,
     Internal = 1,
+    /// Transient failure; retrying may succeed (`EX_TEMPFAIL`).
+    Transient = 75,
 }
 
 impl ExitCode {
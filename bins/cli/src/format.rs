@@ -1,6 +1,6 @@
 //! Output format helpers for CLI commands.
 
-use clap::{Args, ValueEnum};
+use clap::{ArgAction, Args, ValueEnum};
 
 /// Output format choices for CLI responses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -13,8 +13,8 @@ pub enum OutputFormat {
     Ndjson,
 }
 
-/// Log level choices for tracing output.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+/// Log level choices for tracing output, ordered from least to most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Default)]
 pub enum LogLevel {
     /// Error-level events only.
     Error,
@@ -74,6 +74,18 @@ impl LogLevel {
         }
         directive
     }
+
+    /// Map to the structured logger level used by local commands.
+    #[must_use]
+    pub const fn to_facade_level(self) -> semantic_code_facade::LogLevel {
+        match self {
+            Self::Error => semantic_code_facade::LogLevel::Error,
+            Self::Warn => semantic_code_facade::LogLevel::Warn,
+            Self::Info => semantic_code_facade::LogLevel::Info,
+            Self::Debug => semantic_code_facade::LogLevel::Debug,
+            Self::Trace => semantic_code_facade::LogLevel::Trace,
+        }
+    }
 }
 
 /// Output-related CLI flags.
@@ -95,12 +107,54 @@ pub struct OutputArgs {
     /// Enable interactive prompts (human-only).
     #[arg(long, global = true)]
     pub interactive: bool,
+    /// Print a per-stage timing breakdown to stderr after index, reindex, and search.
+    #[arg(long, global = true)]
+    pub profile: bool,
     /// Emit machine-readable JSON output (legacy alias).
     #[arg(long, global = true, hide = true)]
     pub json: bool,
     /// Log level for tracing output when `RUST_LOG` is not set.
     #[arg(long, global = true, value_enum, default_value_t = LogLevel::Info)]
     pub log_level: LogLevel,
+    /// Raise verbosity: `-v` debug, `-vv` trace, `-vvv` trace including dependencies.
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+    /// Log errors only; suppresses `info:` lines on stderr.
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+}
+
+impl OutputArgs {
+    /// Returns the log level after applying `--quiet` and `-v` over `--log-level`.
+    #[must_use]
+    pub const fn effective_log_level(&self) -> LogLevel {
+        if self.quiet {
+            return LogLevel::Error;
+        }
+        match self.verbose {
+            0 => self.log_level,
+            1 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+
+    /// Returns true when a verbosity flag was passed explicitly.
+    #[must_use]
+    pub const fn has_verbosity_flag(&self) -> bool {
+        self.quiet || self.verbose > 0
+    }
+
+    /// Returns the env-filter directive for tracing output.
+    ///
+    /// `-vvv` drops the workspace scoping so dependency crates trace too.
+    #[must_use]
+    pub fn tracing_directive(&self) -> String {
+        let level = self.effective_log_level();
+        if self.verbose >= 3 {
+            return level.as_filter_directive().to_owned();
+        }
+        level.as_scoped_directive()
+    }
 }
 
 /// Output mode derived from CLI flags.
@@ -108,6 +162,8 @@ pub struct OutputArgs {
 pub struct OutputMode {
     pub format: OutputFormat,
     pub no_progress: bool,
+    pub profile: bool,
+    pub log_level: LogLevel,
 }
 
 impl OutputMode {
@@ -132,6 +188,8 @@ impl OutputMode {
         Self {
             format,
             no_progress,
+            profile: args.profile,
+            log_level: args.effective_log_level(),
         }
     }
 
@@ -4,33 +4,42 @@ mod agent_doc;
 mod args;
 mod commands;
 mod config_cmd;
+mod deadline;
 mod dev;
 mod error;
 mod format;
 mod output;
+mod profile;
 mod redact_layer;
 mod resolve;
 mod tracing_init;
 mod vector_kernel;
 
 use args::{
-    Commands, ConfigCommands, EmbeddingCliOverridesArgs, JobsCommands, VectorDbCliOverridesArgs,
-    build_overrides_json, build_vector_overrides_json,
+    BenchCommands, Commands, ConfigCommands, DebugCommands, EmbeddingCliOverridesArgs,
+    JobsCommands, VectorDbCliOverridesArgs, build_overrides_json, build_vector_overrides_json,
+    with_sync_additional_ignore_patterns, with_sync_max_files,
 };
 use clap::Parser;
 use commands::{
-    CalibrateCommandInput, SearchCommandInput, run_calibrate, run_clear, run_estimate_storage,
-    run_index, run_info, run_init, run_jobs_cancel, run_jobs_run, run_jobs_status, run_reindex,
-    run_search, run_status,
+    BenchSearchInput, CalibrateCommandInput, SearchCommandInput, run_bench_search, run_calibrate,
+    run_clear, run_collection_name, run_dimension, run_doctor, run_estimate_storage, run_export,
+    run_get_vector, run_import, run_index, run_info, run_init, run_jobs_cancel, run_jobs_run,
+    run_jobs_status, run_ls, run_migrate, run_prune, run_reindex, run_search, run_status,
+    run_verify,
 };
 use config_cmd::{config_check, config_show, config_validate};
 use dev::validate_request;
 use error::CliError;
 use format::{OutputArgs, OutputMode};
 use output::{CliOutput, write_output};
-use resolve::{parse_storage_mode, resolve_codebase_root, resolve_query};
+use resolve::{
+    parse_config_template, parse_request_attributes, parse_storage_mode, resolve_codebase_root,
+    resolve_overrides, resolve_query,
+};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 // Re-export for command handlers that import from `crate::`.
 pub(crate) use output::{format_error_output, infra_exit_code};
@@ -47,6 +56,30 @@ struct Cli {
     #[command(flatten)]
     output: OutputArgs,
 
+    /// Abort the command after this many milliseconds (`0` disables the deadline).
+    ///
+    /// Defaults to `core.timeoutMs` for search, clear, status, verify, ls, and prune.
+    #[arg(long, global = true, value_name = "MS")]
+    timeout_ms: Option<u64>,
+
+    /// Attach `key=value` metadata to every log and telemetry event (repeatable).
+    ///
+    /// Values under secret-looking keys (token, key, password, ...) are redacted.
+    #[arg(long = "attr", global = true, value_name = "KEY=VALUE")]
+    attrs: Vec<String>,
+
+    /// Refuse any command that would modify the index, snapshots, config, or manifest.
+    ///
+    /// Search, status, and `config show` work normally.
+    #[arg(long, global = true)]
+    read_only: bool,
+
+    /// Write stdout content to this file (replaced atomically) instead of the terminal.
+    ///
+    /// Logs and progress stay on stderr.
+    #[arg(long, global = true, value_name = "PATH")]
+    output_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -60,13 +93,30 @@ pub fn main_entry() -> std::process::ExitCode {
     tracing::debug!(
         format = ?mode.format,
         no_progress = mode.no_progress,
+        log_level = ?mode.log_level,
         "resolved CLI output mode"
     );
 
-    match run(&cli.command, mode) {
+    match parse_request_attributes(&cli.attrs) {
+        Ok(attributes) if !attributes.is_empty() => {
+            semantic_code_facade::install_request_attributes(attributes);
+        },
+        Ok(_) => {},
+        Err(error) => return exit_with_error(&error),
+    }
+    if cli.read_only {
+        semantic_code_facade::install_read_only_mode();
+    }
+    if cli.output.has_verbosity_flag() {
+        semantic_code_facade::install_log_level(mode.log_level.to_facade_level());
+    }
+
+    let timeout = resolve_command_timeout(cli.timeout_ms, &cli.command);
+    let command = cli.command;
+    match deadline::run_with_deadline(mode, timeout, move || run(&command, mode)) {
         Ok(output) => {
             tracing::debug!(exit_code = output.exit_code.as_u8(), "command completed");
-            match write_output(&output) {
+            match write_output(&output, cli.output_file.as_deref()) {
                 Ok(()) => std::process::ExitCode::from(output.exit_code.as_u8()),
                 Err(error) => exit_with_error(&error),
             }
@@ -78,6 +128,60 @@ pub fn main_entry() -> std::process::ExitCode {
     }
 }
 
+/// Resolve the whole-command deadline.
+///
+/// An explicit `--timeout-ms` applies to every command. Otherwise commands that
+/// make a single bounded backend call fall back to the configured
+/// `core.timeoutMs`; indexing and other long-running commands stay unbounded.
+fn resolve_command_timeout(timeout_ms: Option<u64>, command: &Commands) -> Option<Duration> {
+    let timeout_ms = match timeout_ms {
+        Some(timeout_ms) => timeout_ms,
+        None => configured_timeout_ms(command)?,
+    };
+    (timeout_ms > 0).then(|| Duration::from_millis(timeout_ms))
+}
+
+fn configured_timeout_ms(command: &Commands) -> Option<u64> {
+    let (config, codebase_root, overrides_json) = match command {
+        Commands::Search {
+            config,
+            codebase_root,
+            overrides_json,
+            stdin_batch: false,
+            ..
+        } => (config, codebase_root, overrides_json.as_deref()),
+        Commands::Clear {
+            config,
+            codebase_root,
+            ..
+        }
+        | Commands::Status {
+            config,
+            codebase_root,
+            ..
+        }
+        | Commands::Verify {
+            config,
+            codebase_root,
+            ..
+        }
+        | Commands::Ls {
+            config,
+            codebase_root,
+        }
+        | Commands::Prune {
+            config,
+            codebase_root,
+        } => (config, codebase_root, None),
+        _ => return None,
+    };
+    let root = resolve_codebase_root(codebase_root.as_ref()).ok()?;
+    // Config errors surface from the command itself; run without a deadline here.
+    semantic_code_facade::resolve_core_timeout_ms(config.as_deref(), overrides_json, &root)
+        .inspect_err(|error| tracing::debug!(?error, "could not resolve core.timeoutMs"))
+        .ok()
+}
+
 fn exit_with_error(error: &CliError) -> std::process::ExitCode {
     let _ = writeln!(io::stderr(), "error: {error}");
     std::process::ExitCode::from(error.exit_code().as_u8())
@@ -86,23 +190,96 @@ fn exit_with_error(error: &CliError) -> std::process::ExitCode {
 const fn command_name(command: &Commands) -> &'static str {
     match command {
         #[cfg(any(debug_assertions, feature = "dev-tools"))]
-        Commands::SelfCheck => "self-check",
+        Commands::SelfCheck { .. } => "self-check",
         Commands::Info => "info",
         Commands::AgentDoc { .. } => "agent-doc",
         Commands::Config { .. } => "config",
         Commands::Jobs { .. } => "jobs",
         Commands::Init { .. } => "init",
+        Commands::Doctor { .. } => "doctor",
         Commands::EstimateStorage { .. } => "estimate-storage",
         Commands::Index { .. } => "index",
         Commands::Search { .. } => "search",
         Commands::Clear { .. } => "clear",
         Commands::Status { .. } => "status",
+        Commands::Verify { .. } => "verify",
+        Commands::Ls { .. } => "ls",
+        Commands::Prune { .. } => "prune",
+        Commands::Export { .. } => "export",
+        Commands::Import { .. } => "import",
+        Commands::Migrate { .. } => "migrate",
         Commands::Reindex { .. } => "reindex",
         Commands::Calibrate { .. } => "calibrate",
+        Commands::Embed { .. } => "embed",
+        Commands::Bench { .. } => "bench",
+        Commands::Debug { .. } => "debug",
         Commands::ValidateRequest { .. } => "validate-request",
     }
 }
 
+fn run_config(command: &ConfigCommands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    match command {
+        ConfigCommands::Check {
+            path,
+            overrides_json,
+            overrides_file,
+            show_diff,
+        } => {
+            let overrides =
+                resolve_overrides(overrides_json.as_deref(), overrides_file.as_deref())?;
+            config_check(mode, path.as_deref(), overrides.as_deref(), *show_diff)
+        },
+        ConfigCommands::Show {
+            path,
+            overrides_json,
+            overrides_file,
+            show_diff,
+        } => {
+            let overrides =
+                resolve_overrides(overrides_json.as_deref(), overrides_file.as_deref())?;
+            config_show(mode, path.as_deref(), overrides.as_deref(), *show_diff)
+        },
+        ConfigCommands::Validate {
+            path,
+            overrides_json,
+            overrides_file,
+        } => {
+            let overrides =
+                resolve_overrides(overrides_json.as_deref(), overrides_file.as_deref())?;
+            config_validate(mode, path.as_deref(), overrides.as_deref())
+        },
+    }
+}
+
+fn run_jobs(command: &JobsCommands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    match command {
+        JobsCommands::Status {
+            job_id,
+            codebase_root,
+        } => run_jobs_status(
+            mode,
+            &resolve_codebase_root(codebase_root.as_ref())?,
+            job_id,
+        ),
+        JobsCommands::Cancel {
+            job_id,
+            codebase_root,
+        } => run_jobs_cancel(
+            mode,
+            &resolve_codebase_root(codebase_root.as_ref())?,
+            job_id,
+        ),
+        JobsCommands::Run {
+            job_id,
+            codebase_root,
+        } => run_jobs_run(
+            mode,
+            &resolve_codebase_root(codebase_root.as_ref())?,
+            job_id,
+        ),
+    }
+}
+
 #[tracing::instrument(
     name = "cli.dispatch",
     skip_all,
@@ -118,67 +295,83 @@ fn run(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
 
     match command {
         #[cfg(any(debug_assertions, feature = "dev-tools"))]
-        Commands::SelfCheck => dev::self_check(mode),
+        Commands::SelfCheck { serial } => dev::self_check(mode, *serial),
         Commands::Info => run_info(mode),
         Commands::AgentDoc { command } => agent_doc::run_agent_doc(command.as_deref()),
-        Commands::Config { command } => match command {
-            ConfigCommands::Check {
-                path,
-                overrides_json,
-            } => config_check(mode, path.as_deref(), overrides_json.as_deref()),
-            ConfigCommands::Show {
-                path,
-                overrides_json,
-            } => config_show(mode, path.as_deref(), overrides_json.as_deref()),
-            ConfigCommands::Validate {
-                path,
-                overrides_json,
-            } => config_validate(mode, path.as_deref(), overrides_json.as_deref()),
-        },
+        Commands::Config { command } => run_config(command, mode),
         Commands::Init {
             config,
             codebase_root,
             storage_mode,
+            template,
             force,
         } => run_init_command(
             mode,
             config.as_deref(),
             codebase_root.as_ref(),
             storage_mode.as_deref(),
+            template.as_deref(),
             *force,
         ),
+        Commands::Doctor {
+            config,
+            codebase_root,
+        } => run_doctor(
+            mode,
+            config.as_deref(),
+            &resolve_codebase_root(codebase_root.as_ref())?,
+        ),
         Commands::EstimateStorage { .. } => run_estimate_storage_from_command(command, mode),
-        Commands::Jobs { command } => match command {
-            JobsCommands::Status {
-                job_id,
-                codebase_root,
-            } => run_jobs_status(
-                mode,
-                &resolve_codebase_root(codebase_root.as_ref())?,
-                job_id,
-            ),
-            JobsCommands::Cancel {
-                job_id,
-                codebase_root,
-            } => run_jobs_cancel(
-                mode,
-                &resolve_codebase_root(codebase_root.as_ref())?,
-                job_id,
-            ),
-            JobsCommands::Run {
-                job_id,
-                codebase_root,
-            } => run_jobs_run(
-                mode,
-                &resolve_codebase_root(codebase_root.as_ref())?,
-                job_id,
-            ),
-        },
+        Commands::Jobs { command } => run_jobs(command, mode),
+        Commands::Ls {
+            config,
+            codebase_root,
+        } => run_ls(
+            mode,
+            config.as_deref(),
+            &resolve_codebase_root(codebase_root.as_ref())?,
+        ),
+        Commands::Prune {
+            config,
+            codebase_root,
+        } => run_prune(
+            mode,
+            config.as_deref(),
+            None,
+            &resolve_codebase_root(codebase_root.as_ref())?,
+        ),
+        Commands::Export {
+            config,
+            codebase_root,
+            out,
+            with_vectors,
+        } => run_export(
+            mode,
+            config.as_deref(),
+            &resolve_codebase_root(codebase_root.as_ref())?,
+            out,
+            *with_vectors,
+        ),
+        Commands::Import {
+            config,
+            codebase_root,
+            input,
+        } => run_import(
+            mode,
+            config.as_deref(),
+            &resolve_codebase_root(codebase_root.as_ref())?,
+            input,
+        ),
+        Commands::Migrate { .. } => run_migrate_from_command(command, mode),
         Commands::Calibrate { .. } => run_calibrate_from_command(command, mode),
+        Commands::Embed { .. } => run_embed_from_command(command, mode),
+        Commands::Bench { command } => run_bench_command(command, mode),
+        Commands::Debug { command } => run_debug_command(command, mode),
         Commands::Index { .. }
         | Commands::Search { .. }
         | Commands::Clear { .. }
         | Commands::Status { .. }
+        | Commands::Verify { .. }
         | Commands::Reindex { .. } => run_vector_db_command(command, mode),
         Commands::ValidateRequest { kind, input_json } => {
             validate_request(*kind, input_json.as_str(), mode)
@@ -193,6 +386,7 @@ fn run_vector_db_command(command: &Commands, mode: OutputMode) -> Result<CliOutp
         Commands::Search { .. } => run_search_from_command(command, mode),
         Commands::Clear { .. } => run_clear_from_command(command, mode),
         Commands::Status { .. } => run_status_from_command(command, mode),
+        Commands::Verify { .. } => run_verify_from_command(command, mode),
         Commands::Reindex { .. } => run_reindex_from_command(command, mode),
         _ => Err(CliError::InvalidInput("unsupported CLI command".to_owned())),
     }
@@ -202,11 +396,12 @@ fn run_clear_command(
     mode: OutputMode,
     config: Option<&Path>,
     codebase_root: Option<&PathBuf>,
+    collection: Option<&str>,
     overrides: VectorDbCliOverridesArgs<'_>,
 ) -> Result<CliOutput, CliError> {
     let root = resolve_codebase_root(codebase_root)?;
     let overrides = build_vector_overrides_json(overrides)?;
-    run_clear(mode, config, overrides.as_deref(), &root)
+    run_clear(mode, config, overrides.as_deref(), &root, collection)
 }
 
 fn run_status_command(
@@ -220,6 +415,17 @@ fn run_status_command(
     run_status(mode, config, overrides.as_deref(), &root)
 }
 
+fn run_verify_command(
+    mode: OutputMode,
+    config: Option<&Path>,
+    codebase_root: Option<&PathBuf>,
+    overrides: VectorDbCliOverridesArgs<'_>,
+) -> Result<CliOutput, CliError> {
+    let root = resolve_codebase_root(codebase_root)?;
+    let overrides = build_vector_overrides_json(overrides)?;
+    run_verify(mode, config, overrides.as_deref(), &root)
+}
+
 fn run_index_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
     let Commands::Index {
         config,
@@ -243,6 +449,8 @@ fn run_index_from_command(command: &Commands, mode: OutputMode) -> Result<CliOut
         vector_db_token,
         vector_db_username,
         vector_db_password,
+        max_file_count,
+        ignore,
         danger_close_storage,
         overrides_json,
     } = command
@@ -255,7 +463,7 @@ fn run_index_from_command(command: &Commands, mode: OutputMode) -> Result<CliOut
     let overrides = if let Some(raw) = overrides_json {
         Some(raw.clone())
     } else {
-        build_overrides_json(
+        let overrides = build_overrides_json(
             VectorDbCliOverridesArgs {
                 provider: vector_db_provider.as_deref(),
                 vector_kernel: vector_kernel.as_deref(),
@@ -277,7 +485,9 @@ fn run_index_from_command(command: &Commands, mode: OutputMode) -> Result<CliOut
                 routing_mode: embedding_routing_mode.as_deref(),
                 split_max_remote_batches: *embedding_split_remote_batches,
             },
-        )?
+        )?;
+        let overrides = with_sync_max_files(overrides, *max_file_count)?;
+        with_sync_additional_ignore_patterns(overrides, ignore)?
     };
 
     run_index(
@@ -364,6 +574,12 @@ fn run_search_from_command(command: &Commands, mode: OutputMode) -> Result<CliOu
         threshold,
         filter_expr,
         include_content,
+        context_lines,
+        explain,
+        group_by_file,
+        fail_on_empty,
+        exclude_paths,
+        exclude_languages,
         config,
         codebase_root,
         vector_db_provider,
@@ -381,6 +597,16 @@ fn run_search_from_command(command: &Commands, mode: OutputMode) -> Result<CliOu
         return Err(CliError::InvalidInput("unsupported CLI command".to_owned()));
     };
 
+    if (*stdin || *stdin_batch)
+        && config
+            .as_deref()
+            .is_some_and(semantic_code_facade::is_stdin_config_path)
+    {
+        return Err(CliError::InvalidInput(
+            "--config - cannot be combined with --stdin or --stdin-batch".to_owned(),
+        ));
+    }
+
     let root = resolve_codebase_root(codebase_root.as_ref())?;
     // --overrides-json takes precedence over individual CLI flags.
     let overrides = if let Some(raw) = overrides_json {
@@ -422,14 +648,118 @@ fn run_search_from_command(command: &Commands, mode: OutputMode) -> Result<CliOu
         threshold: *threshold,
         filter_expr: filter_expr.as_deref(),
         include_content: *include_content,
+        context_lines: *context_lines,
+        explain: *explain,
+        group_by_file: *group_by_file,
+        exclude_paths,
+        exclude_languages,
     };
-    run_search(mode, &input)
+    run_search(mode, &input, *fail_on_empty)
+}
+
+fn run_bench_command(command: &BenchCommands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    let BenchCommands::Search {
+        queries_file,
+        iterations,
+        top_k,
+        threshold,
+        config,
+        codebase_root,
+        overrides_json,
+    } = command;
+    let root = resolve_codebase_root(codebase_root.as_ref())?;
+    run_bench_search(
+        mode,
+        &BenchSearchInput {
+            config_path: config.as_deref(),
+            overrides_json: overrides_json.as_deref(),
+            codebase_root: &root,
+            queries_file,
+            iterations: *iterations,
+            top_k: *top_k,
+            threshold: *threshold,
+        },
+    )
+}
+
+fn run_debug_command(command: &DebugCommands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    match command {
+        DebugCommands::GetVector {
+            id,
+            config,
+            codebase_root,
+            overrides_json,
+        } => {
+            let root = resolve_codebase_root(codebase_root.as_ref())?;
+            run_get_vector(
+                mode,
+                config.as_deref(),
+                overrides_json.as_deref(),
+                &root,
+                id,
+            )
+        },
+        DebugCommands::CollectionName {
+            config,
+            codebase_root,
+            overrides_json,
+        } => {
+            let root = resolve_codebase_root(codebase_root.as_ref())?;
+            run_collection_name(mode, config.as_deref(), overrides_json.as_deref(), &root)
+        },
+        DebugCommands::Dimension {
+            config,
+            codebase_root,
+            overrides_json,
+        } => {
+            let root = resolve_codebase_root(codebase_root.as_ref())?;
+            run_dimension(mode, config.as_deref(), overrides_json.as_deref(), &root)
+        },
+    }
+}
+
+fn run_embed_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    let Commands::Embed {
+        text,
+        stdin,
+        config,
+        codebase_root,
+        overrides_json,
+    } = command
+    else {
+        return Err(CliError::InvalidInput("unsupported CLI command".to_owned()));
+    };
+
+    if *stdin
+        && config
+            .as_deref()
+            .is_some_and(semantic_code_facade::is_stdin_config_path)
+    {
+        return Err(CliError::InvalidInput(
+            "--config - cannot be combined with --stdin".to_owned(),
+        ));
+    }
+    let text = if *stdin {
+        resolve_query(true, None)?
+    } else {
+        text.clone()
+            .ok_or_else(|| CliError::InvalidInput("missing --text or --stdin".to_owned()))?
+    };
+    let root = resolve_codebase_root(codebase_root.as_ref())?;
+    commands::run_embed(
+        mode,
+        config.as_deref(),
+        overrides_json.as_deref(),
+        &root,
+        &text,
+    )
 }
 
 fn run_clear_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
     let Commands::Clear {
         config,
         codebase_root,
+        collection,
         vector_db_provider,
         vector_db_address,
         vector_db_base_url,
@@ -447,6 +777,7 @@ fn run_clear_from_command(command: &Commands, mode: OutputMode) -> Result<CliOut
         mode,
         config.as_deref(),
         codebase_root.as_ref(),
+        collection.as_deref(),
         VectorDbCliOverridesArgs {
             provider: vector_db_provider.as_deref(),
             vector_kernel: None,
@@ -496,6 +827,74 @@ fn run_status_from_command(command: &Commands, mode: OutputMode) -> Result<CliOu
     )
 }
 
+fn run_migrate_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    let Commands::Migrate {
+        config,
+        codebase_root,
+        to,
+        vector_db_address,
+        vector_db_base_url,
+        vector_db_database,
+        vector_db_ssl,
+        vector_db_token,
+        vector_db_username,
+        vector_db_password,
+    } = command
+    else {
+        return Err(CliError::InvalidInput("unsupported CLI command".to_owned()));
+    };
+
+    let root = resolve_codebase_root(codebase_root.as_ref())?;
+    let destination = build_vector_overrides_json(VectorDbCliOverridesArgs {
+        provider: Some(to.as_str()),
+        vector_kernel: None,
+        address: vector_db_address.as_deref(),
+        base_url: vector_db_base_url.as_deref(),
+        database: vector_db_database.as_deref(),
+        ssl: *vector_db_ssl,
+        token: vector_db_token.as_deref(),
+        username: vector_db_username.as_deref(),
+        password: vector_db_password.as_deref(),
+    })?
+    .ok_or_else(|| CliError::InvalidInput("--to must name a vector DB provider".to_owned()))?;
+    run_migrate(mode, config.as_deref(), &destination, &root)
+}
+
+fn run_verify_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
+    let Commands::Verify {
+        config,
+        codebase_root,
+        vector_db_provider,
+        vector_db_address,
+        vector_db_base_url,
+        vector_db_database,
+        vector_db_ssl,
+        vector_db_token,
+        vector_db_username,
+        vector_db_password,
+    } = command
+    else {
+        return Err(CliError::InvalidInput("unsupported CLI command".to_owned()));
+    };
+
+    run_verify_command(
+        mode,
+        config.as_deref(),
+        codebase_root.as_ref(),
+        VectorDbCliOverridesArgs {
+            provider: vector_db_provider.as_deref(),
+            vector_kernel: None,
+            address: vector_db_address.as_deref(),
+            base_url: vector_db_base_url.as_deref(),
+            database: vector_db_database.as_deref(),
+            ssl: *vector_db_ssl,
+            token: vector_db_token.as_deref(),
+            username: vector_db_username.as_deref(),
+            password: vector_db_password.as_deref(),
+        },
+    )
+}
+
 fn run_reindex_from_command(command: &Commands, mode: OutputMode) -> Result<CliOutput, CliError> {
     let Commands::Reindex {
         config,
@@ -518,6 +917,7 @@ fn run_reindex_from_command(command: &Commands, mode: OutputMode) -> Result<CliO
         vector_db_token,
         vector_db_username,
         vector_db_password,
+        ignore,
     } = command
     else {
         return Err(CliError::InvalidInput("unsupported CLI command".to_owned()));
@@ -547,6 +947,7 @@ fn run_reindex_from_command(command: &Commands, mode: OutputMode) -> Result<CliO
             split_max_remote_batches: *embedding_split_remote_batches,
         },
     )?;
+    let overrides = with_sync_additional_ignore_patterns(overrides, ignore)?;
     run_reindex(
         mode,
         config.as_deref(),
@@ -608,11 +1009,13 @@ fn run_init_command(
     config: Option<&Path>,
     codebase_root: Option<&PathBuf>,
     storage_mode: Option<&str>,
+    template: Option<&str>,
     force: bool,
 ) -> Result<CliOutput, CliError> {
     let root = resolve_codebase_root(codebase_root)?;
     let storage_mode = parse_storage_mode(storage_mode)?;
-    run_init(mode, config, &root, storage_mode, force)
+    let template = parse_config_template(template)?;
+    run_init(mode, config, &root, storage_mode, template, force)
 }
 
 #[cfg(test)]
@@ -645,6 +1048,132 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cli_parses_repeatable_attr_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from([
+            "cli",
+            "status",
+            "--attr",
+            "team=search",
+            "--attr",
+            "ticket=ENG-42",
+        ])?;
+        assert_eq!(cli.attrs, vec!["team=search", "ticket=ENG-42"]);
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_global_read_only_flag() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "clear", "--read-only"])?;
+        assert!(cli.read_only);
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_doctor_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "doctor", "--codebase-root", "/tmp/repo"])?;
+        assert_eq!(command_name(&cli.command), "doctor");
+        match cli.command {
+            Commands::Doctor { codebase_root, .. } => {
+                assert_eq!(codebase_root, Some(PathBuf::from("/tmp/repo")));
+            },
+            _ => return Err("expected doctor command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_ls_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "ls", "--codebase-root", "/tmp/repo"])?;
+        assert_eq!(command_name(&cli.command), "ls");
+        match cli.command {
+            Commands::Ls { codebase_root, .. } => {
+                assert_eq!(codebase_root, Some(PathBuf::from("/tmp/repo")));
+            },
+            _ => return Err("expected ls command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_export_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "export", "--out", "index.jsonl", "--with-vectors"])?;
+        assert_eq!(command_name(&cli.command), "export");
+        match cli.command {
+            Commands::Export {
+                out, with_vectors, ..
+            } => {
+                assert_eq!(out, PathBuf::from("index.jsonl"));
+                assert!(with_vectors);
+            },
+            _ => return Err("expected export command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_import_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "import", "--in", "index.jsonl"])?;
+        assert_eq!(command_name(&cli.command), "import");
+        match cli.command {
+            Commands::Import { input, .. } => {
+                assert_eq!(input, PathBuf::from("index.jsonl"));
+            },
+            _ => return Err("expected import command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_migrate_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from([
+            "cli",
+            "migrate",
+            "--to",
+            "milvus_grpc",
+            "--vector-db-address",
+            "localhost:19530",
+        ])?;
+        assert_eq!(command_name(&cli.command), "migrate");
+        match cli.command {
+            Commands::Migrate {
+                to,
+                vector_db_address,
+                ..
+            } => {
+                assert_eq!(to, "milvus_grpc");
+                assert_eq!(vector_db_address.as_deref(), Some("localhost:19530"));
+            },
+            _ => return Err("expected migrate command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_verify_flags() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from([
+            "cli",
+            "verify",
+            "--codebase-root",
+            "/tmp/dspy/dspy",
+            "--vector-db-provider",
+            "local",
+        ])?;
+        assert_eq!(command_name(&cli.command), "verify");
+        match cli.command {
+            Commands::Verify {
+                codebase_root,
+                vector_db_provider,
+                ..
+            } => {
+                assert_eq!(codebase_root, Some(PathBuf::from("/tmp/dspy/dspy")));
+                assert_eq!(vector_db_provider.as_deref(), Some("local"));
+            },
+            _ => return Err("expected verify command".into()),
+        }
+        Ok(())
+    }
+
     #[test]
     fn cli_parses_index_flags() -> Result<(), Box<dyn std::error::Error>> {
         let cli = Cli::try_parse_from([
@@ -655,6 +1184,10 @@ mod tests {
             "/tmp/dspy/dspy/config.json",
             "--codebase-root",
             "/tmp/dspy/dspy",
+            "--ignore",
+            "vendor/",
+            "--ignore",
+            "!vendor/keep.rs",
         ])?;
         assert!(!cli.output.json);
         match cli.command {
@@ -662,11 +1195,13 @@ mod tests {
                 config,
                 codebase_root,
                 init,
+                ignore,
                 ..
             } => {
                 assert!(init);
                 assert_eq!(config, Some(PathBuf::from("/tmp/dspy/dspy/config.json")));
                 assert_eq!(codebase_root, Some(PathBuf::from("/tmp/dspy/dspy")));
+                assert_eq!(ignore, ["vendor/", "!vendor/keep.rs"]);
             },
             _ => return Err("expected index command".into()),
         }
@@ -688,6 +1223,16 @@ mod tests {
             "--filter-expr",
             "relativePath == 'README.md'",
             "--include-content",
+            "--context-lines",
+            "3",
+            "--group-by-file",
+            "--fail-on-empty",
+            "--exclude-path",
+            "*.test.rs",
+            "--exclude-path",
+            "vendor/*",
+            "--exclude-language",
+            "markdown",
             "--config",
             "/tmp/dspy/dspy/config.json",
             "--codebase-root",
@@ -701,6 +1246,11 @@ mod tests {
                 threshold,
                 filter_expr,
                 include_content,
+                context_lines,
+                group_by_file,
+                fail_on_empty,
+                exclude_paths,
+                exclude_languages,
                 config,
                 codebase_root,
                 ..
@@ -715,6 +1265,11 @@ mod tests {
                 }
                 assert_eq!(filter_expr.as_deref(), Some("relativePath == 'README.md'"));
                 assert!(include_content);
+                assert_eq!(context_lines, Some(3));
+                assert!(group_by_file);
+                assert!(fail_on_empty);
+                assert_eq!(exclude_paths, vec!["*.test.rs", "vendor/*"]);
+                assert_eq!(exclude_languages, vec!["markdown"]);
                 assert_eq!(config, Some(PathBuf::from("/tmp/dspy/dspy/config.json")));
                 assert_eq!(codebase_root, Some(PathBuf::from("/tmp/dspy/dspy")));
             },
@@ -723,6 +1278,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn cli_parses_hidden_embed_command() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "embed", "--text", "fn main() {}"])?;
+        match cli.command {
+            Commands::Embed { text, stdin, .. } => {
+                assert_eq!(text.as_deref(), Some("fn main() {}"));
+                assert!(!stdin);
+            },
+            _ => return Err("expected embed command".into()),
+        }
+        assert!(Cli::try_parse_from(["cli", "embed", "--text", "a", "--stdin"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_clear_collection_flag() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "clear", "--collection", "code_chunks_orphan"])?;
+        match cli.command {
+            Commands::Clear { collection, .. } => {
+                assert_eq!(collection.as_deref(), Some("code_chunks_orphan"));
+            },
+            _ => return Err("expected clear command".into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cli_parses_global_timeout_flag() -> Result<(), Box<dyn std::error::Error>> {
+        let cli = Cli::try_parse_from(["cli", "status", "--timeout-ms", "1500"])?;
+        assert_eq!(cli.timeout_ms, Some(1500));
+        assert_eq!(
+            resolve_command_timeout(cli.timeout_ms, &cli.command),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(resolve_command_timeout(Some(0), &cli.command), None);
+
+        let cli = Cli::try_parse_from(["cli", "info"])?;
+        assert_eq!(resolve_command_timeout(cli.timeout_ms, &cli.command), None);
+        Ok(())
+    }
+
     #[test]
     fn cli_parses_estimate_storage_flags() -> Result<(), Box<dyn std::error::Error>> {
         let cli = Cli::try_parse_from([
@@ -784,12 +1380,38 @@ mod tests {
             agent: true,
             no_progress: false,
             interactive: true,
+            profile: false,
             log_level: format::LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
         assert!(mode.is_ndjson());
         assert!(mode.no_progress);
     }
 
+    #[test]
+    fn verbosity_flags_map_to_log_levels() -> Result<(), Box<dyn std::error::Error>> {
+        let default = Cli::try_parse_from(["cli", "config", "check"])?;
+        assert_eq!(default.output.effective_log_level(), format::LogLevel::Info);
+        assert!(!default.output.has_verbosity_flag());
+
+        let debug = Cli::try_parse_from(["cli", "-v", "config", "check"])?;
+        assert_eq!(debug.output.effective_log_level(), format::LogLevel::Debug);
+
+        let trace = Cli::try_parse_from(["cli", "config", "check", "-vv"])?;
+        assert_eq!(trace.output.effective_log_level(), format::LogLevel::Trace);
+        assert!(trace.output.tracing_directive().starts_with("warn,"));
+
+        let everything = Cli::try_parse_from(["cli", "-vvv", "config", "check"])?;
+        assert_eq!(everything.output.tracing_directive(), "trace");
+
+        let quiet = Cli::try_parse_from(["cli", "--quiet", "config", "check"])?;
+        assert_eq!(quiet.output.effective_log_level(), format::LogLevel::Error);
+
+        assert!(Cli::try_parse_from(["cli", "-q", "-v", "config", "check"]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn cli_parses_agent_doc_bare() -> Result<(), Box<dyn std::error::Error>> {
         let cli = Cli::try_parse_from(["cli", "agent-doc"])?;
@@ -822,7 +1444,10 @@ mod tests {
             agent: false,
             no_progress: true,
             interactive: false,
+            profile: false,
             log_level: format::LogLevel::Info,
+            verbose: 0,
+            quiet: false,
         });
         let invalid_root = Path::new("   ");
         let output = run_reindex(mode, None, None, invalid_root, false)?;
@@ -5,10 +5,12 @@
 //! three output formats (text, JSON, NDJSON).
 
 use crate::error::{CliError, ExitCode};
-use crate::format::OutputMode;
+use crate::format::{LogLevel, OutputMode};
 use semantic_code_facade::{ApiV1ErrorDto, ApiV1ErrorKind, InfraError, infra_error_to_api_v1};
 use semantic_code_shared::is_secret_key;
+use std::fs;
 use std::io::{self, Write};
+use std::path::Path;
 
 pub struct CliOutput {
     pub stdout: String,
@@ -20,7 +22,7 @@ pub fn format_error_output(mode: OutputMode, error: &InfraError, exit_code: Exit
     let api_error = sanitize_api_error(infra_error_to_api_v1(error));
 
     let mut stderr = String::new();
-    log_info(&mut stderr, "command failed", mode.no_progress);
+    log_info(&mut stderr, "command failed", mode);
 
     let stdout = if mode.is_ndjson() {
         format_ndjson_error(&api_error)
@@ -48,9 +50,14 @@ pub fn format_error_output(mode: OutputMode, error: &InfraError, exit_code: Exit
 }
 
 pub fn infra_exit_code(error: &InfraError) -> ExitCode {
-    match infra_error_to_api_v1(error).kind {
-        ApiV1ErrorKind::Expected => ExitCode::InvalidInput,
+    api_error_exit_code(&infra_error_to_api_v1(error))
+}
+
+const fn api_error_exit_code(error: &ApiV1ErrorDto) -> ExitCode {
+    match error.kind {
         ApiV1ErrorKind::Invariant => ExitCode::Internal,
+        ApiV1ErrorKind::Expected if error.retriable => ExitCode::Transient,
+        ApiV1ErrorKind::Expected => ExitCode::InvalidInput,
     }
 }
 
@@ -97,8 +104,8 @@ fn format_api_error_text(error: &ApiV1ErrorDto) -> String {
     out
 }
 
-pub fn log_info(stderr: &mut String, message: &str, no_progress: bool) {
-    if no_progress {
+pub fn log_info(stderr: &mut String, message: &str, mode: OutputMode) {
+    if mode.no_progress || mode.log_level < LogLevel::Info {
         return;
     }
     stderr.push_str("info: ");
@@ -106,6 +113,19 @@ pub fn log_info(stderr: &mut String, message: &str, no_progress: bool) {
     stderr.push('\n');
 }
 
+/// Emit a `debug:` line when `-v` (or `--log-level debug`) is in effect.
+///
+/// Unlike [`log_info`], debug lines are requested explicitly and are kept
+/// under `--no-progress`.
+pub fn log_debug(stderr: &mut String, message: &str, mode: OutputMode) {
+    if mode.log_level < LogLevel::Debug {
+        return;
+    }
+    stderr.push_str("debug: ");
+    stderr.push_str(message);
+    stderr.push('\n');
+}
+
 pub fn format_ndjson_summary(status: &str, kind: &str, extra: Option<serde_json::Value>) -> String {
     let mut payload = serde_json::Map::new();
     payload.insert(
@@ -145,9 +165,14 @@ pub fn format_ndjson_error(error: &ApiV1ErrorDto) -> String {
     out
 }
 
-pub fn write_output(output: &CliOutput) -> Result<(), CliError> {
-    let mut stdout = io::stdout();
-    stdout.write_all(output.stdout.as_bytes())?;
+/// Write a command's output, sending stdout content to `output_file` when set.
+pub fn write_output(output: &CliOutput, output_file: Option<&Path>) -> Result<(), CliError> {
+    if let Some(path) = output_file {
+        write_file_atomic(path, output.stdout.as_bytes())?;
+    } else {
+        let mut stdout = io::stdout();
+        stdout.write_all(output.stdout.as_bytes())?;
+    }
 
     if !output.stderr.is_empty() {
         let mut stderr = io::stderr();
@@ -158,10 +183,42 @@ pub fn write_output(output: &CliOutput) -> Result<(), CliError> {
     Ok(())
 }
 
+/// Replace `path` with `payload` via a sibling temp file and rename, so readers
+/// never observe a partially written file.
+fn write_file_atomic(path: &Path, payload: &[u8]) -> io::Result<()> {
+    let file_name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("--output-file has no file name: {}", path.display()),
+        )
+    })?;
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let temp_path = parent.join(format!(
+        ".{}.tmp-{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(payload)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use semantic_code_facade::ApiV1ErrorKind;
+    use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope};
     use std::collections::BTreeMap;
 
     #[test]
@@ -170,6 +227,7 @@ mod tests {
             code: "ERR_CONFIG_INVALID_ENV_URL".to_string(),
             message: "bad env".to_string(),
             kind: ApiV1ErrorKind::Expected,
+            retriable: false,
             meta: Some(BTreeMap::from([
                 ("apiKey".to_string(), "secret-value".to_string()),
                 ("field".to_string(), "timeoutMs".to_string()),
@@ -182,10 +240,62 @@ mod tests {
         assert_eq!(meta.get("field").map(String::as_str), Some("timeoutMs"));
     }
 
+    #[test]
+    fn retriable_network_error_maps_to_transient_exit_code() {
+        let error = InfraError::from(ErrorEnvelope::unexpected(
+            ErrorCode::new("vector", "vdb_connection"),
+            "connection refused",
+            ErrorClass::Retriable,
+        ));
+        assert_eq!(infra_exit_code(&error), ExitCode::Transient);
+        assert_eq!(ExitCode::Transient.as_u8(), 75);
+
+        let error = InfraError::from(ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "bad input",
+        ));
+        assert_eq!(infra_exit_code(&error), ExitCode::InvalidInput);
+    }
+
+    fn mode_with(no_progress: bool, verbose: u8, quiet: bool) -> OutputMode {
+        OutputMode::from_args(&crate::format::OutputArgs {
+            output: None,
+            json: false,
+            agent: false,
+            no_progress,
+            interactive: false,
+            profile: false,
+            log_level: LogLevel::Info,
+            verbose,
+            quiet,
+        })
+    }
+
     #[test]
     fn log_info_respects_no_progress() {
         let mut stderr = String::new();
-        log_info(&mut stderr, "message", true);
+        log_info(&mut stderr, "message", mode_with(true, 0, false));
+        assert!(stderr.is_empty());
+    }
+
+    #[test]
+    fn log_lines_are_filtered_by_level() {
+        let mut stderr = String::new();
+        let mode = mode_with(false, 0, false);
+        log_info(&mut stderr, "info line", mode);
+        log_debug(&mut stderr, "debug line", mode);
+        assert_eq!(stderr, "info: info line\n");
+
+        let mut stderr = String::new();
+        let mode = mode_with(false, 2, false);
+        log_info(&mut stderr, "info line", mode);
+        log_debug(&mut stderr, "debug line", mode);
+        assert_eq!(stderr, "info: info line\ndebug: debug line\n");
+
+        let mut stderr = String::new();
+        let mode = mode_with(false, 0, true);
+        log_info(&mut stderr, "info line", mode);
+        log_debug(&mut stderr, "debug line", mode);
         assert!(stderr.is_empty());
     }
 }
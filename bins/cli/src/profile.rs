@@ -0,0 +1,83 @@
+//! `--profile` timing breakdowns, written to stderr.
+
+use semantic_code_facade::{IndexStageStats, SearchOutput};
+use std::fmt::Write;
+
+/// Render index stage timings: scan, split, embed, insert.
+pub fn format_index_profile(stats: &IndexStageStats) -> String {
+    let mut out = String::from("profile:\n");
+    push_stage(
+        &mut out,
+        "scan",
+        stats.scan.duration_ms,
+        "files",
+        stats.scan.files,
+    );
+    push_stage(
+        &mut out,
+        "split",
+        stats.split.duration_ms,
+        "chunks",
+        stats.split.chunks,
+    );
+    push_stage(
+        &mut out,
+        "embed",
+        stats.embed.duration_ms,
+        "chunks",
+        stats.embed.chunks,
+    );
+    push_stage(
+        &mut out,
+        "insert",
+        stats.insert.duration_ms,
+        "chunks",
+        stats.insert.chunks,
+    );
+    out
+}
+
+/// Render search phase timings: query embedding and vector search.
+pub fn format_search_profile(output: &SearchOutput) -> String {
+    let results = u64::try_from(output.results.len()).unwrap_or(u64::MAX);
+    let mut out = String::from("profile:\n");
+    push_stage(&mut out, "embed", output.timings.embed_ms, "queries", 1);
+    push_stage(
+        &mut out,
+        "search",
+        output.timings.search_ms,
+        "results",
+        results,
+    );
+    out
+}
+
+fn push_stage(out: &mut String, stage: &str, duration_ms: u64, unit: &str, count: u64) {
+    let _ = write!(
+        out,
+        "  {stage}: durationMs={duration_ms} {unit}={count} rate="
+    );
+    match count.saturating_mul(1_000).checked_div(duration_ms) {
+        Some(per_sec) => {
+            let _ = writeln!(out, "{per_sec} {unit}/s");
+        },
+        None => out.push_str("n/a\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_is_items_per_second_and_skipped_for_zero_durations() {
+        let mut out = String::new();
+        push_stage(&mut out, "embed", 250, "chunks", 10);
+        push_stage(&mut out, "insert", 0, "chunks", 10);
+
+        assert_eq!(
+            out,
+            "  embed: durationMs=250 chunks=10 rate=40 chunks/s\n  insert: durationMs=0 chunks=10 rate=n/a\n"
+        );
+    }
+}
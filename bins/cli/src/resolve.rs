@@ -1,14 +1,14 @@
 //! CLI input resolution utilities.
 //!
-//! Pure helpers that resolve codebase roots, query text, storage modes, and
-//! environment variables from raw CLI arguments. No side effects beyond
-//! reading `stdin` or `env::vars`.
+//! Pure helpers that resolve codebase roots, query text, storage modes, config
+//! overrides, and environment variables from raw CLI arguments. No side
+//! effects beyond reading `stdin`, an overrides file, or `env::vars`.
 
 use crate::error::CliError;
-use semantic_code_facade::SnapshotStorageMode;
+use semantic_code_facade::{ConfigTemplate, RequestAttributes, SnapshotStorageMode};
 use std::collections::BTreeMap;
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub fn resolve_codebase_root(path: Option<&PathBuf>) -> Result<PathBuf, CliError> {
     match path {
@@ -36,38 +36,80 @@ fn read_stdin_query() -> Result<String, CliError> {
     Ok(trimmed.to_string())
 }
 
-pub fn parse_storage_mode(value: Option<&str>) -> Result<Option<SnapshotStorageMode>, CliError> {
-    let Some(value) = value else {
-        return Ok(None);
+/// Resolve config overrides from `--overrides-json` or `--overrides-file`.
+///
+/// The file format follows its extension; only `.json` is supported today.
+pub fn resolve_overrides(
+    inline: Option<&str>,
+    file: Option<&Path>,
+) -> Result<Option<String>, CliError> {
+    let Some(path) = file else {
+        return Ok(inline.map(str::to_owned));
     };
-    let normalized = value.trim();
-    if normalized.is_empty() {
-        return Err(CliError::InvalidInput(
-            "storage mode cannot be empty".to_string(),
-        ));
-    }
-    let lower = normalized.to_ascii_lowercase();
-    match lower.as_str() {
-        "disabled" => Ok(Some(SnapshotStorageMode::Disabled)),
-        "project" => Ok(Some(SnapshotStorageMode::Project)),
-        "custom" => Err(CliError::InvalidInput(
-            "custom storage mode requires a path (custom:/path)".to_string(),
-        )),
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("json") => {},
+        Some("yaml" | "yml") => {
+            return Err(CliError::InvalidInput(format!(
+                "YAML overrides are not supported yet; convert `{}` to .json",
+                path.display()
+            )));
+        },
         _ => {
-            if lower.starts_with("custom:") || lower.starts_with("custom=") {
-                let path = normalized[7..].trim();
-                if path.is_empty() {
-                    return Err(CliError::InvalidInput(
-                        "custom storage mode requires a path (custom:/path)".to_string(),
-                    ));
-                }
-                return Ok(Some(SnapshotStorageMode::Custom(PathBuf::from(path))));
-            }
-            Err(CliError::InvalidInput(format!(
-                "unsupported storage mode: {normalized}"
-            )))
+            return Err(CliError::InvalidInput(format!(
+                "unsupported --overrides-file `{}` (expected a .json file)",
+                path.display()
+            )));
         },
     }
+    std::fs::read_to_string(path).map(Some).map_err(|error| {
+        CliError::InvalidInput(format!(
+            "failed to read --overrides-file `{}`: {error}",
+            path.display()
+        ))
+    })
+}
+
+pub fn parse_storage_mode(value: Option<&str>) -> Result<Option<SnapshotStorageMode>, CliError> {
+    value
+        .map(SnapshotStorageMode::parse)
+        .transpose()
+        .map_err(|error| CliError::InvalidInput(error.as_envelope().message.clone()))
+}
+
+pub fn parse_config_template(value: Option<&str>) -> Result<Option<ConfigTemplate>, CliError> {
+    value
+        .map(|name| {
+            ConfigTemplate::from_name(name).ok_or_else(|| {
+                CliError::InvalidInput(format!(
+                    "unknown --template `{name}` (expected one of: {})",
+                    ConfigTemplate::names()
+                ))
+            })
+        })
+        .transpose()
+}
+
+pub fn parse_request_attributes(values: &[String]) -> Result<RequestAttributes, CliError> {
+    let mut attributes = RequestAttributes::new();
+    for value in values {
+        let Some((key, attr_value)) = value.split_once('=') else {
+            return Err(CliError::InvalidInput(format!(
+                "invalid --attr `{value}` (expected key=value)"
+            )));
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(CliError::InvalidInput(format!(
+                "invalid --attr `{value}` (key cannot be empty)"
+            )));
+        }
+        attributes.insert(key.to_string(), attr_value.to_string());
+    }
+    Ok(attributes)
 }
 
 pub fn collect_scoped_env(prefix: &str) -> BTreeMap<String, String> {
@@ -89,6 +131,30 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn resolve_overrides_checks_the_file_extension() {
+        assert_eq!(
+            resolve_overrides(Some("{}"), None)
+                .ok()
+                .flatten()
+                .as_deref(),
+            Some("{}")
+        );
+        assert!(resolve_overrides(None, Some(Path::new("overrides.yaml"))).is_err());
+        assert!(resolve_overrides(None, Some(Path::new("overrides.toml"))).is_err());
+        assert!(resolve_overrides(None, Some(Path::new("missing-overrides.json"))).is_err());
+    }
+
+    #[test]
+    fn parse_config_template_rejects_unknown_names() {
+        assert!(matches!(
+            parse_config_template(Some("milvus-openai")),
+            Ok(Some(ConfigTemplate::MilvusOpenAi))
+        ));
+        assert!(matches!(parse_config_template(None), Ok(None)));
+        assert!(parse_config_template(Some("pinecone")).is_err());
+    }
+
     #[test]
     fn resolve_query_requires_input() {
         let result = resolve_query(false, None);
@@ -97,4 +163,15 @@ mod tests {
         let result = resolve_query(false, Some("needle"));
         assert_eq!(result.ok().as_deref(), Some("needle"));
     }
+
+    #[test]
+    fn parse_request_attributes_requires_key_value_pairs() {
+        let parsed = parse_request_attributes(&["team=search".to_string(), "note=a=b".to_string()]);
+        let parsed = parsed.ok().unwrap_or_default();
+        assert_eq!(parsed.get("team").map(String::as_str), Some("search"));
+        assert_eq!(parsed.get("note").map(String::as_str), Some("a=b"));
+
+        assert!(parse_request_attributes(&["missing-separator".to_string()]).is_err());
+        assert!(parse_request_attributes(&["=value".to_string()]).is_err());
+    }
 }
@@ -10,7 +10,7 @@ use tracing_subscriber::util::SubscriberInitExt;
 ///
 /// Behavior:
 /// - If `RUST_LOG` is set, it takes precedence.
-/// - Otherwise, uses the CLI `--log-level` value.
+/// - Otherwise, uses the CLI `--log-level` value as adjusted by `-v`/`--quiet`.
 /// - Initialization failures are ignored so startup remains non-fatal.
 pub fn init_tracing(output: &OutputArgs) {
     let filter = build_filter(output);
@@ -29,10 +29,10 @@ pub fn init_tracing(output: &OutputArgs) {
 
 fn build_filter(output: &OutputArgs) -> EnvFilter {
     std::env::var_os(EnvFilter::DEFAULT_ENV).map_or_else(
-        || EnvFilter::new(output.log_level.as_scoped_directive()),
+        || EnvFilter::new(output.tracing_directive()),
         |_| {
             EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new(output.log_level.as_scoped_directive()))
+                .unwrap_or_else(|_| EnvFilter::new(output.tracing_directive()))
         },
     )
 }
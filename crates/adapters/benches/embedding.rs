@@ -40,11 +40,8 @@ fn main() -> Result<()> {
     if matches!(mode, BenchMode::Onnx | BenchMode::Both) {
         if let Some(model_dir) = resolve_model_dir(&args) {
             let config = OnnxEmbeddingConfig {
-                model_dir,
-                model_filename: None,
-                tokenizer_filename: None,
-                dimension: None,
                 session_pool_size: session_pool,
+                ..OnnxEmbeddingConfig::new(model_dir)
             };
             let embedder = Arc::new(OnnxEmbedding::new(&config)?);
             run_bench("embedding_onnx", embedder, &texts, iterations)?;
@@ -21,6 +21,23 @@ pub struct EmbeddingCacheConfig {
     pub disk_table: Option<Box<str>>,
     /// Maximum disk cache size in bytes.
     pub disk_max_bytes: Option<u64>,
+    /// Maximum concurrent lookups issued by [`super::EmbeddingCache::get_many`].
+    pub warmup_concurrency: usize,
+    /// Hash used to derive cache keys.
+    pub key_hash: CacheKeyHash,
+}
+
+/// Hash algorithm used to derive cache keys.
+///
+/// Keys are not portable across algorithms: switching invalidates every
+/// existing memory/disk entry. Chunk ids always use SHA-256.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheKeyHash {
+    /// SHA-256 (default).
+    #[default]
+    Sha256,
+    /// BLAKE3; requires the `cache-blake3` feature.
+    Blake3,
 }
 
 /// Disk cache provider.
@@ -1,27 +1,48 @@
 use super::{SCHEMA_VERSION, disk_error, legacy_suffix, now_epoch_ms};
 use rusqlite::{Connection, OptionalExtension};
 use semantic_code_ports::EmbeddingVector;
-use semantic_code_shared::{ErrorEnvelope, Result};
+use semantic_code_shared::{
+    ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result, RetryPolicy, retry_async,
+};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::task::spawn_blocking;
 
+/// How long sqlite waits on a held lock before reporting `SQLITE_BUSY`.
+const BUSY_TIMEOUT_MS: u64 = 250;
+
+/// Backoff applied to writes that still hit a busy/locked database.
+const WRITE_RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 4,
+    base_delay_ms: 25,
+    max_delay_ms: 200,
+    jitter_ratio_pct: 20,
+};
+
 #[derive(Debug)]
 pub(super) struct SqliteCache {
     path: PathBuf,
     max_bytes: Option<u64>,
+    busy_timeout_ms: u64,
+    write_retry_policy: RetryPolicy,
 }
 
 impl SqliteCache {
     pub(crate) const fn new(path: PathBuf, max_bytes: Option<u64>) -> Self {
-        Self { path, max_bytes }
+        Self {
+            path,
+            max_bytes,
+            busy_timeout_ms: BUSY_TIMEOUT_MS,
+            write_retry_policy: WRITE_RETRY_POLICY,
+        }
     }
 
     pub(crate) async fn get(&self, key: &str) -> Result<Option<EmbeddingVector>> {
         let path = self.path.clone();
         let key = key.to_owned();
+        let busy_timeout_ms = self.busy_timeout_ms;
         let value = spawn_blocking(move || {
-            let conn = open_connection(&path)?;
+            let conn = open_connection(&path, busy_timeout_ms)?;
             let row: Option<(String, u32)> = conn
                 .query_row(
                     "SELECT vector_json, dimension FROM embeddings WHERE cache_key = ?1",
@@ -29,7 +50,7 @@ impl SqliteCache {
                     |row| Ok((row.get(0)?, row.get(1)?)),
                 )
                 .optional()
-                .map_err(|error| disk_error(&format!("disk cache query failed: {error}")))?;
+                .map_err(|error| sqlite_error("disk cache query failed", &error))?;
 
             let Some((vector_json, dimension)) = row else {
                 return Ok::<Option<EmbeddingVector>, ErrorEnvelope>(None);
@@ -40,7 +61,7 @@ impl SqliteCache {
                 "UPDATE embeddings SET last_accessed_ms = ?1 WHERE cache_key = ?2",
                 (now, &key),
             )
-            .map_err(|error| disk_error(&format!("disk cache update failed: {error}")))?;
+            .map_err(|error| sqlite_error("disk cache update failed", &error))?;
 
             let vector: Vec<f32> = serde_json::from_str(&vector_json)
                 .map_err(|error| disk_error(&format!("disk cache decode failed: {error}")))?;
@@ -54,76 +75,128 @@ impl SqliteCache {
     }
 
     pub(crate) async fn insert(&self, key: &str, value: &EmbeddingVector) -> Result<()> {
-        let path = self.path.clone();
-        let key = key.to_owned();
-        let vector_json = serde_json::to_string(value.as_slice())
-            .map_err(|error| disk_error(&format!("disk cache encode failed: {error}")))?;
+        let vector_json: Arc<str> = serde_json::to_string(value.as_slice())
+            .map_err(|error| disk_error(&format!("disk cache encode failed: {error}")))?
+            .into();
         let dimension = value.dimension();
         let size_bytes = vector_json.len() as u64;
-        let max_bytes = self.max_bytes;
 
-        spawn_blocking(move || {
-            let conn = open_connection(&path)?;
-            let now = now_epoch_ms()?;
-            conn.execute(
-                "INSERT OR REPLACE INTO embeddings (cache_key, vector_json, dimension, size_bytes, created_at_ms, last_accessed_ms) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                (
-                    &key,
-                    &vector_json,
-                    dimension,
-                    size_bytes.cast_signed(),
-                    now,
-                    now,
-                ),
-            )
-            .map_err(|error| disk_error(&format!("disk cache insert failed: {error}")))?;
+        // The cache API carries no request context, so retries run uncancellable.
+        let ctx = RequestContext::new_request();
+        retry_async(
+            &ctx,
+            self.write_retry_policy,
+            "embedding.cache_disk.insert",
+            || {
+                let path = self.path.clone();
+                let key = key.to_owned();
+                let vector_json = Arc::clone(&vector_json);
+                let max_bytes = self.max_bytes;
+                let busy_timeout_ms = self.busy_timeout_ms;
+                async move {
+                    spawn_blocking(move || {
+                        insert_blocking(
+                            &path,
+                            busy_timeout_ms,
+                            &key,
+                            &vector_json,
+                            dimension,
+                            size_bytes,
+                            max_bytes,
+                        )
+                    })
+                    .await
+                    .map_err(|error| disk_error(&format!("disk cache task failed: {error}")))?
+                }
+            },
+        )
+        .await
+    }
+}
 
-            if let Some(limit) = max_bytes {
-                evict_disk_cache(&conn, limit)?;
-            }
+fn insert_blocking(
+    path: &Path,
+    busy_timeout_ms: u64,
+    key: &str,
+    vector_json: &str,
+    dimension: u32,
+    size_bytes: u64,
+    max_bytes: Option<u64>,
+) -> Result<()> {
+    let conn = open_connection(path, busy_timeout_ms)?;
+    let now = now_epoch_ms()?;
+    conn.execute(
+        "INSERT OR REPLACE INTO embeddings (cache_key, vector_json, dimension, size_bytes, created_at_ms, last_accessed_ms) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        (
+            key,
+            vector_json,
+            dimension,
+            size_bytes.cast_signed(),
+            now,
+            now,
+        ),
+    )
+    .map_err(|error| sqlite_error("disk cache insert failed", &error))?;
 
-            Ok::<(), ErrorEnvelope>(())
-        })
-        .await
-        .map_err(|error| disk_error(&format!("disk cache task failed: {error}")))??;
+    if let Some(limit) = max_bytes {
+        evict_disk_cache(&conn, limit)?;
+    }
 
-        Ok(())
+    Ok(())
+}
+
+/// Map a sqlite failure, treating busy/locked databases as transient.
+fn sqlite_error(message: &str, error: &rusqlite::Error) -> ErrorEnvelope {
+    match error.sqlite_error_code() {
+        Some(rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked) => {
+            ErrorEnvelope::unexpected(
+                ErrorCode::new("embedding", "cache_disk_busy"),
+                format!("{message}: {error}"),
+                ErrorClass::Retriable,
+            )
+        },
+        _ => disk_error(&format!("{message}: {error}")),
     }
 }
 
-fn open_connection(path: &Path) -> Result<Connection> {
+fn open_connection(path: &Path, busy_timeout_ms: u64) -> Result<Connection> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
             .map_err(|error| disk_error(&format!("disk cache mkdir failed: {error}")))?;
     }
 
-    let conn = Connection::open(path)
-        .map_err(|error| disk_error(&format!("disk cache open failed: {error}")))?;
-
-    conn.execute_batch("PRAGMA journal_mode = WAL;")
-        .map_err(|error| disk_error(&format!("disk cache pragma failed: {error}")))?;
+    let conn =
+        Connection::open(path).map_err(|error| sqlite_error("disk cache open failed", &error))?;
 
+    apply_pragmas(&conn, busy_timeout_ms)?;
     init_sqlite_schema(&conn)?;
 
     let version: i64 = conn
         .query_row("PRAGMA user_version", [], |row| row.get(0))
-        .map_err(|error| disk_error(&format!("disk cache version failed: {error}")))?;
+        .map_err(|error| sqlite_error("disk cache version failed", &error))?;
 
     if version == 0 {
         conn.execute_batch(&format!("PRAGMA user_version = {SCHEMA_VERSION};"))
-            .map_err(|error| disk_error(&format!("disk cache version set failed: {error}")))?;
+            .map_err(|error| sqlite_error("disk cache version set failed", &error))?;
         return Ok(conn);
     }
 
     if version != SCHEMA_VERSION {
         drop(conn);
         rotate_sqlite_legacy(path, version)?;
-        return open_connection_fresh(path);
+        return open_connection_fresh(path, busy_timeout_ms);
     }
 
     Ok(conn)
 }
 
+fn apply_pragmas(conn: &Connection, busy_timeout_ms: u64) -> Result<()> {
+    conn.execute_batch(&format!(
+        "PRAGMA busy_timeout = {busy_timeout_ms}; PRAGMA journal_mode = WAL;"
+    ))
+    .map_err(|error| sqlite_error("disk cache pragma failed", &error))
+}
+
 fn evict_disk_cache(conn: &Connection, max_bytes: u64) -> Result<()> {
     let mut total: u64 = conn
         .query_row(
@@ -131,7 +204,7 @@ fn evict_disk_cache(conn: &Connection, max_bytes: u64) -> Result<()> {
             [],
             |row| row.get::<_, i64>(0).map(i64::cast_unsigned),
         )
-        .map_err(|error| disk_error(&format!("disk cache size failed: {error}")))?;
+        .map_err(|error| sqlite_error("disk cache size failed", &error))?;
 
     while total > max_bytes {
         let candidate: Option<(String, u64)> = conn
@@ -141,14 +214,14 @@ fn evict_disk_cache(conn: &Connection, max_bytes: u64) -> Result<()> {
                 |row| Ok((row.get(0)?, row.get::<_, i64>(1).map(i64::cast_unsigned)?)),
             )
             .optional()
-            .map_err(|error| disk_error(&format!("disk cache eviction scan failed: {error}")))?;
+            .map_err(|error| sqlite_error("disk cache eviction scan failed", &error))?;
 
         let Some((key, size_bytes)) = candidate else {
             break;
         };
 
         conn.execute("DELETE FROM embeddings WHERE cache_key = ?1", [&key])
-            .map_err(|error| disk_error(&format!("disk cache eviction delete failed: {error}")))?;
+            .map_err(|error| sqlite_error("disk cache eviction delete failed", &error))?;
         total = total.saturating_sub(size_bytes);
     }
 
@@ -170,18 +243,17 @@ fn init_sqlite_schema(conn: &Connection) -> Result<()> {
             last_accessed_ms INTEGER NOT NULL
          );",
     )
-    .map_err(|error| disk_error(&format!("disk cache schema failed: {error}")))?;
+    .map_err(|error| sqlite_error("disk cache schema failed", &error))?;
     Ok(())
 }
 
-fn open_connection_fresh(path: &Path) -> Result<Connection> {
-    let conn = Connection::open(path)
-        .map_err(|error| disk_error(&format!("disk cache open failed: {error}")))?;
-    conn.execute_batch("PRAGMA journal_mode = WAL;")
-        .map_err(|error| disk_error(&format!("disk cache pragma failed: {error}")))?;
+fn open_connection_fresh(path: &Path, busy_timeout_ms: u64) -> Result<Connection> {
+    let conn =
+        Connection::open(path).map_err(|error| sqlite_error("disk cache open failed", &error))?;
+    apply_pragmas(&conn, busy_timeout_ms)?;
     init_sqlite_schema(&conn)?;
     conn.execute_batch(&format!("PRAGMA user_version = {SCHEMA_VERSION};"))
-        .map_err(|error| disk_error(&format!("disk cache version set failed: {error}")))?;
+        .map_err(|error| sqlite_error("disk cache version set failed", &error))?;
     Ok(conn)
 }
 
@@ -211,3 +283,81 @@ fn rotate_sqlite_legacy(path: &Path, version: i64) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn temp_db_path(prefix: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        std::env::temp_dir()
+            .join(format!("{prefix}-{nanos}"))
+            .join("cache.sqlite")
+    }
+
+    fn cache_without_busy_wait(path: PathBuf, max_attempts: u32) -> SqliteCache {
+        SqliteCache {
+            path,
+            max_bytes: None,
+            busy_timeout_ms: 0,
+            write_retry_policy: RetryPolicy {
+                max_attempts,
+                base_delay_ms: 20,
+                max_delay_ms: 100,
+                jitter_ratio_pct: 0,
+            },
+        }
+    }
+
+    /// Open a connection that holds an exclusive write lock.
+    fn lock_database(path: &Path) -> Result<Connection> {
+        let conn = open_connection(path, 0)?;
+        conn.execute_batch("BEGIN EXCLUSIVE;")
+            .map_err(|error| sqlite_error("lock failed", &error))?;
+        Ok(conn)
+    }
+
+    #[tokio::test]
+    async fn locked_database_is_retriable() -> Result<()> {
+        let path = temp_db_path("sqlite-cache-locked");
+        let cache = cache_without_busy_wait(path.clone(), 1);
+        let lock = lock_database(&path)?;
+
+        let value = EmbeddingVector::from_vec(vec![1.0, 2.0]);
+        let error = cache
+            .insert("key", &value)
+            .await
+            .expect_err("insert should fail while locked");
+        assert_eq!(error.class, ErrorClass::Retriable);
+        assert_eq!(error.code, ErrorCode::new("embedding", "cache_disk_busy"));
+
+        drop(lock);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_succeeds_after_lock_released() -> Result<()> {
+        let path = temp_db_path("sqlite-cache-retry");
+        let cache = cache_without_busy_wait(path.clone(), 10);
+        let lock = lock_database(&path)?;
+        let holder = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(60));
+            lock.execute_batch("COMMIT;")
+        });
+
+        let value = EmbeddingVector::from_vec(vec![1.0, 2.0]);
+        cache.insert("key", &value).await?;
+        holder
+            .join()
+            .expect("lock holder thread")
+            .map_err(|error| sqlite_error("unlock failed", &error))?;
+
+        let cached = cache.get("key").await?.expect("cache hit");
+        assert_eq!(cached, value);
+        Ok(())
+    }
+}
@@ -3,10 +3,10 @@ use semantic_code_ports::{
     EmbeddingPort, EmbeddingProviderInfo, EmbeddingVector, TelemetryPort, TelemetryTags,
 };
 use semantic_code_shared::{
-    ErrorClass, ErrorCode, ErrorEnvelope, Result, RetryPolicy, retry_async_with_observer,
-    timeout_with_context,
+    ErrorClass, ErrorCode, ErrorEnvelope, Result, RetryPolicy, log_retry,
+    retry_async_with_observer, timeout_with_context,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::Semaphore;
@@ -49,7 +49,7 @@ impl CachingEmbedding {
     }
 
     fn cache_key(&self, text: &str) -> Box<str> {
-        EmbeddingCache::make_key(&self.cache_namespace, text)
+        self.cache.key(&self.cache_namespace, text)
     }
 
     fn cache_tags(&self, source: Option<&str>) -> TelemetryTags {
@@ -145,12 +145,18 @@ impl CachingEmbedding {
         Fut: Future<Output = Result<T>>,
     {
         let mut retry_attempts = 0u32;
-        let result =
-            retry_async_with_observer(ctx, self.retry_policy, operation, &mut op, |_, _| {
+        let result = retry_async_with_observer(
+            ctx,
+            self.retry_policy,
+            operation,
+            &mut op,
+            |attempt, error, delay| {
                 retry_attempts = retry_attempts.saturating_add(1);
                 self.record_retry_attempt();
-            })
-            .await;
+                log_retry(operation, attempt, error, delay);
+            },
+        )
+        .await;
 
         match &result {
             Err(error) if error.code == ErrorCode::timeout() => {
@@ -164,6 +170,34 @@ impl CachingEmbedding {
 
         result
     }
+
+    /// Embed cache misses in one provider call with retries and timeouts.
+    async fn embed_uncached_batch(
+        &self,
+        ctx: &semantic_code_shared::RequestContext,
+        texts: Vec<Box<str>>,
+    ) -> Result<Vec<EmbeddingVector>> {
+        let timeout = std::time::Duration::from_millis(self.timeout_ms);
+        self.run_with_resilience(ctx, "embedding.embed_batch", || {
+            let batch = texts.clone();
+            async move {
+                self.with_in_flight(ctx, "embedding.embed_batch", || async {
+                    timeout_with_context(
+                        ctx,
+                        timeout,
+                        "embedding.embed_batch",
+                        self.inner.embed_batch(
+                            ctx,
+                            semantic_code_ports::EmbedBatchRequest { texts: batch },
+                        ),
+                    )
+                    .await
+                })
+                .await
+            }
+        })
+        .await
+    }
 }
 
 impl EmbeddingPort for CachingEmbedding {
@@ -264,12 +298,22 @@ impl EmbeddingPort for CachingEmbedding {
         Box::pin(async move {
             let ctx_ref = &ctx;
             let mut results: Vec<Option<EmbeddingVector>> = vec![None; texts.len()];
-            let mut missing = Vec::new();
-            let mut missing_indices = Vec::new();
+            // Each distinct missing text is embedded once; `missing_slots`
+            // lists every batch position that receives its vector.
+            let mut missing: Vec<Box<str>> = Vec::new();
+            let mut missing_keys: Vec<Box<str>> = Vec::new();
+            let mut missing_slots: Vec<Vec<usize>> = Vec::new();
+            let mut missing_by_key: HashMap<Box<str>, usize> = HashMap::new();
 
-            for (idx, text) in texts.iter().enumerate() {
-                let key = self.cache_key(text);
-                let cache_lookup = self.cache.get(&key).await?;
+            let keys = texts
+                .iter()
+                .map(|text| self.cache_key(text))
+                .collect::<Vec<_>>();
+            let lookups = self.cache.get_many(&keys).await?;
+
+            for (idx, ((text, key), cache_lookup)) in
+                texts.iter().zip(keys).zip(lookups).enumerate()
+            {
                 tracing::debug!(
                     operation = "embedding.embed_batch",
                     hit = cache_lookup.is_some(),
@@ -289,36 +333,23 @@ impl EmbeddingPort for CachingEmbedding {
                     }
                 } else {
                     self.record_cache_miss();
-                    missing.push(text.clone());
-                    missing_indices.push((idx, key));
+                    if let Some(&unique) = missing_by_key.get(&key) {
+                        if let Some(slots) = missing_slots.get_mut(unique) {
+                            slots.push(idx);
+                        }
+                    } else {
+                        missing_by_key.insert(key.clone(), missing.len());
+                        missing.push(text.clone());
+                        missing_keys.push(key);
+                        missing_slots.push(vec![idx]);
+                    }
                 }
             }
 
             if !missing.is_empty() {
-                let timeout = std::time::Duration::from_millis(self.timeout_ms);
-                let missing_for_retry = missing.clone();
-                let batch_result = self
-                    .run_with_resilience(ctx_ref, "embedding.embed_batch", || {
-                        let batch = missing_for_retry.clone();
-                        async move {
-                            self.with_in_flight(ctx_ref, "embedding.embed_batch", || async {
-                                timeout_with_context(
-                                    ctx_ref,
-                                    timeout,
-                                    "embedding.embed_batch",
-                                    self.inner.embed_batch(
-                                        ctx_ref,
-                                        semantic_code_ports::EmbedBatchRequest { texts: batch },
-                                    ),
-                                )
-                                .await
-                            })
-                            .await
-                        }
-                    })
-                    .await?;
+                let batch_result = self.embed_uncached_batch(ctx_ref, missing).await?;
 
-                if batch_result.len() != missing_indices.len() {
+                if batch_result.len() != missing_keys.len() {
                     return Err(ErrorEnvelope::unexpected(
                         ErrorCode::internal(),
                         "embedding batch result length mismatch",
@@ -326,18 +357,22 @@ impl EmbeddingPort for CachingEmbedding {
                     ));
                 }
 
-                for ((idx, key), vector) in missing_indices.into_iter().zip(batch_result) {
-                    self.cache.insert(&key, vector.clone()).await?;
-                    if let Some(slot) = results.get_mut(idx) {
-                        *slot = Some(vector);
-                    } else {
-                        return Err(ErrorEnvelope::unexpected(
-                            ErrorCode::internal(),
-                            "embedding cache index out of bounds",
-                            ErrorClass::NonRetriable,
-                        ));
+                for (slots, vector) in missing_slots.iter().zip(&batch_result) {
+                    for &idx in slots {
+                        if let Some(slot) = results.get_mut(idx) {
+                            *slot = Some(vector.clone());
+                        } else {
+                            return Err(ErrorEnvelope::unexpected(
+                                ErrorCode::internal(),
+                                "embedding cache index out of bounds",
+                                ErrorClass::NonRetriable,
+                            ));
+                        }
                     }
                 }
+                self.cache
+                    .insert_many(missing_keys.into_iter().zip(batch_result).collect())
+                    .await?;
             }
 
             let mut out = Vec::with_capacity(results.len());
@@ -354,3 +389,120 @@ impl EmbeddingPort for CachingEmbedding {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{CacheKeyHash, DiskCacheProvider, EmbeddingCacheConfig};
+    use crate::embedding_test::TestEmbedding;
+    use semantic_code_ports::{DetectDimensionRequest, EmbedBatchRequest, EmbedRequest};
+    use semantic_code_shared::RequestContext;
+    use std::sync::Mutex;
+
+    /// Records the texts of every batch forwarded to the provider.
+    struct RecordingEmbedding {
+        inner: TestEmbedding,
+        batches: Mutex<Vec<Vec<Box<str>>>>,
+    }
+
+    impl EmbeddingPort for RecordingEmbedding {
+        fn provider(&self) -> &EmbeddingProviderInfo {
+            self.inner.provider()
+        }
+
+        fn detect_dimension(
+            &self,
+            ctx: &RequestContext,
+            request: DetectDimensionRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<u32>> {
+            self.inner.detect_dimension(ctx, request)
+        }
+
+        fn embed(
+            &self,
+            ctx: &RequestContext,
+            request: EmbedRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<EmbeddingVector>> {
+            self.inner.embed(ctx, request)
+        }
+
+        fn embed_batch(
+            &self,
+            ctx: &RequestContext,
+            request: EmbedBatchRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+            if let Ok(mut batches) = self.batches.lock() {
+                batches.push(request.texts.clone());
+            }
+            self.inner.embed_batch(ctx, request)
+        }
+    }
+
+    fn batch(texts: &[&str]) -> EmbedBatchRequest {
+        EmbedBatchRequest {
+            texts: texts.iter().map(|text| (*text).into()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn embed_batch_embeds_only_distinct_misses_in_input_order() -> Result<()> {
+        let recorder = Arc::new(RecordingEmbedding {
+            inner: TestEmbedding::new(8)?,
+            batches: Mutex::new(Vec::new()),
+        });
+        let cache = EmbeddingCache::new(&EmbeddingCacheConfig {
+            enabled: true,
+            max_entries: 16,
+            max_bytes: 1024 * 1024,
+            disk_enabled: false,
+            disk_provider: DiskCacheProvider::Sqlite,
+            disk_path: None,
+            disk_connection: None,
+            disk_table: None,
+            disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash: CacheKeyHash::Sha256,
+        })?;
+        let inner: Arc<dyn EmbeddingPort> = recorder.clone();
+        let caching = CachingEmbedding::new(
+            inner,
+            cache,
+            "test".into(),
+            RetryPolicy {
+                max_attempts: 1,
+                base_delay_ms: 1,
+                max_delay_ms: 1,
+                jitter_ratio_pct: 0,
+            },
+            5_000,
+            None,
+            None,
+        );
+        let ctx = RequestContext::new_request();
+
+        caching.embed_batch(&ctx, batch(&["a", "b"])).await?;
+        let texts = ["c", "a", "d", "b", "c"];
+        let vectors = caching.embed_batch(&ctx, batch(&texts)).await?;
+
+        let batches = recorder
+            .batches
+            .lock()
+            .map_err(|_| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "recorder lock poisoned",
+                    ErrorClass::NonRetriable,
+                )
+            })?
+            .clone();
+        let expected_batches: Vec<Vec<Box<str>>> =
+            vec![vec!["a".into(), "b".into()], vec!["c".into(), "d".into()]];
+        assert_eq!(batches, expected_batches);
+
+        let direct = TestEmbedding::new(8)?
+            .embed_batch(&ctx, batch(&texts))
+            .await?;
+        assert_eq!(vectors, direct);
+        Ok(())
+    }
+}
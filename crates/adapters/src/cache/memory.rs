@@ -106,9 +106,29 @@ impl MemoryCache {
 
 #[cfg(test)]
 mod tests {
-    use super::super::{CacheSource, DiskCacheProvider, EmbeddingCache, EmbeddingCacheConfig};
+    use super::super::{
+        CacheKeyHash, CacheSource, DiskCacheProvider, EmbeddingCache, EmbeddingCacheConfig,
+    };
     use semantic_code_ports::EmbeddingVector;
     use semantic_code_shared::Result;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn disk_cache_config(path: PathBuf, memory_enabled: bool) -> EmbeddingCacheConfig {
+        EmbeddingCacheConfig {
+            enabled: memory_enabled,
+            max_entries: 16,
+            max_bytes: 1024 * 1024,
+            disk_enabled: true,
+            disk_provider: DiskCacheProvider::Sqlite,
+            disk_path: Some(path),
+            disk_connection: None,
+            disk_table: None,
+            disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash: CacheKeyHash::Sha256,
+        }
+    }
 
     #[tokio::test]
     async fn cache_roundtrip_memory() -> Result<()> {
@@ -122,6 +142,8 @@ mod tests {
             disk_connection: None,
             disk_table: None,
             disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash: CacheKeyHash::Sha256,
         };
         let cache = EmbeddingCache::new(&cache_config)?;
 
@@ -147,6 +169,8 @@ mod tests {
             disk_connection: None,
             disk_table: None,
             disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash: CacheKeyHash::Sha256,
         };
         let cache = EmbeddingCache::new(&cache_config)?;
 
@@ -177,6 +201,8 @@ mod tests {
             disk_connection: None,
             disk_table: None,
             disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash: CacheKeyHash::Sha256,
         };
         let cache = EmbeddingCache::new(&cache_config)?;
         let key = EmbeddingCache::make_key("test", "source");
@@ -187,4 +213,90 @@ mod tests {
         assert_eq!(lookup.source, CacheSource::Memory);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn get_many_is_positional_and_promotes_disk_hits() -> Result<()> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path = std::env::temp_dir()
+            .join(format!("sca-cache-get-many-{nanos}"))
+            .join("cache.sqlite");
+
+        // Seed the disk tier only, so the first lookups must come from disk.
+        let disk_only = EmbeddingCache::new(&disk_cache_config(path.clone(), false))?;
+        let first = EmbeddingCache::make_key("test", "first");
+        let second = EmbeddingCache::make_key("test", "second");
+        let missing = EmbeddingCache::make_key("test", "missing");
+        disk_only
+            .insert(&first, EmbeddingVector::from_vec(vec![1.0]))
+            .await?;
+        disk_only
+            .insert(&second, EmbeddingVector::from_vec(vec![2.0]))
+            .await?;
+
+        let cache = EmbeddingCache::new(&disk_cache_config(path, true))?;
+        let keys = vec![second.clone(), missing, first.clone(), second.clone()];
+        let lookups = cache.get_many(&keys).await?;
+
+        assert_eq!(lookups.len(), keys.len());
+        let values = lookups
+            .iter()
+            .map(|lookup| lookup.as_ref().map(|hit| (hit.source, hit.value.clone())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                Some((CacheSource::Disk, EmbeddingVector::from_vec(vec![2.0]))),
+                None,
+                Some((CacheSource::Disk, EmbeddingVector::from_vec(vec![1.0]))),
+                Some((CacheSource::Disk, EmbeddingVector::from_vec(vec![2.0]))),
+            ]
+        );
+
+        let promoted = cache.get(&second).await?.expect("promoted hit");
+        assert_eq!(promoted.source, CacheSource::Memory);
+        let promoted = cache.get(&first).await?.expect("promoted hit");
+        assert_eq!(promoted.source, CacheSource::Memory);
+        Ok(())
+    }
+
+    fn memory_cache_config(key_hash: CacheKeyHash) -> EmbeddingCacheConfig {
+        EmbeddingCacheConfig {
+            enabled: true,
+            max_entries: 2,
+            max_bytes: 1024,
+            disk_enabled: false,
+            disk_provider: DiskCacheProvider::Sqlite,
+            disk_path: None,
+            disk_connection: None,
+            disk_table: None,
+            disk_max_bytes: None,
+            warmup_concurrency: 4,
+            key_hash,
+        }
+    }
+
+    #[test]
+    fn cache_keys_are_stable_within_a_hash_and_differ_across_hashes() -> Result<()> {
+        let sha256 = EmbeddingCache::new(&memory_cache_config(CacheKeyHash::Sha256))?;
+        let key = sha256.key("ns", "text");
+        assert_eq!(key, sha256.key("ns", "text"));
+        assert_eq!(key, EmbeddingCache::make_key("ns", "text"));
+        assert_ne!(key, sha256.key("ns", "other"));
+
+        let blake3 = EmbeddingCache::new(&memory_cache_config(CacheKeyHash::Blake3));
+        #[cfg(feature = "cache-blake3")]
+        {
+            let blake3 = blake3?;
+            let blake3_key = blake3.key("ns", "text");
+            assert_eq!(blake3_key, blake3.key("ns", "text"));
+            assert_eq!(blake3_key.len(), key.len());
+            assert_ne!(blake3_key, key);
+        }
+        #[cfg(not(feature = "cache-blake3"))]
+        assert!(blake3.is_err());
+        Ok(())
+    }
 }
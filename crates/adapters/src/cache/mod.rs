@@ -5,14 +5,16 @@ mod disk;
 mod embedding;
 mod memory;
 
-pub use config::{DiskCacheProvider, EmbeddingCacheConfig};
+pub use config::{CacheKeyHash, DiskCacheProvider, EmbeddingCacheConfig};
 pub use embedding::CachingEmbedding;
 
 use disk::DiskCache;
+use futures_util::{StreamExt, TryStreamExt, stream};
 use memory::MemoryCache;
 use semantic_code_ports::EmbeddingVector;
 use semantic_code_shared::Result;
 use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 
 /// Cache source for telemetry.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,11 +39,20 @@ pub struct CacheLookup {
 pub struct EmbeddingCache {
     memory: Option<MemoryCache>,
     disk: Option<DiskCache>,
+    warmup_concurrency: usize,
+    key_fn: fn(&str, &str) -> Box<str>,
 }
 
 impl EmbeddingCache {
     /// Create a new cache from config.
     pub fn new(config: &EmbeddingCacheConfig) -> Result<Self> {
+        let key_fn: fn(&str, &str) -> Box<str> = match config.key_hash {
+            CacheKeyHash::Sha256 => sha256_key,
+            #[cfg(feature = "cache-blake3")]
+            CacheKeyHash::Blake3 => blake3_key,
+            #[cfg(not(feature = "cache-blake3"))]
+            CacheKeyHash::Blake3 => return Err(blake3_not_enabled()),
+        };
         let memory = if config.enabled {
             Some(MemoryCache::new(config.max_entries, config.max_bytes)?)
         } else {
@@ -58,24 +69,24 @@ impl EmbeddingCache {
         } else {
             None
         };
-        Ok(Self { memory, disk })
+        Ok(Self {
+            memory,
+            disk,
+            warmup_concurrency: config.warmup_concurrency.max(1),
+            key_fn,
+        })
     }
 
-    /// Compute a stable cache key for an embedding payload.
+    /// Compute a stable SHA-256 cache key for an embedding payload.
     #[must_use]
     pub fn make_key(namespace: &str, text: &str) -> Box<str> {
-        let mut hasher = Sha256::new();
-        hasher.update(namespace.as_bytes());
-        hasher.update([0u8]);
-        hasher.update(text.as_bytes());
-        let hash = hasher.finalize();
-        hash.iter()
-            .fold(String::with_capacity(64), |mut s, b| {
-                use std::fmt::Write;
-                let _ = write!(s, "{b:02x}");
-                s
-            })
-            .into_boxed_str()
+        sha256_key(namespace, text)
+    }
+
+    /// Compute a cache key with the hash configured for this cache.
+    #[must_use]
+    pub fn key(&self, namespace: &str, text: &str) -> Box<str> {
+        (self.key_fn)(namespace, text)
     }
 
     /// Read from cache.
@@ -104,6 +115,37 @@ impl EmbeddingCache {
         Ok(None)
     }
 
+    /// Read many keys with bounded concurrency.
+    ///
+    /// Results are positionally aligned with `keys`. Duplicate keys are looked
+    /// up once, so a disk hit is promoted into memory a single time.
+    pub async fn get_many(&self, keys: &[Box<str>]) -> Result<Vec<Option<CacheLookup>>> {
+        let mut unique: Vec<Box<str>> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        let slots = keys
+            .iter()
+            .map(|key| {
+                *positions.entry(key.as_ref()).or_insert_with(|| {
+                    unique.push(key.clone());
+                    unique.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Owned keys keep the lookup futures free of higher-ranked borrows, so
+        // callers can still box this future as `Send`.
+        let lookups = stream::iter(unique)
+            .map(|key| async move { self.get(&key).await })
+            .buffered(self.warmup_concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(slots
+            .into_iter()
+            .map(|slot| lookups.get(slot).cloned().flatten())
+            .collect())
+    }
+
     /// Insert into cache.
     pub async fn insert(&self, key: &str, value: EmbeddingVector) -> Result<()> {
         if let Some(memory) = &self.memory {
@@ -114,4 +156,57 @@ impl EmbeddingCache {
         }
         Ok(())
     }
+
+    /// Insert many entries, writing the disk tier with bounded concurrency.
+    pub async fn insert_many(&self, entries: Vec<(Box<str>, EmbeddingVector)>) -> Result<()> {
+        if let Some(memory) = &self.memory {
+            for (key, value) in &entries {
+                memory.insert(key, value.clone()).await;
+            }
+        }
+        if let Some(disk) = &self.disk {
+            stream::iter(entries)
+                .map(|(key, value)| async move { disk.insert(&key, &value).await })
+                .buffer_unordered(self.warmup_concurrency)
+                .try_collect::<()>()
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "cache-blake3"))]
+fn blake3_not_enabled() -> semantic_code_shared::ErrorEnvelope {
+    semantic_code_shared::ErrorEnvelope::unexpected(
+        semantic_code_shared::ErrorCode::new("embedding", "cache_key_hash"),
+        "blake3 cache key hashing not enabled",
+        semantic_code_shared::ErrorClass::NonRetriable,
+    )
+}
+
+fn sha256_key(namespace: &str, text: &str) -> Box<str> {
+    let mut hasher = Sha256::new();
+    hasher.update(namespace.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(text.as_bytes());
+    hex_key(&hasher.finalize())
+}
+
+#[cfg(feature = "cache-blake3")]
+fn blake3_key(namespace: &str, text: &str) -> Box<str> {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(namespace.as_bytes());
+    hasher.update(&[0u8]);
+    hasher.update(text.as_bytes());
+    hex_key(hasher.finalize().as_bytes())
+}
+
+fn hex_key(hash: &[u8]) -> Box<str> {
+    hash.iter()
+        .fold(String::with_capacity(64), |mut s, b| {
+            use std::fmt::Write;
+            let _ = write!(s, "{b:02x}");
+            s
+        })
+        .into_boxed_str()
 }
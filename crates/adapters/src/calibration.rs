@@ -4,6 +4,7 @@
 //! against a loaded `VectorIndex`. Probe vectors are sampled from the existing
 //! index using a seeded PRNG for deterministic results.
 
+use crate::vectordb_local::read_v1_snapshot_payload;
 use semantic_code_domain::{CalibrationParams, CalibrationState};
 use semantic_code_ports::CalibrationPort;
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
@@ -68,19 +69,15 @@ impl LocalCalibrationAdapter {
         snapshot_path: &Path,
         search_backend: VectorSearchBackend,
     ) -> Result<Self> {
-        let payload = match std::fs::read(snapshot_path) {
-            Ok(payload) => payload,
-            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
-                if let Some(index) = try_load_v2_companion_index(snapshot_path)? {
-                    return Ok(Self::new(kernel, Arc::new(index), search_backend));
-                }
-                return Err(ErrorEnvelope::expected(
-                    ErrorCode::new("calibration", "snapshot_not_found"),
-                    "vector snapshot not found; run `sca index --init` first",
-                )
-                .with_metadata("path", snapshot_path.display().to_string()));
-            },
-            Err(error) => return Err(ErrorEnvelope::from(error)),
+        let Some(payload) = read_v1_snapshot_payload(snapshot_path)? else {
+            if let Some(index) = try_load_v2_companion_index(snapshot_path)? {
+                return Ok(Self::new(kernel, Arc::new(index), search_backend));
+            }
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::new("calibration", "snapshot_not_found"),
+                "vector snapshot not found; run `sca index --init` first",
+            )
+            .with_metadata("path", snapshot_path.display().to_string()));
         };
 
         let snapshot: MinimalSnapshot = match serde_json::from_slice(&payload) {
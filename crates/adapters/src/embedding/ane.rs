@@ -107,6 +107,7 @@ impl AneEmbedding {
             provider: EmbeddingProviderInfo {
                 id: EmbeddingProviderId::parse("ane").map_err(ErrorEnvelope::from)?,
                 name: "ANE (Apple Neural Engine)".into(),
+                max_input_chars: None,
             },
             model: Arc::new(model),
             dimension,
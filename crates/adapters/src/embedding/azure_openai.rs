@@ -0,0 +1,405 @@
+//! Azure OpenAI embedding adapter.
+//!
+//! Azure exposes the OpenAI embeddings API per deployment, so requests, batching,
+//! and error mapping are shared with the [`super::openai`] adapter. Only the URL
+//! shape (`/openai/deployments/{deployment}/embeddings?api-version=...`) and the
+//! `api-key` auth header differ.
+
+use super::openai::{
+    OpenAiEmbeddingRequest, OpenAiEmbeddingResponse, OpenAiInput, cancelled_error, map_embeddings,
+    map_openai_http_error, map_reqwest_error, normalize_optional_required, normalize_required,
+    sanitize_input,
+};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use semantic_code_config::EmbeddingConfig;
+use semantic_code_ports::{
+    DetectDimensionRequest, EmbedBatchRequest, EmbedRequest, EmbeddingPort, EmbeddingProviderId,
+    EmbeddingProviderInfo, EmbeddingVector,
+};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use std::time::Duration;
+use tracing::Instrument;
+
+const DEFAULT_API_VERSION: &str = "2024-02-01";
+const DEFAULT_TEST_TEXT: &str = "dimension probe";
+const API_KEY_HEADER: &str = "api-key";
+
+/// Azure OpenAI embedding adapter configuration.
+#[derive(Debug, Clone)]
+pub struct AzureOpenAiEmbeddingConfig {
+    /// API key used for the `api-key` header.
+    pub api_key: Box<str>,
+    /// Resource endpoint (e.g. `https://my-resource.openai.azure.com`).
+    pub endpoint: Option<Box<str>>,
+    /// Deployment name hosting the embedding model.
+    pub deployment: Option<Box<str>>,
+    /// REST API version (defaults to `2024-02-01`).
+    pub api_version: Option<Box<str>>,
+    /// Request timeout in milliseconds.
+    pub timeout_ms: u64,
+    /// Optional output dimension override.
+    pub dimension: Option<u32>,
+}
+
+impl AzureOpenAiEmbeddingConfig {
+    /// Build from the shared embedding config plus an API key.
+    ///
+    /// `embedding.baseUrl` and `embedding.model` act as the endpoint and
+    /// deployment defaults; env overrides are applied by the caller.
+    #[must_use]
+    pub fn from_embedding_config(api_key: Box<str>, config: &EmbeddingConfig) -> Self {
+        Self {
+            api_key,
+            endpoint: config.base_url.clone(),
+            deployment: config.model.clone(),
+            api_version: None,
+            timeout_ms: config.timeout_ms,
+            dimension: config.dimension,
+        }
+    }
+}
+
+/// Azure OpenAI embedding adapter implementation.
+pub struct AzureOpenAiEmbedding {
+    provider: EmbeddingProviderInfo,
+    client: reqwest::Client,
+    endpoint: Box<str>,
+    deployment: Box<str>,
+    dimension_override: Option<u32>,
+}
+
+impl AzureOpenAiEmbedding {
+    /// Create a new Azure OpenAI embedding adapter.
+    pub fn new(config: &AzureOpenAiEmbeddingConfig) -> Result<Self> {
+        let api_key = normalize_required("api key", config.api_key.as_ref())?;
+        let endpoint = normalize_optional_required("endpoint", config.endpoint.as_deref())?
+            .ok_or_else(|| {
+                ErrorEnvelope::expected(
+                    ErrorCode::invalid_input(),
+                    "Azure OpenAI endpoint must be set",
+                )
+            })?;
+        let endpoint = endpoint.trim_end_matches('/').to_owned();
+        if endpoint.is_empty() {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "endpoint must be non-empty",
+            ));
+        }
+        let deployment = normalize_optional_required("deployment", config.deployment.as_deref())?
+            .ok_or_else(|| {
+            ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "Azure OpenAI deployment must be set",
+            )
+        })?;
+        if deployment.contains(['/', '?', '#']) {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "deployment must not contain URL separators",
+            )
+            .with_metadata("deployment", deployment.to_string()));
+        }
+        let api_version =
+            normalize_optional_required("api version", config.api_version.as_deref())?
+                .unwrap_or_else(|| DEFAULT_API_VERSION.to_owned().into_boxed_str());
+        if config.timeout_ms == 0 {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "timeout must be greater than zero",
+            ));
+        }
+
+        let mut headers = HeaderMap::new();
+        let mut auth_header = HeaderValue::from_str(&api_key).map_err(|_| {
+            ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "api key contains invalid header characters",
+            )
+        })?;
+        auth_header.set_sensitive(true);
+        headers.insert(HeaderName::from_static(API_KEY_HEADER), auth_header);
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .default_headers(headers)
+            .build()
+            .map_err(|error| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::new("embedding", "azure_openai_client_init_failed"),
+                    format!("failed to build Azure OpenAI client: {error}"),
+                    ErrorClass::NonRetriable,
+                )
+            })?;
+
+        let provider = EmbeddingProviderInfo {
+            id: EmbeddingProviderId::parse("azure_openai").map_err(ErrorEnvelope::from)?,
+            name: "Azure OpenAI".into(),
+            max_input_chars: None,
+        };
+        let endpoint = deployment_url(&endpoint, &deployment, &api_version);
+
+        Ok(Self {
+            provider,
+            client,
+            endpoint,
+            deployment,
+            dimension_override: config.dimension,
+        })
+    }
+
+    async fn embed_many(
+        &self,
+        ctx: &RequestContext,
+        inputs: OpenAiInput,
+        expected_count: usize,
+        operation: &'static str,
+    ) -> Result<Vec<EmbeddingVector>> {
+        ctx.ensure_not_cancelled(operation)?;
+        if expected_count == 0 {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "embedding input must be non-empty",
+            ));
+        }
+
+        // Azure routes by deployment; the model field is ignored but kept so the
+        // payload shape matches the OpenAI adapter.
+        let request = OpenAiEmbeddingRequest {
+            model: self.deployment.clone(),
+            input: sanitize_input(inputs),
+            dimensions: self.dimension_override,
+        };
+        let response = self.send_request(ctx, request, operation).await?;
+        map_embeddings(response, expected_count, self.dimension_override)
+    }
+
+    async fn send_request(
+        &self,
+        ctx: &RequestContext,
+        request: OpenAiEmbeddingRequest,
+        operation: &'static str,
+    ) -> Result<OpenAiEmbeddingResponse> {
+        ctx.ensure_not_cancelled(operation)?;
+
+        let response = tokio::select! {
+            () = ctx.cancelled() => return Err(cancelled_error(operation)),
+            result = self.client.post(self.endpoint.as_ref()).json(&request).send() => {
+                result.map_err(|error| map_reqwest_error(&error))?
+            }
+        };
+
+        let status = response.status();
+        let payload = tokio::select! {
+            () = ctx.cancelled() => return Err(cancelled_error(operation)),
+            result = response.bytes() => result.map_err(|error| map_reqwest_error(&error))?,
+        };
+
+        if !status.is_success() {
+            return Err(
+                map_openai_http_error(status, &payload).with_metadata("provider", "azure_openai")
+            );
+        }
+
+        serde_json::from_slice(&payload).map_err(|error| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::new("embedding", "azure_openai_invalid_response"),
+                format!("failed to decode Azure OpenAI response: {error}"),
+                ErrorClass::NonRetriable,
+            )
+        })
+    }
+}
+
+impl EmbeddingPort for AzureOpenAiEmbedding {
+    fn provider(&self) -> &EmbeddingProviderInfo {
+        &self.provider
+    }
+
+    fn detect_dimension(
+        &self,
+        ctx: &RequestContext,
+        request: DetectDimensionRequest,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<u32>> {
+        let ctx = ctx.clone();
+        let test_text = request
+            .options
+            .test_text
+            .unwrap_or_else(|| DEFAULT_TEST_TEXT.to_owned().into_boxed_str());
+        let dimension_override = self.dimension_override;
+        Box::pin(async move {
+            ctx.ensure_not_cancelled("azure_openai_embedding.detect_dimension")?;
+            if let Some(dimension) = dimension_override {
+                return Ok(dimension);
+            }
+            let vectors = self
+                .embed_many(
+                    &ctx,
+                    OpenAiInput::Single(test_text),
+                    1,
+                    "azure_openai_embedding.detect_dimension",
+                )
+                .await?;
+            let vector = vectors.into_iter().next().ok_or_else(|| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "missing embedding response",
+                    ErrorClass::NonRetriable,
+                )
+            })?;
+            Ok(vector.dimension())
+        })
+    }
+
+    fn embed(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedRequest,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<EmbeddingVector>> {
+        let ctx = ctx.clone();
+        let text = request.text;
+        Box::pin(async move {
+            let mut vectors = self
+                .embed_many(
+                    &ctx,
+                    OpenAiInput::Single(text),
+                    1,
+                    "azure_openai_embedding.embed",
+                )
+                .await?;
+            vectors.pop().ok_or_else(|| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "missing embedding response",
+                    ErrorClass::NonRetriable,
+                )
+            })
+        })
+    }
+
+    fn embed_batch(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedBatchRequest,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+        let ctx = ctx.clone();
+        let texts = request.texts;
+        let expected_count = texts.len();
+        let span = tracing::info_span!(
+            "adapter.embedding.azure_openai.embed_batch",
+            provider = "azure_openai",
+            batch_size = expected_count
+        );
+        Box::pin(
+            async move {
+                self.embed_many(
+                    &ctx,
+                    OpenAiInput::Many(texts),
+                    expected_count,
+                    "azure_openai_embedding.embed_batch",
+                )
+                .await
+            }
+            .instrument(span),
+        )
+    }
+}
+
+fn deployment_url(endpoint: &str, deployment: &str, api_version: &str) -> Box<str> {
+    format!("{endpoint}/openai/deployments/{deployment}/embeddings?api-version={api_version}")
+        .into_boxed_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config(endpoint: &str) -> AzureOpenAiEmbeddingConfig {
+        AzureOpenAiEmbeddingConfig {
+            api_key: "example".into(), // pragma: allowlist secret
+            endpoint: Some(endpoint.into()),
+            deployment: Some("embed-small".into()),
+            api_version: Some("2024-06-01".into()),
+            timeout_ms: 1_000,
+            dimension: None,
+        }
+    }
+
+    #[test]
+    fn deployment_url_includes_api_version() {
+        assert_eq!(
+            deployment_url("https://res.openai.azure.com", "embed", "2024-02-01").as_ref(),
+            "https://res.openai.azure.com/openai/deployments/embed/embeddings?api-version=2024-02-01"
+        );
+    }
+
+    #[test]
+    fn new_requires_endpoint_and_deployment() {
+        let mut missing_endpoint = config("http://localhost");
+        missing_endpoint.endpoint = None;
+        assert!(AzureOpenAiEmbedding::new(&missing_endpoint).is_err());
+
+        let mut missing_deployment = config("http://localhost");
+        missing_deployment.deployment = None;
+        assert!(AzureOpenAiEmbedding::new(&missing_deployment).is_err());
+    }
+
+    #[tokio::test]
+    async fn embed_batch_targets_deployment_with_api_key_header() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/openai/deployments/embed-small/embeddings"))
+            .and(query_param("api-version", "2024-06-01"))
+            .and(header("api-key", "example")) // pragma: allowlist secret
+            .and(body_partial_json(json!({ "input": ["a", "b"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [
+                    { "embedding": [0.3, 0.4], "index": 1 },
+                    { "embedding": [0.1, 0.2], "index": 0 }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = AzureOpenAiEmbedding::new(&config(&format!("{}/", server.uri())))?;
+        let ctx = RequestContext::new_request();
+        let vectors = adapter
+            .embed_batch(
+                &ctx,
+                EmbedBatchRequest {
+                    texts: vec!["a".into(), "b".into()],
+                },
+            )
+            .await?;
+
+        assert_eq!(adapter.provider().id.as_str(), "azure_openai");
+        let slices: Vec<&[f32]> = vectors.iter().map(EmbeddingVector::as_slice).collect();
+        assert_eq!(slices, vec![&[0.1, 0.2][..], &[0.3, 0.4][..]]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http_errors_use_openai_mapping() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+                "error": { "message": "rate limited", "code": "429" }
+            })))
+            .mount(&server)
+            .await;
+
+        let adapter = AzureOpenAiEmbedding::new(&config(&server.uri()))?;
+        let ctx = RequestContext::new_request();
+        let error = adapter
+            .embed(&ctx, EmbedRequest { text: "a".into() })
+            .await
+            .expect_err("expected rate limit error");
+        assert_eq!(error.class, ErrorClass::Retriable);
+        assert_eq!(error.code, ErrorCode::new("core", "rate_limited"));
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 
 use semantic_code_ports::{
     BoxFuture, DetectDimensionRequest, EmbedBatchRequest, EmbedRequest, EmbeddingPort,
-    EmbeddingProviderInfo, EmbeddingVector, EmbeddingVectorFixed,
+    EmbeddingProviderInfo, EmbeddingVector, EmbeddingVectorFixed, IndexedEmbedding,
 };
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, RequestContext, Result};
 
@@ -93,4 +93,27 @@ where
             Ok(fixed.into_iter().map(EmbeddingVector::from).collect())
         })
     }
+
+    fn embed_batch_indexed(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedBatchRequest,
+    ) -> BoxFuture<'_, Result<Vec<IndexedEmbedding>>> {
+        let ctx = ctx.clone();
+        let future = self.inner.embed_batch_indexed(&ctx, request);
+        Box::pin(async move {
+            ctx.ensure_not_cancelled("embedding_fixed.embed_batch_indexed")?;
+            future
+                .await?
+                .into_iter()
+                .map(|IndexedEmbedding { index, vector }| {
+                    let fixed = EmbeddingVectorFixed::<D>::try_from(vector)?;
+                    Ok(IndexedEmbedding {
+                        index,
+                        vector: EmbeddingVector::from(fixed),
+                    })
+                })
+                .collect()
+        })
+    }
 }
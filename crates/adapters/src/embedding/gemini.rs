@@ -104,6 +104,7 @@ impl GeminiEmbedding {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("gemini").map_err(ErrorEnvelope::from)?,
             name: "Gemini".into(),
+            max_input_chars: None,
         };
         let embed_endpoint = format!("{base_url}/{model_resource}:embedContent").into_boxed_str();
         let batch_endpoint =
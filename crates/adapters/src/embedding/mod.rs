@@ -3,6 +3,9 @@
 #[cfg(feature = "openai")]
 pub mod openai;
 
+#[cfg(feature = "azure-openai")]
+pub mod azure_openai;
+
 #[cfg(feature = "gemini")]
 pub mod gemini;
 
@@ -19,3 +22,5 @@ pub mod onnx;
 pub mod ane;
 
 pub mod fixed;
+
+pub mod ordered;
@@ -86,6 +86,7 @@ impl OllamaEmbedding {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("ollama").map_err(ErrorEnvelope::from)?,
             name: "Ollama".into(),
+            max_input_chars: None,
         };
         let endpoint = format!("{base_url}{EMBED_PATH}").into_boxed_str();
 
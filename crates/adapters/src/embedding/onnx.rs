@@ -1,7 +1,11 @@
 //! ONNX embedding adapter (local).
 
+use ort::execution_providers::{
+    CUDAExecutionProvider, CoreMLExecutionProvider, ExecutionProvider, ExecutionProviderDispatch,
+};
 use ort::session::{Session, SessionInputValue, SessionInputs};
 use ort::value::TensorRef;
+use semantic_code_config::OnnxExecutionProvider;
 use semantic_code_ports::{
     DetectDimensionRequest, EmbedBatchRequest, EmbedRequest, EmbeddingPort, EmbeddingProviderId,
     EmbeddingProviderInfo, EmbeddingVector, EmbeddingVectorFixed,
@@ -22,6 +26,11 @@ const DEFAULT_MODEL_FILE: &str = "model.onnx";
 const DEFAULT_TOKENIZER_FILE: &str = "tokenizer.json";
 const DEFAULT_INPUT_IDS: &str = "input_ids";
 const DEFAULT_ATTENTION_MASK: &str = "attention_mask";
+/// Chars-per-token ratio used to turn the tokenizer limit into a char budget.
+///
+/// Source code tokenizes denser than prose (punctuation and identifiers split
+/// into many short tokens), so this stays below the usual ~4 for English text.
+const APPROX_CHARS_PER_TOKEN: usize = 3;
 
 /// ONNX embedding adapter configuration.
 #[derive(Debug, Clone)]
@@ -36,6 +45,12 @@ pub struct OnnxEmbeddingConfig {
     pub dimension: Option<u32>,
     /// Number of ONNX sessions to keep in the pool.
     pub session_pool_size: usize,
+    /// Requested execution provider (falls back to CPU when unavailable).
+    pub execution_provider: OnnxExecutionProvider,
+    /// Intra-op thread count (0 = ONNX Runtime default).
+    pub intra_op_threads: usize,
+    /// Inter-op thread count (0 = ONNX Runtime default).
+    pub inter_op_threads: usize,
 }
 
 impl OnnxEmbeddingConfig {
@@ -48,6 +63,9 @@ impl OnnxEmbeddingConfig {
             tokenizer_filename: None,
             dimension: None,
             session_pool_size: 1,
+            execution_provider: OnnxExecutionProvider::Cpu,
+            intra_op_threads: 0,
+            inter_op_threads: 0,
         }
     }
 }
@@ -130,10 +148,8 @@ impl OnnxEmbedding {
             configure_tokenizer(tokenizer, &tokenizer_config, &model_config, max_length)?;
 
         let pool_size = config.session_pool_size.max(1);
-        let primary_session = Session::builder()
-            .map_err(map_ort_error("onnx_session_builder_failed"))?
-            .commit_from_file(&model_path)
-            .map_err(map_ort_error("onnx_session_load_failed"))?;
+        let providers = resolve_execution_providers(config.execution_provider);
+        let primary_session = build_session(&model_path, &providers, config)?;
         let inputs = resolve_inputs(primary_session.inputs())?;
         let output_name = primary_session
             .outputs()
@@ -148,17 +164,14 @@ impl OnnxEmbedding {
             })?;
         let mut extras = Vec::with_capacity(pool_size.saturating_sub(1));
         for _ in 1..pool_size {
-            let session = Session::builder()
-                .map_err(map_ort_error("onnx_session_builder_failed"))?
-                .commit_from_file(&model_path)
-                .map_err(map_ort_error("onnx_session_load_failed"))?;
-            extras.push(session);
+            extras.push(build_session(&model_path, &providers, config)?);
         }
         let session_pool = Arc::new(SessionPool::new(primary_session, extras));
 
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("onnx").map_err(ErrorEnvelope::from)?,
             name: "ONNX".into(),
+            max_input_chars: max_input_chars(max_length),
         };
 
         Ok(Self {
@@ -778,6 +791,13 @@ fn resolve_max_length(
     }
 }
 
+/// Approximate the char budget that fits within the tokenizer's max length.
+fn max_input_chars(max_length: Option<usize>) -> Option<u32> {
+    max_length
+        .map(|tokens| tokens.saturating_mul(APPROX_CHARS_PER_TOKEN))
+        .and_then(|chars| u32::try_from(chars).ok())
+}
+
 fn configure_tokenizer(
     mut tokenizer: Tokenizer,
     tokenizer_config: &TokenizerConfig,
@@ -1039,7 +1059,80 @@ fn validate_dimension(hidden: usize, expected_dimension: Option<u32>) -> Result<
     Ok(())
 }
 
-fn map_ort_error(code: &'static str) -> impl FnOnce(ort::Error) -> ErrorEnvelope {
+fn build_session(
+    model_path: &Path,
+    providers: &[ExecutionProviderDispatch],
+    config: &OnnxEmbeddingConfig,
+) -> Result<Session> {
+    let mut builder = Session::builder()
+        .map_err(map_ort_error("onnx_session_builder_failed"))?
+        .with_execution_providers(providers)
+        .map_err(map_ort_error("onnx_execution_provider_failed"))?;
+    if config.intra_op_threads > 0 {
+        builder = builder
+            .with_intra_threads(config.intra_op_threads)
+            .map_err(map_ort_error("onnx_session_builder_failed"))?;
+    }
+    if config.inter_op_threads > 0 {
+        builder = builder
+            .with_inter_threads(config.inter_op_threads)
+            .map_err(map_ort_error("onnx_session_builder_failed"))?;
+    }
+    builder
+        .commit_from_file(model_path)
+        .map_err(map_ort_error("onnx_session_load_failed"))
+}
+
+/// Map the requested provider onto ORT dispatches; an empty list means CPU.
+fn resolve_execution_providers(requested: OnnxExecutionProvider) -> Vec<ExecutionProviderDispatch> {
+    let cuda = CUDAExecutionProvider::default();
+    let coreml = CoreMLExecutionProvider::default();
+    match requested {
+        OnnxExecutionProvider::Cpu => Vec::new(),
+        OnnxExecutionProvider::Cuda => {
+            if provider_available(&cuda) {
+                vec![cuda.build()]
+            } else {
+                warn_provider_unavailable(requested);
+                Vec::new()
+            }
+        },
+        OnnxExecutionProvider::CoreMl => {
+            if provider_available(&coreml) {
+                vec![coreml.build()]
+            } else {
+                warn_provider_unavailable(requested);
+                Vec::new()
+            }
+        },
+        OnnxExecutionProvider::Auto => {
+            let mut providers = Vec::new();
+            if provider_available(&cuda) {
+                providers.push(cuda.build());
+            }
+            if provider_available(&coreml) {
+                providers.push(coreml.build());
+            }
+            if providers.is_empty() {
+                tracing::debug!("no accelerated ONNX execution provider available; using CPU");
+            }
+            providers
+        },
+    }
+}
+
+fn provider_available(provider: &impl ExecutionProvider) -> bool {
+    provider.is_available().unwrap_or(false)
+}
+
+fn warn_provider_unavailable(requested: OnnxExecutionProvider) {
+    tracing::warn!(
+        requested = %requested,
+        "ONNX execution provider unavailable; falling back to CPU"
+    );
+}
+
+fn map_ort_error<R>(code: &'static str) -> impl FnOnce(ort::Error<R>) -> ErrorEnvelope {
     move |error| {
         ErrorEnvelope::unexpected(
             ErrorCode::new("embedding", code),
@@ -102,6 +102,7 @@ impl OpenAiEmbedding {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("openai").map_err(ErrorEnvelope::from)?,
             name: "OpenAI".into(),
+            max_input_chars: None,
         };
         let endpoint = format!("{base_url}/embeddings").into_boxed_str();
 
@@ -263,22 +264,22 @@ impl EmbeddingPort for OpenAiEmbedding {
 }
 
 #[derive(Debug, Serialize)]
-struct OpenAiEmbeddingRequest {
-    model: Box<str>,
-    input: OpenAiInput,
+pub(super) struct OpenAiEmbeddingRequest {
+    pub(super) model: Box<str>,
+    pub(super) input: OpenAiInput,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dimensions: Option<u32>,
+    pub(super) dimensions: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
-enum OpenAiInput {
+pub(super) enum OpenAiInput {
     Single(Box<str>),
     Many(Vec<Box<str>>),
 }
 
 #[derive(Debug, Deserialize)]
-struct OpenAiEmbeddingResponse {
+pub(super) struct OpenAiEmbeddingResponse {
     data: Vec<OpenAiEmbeddingDatum>,
 }
 
@@ -301,7 +302,7 @@ struct OpenAiErrorDetail {
     code: Option<String>,
 }
 
-fn normalize_required(label: &str, value: &str) -> Result<Box<str>> {
+pub(super) fn normalize_required(label: &str, value: &str) -> Result<Box<str>> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
         return Err(ErrorEnvelope::expected(
@@ -312,7 +313,10 @@ fn normalize_required(label: &str, value: &str) -> Result<Box<str>> {
     Ok(trimmed.to_owned().into_boxed_str())
 }
 
-fn normalize_optional_required(label: &str, value: Option<&str>) -> Result<Option<Box<str>>> {
+pub(super) fn normalize_optional_required(
+    label: &str,
+    value: Option<&str>,
+) -> Result<Option<Box<str>>> {
     let trimmed = match value {
         Some(value) => value.trim(),
         None => return Ok(None),
@@ -330,7 +334,7 @@ fn sanitize_text(text: Box<str>) -> Box<str> {
     if text.is_empty() { " ".into() } else { text }
 }
 
-fn sanitize_input(input: OpenAiInput) -> OpenAiInput {
+pub(super) fn sanitize_input(input: OpenAiInput) -> OpenAiInput {
     match input {
         OpenAiInput::Single(text) => OpenAiInput::Single(sanitize_text(text)),
         OpenAiInput::Many(texts) => {
@@ -339,11 +343,11 @@ fn sanitize_input(input: OpenAiInput) -> OpenAiInput {
     }
 }
 
-fn cancelled_error(operation: &'static str) -> ErrorEnvelope {
+pub(super) fn cancelled_error(operation: &'static str) -> ErrorEnvelope {
     ErrorEnvelope::cancelled("operation cancelled").with_metadata("operation", operation)
 }
 
-fn map_reqwest_error(error: &reqwest::Error) -> ErrorEnvelope {
+pub(super) fn map_reqwest_error(error: &reqwest::Error) -> ErrorEnvelope {
     if error.is_timeout() {
         return ErrorEnvelope::unexpected(
             ErrorCode::timeout(),
@@ -365,7 +369,7 @@ fn map_reqwest_error(error: &reqwest::Error) -> ErrorEnvelope {
     )
 }
 
-fn map_openai_http_error(status: StatusCode, payload: &[u8]) -> ErrorEnvelope {
+pub(super) fn map_openai_http_error(status: StatusCode, payload: &[u8]) -> ErrorEnvelope {
     let mut envelope = if let Ok(parsed) = serde_json::from_slice::<OpenAiErrorResponse>(payload) {
         let message = parsed.error.message;
         let mut envelope = match status.as_u16() {
@@ -412,7 +416,7 @@ fn map_openai_http_error(status: StatusCode, payload: &[u8]) -> ErrorEnvelope {
     envelope
 }
 
-fn map_embeddings(
+pub(super) fn map_embeddings(
     response: OpenAiEmbeddingResponse,
     expected_count: usize,
     expected_dimension: Option<u32>,
@@ -0,0 +1,215 @@
+//! Ordering guard for batch embedding results.
+
+use semantic_code_ports::{
+    BoxFuture, DetectDimensionRequest, EmbedBatchRequest, EmbedRequest, EmbeddingPort,
+    EmbeddingProviderInfo, EmbeddingVector, IndexedEmbedding,
+};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use std::sync::Arc;
+
+/// Wrapper that guarantees `embed_batch` output is aligned with its inputs.
+///
+/// The inner port is driven through [`EmbeddingPort::embed_batch_indexed`],
+/// and vectors are placed back at the index of the text they embed.
+#[derive(Clone)]
+pub struct OrderedEmbedding {
+    inner: Arc<dyn EmbeddingPort>,
+}
+
+impl OrderedEmbedding {
+    /// Wrap an embedding port with batch-order enforcement.
+    #[must_use]
+    pub fn new(inner: Arc<dyn EmbeddingPort>) -> Self {
+        Self { inner }
+    }
+}
+
+impl EmbeddingPort for OrderedEmbedding {
+    fn provider(&self) -> &EmbeddingProviderInfo {
+        self.inner.provider()
+    }
+
+    fn detect_dimension(
+        &self,
+        ctx: &RequestContext,
+        request: DetectDimensionRequest,
+    ) -> BoxFuture<'_, Result<u32>> {
+        self.inner.detect_dimension(ctx, request)
+    }
+
+    fn embed(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedRequest,
+    ) -> BoxFuture<'_, Result<EmbeddingVector>> {
+        self.inner.embed(ctx, request)
+    }
+
+    fn embed_batch(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedBatchRequest,
+    ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+        let expected = request.texts.len();
+        let future = self.inner.embed_batch_indexed(ctx, request);
+        Box::pin(async move {
+            let indexed = future.await?;
+            debug_assert_eq!(
+                indexed.len(),
+                expected,
+                "embed_batch must return one vector per input"
+            );
+            restore_input_order(indexed, expected)
+        })
+    }
+
+    fn embed_batch_indexed(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedBatchRequest,
+    ) -> BoxFuture<'_, Result<Vec<IndexedEmbedding>>> {
+        self.inner.embed_batch_indexed(ctx, request)
+    }
+}
+
+fn restore_input_order(
+    indexed: Vec<IndexedEmbedding>,
+    expected: usize,
+) -> Result<Vec<EmbeddingVector>> {
+    if indexed.len() != expected {
+        return Err(order_error(format!(
+            "embedding batch returned {} vectors for {expected} inputs",
+            indexed.len()
+        )));
+    }
+    let mut slots: Vec<Option<EmbeddingVector>> = vec![None; expected];
+    for IndexedEmbedding { index, vector } in indexed {
+        let slot = slots.get_mut(index).ok_or_else(|| {
+            order_error(format!(
+                "embedding batch returned out-of-range index {index} for {expected} inputs"
+            ))
+        })?;
+        if slot.replace(vector).is_some() {
+            return Err(order_error(format!(
+                "embedding batch returned duplicate index {index}"
+            )));
+        }
+    }
+    // Length matches and indices are unique and in range, so every slot is filled.
+    slots
+        .into_iter()
+        .map(|slot| slot.ok_or_else(|| order_error("embedding batch left an input unfilled")))
+        .collect()
+}
+
+fn order_error(message: impl Into<String>) -> ErrorEnvelope {
+    ErrorEnvelope::unexpected(
+        ErrorCode::new("embedding", "batch_order_mismatch"),
+        message,
+        ErrorClass::NonRetriable,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_ports::EmbeddingProviderId;
+
+    /// Adapter that completes batches in reverse and reports true indices.
+    struct ShufflingEmbedding {
+        provider: EmbeddingProviderInfo,
+    }
+
+    impl ShufflingEmbedding {
+        fn new() -> Result<Self> {
+            Ok(Self {
+                provider: EmbeddingProviderInfo {
+                    id: EmbeddingProviderId::parse("test").map_err(ErrorEnvelope::from)?,
+                    name: "shuffling".into(),
+                    max_input_chars: None,
+                },
+            })
+        }
+    }
+
+    fn vector_for(text: &str) -> EmbeddingVector {
+        EmbeddingVector::from_vec(vec![text.parse::<f32>().unwrap_or(f32::NAN)])
+    }
+
+    impl EmbeddingPort for ShufflingEmbedding {
+        fn provider(&self) -> &EmbeddingProviderInfo {
+            &self.provider
+        }
+
+        fn detect_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _request: DetectDimensionRequest,
+        ) -> BoxFuture<'_, Result<u32>> {
+            Box::pin(async { Ok(1) })
+        }
+
+        fn embed(
+            &self,
+            _ctx: &RequestContext,
+            request: EmbedRequest,
+        ) -> BoxFuture<'_, Result<EmbeddingVector>> {
+            Box::pin(async move { Ok(vector_for(&request.text)) })
+        }
+
+        fn embed_batch(
+            &self,
+            _ctx: &RequestContext,
+            request: EmbedBatchRequest,
+        ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+            Box::pin(async move { Ok(request.texts.iter().rev().map(|t| vector_for(t)).collect()) })
+        }
+
+        fn embed_batch_indexed(
+            &self,
+            _ctx: &RequestContext,
+            request: EmbedBatchRequest,
+        ) -> BoxFuture<'_, Result<Vec<IndexedEmbedding>>> {
+            Box::pin(async move {
+                Ok(request
+                    .texts
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(index, text)| IndexedEmbedding {
+                        index,
+                        vector: vector_for(text),
+                    })
+                    .collect())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn guard_restores_input_order_from_shuffling_adapter() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let texts: Vec<String> = ["0", "1", "2", "3"].map(String::from).to_vec();
+        let guard = OrderedEmbedding::new(Arc::new(ShufflingEmbedding::new()?));
+
+        let vectors = guard.embed_batch(&ctx, texts.clone().into()).await?;
+
+        let expected: Vec<EmbeddingVector> = texts.iter().map(|t| vector_for(t)).collect();
+        assert_eq!(vectors, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn restore_rejects_duplicate_indices() {
+        let indexed = vec![
+            IndexedEmbedding {
+                index: 0,
+                vector: vector_for("0"),
+            },
+            IndexedEmbedding {
+                index: 0,
+                vector: vector_for("1"),
+            },
+        ];
+        assert!(restore_input_order(indexed, 2).is_err());
+    }
+}
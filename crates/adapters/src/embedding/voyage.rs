@@ -102,6 +102,7 @@ impl VoyageEmbedding {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("voyage").map_err(ErrorEnvelope::from)?,
             name: "Voyage".into(),
+            max_input_chars: None,
         };
         let endpoint = format!("{base_url}/embeddings").into_boxed_str();
 
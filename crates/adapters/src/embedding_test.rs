@@ -26,6 +26,7 @@ impl TestEmbedding {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("test").map_err(ErrorEnvelope::from)?,
             name: "test".into(),
+            max_input_chars: None,
         };
         Ok(Self {
             provider,
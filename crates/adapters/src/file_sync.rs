@@ -2,6 +2,7 @@
 
 use crate::ignore::IgnoreMatcher;
 use semantic_code_config::SnapshotStorageMode;
+use semantic_code_domain::{CONTEXT_IGNORE_FILE, ignore_layers};
 use semantic_code_ports::{
     FileChangeSet, FileSyncInitOptions, FileSyncOptions, FileSyncPort, IgnoreMatchInput, IgnorePort,
 };
@@ -18,7 +19,8 @@ use tokio::sync::RwLock;
 const SNAPSHOT_VERSION: u32 = 1;
 const SNAPSHOT_DIR: &str = "sync";
 const SNAPSHOT_FILE_EXT: &str = "json";
-const CONTEXT_DIR_PATTERN: &str = ".context/";
+/// Hash recorded for an invalidated file; never matches a real content hash.
+const INVALIDATED_HASH: &str = "";
 
 /// Local filesystem-based file sync adapter.
 #[derive(Clone)]
@@ -30,7 +32,7 @@ pub struct LocalFileSync {
 
 #[derive(Debug, Clone)]
 struct SyncState {
-    ignore_patterns: Vec<Box<str>>,
+    ignore_layers: Vec<Vec<Box<str>>>,
     file_hashes: BTreeMap<Box<str>, Box<str>>,
     merkle_dag: MerkleDag,
 }
@@ -38,7 +40,7 @@ struct SyncState {
 impl Default for SyncState {
     fn default() -> Self {
         Self {
-            ignore_patterns: Vec::new(),
+            ignore_layers: Vec::new(),
             file_hashes: BTreeMap::new(),
             merkle_dag: MerkleDag::new(),
         }
@@ -132,20 +134,23 @@ impl LocalFileSync {
         Ok(())
     }
 
-    fn merged_ignore_patterns(input: Option<Vec<Box<str>>>) -> Vec<Box<str>> {
-        let mut patterns = input.unwrap_or_default();
-        if !patterns
-            .iter()
-            .any(|pattern| pattern.as_ref() == CONTEXT_DIR_PATTERN)
-        {
-            patterns.push(CONTEXT_DIR_PATTERN.into());
-        }
-        patterns
+    /// Build the same ignore layers as an index scan, reading `.contextignore`
+    /// from the codebase root (a missing or unreadable file is skipped).
+    async fn load_ignore_layers(&self, options: &FileSyncInitOptions) -> Vec<Vec<Box<str>>> {
+        let context_ignore =
+            tokio::fs::read_to_string(self.codebase_root.join(CONTEXT_IGNORE_FILE))
+                .await
+                .ok();
+        ignore_layers(
+            options.ignore_patterns.as_deref().unwrap_or_default(),
+            context_ignore.as_deref(),
+            &options.additional_ignore_patterns,
+        )
     }
 
-    async fn generate_file_hashes(&self, ignore_patterns: &[Box<str>]) -> Result<FileHashMap> {
+    async fn generate_file_hashes(&self, ignore_layers: &[Vec<Box<str>>]) -> Result<FileHashMap> {
         let mut file_hashes = BTreeMap::new();
-        self.scan_dir(&self.codebase_root, ignore_patterns, &mut file_hashes)
+        self.scan_dir(&self.codebase_root, ignore_layers, &mut file_hashes)
             .await?;
         Ok(file_hashes)
     }
@@ -153,7 +158,7 @@ impl LocalFileSync {
     async fn scan_dir(
         &self,
         dir: &Path,
-        ignore_patterns: &[Box<str>],
+        ignore_layers: &[Vec<Box<str>>],
         file_hashes: &mut FileHashMap,
     ) -> Result<()> {
         let mut pending = VecDeque::new();
@@ -173,7 +178,7 @@ impl LocalFileSync {
                 let path = entry.path();
                 let relative = self.relative_path_for(&path);
                 if let Some(ref relative) = relative
-                    && is_ignored(ignore_patterns, relative)
+                    && is_ignored(ignore_layers, relative)
                 {
                     continue;
                 }
@@ -264,7 +269,7 @@ impl FileSyncPort for LocalFileSync {
             ctx.ensure_not_cancelled("file_sync.initialize")?;
             sync.ensure_root_matches(&options.codebase_root)?;
 
-            let ignore_patterns = Self::merged_ignore_patterns(options.ignore_patterns);
+            let ignore_layers = sync.load_ignore_layers(&options).await;
             let snapshot = if sync.resolve_snapshot_mode() {
                 sync.load_snapshot().await?
             } else {
@@ -278,7 +283,7 @@ impl FileSyncPort for LocalFileSync {
             };
 
             let mut state = sync.state.write().await;
-            state.ignore_patterns = ignore_patterns;
+            state.ignore_layers = ignore_layers;
             state.file_hashes = file_hashes;
             state.merkle_dag = merkle_dag;
             drop(state);
@@ -295,16 +300,16 @@ impl FileSyncPort for LocalFileSync {
         let sync = self.clone();
         Box::pin(async move {
             ctx.ensure_not_cancelled("file_sync.check_for_changes")?;
-            let (previous_hashes, previous_dag, ignore_patterns) = {
+            let (previous_hashes, previous_dag, ignore_layers) = {
                 let state = sync.state.read().await;
                 (
                     state.file_hashes.clone(),
                     state.merkle_dag.clone(),
-                    state.ignore_patterns.clone(),
+                    state.ignore_layers.clone(),
                 )
             };
 
-            let new_hashes = sync.generate_file_hashes(&ignore_patterns).await?;
+            let new_hashes = sync.generate_file_hashes(&ignore_layers).await?;
             let new_dag = Self::build_merkle_dag(&new_hashes);
 
             let dag_changes = MerkleDag::compare(&previous_dag, &new_dag);
@@ -337,6 +342,39 @@ impl FileSyncPort for LocalFileSync {
         })
     }
 
+    fn invalidate_files(
+        &self,
+        ctx: &RequestContext,
+        relative_paths: Vec<Box<str>>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+        let ctx = ctx.clone();
+        let sync = self.clone();
+        Box::pin(async move {
+            ctx.ensure_not_cancelled("file_sync.invalidate_files")?;
+            let mut state = sync.state.write().await;
+            let mut invalidated = false;
+            for relative_path in &relative_paths {
+                if let Some(hash) = state.file_hashes.get_mut(relative_path) {
+                    *hash = INVALIDATED_HASH.into();
+                    invalidated = true;
+                }
+            }
+            if !invalidated {
+                return Ok(());
+            }
+            state.merkle_dag = Self::build_merkle_dag(&state.file_hashes);
+            let snapshot = sync.resolve_snapshot_mode().then(|| {
+                SyncSnapshot::from_state(SNAPSHOT_VERSION, &state.file_hashes, &state.merkle_dag)
+            });
+            drop(state);
+
+            if let Some(snapshot) = snapshot {
+                sync.write_snapshot(&snapshot).await?;
+            }
+            Ok(())
+        })
+    }
+
     fn delete_snapshot(
         &self,
         ctx: &RequestContext,
@@ -439,13 +477,13 @@ fn normalize_root_path(path: &Path) -> PathBuf {
     std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
 }
 
-fn is_ignored(ignore_patterns: &[Box<str>], relative_path: &str) -> bool {
-    if ignore_patterns.is_empty() {
+fn is_ignored(ignore_layers: &[Vec<Box<str>>], relative_path: &str) -> bool {
+    if ignore_layers.is_empty() {
         return false;
     }
     let matcher = IgnoreMatcher::new();
     matcher.is_ignored(&IgnoreMatchInput {
-        ignore_patterns: ignore_patterns.to_vec(),
+        ignore_layers: ignore_layers.to_vec(),
         relative_path: relative_path.to_owned().into_boxed_str(),
     })
 }
@@ -481,6 +519,7 @@ mod tests {
             FileSyncInitOptions {
                 codebase_root: root.clone(),
                 ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
             },
         )
         .await?;
@@ -493,4 +532,97 @@ mod tests {
         assert_eq!(decoded.1.serialize(), dag.serialize());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn change_detection_applies_index_ignore_layers() -> Result<()> {
+        let root = temp_dir("sync-ignore");
+        for (path, contents) in [
+            ("src/lib.rs", "fn lib() {}"),
+            ("src/skip.rs", "fn skip() {}"),
+            ("gen/out.rs", "fn generated() {}"),
+            ("target/debug.rs", "fn built() {}"),
+            (".contextignore", "# generated\ngen/\n"),
+        ] {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(ErrorEnvelope::from)?;
+            }
+            tokio::fs::write(path, contents)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+        }
+
+        let ctx = RequestContext::new_request();
+        let sync = LocalFileSync::new(root.clone(), SnapshotStorageMode::Disabled);
+        sync.initialize(
+            &ctx,
+            FileSyncInitOptions {
+                codebase_root: root.clone(),
+                ignore_patterns: Some(vec!["src/skip.rs".into()]),
+                additional_ignore_patterns: vec!["!src/skip.rs".into()],
+            },
+        )
+        .await?;
+        let changes = sync
+            .check_for_changes(&ctx, FileSyncOptions::default())
+            .await;
+        tokio::fs::remove_dir_all(&root)
+            .await
+            .map_err(ErrorEnvelope::from)?;
+
+        let changes = changes?;
+        let added: Vec<&str> = changes.added.iter().map(AsRef::as_ref).collect();
+        assert_eq!(added, ["src/lib.rs", "src/skip.rs"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalidated_files_are_reported_as_modified() -> Result<()> {
+        let root = temp_dir("sync-invalidate");
+        tokio::fs::create_dir_all(&root)
+            .await
+            .map_err(ErrorEnvelope::from)?;
+        for name in ["a.rs", "b.rs"] {
+            tokio::fs::write(root.join(name), name)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+        }
+
+        let ctx = RequestContext::new_request();
+        let sync = LocalFileSync::new(root.clone(), SnapshotStorageMode::Disabled);
+        sync.initialize(
+            &ctx,
+            FileSyncInitOptions {
+                codebase_root: root.clone(),
+                ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
+            },
+        )
+        .await?;
+        let first = sync
+            .check_for_changes(&ctx, FileSyncOptions::default())
+            .await;
+        let invalidated = sync
+            .invalidate_files(&ctx, vec!["b.rs".into(), "missing.rs".into()])
+            .await;
+        let second = sync
+            .check_for_changes(&ctx, FileSyncOptions::default())
+            .await;
+        let third = sync
+            .check_for_changes(&ctx, FileSyncOptions::default())
+            .await;
+        tokio::fs::remove_dir_all(&root)
+            .await
+            .map_err(ErrorEnvelope::from)?;
+
+        assert_eq!(first?.added.len(), 2);
+        invalidated?;
+        let second = second?;
+        assert!(second.added.is_empty());
+        assert_eq!(second.modified, vec![Box::<str>::from("b.rs")]);
+        assert_eq!(third?, FileChangeSet::default());
+        Ok(())
+    }
 }
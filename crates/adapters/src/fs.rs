@@ -2,16 +2,18 @@
 
 use semantic_code_ports::{
     FileSystemDirEntry, FileSystemEntryKind, FileSystemPort, FileSystemStat, PathPolicyPort,
-    SafeRelativePath,
+    SafeRelativePath, symlink_escape_code,
 };
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, RequestContext, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, UNIX_EPOCH};
+use tokio::io::AsyncReadExt;
 
 /// Local filesystem adapter using async IO.
 #[derive(Debug, Clone, Default)]
 pub struct LocalFileSystem {
     max_file_size_bytes: Option<u64>,
+    follow_symlinks: bool,
 }
 
 impl LocalFileSystem {
@@ -19,8 +21,22 @@ impl LocalFileSystem {
     pub const fn new(max_file_size_bytes: Option<u64>) -> Self {
         Self {
             max_file_size_bytes,
+            follow_symlinks: false,
         }
     }
+
+    /// Follow symlinks when listing directories.
+    ///
+    /// Off by default, which lists every symlink as
+    /// [`FileSystemEntryKind::Other`]. When on, a link resolving outside the
+    /// codebase root fails with `sync:symlink_escape`, and a link to a
+    /// directory already on the listed path is reported as `Other` so walks
+    /// terminate.
+    #[must_use]
+    pub const fn with_follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
 }
 
 impl FileSystemPort for LocalFileSystem {
@@ -30,6 +46,7 @@ impl FileSystemPort for LocalFileSystem {
         codebase_root: PathBuf,
         dir: SafeRelativePath,
     ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<FileSystemDirEntry>>> {
+        let follow_symlinks = self.follow_symlinks;
         Box::pin(async move {
             let full_path = codebase_root.join(dir.as_str());
             let mut entries = Vec::new();
@@ -39,14 +56,18 @@ impl FileSystemPort for LocalFileSystem {
 
             while let Some(entry) = read_dir.next_entry().await.map_err(ErrorEnvelope::from)? {
                 let file_type = entry.file_type().await.map_err(ErrorEnvelope::from)?;
+                let name = entry
+                    .file_name()
+                    .to_string_lossy()
+                    .to_string()
+                    .into_boxed_str();
                 // DirEntry::file_type() returns the raw d_type which does NOT
                 // follow symlinks — symlinks appear as neither file nor dir.
-                // Resolve through symlinks so a symlink forest is transparent.
                 let kind = if file_type.is_symlink() {
-                    match tokio::fs::metadata(entry.path()).await {
-                        Ok(meta) if meta.is_file() => FileSystemEntryKind::File,
-                        Ok(meta) if meta.is_dir() => FileSystemEntryKind::Directory,
-                        _ => FileSystemEntryKind::Other,
+                    if follow_symlinks {
+                        resolve_symlink(&codebase_root, &dir, &name, &entry.path()).await?
+                    } else {
+                        FileSystemEntryKind::Other
                     }
                 } else if file_type.is_file() {
                     FileSystemEntryKind::File
@@ -55,11 +76,6 @@ impl FileSystemPort for LocalFileSystem {
                 } else {
                     FileSystemEntryKind::Other
                 };
-                let name = entry
-                    .file_name()
-                    .to_string_lossy()
-                    .to_string()
-                    .into_boxed_str();
                 entries.push(FileSystemDirEntry { name, kind });
             }
 
@@ -102,6 +118,28 @@ impl FileSystemPort for LocalFileSystem {
         })
     }
 
+    fn read_file_head(
+        &self,
+        _ctx: &RequestContext,
+        codebase_root: PathBuf,
+        file: SafeRelativePath,
+        max_bytes: usize,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<u8>>> {
+        Box::pin(async move {
+            let full_path = codebase_root.join(file.as_str());
+            let handle = tokio::fs::File::open(&full_path)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+            let mut head = Vec::with_capacity(max_bytes.min(64 * 1024));
+            handle
+                .take(u64::try_from(max_bytes).unwrap_or(u64::MAX))
+                .read_to_end(&mut head)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+            Ok(head)
+        })
+    }
+
     fn stat(
         &self,
         _ctx: &RequestContext,
@@ -168,6 +206,61 @@ fn is_state_dir(path: &str) -> bool {
     path == ".context" || path.starts_with(".context/")
 }
 
+/// Classify a symlink found in `dir` by what it resolves to.
+///
+/// Targets outside the canonical root are refused. A directory target equal
+/// to the root or to `dir` or one of its ancestors would make the walk loop,
+/// so it is reported as `Other`; dangling links are `Other` too.
+async fn resolve_symlink(
+    codebase_root: &Path,
+    dir: &SafeRelativePath,
+    name: &str,
+    link: &Path,
+) -> Result<FileSystemEntryKind> {
+    let Ok(target) = tokio::fs::canonicalize(link).await else {
+        return Ok(FileSystemEntryKind::Other);
+    };
+    let root = tokio::fs::canonicalize(codebase_root)
+        .await
+        .map_err(ErrorEnvelope::from)?;
+    if !target.starts_with(&root) {
+        let relative_path = match dir.as_str() {
+            "." => name.to_owned(),
+            parent => format!("{parent}/{name}"),
+        };
+        return Err(ErrorEnvelope::expected(
+            symlink_escape_code(),
+            "symlink resolves outside the codebase root",
+        )
+        .with_metadata("path", relative_path));
+    }
+
+    let metadata = tokio::fs::metadata(&target)
+        .await
+        .map_err(ErrorEnvelope::from)?;
+    if metadata.is_file() {
+        return Ok(FileSystemEntryKind::File);
+    }
+    if !metadata.is_dir() {
+        return Ok(FileSystemEntryKind::Other);
+    }
+
+    let mut walked = codebase_root.to_path_buf();
+    let mut ancestors = vec![root];
+    for segment in dir.as_str().split('/').filter(|segment| *segment != ".") {
+        walked.push(segment);
+        let canonical = tokio::fs::canonicalize(&walked)
+            .await
+            .map_err(ErrorEnvelope::from)?;
+        ancestors.push(canonical);
+    }
+    if ancestors.contains(&target) {
+        Ok(FileSystemEntryKind::Other)
+    } else {
+        Ok(FileSystemEntryKind::Directory)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +314,148 @@ mod tests {
         assert_eq!(err.code, ErrorCode::invalid_input());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn read_file_head_returns_raw_leading_bytes() -> Result<()> {
+        let root = std::env::temp_dir().join("sca_fs_head_test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).map_err(ErrorEnvelope::from)?;
+        fs::write(root.join("blob.rs"), [b'a', 0xff, 0x00, b'b', b'c'])
+            .map_err(ErrorEnvelope::from)?;
+
+        let fs = LocalFileSystem::default();
+        let head = fs
+            .read_file_head(
+                &RequestContext::new_request(),
+                root.clone(),
+                SafeRelativePath::new("blob.rs")?,
+                3,
+            )
+            .await?;
+        assert_eq!(head, vec![b'a', 0xff, 0x00]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exists_reports_present_and_missing_paths() -> Result<()> {
+        let root = std::env::temp_dir().join("sca_fs_exists_test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).map_err(ErrorEnvelope::from)?;
+        fs::write(root.join("present.rs"), "fn main() {}").map_err(ErrorEnvelope::from)?;
+
+        let fs = LocalFileSystem::default();
+        let ctx = RequestContext::new_request();
+        assert!(
+            fs.exists(&ctx, root.clone(), SafeRelativePath::new("present.rs")?)
+                .await?
+        );
+        assert!(
+            !fs.exists(&ctx, root.clone(), SafeRelativePath::new("missing.rs")?)
+                .await?
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    async fn walk_files(fs: &LocalFileSystem, root: &Path) -> Result<Vec<String>> {
+        let ctx = RequestContext::new_request();
+        let mut dirs = vec![String::from(".")];
+        let mut files = Vec::new();
+        let mut listed = 0;
+        while let Some(dir) = dirs.pop() {
+            listed += 1;
+            assert!(listed < 32, "walk did not terminate");
+            let entries = fs
+                .read_dir(&ctx, root.to_path_buf(), SafeRelativePath::new(&dir)?)
+                .await?;
+            for entry in entries {
+                let path = match dir.as_str() {
+                    "." => entry.name.to_string(),
+                    parent => format!("{parent}/{}", entry.name),
+                };
+                match entry.kind {
+                    FileSystemEntryKind::Directory => dirs.push(path),
+                    FileSystemEntryKind::File => files.push(path),
+                    FileSystemEntryKind::Other => {},
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn followed_symlink_cycles_terminate() -> Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let root = std::env::temp_dir().join("sca_fs_symlink_cycle_test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("p")).map_err(ErrorEnvelope::from)?;
+        fs::create_dir_all(root.join("q")).map_err(ErrorEnvelope::from)?;
+        fs::write(root.join("p/a.rs"), "fn a() {}").map_err(ErrorEnvelope::from)?;
+        fs::write(root.join("q/b.rs"), "fn b() {}").map_err(ErrorEnvelope::from)?;
+        symlink(root.join("q"), root.join("p/to_q")).map_err(ErrorEnvelope::from)?;
+        symlink(root.join("p"), root.join("q/to_p")).map_err(ErrorEnvelope::from)?;
+        symlink("..", root.join("p/up")).map_err(ErrorEnvelope::from)?;
+        symlink("b.rs", root.join("q/b_link.rs")).map_err(ErrorEnvelope::from)?;
+
+        let followed = walk_files(
+            &LocalFileSystem::default().with_follow_symlinks(true),
+            &root,
+        )
+        .await?;
+        assert_eq!(
+            followed,
+            vec![
+                "p/a.rs",
+                "p/to_q/b.rs",
+                "p/to_q/b_link.rs",
+                "q/b.rs",
+                "q/b_link.rs",
+                "q/to_p/a.rs",
+            ]
+        );
+
+        let skipped = walk_files(&LocalFileSystem::default(), &root).await?;
+        assert_eq!(skipped, vec!["p/a.rs", "q/b.rs"]);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn followed_symlink_escaping_root_is_rejected() -> Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let base = std::env::temp_dir().join("sca_fs_symlink_escape_test");
+        let _ = fs::remove_dir_all(&base);
+        let root = base.join("repo");
+        fs::create_dir_all(root.join("src")).map_err(ErrorEnvelope::from)?;
+        fs::create_dir_all(base.join("outside")).map_err(ErrorEnvelope::from)?;
+        symlink(base.join("outside"), root.join("src/escape")).map_err(ErrorEnvelope::from)?;
+
+        let ctx = RequestContext::new_request();
+        let error = LocalFileSystem::default()
+            .with_follow_symlinks(true)
+            .read_dir(&ctx, root.clone(), SafeRelativePath::new("src")?)
+            .await
+            .expect_err("expected symlink escape error");
+        assert_eq!(error.code, symlink_escape_code());
+        assert_eq!(
+            error.metadata.get("path").map(String::as_str),
+            Some("src/escape")
+        );
+
+        let entries = LocalFileSystem::default()
+            .read_dir(&ctx, root, SafeRelativePath::new("src")?)
+            .await?;
+        assert_eq!(
+            entries,
+            vec![FileSystemDirEntry {
+                name: "escape".into(),
+                kind: FileSystemEntryKind::Other,
+            }]
+        );
+        Ok(())
+    }
 }
@@ -21,33 +21,46 @@ impl IgnorePort for IgnoreMatcher {
             return false;
         }
 
-        let patterns = normalize_patterns(&input.ignore_patterns);
-        if patterns.is_empty() {
-            return false;
-        }
+        input
+            .ignore_layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer_verdict(&path_segments, layer))
+            .unwrap_or(false)
+    }
+}
 
-        for pattern in patterns {
-            let pattern_segments = split_segments(&pattern);
-            if pattern_segments.is_empty() {
-                continue;
-            }
-            if matches_segments(&path_segments, &pattern_segments) {
-                return true;
-            }
+/// Verdict of one pattern layer: `Some(true)` excludes, `Some(false)`
+/// re-includes, `None` when no pattern in the layer matches.
+fn layer_verdict(path_segments: &[&str], patterns: &[Box<str>]) -> Option<bool> {
+    let mut excluded = false;
+    for (negated, pattern) in normalize_patterns(patterns) {
+        let pattern_segments = split_segments(&pattern);
+        if pattern_segments.is_empty() || !matches_segments(path_segments, &pattern_segments) {
+            continue;
         }
-        false
+        if negated {
+            return Some(false);
+        }
+        excluded = true;
     }
+    excluded.then_some(true)
 }
 
-fn normalize_patterns(patterns: &[Box<str>]) -> Vec<String> {
+/// Normalize patterns into sorted `(negated, pattern)` pairs.
+fn normalize_patterns(patterns: &[Box<str>]) -> Vec<(bool, String)> {
     let mut normalized = patterns
         .iter()
         .filter_map(|pattern| {
             let trimmed = pattern.trim();
-            if trimmed.is_empty() {
+            let (negated, body) = trimmed
+                .strip_prefix('!')
+                .map_or((false, trimmed), |body| (true, body));
+            let body = normalize_path(body);
+            if body.is_empty() {
                 return None;
             }
-            Some(normalize_path(trimmed))
+            Some((negated, body))
         })
         .collect::<Vec<_>>();
     normalized.sort();
@@ -125,7 +138,7 @@ mod tests {
         let patterns = vec!["node_modules/".into(), "target/".into()];
 
         let input = IgnoreMatchInput {
-            ignore_patterns: patterns,
+            ignore_layers: vec![patterns],
             relative_path: "src/node_modules/pkg/index.js".into(),
         };
         assert!(matcher.is_ignored(&input));
@@ -135,7 +148,7 @@ mod tests {
     fn ignores_nested_segment_sequences() {
         let matcher = IgnoreMatcher::new();
         let input = IgnoreMatchInput {
-            ignore_patterns: vec!["src/generated".into()],
+            ignore_layers: vec![vec!["src/generated".into()]],
             relative_path: "src/generated/code.rs".into(),
         };
         assert!(matcher.is_ignored(&input));
@@ -145,11 +158,11 @@ mod tests {
     fn ignore_order_is_deterministic() {
         let matcher = IgnoreMatcher::new();
         let input_a = IgnoreMatchInput {
-            ignore_patterns: vec!["target/".into(), "node_modules/".into()],
+            ignore_layers: vec![vec!["target/".into(), "node_modules/".into()]],
             relative_path: "node_modules/pkg/index.js".into(),
         };
         let input_b = IgnoreMatchInput {
-            ignore_patterns: vec!["node_modules/".into(), "target/".into()],
+            ignore_layers: vec![vec!["node_modules/".into(), "target/".into()]],
             relative_path: "node_modules/pkg/index.js".into(),
         };
         assert_eq!(matcher.is_ignored(&input_a), matcher.is_ignored(&input_b));
@@ -159,7 +172,7 @@ mod tests {
     fn ignores_wildcard_extension_patterns() {
         let matcher = IgnoreMatcher::new();
         let input = IgnoreMatchInput {
-            ignore_patterns: vec!["*.json".into(), "*.ipynb".into()],
+            ignore_layers: vec![vec!["*.json".into(), "*.ipynb".into()]],
             relative_path: "docs/report.ipynb".into(),
         };
         assert!(matcher.is_ignored(&input));
@@ -169,13 +182,13 @@ mod tests {
     fn dotted_patterns_match_exact_segment_only() {
         let matcher = IgnoreMatcher::new();
         let exact_match = IgnoreMatchInput {
-            ignore_patterns: vec![".env".into()],
+            ignore_layers: vec![vec![".env".into()]],
             relative_path: "config/.env".into(),
         };
         assert!(matcher.is_ignored(&exact_match));
 
         let suffix_only_match = IgnoreMatchInput {
-            ignore_patterns: vec![".env".into()],
+            ignore_layers: vec![vec![".env".into()]],
             relative_path: "config/production.env".into(),
         };
         assert!(!matcher.is_ignored(&suffix_only_match));
@@ -185,7 +198,7 @@ mod tests {
     fn dotted_pattern_does_not_match_suffixes() {
         let matcher = IgnoreMatcher::new();
         let input = IgnoreMatchInput {
-            ignore_patterns: vec![".env".into()],
+            ignore_layers: vec![vec![".env".into()]],
             relative_path: "config/production.env".into(),
         };
         assert!(!matcher.is_ignored(&input));
@@ -195,9 +208,42 @@ mod tests {
     fn normalizes_windows_separators() {
         let matcher = IgnoreMatcher::new();
         let input = IgnoreMatchInput {
-            ignore_patterns: vec!["target/".into()],
+            ignore_layers: vec![vec!["target/".into()]],
             relative_path: "target\\out\\file.txt".into(),
         };
         assert!(matcher.is_ignored(&input));
     }
+
+    #[test]
+    fn negation_wins_within_a_layer() {
+        let matcher = IgnoreMatcher::new();
+        let input = |path: &str| IgnoreMatchInput {
+            ignore_layers: vec![vec!["!keep.json".into(), "*.json".into()]],
+            relative_path: path.into(),
+        };
+        assert!(!matcher.is_ignored(&input("config/keep.json")));
+        assert!(matcher.is_ignored(&input("config/other.json")));
+    }
+
+    #[test]
+    fn higher_layers_override_lower_layers() {
+        let matcher = IgnoreMatcher::new();
+        let input = |layers: Vec<Vec<Box<str>>>| IgnoreMatchInput {
+            ignore_layers: layers,
+            relative_path: "target/out.rs".into(),
+        };
+        assert!(!matcher.is_ignored(&input(vec![
+            vec!["target/".into()],
+            vec!["!target/".into()],
+        ])));
+        assert!(matcher.is_ignored(&input(vec![
+            vec!["!target/".into()],
+            vec!["target/".into()],
+        ])));
+        assert!(!matcher.is_ignored(&input(vec![
+            vec!["target/".into()],
+            vec!["!target/".into()],
+            vec!["node_modules/".into()],
+        ])));
+    }
 }
@@ -13,6 +13,7 @@ mod fs;
 mod ignore;
 mod log_sink;
 mod logger;
+mod otel;
 mod self_check;
 mod splitter;
 mod telemetry;
@@ -29,12 +30,14 @@ mod placeholder {
 }
 
 pub use cache::{
-    CacheLookup, CacheSource, CachingEmbedding, DiskCacheProvider, EmbeddingCache,
+    CacheKeyHash, CacheLookup, CacheSource, CachingEmbedding, DiskCacheProvider, EmbeddingCache,
     EmbeddingCacheConfig,
 };
 pub use calibration::LocalCalibrationAdapter;
 #[cfg(all(target_os = "macos", target_arch = "aarch64", feature = "ane"))]
 pub use embedding::ane::{AneEmbedding, AneEmbeddingConfig, AneExecutionMode};
+#[cfg(feature = "azure-openai")]
+pub use embedding::azure_openai::{AzureOpenAiEmbedding, AzureOpenAiEmbeddingConfig};
 pub use embedding::fixed::FixedDimensionEmbedding;
 #[cfg(feature = "gemini")]
 pub use embedding::gemini::{GeminiEmbedding, GeminiEmbeddingConfig};
@@ -44,6 +47,7 @@ pub use embedding::ollama::{OllamaEmbedding, OllamaEmbeddingConfig};
 pub use embedding::onnx::{OnnxEmbedding, OnnxEmbeddingConfig, OnnxEmbeddingFixed};
 #[cfg(feature = "openai")]
 pub use embedding::openai::{OpenAiEmbedding, OpenAiEmbeddingConfig};
+pub use embedding::ordered::OrderedEmbedding;
 #[cfg(feature = "voyage")]
 pub use embedding::voyage::{VoyageEmbedding, VoyageEmbeddingConfig};
 pub use embedding_test::TestEmbedding;
@@ -52,6 +56,12 @@ pub use fs::{LocalFileSystem, LocalPathPolicy};
 pub use ignore::IgnoreMatcher;
 pub use log_sink::{LogSink, StderrLogSink};
 pub use logger::JsonLogger;
+#[cfg(feature = "otlp")]
+pub use otel::OtlpHttpExporter;
+pub use otel::{
+    DEFAULT_OTEL_SERVICE_NAME, OTEL_ATTR_ERROR_TYPE, OTEL_ATTR_OPERATION, OTEL_ATTR_SERVICE_NAME,
+    OtelSpan, OtelSpanExporter, OtelTelemetry,
+};
 pub use placeholder::adapters_crate_version;
 pub use self_check::{
     SelfCheckEmbedding, SelfCheckFileSync, SelfCheckFileSystem, SelfCheckIgnore,
@@ -68,6 +78,7 @@ pub use vectordb::milvus::{MilvusIndexConfig, MilvusIndexSpec};
 pub use vectordb::milvus::{MilvusRestConfig, MilvusRestVectorDb};
 pub use vectordb_local::{
     DfrrReadyStatePrewarmRequest, DfrrReadyStateRequirement, LocalVectorDb, LocalVectorDbBuilder,
+    read_v1_snapshot_payload,
 };
 
 #[cfg(test)]
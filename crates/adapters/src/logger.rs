@@ -107,6 +107,7 @@ const fn should_log(min_level: LogLevel, level: LogLevel) -> bool {
 
 const fn level_rank(level: LogLevel) -> u8 {
     match level {
+        LogLevel::Trace => 0,
         LogLevel::Debug => 10,
         LogLevel::Info => 20,
         LogLevel::Warn => 30,
@@ -116,6 +117,7 @@ const fn level_rank(level: LogLevel) -> u8 {
 
 fn level_str(level: LogLevel) -> String {
     match level {
+        LogLevel::Trace => "trace".to_string(),
         LogLevel::Debug => "debug".to_string(),
         LogLevel::Info => "info".to_string(),
         LogLevel::Warn => "warn".to_string(),
@@ -0,0 +1,427 @@
+//! OpenTelemetry-compatible telemetry adapter (spans with semantic-convention attributes).
+//!
+//! Timers become spans carrying `service.name`, `operation`, and any
+//! request tags. Failure counters (`*.failed` / `*_failed`) become
+//! zero-duration error spans tagged with `error.type`. Other counters are
+//! metrics, not traces, and are left to [`crate::JsonTelemetry`].
+
+use semantic_code_ports::{TelemetryPort, TelemetryTags, TelemetryTimer};
+use semantic_code_shared::{REDACTED, is_secret_key};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// OpenTelemetry resource attribute: logical service name.
+pub const OTEL_ATTR_SERVICE_NAME: &str = "service.name";
+/// Span attribute: internal operation (telemetry event) name.
+pub const OTEL_ATTR_OPERATION: &str = "operation";
+/// OpenTelemetry span attribute: class of error for failed operations.
+pub const OTEL_ATTR_ERROR_TYPE: &str = "error.type";
+/// Default `service.name` when none is configured.
+pub const DEFAULT_OTEL_SERVICE_NAME: &str = "semantic-code-agx";
+
+const ERROR_CODE_TAG: &str = "errorCode";
+
+/// A finished span in OpenTelemetry shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtelSpan {
+    /// Span name (the internal operation name).
+    pub name: Box<str>,
+    /// 32-char lowercase hex trace id shared by spans of one adapter.
+    pub trace_id: Box<str>,
+    /// 16-char lowercase hex span id.
+    pub span_id: Box<str>,
+    /// Start time (ns since the Unix epoch).
+    pub start_time_unix_nano: u64,
+    /// End time (ns since the Unix epoch).
+    pub end_time_unix_nano: u64,
+    /// Span attributes keyed by OpenTelemetry attribute name.
+    pub attributes: BTreeMap<Box<str>, Box<str>>,
+    /// Whether the span represents a failure (OpenTelemetry status `ERROR`).
+    pub is_error: bool,
+}
+
+/// Destination for finished spans.
+pub trait OtelSpanExporter: Send + Sync {
+    /// Export one finished span. Must not block on network I/O.
+    fn export(&self, span: OtelSpan);
+}
+
+/// Telemetry adapter that maps telemetry events to OpenTelemetry spans.
+#[derive(Clone)]
+pub struct OtelTelemetry {
+    exporter: Arc<dyn OtelSpanExporter>,
+    service_name: Box<str>,
+    trace_id: Box<str>,
+    next_span_id: Arc<AtomicU64>,
+}
+
+impl OtelTelemetry {
+    /// Create an adapter that sends spans to `exporter`.
+    #[must_use]
+    pub fn new(exporter: Arc<dyn OtelSpanExporter>) -> Self {
+        Self {
+            exporter,
+            service_name: DEFAULT_OTEL_SERVICE_NAME.into(),
+            trace_id: new_trace_id(),
+            next_span_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// Override the `service.name` attribute.
+    #[must_use]
+    pub fn with_service_name(mut self, service_name: impl Into<Box<str>>) -> Self {
+        self.service_name = service_name.into();
+        self
+    }
+
+    fn span(
+        &self,
+        name: &str,
+        start_time_unix_nano: u64,
+        end_time_unix_nano: u64,
+        tags: Option<&TelemetryTags>,
+        error_type: Option<Box<str>>,
+    ) -> OtelSpan {
+        let mut attributes = BTreeMap::new();
+        if let Some(tags) = tags {
+            for (key, value) in tags {
+                let value = if is_secret_key(key) {
+                    REDACTED.into()
+                } else {
+                    value.clone()
+                };
+                attributes.insert(key.clone(), value);
+            }
+        }
+        attributes.insert(OTEL_ATTR_SERVICE_NAME.into(), self.service_name.clone());
+        attributes.insert(OTEL_ATTR_OPERATION.into(), name.into());
+        let is_error = error_type.is_some();
+        if let Some(error_type) = error_type {
+            attributes.insert(OTEL_ATTR_ERROR_TYPE.into(), error_type);
+        }
+        let span_id = self.next_span_id.fetch_add(1, Ordering::Relaxed);
+        OtelSpan {
+            name: name.into(),
+            trace_id: self.trace_id.clone(),
+            span_id: format!("{span_id:016x}").into_boxed_str(),
+            start_time_unix_nano,
+            end_time_unix_nano,
+            attributes,
+            is_error,
+        }
+    }
+}
+
+impl TelemetryPort for OtelTelemetry {
+    fn increment_counter(&self, name: &str, _value: u64, tags: Option<&TelemetryTags>) {
+        if !is_failure_counter(name) {
+            return;
+        }
+        let error_type = tags
+            .and_then(|tags| tags.get(ERROR_CODE_TAG))
+            .cloned()
+            .unwrap_or_else(|| name.into());
+        let now = now_unix_nanos();
+        self.exporter
+            .export(self.span(name, now, now, tags, Some(error_type)));
+    }
+
+    fn record_timer_ms(&self, name: &str, duration_ms: u64, tags: Option<&TelemetryTags>) {
+        let end = now_unix_nanos();
+        let start = end.saturating_sub(duration_ms.saturating_mul(1_000_000));
+        self.exporter
+            .export(self.span(name, start, end, tags, None));
+    }
+
+    fn start_timer(&self, name: &str, tags: Option<&TelemetryTags>) -> Box<dyn TelemetryTimer> {
+        Box::new(OtelTimer {
+            telemetry: self.clone(),
+            name: name.into(),
+            tags: tags.cloned(),
+            start_time_unix_nano: now_unix_nanos(),
+            started_at: Instant::now(),
+            stopped: AtomicBool::new(false),
+        })
+    }
+}
+
+struct OtelTimer {
+    telemetry: OtelTelemetry,
+    name: Box<str>,
+    tags: Option<TelemetryTags>,
+    start_time_unix_nano: u64,
+    started_at: Instant,
+    stopped: AtomicBool,
+}
+
+impl TelemetryTimer for OtelTimer {
+    fn stop(&self) {
+        if self.stopped.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let elapsed = u64::try_from(self.started_at.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let end = self.start_time_unix_nano.saturating_add(elapsed);
+        let span = self.telemetry.span(
+            &self.name,
+            self.start_time_unix_nano,
+            end,
+            self.tags.as_ref(),
+            None,
+        );
+        self.telemetry.exporter.export(span);
+    }
+}
+
+fn is_failure_counter(name: &str) -> bool {
+    name.ends_with(".failed") || name.ends_with("_failed")
+}
+
+fn new_trace_id() -> Box<str> {
+    let seed = format!("{}:{}", now_unix_nanos(), std::process::id());
+    format!("{:x}", md5::compute(seed.as_bytes())).into_boxed_str()
+}
+
+fn now_unix_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| u64::try_from(duration.as_nanos()).ok())
+        .unwrap_or_default()
+}
+
+/// Build an OTLP/HTTP JSON `ExportTraceServiceRequest` body for `spans`.
+#[cfg(feature = "otlp")]
+fn otlp_traces_payload(service_name: &str, spans: &[OtelSpan]) -> serde_json::Value {
+    use serde_json::json;
+
+    let string_attr =
+        |key: &str, value: &str| json!({ "key": key, "value": { "stringValue": value } });
+    let spans: Vec<serde_json::Value> = spans
+        .iter()
+        .map(|span| {
+            let attributes: Vec<serde_json::Value> = span
+                .attributes
+                .iter()
+                .map(|(key, value)| string_attr(key, value))
+                .collect();
+            json!({
+                "traceId": span.trace_id,
+                "spanId": span.span_id,
+                "name": span.name,
+                "kind": 1,
+                "startTimeUnixNano": span.start_time_unix_nano.to_string(),
+                "endTimeUnixNano": span.end_time_unix_nano.to_string(),
+                "attributes": attributes,
+                "status": { "code": if span.is_error { 2 } else { 0 } },
+            })
+        })
+        .collect();
+    json!({
+        "resourceSpans": [{
+            "resource": { "attributes": [string_attr(OTEL_ATTR_SERVICE_NAME, service_name)] },
+            "scopeSpans": [{
+                "scope": { "name": DEFAULT_OTEL_SERVICE_NAME },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+/// Max spans sent per OTLP request.
+#[cfg(feature = "otlp")]
+const OTLP_BATCH_SIZE: usize = 256;
+/// Max time a partial batch waits before it is sent.
+#[cfg(feature = "otlp")]
+const OTLP_FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+/// Per-request timeout for OTLP exports.
+#[cfg(feature = "otlp")]
+const OTLP_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Exporter that batches spans and POSTs them as OTLP/HTTP JSON to
+/// `<endpoint>/v1/traces` from a background thread.
+///
+/// Dropping the exporter flushes pending spans and joins the worker.
+#[cfg(feature = "otlp")]
+pub struct OtlpHttpExporter {
+    sender: std::sync::Mutex<Option<std::sync::mpsc::Sender<OtelSpan>>>,
+    worker: std::sync::Mutex<Option<std::thread::JoinHandle<()>>>,
+}
+
+#[cfg(feature = "otlp")]
+impl OtlpHttpExporter {
+    /// Start the export worker for `endpoint` (e.g. `http://localhost:4318`).
+    pub fn new(endpoint: &str, service_name: &str) -> semantic_code_shared::Result<Self> {
+        use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope};
+
+        let url = format!("{}/v1/traces", endpoint.trim_end_matches('/'));
+        let client = reqwest::Client::builder()
+            .timeout(OTLP_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|error| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::new("telemetry", "otlp_client_failed"),
+                    format!("failed to build OTLP client: {error}"),
+                    ErrorClass::NonRetriable,
+                )
+            })?;
+        let service_name: Box<str> = service_name.into();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let worker = std::thread::Builder::new()
+            .name("sca-otlp-export".to_owned())
+            .spawn(move || run_otlp_worker(&client, &url, &service_name, &receiver))
+            .map_err(ErrorEnvelope::from)?;
+        Ok(Self {
+            sender: std::sync::Mutex::new(Some(sender)),
+            worker: std::sync::Mutex::new(Some(worker)),
+        })
+    }
+}
+
+#[cfg(feature = "otlp")]
+impl OtelSpanExporter for OtlpHttpExporter {
+    fn export(&self, span: OtelSpan) {
+        if let Ok(guard) = self.sender.lock()
+            && let Some(sender) = guard.as_ref()
+        {
+            let _ = sender.send(span);
+        }
+    }
+}
+
+#[cfg(feature = "otlp")]
+impl Drop for OtlpHttpExporter {
+    fn drop(&mut self) {
+        if let Ok(mut sender) = self.sender.lock() {
+            sender.take();
+        }
+        if let Ok(mut worker) = self.worker.lock()
+            && let Some(worker) = worker.take()
+        {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(feature = "otlp")]
+fn run_otlp_worker(
+    client: &reqwest::Client,
+    url: &str,
+    service_name: &str,
+    receiver: &std::sync::mpsc::Receiver<OtelSpan>,
+) {
+    use std::sync::mpsc::RecvTimeoutError;
+
+    let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    else {
+        tracing::warn!("failed to start OTLP export runtime; spans will be dropped");
+        return;
+    };
+    let send = |batch: &mut Vec<OtelSpan>| {
+        if batch.is_empty() {
+            return;
+        }
+        let payload = otlp_traces_payload(service_name, batch);
+        let spans = batch.len();
+        batch.clear();
+        let result = runtime.block_on(async {
+            client
+                .post(url)
+                .json(&payload)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+        });
+        if let Err(error) = result {
+            tracing::warn!(spans, %error, "OTLP span export failed");
+        }
+    };
+    let mut batch = Vec::with_capacity(OTLP_BATCH_SIZE);
+    loop {
+        match receiver.recv_timeout(OTLP_FLUSH_INTERVAL) {
+            Ok(span) => {
+                batch.push(span);
+                if batch.len() >= OTLP_BATCH_SIZE {
+                    send(&mut batch);
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => send(&mut batch),
+            Err(RecvTimeoutError::Disconnected) => {
+                send(&mut batch);
+                return;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingExporter {
+        spans: Mutex<Vec<OtelSpan>>,
+    }
+
+    impl OtelSpanExporter for RecordingExporter {
+        fn export(&self, span: OtelSpan) {
+            self.spans.lock().expect("spans lock").push(span);
+        }
+    }
+
+    #[test]
+    fn failure_counters_become_error_spans_and_other_counters_are_skipped() {
+        let exporter = Arc::new(RecordingExporter::default());
+        let telemetry = OtelTelemetry::new(exporter.clone()).with_service_name("sca-test");
+        let mut tags = TelemetryTags::new();
+        tags.insert(ERROR_CODE_TAG.into(), "ERR_VECTOR_TIMEOUT".into());
+        tags.insert("apiKey".into(), "secret".into());
+
+        telemetry.increment_counter("index.embed_batch.items", 4, None);
+        telemetry.increment_counter("index.insert_batch_failed", 1, Some(&tags));
+
+        let spans = exporter.spans.lock().expect("spans lock").clone();
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert!(span.is_error);
+        assert_eq!(span.trace_id.len(), 32);
+        assert_eq!(
+            span.attributes
+                .get(OTEL_ATTR_SERVICE_NAME)
+                .map(AsRef::as_ref),
+            Some("sca-test")
+        );
+        assert_eq!(
+            span.attributes.get(OTEL_ATTR_ERROR_TYPE).map(AsRef::as_ref),
+            Some("ERR_VECTOR_TIMEOUT")
+        );
+        assert_eq!(
+            span.attributes.get("apiKey").map(AsRef::as_ref),
+            Some(REDACTED)
+        );
+    }
+
+    #[cfg(feature = "otlp")]
+    #[test]
+    fn otlp_payload_uses_string_attributes_and_error_status() {
+        let exporter = Arc::new(RecordingExporter::default());
+        let telemetry = OtelTelemetry::new(exporter.clone());
+        telemetry.record_timer_ms("index.embed_batch", 5, None);
+        telemetry.increment_counter("backend.search.failed", 1, None);
+
+        let spans = exporter.spans.lock().expect("spans lock").clone();
+        let payload = otlp_traces_payload("svc", &spans);
+        let resource = &payload["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0]["value"]["stringValue"],
+            "svc"
+        );
+        let span_json = &resource["scopeSpans"][0]["spans"];
+        assert_eq!(span_json[0]["status"]["code"], 0);
+        assert_eq!(span_json[1]["status"]["code"], 2);
+    }
+}
@@ -1,5 +1,6 @@
 //! In-memory adapters for CLI self-check smoke tests.
 
+use semantic_code_domain::SearchExclusions;
 use semantic_code_ports::{
     CodeChunk, CollectionName, DetectDimensionRequest, EmbedBatchRequest, EmbedRequest,
     EmbeddingPort, EmbeddingProviderInfo, EmbeddingVector, FileChangeSet, FileSyncInitOptions,
@@ -30,6 +31,7 @@ impl SelfCheckEmbedding {
             id: semantic_code_ports::EmbeddingProviderId::parse("openai")
                 .map_err(ErrorEnvelope::from)?,
             name: "self-check".into(),
+            max_input_chars: None,
         };
         Ok(Self {
             provider,
@@ -254,6 +256,7 @@ impl SelfCheckVectorDb {
                         top_k: Some(limit),
                         threshold: None,
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                     },
                 )
                 .await?;
@@ -673,6 +676,14 @@ impl FileSyncPort for SelfCheckFileSync {
         Box::pin(async move { Ok(FileChangeSet::default()) })
     }
 
+    fn invalidate_files(
+        &self,
+        _ctx: &RequestContext,
+        _relative_paths: Vec<Box<str>>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+
     fn delete_snapshot(
         &self,
         _ctx: &RequestContext,
@@ -2,13 +2,14 @@
 
 use semantic_code_domain::CollectionName;
 use semantic_code_ports::{
-    BoxFuture, HybridSearchBatchRequest, HybridSearchData, HybridSearchRequest, HybridSearchResult,
-    VectorDbPort, VectorDbProviderInfo, VectorDocumentForInsert, VectorSearchRequest,
-    VectorSearchResponse,
+    BoxFuture, EmbeddingVector, HybridSearchBatchRequest, HybridSearchData, HybridSearchRequest,
+    HybridSearchResult, InsertOutcome, PageToken, QueryPage, VectorDbPort, VectorDbProviderInfo,
+    VectorDocumentForInsert, VectorSearchRequest, VectorSearchResponse,
 };
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, RequestContext, Result};
 use serde_json::Value;
 use std::collections::BTreeMap;
+use std::num::NonZeroU32;
 
 /// Wrapper that enforces a compile-time vector dimension.
 #[derive(Debug, Clone)]
@@ -141,6 +142,22 @@ where
         self.inner.has_collection(ctx, collection_name)
     }
 
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> BoxFuture<'_, Result<Option<u32>>> {
+        self.inner.collection_dimension(ctx, collection_name)
+    }
+
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> BoxFuture<'_, Result<Option<u64>>> {
+        self.inner.count(ctx, collection_name)
+    }
+
     fn list_collections(&self, ctx: &RequestContext) -> BoxFuture<'_, Result<Vec<CollectionName>>> {
         self.inner.list_collections(ctx)
     }
@@ -177,6 +194,40 @@ where
         self.inner.insert_hybrid(ctx, collection_name, documents)
     }
 
+    fn insert_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        let expected = match Self::expected_dimension() {
+            Ok(value) => value,
+            Err(error) => return Box::pin(async move { Err(error) }),
+        };
+        if let Err(error) = Self::ensure_documents(expected, &documents) {
+            return Box::pin(async move { Err(error) });
+        }
+        self.inner
+            .insert_with_outcomes(ctx, collection_name, documents)
+    }
+
+    fn insert_hybrid_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        let expected = match Self::expected_dimension() {
+            Ok(value) => value,
+            Err(error) => return Box::pin(async move { Err(error) }),
+        };
+        if let Err(error) = Self::ensure_documents(expected, &documents) {
+            return Box::pin(async move { Err(error) });
+        }
+        self.inner
+            .insert_hybrid_with_outcomes(ctx, collection_name, documents)
+    }
+
     fn flush(
         &self,
         ctx: &RequestContext,
@@ -235,6 +286,34 @@ where
         self.inner
             .query(ctx, collection_name, filter, output_fields, limit)
     }
+
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> BoxFuture<'_, Result<QueryPage>> {
+        self.inner.query_paged(
+            ctx,
+            collection_name,
+            filter,
+            output_fields,
+            page_size,
+            page_token,
+        )
+    }
+
+    fn get_vector(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        id: Box<str>,
+    ) -> BoxFuture<'_, Result<Option<EmbeddingVector>>> {
+        self.inner.get_vector(ctx, collection_name, id)
+    }
 }
 
 #[cfg(test)]
@@ -16,15 +16,17 @@ use crate::vectordb::milvus::proto::milvus::{
     ShowCollectionsRequest, ShowType,
 };
 use crate::vectordb::milvus::proto::schema::{
-    DataType, FieldData, FloatArray, LongArray, ScalarField, SearchResultData, StringArray,
-    VectorField, field_data,
+    DataType, FieldData, FieldSchema, FloatArray, LongArray, ScalarField, SearchResultData,
+    StringArray, VectorField, field_data,
 };
 use crate::vectordb::milvus::schema::{
     MilvusSchemaSpec, build_dense_schema_spec, build_grpc_schema, build_hybrid_schema_spec,
 };
 use crate::vectordb::milvus::shared::{
     DEFAULT_COLLECTION_DESCRIPTION, DEFAULT_HYBRID_COLLECTION_DESCRIPTION, DEFAULT_SPARSE_FIELD,
-    DEFAULT_VECTOR_FIELD, MILVUS_OUTPUT_FIELDS, ensure_collection_name, milvus_in_string,
+    DEFAULT_VECTOR_FIELD, MILVUS_COUNT_FIELD, MILVUS_OUTPUT_FIELDS, cursor_output_fields,
+    cursor_page_filter, ensure_collection_name, milvus_filter_expr, milvus_in_string,
+    split_cursor_page,
 };
 use crate::vectordb::milvus::{MilvusIndexConfig, MilvusIndexSpec};
 use base64::Engine;
@@ -35,13 +37,18 @@ use semantic_code_domain::{CollectionName, VectorDbProviderId};
 use semantic_code_ports::{
     CollectionName as PortsCollectionName, HybridSearchBatchRequest, HybridSearchData,
     HybridSearchOptions, HybridSearchRequest as PortsHybridSearchRequest, HybridSearchResult,
-    VectorDbPort, VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
-    VectorSearchRequest, VectorSearchResponse, VectorSearchResult,
+    InsertOutcome, PageToken, QueryPage, VectorDbPort, VectorDbProviderInfo, VectorDbRow,
+    VectorDocument, VectorDocumentForInsert, VectorSearchRequest, VectorSearchResponse,
+    VectorSearchResult, insert_outcomes_from_batch, sort_hybrid_score_ties_by_id,
+    sort_search_score_ties_by_id,
 };
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
 use std::collections::BTreeMap;
 use std::future::Future;
+use std::num::NonZeroU32;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use tonic::codegen::InterceptedService;
 use tonic::metadata::AsciiMetadataValue;
@@ -70,6 +77,10 @@ pub struct MilvusGrpcConfig {
     pub index_timeout_ms: u64,
     /// Index configuration (dense + sparse).
     pub index_config: MilvusIndexConfig,
+    /// HTTP/2 keep-alive ping interval in milliseconds.
+    pub keep_alive_ms: u64,
+    /// Number of channels opened up front and reused across operations.
+    pub max_channels: u32,
 }
 
 impl MilvusGrpcConfig {
@@ -93,6 +104,18 @@ impl MilvusGrpcConfig {
                 "Milvus index timeout must be greater than zero",
             ));
         }
+        if self.keep_alive_ms == 0 {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "Milvus keep-alive interval must be greater than zero",
+            ));
+        }
+        if self.max_channels == 0 {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "Milvus max channels must be greater than zero",
+            ));
+        }
         Ok(())
     }
 }
@@ -118,11 +141,35 @@ impl Interceptor for AuthInterceptor {
     }
 }
 
+type GrpcClient = MilvusServiceClient<InterceptedService<Channel, AuthInterceptor>>;
+
+/// Channels connected once at construction and handed out round-robin.
+///
+/// Each `Channel` multiplexes concurrent calls over one HTTP/2 connection, so
+/// operations clone a client from the pool instead of reconnecting. The first
+/// client is held separately so the pool can never be empty.
+struct ChannelPool {
+    first: GrpcClient,
+    rest: Box<[GrpcClient]>,
+    next: AtomicUsize,
+}
+
+impl ChannelPool {
+    fn client(&self) -> GrpcClient {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % (self.rest.len() + 1);
+        index
+            .checked_sub(1)
+            .and_then(|index| self.rest.get(index))
+            .unwrap_or(&self.first)
+            .clone()
+    }
+}
+
 /// Milvus gRPC vector DB adapter.
 #[derive(Clone)]
 pub struct MilvusGrpcVectorDb {
     provider: VectorDbProviderInfo,
-    client: MilvusServiceClient<InterceptedService<Channel, AuthInterceptor>>,
+    channels: Arc<ChannelPool>,
     timeout: Duration,
     index_timeout: Duration,
     index_config: MilvusIndexConfig,
@@ -140,7 +187,16 @@ impl MilvusGrpcVectorDb {
                 format!("invalid Milvus address: {error}"),
             )
         })?;
-        endpoint = endpoint.timeout(Duration::from_millis(config.timeout_ms));
+        // The channel deadline must admit the slowest call; per-operation
+        // deadlines are enforced by `call_with_timeout`/`call_with_index_timeout`.
+        endpoint = endpoint.timeout(Duration::from_millis(
+            config.timeout_ms.max(config.index_timeout_ms),
+        ));
+        let keep_alive = Duration::from_millis(config.keep_alive_ms);
+        endpoint = endpoint
+            .http2_keep_alive_interval(keep_alive)
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(keep_alive));
         if config.ssl {
             let tls = ClientTlsConfig::new();
             endpoint = endpoint.tls_config(tls).map_err(|error| {
@@ -151,21 +207,23 @@ impl MilvusGrpcVectorDb {
             })?;
         }
 
-        let channel = endpoint.connect().await.map_err(|error| {
-            ErrorEnvelope::unexpected(
-                ErrorCode::new("vector", "vdb_connection"),
-                format!("failed to connect to Milvus gRPC: {error}"),
-                ErrorClass::Retriable,
-            )
-        })?;
-
         let auth_header = build_auth_header(&config)?;
         let db_name = build_db_header(&config)?;
         let interceptor = AuthInterceptor {
             auth_header,
             db_name,
         };
-        let client = MilvusServiceClient::with_interceptor(channel, interceptor);
+        let first = connect_client(&endpoint, &interceptor).await?;
+        let extra_channels = config.max_channels.saturating_sub(1);
+        let mut rest = Vec::with_capacity(usize::try_from(extra_channels).unwrap_or(0));
+        for _ in 0..extra_channels {
+            rest.push(connect_client(&endpoint, &interceptor).await?);
+        }
+        let channels = Arc::new(ChannelPool {
+            first,
+            rest: rest.into_boxed_slice(),
+            next: AtomicUsize::new(0),
+        });
 
         let provider = VectorDbProviderInfo {
             id: VectorDbProviderId::parse("milvus_grpc").map_err(ErrorEnvelope::from)?,
@@ -174,7 +232,7 @@ impl MilvusGrpcVectorDb {
 
         Ok(Self {
             provider,
-            client,
+            channels,
             timeout: Duration::from_millis(config.timeout_ms),
             index_timeout: Duration::from_millis(config.index_timeout_ms),
             index_config: config.index_config,
@@ -191,6 +249,45 @@ impl MilvusGrpcVectorDb {
         }
     }
 
+    /// Insert rows and return the indexes Milvus reported as rejected.
+    async fn insert_rows(
+        &self,
+        ctx: &RequestContext,
+        collection_name: &CollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> Result<Vec<u32>> {
+        ensure_collection_name(collection_name)?;
+        self.ensure_loaded(ctx, collection_name).await?;
+        let fields_data = Self::build_documents(documents)?;
+        let row_count = fields_data.first().map_or(0, f_len);
+        let num_rows = u32::try_from(row_count).unwrap_or_default();
+        let request = InsertRequest {
+            base: Some(MsgBase::new(MsgType::Insert)),
+            db_name: self.db_name.clone().unwrap_or_default().into(),
+            collection_name: collection_name.as_str().to_owned(),
+            partition_name: String::new(),
+            num_rows,
+            fields_data,
+            hash_keys: Vec::new(),
+            schema_timestamp: 0,
+        };
+        let response = self
+            .call_with_timeout(
+                ctx,
+                "milvus_grpc.insert",
+                Some(collection_name),
+                self.channels.client().insert(request),
+            )
+            .await?;
+        if let Some(status) = response.status.as_ref() {
+            ensure_status_ok(
+                status,
+                &Self::context("milvus_grpc.insert", Some(collection_name)),
+            )?;
+        }
+        Ok(response.err_index)
+    }
+
     async fn ensure_loaded(&self, ctx: &RequestContext, collection: &CollectionName) -> Result<()> {
         let operation = "milvus_grpc.ensure_loaded";
         ctx.ensure_not_cancelled(operation)?;
@@ -206,7 +303,7 @@ impl MilvusGrpcVectorDb {
                 ctx,
                 operation,
                 Some(collection),
-                self.client.clone().get_load_state(request),
+                self.channels.client().get_load_state(request),
             )
             .await?;
         if let Some(status) = response.status.as_ref() {
@@ -230,11 +327,11 @@ impl MilvusGrpcVectorDb {
         };
 
         let response = self
-            .call_with_timeout(
+            .call_with_index_timeout(
                 ctx,
                 "milvus_grpc.load_collection",
                 Some(collection),
-                self.client.clone().load_collection(load_request),
+                self.channels.client().load_collection(load_request),
             )
             .await?;
         ensure_status_ok(
@@ -244,6 +341,68 @@ impl MilvusGrpcVectorDb {
         Ok(())
     }
 
+    /// Run a `Query` RPC.
+    async fn query_rows(
+        &self,
+        ctx: &RequestContext,
+        collection_name: &CollectionName,
+        filter: &str,
+        output_fields: &[Box<str>],
+        limit: Option<u32>,
+    ) -> Result<Vec<VectorDbRow>> {
+        ensure_collection_name(collection_name)?;
+        self.ensure_loaded(ctx, collection_name).await?;
+        let mut params = Vec::new();
+        if let Some(limit) = limit {
+            params.push(KeyValuePair {
+                key: "limit".to_owned(),
+                value: limit.to_string(),
+            });
+        }
+        let request = QueryRequest {
+            base: None,
+            db_name: self.db_name.clone().unwrap_or_default().into(),
+            collection_name: collection_name.as_str().to_owned(),
+            expr: filter.to_owned(),
+            output_fields: output_fields
+                .iter()
+                .map(|f| f.as_ref().to_owned())
+                .collect(),
+            partition_names: Vec::new(),
+            travel_timestamp: 0,
+            guarantee_timestamp: 0,
+            query_params: params,
+            not_return_all_meta: false,
+            consistency_level: crate::vectordb::milvus::proto::common::ConsistencyLevel::Bounded
+                as i32,
+            use_default_consistency: false,
+            expr_template_values: std::collections::HashMap::new(),
+        };
+        let response = self
+            .call_with_timeout(
+                ctx,
+                "milvus_grpc.query",
+                Some(collection_name),
+                self.channels.client().query(request),
+            )
+            .await?;
+        if let Some(status) = response.status.as_ref() {
+            ensure_status_ok(
+                status,
+                &Self::context("milvus_grpc.query", Some(collection_name)),
+            )?;
+        }
+        let fields = response.fields_data;
+        let columns = collect_fields(fields)?;
+        let row_count = columns.values().next().map_or(0, FieldColumn::len);
+        let mut rows = Vec::with_capacity(row_count);
+        for idx in 0..row_count {
+            let doc = build_row_from_columns(&columns, idx);
+            rows.push(doc);
+        }
+        Ok(rows)
+    }
+
     async fn wait_for_index(
         &self,
         ctx: &RequestContext,
@@ -268,7 +427,7 @@ impl MilvusGrpcVectorDb {
                     ctx,
                     operation,
                     Some(collection),
-                    self.client.clone().get_index_build_progress(request),
+                    self.channels.client().get_index_build_progress(request),
                 )
                 .await?;
             if let Some(status) = response.status.as_ref() {
@@ -294,9 +453,32 @@ impl MilvusGrpcVectorDb {
         operation: &'static str,
         collection: Option<&CollectionName>,
         fut: impl Future<Output = std::result::Result<tonic::Response<T>, tonic::Status>>,
+    ) -> Result<T> {
+        self.call_within(ctx, operation, collection, self.timeout, fut)
+            .await
+    }
+
+    /// Like [`Self::call_with_timeout`], bounded by `index_timeout_ms` instead.
+    async fn call_with_index_timeout<T>(
+        &self,
+        ctx: &RequestContext,
+        operation: &'static str,
+        collection: Option<&CollectionName>,
+        fut: impl Future<Output = std::result::Result<tonic::Response<T>, tonic::Status>>,
+    ) -> Result<T> {
+        self.call_within(ctx, operation, collection, self.index_timeout, fut)
+            .await
+    }
+
+    async fn call_within<T>(
+        &self,
+        ctx: &RequestContext,
+        operation: &'static str,
+        collection: Option<&CollectionName>,
+        timeout: Duration,
+        fut: impl Future<Output = std::result::Result<tonic::Response<T>, tonic::Status>>,
     ) -> Result<T> {
         ctx.ensure_not_cancelled(operation)?;
-        let timeout = self.timeout;
         let result = tokio::select! {
             () = ctx.cancelled() => return Err(cancelled_error(operation)),
             res = tokio::time::timeout(timeout, fut) => res,
@@ -411,6 +593,7 @@ impl MilvusGrpcVectorDb {
                 score,
             });
         }
+        sort_search_score_ties_by_id(&mut results);
         Ok(results)
     }
 
@@ -430,6 +613,7 @@ impl MilvusGrpcVectorDb {
                 score,
             });
         }
+        sort_hybrid_score_ties_by_id(&mut results);
         Ok(results)
     }
 }
@@ -526,7 +710,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.drop_collection",
                         Some(&collection_name),
-                        adapter.client.clone().drop_collection(request),
+                        adapter.channels.client().drop_collection(request),
                     )
                     .await?;
                 ensure_status_ok(
@@ -565,7 +749,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.has_collection",
                         Some(&collection_name),
-                        adapter.client.clone().has_collection(request),
+                        adapter.channels.client().has_collection(request),
                     )
                     .await?;
                 Ok(response.value)
@@ -574,6 +758,106 @@ impl VectorDbPort for MilvusGrpcVectorDb {
         )
     }
 
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: PortsCollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u32>>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.grpc.collection_dimension",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ensure_collection_name(&collection_name)?;
+                let request = DescribeCollectionRequest {
+                    base: Some(MsgBase::new(MsgType::DescribeCollection)),
+                    db_name: adapter.db_name.clone().unwrap_or_default().into(),
+                    collection_name: collection_name.as_str().to_owned(),
+                    ..Default::default()
+                };
+                let response = adapter
+                    .call_with_timeout(
+                        &ctx,
+                        "milvus_grpc.describe_collection",
+                        Some(&collection_name),
+                        adapter.channels.client().describe_collection(request),
+                    )
+                    .await?;
+                if let Some(status) = response.status.as_ref() {
+                    ensure_status_ok(
+                        status,
+                        &Self::context("milvus_grpc.describe_collection", Some(&collection_name)),
+                    )?;
+                }
+                Ok(response
+                    .schema
+                    .as_ref()
+                    .and_then(|schema| grpc_vector_dimension(&schema.fields)))
+            }
+            .instrument(span),
+        )
+    }
+
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: PortsCollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u64>>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.grpc.count",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ensure_collection_name(&collection_name)?;
+                adapter.ensure_loaded(&ctx, &collection_name).await?;
+                let request = QueryRequest {
+                    base: None,
+                    db_name: adapter.db_name.clone().unwrap_or_default().into(),
+                    collection_name: collection_name.as_str().to_owned(),
+                    expr: String::new(),
+                    output_fields: vec![MILVUS_COUNT_FIELD.to_owned()],
+                    partition_names: Vec::new(),
+                    travel_timestamp: 0,
+                    guarantee_timestamp: 0,
+                    query_params: Vec::new(),
+                    not_return_all_meta: false,
+                    consistency_level:
+                        crate::vectordb::milvus::proto::common::ConsistencyLevel::Bounded as i32,
+                    use_default_consistency: false,
+                    expr_template_values: std::collections::HashMap::new(),
+                };
+                let response = adapter
+                    .call_with_timeout(
+                        &ctx,
+                        "milvus_grpc.count",
+                        Some(&collection_name),
+                        adapter.channels.client().query(request),
+                    )
+                    .await?;
+                if let Some(status) = response.status.as_ref() {
+                    ensure_status_ok(
+                        status,
+                        &Self::context("milvus_grpc.count", Some(&collection_name)),
+                    )?;
+                }
+                let columns = collect_fields(response.fields_data)?;
+                Ok(columns
+                    .get(MILVUS_COUNT_FIELD)
+                    .and_then(|column| column.long_at(0))
+                    .and_then(|count| u64::try_from(count).ok()))
+            }
+            .instrument(span),
+        )
+    }
+
     fn list_collections(
         &self,
         ctx: &RequestContext,
@@ -595,7 +879,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.list_collections",
                         None,
-                        adapter.client.clone().show_collections(request),
+                        adapter.channels.client().show_collections(request),
                     )
                     .await?;
                 if let Some(status) = response.status.as_ref() {
@@ -631,41 +915,54 @@ impl VectorDbPort for MilvusGrpcVectorDb {
         );
         Box::pin(
             async move {
-                ensure_collection_name(&collection_name)?;
-                adapter.ensure_loaded(&ctx, &collection_name).await?;
-                let fields_data = Self::build_documents(documents)?;
-                let row_count = fields_data.first().map_or(0, f_len);
-                let num_rows = u32::try_from(row_count).unwrap_or_default();
-                let request = InsertRequest {
-                    base: Some(MsgBase::new(MsgType::Insert)),
-                    db_name: adapter.db_name.clone().unwrap_or_default().into(),
-                    collection_name: collection_name.as_str().to_owned(),
-                    partition_name: String::new(),
-                    num_rows,
-                    fields_data,
-                    hash_keys: Vec::new(),
-                    schema_timestamp: 0,
-                };
-                let response = adapter
-                    .call_with_timeout(
-                        &ctx,
-                        "milvus_grpc.insert",
-                        Some(&collection_name),
-                        adapter.client.clone().insert(request),
-                    )
-                    .await?;
-                if let Some(status) = response.status.as_ref() {
-                    ensure_status_ok(
-                        status,
-                        &Self::context("milvus_grpc.insert", Some(&collection_name)),
-                    )?;
+                adapter
+                    .insert_rows(&ctx, &collection_name, documents)
+                    .await
+                    .map(|_| ())
+            }
+            .instrument(span),
+        )
+    }
+
+    fn insert_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: PortsCollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let doc_count = documents.len();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.grpc.insert_with_outcomes",
+            collection = %collection,
+            doc_count
+        );
+        Box::pin(
+            async move {
+                let ids: Vec<Box<str>> = documents
+                    .iter()
+                    .map(|document| document.id.clone())
+                    .collect();
+                match adapter.insert_rows(&ctx, &collection_name, documents).await {
+                    Ok(err_index) => Ok(outcomes_from_err_index(ids, &err_index, &collection_name)),
+                    Err(error) => insert_outcomes_from_batch(ids, Err(error)),
                 }
-                Ok(())
             }
             .instrument(span),
         )
     }
 
+    fn insert_hybrid_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: PortsCollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        self.insert_with_outcomes(ctx, collection_name, documents)
+    }
+
     fn insert_hybrid(
         &self,
         ctx: &RequestContext,
@@ -734,7 +1031,9 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                     db_name: adapter.db_name.clone().unwrap_or_default().into(),
                     collection_name: collection_name.as_str().to_owned(),
                     partition_names: Vec::new(),
-                    dsl: options.filter_expr.clone().unwrap_or_default().into(),
+                    dsl: milvus_filter_expr(options.filter_expr.as_deref(), &options.exclusions)
+                        .unwrap_or_default()
+                        .into(),
                     placeholder_group,
                     dsl_type: crate::vectordb::milvus::proto::common::DslType::BoolExprV1 as i32,
                     output_fields: milvus_output_fields(),
@@ -757,7 +1056,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.search",
                         Some(&collection_name),
-                        adapter.client.clone().search(request),
+                        adapter.channels.client().search(request),
                     )
                     .await?;
                 if let Some(status) = response.status.as_ref() {
@@ -840,7 +1139,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.hybrid_search",
                         Some(&collection_name),
-                        adapter.client.clone().hybrid_search(request),
+                        adapter.channels.client().hybrid_search(request),
                     )
                     .await?;
                 if let Some(status) = response.status.as_ref() {
@@ -899,7 +1198,7 @@ impl VectorDbPort for MilvusGrpcVectorDb {
                         &ctx,
                         "milvus_grpc.delete",
                         Some(&collection_name),
-                        adapter.client.clone().delete(request),
+                        adapter.channels.client().delete(request),
                     )
                     .await?;
                 if let Some(status) = response.status.as_ref() {
@@ -937,57 +1236,43 @@ impl VectorDbPort for MilvusGrpcVectorDb {
         );
         Box::pin(
             async move {
-                ensure_collection_name(&collection_name)?;
-                adapter.ensure_loaded(&ctx, &collection_name).await?;
-                let mut params = Vec::new();
-                if let Some(limit) = limit {
-                    params.push(KeyValuePair {
-                        key: "limit".to_owned(),
-                        value: limit.to_string(),
-                    });
-                }
-                let request = QueryRequest {
-                    base: None,
-                    db_name: adapter.db_name.clone().unwrap_or_default().into(),
-                    collection_name: collection_name.as_str().to_owned(),
-                    expr: filter.as_ref().to_owned(),
-                    output_fields: output_fields
-                        .iter()
-                        .map(|f| f.as_ref().to_owned())
-                        .collect(),
-                    partition_names: Vec::new(),
-                    travel_timestamp: 0,
-                    guarantee_timestamp: 0,
-                    query_params: params,
-                    not_return_all_meta: false,
-                    consistency_level:
-                        crate::vectordb::milvus::proto::common::ConsistencyLevel::Bounded as i32,
-                    use_default_consistency: false,
-                    expr_template_values: std::collections::HashMap::new(),
-                };
-                let response = adapter
-                    .call_with_timeout(
+                adapter
+                    .query_rows(&ctx, &collection_name, &filter, &output_fields, limit)
+                    .await
+            }
+            .instrument(span),
+        )
+    }
+
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: PortsCollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<QueryPage>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.grpc.query_paged",
+            collection = %collection,
+            page_size = page_size.get()
+        );
+        Box::pin(
+            async move {
+                let rows = adapter
+                    .query_rows(
                         &ctx,
-                        "milvus_grpc.query",
-                        Some(&collection_name),
-                        adapter.client.clone().query(request),
+                        &collection_name,
+                        &cursor_page_filter(&filter, page_token.as_ref()),
+                        &cursor_output_fields(&output_fields),
+                        Some(page_size.get().saturating_add(1)),
                     )
                     .await?;
-                if let Some(status) = response.status.as_ref() {
-                    ensure_status_ok(
-                        status,
-                        &Self::context("milvus_grpc.query", Some(&collection_name)),
-                    )?;
-                }
-                let fields = response.fields_data;
-                let columns = collect_fields(fields)?;
-                let row_count = columns.values().next().map_or(0, FieldColumn::len);
-                let mut rows = Vec::with_capacity(row_count);
-                for idx in 0..row_count {
-                    let doc = build_row_from_columns(&columns, idx);
-                    rows.push(doc);
-                }
-                Ok(rows)
+                split_cursor_page(rows, &output_fields, page_size)
             }
             .instrument(span),
         )
@@ -1024,11 +1309,11 @@ async fn create_collection(
     };
 
     let response = adapter
-        .call_with_timeout(
+        .call_with_index_timeout(
             ctx,
             operation,
             Some(collection_name),
-            adapter.client.clone().create_collection(request),
+            adapter.channels.client().create_collection(request),
         )
         .await?;
     ensure_status_ok(
@@ -1081,7 +1366,10 @@ async fn create_collection(
             ctx,
             "milvus_grpc.describe_collection",
             Some(collection_name),
-            adapter.client.clone().describe_collection(describe_request),
+            adapter
+                .channels
+                .client()
+                .describe_collection(describe_request),
         )
         .await?;
 
@@ -1122,11 +1410,11 @@ async fn create_index(
     };
 
     let response = adapter
-        .call_with_timeout(
+        .call_with_index_timeout(
             ctx,
             "milvus_grpc.create_index",
             Some(collection_name),
-            adapter.client.clone().create_index(request),
+            adapter.channels.client().create_index(request),
         )
         .await?;
     ensure_status_ok(
@@ -1136,6 +1424,31 @@ async fn create_index(
     Ok(())
 }
 
+/// Extract the dense vector dimension from a described collection schema.
+fn grpc_vector_dimension(fields: &[FieldSchema]) -> Option<u32> {
+    fields
+        .iter()
+        .find(|field| field.name == DEFAULT_VECTOR_FIELD)?
+        .type_params
+        .iter()
+        .find(|param| param.key == "dim")
+        .and_then(|param| param.value.parse().ok())
+}
+
+async fn connect_client(endpoint: &Endpoint, interceptor: &AuthInterceptor) -> Result<GrpcClient> {
+    let channel = endpoint.connect().await.map_err(|error| {
+        ErrorEnvelope::unexpected(
+            ErrorCode::new("vector", "vdb_connection"),
+            format!("failed to connect to Milvus gRPC: {error}"),
+            ErrorClass::Retriable,
+        )
+    })?;
+    Ok(MilvusServiceClient::with_interceptor(
+        channel,
+        interceptor.clone(),
+    ))
+}
+
 fn normalize_grpc_address(address: &str, ssl: bool) -> String {
     let trimmed = address.trim();
     if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
@@ -1145,6 +1458,32 @@ fn normalize_grpc_address(address: &str, ssl: bool) -> String {
     format!("{scheme}://{trimmed}")
 }
 
+/// Build per-document outcomes from the row indexes Milvus rejected.
+fn outcomes_from_err_index(
+    ids: Vec<Box<str>>,
+    err_index: &[u32],
+    collection_name: &CollectionName,
+) -> Vec<InsertOutcome> {
+    ids.into_iter()
+        .enumerate()
+        .map(|(index, id)| {
+            let rejected = u32::try_from(index).is_ok_and(|index| err_index.contains(&index));
+            if rejected {
+                let error = ErrorEnvelope::unexpected(
+                    ErrorCode::new("vector", "vdb_row_rejected"),
+                    "Milvus rejected the row during insert",
+                    ErrorClass::NonRetriable,
+                )
+                .with_metadata("collection", collection_name.as_str())
+                .with_metadata("documentId", &*id);
+                InsertOutcome::Failed { id, error }
+            } else {
+                InsertOutcome::Inserted { id }
+            }
+        })
+        .collect()
+}
+
 fn ensure_status_ok(
     status: &crate::vectordb::milvus::proto::common::Status,
     ctx: &MilvusErrorContext,
@@ -1504,7 +1843,8 @@ fn build_hybrid_search_requests(
     let mut requests = Vec::with_capacity(search_requests.len());
     let db_name = adapter.db_name.clone().unwrap_or_default();
     let db_name = db_name.as_ref().to_owned();
-    let filter_expr = options.filter_expr.clone().unwrap_or_default();
+    let filter_expr =
+        milvus_filter_expr(options.filter_expr.as_deref(), &options.exclusions).unwrap_or_default();
 
     for req in search_requests {
         let (placeholder_group, metric_type) = match &req.data {
@@ -1596,6 +1936,118 @@ fn build_rank_params(options: &HybridSearchOptions) -> Vec<KeyValuePair> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vectordb::milvus::proto::milvus::BoolResponse;
+    use std::convert::Infallible;
+    use std::error::Error;
+    use tonic::body::BoxBody;
+    use tonic::codec::ProstCodec;
+    use tonic::codegen::{BoxFuture, Context, Poll, Service, http};
+    use tonic::server::{Grpc, NamedService, UnaryService};
+
+    /// Mock Milvus service answering every call as `HasCollection -> true`.
+    #[derive(Clone)]
+    struct MockMilvus;
+
+    impl NamedService for MockMilvus {
+        const NAME: &'static str = "milvus.proto.milvus.MilvusService";
+    }
+
+    struct HasCollection;
+
+    impl UnaryService<HasCollectionRequest> for HasCollection {
+        type Response = BoolResponse;
+        type Future = BoxFuture<tonic::Response<BoolResponse>, tonic::Status>;
+
+        fn call(&mut self, _request: tonic::Request<HasCollectionRequest>) -> Self::Future {
+            Box::pin(async {
+                Ok(tonic::Response::new(BoolResponse {
+                    value: true,
+                    ..BoolResponse::default()
+                }))
+            })
+        }
+    }
+
+    impl Service<http::Request<BoxBody>> for MockMilvus {
+        type Response = http::Response<BoxBody>;
+        type Error = Infallible;
+        type Future = BoxFuture<Self::Response, Infallible>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+            Box::pin(async move {
+                let mut grpc = Grpc::new(ProstCodec::default());
+                Ok(grpc.unary(HasCollection, request).await)
+            })
+        }
+    }
+
+    /// Serve [`MockMilvus`] on a loopback port, counting accepted connections.
+    async fn spawn_mock_milvus() -> std::io::Result<(Box<str>, Arc<AtomicUsize>)> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?.to_string().into_boxed_str();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        let incoming = futures_util::stream::unfold(listener, move |listener| {
+            let counter = Arc::clone(&counter);
+            async move {
+                let accepted = listener.accept().await.map(|(stream, _)| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    stream
+                });
+                Some((accepted, listener))
+            }
+        });
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(MockMilvus)
+                .serve_with_incoming(incoming),
+        );
+        Ok((address, connections))
+    }
+
+    fn mock_config(address: Box<str>, max_channels: u32) -> MilvusGrpcConfig {
+        MilvusGrpcConfig {
+            address,
+            token: None,
+            username: None,
+            password: None,
+            ssl: false,
+            database: None,
+            timeout_ms: 5_000,
+            index_timeout_ms: 5_000,
+            index_config: MilvusIndexConfig::default(),
+            keep_alive_ms: 30_000,
+            max_channels,
+        }
+    }
+
+    #[tokio::test]
+    async fn operations_reuse_pooled_channels_instead_of_reconnecting() -> Result<(), Box<dyn Error>>
+    {
+        for max_channels in [1, 3] {
+            let (address, connections) = spawn_mock_milvus().await?;
+            let adapter = MilvusGrpcVectorDb::new(mock_config(address, max_channels)).await?;
+            let ctx = RequestContext::new_request();
+            let collection = PortsCollectionName::parse("pooled")?;
+
+            for _ in 0..8 {
+                assert!(adapter.has_collection(&ctx, collection.clone()).await?);
+            }
+
+            assert_eq!(
+                connections.load(Ordering::SeqCst),
+                usize::try_from(max_channels)?
+            );
+        }
+        Ok(())
+    }
 
     #[test]
     fn collection_name_enforces_length() {
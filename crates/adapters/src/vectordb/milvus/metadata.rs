@@ -2,17 +2,37 @@
 
 use semantic_code_domain::{Language, LineSpan, VectorDocumentMetadata};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, Result};
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
 
 /// Serializes vector document metadata into a JSON string for storage.
+///
+/// Object keys are emitted in sorted order so stored payloads are byte-stable.
 pub(super) fn serialize_metadata(metadata: &VectorDocumentMetadata) -> Result<String> {
-    serde_json::to_string(metadata).map_err(|error| {
+    let map_error = |error: serde_json::Error| {
         ErrorEnvelope::unexpected(
             ErrorCode::new("vector", "metadata_serialize_failed"),
             format!("failed to serialize metadata: {error}"),
             semantic_code_shared::ErrorClass::NonRetriable,
         )
-    })
+    };
+    let value = serde_json::to_value(metadata).map_err(map_error)?;
+    serde_json::to_string(&sorted_value(value)).map_err(map_error)
+}
+
+/// Rebuilds every nested object with keys inserted in sorted order.
+fn sorted_value(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let sorted: BTreeMap<String, Value> = object
+                .into_iter()
+                .map(|(key, value)| (key, sorted_value(value)))
+                .collect();
+            Value::Object(sorted.into_iter().collect::<Map<_, _>>())
+        },
+        Value::Array(items) => Value::Array(items.into_iter().map(sorted_value).collect()),
+        other => other,
+    }
 }
 
 /// Parses a JSON metadata payload from Milvus into a typed metadata object.
@@ -59,6 +79,8 @@ pub(super) fn metadata_from_fields(
         fragment_start_byte: None,
         fragment_end_byte: None,
         node_kind: None,
+        extra: BTreeMap::new(),
+        mtime_ms: None,
     };
 
     if let Some(parsed) = parse_metadata(metadata_json) {
@@ -71,6 +93,8 @@ pub(super) fn metadata_from_fields(
         if parsed.file_extension.is_some() {
             metadata.file_extension = parsed.file_extension;
         }
+        metadata.extra = parsed.extra;
+        metadata.mtime_ms = parsed.mtime_ms;
     }
 
     Ok(metadata)
@@ -106,6 +130,22 @@ fn metadata_from_value(value: &Value) -> Option<VectorDocumentMetadata> {
         .filter(|value| !value.is_empty())
         .map(|value| value.to_owned().into_boxed_str());
 
+    let extra = object
+        .get("extra")
+        .and_then(Value::as_object)
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|(key, value)| {
+                    let value = value.as_str()?;
+                    Some((key.as_str().into(), value.into()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mtime_ms = object.get("mtimeMs").and_then(Value::as_u64);
+
     Some(VectorDocumentMetadata {
         relative_path: relative_path.to_owned().into_boxed_str(),
         language,
@@ -114,6 +154,8 @@ fn metadata_from_value(value: &Value) -> Option<VectorDocumentMetadata> {
         fragment_start_byte: None,
         fragment_end_byte: None,
         node_kind,
+        extra,
+        mtime_ms,
     })
 }
 
@@ -144,3 +186,32 @@ fn parse_language(raw: &str) -> Option<Language> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialized_metadata_is_sorted_and_order_independent() -> Result<()> {
+        let forward = r#"{"relativePath":"src/lib.rs","language":"rust","fileExtension":"rs","span":{"startLine":1,"endLine":4},"nodeKind":"function_item"}"#;
+        let reversed = r#"{"nodeKind":"function_item","span":{"endLine":4,"startLine":1},"fileExtension":"rs","language":"rust","relativePath":"src/lib.rs"}"#;
+
+        let forward = parse_metadata(Some(forward)).ok_or_else(|| {
+            ErrorEnvelope::expected(ErrorCode::invalid_input(), "forward metadata")
+        })?;
+        let reversed = parse_metadata(Some(reversed)).ok_or_else(|| {
+            ErrorEnvelope::expected(ErrorCode::invalid_input(), "reversed metadata")
+        })?;
+        assert_eq!(forward, reversed);
+
+        let first = serialize_metadata(&forward)?;
+        let second = serialize_metadata(&reversed)?;
+        assert_eq!(first.as_bytes(), second.as_bytes());
+        assert_eq!(
+            first,
+            r#"{"fileExtension":"rs","language":"rust","nodeKind":"function_item","relativePath":"src/lib.rs","span":{"endLine":4,"startLine":1}}"#
+        );
+        assert_eq!(parse_metadata(Some(&first)), Some(forward));
+        Ok(())
+    }
+}
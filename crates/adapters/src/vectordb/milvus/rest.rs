@@ -10,19 +10,23 @@ use crate::vectordb::milvus::schema::{
     build_dense_schema_spec, build_hybrid_schema_spec, build_rest_schema,
 };
 use crate::vectordb::milvus::shared::{
-    MILVUS_OUTPUT_FIELDS, ensure_collection_name, milvus_in_string,
+    DEFAULT_COLLECTION_DESCRIPTION, DEFAULT_HYBRID_COLLECTION_DESCRIPTION, DEFAULT_VECTOR_FIELD,
+    MILVUS_COUNT_FIELD, MILVUS_OUTPUT_FIELDS, cursor_output_fields, cursor_page_filter,
+    ensure_collection_name, milvus_filter_expr, milvus_in_string, split_cursor_page,
 };
 use crate::vectordb::milvus::{MilvusIndexConfig, MilvusIndexSpec};
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
 use semantic_code_domain::{CollectionName, VectorDbProviderId};
 use semantic_code_ports::{
     HybridSearchBatchRequest, HybridSearchData, HybridSearchOptions,
-    HybridSearchRequest as PortsHybridSearchRequest, HybridSearchResult, VectorDbPort,
-    VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
-    VectorSearchRequest, VectorSearchResponse, VectorSearchResult,
+    HybridSearchRequest as PortsHybridSearchRequest, HybridSearchResult, PageToken, QueryPage,
+    VectorDbPort, VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
+    VectorSearchRequest, VectorSearchResponse, VectorSearchResult, sort_hybrid_score_ties_by_id,
+    sort_search_score_ties_by_id,
 };
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
 use std::time::Duration;
 use tracing::Instrument;
 
@@ -41,6 +45,8 @@ pub struct MilvusRestConfig {
     pub database: Option<Box<str>>,
     /// Request timeout in milliseconds.
     pub timeout_ms: u64,
+    /// Timeout in milliseconds for collection creation, index builds, and loads.
+    pub index_timeout_ms: u64,
     /// Index configuration (dense + sparse).
     pub index_config: MilvusIndexConfig,
 }
@@ -60,6 +66,12 @@ impl MilvusRestConfig {
                 "Milvus timeout must be greater than zero",
             ));
         }
+        if self.index_timeout_ms == 0 {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "Milvus index timeout must be greater than zero",
+            ));
+        }
         Ok(())
     }
 }
@@ -112,6 +124,7 @@ pub struct MilvusRestVectorDb {
     base_url: Box<str>,
     database: Option<Box<str>>,
     timeout: Duration,
+    index_timeout: Duration,
     index_config: MilvusIndexConfig,
 }
 
@@ -138,8 +151,9 @@ impl MilvusRestVectorDb {
             headers.insert(AUTHORIZATION, value);
         }
 
+        // Deadlines are applied per request: collection/index operations use
+        // `index_timeout_ms`, everything else `timeout_ms`.
         let client = reqwest::Client::builder()
-            .timeout(Duration::from_millis(config.timeout_ms))
             .default_headers(headers)
             .build()
             .map_err(|error| {
@@ -161,6 +175,7 @@ impl MilvusRestVectorDb {
             base_url,
             database: config.database,
             timeout: Duration::from_millis(config.timeout_ms),
+            index_timeout: Duration::from_millis(config.index_timeout_ms),
             index_config: config.index_config,
         })
     }
@@ -185,17 +200,52 @@ impl MilvusRestVectorDb {
         body: Option<&B>,
         operation: &'static str,
         collection: Option<&CollectionName>,
+    ) -> Result<MilvusRestResponse<T>> {
+        self.make_request_within(ctx, endpoint, body, operation, collection, self.timeout)
+            .await
+    }
+
+    /// Like [`Self::make_request`], bounded by `index_timeout_ms` instead.
+    async fn make_index_request<T: for<'de> Deserialize<'de>, B: Serialize + Sync>(
+        &self,
+        ctx: &RequestContext,
+        endpoint: &str,
+        body: Option<&B>,
+        operation: &'static str,
+        collection: Option<&CollectionName>,
+    ) -> Result<MilvusRestResponse<T>> {
+        self.make_request_within(
+            ctx,
+            endpoint,
+            body,
+            operation,
+            collection,
+            self.index_timeout,
+        )
+        .await
+    }
+
+    async fn make_request_within<T: for<'de> Deserialize<'de>, B: Serialize + Sync>(
+        &self,
+        ctx: &RequestContext,
+        endpoint: &str,
+        body: Option<&B>,
+        operation: &'static str,
+        collection: Option<&CollectionName>,
+        timeout: Duration,
     ) -> Result<MilvusRestResponse<T>> {
         ctx.ensure_not_cancelled(operation)?;
         let url = format!("{}{}", self.base_url, endpoint);
-        let request = body.map_or_else(
-            || self.client.post(&url),
-            |body| self.client.post(&url).json(body),
-        );
+        let request = body
+            .map_or_else(
+                || self.client.post(&url),
+                |body| self.client.post(&url).json(body),
+            )
+            .timeout(timeout);
 
         let response = tokio::select! {
             () = ctx.cancelled() => return Err(cancelled_error(operation)),
-            res = tokio::time::timeout(self.timeout, request.send()) => res,
+            res = tokio::time::timeout(timeout, request.send()) => res,
         };
 
         let response = match response {
@@ -275,7 +325,7 @@ impl MilvusRestVectorDb {
             "dbName": self.database,
         });
         let _response: MilvusRestResponse<serde_json::Value> = self
-            .make_request(
+            .make_index_request(
                 ctx,
                 "/collections/load",
                 Some(&body),
@@ -286,6 +336,63 @@ impl MilvusRestVectorDb {
         Ok(())
     }
 
+    /// Run an `/entities/query` request.
+    async fn query_rows(
+        &self,
+        ctx: &RequestContext,
+        collection_name: &CollectionName,
+        filter: &str,
+        output_fields: &[Box<str>],
+        limit: Option<u32>,
+    ) -> Result<Vec<VectorDbRow>> {
+        ensure_collection_name(collection_name)?;
+        self.ensure_loaded(ctx, collection_name).await?;
+        let body = serde_json::json!({
+            "collectionName": collection_name.as_str(),
+            "dbName": self.database,
+            "filter": filter,
+            "outputFields": output_fields,
+            "limit": limit,
+        });
+        let response: MilvusRestResponse<SearchData> = self
+            .make_request(
+                ctx,
+                "/entities/query",
+                Some(&body),
+                "milvus_rest.query",
+                Some(collection_name),
+            )
+            .await?;
+        let rows = response.data.and_then(|data| data.data).unwrap_or_default();
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut map = VectorDbRow::new();
+            if let Some(id) = row.id {
+                map.insert("id".into(), serde_json::Value::String(id));
+            }
+            if let Some(relative_path) = row.relative_path {
+                map.insert(
+                    "relativePath".into(),
+                    serde_json::Value::String(relative_path),
+                );
+            }
+            if let Some(start_line) = row.start_line {
+                map.insert("startLine".into(), serde_json::Value::from(start_line));
+            }
+            if let Some(end_line) = row.end_line {
+                map.insert("endLine".into(), serde_json::Value::from(end_line));
+            }
+            if let Some(extension) = row.file_extension {
+                map.insert("fileExtension".into(), serde_json::Value::String(extension));
+            }
+            if let Some(content) = row.content {
+                map.insert("content".into(), serde_json::Value::String(content));
+            }
+            out.push(map);
+        }
+        Ok(out)
+    }
+
     async fn create_index(&self, ctx: &RequestContext, collection: &CollectionName) -> Result<()> {
         let params = index_params_json(&self.index_config.dense);
         let body = serde_json::json!({
@@ -302,7 +409,7 @@ impl MilvusRestVectorDb {
             ],
         });
         let _response: MilvusRestResponse<serde_json::Value> = self
-            .make_request(
+            .make_index_request(
                 ctx,
                 "/indexes/create",
                 Some(&body),
@@ -333,7 +440,7 @@ impl MilvusRestVectorDb {
             ],
         });
         let _response: MilvusRestResponse<serde_json::Value> = self
-            .make_request(
+            .make_index_request(
                 ctx,
                 "/indexes/create",
                 Some(&dense),
@@ -357,7 +464,7 @@ impl MilvusRestVectorDb {
             ],
         });
         let _response: MilvusRestResponse<serde_json::Value> = self
-            .make_request(
+            .make_index_request(
                 ctx,
                 "/indexes/create",
                 Some(&sparse),
@@ -411,24 +518,30 @@ impl VectorDbPort for MilvusRestVectorDb {
         ctx: &RequestContext,
         collection_name: CollectionName,
         dimension: u32,
-        _description: Option<Box<str>>,
+        description: Option<Box<str>>,
     ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
         let ctx = ctx.clone();
         let adapter = self.clone();
+        let has_description = description.is_some();
         let collection = collection_name.as_str().to_owned();
         let span = tracing::info_span!(
             "adapter.vectordb.milvus.rest.create_collection",
             collection = %collection,
-            dimension
+            dimension,
+            has_description
         );
         Box::pin(
             async move {
                 ensure_collection_name(&collection_name)?;
                 let spec = build_dense_schema_spec(dimension);
                 let schema = build_rest_schema(&spec);
+                let description = description
+                    .as_deref()
+                    .unwrap_or(DEFAULT_COLLECTION_DESCRIPTION);
                 let body = serde_json::json!({
                     "collectionName": collection_name.as_str(),
                     "dbName": adapter.database,
+                    "description": description,
                     "schema": {
                         "enableDynamicField": false,
                         "fields": schema.get("fields").cloned().unwrap_or_default(),
@@ -436,7 +549,7 @@ impl VectorDbPort for MilvusRestVectorDb {
                 });
 
                 let _response: MilvusRestResponse<serde_json::Value> = adapter
-                    .make_request(
+                    .make_index_request(
                         &ctx,
                         "/collections/create",
                         Some(&body),
@@ -457,24 +570,30 @@ impl VectorDbPort for MilvusRestVectorDb {
         ctx: &RequestContext,
         collection_name: CollectionName,
         dimension: u32,
-        _description: Option<Box<str>>,
+        description: Option<Box<str>>,
     ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
         let ctx = ctx.clone();
         let adapter = self.clone();
+        let has_description = description.is_some();
         let collection = collection_name.as_str().to_owned();
         let span = tracing::info_span!(
             "adapter.vectordb.milvus.rest.create_hybrid_collection",
             collection = %collection,
-            dimension
+            dimension,
+            has_description
         );
         Box::pin(
             async move {
                 ensure_collection_name(&collection_name)?;
                 let spec = build_hybrid_schema_spec(dimension);
                 let schema = build_rest_schema(&spec);
+                let description = description
+                    .as_deref()
+                    .unwrap_or(DEFAULT_HYBRID_COLLECTION_DESCRIPTION);
                 let body = serde_json::json!({
                     "collectionName": collection_name.as_str(),
                     "dbName": adapter.database,
+                    "description": description,
                     "schema": {
                         "enableDynamicField": false,
                         "fields": schema.get("fields").cloned().unwrap_or_default(),
@@ -482,7 +601,7 @@ impl VectorDbPort for MilvusRestVectorDb {
                     }
                 });
                 let _response: MilvusRestResponse<serde_json::Value> = adapter
-                    .make_request(
+                    .make_index_request(
                         &ctx,
                         "/collections/create",
                         Some(&body),
@@ -573,6 +692,77 @@ impl VectorDbPort for MilvusRestVectorDb {
         )
     }
 
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u32>>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.rest.collection_dimension",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ensure_collection_name(&collection_name)?;
+                let body = serde_json::json!({
+                    "collectionName": collection_name.as_str(),
+                    "dbName": adapter.database,
+                });
+                let response: MilvusRestResponse<serde_json::Value> = adapter
+                    .make_request(
+                        &ctx,
+                        "/collections/describe",
+                        Some(&body),
+                        "milvus_rest.describe_collection",
+                        Some(&collection_name),
+                    )
+                    .await?;
+                Ok(response.data.as_ref().and_then(rest_vector_dimension))
+            }
+            .instrument(span),
+        )
+    }
+
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u64>>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.rest.count",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ensure_collection_name(&collection_name)?;
+                adapter.ensure_loaded(&ctx, &collection_name).await?;
+                let body = serde_json::json!({
+                    "collectionName": collection_name.as_str(),
+                    "dbName": adapter.database,
+                    "filter": "",
+                    "outputFields": [MILVUS_COUNT_FIELD],
+                });
+                let response: MilvusRestResponse<serde_json::Value> = adapter
+                    .make_request(
+                        &ctx,
+                        "/entities/query",
+                        Some(&body),
+                        "milvus_rest.count",
+                        Some(&collection_name),
+                    )
+                    .await?;
+                Ok(response.data.as_ref().and_then(rest_count))
+            }
+            .instrument(span),
+        )
+    }
+
     fn list_collections(
         &self,
         ctx: &RequestContext,
@@ -715,7 +905,9 @@ impl VectorDbPort for MilvusRestVectorDb {
                     "outputFields": MILVUS_OUTPUT_FIELDS,
                     "data": [query_vector.as_ref()],
                 });
-                if let Some(filter) = options.filter_expr {
+                if let Some(filter) =
+                    milvus_filter_expr(options.filter_expr.as_deref(), &options.exclusions)
+                {
                     search_request.as_object_mut().map(|map| {
                         map.insert(
                             "filter".to_owned(),
@@ -742,6 +934,7 @@ impl VectorDbPort for MilvusRestVectorDb {
                         score: row.score.unwrap_or_default(),
                     });
                 }
+                sort_search_score_ties_by_id(&mut results);
                 Ok(VectorSearchResponse {
                     results,
                     stats: None,
@@ -805,6 +998,7 @@ impl VectorDbPort for MilvusRestVectorDb {
                         score: row.score.unwrap_or_default(),
                     });
                 }
+                sort_hybrid_score_ties_by_id(&mut results);
                 Ok(results)
             }
             .instrument(span),
@@ -874,58 +1068,74 @@ impl VectorDbPort for MilvusRestVectorDb {
         );
         Box::pin(
             async move {
-                ensure_collection_name(&collection_name)?;
-                adapter.ensure_loaded(&ctx, &collection_name).await?;
-                let body = serde_json::json!({
-                    "collectionName": collection_name.as_str(),
-                    "dbName": adapter.database,
-                    "filter": filter,
-                    "outputFields": output_fields,
-                    "limit": limit,
-                });
-                let response: MilvusRestResponse<SearchData> = adapter
-                    .make_request(
+                adapter
+                    .query_rows(&ctx, &collection_name, &filter, &output_fields, limit)
+                    .await
+            }
+            .instrument(span),
+        )
+    }
+
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<QueryPage>> {
+        let ctx = ctx.clone();
+        let adapter = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.milvus.rest.query_paged",
+            collection = %collection,
+            page_size = page_size.get()
+        );
+        Box::pin(
+            async move {
+                let rows = adapter
+                    .query_rows(
                         &ctx,
-                        "/entities/query",
-                        Some(&body),
-                        "milvus_rest.query",
-                        Some(&collection_name),
+                        &collection_name,
+                        &cursor_page_filter(&filter, page_token.as_ref()),
+                        &cursor_output_fields(&output_fields),
+                        Some(page_size.get().saturating_add(1)),
                     )
                     .await?;
-                let rows = response.data.and_then(|data| data.data).unwrap_or_default();
-                let mut out = Vec::with_capacity(rows.len());
-                for row in rows {
-                    let mut map = VectorDbRow::new();
-                    if let Some(id) = row.id {
-                        map.insert("id".into(), serde_json::Value::String(id));
-                    }
-                    if let Some(relative_path) = row.relative_path {
-                        map.insert(
-                            "relativePath".into(),
-                            serde_json::Value::String(relative_path),
-                        );
-                    }
-                    if let Some(start_line) = row.start_line {
-                        map.insert("startLine".into(), serde_json::Value::from(start_line));
-                    }
-                    if let Some(end_line) = row.end_line {
-                        map.insert("endLine".into(), serde_json::Value::from(end_line));
-                    }
-                    if let Some(extension) = row.file_extension {
-                        map.insert("fileExtension".into(), serde_json::Value::String(extension));
-                    }
-                    if let Some(content) = row.content {
-                        map.insert("content".into(), serde_json::Value::String(content));
-                    }
-                    out.push(map);
-                }
-                Ok(out)
+                split_cursor_page(rows, &output_fields, page_size)
             }
             .instrument(span),
         )
     }
 }
 
+/// Extract the dense vector dimension from a `/collections/describe` payload.
+fn rest_vector_dimension(data: &serde_json::Value) -> Option<u32> {
+    let fields = data.get("fields")?.as_array()?;
+    let field = fields.iter().find(|field| {
+        field.get("name").and_then(serde_json::Value::as_str) == Some(DEFAULT_VECTOR_FIELD)
+    })?;
+    let params = field.get("params")?.as_array()?;
+    params
+        .iter()
+        .find(|param| param.get("key").and_then(serde_json::Value::as_str) == Some("dim"))
+        .and_then(|param| param.get("value"))
+        .and_then(|value| match value {
+            serde_json::Value::String(raw) => raw.parse().ok(),
+            serde_json::Value::Number(number) => {
+                number.as_u64().and_then(|raw| u32::try_from(raw).ok())
+            },
+            _ => None,
+        })
+}
+
+/// Extract the `count(*)` value from an `/entities/query` payload.
+fn rest_count(data: &serde_json::Value) -> Option<u64> {
+    data.as_array()?.first()?.get(MILVUS_COUNT_FIELD)?.as_u64()
+}
+
 fn build_hybrid_search_body(
     collection_name: &CollectionName,
     database: Option<&str>,
@@ -981,12 +1191,12 @@ fn build_hybrid_search_body(
         "outputFields": MILVUS_OUTPUT_FIELDS,
     });
 
-    if let Some(filter) = options.filter_expr.as_ref()
+    if let Some(filter) = milvus_filter_expr(options.filter_expr.as_deref(), &options.exclusions)
         && let Some(obj) = body.as_object_mut()
     {
         obj.insert(
             "filter".to_owned(),
-            serde_json::Value::String(filter.as_ref().to_owned()),
+            serde_json::Value::String(filter.into()),
         );
     }
 
@@ -1044,8 +1254,197 @@ fn missing_field(name: &str) -> ErrorEnvelope {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use semantic_code_domain::SearchExclusions;
     use std::collections::BTreeMap;
     use std::sync::Arc;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mock_slow_collection_server(delay: Duration) -> MockServer {
+        let server = MockServer::start().await;
+        let ok = serde_json::json!({ "code": 0, "data": {} });
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/collections/create"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(ok.clone())
+                    .set_delay(delay),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/collections/has"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "code": 0, "data": { "value": true } }))
+                    .set_delay(delay),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/indexes/create"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ok))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/collections/get_load_state"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": 0,
+                "data": { "loadState": "LoadStateLoaded" }
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn rest_adapter(
+        server: &MockServer,
+        timeout_ms: u64,
+        index_timeout_ms: u64,
+    ) -> Result<MilvusRestVectorDb> {
+        MilvusRestVectorDb::new(MilvusRestConfig {
+            address: server.uri().into_boxed_str(),
+            token: None,
+            username: None,
+            password: None,
+            database: None,
+            timeout_ms,
+            index_timeout_ms,
+            index_config: MilvusIndexConfig::default(),
+        })
+    }
+
+    #[tokio::test]
+    async fn slow_collection_create_is_bounded_by_index_timeout() -> Result<()> {
+        let server = mock_slow_collection_server(Duration::from_millis(400)).await;
+        let ctx = RequestContext::new_request();
+        let collection = CollectionName::parse("slow_create").map_err(ErrorEnvelope::from)?;
+
+        // A 400ms create outlives `timeoutMs` but fits in `indexTimeoutMs`.
+        let adapter = rest_adapter(&server, 100, 5_000)?;
+        adapter
+            .create_collection(&ctx, collection.clone(), 8, None)
+            .await?;
+        let per_query = adapter.has_collection(&ctx, collection.clone()).await;
+        assert_eq!(
+            per_query.err().map(|error| error.code),
+            Some(ErrorCode::new("vector", "vdb_timeout"))
+        );
+
+        // With a short `indexTimeoutMs`, a generous `timeoutMs` does not help.
+        let adapter = rest_adapter(&server, 5_000, 100)?;
+        let created = adapter.create_collection(&ctx, collection, 8, None).await;
+        assert_eq!(
+            created.err().map(|error| error.code),
+            Some(ErrorCode::new("vector", "vdb_timeout"))
+        );
+        Ok(())
+    }
+
+    /// Milvus caps `offset + limit` for a single query at this many rows.
+    const MILVUS_QUERY_WINDOW: u64 = 16_384;
+
+    /// Serves `/entities/query` over `ids`, honouring an `id > "..."` cursor
+    /// and rejecting requests past the query window like Milvus does.
+    struct CursorQueryResponder {
+        ids: Vec<String>,
+    }
+
+    impl wiremock::Respond for CursorQueryResponder {
+        fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap_or_default();
+            let limit = body.get("limit").and_then(serde_json::Value::as_u64);
+            let offset = body.get("offset").and_then(serde_json::Value::as_u64);
+            if offset.unwrap_or(0) + limit.unwrap_or(0) > MILVUS_QUERY_WINDOW {
+                return ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "code": 1100,
+                    "message": "invalid max query result window"
+                }));
+            }
+            let filter = body
+                .get("filter")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default();
+            let after = filter
+                .split_once("id > \"")
+                .and_then(|(_, rest)| rest.strip_suffix('"'));
+            let take = usize::try_from(limit.unwrap_or(u64::MAX)).unwrap_or(usize::MAX);
+            let mut rows: Vec<serde_json::Value> = self
+                .ids
+                .iter()
+                .filter(|id| after.is_none_or(|after| id.as_str() > after))
+                .take(take)
+                .map(|id| serde_json::json!({ "id": id, "relativePath": format!("src/{id}.rs") }))
+                .collect();
+            // Milvus does not promise row order within a page.
+            rows.reverse();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": 0,
+                "data": { "data": rows }
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn query_paged_walks_past_the_offset_window() -> Result<()> {
+        let total = 17_000_usize;
+        let ids: Vec<String> = (0..total)
+            .map(|index| format!("chunk_{index:05}"))
+            .collect();
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/collections/get_load_state"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": 0,
+                "data": { "loadState": "LoadStateLoaded" }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v2/vectordb/entities/query"))
+            .respond_with(CursorQueryResponder { ids: ids.clone() })
+            .mount(&server)
+            .await;
+
+        let ctx = RequestContext::new_request();
+        let collection = CollectionName::parse("deep_paging").map_err(ErrorEnvelope::from)?;
+        let adapter = rest_adapter(&server, 5_000, 5_000)?;
+        let page_size = NonZeroU32::new(1_000).ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "page size must be non-zero",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        let mut seen = Vec::with_capacity(total);
+        let mut token = None;
+        loop {
+            let (rows, next) = adapter
+                .query_paged(
+                    &ctx,
+                    collection.clone(),
+                    "".into(),
+                    vec!["relativePath".into()],
+                    page_size,
+                    token,
+                )
+                .await?;
+            for row in rows {
+                assert!(!row.contains_key("id"), "cursor id leaked into rows");
+                if let Some(path) = row.get("relativePath").and_then(serde_json::Value::as_str) {
+                    seen.push(path.to_owned());
+                }
+            }
+            match next {
+                Some(next) => token = Some(next),
+                None => break,
+            }
+        }
+
+        let expected: Vec<String> = ids.iter().map(|id| format!("src/{id}.rs")).collect();
+        assert_eq!(seen, expected);
+        Ok(())
+    }
 
     #[test]
     fn rest_search_serializes_request() {
@@ -1067,6 +1466,23 @@ mod tests {
         assert!(payload.contains("outputFields"));
     }
 
+    #[test]
+    fn rest_describe_and_count_payloads_parse() {
+        let describe = serde_json::json!({
+            "collectionName": "collection",
+            "fields": [
+                {"name": "id", "type": "VarChar", "params": [{"key": "max_length", "value": "512"}]},
+                {"name": "vector", "type": "FloatVector", "params": [{"key": "dim", "value": "384"}]},
+            ],
+        });
+        assert_eq!(rest_vector_dimension(&describe), Some(384));
+        assert_eq!(rest_vector_dimension(&serde_json::json!({})), None);
+
+        let count = serde_json::json!([{ "count(*)": 42 }]);
+        assert_eq!(rest_count(&count), Some(42));
+        assert_eq!(rest_count(&serde_json::json!([])), None);
+    }
+
     #[test]
     fn rest_hybrid_search_builds_payload() {
         let collection = if let Ok(value) = CollectionName::parse("collection") {
@@ -1097,6 +1513,7 @@ mod tests {
         let options = HybridSearchOptions {
             limit: Some(10),
             filter_expr: Some("fileExtension == \"rs\"".into()),
+            exclusions: SearchExclusions::default(),
             rerank: None,
         };
 
@@ -1,7 +1,9 @@
 //! Shared Milvus helpers.
 
-use semantic_code_domain::CollectionName;
-use semantic_code_shared::{ErrorCode, ErrorEnvelope, Result};
+use semantic_code_domain::{CollectionName, SearchExclusions};
+use semantic_code_ports::{PageToken, QueryPage, VectorDbRow};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, Result};
+use std::num::NonZeroU32;
 
 pub(super) const DEFAULT_VECTOR_FIELD: &str = "vector";
 pub(super) const DEFAULT_SPARSE_FIELD: &str = "sparse_vector";
@@ -19,6 +21,9 @@ pub(super) const MILVUS_OUTPUT_FIELDS: [&str; 7] = [
     "metadata",
 ];
 
+/// Milvus aggregate output field used for row counts.
+pub(super) const MILVUS_COUNT_FIELD: &str = "count(*)";
+
 const MAX_COLLECTION_NAME_LEN: usize = 255;
 
 pub(super) fn ensure_collection_name(collection: &CollectionName) -> Result<()> {
@@ -32,15 +37,323 @@ pub(super) fn ensure_collection_name(collection: &CollectionName) -> Result<()>
     Ok(())
 }
 
+/// Primary-key field; query pages are keyed on it.
+const MILVUS_ID_FIELD: &str = "id";
+
+/// Filter for one cursor page: rows whose id sorts after the token's id.
+///
+/// Paging by primary key instead of `offset` keeps every request inside
+/// Milvus's `offset + limit` window, however deep the scan goes.
+pub(super) fn cursor_page_filter(filter: &str, page_token: Option<&PageToken>) -> Box<str> {
+    let Some(token) = page_token else {
+        return filter.into();
+    };
+    let cursor = format!(
+        "{MILVUS_ID_FIELD} > \"{}\"",
+        escape_milvus_string_literal(token.as_str())
+    );
+    if filter.trim().is_empty() {
+        cursor.into_boxed_str()
+    } else {
+        format!("({filter}) and {cursor}").into_boxed_str()
+    }
+}
+
+/// Output fields for a cursor page; the id is always fetched to key the next
+/// page.
+pub(super) fn cursor_output_fields(output_fields: &[Box<str>]) -> Vec<Box<str>> {
+    let mut fields = output_fields.to_vec();
+    if !fields.iter().any(|field| field.as_ref() == MILVUS_ID_FIELD) {
+        fields.push(MILVUS_ID_FIELD.into());
+    }
+    fields
+}
+
+/// Split a cursor query fetched with `limit = page_size + 1` into one page and
+/// the token (last id) for the next page.
+///
+/// Rows are ordered by id so the cursor only moves forward; an id fetched only
+/// for the cursor is dropped from the returned rows.
+pub(super) fn split_cursor_page(
+    mut rows: Vec<VectorDbRow>,
+    output_fields: &[Box<str>],
+    page_size: NonZeroU32,
+) -> Result<QueryPage> {
+    let page_size = page_size.get() as usize;
+    rows.sort_by(|a, b| row_id(a).cmp(&row_id(b)));
+    let has_more = rows.len() > page_size;
+    rows.truncate(page_size);
+    let next = if has_more {
+        let last = rows.last().and_then(row_id).ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::new("vector", "query_page_missing_id"),
+                "Milvus query row is missing its id",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        Some(PageToken::new(last))
+    } else {
+        None
+    };
+    if !output_fields
+        .iter()
+        .any(|field| field.as_ref() == MILVUS_ID_FIELD)
+    {
+        for row in &mut rows {
+            row.remove(MILVUS_ID_FIELD);
+        }
+    }
+    Ok((rows, next))
+}
+
+fn row_id(row: &VectorDbRow) -> Option<&str> {
+    row.get(MILVUS_ID_FIELD).and_then(serde_json::Value::as_str)
+}
+
 pub(super) fn milvus_in_string(field: &str, values: &[Box<str>]) -> Box<str> {
-    let escaped = values
+    format!("{field} in [{}]", milvus_string_list(values)).into_boxed_str()
+}
+
+fn milvus_string_list(values: &[Box<str>]) -> String {
+    values
         .iter()
         .map(|value| format!("\"{}\"", escape_milvus_string_literal(value)))
         .collect::<Vec<_>>()
-        .join(", ");
-    format!("{field} in [{escaped}]").into_boxed_str()
+        .join(", ")
+}
+
+/// Combine a provider filter expression with search exclusions.
+///
+/// Exact paths become a single `not in` clause, globs become `not like`
+/// clauses, and languages are matched against the serialized `metadata` JSON.
+/// Membership lists (`in (...)`) become native Milvus `in [...]` lists and
+/// `startsWith` / `endsWith` / `contains` become `like` patterns; without
+/// exclusions the expression is otherwise passed through unchanged.
+pub(super) fn milvus_filter_expr(
+    filter_expr: Option<&str>,
+    exclusions: &SearchExclusions,
+) -> Option<Box<str>> {
+    if exclusions.is_empty() {
+        return filter_expr.map(|expr| milvus_expr(expr).into_boxed_str());
+    }
+
+    let mut clauses = Vec::new();
+    if let Some(expr) = filter_expr.filter(|expr| !expr.trim().is_empty()) {
+        clauses.push(format!("({})", milvus_expr(expr)));
+    }
+
+    let (globs, exact): (Vec<Box<str>>, Vec<Box<str>>) = exclusions
+        .exclude_paths
+        .iter()
+        .cloned()
+        .partition(|path| path.contains(['*', '?']));
+    if !exact.is_empty() {
+        clauses.push(format!(
+            "relativePath not in [{}]",
+            milvus_string_list(&exact)
+        ));
+    }
+    for glob in &globs {
+        let pattern = escape_milvus_string_literal(&glob_to_like_pattern(glob));
+        clauses.push(format!("not (relativePath like \"{pattern}\")"));
+    }
+    for language in &exclusions.exclude_languages {
+        let pattern =
+            escape_milvus_string_literal(&format!("%\"language\":\"{}\"%", language.as_str()));
+        clauses.push(format!("not (metadata like \"{pattern}\")"));
+    }
+
+    Some(clauses.join(" and ").into_boxed_str())
+}
+
+fn milvus_expr(expr: &str) -> String {
+    milvus_membership_lists(&milvus_string_matches(expr))
+}
+
+/// Rewrite `startsWith` / `endsWith` / `contains` comparisons as Milvus
+/// `like` patterns. Both are case-sensitive; literal `%`/`_` in the value are
+/// escaped.
+fn milvus_string_matches(expr: &str) -> String {
+    let mut out = String::with_capacity(expr.len());
+    let mut rest = expr;
+    let mut quote = None;
+    while let Some(ch) = rest.chars().next() {
+        if quote.is_none()
+            && out.ends_with(char::is_whitespace)
+            && let Some((like, remaining)) = string_match_as_like(rest)
+        {
+            out.push_str(&like);
+            rest = remaining;
+            continue;
+        }
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None, '\'' | '"') => quote = Some(ch),
+            _ => {},
+        }
+        out.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    out
+}
+
+/// Translate a leading `<op> '<value>'` into `like "<pattern>"`, returning the
+/// unconsumed remainder.
+fn string_match_as_like(input: &str) -> Option<(String, &str)> {
+    let (keyword, after) = ["startsWith", "endsWith", "contains"]
+        .into_iter()
+        .find_map(|keyword| input.strip_prefix(keyword).map(|after| (keyword, after)))?;
+    if !after.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let after = after.trim_start();
+    let open = after.chars().next().filter(|ch| matches!(ch, '\'' | '"'))?;
+    let close = after[1..].find(open)? + 1;
+    let literal = escape_like_literal(&after[1..close]);
+    let pattern = match keyword {
+        "startsWith" => format!("{literal}%"),
+        "endsWith" => format!("%{literal}"),
+        _ => format!("%{literal}%"),
+    };
+    Some((
+        format!("like \"{}\"", escape_milvus_string_literal(&pattern)),
+        &after[close + 1..],
+    ))
+}
+
+/// Rewrite the parentheses of `in (...)` / `not in (...)` value lists as
+/// brackets, which Milvus evaluates as a single hashed membership test.
+fn milvus_membership_lists(expr: &str) -> String {
+    let mut out = String::with_capacity(expr.len());
+    let mut quote = None;
+    let mut in_list = false;
+    for (idx, ch) in expr.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, '(') if !in_list && ends_with_in_keyword(&expr[..idx]) => {
+                in_list = true;
+                out.push('[');
+                continue;
+            },
+            (None, ')') if in_list => {
+                in_list = false;
+                out.push(']');
+                continue;
+            },
+            _ => {},
+        }
+        out.push(ch);
+    }
+    out
+}
+
+fn ends_with_in_keyword(prefix: &str) -> bool {
+    prefix
+        .trim_end()
+        .strip_suffix("in")
+        .is_some_and(|before| before.ends_with(char::is_whitespace))
+}
+
+/// Translate a path glob into a Milvus `like` pattern, escaping literal
+/// `%`/`_` so only the glob wildcards match loosely.
+fn glob_to_like_pattern(glob: &str) -> String {
+    let mut pattern = String::with_capacity(glob.len());
+    for ch in glob.chars() {
+        match ch {
+            '*' => pattern.push('%'),
+            '?' => pattern.push('_'),
+            _ => push_like_literal(&mut pattern, ch),
+        }
+    }
+    pattern
+}
+
+/// Escape `%`, `_`, and `\` so `input` matches literally in a `like` pattern.
+fn escape_like_literal(input: &str) -> String {
+    let mut pattern = String::with_capacity(input.len());
+    for ch in input.chars() {
+        push_like_literal(&mut pattern, ch);
+    }
+    pattern
+}
+
+fn push_like_literal(pattern: &mut String, ch: char) {
+    if matches!(ch, '%' | '_' | '\\') {
+        pattern.push('\\');
+    }
+    pattern.push(ch);
 }
 
 fn escape_milvus_string_literal(input: &str) -> String {
     input.replace('\\', "\\\\").replace('"', "\\\"")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_domain::Language;
+
+    #[test]
+    fn filter_expr_appends_exclusions_with_and() {
+        let exclusions = SearchExclusions {
+            exclude_paths: vec!["*.test.rs".into(), "src/gen_a.rs".into()],
+            exclude_languages: vec![Language::Markdown],
+        };
+
+        let expr = milvus_filter_expr(Some("fileExtension == \"rs\""), &exclusions);
+
+        assert_eq!(
+            expr.as_deref(),
+            Some(
+                "(fileExtension == \"rs\") and relativePath not in [\"src/gen_a.rs\"] \
+                 and not (relativePath like \"%.test.rs\") \
+                 and not (metadata like \"%\\\"language\\\":\\\"markdown\\\"%\")"
+            )
+        );
+        assert_eq!(milvus_filter_expr(None, &SearchExclusions::default()), None);
+        assert_eq!(
+            milvus_filter_expr(Some("language == \"rust\""), &SearchExclusions::default())
+                .as_deref(),
+            Some("language == \"rust\"")
+        );
+    }
+
+    #[test]
+    fn filter_expr_string_matches_become_like_patterns() {
+        let expr = milvus_filter_expr(
+            Some("relativePath startsWith 'src/' && fileExtension endsWith \"s\""),
+            &SearchExclusions::default(),
+        );
+        assert_eq!(
+            expr.as_deref(),
+            Some("relativePath like \"src/%\" && fileExtension like \"%s\"")
+        );
+
+        let expr = milvus_filter_expr(
+            Some("relativePath contains '100%_done' && relativePath != 'startsWith x'"),
+            &SearchExclusions::default(),
+        );
+        assert_eq!(
+            expr.as_deref(),
+            Some("relativePath like \"%100\\\\%\\\\_done%\" && relativePath != 'startsWith x'")
+        );
+    }
+
+    #[test]
+    fn filter_expr_membership_lists_become_milvus_lists() {
+        let expr = milvus_filter_expr(
+            Some("relativePath in ('a (1).rs', 'b.rs') && language not in(\"md\")"),
+            &SearchExclusions::default(),
+        );
+
+        assert_eq!(
+            expr.as_deref(),
+            Some("relativePath in ['a (1).rs', 'b.rs'] && language not in[\"md\"]")
+        );
+        assert_eq!(
+            milvus_filter_expr(Some("join('x')"), &SearchExclusions::default()).as_deref(),
+            Some("join('x')")
+        );
+    }
+}
@@ -10,10 +10,13 @@ use semantic_code_config::{
     SnapshotStorageMode, VectorKernelKind as ConfigVectorKernelKind, VectorSearchStrategy,
     VectorSnapshotFormat,
 };
-use semantic_code_domain::{IndexMode, Language, SearchStats};
+use semantic_code_domain::{
+    IndexMode, Language, SearchExclusions, SearchStats, is_valid_extra_key,
+};
 use semantic_code_ports::{
-    CollectionName, HybridSearchBatchRequest, HybridSearchData, HybridSearchResult, VectorDbPort,
-    VectorDbProviderId, VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
+    CollectionName, EmbeddingVector, HybridSearchBatchRequest, HybridSearchData,
+    HybridSearchResult, PageToken, QueryPage, VectorDbPort, VectorDbProviderId,
+    VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
     VectorDocumentMetadata, VectorSearchRequest, VectorSearchResponse, VectorSearchResult,
 };
 use semantic_code_shared::{
@@ -29,9 +32,11 @@ use semantic_code_vector::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::future::Future;
+use std::io::{Read, Write};
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 #[cfg(test)]
 use std::sync::atomic::AtomicUsize;
@@ -48,6 +53,7 @@ const LOCAL_SNAPSHOT_VERSION: u32 = 1;
 const LOCAL_SNAPSHOT_DIR: &str = "vector";
 const LOCAL_COLLECTIONS_DIR: &str = "collections";
 const LOCAL_SNAPSHOT_V2_DIR_SUFFIX: &str = ".v2";
+const LOCAL_SNAPSHOT_V1_GZIP_EXTENSION: &str = "gz";
 const LOCAL_SNAPSHOT_V2_IDS_FILE_NAME: &str = "ids.json";
 const LOCAL_SNAPSHOT_V2_RECORDS_META_FILE_NAME: &str = "records.meta.jsonl";
 const LOCAL_INSERT_WAL_FILE_SUFFIX: &str = ".wal.jsonl";
@@ -293,6 +299,7 @@ struct CollectionLoaderContext {
     storage_mode: SnapshotStorageMode,
     snapshot_format: VectorSnapshotFormat,
     snapshot_max_bytes: Option<u64>,
+    snapshot_gzip: bool,
     kernel: Arc<dyn VectorKernel + Send + Sync>,
     runtime_dfrr_ready_state: Option<DfrrReadyStateRequirement>,
     dfrr_prewarm_requests: Vec<DfrrReadyStatePrewarmRequest>,
@@ -374,6 +381,7 @@ pub struct LocalVectorDbBuilder {
     storage_mode: SnapshotStorageMode,
     snapshot_format: VectorSnapshotFormat,
     snapshot_max_bytes: Option<u64>,
+    snapshot_gzip: bool,
     force_reindex_on_kernel_change: bool,
     search_strategy: VectorSearchStrategy,
     hnsw_build_config: Option<semantic_code_config::HnswBuildConfig>,
@@ -396,6 +404,7 @@ impl LocalVectorDbBuilder {
             storage_mode: SnapshotStorageMode::default(),
             snapshot_format: VectorSnapshotFormat::default(),
             snapshot_max_bytes: None,
+            snapshot_gzip: false,
             force_reindex_on_kernel_change: false,
             search_strategy: VectorSearchStrategy::default(),
             hnsw_build_config: None,
@@ -425,6 +434,13 @@ impl LocalVectorDbBuilder {
         self
     }
 
+    /// Gzip-compress the v1 JSON snapshot (`<collection>.json.gz`).
+    #[must_use]
+    pub const fn snapshot_gzip(mut self, gzip: bool) -> Self {
+        self.snapshot_gzip = gzip;
+        self
+    }
+
     /// Force a full reindex when the kernel kind changes between restarts.
     #[must_use]
     pub const fn force_reindex_on_kernel_change(mut self, force: bool) -> Self {
@@ -474,6 +490,7 @@ impl LocalVectorDbBuilder {
             storage_mode: self.storage_mode,
             snapshot_format: self.snapshot_format,
             snapshot_max_bytes: self.snapshot_max_bytes,
+            snapshot_gzip: self.snapshot_gzip,
             kernel: self.kernel,
             runtime_dfrr_ready_state: self.runtime_dfrr_ready_state,
             dfrr_prewarm_requests: self.dfrr_prewarm_requests,
@@ -550,6 +567,57 @@ impl LocalVectorDb {
         self.loader_handle.load(collection_name.clone()).await
     }
 
+    /// Collect filtered rows in id order, skipping the first `offset` matches.
+    ///
+    /// Returns the rows plus whether further matches exist past `limit`.
+    async fn query_rows(
+        &self,
+        collection_name: &CollectionName,
+        filter: &str,
+        output_fields: &[Box<str>],
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<(Vec<VectorDbRow>, bool)> {
+        self.ensure_loaded(collection_name).await?;
+        let filter = parse_filter_expr(Some(filter))?;
+
+        let guard = self.collections.read().await;
+        let Some(collection) = guard.get(collection_name) else {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::not_found(),
+                "collection not found",
+            ));
+        };
+        // Vectors live in the index, not the document map; only take the index
+        // lock when the caller asked for them.
+        let index = if output_fields.iter().any(|field| field.as_ref() == "vector") {
+            Some(collection.read_index()?)
+        } else {
+            None
+        };
+        let mut matches = collection
+            .documents
+            .iter()
+            .filter(|(_, doc)| filter_matches(filter.as_ref(), doc))
+            .skip(offset);
+        let is_full = |count: usize| limit.is_some_and(|value| count >= value);
+        let mut rows = Vec::new();
+        for (id, doc) in matches.by_ref() {
+            let vector = index
+                .as_ref()
+                .and_then(|index| index.record_for_id(id))
+                .map(|record| record.vector.as_slice());
+            rows.push(build_row(id, doc, vector, output_fields)?);
+            if is_full(rows.len()) {
+                break;
+            }
+        }
+        let has_more = is_full(rows.len()) && matches.next().is_some();
+        drop(index);
+        drop(guard);
+        Ok((rows, has_more))
+    }
+
     /// Read a v1 JSON snapshot from disk.
     ///
     /// Delegated to the loader context.  Only used in integration tests —
@@ -610,6 +678,80 @@ impl LocalVectorDb {
         append_insert_wal_record(paths.insert_wal.as_path(), wal_record).await
     }
 
+    /// Keep a collection's persisted footprint under `snapshot_max_bytes`.
+    ///
+    /// The footprint is the active snapshot plus the pending insert WAL. When
+    /// an insert pushes it over the limit, a checkpoint is forced first; that
+    /// compacts the WAL and drops superseded rows. If the footprint is still
+    /// over the limit, the insert fails with `snapshot:size_exceeded`. The
+    /// inserted rows stay durable in the WAL either way.
+    async fn enforce_snapshot_budget(
+        &self,
+        ctx: &RequestContext,
+        collection_name: &CollectionName,
+        sequence: u64,
+    ) -> Result<()> {
+        let (Some(max_bytes), Some(paths)) = (
+            self.loader.snapshot_max_bytes,
+            self.snapshot_paths(collection_name),
+        ) else {
+            return Ok(());
+        };
+        let bytes = self.persisted_collection_bytes(&paths).await?;
+        if bytes <= max_bytes {
+            return Ok(());
+        }
+
+        tracing::info!(
+            collection = collection_name.as_str(),
+            bytes,
+            max_bytes,
+            "snapshot footprint over limit — compacting before accepting more writes"
+        );
+        let vector_count = self.collection_vector_count(collection_name).await;
+        let checkpoint_state = self
+            .schedule_checkpoint(collection_name, sequence, vector_count, true)
+            .await;
+        match self
+            .wait_for_checkpoint_durable(ctx, collection_name, sequence, checkpoint_state)
+            .await
+        {
+            Ok(()) => {},
+            Err(error) if error.code == ErrorCode::new("vector", "snapshot_oversize") => {
+                let bytes = self.persisted_collection_bytes(&paths).await?;
+                return Err(snapshot_size_exceeded_error(
+                    collection_name,
+                    &paths,
+                    bytes,
+                    max_bytes,
+                ));
+            },
+            Err(error) => return Err(error),
+        }
+
+        let bytes = self.persisted_collection_bytes(&paths).await?;
+        if bytes > max_bytes {
+            return Err(snapshot_size_exceeded_error(
+                collection_name,
+                &paths,
+                bytes,
+                max_bytes,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Bytes on disk for a collection's active snapshot plus its insert WAL.
+    async fn persisted_collection_bytes(&self, paths: &CollectionSnapshotPaths) -> Result<u64> {
+        let snapshot = if self.loader.snapshot_format == VectorSnapshotFormat::V2 {
+            dir_bytes(paths.v2_dir.as_path()).await?
+        } else {
+            file_bytes(paths.v1_json.as_path()).await?
+        };
+        let wal = file_bytes(paths.insert_wal.as_path()).await?;
+        Ok(snapshot.saturating_add(wal))
+    }
+
     async fn finalize_staged_collection(&self, collection_name: &CollectionName) -> Result<()> {
         let should_close = {
             let collections = self.collections.read().await;
@@ -1305,9 +1447,16 @@ impl LocalVectorDb {
                 .await
                 .map_err(ErrorEnvelope::from)?;
         }
-        tokio::fs::write(path, payload)
-            .await
-            .map_err(ErrorEnvelope::from)?;
+        if is_gzip_snapshot_path(path) {
+            let compressed = gzip_snapshot_payload(payload)?;
+            tokio::fs::write(path, compressed)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+        } else {
+            tokio::fs::write(path, payload)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+        }
         Ok(())
     }
 
@@ -1415,7 +1564,13 @@ impl CollectionLoaderContext {
     fn snapshot_paths(&self, collection_name: &CollectionName) -> Option<CollectionSnapshotPaths> {
         let root = self.snapshot_root()?;
         let collection = collection_name.as_str();
-        let v1_json = root.join(format!("{collection}.json"));
+        let v1_json = if self.snapshot_gzip {
+            root.join(format!(
+                "{collection}.json.{LOCAL_SNAPSHOT_V1_GZIP_EXTENSION}"
+            ))
+        } else {
+            root.join(format!("{collection}.json"))
+        };
         let v2_dir = root.join(format!("{collection}{LOCAL_SNAPSHOT_V2_DIR_SUFFIX}"));
         let v2_meta = v2_dir.join(SNAPSHOT_V2_META_FILE_NAME);
         let v2_vectors = v2_dir.join(SNAPSHOT_V2_VECTORS_FILE_NAME);
@@ -1855,8 +2010,13 @@ impl CollectionLoaderContext {
         };
         let path = paths.v1_json;
 
-        match tokio::fs::read(path).await {
+        match tokio::fs::read(&path).await {
             Ok(payload) => {
+                let payload = if is_gzip_snapshot_path(&path) {
+                    gunzip_snapshot_payload(&path, &payload)?
+                } else {
+                    payload
+                };
                 let snapshot = serde_json::from_slice(&payload).map_err(|error| {
                     snapshot_error("snapshot_parse_failed", "failed to parse snapshot", error)
                 })?;
@@ -2297,6 +2457,62 @@ impl VectorDbPort for LocalVectorDb {
         )
     }
 
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u32>>> {
+        let ctx = ctx.clone();
+        let db = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.local.collection_dimension",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ctx.ensure_not_cancelled("vectordb_local.collection_dimension")?;
+                db.ensure_loaded(&collection_name).await?;
+                let dimension = db
+                    .collections
+                    .read()
+                    .await
+                    .get(&collection_name)
+                    .map(|collection| collection.dimension);
+                Ok(dimension)
+            }
+            .instrument(span),
+        )
+    }
+
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u64>>> {
+        let ctx = ctx.clone();
+        let db = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.local.count",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ctx.ensure_not_cancelled("vectordb_local.count")?;
+                db.ensure_loaded(&collection_name).await?;
+                let count = db
+                    .collections
+                    .read()
+                    .await
+                    .get(&collection_name)
+                    .map(|collection| collection.documents.len() as u64);
+                Ok(count)
+            }
+            .instrument(span),
+        )
+    }
+
     fn list_collections(
         &self,
         ctx: &RequestContext,
@@ -2415,6 +2631,8 @@ impl VectorDbPort for LocalVectorDb {
                         false,
                     )
                     .await;
+                    db.enforce_snapshot_budget(&ctx, &collection_name, wal_record.sequence)
+                        .await?;
                 }
                 Ok(())
             }
@@ -2496,7 +2714,11 @@ impl VectorDbPort for LocalVectorDb {
                 let top_k = options.top_k.unwrap_or(10).max(1) as usize;
                 let threshold = options.threshold;
                 let filter = parse_filter_expr(options.filter_expr.as_deref())?;
-                let search_limit = local_search_limit(top_k, filter.is_some(), threshold);
+                let search_limit = local_search_limit(
+                    top_k,
+                    filter.is_some() || !options.exclusions.is_empty(),
+                    threshold,
+                );
 
                 let response = {
                     let guard = db.collections.read().await;
@@ -2544,7 +2766,7 @@ impl VectorDbPort for LocalVectorDb {
                         let Some(doc) = collection.documents.get(candidate.id.as_ref()) else {
                             continue;
                         };
-                        if !filter_matches(filter.as_ref(), doc) {
+                        if !candidate_matches(filter.as_ref(), &options.exclusions, doc) {
                             continue;
                         }
                         let score = candidate.score;
@@ -2633,7 +2855,7 @@ impl VectorDbPort for LocalVectorDb {
                             let Some(doc) = collection.documents.get(candidate.id.as_ref()) else {
                                 continue;
                             };
-                            if !filter_matches(filter.as_ref(), doc) {
+                            if !candidate_matches(filter.as_ref(), &options.exclusions, doc) {
                                 continue;
                             }
                             let entry = merged.entry(candidate.id.clone()).or_insert_with(|| {
@@ -2656,12 +2878,11 @@ impl VectorDbPort for LocalVectorDb {
                 }
 
                 let mut out: Vec<HybridSearchResult> = merged.into_values().collect();
+                // Score descending, then id ascending, like the dense kernel.
                 out.sort_by(|a, b| {
-                    let score = b.score.total_cmp(&a.score);
-                    if score != std::cmp::Ordering::Equal {
-                        return score;
-                    }
-                    a.document.id.cmp(&b.document.id)
+                    b.score
+                        .total_cmp(&a.score)
+                        .then_with(|| a.document.id.cmp(&b.document.id))
                 });
 
                 if let Some(limit) = global_limit {
@@ -2713,6 +2934,7 @@ impl VectorDbPort for LocalVectorDb {
                             db.loader.snapshot_max_bytes,
                             None,
                         )?;
+                        retire_active_generation(&collection_name, paths)?;
                     }
                     drop(guard);
                     warm_collection_kernel_state(
@@ -2756,39 +2978,124 @@ impl VectorDbPort for LocalVectorDb {
         Box::pin(
             async move {
                 ctx.ensure_not_cancelled("vectordb_local.query")?;
-                db.ensure_loaded(&collection_name).await?;
                 let limit = limit.map(|value| value.max(1) as usize);
-                let filter = parse_filter_expr(Some(filter.as_ref()))?;
+                let (rows, _) = db
+                    .query_rows(&collection_name, &filter, &output_fields, 0, limit)
+                    .await?;
+                Ok(rows)
+            }
+            .instrument(span),
+        )
+    }
 
-                let rows = {
-                    let guard = db.collections.read().await;
-                    let Some(collection) = guard.get(&collection_name) else {
-                        return Err(ErrorEnvelope::expected(
-                            ErrorCode::not_found(),
-                            "collection not found",
-                        ));
-                    };
-                    let mut rows = Vec::new();
-                    for (id, doc) in &collection.documents {
-                        if !filter_matches(filter.as_ref(), doc) {
-                            continue;
-                        }
-                        rows.push(build_row(id, doc, &output_fields));
-                        if limit.is_some_and(|value| rows.len() >= value) {
-                            break;
-                        }
-                    }
-                    drop(guard);
-                    rows
-                };
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<QueryPage>> {
+        let ctx = ctx.clone();
+        let db = self.clone();
+        let field_count = output_fields.len();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.local.query_paged",
+            collection = %collection,
+            field_count,
+            page_size = page_size.get()
+        );
+        Box::pin(
+            async move {
+                ctx.ensure_not_cancelled("vectordb_local.query_paged")?;
+                let offset = page_token
+                    .as_ref()
+                    .map(PageToken::offset)
+                    .transpose()?
+                    .unwrap_or(0);
+                let skip = usize::try_from(offset).unwrap_or(usize::MAX);
+                let (rows, has_more) = db
+                    .query_rows(
+                        &collection_name,
+                        &filter,
+                        &output_fields,
+                        skip,
+                        Some(page_size.get() as usize),
+                    )
+                    .await?;
+                let next = has_more
+                    .then(|| PageToken::from_offset(offset.saturating_add(rows.len() as u64)));
+                Ok((rows, next))
+            }
+            .instrument(span),
+        )
+    }
 
-                Ok(rows)
+    fn get_vector(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        id: Box<str>,
+    ) -> semantic_code_ports::BoxFuture<'_, Result<Option<EmbeddingVector>>> {
+        let ctx = ctx.clone();
+        let db = self.clone();
+        let collection = collection_name.as_str().to_owned();
+        let span = tracing::info_span!(
+            "adapter.vectordb.local.get_vector",
+            collection = %collection
+        );
+        Box::pin(
+            async move {
+                ctx.ensure_not_cancelled("vectordb_local.get_vector")?;
+                db.ensure_loaded(&collection_name).await?;
+                let guard = db.collections.read().await;
+                let Some(collection) = guard.get(&collection_name) else {
+                    return Err(ErrorEnvelope::expected(
+                        ErrorCode::not_found(),
+                        "collection not found",
+                    ));
+                };
+                if !collection.documents.contains_key(id.as_ref()) {
+                    return Ok(None);
+                }
+                let vector = collection
+                    .read_index()?
+                    .record_for_id(&id)
+                    .map(|record| EmbeddingVector::from_vec(record.vector.clone()));
+                drop(guard);
+                Ok(vector)
             }
             .instrument(span),
         )
     }
 }
 
+/// Drop the `ACTIVE` generation pointer after an in-place mutation.
+///
+/// Published generations are immutable, so once a delete rewrites the v2
+/// bundle the active generation is stale; without the pointer, reloads fall
+/// back to the v2 bundle instead of resurrecting deleted documents.
+fn retire_active_generation(
+    collection_name: &CollectionName,
+    paths: &CollectionSnapshotPaths,
+) -> Result<()> {
+    let active_file = paths.generation_layout.active_file();
+    match std::fs::remove_file(&active_file) {
+        Ok(()) => Ok(()),
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(source) => Err(ErrorEnvelope::unexpected(
+            ErrorCode::new("vector", "active_generation_retire_failed"),
+            "failed to remove stale ACTIVE generation pointer",
+            ErrorClass::NonRetriable,
+        )
+        .with_metadata("collection", collection_name.as_str().to_string())
+        .with_metadata("path", active_file.display().to_string())
+        .with_metadata("source", source.to_string())),
+    }
+}
+
 fn local_search_limit(top_k: usize, has_filter: bool, threshold: Option<f32>) -> usize {
     if has_filter || threshold.is_some_and(|value| value > 0.0) {
         top_k.saturating_mul(5)
@@ -4120,20 +4427,41 @@ struct InsertWalDocument {
 enum FilterOp {
     Eq,
     NotEq,
+    In,
+    NotIn,
+    /// Case-sensitive prefix match.
+    StartsWith,
+    /// Case-sensitive suffix match.
+    EndsWith,
+    /// Case-sensitive substring match.
+    Contains,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl FilterOp {
+    const fn is_negated(self) -> bool {
+        matches!(self, Self::NotEq | Self::NotIn)
+    }
+
+    const fn is_string_match(self) -> bool {
+        matches!(self, Self::StartsWith | Self::EndsWith | Self::Contains)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum FilterField {
     RelativePath,
     Language,
     FileExtension,
+    /// Custom metadata entry addressed as `extra.<key>`.
+    Extra(Box<str>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct FilterCondition {
     field: FilterField,
     op: FilterOp,
-    value: Box<str>,
+    /// Compared values; a set keeps large `in (...)` lists O(1) per candidate.
+    values: HashSet<Box<str>>,
 }
 
 fn parse_filter_expr(expr: Option<&str>) -> Result<Option<FilterCondition>> {
@@ -4148,46 +4476,82 @@ fn parse_filter_expr(expr: Option<&str>) -> Result<Option<FilterCondition>> {
         return Err(invalid_filter_expr(expr));
     }
 
-    let (field, op, value) =
+    let (field, op, values) =
         parse_simple_comparison(expr).ok_or_else(|| invalid_filter_expr(expr))?;
     let field = match field {
         "relativePath" => FilterField::RelativePath,
         "language" => FilterField::Language,
         "fileExtension" => FilterField::FileExtension,
-        _ => return Err(invalid_filter_expr(expr)),
-    };
-    let op = match op {
-        "==" => FilterOp::Eq,
-        "!=" => FilterOp::NotEq,
-        _ => return Err(invalid_filter_expr(expr)),
+        other => match other.strip_prefix("extra.") {
+            Some(key) if is_valid_extra_key(key) => FilterField::Extra(key.into()),
+            _ => return Err(invalid_filter_expr(expr)),
+        },
     };
-    if value.is_empty() {
+    // Languages are canonical identifiers; partial matches are not meaningful.
+    if op.is_string_match() && field == FilterField::Language {
+        return Err(invalid_filter_expr(expr));
+    }
+    if values.iter().any(|value| value.is_empty()) {
         return Err(invalid_filter_expr(expr));
     }
 
     Ok(Some(FilterCondition {
         field,
         op,
-        value: value.to_owned().into_boxed_str(),
+        values: values.into_iter().map(Box::from).collect(),
     }))
 }
 
-fn parse_simple_comparison(input: &str) -> Option<(&str, &str, &str)> {
+fn parse_simple_comparison(input: &str) -> Option<(&str, FilterOp, Vec<&str>)> {
     let input = input.trim();
     let (field, rest) = split_once_ws(input)?;
     let rest = rest.trim_start();
 
-    let (op, rest) = if let Some(rest) = rest.strip_prefix("==") {
-        ("==", rest)
-    } else if let Some(rest) = rest.strip_prefix("!=") {
-        ("!=", rest)
-    } else {
-        return None;
+    if let Some(rest) = rest.strip_prefix("==") {
+        return Some((field, FilterOp::Eq, vec![strip_quotes(rest)?]));
+    }
+    if let Some(rest) = rest.strip_prefix("!=") {
+        return Some((field, FilterOp::NotEq, vec![strip_quotes(rest)?]));
+    }
+    for (keyword, op) in [
+        ("startsWith", FilterOp::StartsWith),
+        ("endsWith", FilterOp::EndsWith),
+        ("contains", FilterOp::Contains),
+    ] {
+        if let Some(rest) = rest.strip_prefix(keyword)
+            && rest.starts_with(char::is_whitespace)
+        {
+            return Some((field, op, vec![strip_quotes(rest)?]));
+        }
+    }
+    let (op, rest) = match rest.strip_prefix("not") {
+        Some(rest) if rest.starts_with(char::is_whitespace) => {
+            (FilterOp::NotIn, rest.trim_start().strip_prefix("in")?)
+        },
+        _ => (FilterOp::In, rest.strip_prefix("in")?),
     };
+    Some((field, op, parse_value_list(rest)?))
+}
 
-    let value = rest.trim_start();
-    let unquoted = strip_quotes(value)?;
-    Some((field, op, unquoted))
+/// Parse `('<a>', '<b>', ...)`; commas inside quoted values do not split.
+fn parse_value_list(input: &str) -> Option<Vec<&str>> {
+    let inner = input.trim().strip_prefix('(')?.strip_suffix(')')?;
+    let mut values = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    for (idx, ch) in inner.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, ',') => {
+                values.push(strip_quotes(&inner[start..idx])?);
+                start = idx + 1;
+            },
+            _ => {},
+        }
+    }
+    values.push(strip_quotes(&inner[start..])?);
+    Some(values)
 }
 
 fn split_once_ws(input: &str) -> Option<(&str, &str)> {
@@ -4220,19 +4584,41 @@ fn filter_matches(filter: Option<&FilterCondition>, doc: &StoredDocument) -> boo
         return true;
     };
 
-    let value = match filter.field {
+    let value = match &filter.field {
         FilterField::RelativePath => Some(doc.metadata.relative_path.as_ref()),
         FilterField::Language => doc.metadata.language.map(Language::as_str),
         FilterField::FileExtension => doc.metadata.file_extension.as_deref(),
+        FilterField::Extra(key) => doc.metadata.extra.get(key).map(AsRef::as_ref),
     };
 
+    let matches_any = |matcher: fn(&str, &str) -> bool| {
+        value.is_some_and(|v| filter.values.iter().any(|pattern| matcher(v, pattern)))
+    };
     match filter.op {
-        FilterOp::Eq => value.is_some_and(|v| v == filter.value.as_ref()),
-        FilterOp::NotEq => value.is_none_or(|v| v != filter.value.as_ref()),
+        FilterOp::StartsWith => matches_any(|value, pattern| value.starts_with(pattern)),
+        FilterOp::EndsWith => matches_any(|value, pattern| value.ends_with(pattern)),
+        FilterOp::Contains => matches_any(|value, pattern| value.contains(pattern)),
+        op if op.is_negated() => value.is_none_or(|v| !filter.values.contains(v)),
+        _ => value.is_some_and(|v| filter.values.contains(v)),
     }
 }
 
-fn build_row(id: &str, doc: &StoredDocument, output_fields: &[Box<str>]) -> VectorDbRow {
+/// Apply the inclusion filter and the exclusions (AND semantics).
+fn candidate_matches(
+    filter: Option<&FilterCondition>,
+    exclusions: &SearchExclusions,
+    doc: &StoredDocument,
+) -> bool {
+    filter_matches(filter, doc)
+        && !exclusions.excludes(&doc.metadata.relative_path, doc.metadata.language)
+}
+
+fn build_row(
+    id: &str,
+    doc: &StoredDocument,
+    vector: Option<&[f32]>,
+    output_fields: &[Box<str>],
+) -> Result<VectorDbRow> {
     let mut row = BTreeMap::new();
     for field in output_fields {
         match field.as_ref() {
@@ -4267,10 +4653,25 @@ fn build_row(id: &str, doc: &StoredDocument, output_fields: &[Box<str>]) -> Vect
                     Value::String(doc.content.as_ref().to_owned()),
                 );
             },
+            "vector" => {
+                if let Some(vector) = vector {
+                    row.insert(field.clone(), Value::from(vector));
+                }
+            },
+            "metadata" => {
+                let metadata = serde_json::to_value(&doc.metadata).map_err(|error| {
+                    ErrorEnvelope::unexpected(
+                        ErrorCode::new("vector", "metadata_serialize_failed"),
+                        format!("failed to serialize document metadata: {error}"),
+                        ErrorClass::NonRetriable,
+                    )
+                })?;
+                row.insert(field.clone(), metadata);
+            },
             _ => {},
         }
     }
-    row
+    Ok(row)
 }
 
 fn invalid_filter_expr(expr: &str) -> ErrorEnvelope {
@@ -4308,6 +4709,71 @@ fn duplicate_record_id_in_sidecar_error(
     .with_metadata("duplicate_line", duplicate_line.to_string())
 }
 
+/// V1 snapshots are transparently gzip-compressed when the path ends in `.gz`.
+fn is_gzip_snapshot_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == LOCAL_SNAPSHOT_V1_GZIP_EXTENSION)
+}
+
+/// Compress a serialized snapshot. Size limits are enforced on the
+/// uncompressed body before this runs, and the gzip trailer carries a CRC32
+/// of the uncompressed bytes.
+fn gzip_snapshot_payload(payload: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = flate2::write::GzEncoder::new(
+        Vec::with_capacity(payload.len() / 4),
+        flate2::Compression::default(),
+    );
+    encoder.write_all(payload).map_err(|error| {
+        snapshot_error("snapshot_compress_failed", "failed to gzip snapshot", error)
+    })?;
+    encoder.finish().map_err(|error| {
+        snapshot_error("snapshot_compress_failed", "failed to gzip snapshot", error)
+    })
+}
+
+/// Read a v1 JSON snapshot body from disk, decompressing `.gz` paths.
+///
+/// When `path` does not exist, its gzip sibling (`<path>.gz`) is tried so
+/// readers that only know the plain `.json` location also see compressed
+/// snapshots. Returns `Ok(None)` when neither file exists.
+pub fn read_v1_snapshot_payload(path: &Path) -> Result<Option<Vec<u8>>> {
+    let mut gzip_path = path.as_os_str().to_owned();
+    gzip_path.push(".");
+    gzip_path.push(LOCAL_SNAPSHOT_V1_GZIP_EXTENSION);
+    let gzip_path = PathBuf::from(gzip_path);
+    let candidates = if is_gzip_snapshot_path(path) {
+        vec![path]
+    } else {
+        vec![path, gzip_path.as_path()]
+    };
+    for candidate in candidates {
+        match std::fs::read(candidate) {
+            Ok(payload) if is_gzip_snapshot_path(candidate) => {
+                return gunzip_snapshot_payload(candidate, &payload).map(Some);
+            },
+            Ok(payload) => return Ok(Some(payload)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {},
+            Err(error) => return Err(ErrorEnvelope::from(error)),
+        }
+    }
+    Ok(None)
+}
+
+fn gunzip_snapshot_payload(path: &Path, payload: &[u8]) -> Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+    flate2::read::GzDecoder::new(payload)
+        .read_to_end(&mut decoded)
+        .map_err(|error| {
+            snapshot_error(
+                "snapshot_decompress_failed",
+                "failed to decompress gzip snapshot",
+                error,
+            )
+            .with_metadata("path", path.display().to_string())
+        })?;
+    Ok(decoded)
+}
+
 fn serialize_snapshot_json(snapshot: &CollectionSnapshot) -> Result<Vec<u8>> {
     serde_json::to_vec_pretty(snapshot).map_err(|error| {
         snapshot_error(
@@ -4771,6 +5237,37 @@ async fn compact_insert_wal_records(path: &Path, checkpoint_sequence: u64) -> Re
     Ok(())
 }
 
+/// Size of a file in bytes; a missing file counts as empty.
+async fn file_bytes(path: &Path) -> Result<u64> {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) => Ok(metadata.len()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(error) => Err(ErrorEnvelope::from(error)),
+    }
+}
+
+/// Total size of all files under a directory; a missing directory counts as empty.
+async fn dir_bytes(path: &Path) -> Result<u64> {
+    let mut total = 0_u64;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(ErrorEnvelope::from(error)),
+        };
+        while let Some(entry) = entries.next_entry().await.map_err(ErrorEnvelope::from)? {
+            let metadata = entry.metadata().await.map_err(ErrorEnvelope::from)?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                total = total.saturating_add(metadata.len());
+            }
+        }
+    }
+    Ok(total)
+}
+
 fn replay_insert_wal_records(
     path: &Path,
     collection: &mut LocalCollection,
@@ -4887,13 +5384,29 @@ fn enforce_snapshot_limit(
     .with_metadata("maxBytes", max_bytes.to_string()))
 }
 
-fn log_json_snapshot_stats(
+fn snapshot_size_exceeded_error(
     collection_name: &CollectionName,
-    snapshot_path: &Path,
-    snapshot: &CollectionSnapshot,
+    paths: &CollectionSnapshotPaths,
     bytes: u64,
-) {
-    let count = snapshot.records.len();
+    max_bytes: u64,
+) -> ErrorEnvelope {
+    ErrorEnvelope::expected(
+        ErrorCode::new("snapshot", "size_exceeded"),
+        "local snapshot exceeds configured size limit after compaction",
+    )
+    .with_metadata("collection", collection_name.as_str().to_string())
+    .with_metadata("walPath", paths.insert_wal.display().to_string())
+    .with_metadata("bytes", bytes.to_string())
+    .with_metadata("maxBytes", max_bytes.to_string())
+}
+
+fn log_json_snapshot_stats(
+    collection_name: &CollectionName,
+    snapshot_path: &Path,
+    snapshot: &CollectionSnapshot,
+    bytes: u64,
+) {
+    let count = snapshot.records.len();
     tracing::info!(
         collection = collection_name.as_str(),
         version = "v1",
@@ -5019,7 +5532,9 @@ fn map_spawn_blocking_join_error(
 }
 
 fn collection_name_from_filename(filename: &str) -> Option<CollectionName> {
-    let trimmed = filename.strip_suffix(".json")?;
+    let trimmed = filename
+        .strip_suffix(".json.gz")
+        .or_else(|| filename.strip_suffix(".json"))?;
     CollectionName::parse(trimmed).ok()
 }
 
@@ -5508,6 +6023,8 @@ mod tests {
             fragment_start_byte: None,
             fragment_end_byte: None,
             node_kind: None,
+            extra: BTreeMap::new(),
+            mtime_ms: None,
         })
     }
 
@@ -5694,6 +6211,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(25),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: Some(0.0),
                     },
                 },
@@ -5723,6 +6241,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(25),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: Some(0.5),
                     },
                 },
@@ -5739,6 +6258,56 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn query_paged_enumerates_every_row_exactly_once() -> Result<()> {
+        let (db, collection) = build_hnsw_test_db(32).await?;
+        let ctx = RequestContext::new_request();
+        let page_size = NonZeroU32::new(7).ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "page size must be non-zero",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+
+        let mut ids = Vec::new();
+        let mut pages = 0;
+        let mut page_token = None;
+        loop {
+            let (rows, next) = db
+                .query_paged(
+                    &ctx,
+                    collection.clone(),
+                    "".into(),
+                    vec!["id".into()],
+                    page_size,
+                    page_token,
+                )
+                .await?;
+            assert!(rows.len() <= 7);
+            pages += 1;
+            ids.extend(
+                rows.iter()
+                    .filter_map(|row| row.get("id").and_then(Value::as_str))
+                    .map(str::to_owned),
+            );
+            let Some(next) = next else {
+                break;
+            };
+            page_token = Some(next);
+        }
+
+        assert_eq!(pages, 12);
+        assert_eq!(ids.len(), 80);
+        let unique = ids.iter().collect::<BTreeSet<_>>();
+        assert_eq!(unique.len(), 80);
+        let expected = (0..80)
+            .map(|index| format!("doc-{index}"))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(unique, expected.iter().collect::<BTreeSet<_>>());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn filter_expr_allowlist_accepts_valid_inputs() -> Result<()> {
         let parsed = parse_filter_expr(Some("relativePath == 'src/lib.rs'"))?;
@@ -5755,6 +6324,218 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn search_membership_filter_keeps_only_listed_paths() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-membership-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::with_ef_search(64)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        let collection = CollectionName::parse("search_membership")?;
+        let ctx = RequestContext::new_request();
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        let documents = (0..60)
+            .map(|index| {
+                Ok(VectorDocumentForInsert {
+                    id: format!("doc-{index}").into_boxed_str(),
+                    vector: Arc::from(deterministic_dense_unit_vector(index, 3)),
+                    content: format!("content-{index}").into_boxed_str(),
+                    metadata: sample_metadata(&format!("src/doc_{index}.rs"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        db.insert(&ctx, collection.clone(), documents).await?;
+
+        // 100 even-numbered paths; only the 30 below 60 exist in the collection.
+        let listed: BTreeSet<String> = (0..100)
+            .map(|index| format!("src/doc_{}.rs", index * 2))
+            .collect();
+        let values = listed
+            .iter()
+            .map(|path| format!("'{path}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let response = db
+            .search(
+                &ctx,
+                VectorSearchRequest {
+                    collection_name: collection,
+                    query_vector: Arc::from(deterministic_dense_unit_vector(1, 3)),
+                    options: VectorSearchOptions {
+                        top_k: Some(50),
+                        filter_expr: Some(format!("relativePath in ({values})").into()),
+                        exclusions: SearchExclusions::default(),
+                        threshold: None,
+                    },
+                },
+            )
+            .await?;
+
+        let paths: BTreeSet<String> = response
+            .results
+            .iter()
+            .map(|result| result.document.metadata.relative_path.to_string())
+            .collect();
+        assert_eq!(paths.len(), 30);
+        assert!(paths.is_subset(&listed));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_starts_with_filter_keeps_only_matching_prefix() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-prefix-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::with_ef_search(32)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        let collection = CollectionName::parse("search_prefix")?;
+        let ctx = RequestContext::new_request();
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        let documents = ["src/a.rs", "src/nested/b.rs", "tests/src/c.rs", "Src/d.rs"]
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                Ok(VectorDocumentForInsert {
+                    id: format!("doc-{index}").into_boxed_str(),
+                    vector: Arc::from(deterministic_dense_unit_vector(index, 3)),
+                    content: format!("content-{index}").into_boxed_str(),
+                    metadata: sample_metadata(path)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        db.insert(&ctx, collection.clone(), documents).await?;
+
+        let response = db
+            .search(
+                &ctx,
+                VectorSearchRequest {
+                    collection_name: collection,
+                    query_vector: Arc::from(deterministic_dense_unit_vector(0, 3)),
+                    options: VectorSearchOptions {
+                        top_k: Some(10),
+                        filter_expr: Some("relativePath startsWith 'src/'".into()),
+                        exclusions: SearchExclusions::default(),
+                        threshold: None,
+                    },
+                },
+            )
+            .await?;
+
+        let paths: BTreeSet<String> = response
+            .results
+            .iter()
+            .map(|result| result.document.metadata.relative_path.to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            BTreeSet::from(["src/a.rs".to_owned(), "src/nested/b.rs".to_owned()])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filter_expr_parses_string_matches() -> Result<()> {
+        let parsed = parse_filter_expr(Some("fileExtension endsWith \"s\""))?;
+        assert_eq!(parsed.map(|filter| filter.op), Some(FilterOp::EndsWith));
+        assert!(parse_filter_expr(Some("extra.team contains 'co'"))?.is_some());
+        assert!(parse_filter_expr(Some("language startsWith 'ru'")).is_err());
+        assert!(parse_filter_expr(Some("relativePath startsWith ''")).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filter_expr_parses_membership_lists() -> Result<()> {
+        let parsed = parse_filter_expr(Some("language not in ('rust', \"a, b\")"))?;
+        assert_eq!(
+            parsed.map(|filter| (filter.op, filter.values.len())),
+            Some((FilterOp::NotIn, 2))
+        );
+        assert!(parse_filter_expr(Some("language in ()")).is_err());
+        assert!(parse_filter_expr(Some("language notin ('rust')")).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_exclusions_drop_matching_paths() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-exclusions-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::with_ef_search(32)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        let collection = CollectionName::parse("search_exclusions")?;
+        let ctx = RequestContext::new_request();
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        let documents = ["src/a.rs", "src/a.test.rs"]
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                Ok(VectorDocumentForInsert {
+                    id: format!("doc-{index}").into_boxed_str(),
+                    vector: Arc::from(deterministic_dense_unit_vector(index, 3)),
+                    content: format!("content-{index}").into_boxed_str(),
+                    metadata: sample_metadata(path)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        db.insert(&ctx, collection.clone(), documents).await?;
+
+        let response = db
+            .search(
+                &ctx,
+                VectorSearchRequest {
+                    collection_name: collection,
+                    query_vector: Arc::from(deterministic_dense_unit_vector(1, 3)),
+                    options: VectorSearchOptions {
+                        top_k: Some(10),
+                        filter_expr: None,
+                        exclusions: SearchExclusions {
+                            exclude_paths: vec!["*.test.rs".into()],
+                            exclude_languages: Vec::new(),
+                        },
+                        threshold: None,
+                    },
+                },
+            )
+            .await?;
+        let paths: Vec<&str> = response
+            .results
+            .iter()
+            .map(|result| result.document.metadata.relative_path.as_ref())
+            .collect();
+        assert_eq!(paths, vec!["src/a.rs"]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn snapshot_paths_resolve_v2_bundle() -> Result<()> {
         let tmp = std::env::temp_dir().join(format!(
@@ -5888,6 +6669,210 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn insert_past_snapshot_budget_compacts_then_rejects_oversize() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-snapshot-budget-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let collection = CollectionName::parse("snapshot_budget")?;
+        let ctx = RequestContext::new_request();
+        let large_doc = |id: &str| -> Result<VectorDocumentForInsert> {
+            Ok(VectorDocumentForInsert {
+                id: id.into(),
+                vector: Arc::from(vec![0.1, 0.2, 0.3]),
+                content: "x".repeat(4_000).into(),
+                metadata: sample_metadata(&format!("src/{id}.rs"))?,
+            })
+        };
+
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::new()),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .snapshot_max_bytes(6_000)
+        .build()?;
+        // Hold background checkpoints so the WAL accumulates until the budget
+        // forces one.
+        db.set_checkpoint_delay_for_tests(Duration::from_millis(200));
+
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        db.insert(&ctx, collection.clone(), vec![large_doc("doc1")?])
+            .await?;
+        // Rewriting doc1 doubles the WAL but not the snapshot, so compaction
+        // brings the footprint back under the limit.
+        db.insert(&ctx, collection.clone(), vec![large_doc("doc1")?])
+            .await?;
+
+        let paths = db.snapshot_paths(&collection).ok_or_else(|| {
+            std::io::Error::other("expected snapshot paths for custom storage mode")
+        })?;
+        assert!(
+            !path_exists(paths.insert_wal.as_path()).await?,
+            "expected the budget to force WAL compaction"
+        );
+        let snapshot = db
+            .read_snapshot_json(&collection)
+            .await?
+            .ok_or_else(|| std::io::Error::other("expected persisted snapshot"))?;
+        assert_eq!(snapshot.checkpoint_sequence, Some(2));
+
+        // A second distinct document cannot fit even after compaction.
+        let error = db
+            .insert(&ctx, collection.clone(), vec![large_doc("doc2")?])
+            .await
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected snapshot size error"))?;
+        assert_eq!(error.code, ErrorCode::new("snapshot", "size_exceeded"));
+        assert_eq!(
+            error.metadata.get("maxBytes").map(String::as_str),
+            Some("6000")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn gzip_v1_snapshot_round_trips_to_identical_index() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-snapshot-gzip-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let collection = CollectionName::parse("snapshot_gzip")?;
+        let ctx = RequestContext::new_request();
+        let open_db = || {
+            LocalVectorDbBuilder::new(
+                tmp.clone(),
+                Arc::new(HnswKernel::new()),
+                CancellationToken::new(),
+            )
+            .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+            .snapshot_format(VectorSnapshotFormat::V1)
+            .snapshot_gzip(true)
+            .build()
+        };
+        let search_request = || VectorSearchRequest {
+            collection_name: collection.clone(),
+            query_vector: Arc::from(vec![0.4, 0.5, 0.6]),
+            options: VectorSearchOptions {
+                top_k: Some(2),
+                filter_expr: None,
+                exclusions: SearchExclusions::default(),
+                threshold: None,
+            },
+        };
+
+        let db = open_db()?;
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        db.insert(
+            &ctx,
+            collection.clone(),
+            vec![
+                VectorDocumentForInsert {
+                    id: "doc1".into(),
+                    vector: Arc::from(vec![0.1, 0.2, 0.3]),
+                    content: "one".into(),
+                    metadata: sample_metadata("src/doc1.rs")?,
+                },
+                VectorDocumentForInsert {
+                    id: "doc2".into(),
+                    vector: Arc::from(vec![0.4, 0.5, 0.6]),
+                    content: "two".into(),
+                    metadata: sample_metadata("src/doc2.rs")?,
+                },
+            ],
+        )
+        .await?;
+        db.flush(&ctx, collection.clone()).await?;
+        let expected = db.search(&ctx, search_request()).await?;
+        let paths = db.snapshot_paths(&collection).ok_or_else(|| {
+            std::io::Error::other("expected snapshot paths for custom storage mode")
+        })?;
+        drop(db);
+
+        assert!(
+            paths
+                .v1_json
+                .to_string_lossy()
+                .ends_with("snapshot_gzip.json.gz")
+        );
+        let compressed = tokio::fs::read(paths.v1_json.as_path())
+            .await
+            .map_err(ErrorEnvelope::from)?;
+        assert_eq!(compressed.get(..2), Some(&[0x1f, 0x8b][..]));
+
+        let reopened = open_db()?;
+        let snapshot = reopened
+            .read_snapshot_json(&collection)
+            .await?
+            .ok_or_else(|| std::io::Error::other("expected persisted snapshot"))?;
+        assert_eq!(snapshot.records.len(), 2);
+        let actual = reopened.search(&ctx, search_request()).await?;
+        let ids = |response: &VectorSearchResponse| -> Vec<Box<str>> {
+            response
+                .results
+                .iter()
+                .map(|result| result.document.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&actual), ids(&expected));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn corrupted_gzip_v1_snapshot_fails_with_decompress_error() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-snapshot-gzip-corrupt-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let collection = CollectionName::parse("snapshot_gzip_corrupt")?;
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::new()),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .snapshot_gzip(true)
+        .build()?;
+        let paths = db.snapshot_paths(&collection).ok_or_else(|| {
+            std::io::Error::other("expected snapshot paths for custom storage mode")
+        })?;
+        if let Some(parent) = paths.v1_json.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(ErrorEnvelope::from)?;
+        }
+        tokio::fs::write(paths.v1_json.as_path(), b"\x1f\x8bnot a gzip stream")
+            .await
+            .map_err(ErrorEnvelope::from)?;
+
+        let error = db
+            .read_snapshot_json(&collection)
+            .await
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected decompress error"))?;
+        assert_eq!(
+            error.code,
+            ErrorCode::new("vector", "snapshot_decompress_failed")
+        );
+        assert_eq!(
+            error.metadata.get("path").map(String::as_str),
+            Some(paths.v1_json.display().to_string().as_str())
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn clean_slate_collection_stages_inserts_until_flush_and_publishes_generation()
     -> Result<()> {
@@ -6036,6 +7021,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6046,6 +7032,128 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn get_vector_returns_stored_vector_for_known_id() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-get-vector-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let collection = CollectionName::parse("get_vector")?;
+        let ctx = RequestContext::new_request();
+        let db = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::new()),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+        .build()?;
+        db.create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        db.insert(
+            &ctx,
+            collection.clone(),
+            vec![
+                VectorDocumentForInsert {
+                    id: "doc_a".into(),
+                    vector: Arc::from(vec![0.1, 0.2, 0.3]),
+                    content: "a".into(),
+                    metadata: sample_metadata("src/a.rs")?,
+                },
+                VectorDocumentForInsert {
+                    id: "doc_b".into(),
+                    vector: Arc::from(vec![0.3, 0.2, 0.1]),
+                    content: "b".into(),
+                    metadata: sample_metadata("src/b.rs")?,
+                },
+            ],
+        )
+        .await?;
+
+        let known = db
+            .get_vector(&ctx, collection.clone(), "doc_b".into())
+            .await?;
+        let missing = db
+            .get_vector(&ctx, collection, "doc_missing".into())
+            .await?;
+        let _ = std::fs::remove_dir_all(&tmp);
+
+        assert_eq!(
+            known.as_ref().map(EmbeddingVector::as_slice),
+            Some([0.3, 0.2, 0.1].as_slice())
+        );
+        assert_eq!(missing, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_after_generation_publish_survives_restart() -> Result<()> {
+        let tmp = std::env::temp_dir().join(format!(
+            "sca-localdb-generation-delete-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let collection = CollectionName::parse("generation_delete")?;
+        let ctx = RequestContext::new_request();
+
+        let initial = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::new()),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+        .build()?;
+        initial
+            .create_collection(&ctx, collection.clone(), 3, None)
+            .await?;
+        initial
+            .insert(
+                &ctx,
+                collection.clone(),
+                vec![
+                    VectorDocumentForInsert {
+                        id: "doc_keep".into(),
+                        vector: Arc::from(vec![0.1, 0.2, 0.3]),
+                        content: "keep".into(),
+                        metadata: sample_metadata("src/keep.rs")?,
+                    },
+                    VectorDocumentForInsert {
+                        id: "doc_gone".into(),
+                        vector: Arc::from(vec![0.3, 0.2, 0.1]),
+                        content: "gone".into(),
+                        metadata: sample_metadata("src/gone.rs")?,
+                    },
+                ],
+            )
+            .await?;
+        initial.flush(&ctx, collection.clone()).await?;
+        initial
+            .delete(&ctx, collection.clone(), vec!["doc_gone".into()])
+            .await?;
+        initial.flush(&ctx, collection.clone()).await?;
+        drop(initial);
+
+        let restarted = LocalVectorDbBuilder::new(
+            tmp.clone(),
+            Arc::new(HnswKernel::new()),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(tmp.clone()))
+        .build()?;
+        let rows = restarted
+            .query(&ctx, collection, "".into(), vec!["id".into()], None)
+            .await?;
+        let _ = std::fs::remove_dir_all(&tmp);
+        let ids: Vec<_> = rows
+            .iter()
+            .filter_map(|row| row.get("id").and_then(Value::as_str))
+            .collect();
+        assert_eq!(ids, vec!["doc_keep"]);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn restart_loads_from_published_active_generation() -> Result<()> {
         let tmp = std::env::temp_dir().join(format!(
@@ -6134,6 +7242,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6220,6 +7329,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6262,6 +7372,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6341,6 +7452,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6498,6 +7610,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6575,6 +7688,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6648,6 +7762,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(2),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6726,6 +7841,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6772,6 +7888,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -6873,6 +7990,7 @@ mod tests {
                         options: VectorSearchOptions {
                             top_k: Some(1),
                             filter_expr: None,
+                            exclusions: SearchExclusions::default(),
                             threshold: None,
                         },
                     },
@@ -6937,6 +8055,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7003,6 +8122,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7097,6 +8217,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7139,6 +8260,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7250,6 +8372,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7280,6 +8403,7 @@ mod tests {
             storage_mode: SnapshotStorageMode::Custom(tmp),
             snapshot_format: VectorSnapshotFormat::V2,
             snapshot_max_bytes: None,
+            snapshot_gzip: false,
             kernel: Arc::new(TestDfrrKernel::default()),
             runtime_dfrr_ready_state: None,
             dfrr_prewarm_requests: Vec::new(),
@@ -7376,6 +8500,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7403,6 +8528,7 @@ mod tests {
             storage_mode: SnapshotStorageMode::Custom(tmp.clone()),
             snapshot_format: VectorSnapshotFormat::V2,
             snapshot_max_bytes: None,
+            snapshot_gzip: false,
             kernel,
             runtime_dfrr_ready_state: None,
             dfrr_prewarm_requests: Vec::new(),
@@ -7661,6 +8787,7 @@ mod tests {
                     options: VectorSearchOptions {
                         top_k: Some(1),
                         filter_expr: None,
+                        exclusions: SearchExclusions::default(),
                         threshold: None,
                     },
                 },
@@ -7681,6 +8808,7 @@ mod tests {
                         options: VectorSearchOptions {
                             top_k: Some(1),
                             filter_expr: None,
+                            exclusions: SearchExclusions::default(),
                             threshold: None,
                         },
                     },
@@ -7723,6 +8851,7 @@ mod tests {
             storage_mode: SnapshotStorageMode::Custom(tmp.clone()),
             snapshot_format: VectorSnapshotFormat::V1,
             snapshot_max_bytes: None,
+            snapshot_gzip: false,
             kernel: Arc::new(HnswKernel::new()),
             runtime_dfrr_ready_state: None,
             dfrr_prewarm_requests: Vec::new(),
@@ -1459,6 +1459,7 @@ mod tests {
         CancellationToken, ErrorClass, ErrorCode, ErrorEnvelope, Result as SharedResult,
     };
     use semantic_code_vector::{CollectionGenerationPaths, GenerationId};
+    use std::collections::BTreeMap;
     use std::io;
     use std::path::{Path, PathBuf};
     use std::sync::atomic::{AtomicU64, Ordering};
@@ -1506,6 +1507,8 @@ mod tests {
             fragment_start_byte: None,
             fragment_end_byte: None,
             node_kind: None,
+            extra: BTreeMap::new(),
+            mtime_ms: None,
         })
     }
 
@@ -1,6 +1,10 @@
 //! API v1 DTO mapping helpers.
 
-use crate::v1::{ApiV1ErrorCode, ApiV1ErrorDto, ApiV1ErrorKind, ApiV1ErrorMeta, ApiV1Result};
+use crate::v1::{
+    ApiV1ErrorCode, ApiV1ErrorDto, ApiV1ErrorKind, ApiV1ErrorMeta, ApiV1Result, ApiV1ScoreMetric,
+    ApiV1SearchResultDto,
+};
+use semantic_code_domain::{Language, SearchResult};
 use semantic_code_shared::{ErrorEnvelope, ErrorKind};
 
 const API_V1_REDACTED: &str = "[REDACTED]";
@@ -39,6 +43,7 @@ pub fn error_envelope_to_api_v1_error(
         code: error_code_to_api_v1(&envelope.code),
         message: envelope.message.clone(),
         kind: map_error_kind(envelope.kind),
+        retriable: envelope.class.is_retriable(),
         meta,
     }
 }
@@ -55,6 +60,32 @@ pub fn result_to_api_v1_result<T>(
     }
 }
 
+/// Map a domain search result into an API v1 search result DTO.
+///
+/// `metric` is the distance metric of the backend that scored the result; it
+/// drives `normalizedScore` so clients can compare scores across backends.
+#[must_use]
+pub fn search_result_to_api_v1(
+    result: &SearchResult,
+    metric: ApiV1ScoreMetric,
+) -> ApiV1SearchResultDto {
+    let score = f64::from(result.score);
+    ApiV1SearchResultDto {
+        content: result.content.as_deref().unwrap_or_default().to_string(),
+        relative_path: result.key.relative_path.to_string(),
+        start_line: result.key.span.start_line(),
+        end_line: result.key.span.end_line(),
+        language: result
+            .language
+            .unwrap_or(Language::Text)
+            .as_str()
+            .to_string(),
+        score,
+        normalized_score: metric.normalize(score),
+        metric,
+    }
+}
+
 const fn map_error_kind(kind: ErrorKind) -> ApiV1ErrorKind {
     match kind {
         ErrorKind::Expected | ErrorKind::Unexpected => ApiV1ErrorKind::Expected,
@@ -109,6 +140,7 @@ fn is_query_key(key: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use semantic_code_domain::{LineSpan, SearchResultKey};
     use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope};
     use std::collections::BTreeMap;
     use std::error::Error;
@@ -147,6 +179,19 @@ mod tests {
         assert_eq!(dto.kind, ApiV1ErrorKind::Expected);
     }
 
+    #[test]
+    fn mapping_preserves_retriable_class() {
+        let retriable = ErrorEnvelope::unexpected(
+            ErrorCode::new("vector", "vdb_connection"),
+            "connection reset",
+            ErrorClass::Retriable,
+        );
+        assert!(error_envelope_to_api_v1_error(&retriable, None).retriable);
+
+        let permanent = ErrorEnvelope::expected(ErrorCode::invalid_input(), "bad input");
+        assert!(!error_envelope_to_api_v1_error(&permanent, None).retriable);
+    }
+
     #[test]
     fn result_mapping_preserves_ok_and_err() {
         let ok_result: Result<u32, ErrorEnvelope> = Ok(10);
@@ -162,4 +207,54 @@ mod tests {
         let mapped = result_to_api_v1_result(err_result, Some(extra));
         assert!(matches!(mapped, ApiV1Result::Err { ok: false, .. }));
     }
+
+    #[test]
+    fn search_results_carry_metric_and_normalized_score() -> Result<(), Box<dyn Error>> {
+        let cases = [
+            (ApiV1ScoreMetric::Cosine, 0.75_f32),
+            (ApiV1ScoreMetric::Cosine, -0.2),
+            (ApiV1ScoreMetric::Ip, 1.3),
+            (ApiV1ScoreMetric::L2, 0.5),
+            (ApiV1ScoreMetric::Bm25, 12.0),
+            (ApiV1ScoreMetric::Cosine, f32::NAN),
+        ];
+        for (metric, score) in cases {
+            let result = SearchResult {
+                key: SearchResultKey {
+                    relative_path: "src/lib.rs".into(),
+                    span: LineSpan::new(2, 4)?,
+                },
+                content: Some("fn lib() {}".into()),
+                language: Some(Language::Rust),
+                score,
+            };
+            let mapped = result_to_api_v1_result(
+                Ok::<_, ErrorEnvelope>(search_result_to_api_v1(&result, metric)),
+                None,
+            );
+            let value = serde_json::to_value(&mapped)?;
+            let data = value
+                .get("data")
+                .ok_or_else(|| std::io::Error::other("ok result should carry data"))?;
+            assert_eq!(data.get("metric"), Some(&serde_json::to_value(metric)?));
+            assert!(data.get("score").is_some(), "{metric:?} {score}");
+            let normalized = data
+                .get("normalizedScore")
+                .and_then(serde_json::Value::as_f64)
+                .ok_or_else(|| std::io::Error::other("normalizedScore should be a number"))?;
+            assert!(
+                (0.0..=1.0).contains(&normalized),
+                "{metric:?} {score} -> {normalized}"
+            );
+        }
+
+        assert!((ApiV1ScoreMetric::Cosine.normalize(0.75) - 0.75).abs() < f64::EPSILON);
+        assert!((ApiV1ScoreMetric::L2.normalize(1.0) - 0.5).abs() < f64::EPSILON);
+        assert!(ApiV1ScoreMetric::L2.normalize(0.1) > ApiV1ScoreMetric::L2.normalize(2.0));
+        assert_eq!(
+            ApiV1ScoreMetric::from_metric_type(" ip "),
+            Some(ApiV1ScoreMetric::Ip)
+        );
+        Ok(())
+    }
 }
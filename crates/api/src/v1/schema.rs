@@ -1,32 +1,144 @@
 //! JSON Schema exports for API v1 request DTOs.
+//!
+//! With the `schema` feature, bounds from `#[validate(...)]` attributes
+//! (`minimum`/`maximum`, `minLength`/`maxLength`, `pattern`) are merged into
+//! the generated property schemas so clients see the enforced limits.
 
 use crate::v1::{
     ApiV1ClearIndexRequestDto, ApiV1IndexRequestDto, ApiV1ReindexByChangeRequestDto,
     ApiV1SearchRequestDto,
 };
-use schemars::schema::RootSchema;
+use schemars::schema::{RootSchema, Schema, SchemaObject};
 use schemars::schema_for;
+use serde_json::Value;
+
+#[cfg(feature = "schema")]
+macro_rules! validation_fragment {
+    ($dto:ty) => {
+        Some(&<$dto>::schema_fragment())
+    };
+}
+
+#[cfg(not(feature = "schema"))]
+macro_rules! validation_fragment {
+    ($dto:ty) => {
+        None
+    };
+}
 
 /// JSON Schema for `ApiV1IndexRequestDto`.
 #[must_use]
 pub fn api_v1_index_request_schema() -> RootSchema {
-    schema_for!(ApiV1IndexRequestDto)
+    with_validation_bounds(
+        schema_for!(ApiV1IndexRequestDto),
+        validation_fragment!(ApiV1IndexRequestDto),
+    )
 }
 
 /// JSON Schema for `ApiV1SearchRequestDto`.
 #[must_use]
 pub fn api_v1_search_request_schema() -> RootSchema {
-    schema_for!(ApiV1SearchRequestDto)
+    with_validation_bounds(
+        schema_for!(ApiV1SearchRequestDto),
+        validation_fragment!(ApiV1SearchRequestDto),
+    )
 }
 
 /// JSON Schema for `ApiV1ReindexByChangeRequestDto`.
 #[must_use]
 pub fn api_v1_reindex_by_change_request_schema() -> RootSchema {
-    schema_for!(ApiV1ReindexByChangeRequestDto)
+    with_validation_bounds(
+        schema_for!(ApiV1ReindexByChangeRequestDto),
+        validation_fragment!(ApiV1ReindexByChangeRequestDto),
+    )
 }
 
 /// JSON Schema for `ApiV1ClearIndexRequestDto`.
 #[must_use]
 pub fn api_v1_clear_index_request_schema() -> RootSchema {
-    schema_for!(ApiV1ClearIndexRequestDto)
+    with_validation_bounds(
+        schema_for!(ApiV1ClearIndexRequestDto),
+        validation_fragment!(ApiV1ClearIndexRequestDto),
+    )
+}
+
+/// Merge a derived `schema_fragment()` into the top-level property schemas.
+fn with_validation_bounds(mut schema: RootSchema, fragment: Option<&Value>) -> RootSchema {
+    let Some(properties) = fragment
+        .and_then(|fragment| fragment.get("properties"))
+        .and_then(Value::as_object)
+    else {
+        return schema;
+    };
+    let object = schema.schema.object();
+    for (name, keywords) in properties {
+        if let Some(Schema::Object(property)) = object.properties.get_mut(name) {
+            apply_keywords(property, keywords);
+        }
+    }
+    schema
+}
+
+fn apply_keywords(property: &mut SchemaObject, keywords: &Value) {
+    let length = |key: &str| {
+        keywords
+            .get(key)
+            .and_then(Value::as_u64)
+            .and_then(|value| u32::try_from(value).ok())
+    };
+    if let Some(minimum) = keywords.get("minimum").and_then(Value::as_f64) {
+        property.number().minimum = Some(minimum);
+    }
+    if let Some(maximum) = keywords.get("maximum").and_then(Value::as_f64) {
+        property.number().maximum = Some(maximum);
+    }
+    if let Some(min_length) = length("minLength") {
+        property.string().min_length = Some(min_length);
+    }
+    if let Some(max_length) = length("maxLength") {
+        property.string().max_length = Some(max_length);
+    }
+    if let Some(pattern) = keywords.get("pattern").and_then(Value::as_str) {
+        property.string().pattern = Some(pattern.to_owned());
+    }
+}
+
+#[cfg(all(test, feature = "schema"))]
+mod tests {
+    use super::*;
+    use crate::v1::ApiV1ValidationIssue;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, schemars::JsonSchema, semantic_code_validate_derive::Validate)]
+    #[serde(rename_all = "camelCase")]
+    #[validate(error = "ApiV1ValidationIssue")]
+    struct BoundedDto {
+        #[validate(field = "pageSize", range(min = 1, max = 100))]
+        page_size: u32,
+        #[validate(non_empty, length(min = 1, max = 64))]
+        label: Option<String>,
+    }
+
+    #[test]
+    fn range_bounds_appear_in_schema_fragment() {
+        let fragment = BoundedDto::schema_fragment();
+        let page_size = &fragment["properties"]["pageSize"];
+        assert_eq!(page_size["minimum"], 1);
+        assert_eq!(page_size["maximum"], 100);
+
+        let label = &fragment["properties"]["label"];
+        assert_eq!(label["minLength"], 1);
+        assert_eq!(label["maxLength"], 64);
+        assert_eq!(label["pattern"], "\\S");
+    }
+
+    #[test]
+    fn request_schemas_include_validation_bounds() -> Result<(), serde_json::Error> {
+        let schema = serde_json::to_value(api_v1_search_request_schema())?;
+        let top_k = &schema["properties"]["topK"];
+        assert_eq!(top_k["minimum"], 1.0);
+        assert_eq!(top_k["maximum"], 50.0);
+        assert_eq!(schema["properties"]["query"]["minLength"], 1);
+        Ok(())
+    }
 }
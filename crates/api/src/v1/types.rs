@@ -30,6 +30,9 @@ pub struct ApiV1ErrorDto {
     pub message: String,
     /// Error category.
     pub kind: ApiV1ErrorKind,
+    /// Whether retrying the same request may succeed (transient failure).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub retriable: bool,
     /// Optional metadata for debugging and correlation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<ApiV1ErrorMeta>,
@@ -161,14 +164,73 @@ pub struct ApiV1SearchResultDto {
     pub content: String,
     /// Relative path of the matching file.
     pub relative_path: String,
-    /// Starting line of the match (1-indexed).
+    /// Starting line of the match (1-indexed, `startLine <= endLine`).
     pub start_line: u32,
-    /// Ending line of the match (1-indexed).
+    /// Ending line of the match (1-indexed, inclusive).
     pub end_line: u32,
     /// Language identifier.
     pub language: String,
-    /// Similarity score.
+    /// Similarity score as reported by the backend.
     pub score: f64,
+    /// `score` rescaled into `[0, 1]` (higher is more similar) for `metric`.
+    #[serde(default)]
+    pub normalized_score: f64,
+    /// Distance metric that produced `score`.
+    #[serde(default)]
+    pub metric: ApiV1ScoreMetric,
+}
+
+/// Distance metric behind an API v1 search score.
+///
+/// Names match the Milvus `metricType` values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ApiV1ScoreMetric {
+    /// Cosine similarity (the local index and the Milvus default).
+    #[default]
+    Cosine,
+    /// Inner product.
+    Ip,
+    /// Euclidean distance (lower is more similar).
+    L2,
+    /// BM25 relevance (sparse/hybrid search).
+    Bm25,
+}
+
+impl ApiV1ScoreMetric {
+    /// Parse a Milvus `metricType` value (case-insensitive).
+    #[must_use]
+    pub fn from_metric_type(metric_type: &str) -> Option<Self> {
+        match metric_type.trim().to_ascii_uppercase().as_str() {
+            "COSINE" => Some(Self::Cosine),
+            "IP" => Some(Self::Ip),
+            "L2" => Some(Self::L2),
+            "BM25" => Some(Self::Bm25),
+            _ => None,
+        }
+    }
+
+    /// Rescale a raw score for this metric into `[0, 1]`.
+    ///
+    /// Cosine and inner-product similarities are clamped (the local index
+    /// already clamps negative cosine similarity to `0`, and embeddings are
+    /// unit-length, so IP equals cosine); L2 distances map to `1 / (1 + d)`
+    /// and BM25 scores to `s / (1 + s)`. Non-finite scores map to `0`.
+    #[must_use]
+    pub fn normalize(self, score: f64) -> f64 {
+        if !score.is_finite() {
+            return 0.0;
+        }
+        let normalized = match self {
+            Self::Cosine | Self::Ip => score,
+            Self::L2 => 1.0 / (1.0 + score.max(0.0)),
+            Self::Bm25 => {
+                let score = score.max(0.0);
+                score / (1.0 + score)
+            },
+        };
+        normalized.clamp(0.0, 1.0)
+    }
 }
 
 /// API v1 search response payload.
@@ -227,6 +289,10 @@ pub struct ApiV1ClearIndexRequestDto {
     /// Root path of the codebase to clear.
     #[validate(field = "codebaseRoot", non_empty)]
     pub codebase_root: String,
+    /// Optional explicit collection to clear instead of the derived one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(field = "collectionName", non_empty)]
+    pub collection_name: Option<String>,
 }
 
 /// API v1 clear-index response payload.
@@ -248,6 +314,7 @@ mod tests {
             code: "ERR_DOMAIN_INVALID_COLLECTION_NAME".to_string(),
             message: "CollectionName must match /^[a-zA-Z][a-zA-Z0-9_]*$/".to_string(),
             kind: ApiV1ErrorKind::Expected,
+            retriable: false,
             meta: Some(BTreeMap::from([(
                 "input".to_string(),
                 "bad-name".to_string(),
@@ -284,6 +351,8 @@ mod tests {
             end_line: 1,
             language: "rust".to_string(),
             score: 0.88,
+            normalized_score: 0.88,
+            metric: ApiV1ScoreMetric::Cosine,
         };
         let search_response = ApiV1SearchResponseDto {
             results: vec![search_result],
@@ -310,6 +379,7 @@ mod tests {
 
         let clear_request = ApiV1ClearIndexRequestDto {
             codebase_root: "/tmp/repo".to_string(),
+            collection_name: Some("code_chunks_orphan".to_string()),
         };
         let clear_json = serde_json::to_string(&clear_request)?;
         let parsed: ApiV1ClearIndexRequestDto = serde_json::from_str(&clear_json)?;
@@ -336,4 +406,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn search_result_span_fields_are_flat_camel_case() -> Result<(), Box<dyn Error>> {
+        let result = ApiV1SearchResultDto {
+            content: "fn main() {}".to_string(),
+            relative_path: "src/main.rs".to_string(),
+            start_line: 3,
+            end_line: 7,
+            language: "rust".to_string(),
+            score: 0.5,
+            normalized_score: 0.5,
+            metric: ApiV1ScoreMetric::Cosine,
+        };
+        let value = serde_json::to_value(&result)?;
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "content": "fn main() {}",
+                "relativePath": "src/main.rs",
+                "startLine": 3,
+                "endLine": 7,
+                "language": "rust",
+                "score": 0.5,
+                "normalizedScore": 0.5,
+                "metric": "COSINE",
+            })
+        );
+        Ok(())
+    }
 }
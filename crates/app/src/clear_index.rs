@@ -1,5 +1,6 @@
 //! Clear an index collection and associated sync snapshot.
 
+use crate::sharding::index_collection_names;
 use semantic_code_domain::CollectionName;
 use semantic_code_ports::{FileSyncPort, LoggerPort, TelemetryPort, VectorDbPort};
 use semantic_code_shared::{
@@ -25,6 +26,8 @@ pub struct ClearIndexInput {
     pub codebase_root: PathBuf,
     /// Target collection name.
     pub collection_name: CollectionName,
+    /// Shard count the index was built with; every shard collection is dropped.
+    pub shard_count: Option<u32>,
 }
 
 /// Dependencies required by clear-index.
@@ -67,30 +70,10 @@ pub async fn clear_index(
 
     let result: Result<()> = (async {
         ctx.ensure_not_cancelled("clear_index.start")?;
+        ctx.ensure_writable("clear_index")?;
 
-        let provider_tags = tags_provider(deps.vectordb.provider().id.as_str());
-        let has_collection_timer = deps.telemetry.as_ref().map(|telemetry| {
-            telemetry.start_timer("backend.clearIndex.hasCollection", Some(&provider_tags))
-        });
-        let exists = deps
-            .vectordb
-            .has_collection(ctx, input.collection_name.clone())
-            .await?;
-        tracing::debug!(collection_exists = exists, "checked collection existence");
-        if let Some(timer) = has_collection_timer.as_ref() {
-            timer.stop();
-        }
-
-        if exists {
-            ctx.ensure_not_cancelled("clear_index.drop_collection")?;
-            let drop_tags = tags_provider(deps.vectordb.provider().id.as_str());
-            let drop_timer = deps.telemetry.as_ref().map(|telemetry| {
-                telemetry.start_timer("backend.clearIndex.dropCollection", Some(&drop_tags))
-            });
-            drop_collection_with_retry(ctx, deps, input.collection_name.clone()).await?;
-            if let Some(timer) = drop_timer.as_ref() {
-                timer.stop();
-            }
+        for collection_name in index_collection_names(&input.collection_name, input.shard_count)? {
+            drop_collection_if_exists(ctx, deps, collection_name).await?;
         }
 
         ctx.ensure_not_cancelled("clear_index.delete_snapshot")?;
@@ -135,6 +118,42 @@ pub async fn clear_index(
     }
 }
 
+async fn drop_collection_if_exists(
+    ctx: &RequestContext,
+    deps: &ClearIndexDeps,
+    collection_name: CollectionName,
+) -> Result<()> {
+    let provider_tags = tags_provider(deps.vectordb.provider().id.as_str());
+    let has_collection_timer = deps.telemetry.as_ref().map(|telemetry| {
+        telemetry.start_timer("backend.clearIndex.hasCollection", Some(&provider_tags))
+    });
+    let exists = deps
+        .vectordb
+        .has_collection(ctx, collection_name.clone())
+        .await?;
+    tracing::debug!(
+        collection = %collection_name.as_str(),
+        collection_exists = exists,
+        "checked collection existence"
+    );
+    if let Some(timer) = has_collection_timer.as_ref() {
+        timer.stop();
+    }
+
+    if exists {
+        ctx.ensure_not_cancelled("clear_index.drop_collection")?;
+        let drop_tags = tags_provider(deps.vectordb.provider().id.as_str());
+        let drop_timer = deps.telemetry.as_ref().map(|telemetry| {
+            telemetry.start_timer("backend.clearIndex.dropCollection", Some(&drop_tags))
+        });
+        drop_collection_with_retry(ctx, deps, collection_name).await?;
+        if let Some(timer) = drop_timer.as_ref() {
+            timer.stop();
+        }
+    }
+    Ok(())
+}
+
 fn log_clear_index_failure(
     deps: &ClearIndexDeps,
     input: &ClearIndexInput,
@@ -312,6 +331,7 @@ mod tests {
         FileSyncOptions, FileSyncPort, VectorDbProviderInfo, VectorDocumentForInsert,
         VectorSearchResponse,
     };
+    use std::sync::Mutex;
     use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
     #[derive(Clone)]
@@ -319,6 +339,7 @@ mod tests {
         provider: VectorDbProviderInfo,
         has_collection: bool,
         drop_called: Arc<AtomicBool>,
+        dropped: Arc<Mutex<Vec<CollectionName>>>,
     }
 
     #[derive(Clone)]
@@ -480,6 +501,7 @@ mod tests {
                 },
                 has_collection,
                 drop_called: Arc::new(AtomicBool::new(false)),
+                dropped: Arc::new(Mutex::new(Vec::new())),
             })
         }
     }
@@ -512,11 +534,15 @@ mod tests {
         fn drop_collection(
             &self,
             _ctx: &RequestContext,
-            _collection_name: CollectionName,
+            collection_name: CollectionName,
         ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
             let called = self.drop_called.clone();
+            let dropped = self.dropped.clone();
             Box::pin(async move {
                 called.store(true, Ordering::SeqCst);
+                if let Ok(mut guard) = dropped.lock() {
+                    guard.push(collection_name);
+                }
                 Ok(())
             })
         }
@@ -622,6 +648,14 @@ mod tests {
             Box::pin(async move { Ok(semantic_code_ports::FileChangeSet::default()) })
         }
 
+        fn invalidate_files(
+            &self,
+            _ctx: &RequestContext,
+            _relative_paths: Vec<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
         fn delete_snapshot(
             &self,
             _ctx: &RequestContext,
@@ -652,6 +686,7 @@ mod tests {
             codebase_root: PathBuf::from("/tmp/repo"),
             collection_name: CollectionName::parse("code_chunks_test")
                 .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
         };
 
         let result = clear_index(&ctx, &deps, input).await;
@@ -659,6 +694,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn clear_index_read_only_errors_without_touching_backend() -> Result<()> {
+        let ctx = RequestContext::new_request().with_read_only(true);
+        let vectordb = Arc::new(NoopVectorDb::new(true)?);
+        let file_sync = Arc::new(TestFileSync::default());
+        let deps = ClearIndexDeps {
+            vectordb: vectordb.clone(),
+            file_sync: file_sync.clone(),
+            logger: None,
+            telemetry: None,
+        };
+        let input = ClearIndexInput {
+            codebase_root: PathBuf::from("/tmp/repo"),
+            collection_name: CollectionName::parse("code_chunks_test")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
+        };
+
+        let result = clear_index(&ctx, &deps, input).await;
+        assert!(matches!(result, Err(error) if error.code == ErrorCode::read_only()));
+        assert!(!vectordb.drop_called.load(Ordering::SeqCst));
+        assert!(!file_sync.deleted.load(Ordering::SeqCst));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn clear_index_skips_drop_when_missing() -> Result<()> {
         let ctx = RequestContext::new_request();
@@ -675,6 +735,7 @@ mod tests {
             codebase_root: PathBuf::from("/tmp/repo"),
             collection_name: CollectionName::parse("code_chunks_test")
                 .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
         };
 
         clear_index(&ctx, &deps, input).await?;
@@ -683,6 +744,70 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn clear_index_drops_explicit_collection() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let vectordb = Arc::new(NoopVectorDb::new(true)?);
+        let file_sync = Arc::new(TestFileSync::default());
+        let deps = ClearIndexDeps {
+            vectordb: vectordb.clone(),
+            file_sync,
+            logger: None,
+            telemetry: None,
+        };
+
+        let explicit = CollectionName::parse("code_chunks_orphan").map_err(ErrorEnvelope::from)?;
+        let input = ClearIndexInput {
+            codebase_root: PathBuf::from("/tmp/repo"),
+            collection_name: explicit.clone(),
+            shard_count: None,
+        };
+
+        clear_index(&ctx, &deps, input).await?;
+        let dropped = vectordb
+            .dropped
+            .lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_default();
+        assert_eq!(dropped, vec![explicit]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn clear_index_drops_every_shard() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let vectordb = Arc::new(NoopVectorDb::new(true)?);
+        let file_sync = Arc::new(TestFileSync::default());
+        let deps = ClearIndexDeps {
+            vectordb: vectordb.clone(),
+            file_sync,
+            logger: None,
+            telemetry: None,
+        };
+        let input = ClearIndexInput {
+            codebase_root: PathBuf::from("/tmp/repo"),
+            collection_name: CollectionName::parse("code_chunks_test")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: Some(2),
+        };
+
+        clear_index(&ctx, &deps, input).await?;
+        let dropped = vectordb
+            .dropped
+            .lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_default();
+        let dropped = dropped
+            .iter()
+            .map(CollectionName::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dropped,
+            vec!["code_chunks_test_shard_0", "code_chunks_test_shard_1"]
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn clear_index_retries_transient_milvus_drop_collection_error() -> Result<()> {
         let ctx = RequestContext::new_request();
@@ -698,6 +823,7 @@ mod tests {
             codebase_root: PathBuf::from("/tmp/repo"),
             collection_name: CollectionName::parse("code_chunks_retry")
                 .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
         };
 
         clear_index(&ctx, &deps, input).await?;
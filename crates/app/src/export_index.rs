@@ -0,0 +1,301 @@
+//! Export every indexed document of a collection as JSON lines.
+
+use crate::migrate_index::take_row_metadata;
+use crate::sharding::index_collection_names;
+use semantic_code_domain::CollectionName;
+use semantic_code_ports::{VectorDbPort, VectorDbRow};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+/// Rows fetched per `query_paged` call while exporting.
+const EXPORT_PAGE_SIZE: NonZeroU32 = match NonZeroU32::new(1_000) {
+    Some(size) => size,
+    None => NonZeroU32::MIN,
+};
+
+/// Input payload for exporting an index.
+#[derive(Debug, Clone)]
+pub struct ExportIndexInput {
+    /// Collection to export.
+    pub collection_name: CollectionName,
+    /// Shard count the index was built with; every shard collection is exported.
+    pub shard_count: Option<u32>,
+    /// Include each document's dense vector in its line.
+    pub include_vectors: bool,
+}
+
+/// Dependencies required by export-index.
+#[derive(Clone)]
+pub struct ExportIndexDeps {
+    /// Vector DB adapter the documents are read from.
+    pub vectordb: Arc<dyn VectorDbPort>,
+}
+
+/// Result of a completed export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportIndexOutput {
+    /// Exported collection name.
+    pub collection_name: CollectionName,
+    /// Number of lines written.
+    pub exported_documents: u64,
+}
+
+/// Write every document of a collection to `out` as JSONL, sorted by id.
+///
+/// Each line is an object with `id`, `content`, `metadata`, and (with
+/// `include_vectors`) `vector`, keys in sorted order. Documents are read with
+/// paged `query_paged` calls and buffered as serialized lines so the output
+/// order does not depend on the backend's row order. A sharded index exports
+/// every shard that exists; when no collection exists the error is
+/// `core:not_found`.
+#[tracing::instrument(
+    name = "app.export_index",
+    skip_all,
+    fields(
+        collection = %input.collection_name.as_str(),
+        include_vectors = input.include_vectors,
+    )
+)]
+pub async fn export_index(
+    ctx: &RequestContext,
+    deps: &ExportIndexDeps,
+    input: ExportIndexInput,
+    out: &mut (dyn Write + Send),
+) -> Result<ExportIndexOutput> {
+    ctx.ensure_not_cancelled("export_index.start")?;
+    let collection_name = input.collection_name;
+    let mut existing = Vec::new();
+    for shard_collection in index_collection_names(&collection_name, input.shard_count)? {
+        if deps
+            .vectordb
+            .has_collection(ctx, shard_collection.clone())
+            .await?
+        {
+            existing.push(shard_collection);
+        }
+    }
+    if existing.is_empty() {
+        return Err(
+            ErrorEnvelope::expected(ErrorCode::not_found(), "collection not found")
+                .with_metadata("collection", collection_name.as_str().to_owned()),
+        );
+    }
+
+    let mut lines: BTreeMap<Box<str>, String> = BTreeMap::new();
+    for shard_collection in existing {
+        read_collection_lines(
+            ctx,
+            deps,
+            shard_collection,
+            input.include_vectors,
+            &mut lines,
+        )
+        .await?;
+    }
+
+    ctx.ensure_not_cancelled("export_index.write")?;
+    for line in lines.values() {
+        writeln!(out, "{line}").map_err(ErrorEnvelope::from)?;
+    }
+    out.flush().map_err(ErrorEnvelope::from)?;
+
+    Ok(ExportIndexOutput {
+        collection_name,
+        exported_documents: u64::try_from(lines.len()).unwrap_or(u64::MAX),
+    })
+}
+
+/// Serialize every row of one collection into `lines`, keyed by id.
+async fn read_collection_lines(
+    ctx: &RequestContext,
+    deps: &ExportIndexDeps,
+    collection_name: CollectionName,
+    include_vectors: bool,
+    lines: &mut BTreeMap<Box<str>, String>,
+) -> Result<()> {
+    let mut output_fields = vec![Box::from("id"), Box::from("content"), Box::from("metadata")];
+    if include_vectors {
+        output_fields.push(Box::from("vector"));
+    }
+
+    let mut page_token = None;
+    loop {
+        ctx.ensure_not_cancelled("export_index.query")?;
+        let (rows, next) = deps
+            .vectordb
+            .query_paged(
+                ctx,
+                collection_name.clone(),
+                Box::from(""),
+                output_fields.clone(),
+                EXPORT_PAGE_SIZE,
+                page_token,
+            )
+            .await?;
+        for row in rows {
+            let (id, line) = export_line(row, include_vectors)?;
+            lines.insert(id, line);
+        }
+        if next.is_none() {
+            return Ok(());
+        }
+        page_token = next;
+    }
+}
+
+/// Serialize one query row, returning its id and JSON line.
+fn export_line(mut row: VectorDbRow, include_vectors: bool) -> Result<(Box<str>, String)> {
+    let Some(Value::String(id)) = row.remove("id") else {
+        return Err(invalid_row("row is missing a string `id`", None));
+    };
+    let Some(Value::String(content)) = row.remove("content") else {
+        return Err(invalid_row("row is missing `content`", Some(&id)));
+    };
+    let Some(metadata) = take_row_metadata(&mut row) else {
+        return Err(invalid_row(
+            "row has missing or malformed `metadata`",
+            Some(&id),
+        ));
+    };
+    let metadata = serde_json::to_value(metadata).map_err(|error| {
+        invalid_row(&format!("metadata is not serializable: {error}"), Some(&id))
+    })?;
+
+    let mut object = Map::new();
+    object.insert("id".to_owned(), Value::String(id.clone()));
+    object.insert("content".to_owned(), Value::String(content));
+    object.insert("metadata".to_owned(), metadata);
+    if include_vectors {
+        let Some(vector @ Value::Array(_)) = row.remove("vector") else {
+            return Err(invalid_row(
+                "row is missing `vector`; the backend cannot export vectors",
+                Some(&id),
+            ));
+        };
+        object.insert("vector".to_owned(), vector);
+    }
+    Ok((id.into_boxed_str(), Value::Object(object).to_string()))
+}
+
+fn invalid_row(message: &str, id: Option<&str>) -> ErrorEnvelope {
+    let error = ErrorEnvelope::unexpected(
+        ErrorCode::new("export", "invalid_source_row"),
+        message,
+        ErrorClass::NonRetriable,
+    );
+    match id {
+        Some(id) => error.with_metadata("id", id.to_owned()),
+        None => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_adapters::LocalVectorDbBuilder;
+    use semantic_code_config::{SnapshotStorageMode, VectorSnapshotFormat};
+    use semantic_code_domain::{LineSpan, VectorDocumentMetadata};
+    use semantic_code_ports::VectorDocumentForInsert;
+    use semantic_code_shared::CancellationToken;
+    use semantic_code_vector::HnswKernel;
+
+    fn document(id: &str, path: &str, seed: f32) -> Result<VectorDocumentForInsert> {
+        Ok(VectorDocumentForInsert {
+            id: id.into(),
+            vector: Arc::from(vec![1.0, seed, 0.5]),
+            content: format!("// {path}").into_boxed_str(),
+            metadata: VectorDocumentMetadata {
+                relative_path: path.into(),
+                language: None,
+                file_extension: Some("rs".into()),
+                span: LineSpan::new(1, 2).map_err(ErrorEnvelope::from)?,
+                fragment_start_byte: None,
+                fragment_end_byte: None,
+                node_kind: None,
+                extra: BTreeMap::new(),
+                mtime_ms: None,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn export_writes_one_sorted_line_per_document() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_export").map_err(ErrorEnvelope::from)?;
+        let root = std::env::temp_dir().join(format!("sca-export-{}", std::process::id()));
+        let db = LocalVectorDbBuilder::new(
+            root.clone(),
+            Arc::new(HnswKernel::with_ef_search(32)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(root.clone()))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        db.create_collection(&ctx, collection_name.clone(), 3, None)
+            .await?;
+        db.insert(
+            &ctx,
+            collection_name.clone(),
+            vec![
+                document("chunk-b", "src/b.rs", 2.0)?,
+                document("chunk-a", "src/a.rs", 1.0)?,
+            ],
+        )
+        .await?;
+        let deps = ExportIndexDeps {
+            vectordb: Arc::new(db),
+        };
+
+        let mut out = Vec::new();
+        let output = export_index(
+            &ctx,
+            &deps,
+            ExportIndexInput {
+                collection_name,
+                shard_count: None,
+                include_vectors: true,
+            },
+            &mut out,
+        )
+        .await;
+        let _ = std::fs::remove_dir_all(&root);
+        let output = output?;
+
+        assert_eq!(output.exported_documents, 2);
+        let text = String::from_utf8(out).map_err(|error| {
+            ErrorEnvelope::expected(ErrorCode::invalid_input(), error.to_string())
+        })?;
+        let lines = text
+            .lines()
+            .map(serde_json::from_str::<Value>)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|error| {
+                ErrorEnvelope::expected(ErrorCode::invalid_input(), error.to_string())
+            })?;
+        let ids: Vec<_> = lines
+            .iter()
+            .map(|line| line.get("id").and_then(Value::as_str))
+            .collect();
+        assert_eq!(ids, vec![Some("chunk-a"), Some("chunk-b")]);
+        let first = lines.first();
+        assert_eq!(
+            first
+                .and_then(|line| line.pointer("/metadata/relativePath"))
+                .and_then(Value::as_str),
+            Some("src/a.rs")
+        );
+        assert_eq!(
+            first
+                .and_then(|line| line.get("vector"))
+                .and_then(Value::as_array)
+                .map(Vec::len),
+            Some(3)
+        );
+        Ok(())
+    }
+}
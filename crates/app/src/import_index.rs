@@ -0,0 +1,432 @@
+//! Bulk-load a collection from a JSONL dump produced by `export_index`.
+
+use crate::migrate_index::json_f32;
+use crate::sharding::{index_collection_names, shard_for_id};
+use semantic_code_domain::{CollectionName, IndexMode, VectorDocumentMetadata};
+use semantic_code_ports::{VectorDbPort, VectorDocumentForInsert};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+/// Input payload for importing an index.
+#[derive(Debug, Clone)]
+pub struct ImportIndexInput {
+    /// Collection to load into; created when missing.
+    pub collection_name: CollectionName,
+    /// Shard count of the target index; documents are routed to shard
+    /// collections by id, the same way `index` places them.
+    pub shard_count: Option<u32>,
+    /// Index mode used when creating the collection and inserting.
+    pub index_mode: IndexMode,
+    /// Maximum documents per insert call.
+    pub batch_size: NonZeroUsize,
+}
+
+/// Dependencies required by import-index.
+#[derive(Clone)]
+pub struct ImportIndexDeps {
+    /// Vector DB adapter the documents are written to.
+    pub vectordb: Arc<dyn VectorDbPort>,
+}
+
+/// Result of a completed import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportIndexOutput {
+    /// Target collection name.
+    pub collection_name: CollectionName,
+    /// Vector dimension of the imported documents.
+    pub dimension: u32,
+    /// Number of documents inserted.
+    pub imported_documents: u64,
+    /// Whether a collection was created by this run.
+    pub created_collection: bool,
+}
+
+/// Insert every JSONL document from `input_lines` without re-embedding.
+///
+/// Each non-empty line must hold `id`, `content`, `metadata`, and `vector`
+/// (the `export --with-vectors` shape). The whole dump is parsed and validated
+/// before the first write: malformed lines fail with `import:invalid_line`,
+/// and a vector whose length differs from the existing collection (or from
+/// the first line, for a new collection) fails with `import:dimension_mismatch`.
+/// Both errors carry the 1-based `line` number.
+#[tracing::instrument(
+    name = "app.import_index",
+    skip_all,
+    fields(
+        collection = %input.collection_name.as_str(),
+        index_mode = %input.index_mode.as_str(),
+    )
+)]
+pub async fn import_index(
+    ctx: &RequestContext,
+    deps: &ImportIndexDeps,
+    input: ImportIndexInput,
+    input_lines: &mut (dyn BufRead + Send),
+) -> Result<ImportIndexOutput> {
+    ctx.ensure_not_cancelled("import_index.start")?;
+    ctx.ensure_writable("import_index")?;
+    let collection_name = input.collection_name;
+    let collections = index_collection_names(&collection_name, input.shard_count)?;
+    let mut existing = Vec::with_capacity(collections.len());
+    let mut dimension = None;
+    for shard_collection in &collections {
+        let exists = deps
+            .vectordb
+            .has_collection(ctx, shard_collection.clone())
+            .await?;
+        if exists && dimension.is_none() {
+            dimension = deps
+                .vectordb
+                .collection_dimension(ctx, shard_collection.clone())
+                .await?;
+        }
+        existing.push(exists);
+    }
+
+    let documents = read_documents(input_lines, &collection_name, &mut dimension)?;
+    let Some(dimension) = dimension else {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "import input has no documents and the collection does not exist",
+        ));
+    };
+
+    let shard_count = u32::try_from(collections.len()).unwrap_or(u32::MAX);
+    let mut routed: BTreeMap<usize, Vec<VectorDocumentForInsert>> = BTreeMap::new();
+    for document in documents {
+        let shard = usize::try_from(shard_for_id(&document.id, shard_count)).unwrap_or(0);
+        routed.entry(shard).or_default().push(document);
+    }
+
+    let mut imported_documents = 0_u64;
+    let mut created_collection = false;
+    for (shard, documents) in routed {
+        let (Some(shard_collection), Some(exists)) = (collections.get(shard), existing.get(shard))
+        else {
+            return Err(ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "shard index out of range",
+                ErrorClass::NonRetriable,
+            ));
+        };
+        if !exists {
+            create_collection(ctx, deps, shard_collection, input.index_mode, dimension).await?;
+            created_collection = true;
+        }
+        let inserted = insert_batches(
+            ctx,
+            deps,
+            shard_collection,
+            input.index_mode,
+            input.batch_size,
+            documents,
+        )
+        .await?;
+        imported_documents = imported_documents.saturating_add(inserted);
+    }
+
+    Ok(ImportIndexOutput {
+        collection_name,
+        dimension,
+        imported_documents,
+        created_collection,
+    })
+}
+
+/// Parse and validate every non-empty line, checking vector lengths against
+/// `dimension` (or fixing it from the first line when unset).
+fn read_documents(
+    input_lines: &mut (dyn BufRead + Send),
+    collection_name: &CollectionName,
+    dimension: &mut Option<u32>,
+) -> Result<Vec<VectorDocumentForInsert>> {
+    let mut documents = Vec::new();
+    for (index, line) in input_lines.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.map_err(ErrorEnvelope::from)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let document = document_from_line(&line, line_number)?;
+        let actual = u32::try_from(document.vector.len()).unwrap_or(u32::MAX);
+        match *dimension {
+            Some(expected) if expected != actual => {
+                return Err(ErrorEnvelope::expected(
+                    ErrorCode::new("import", "dimension_mismatch"),
+                    format!(
+                        "line {line_number} has vector dimension {actual}, expected {expected}"
+                    ),
+                )
+                .with_metadata("line", line_number.to_string())
+                .with_metadata("collection", collection_name.as_str().to_owned()));
+            },
+            Some(_) => {},
+            None => *dimension = Some(actual),
+        }
+        documents.push(document);
+    }
+    Ok(documents)
+}
+
+async fn create_collection(
+    ctx: &RequestContext,
+    deps: &ImportIndexDeps,
+    collection_name: &CollectionName,
+    index_mode: IndexMode,
+    dimension: u32,
+) -> Result<()> {
+    let description = Some(
+        format!(
+            "semantic-code {} index imported from JSONL",
+            index_mode.as_str()
+        )
+        .into_boxed_str(),
+    );
+    match index_mode {
+        IndexMode::Hybrid => {
+            deps.vectordb
+                .create_hybrid_collection(ctx, collection_name.clone(), dimension, description)
+                .await
+        },
+        IndexMode::Dense => {
+            deps.vectordb
+                .create_collection(ctx, collection_name.clone(), dimension, description)
+                .await
+        },
+    }
+}
+
+/// Insert `documents` in `batch_size` chunks, returning the inserted count.
+async fn insert_batches(
+    ctx: &RequestContext,
+    deps: &ImportIndexDeps,
+    collection_name: &CollectionName,
+    index_mode: IndexMode,
+    batch_size: NonZeroUsize,
+    documents: Vec<VectorDocumentForInsert>,
+) -> Result<u64> {
+    let mut inserted = 0_u64;
+    let mut documents = documents.into_iter().peekable();
+    while documents.peek().is_some() {
+        ctx.ensure_not_cancelled("import_index.insert")?;
+        let batch: Vec<_> = documents.by_ref().take(batch_size.get()).collect();
+        let batch_len = u64::try_from(batch.len()).unwrap_or(u64::MAX);
+        match index_mode {
+            IndexMode::Hybrid => {
+                deps.vectordb
+                    .insert_hybrid(ctx, collection_name.clone(), batch)
+                    .await?;
+            },
+            IndexMode::Dense => {
+                deps.vectordb
+                    .insert(ctx, collection_name.clone(), batch)
+                    .await?;
+            },
+        }
+        inserted = inserted.saturating_add(batch_len);
+    }
+    Ok(inserted)
+}
+
+fn document_from_line(line: &str, line_number: usize) -> Result<VectorDocumentForInsert> {
+    let Ok(Value::Object(mut object)) = serde_json::from_str::<Value>(line) else {
+        return Err(invalid_line("line is not a JSON object", line_number));
+    };
+    let Some(Value::String(id)) = object
+        .remove("id")
+        .filter(|id| id.as_str().is_some_and(|id| !id.trim().is_empty()))
+    else {
+        return Err(invalid_line(
+            "line is missing a non-empty string `id`",
+            line_number,
+        ));
+    };
+    let Some(Value::String(content)) = object.remove("content") else {
+        return Err(invalid_line("line is missing `content`", line_number));
+    };
+    let vector = match object.remove("vector") {
+        Some(Value::Array(values)) if !values.is_empty() => {
+            values.iter().map(json_f32).collect::<Option<Vec<f32>>>()
+        },
+        _ => None,
+    };
+    let Some(vector) = vector else {
+        return Err(invalid_line(
+            "line is missing a numeric `vector`; export with --with-vectors",
+            line_number,
+        ));
+    };
+    let metadata = object
+        .remove("metadata")
+        .and_then(|value| serde_json::from_value::<VectorDocumentMetadata>(value).ok());
+    let Some(metadata) = metadata else {
+        return Err(invalid_line(
+            "line has missing or malformed `metadata`",
+            line_number,
+        ));
+    };
+    metadata.validate().map_err(|error| {
+        ErrorEnvelope::from(error).with_metadata("line", line_number.to_string())
+    })?;
+
+    Ok(VectorDocumentForInsert {
+        id: id.into_boxed_str(),
+        vector: Arc::from(vector),
+        content: content.into_boxed_str(),
+        metadata,
+    })
+}
+
+fn invalid_line(message: &str, line_number: usize) -> ErrorEnvelope {
+    ErrorEnvelope::expected(
+        ErrorCode::new("import", "invalid_line"),
+        format!("line {line_number}: {message}"),
+    )
+    .with_metadata("line", line_number.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_adapters::LocalVectorDbBuilder;
+    use semantic_code_config::{SnapshotStorageMode, VectorSnapshotFormat};
+    use semantic_code_ports::{VectorSearchOptions, VectorSearchRequest};
+    use semantic_code_shared::CancellationToken;
+    use semantic_code_vector::HnswKernel;
+
+    const DUMP: &str = concat!(
+        r#"{"id":"chunk-a","content":"fn alpha() {}","metadata":{"relativePath":"src/a.rs","span":{"startLine":1,"endLine":1}},"vector":[1.0,0.0,0.0]}"#,
+        "\n",
+        r#"{"id":"chunk-b","content":"fn beta() {}","metadata":{"relativePath":"src/b.rs","span":{"startLine":1,"endLine":1}},"vector":[0.0,1.0,0.0]}"#,
+        "\n",
+    );
+
+    fn local_db(name: &str) -> Result<(Arc<dyn VectorDbPort>, std::path::PathBuf)> {
+        let root = std::env::temp_dir().join(format!("sca-import-{}-{name}", std::process::id()));
+        let db = LocalVectorDbBuilder::new(
+            root.clone(),
+            Arc::new(HnswKernel::with_ef_search(32)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(root.clone()))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        Ok((Arc::new(db), root))
+    }
+
+    fn input(collection_name: &CollectionName) -> Result<ImportIndexInput> {
+        Ok(ImportIndexInput {
+            collection_name: collection_name.clone(),
+            shard_count: None,
+            index_mode: IndexMode::Dense,
+            batch_size: NonZeroUsize::new(1)
+                .ok_or_else(|| ErrorEnvelope::expected(ErrorCode::invalid_input(), "batch size"))?,
+        })
+    }
+
+    #[tokio::test]
+    async fn imported_documents_are_searchable() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_import").map_err(ErrorEnvelope::from)?;
+        let (vectordb, root) = local_db("search")?;
+        let deps = ImportIndexDeps {
+            vectordb: Arc::clone(&vectordb),
+        };
+
+        let output =
+            import_index(&ctx, &deps, input(&collection_name)?, &mut DUMP.as_bytes()).await;
+        let search = vectordb
+            .search(
+                &ctx,
+                VectorSearchRequest {
+                    collection_name: collection_name.clone(),
+                    query_vector: Arc::from(vec![0.0, 1.0, 0.0]),
+                    options: VectorSearchOptions {
+                        top_k: Some(2),
+                        threshold: None,
+                        filter_expr: None,
+                        exclusions: semantic_code_domain::SearchExclusions::default(),
+                    },
+                },
+            )
+            .await;
+        let _ = std::fs::remove_dir_all(&root);
+        let output = output?;
+
+        assert_eq!(output.imported_documents, 2);
+        assert_eq!(output.dimension, 3);
+        assert!(output.created_collection);
+        let ids: Vec<_> = search?
+            .results
+            .into_iter()
+            .map(|result| result.document.id)
+            .collect();
+        assert_eq!(ids, vec![Box::from("chunk-b"), Box::from("chunk-a")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dimension_mismatch_fails_before_any_write() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_import_dim").map_err(ErrorEnvelope::from)?;
+        let (vectordb, root) = local_db("dim")?;
+        let deps = ImportIndexDeps {
+            vectordb: Arc::clone(&vectordb),
+        };
+        let dump = format!(
+            "{DUMP}{}\n",
+            r#"{"id":"chunk-c","content":"fn gamma() {}","metadata":{"relativePath":"src/c.rs","span":{"startLine":1,"endLine":1}},"vector":[0.0,1.0]}"#
+        );
+
+        let result =
+            import_index(&ctx, &deps, input(&collection_name)?, &mut dump.as_bytes()).await;
+        let created = vectordb.has_collection(&ctx, collection_name).await;
+        let _ = std::fs::remove_dir_all(&root);
+
+        let error = result.err().ok_or_else(|| {
+            ErrorEnvelope::expected(ErrorCode::invalid_input(), "expected a dimension error")
+        })?;
+        assert_eq!(error.code, ErrorCode::new("import", "dimension_mismatch"));
+        assert_eq!(error.metadata.get("line").map(String::as_str), Some("3"));
+        assert!(!created?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sharded_import_routes_documents_to_their_shard() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_import_shard").map_err(ErrorEnvelope::from)?;
+        let (vectordb, root) = local_db("shard")?;
+        let deps = ImportIndexDeps {
+            vectordb: Arc::clone(&vectordb),
+        };
+        let shard_input = ImportIndexInput {
+            shard_count: Some(2),
+            ..input(&collection_name)?
+        };
+
+        let output = import_index(&ctx, &deps, shard_input, &mut DUMP.as_bytes()).await;
+        let mut placements = Vec::new();
+        for id in ["chunk-a", "chunk-b"] {
+            let shard =
+                crate::sharding::shard_collection_name(&collection_name, shard_for_id(id, 2))?;
+            placements.push(vectordb.get_vector(&ctx, shard, id.into()).await);
+        }
+        let base_exists = vectordb.has_collection(&ctx, collection_name).await;
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(output?.imported_documents, 2);
+        for placement in placements {
+            assert!(placement?.is_some());
+        }
+        assert!(!base_exists?);
+        Ok(())
+    }
+}
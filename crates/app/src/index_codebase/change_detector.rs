@@ -2,8 +2,11 @@
 
 use crate::index_codebase::IndexProgress;
 use crate::reindex_by_change::{ReindexByChangeDeps, ReindexByChangeInput};
+use crate::sharding::index_collection_names;
 use semantic_code_domain::{CollectionName, IndexMode};
-use semantic_code_ports::{FileChangeSet, FileSyncInitOptions, FileSyncOptions, VectorDbRow};
+use semantic_code_ports::{
+    FileChangeSet, FileSyncInitOptions, FileSyncOptions, FileSystemPortExt, VectorDbRow,
+};
 use semantic_code_shared::{RequestContext, Result};
 use serde_json::Value;
 use std::collections::BTreeMap;
@@ -20,6 +23,7 @@ pub async fn detect_changes(
             FileSyncInitOptions {
                 codebase_root: input.codebase_root.clone(),
                 ignore_patterns: input.ignore_patterns.clone(),
+                additional_ignore_patterns: input.additional_ignore_patterns.clone(),
             },
         )
         .await?;
@@ -49,11 +53,11 @@ pub async fn delete_removed_files(
         let delete_timer = deps.telemetry.as_ref().map(|telemetry| {
             telemetry.start_timer("backend.reindex.deleteFileChunks", Some(&delete_tags))
         });
-        delete_file_chunks_by_relative_path(
+        delete_file_chunks(
             ctx,
             deps,
-            input.collection_name.clone(),
-            relative_path.as_ref(),
+            input,
+            &input.path_normalization.apply(relative_path.as_ref()),
         )
         .await?;
         if let Some(timer) = delete_timer.as_ref() {
@@ -81,20 +85,7 @@ pub async fn delete_modified_files(
 ) -> Result<()> {
     for relative_path in &changes.modified {
         ctx.ensure_not_cancelled("reindex_by_change.modified_loop")?;
-        let delete_tags = tags_delete_reason(input.index_mode, "modified");
-        let delete_timer = deps.telemetry.as_ref().map(|telemetry| {
-            telemetry.start_timer("backend.reindex.deleteFileChunks", Some(&delete_tags))
-        });
-        delete_file_chunks_by_relative_path(
-            ctx,
-            deps,
-            input.collection_name.clone(),
-            relative_path.as_ref(),
-        )
-        .await?;
-        if let Some(timer) = delete_timer.as_ref() {
-            timer.stop();
-        }
+        delete_modified_file(ctx, deps, input, relative_path).await?;
         *processed += 1;
         emit_progress(
             input.on_progress.as_ref(),
@@ -107,6 +98,85 @@ pub async fn delete_modified_files(
     Ok(())
 }
 
+/// Delete the indexed chunks of one modified file.
+pub async fn delete_modified_file(
+    ctx: &RequestContext,
+    deps: &ReindexByChangeDeps,
+    input: &ReindexByChangeInput,
+    relative_path: &str,
+) -> Result<()> {
+    let delete_tags = tags_delete_reason(input.index_mode, "modified");
+    let delete_timer = deps.telemetry.as_ref().map(|telemetry| {
+        telemetry.start_timer("backend.reindex.deleteFileChunks", Some(&delete_tags))
+    });
+    delete_file_chunks(
+        ctx,
+        deps,
+        input,
+        &input.path_normalization.apply(relative_path),
+    )
+    .await?;
+    if let Some(timer) = delete_timer.as_ref() {
+        timer.stop();
+    }
+    Ok(())
+}
+
+/// Drop files that were deleted after change detection so the index pipeline
+/// never tries to read them. Uses `exists` rather than a full read.
+pub async fn retain_existing_files(
+    ctx: &RequestContext,
+    deps: &ReindexByChangeDeps,
+    input: &ReindexByChangeInput,
+    files: Vec<Box<str>>,
+) -> Result<Vec<Box<str>>> {
+    let session = deps.filesystem.session(input.codebase_root.clone());
+    let mut existing = Vec::with_capacity(files.len());
+    for relative_path in files {
+        ctx.ensure_not_cancelled("reindex_by_change.exists_check")?;
+        let safe_path = deps.path_policy.to_safe_relative_path(&relative_path)?;
+        if session.exists(ctx, safe_path).await? {
+            existing.push(relative_path);
+        } else {
+            tracing::debug!(
+                relative_path = %relative_path,
+                "skipping changed file that no longer exists"
+            );
+        }
+    }
+    Ok(existing)
+}
+
+/// Delete a file's chunks from every collection the index occupies.
+///
+/// Shard collections are created lazily, so missing shards are skipped.
+async fn delete_file_chunks(
+    ctx: &RequestContext,
+    deps: &ReindexByChangeDeps,
+    input: &ReindexByChangeInput,
+    relative_path: &str,
+) -> Result<()> {
+    let Some(shard_count) = input.shard_count else {
+        return delete_file_chunks_by_relative_path(
+            ctx,
+            deps,
+            input.collection_name.clone(),
+            relative_path,
+        )
+        .await;
+    };
+    for collection in index_collection_names(&input.collection_name, Some(shard_count))? {
+        if deps
+            .vectordb
+            .has_collection(ctx, collection.clone())
+            .await?
+        {
+            delete_file_chunks_by_relative_path(ctx, deps, collection, relative_path).await?;
+        }
+    }
+    Ok(())
+}
+
 pub async fn delete_file_chunks_by_relative_path(
     ctx: &RequestContext,
     deps: &ReindexByChangeDeps,
@@ -133,6 +203,7 @@ pub async fn delete_file_chunks_by_relative_path(
     }
 
     ctx.ensure_not_cancelled("reindex_by_change.delete_file_chunks.delete")?;
+    ctx.ensure_writable("reindex_by_change.delete_file_chunks")?;
     deps.vectordb.delete(ctx, collection_name, ids).await?;
 
     Ok(())
@@ -1,17 +1,30 @@
 //! Embedding orchestration for `index_codebase`.
 
 use super::inserter::{drain_insert_batches_for_backpressure, schedule_insert_batch};
-use super::types::{BatchContext, BatchState, EmbeddedBatch, PendingChunk};
-use semantic_code_domain::{ChunkIdInput, VectorDocumentMetadata, derive_chunk_id};
-use semantic_code_ports::{EmbeddingPort, TelemetryPort, VectorDocumentForInsert};
+use super::splitter::apply_oversize_policy;
+use super::types::{
+    BatchContext, BatchState, EmbeddedBatch, LanguageEmbeddingRoutes, PendingChunk,
+    ScheduledEmbeddingBatch,
+};
+use crate::apply_input_template;
+use semantic_code_domain::{
+    ChunkIdInput, Language, OversizeChunkPolicy, VectorDocumentMetadata, derive_chunk_id,
+};
+use semantic_code_ports::{
+    CodeChunk, EmbeddingPort, EmbeddingVector, TelemetryPort, VectorDocumentForInsert,
+};
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 
 struct EmbedBatchTask {
     request_ctx: RequestContext,
     embedding: Arc<dyn EmbeddingPort>,
+    embedding_routes: LanguageEmbeddingRoutes,
     telemetry: Option<Arc<dyn TelemetryPort>>,
+    document_template: Option<Box<str>>,
+    extra_metadata: BTreeMap<Box<str>, Box<str>>,
     batch: Vec<PendingChunk>,
     queued_at: Instant,
     stats: Arc<super::types::IndexStageStatsCollector>,
@@ -22,7 +35,10 @@ impl EmbedBatchTask {
         Self {
             request_ctx: ctx.ctx.clone(),
             embedding: Arc::clone(&ctx.deps.embedding),
+            embedding_routes: ctx.deps.embedding_routes.clone(),
             telemetry: ctx.deps.telemetry.clone(),
+            document_template: ctx.input.document_template.clone(),
+            extra_metadata: ctx.input.extra_metadata.clone(),
             batch,
             queued_at: Instant::now(),
             stats: Arc::clone(&ctx.stats),
@@ -33,7 +49,10 @@ impl EmbedBatchTask {
         let Self {
             request_ctx,
             embedding,
+            embedding_routes,
             telemetry,
+            document_template,
+            extra_metadata,
             batch,
             queued_at,
             stats,
@@ -54,10 +73,11 @@ impl EmbedBatchTask {
         let batch_len = u64::try_from(batch.len()).unwrap_or(u64::MAX);
         let texts = batch
             .iter()
-            .map(|chunk| chunk.content.clone().into_inner())
+            .map(|chunk| apply_input_template(document_template.as_deref(), chunk.content.as_str()))
             .collect::<Vec<_>>();
         let embed_started = Instant::now();
-        let vectors = embedding.embed_batch(&request_ctx, texts.into()).await?;
+        let vectors =
+            embed_routed(&request_ctx, &embedding, &embedding_routes, &batch, texts).await?;
         stats.record_provider_embed_batch(embed_started.elapsed());
 
         if let Some(timer) = timer.as_ref() {
@@ -65,11 +85,7 @@ impl EmbedBatchTask {
         }
 
         if vectors.len() != batch.len() {
-            return Err(ErrorEnvelope::unexpected(
-                ErrorCode::internal(),
-                "embedding batch size mismatch",
-                ErrorClass::NonRetriable,
-            ));
+            return Err(batch_size_mismatch());
         }
         stats.record_embed(batch_len, embed_started.elapsed());
 
@@ -105,6 +121,8 @@ impl EmbedBatchTask {
                     fragment_start_byte: chunk.fragment_start_byte,
                     fragment_end_byte: chunk.fragment_end_byte,
                     node_kind: None,
+                    extra: extra_metadata.clone(),
+                    mtime_ms: chunk.mtime_ms,
                 },
             });
         }
@@ -114,6 +132,128 @@ impl EmbedBatchTask {
     }
 }
 
+/// Chunks of one batch that share an embedding port, with their batch positions.
+struct RouteGroup<'a> {
+    port: &'a Arc<dyn EmbeddingPort>,
+    indices: Vec<usize>,
+    texts: Vec<Box<str>>,
+}
+
+/// Embed `texts`, sending each chunk to its language route (or `default`) and
+/// returning vectors in batch order.
+async fn embed_routed(
+    ctx: &RequestContext,
+    default: &Arc<dyn EmbeddingPort>,
+    routes: &LanguageEmbeddingRoutes,
+    batch: &[PendingChunk],
+    texts: Vec<Box<str>>,
+) -> Result<Vec<EmbeddingVector>> {
+    if routes.is_empty() {
+        return default.embed_batch(ctx, texts.into()).await;
+    }
+
+    let mut groups: Vec<RouteGroup<'_>> = Vec::new();
+    for (index, (chunk, text)) in batch.iter().zip(texts).enumerate() {
+        let port = routes.resolve(chunk.language).unwrap_or(default);
+        if let Some(group) = groups
+            .iter_mut()
+            .find(|group| Arc::ptr_eq(group.port, port))
+        {
+            group.indices.push(index);
+            group.texts.push(text);
+        } else {
+            groups.push(RouteGroup {
+                port,
+                indices: vec![index],
+                texts: vec![text],
+            });
+        }
+    }
+
+    let mut slots: Vec<Option<EmbeddingVector>> = (0..batch.len()).map(|_| None).collect();
+    for group in groups {
+        let vectors = group.port.embed_batch(ctx, group.texts.into()).await?;
+        if vectors.len() != group.indices.len() {
+            return Err(batch_size_mismatch());
+        }
+        for (index, vector) in group.indices.into_iter().zip(vectors) {
+            if let Some(slot) = slots.get_mut(index) {
+                *slot = Some(vector);
+            }
+        }
+    }
+    slots
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(batch_size_mismatch)
+}
+
+fn batch_size_mismatch() -> ErrorEnvelope {
+    ErrorEnvelope::unexpected(
+        ErrorCode::internal(),
+        "embedding batch size mismatch",
+        ErrorClass::NonRetriable,
+    )
+}
+
+/// Apply the oversize policy to a file's chunks against the input each chunk
+/// will be embedded as: its content behind the document template, checked
+/// against the `max_input_chars` of the provider its language routes to.
+pub(super) fn fit_chunks_to_embedding_input(
+    ctx: &BatchContext<'_>,
+    file_path: &str,
+    language: Language,
+    chunks: Vec<CodeChunk>,
+) -> Result<Vec<CodeChunk>> {
+    let policy = ctx.input.oversize_chunk_policy;
+    let template_chars = ctx
+        .input
+        .document_template
+        .as_deref()
+        .map_or(0, |template| template.chars().count());
+    let mut fitted = Vec::with_capacity(chunks.len());
+    let mut oversized = 0_u64;
+    for chunk in chunks {
+        let port = ctx
+            .deps
+            .embedding_routes
+            .resolve(chunk.language.unwrap_or(language))
+            .unwrap_or(&ctx.deps.embedding);
+        let max_input_chars = port.provider().max_input_chars;
+        let (pieces, count) = apply_oversize_policy(
+            vec![chunk],
+            max_input_chars,
+            template_chars,
+            policy,
+            file_path,
+        )?;
+        oversized = oversized.saturating_add(count);
+        fitted.extend(pieces);
+    }
+
+    if oversized > 0 {
+        ctx.stats.record_split_oversized_chunks(oversized);
+        if let Some(logger) = ctx.deps.logger.as_ref() {
+            let (event, message) = match policy {
+                OversizeChunkPolicy::Truncate => (
+                    "index.chunk.truncated_oversized",
+                    "Truncating chunks over the embedding provider input limit",
+                ),
+                OversizeChunkPolicy::Skip => (
+                    "index.chunk.skipped_oversized",
+                    "Skipping chunks over the embedding provider input limit",
+                ),
+                OversizeChunkPolicy::Split | OversizeChunkPolicy::Error => (
+                    "index.chunk.split_oversized",
+                    "Splitting chunks over the embedding provider input limit",
+                ),
+            };
+            logger.warn(event, message, None);
+        }
+    }
+    Ok(fitted)
+}
+
 pub(super) async fn flush_pending_batches<'a>(
     ctx: &BatchContext<'a>,
     state: &mut BatchState<'a>,
@@ -146,12 +286,22 @@ pub(super) fn schedule_embedding_batch<'a>(
         return;
     }
 
+    let chunks = batch.len();
+    let mut relative_paths = batch
+        .iter()
+        .map(|chunk| chunk.relative_path.clone())
+        .collect::<Vec<_>>();
+    relative_paths.dedup();
     let task_ctx = EmbedBatchTask::new(ctx, batch);
     let task = ctx
         .embedding_pool
         .submit(move || async move { task_ctx.run().await });
 
     state.embedding_tasks.push(Box::pin(task));
+    state.embedding_batches.push(ScheduledEmbeddingBatch {
+        chunks,
+        relative_paths,
+    });
     tracing::debug!(
         queued_embedding_tasks = state.embedding_tasks.len(),
         next_batch_to_insert = state.next_batch_to_insert,
@@ -216,21 +366,16 @@ pub(super) async fn drain_one_embedding_batch<'a>(
             if let Some(telemetry) = ctx.deps.telemetry.as_ref() {
                 telemetry.increment_counter("index.embed_batch_failed", 1, None);
             }
-            if let Some(logger) = ctx.deps.logger.as_ref() {
-                logger.error(
-                    "index.embed_batch_failed",
-                    "Failed to embed chunk batch; aborting",
-                    None,
-                );
-            }
-            return Err(with_embed_failure_metadata(
+            let error = with_embed_failure_metadata(
                 error,
                 batch_index,
                 queued_embedding_tasks,
                 state.next_batch_to_insert,
-            ));
+            );
+            return handle_embed_failure(ctx, state, batch_index, error);
         },
     };
+    state.consecutive_embed_failures = 0;
     ctx.stats
         .record_await_embedding_task(wait_started.elapsed());
 
@@ -240,6 +385,110 @@ pub(super) async fn drain_one_embedding_batch<'a>(
     Ok(())
 }
 
+/// Record a failed embedding batch and decide whether the run may continue.
+///
+/// Without configured thresholds the first failure aborts the run with the
+/// original error; otherwise the batch's chunks are skipped (and their files
+/// recorded as failed) until a threshold is crossed.
+fn handle_embed_failure(
+    ctx: &BatchContext<'_>,
+    state: &mut BatchState<'_>,
+    batch_index: usize,
+    error: ErrorEnvelope,
+) -> Result<()> {
+    let chunks = state
+        .embedding_batches
+        .get(batch_index)
+        .map_or(0, |batch| batch.chunks);
+    state.consecutive_embed_failures = state.consecutive_embed_failures.saturating_add(1);
+    state.total_embed_failures = state.total_embed_failures.saturating_add(1);
+    ctx.stats
+        .record_embed_failure(u64::try_from(chunks).unwrap_or(u64::MAX));
+
+    let consecutive_limit = ctx.input.max_consecutive_failures;
+    let total_limit = ctx.input.max_total_failures;
+    let crossed = [
+        (
+            "maxConsecutiveFailures",
+            consecutive_limit,
+            state.consecutive_embed_failures,
+        ),
+        ("maxTotalFailures", total_limit, state.total_embed_failures),
+    ]
+    .into_iter()
+    .find_map(|(threshold, limit, count)| {
+        limit
+            .filter(|limit| count > limit.get())
+            .map(|limit| (threshold, limit.get()))
+    });
+
+    if consecutive_limit.is_some() || total_limit.is_some() {
+        let Some((threshold, limit)) = crossed else {
+            if let Some(batch) = state.embedding_batches.get(batch_index) {
+                state
+                    .failed_files
+                    .extend(batch.relative_paths.iter().cloned());
+            }
+            if let Some(logger) = ctx.deps.logger.as_ref() {
+                logger.warn(
+                    "index.embed_batch_skipped",
+                    "Failed to embed chunk batch; skipping its chunks",
+                    None,
+                );
+            }
+            return Ok(());
+        };
+        log_embed_abort(ctx);
+        return Err(embed_failure_threshold_error(
+            state, threshold, limit, &error,
+        ));
+    }
+
+    log_embed_abort(ctx);
+    Err(error)
+}
+
+fn log_embed_abort(ctx: &BatchContext<'_>) {
+    if let Some(logger) = ctx.deps.logger.as_ref() {
+        logger.error(
+            "index.embed_batch_failed",
+            "Failed to embed chunk batch; aborting",
+            None,
+        );
+    }
+}
+
+fn embed_failure_threshold_error(
+    state: &BatchState<'_>,
+    threshold: &'static str,
+    limit: usize,
+    last_error: &ErrorEnvelope,
+) -> ErrorEnvelope {
+    let mut error = ErrorEnvelope::unexpected(
+        ErrorCode::new("index", "embed_failure_threshold"),
+        format!(
+            "embedding failures exceeded {threshold} ({limit}); last error: {}",
+            last_error.message
+        ),
+        ErrorClass::NonRetriable,
+    )
+    .with_metadata("threshold", threshold)
+    .with_metadata("limit", limit.to_string())
+    .with_metadata(
+        "consecutiveFailures",
+        state.consecutive_embed_failures.to_string(),
+    )
+    .with_metadata("totalFailures", state.total_embed_failures.to_string())
+    .with_metadata("lastErrorCode", last_error.code.to_string());
+    for (key, value) in &last_error.metadata {
+        error
+            .metadata
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+    error
+}
+
 fn with_embed_failure_metadata(
     error: ErrorEnvelope,
     batch_index: usize,
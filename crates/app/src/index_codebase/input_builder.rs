@@ -0,0 +1,418 @@
+//! Fluent construction of [`IndexCodebaseInput`].
+
+use super::types::{CollectionPolicy, IndexCodebaseInput, IndexProgress};
+use semantic_code_domain::{
+    CollectionName, IndexMode, OnDuplicateId, OversizeChunkPolicy, PathNormalization,
+};
+use semantic_code_shared::{ErrorCode, ErrorEnvelope, Result};
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default embedding batch size used by [`IndexCodebaseInputBuilder`].
+pub const DEFAULT_EMBEDDING_BATCH_SIZE: NonZeroUsize = match NonZeroUsize::new(32) {
+    Some(size) => size,
+    None => NonZeroUsize::MIN,
+};
+
+impl IndexCodebaseInput {
+    /// Start building an input from the two required fields.
+    ///
+    /// Every other field starts at its default: dense mode, no file or chunk
+    /// caps, single-task concurrency, and binary files skipped.
+    #[must_use]
+    pub fn builder(
+        codebase_root: impl Into<PathBuf>,
+        collection_name: CollectionName,
+    ) -> IndexCodebaseInputBuilder {
+        IndexCodebaseInputBuilder::new(codebase_root.into(), collection_name)
+    }
+}
+
+/// Builder for [`IndexCodebaseInput`].
+///
+/// Count limits are taken as plain `usize` and checked by [`Self::build`], so
+/// callers do not have to construct `NonZeroUsize` values themselves.
+///
+/// ```ignore
+/// let input = IndexCodebaseInput::builder(root, collection)
+///     .embedding_batch_size(16)
+///     .max_in_flight_inserts(2)
+///     .build()?;
+/// ```
+#[derive(Clone)]
+pub struct IndexCodebaseInputBuilder {
+    codebase_root: PathBuf,
+    collection_name: CollectionName,
+    index_mode: IndexMode,
+    supported_extensions: Option<Vec<Box<str>>>,
+    ignore_patterns: Option<Vec<Box<str>>>,
+    additional_ignore_patterns: Vec<Box<str>>,
+    file_list: Option<Vec<Box<str>>>,
+    force_reindex: bool,
+    collection_description: Option<Box<str>>,
+    collection_policy: CollectionPolicy,
+    on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
+    embedding_batch_size: usize,
+    chunk_limit: Option<usize>,
+    max_files: Option<usize>,
+    max_file_size_bytes: Option<u64>,
+    max_file_size_bytes_by_extension: BTreeMap<Box<str>, u64>,
+    path_normalization: PathNormalization,
+    oversize_chunk_policy: OversizeChunkPolicy,
+    skip_binary: bool,
+    document_template: Option<Box<str>>,
+    extra_metadata: BTreeMap<Box<str>, Box<str>>,
+    record_mtime: bool,
+    min_chunk_chars: u32,
+    max_chunk_chars: Option<usize>,
+    max_buffered_chunks: Option<usize>,
+    max_buffered_embeddings: Option<usize>,
+    max_in_flight_files: Option<usize>,
+    max_in_flight_embedding_batches: Option<usize>,
+    max_in_flight_inserts: Option<usize>,
+    shard_count: Option<u32>,
+    max_consecutive_failures: Option<usize>,
+    max_total_failures: Option<usize>,
+    on_duplicate_id: OnDuplicateId,
+    shutdown_timeout: Option<Duration>,
+}
+
+impl IndexCodebaseInputBuilder {
+    fn new(codebase_root: PathBuf, collection_name: CollectionName) -> Self {
+        Self {
+            codebase_root,
+            collection_name,
+            index_mode: IndexMode::Dense,
+            supported_extensions: None,
+            ignore_patterns: None,
+            additional_ignore_patterns: Vec::new(),
+            file_list: None,
+            force_reindex: false,
+            collection_description: None,
+            collection_policy: CollectionPolicy::default(),
+            on_progress: None,
+            embedding_batch_size: DEFAULT_EMBEDDING_BATCH_SIZE.get(),
+            chunk_limit: None,
+            max_files: None,
+            max_file_size_bytes: None,
+            max_file_size_bytes_by_extension: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::default(),
+            skip_binary: true,
+            document_template: None,
+            extra_metadata: BTreeMap::new(),
+            record_mtime: false,
+            min_chunk_chars: 0,
+            max_chunk_chars: None,
+            max_buffered_chunks: None,
+            max_buffered_embeddings: None,
+            max_in_flight_files: None,
+            max_in_flight_embedding_batches: None,
+            max_in_flight_inserts: None,
+            shard_count: None,
+            max_consecutive_failures: None,
+            max_total_failures: None,
+            on_duplicate_id: OnDuplicateId::default(),
+            shutdown_timeout: None,
+        }
+    }
+
+    /// Set the index mode.
+    #[must_use]
+    pub const fn index_mode(mut self, mode: IndexMode) -> Self {
+        self.index_mode = mode;
+        self
+    }
+
+    /// Restrict indexing to these file extensions.
+    #[must_use]
+    pub fn supported_extensions(mut self, extensions: Vec<Box<str>>) -> Self {
+        self.supported_extensions = Some(extensions);
+        self
+    }
+
+    /// Set the configured ignore patterns.
+    #[must_use]
+    pub fn ignore_patterns(mut self, patterns: Vec<Box<str>>) -> Self {
+        self.ignore_patterns = Some(patterns);
+        self
+    }
+
+    /// Set the ignore patterns layered above the configured ones.
+    #[must_use]
+    pub fn additional_ignore_patterns(mut self, patterns: Vec<Box<str>>) -> Self {
+        self.additional_ignore_patterns = patterns;
+        self
+    }
+
+    /// Index only these relative paths instead of scanning the root.
+    #[must_use]
+    pub fn file_list(mut self, files: Vec<Box<str>>) -> Self {
+        self.file_list = Some(files);
+        self
+    }
+
+    /// Drop and recreate the collection before indexing.
+    #[must_use]
+    pub const fn force_reindex(mut self, force: bool) -> Self {
+        self.force_reindex = force;
+        self
+    }
+
+    /// Set the description attached to the collection on creation.
+    #[must_use]
+    pub fn collection_description(mut self, description: impl Into<Box<str>>) -> Self {
+        self.collection_description = Some(description.into());
+        self
+    }
+
+    /// Set whether the collection may be created or dropped.
+    #[must_use]
+    pub const fn collection_policy(mut self, policy: CollectionPolicy) -> Self {
+        self.collection_policy = policy;
+        self
+    }
+
+    /// Set the progress callback.
+    #[must_use]
+    pub fn on_progress(mut self, callback: Arc<dyn Fn(IndexProgress) + Send + Sync>) -> Self {
+        self.on_progress = Some(callback);
+        self
+    }
+
+    /// Set the embedding batch size; must be non-zero.
+    #[must_use]
+    pub const fn embedding_batch_size(mut self, size: usize) -> Self {
+        self.embedding_batch_size = size;
+        self
+    }
+
+    /// Cap the number of chunks indexed; must be non-zero.
+    #[must_use]
+    pub const fn chunk_limit(mut self, limit: usize) -> Self {
+        self.chunk_limit = Some(limit);
+        self
+    }
+
+    /// Cap the number of files scanned; must be non-zero.
+    #[must_use]
+    pub const fn max_files(mut self, limit: usize) -> Self {
+        self.max_files = Some(limit);
+        self
+    }
+
+    /// Skip files larger than this many bytes.
+    #[must_use]
+    pub const fn max_file_size_bytes(mut self, bytes: u64) -> Self {
+        self.max_file_size_bytes = Some(bytes);
+        self
+    }
+
+    /// Set per-extension size limits.
+    #[must_use]
+    pub fn max_file_size_bytes_by_extension(mut self, limits: BTreeMap<Box<str>, u64>) -> Self {
+        self.max_file_size_bytes_by_extension = limits;
+        self
+    }
+
+    /// Set the relative path canonicalization.
+    #[must_use]
+    pub const fn path_normalization(mut self, normalization: PathNormalization) -> Self {
+        self.path_normalization = normalization;
+        self
+    }
+
+    /// Set the handling of chunks over the provider's input limit.
+    #[must_use]
+    pub const fn oversize_chunk_policy(mut self, policy: OversizeChunkPolicy) -> Self {
+        self.oversize_chunk_policy = policy;
+        self
+    }
+
+    /// Skip files that look binary.
+    #[must_use]
+    pub const fn skip_binary(mut self, skip: bool) -> Self {
+        self.skip_binary = skip;
+        self
+    }
+
+    /// Set the instruction prefix prepended to chunk content before embedding.
+    #[must_use]
+    pub fn document_template(mut self, template: impl Into<Box<str>>) -> Self {
+        self.document_template = Some(template.into());
+        self
+    }
+
+    /// Set the custom metadata attached to every indexed document.
+    #[must_use]
+    pub fn extra_metadata(mut self, metadata: BTreeMap<Box<str>, Box<str>>) -> Self {
+        self.extra_metadata = metadata;
+        self
+    }
+
+    /// Record file modification times in chunk metadata.
+    #[must_use]
+    pub const fn record_mtime(mut self, record: bool) -> Self {
+        self.record_mtime = record;
+        self
+    }
+
+    /// Drop chunks shorter than this many characters.
+    #[must_use]
+    pub const fn min_chunk_chars(mut self, chars: u32) -> Self {
+        self.min_chunk_chars = chars;
+        self
+    }
+
+    /// Cap chunk content length in characters.
+    #[must_use]
+    pub const fn max_chunk_chars(mut self, chars: usize) -> Self {
+        self.max_chunk_chars = Some(chars);
+        self
+    }
+
+    /// Cap buffered chunks; must be non-zero.
+    #[must_use]
+    pub const fn max_buffered_chunks(mut self, limit: usize) -> Self {
+        self.max_buffered_chunks = Some(limit);
+        self
+    }
+
+    /// Cap buffered embeddings; must be non-zero.
+    #[must_use]
+    pub const fn max_buffered_embeddings(mut self, limit: usize) -> Self {
+        self.max_buffered_embeddings = Some(limit);
+        self
+    }
+
+    /// Cap in-flight file tasks; must be non-zero.
+    #[must_use]
+    pub const fn max_in_flight_files(mut self, limit: usize) -> Self {
+        self.max_in_flight_files = Some(limit);
+        self
+    }
+
+    /// Cap in-flight embedding batches; must be non-zero.
+    #[must_use]
+    pub const fn max_in_flight_embedding_batches(mut self, limit: usize) -> Self {
+        self.max_in_flight_embedding_batches = Some(limit);
+        self
+    }
+
+    /// Cap in-flight insert batches; must be non-zero.
+    #[must_use]
+    pub const fn max_in_flight_inserts(mut self, limit: usize) -> Self {
+        self.max_in_flight_inserts = Some(limit);
+        self
+    }
+
+    /// Route documents across this many shard collections; must be non-zero.
+    #[must_use]
+    pub const fn shard_count(mut self, count: u32) -> Self {
+        self.shard_count = Some(count);
+        self
+    }
+
+    /// Abort after this many back-to-back failed embedding batches; must be non-zero.
+    #[must_use]
+    pub const fn max_consecutive_failures(mut self, limit: usize) -> Self {
+        self.max_consecutive_failures = Some(limit);
+        self
+    }
+
+    /// Abort after this many failed embedding batches in total; must be non-zero.
+    #[must_use]
+    pub const fn max_total_failures(mut self, limit: usize) -> Self {
+        self.max_total_failures = Some(limit);
+        self
+    }
+
+    /// Set the policy for ids repeated within an insert batch.
+    #[must_use]
+    pub const fn on_duplicate_id(mut self, policy: OnDuplicateId) -> Self {
+        self.on_duplicate_id = policy;
+        self
+    }
+
+    /// Bound how long a cancelled run waits for its workers to drain.
+    #[must_use]
+    pub const fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = Some(timeout);
+        self
+    }
+
+    /// Build the input, rejecting zero values for non-zero limits.
+    pub fn build(self) -> Result<IndexCodebaseInput> {
+        optional_non_zero(
+            "shardCount",
+            self.shard_count
+                .map(|count| usize::try_from(count).unwrap_or(usize::MAX)),
+        )?;
+        Ok(IndexCodebaseInput {
+            embedding_batch_size: non_zero("embeddingBatchSize", self.embedding_batch_size)?,
+            chunk_limit: optional_non_zero("chunkLimit", self.chunk_limit)?
+                .unwrap_or(NonZeroUsize::MAX),
+            max_files: optional_non_zero("maxFiles", self.max_files)?,
+            max_buffered_chunks: optional_non_zero("maxBufferedChunks", self.max_buffered_chunks)?,
+            max_buffered_embeddings: optional_non_zero(
+                "maxBufferedEmbeddings",
+                self.max_buffered_embeddings,
+            )?,
+            max_in_flight_files: optional_non_zero("maxInFlightFiles", self.max_in_flight_files)?,
+            max_in_flight_embedding_batches: optional_non_zero(
+                "maxInFlightEmbeddingBatches",
+                self.max_in_flight_embedding_batches,
+            )?,
+            max_in_flight_inserts: optional_non_zero(
+                "maxInFlightInserts",
+                self.max_in_flight_inserts,
+            )?,
+            max_consecutive_failures: optional_non_zero(
+                "maxConsecutiveFailures",
+                self.max_consecutive_failures,
+            )?,
+            max_total_failures: optional_non_zero("maxTotalFailures", self.max_total_failures)?,
+            codebase_root: self.codebase_root,
+            collection_name: self.collection_name,
+            index_mode: self.index_mode,
+            supported_extensions: self.supported_extensions,
+            ignore_patterns: self.ignore_patterns,
+            additional_ignore_patterns: self.additional_ignore_patterns,
+            file_list: self.file_list,
+            force_reindex: self.force_reindex,
+            collection_description: self.collection_description,
+            collection_policy: self.collection_policy,
+            on_progress: self.on_progress,
+            max_file_size_bytes: self.max_file_size_bytes,
+            max_file_size_bytes_by_extension: self.max_file_size_bytes_by_extension,
+            path_normalization: self.path_normalization,
+            oversize_chunk_policy: self.oversize_chunk_policy,
+            skip_binary: self.skip_binary,
+            document_template: self.document_template,
+            extra_metadata: self.extra_metadata,
+            record_mtime: self.record_mtime,
+            min_chunk_chars: self.min_chunk_chars,
+            max_chunk_chars: self.max_chunk_chars,
+            shard_count: self.shard_count,
+            on_duplicate_id: self.on_duplicate_id,
+            shutdown_timeout: self.shutdown_timeout,
+        })
+    }
+}
+
+fn non_zero(field: &'static str, value: usize) -> Result<NonZeroUsize> {
+    NonZeroUsize::new(value).ok_or_else(|| {
+        ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            format!("{field} must be greater than zero"),
+        )
+        .with_metadata("field", field)
+    })
+}
+
+fn optional_non_zero(field: &'static str, value: Option<usize>) -> Result<Option<NonZeroUsize>> {
+    value.map(|value| non_zero(field, value)).transpose()
+}
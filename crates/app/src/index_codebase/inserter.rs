@@ -1,19 +1,125 @@
 //! Vector DB insertion and backpressure handling.
 
 use super::types::{BatchContext, BatchState, EmbeddedBatch, InsertTask};
-use semantic_code_domain::IndexMode;
-use semantic_code_ports::{TelemetryPort, VectorDbPort};
+use crate::sharding::shard_for_id;
+use semantic_code_domain::{CollectionName, IndexMode, OnDuplicateId};
+use semantic_code_ports::{InsertOutcome, TelemetryPort, VectorDbPort, VectorDocumentForInsert};
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+/// Routes documents to shard collections, creating each shard on first use.
+pub(super) struct ShardRouter {
+    collections: Vec<CollectionName>,
+    index_mode: IndexMode,
+    dimension: u32,
+    description: Box<str>,
+    ready: Mutex<Vec<bool>>,
+}
+
+impl ShardRouter {
+    pub(super) fn new(
+        collections: Vec<CollectionName>,
+        index_mode: IndexMode,
+        dimension: u32,
+        description: Box<str>,
+    ) -> Self {
+        let ready = Mutex::new(vec![false; collections.len()]);
+        Self {
+            collections,
+            index_mode,
+            dimension,
+            description,
+            ready,
+        }
+    }
+
+    fn route(
+        &self,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> BTreeMap<usize, Vec<VectorDocumentForInsert>> {
+        let shard_count = u32::try_from(self.collections.len()).unwrap_or(u32::MAX);
+        let mut routed: BTreeMap<usize, Vec<VectorDocumentForInsert>> = BTreeMap::new();
+        for document in documents {
+            let shard = usize::try_from(shard_for_id(&document.id, shard_count)).unwrap_or(0);
+            routed.entry(shard).or_default().push(document);
+        }
+        routed
+    }
+
+    fn is_ready(&self, shard: usize) -> bool {
+        self.ready
+            .lock()
+            .is_ok_and(|ready| ready.get(shard).copied().unwrap_or(false))
+    }
+
+    fn mark_ready(&self, shard: usize) {
+        if let Ok(mut ready) = self.ready.lock()
+            && let Some(slot) = ready.get_mut(shard)
+        {
+            *slot = true;
+        }
+    }
+
+    async fn ensure_shard(
+        &self,
+        ctx: &RequestContext,
+        vectordb: &dyn VectorDbPort,
+        shard: usize,
+    ) -> Result<CollectionName> {
+        let collection = self.collections.get(shard).cloned().ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "shard index out of range",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        if self.is_ready(shard) {
+            return Ok(collection);
+        }
+
+        if !vectordb.has_collection(ctx, collection.clone()).await? {
+            let description = Some(self.description.clone());
+            let created = match self.index_mode {
+                IndexMode::Hybrid => {
+                    vectordb
+                        .create_hybrid_collection(
+                            ctx,
+                            collection.clone(),
+                            self.dimension,
+                            description,
+                        )
+                        .await
+                },
+                IndexMode::Dense => {
+                    vectordb
+                        .create_collection(ctx, collection.clone(), self.dimension, description)
+                        .await
+                },
+            };
+            // A concurrent insert task may have created the shard first.
+            if let Err(error) = created
+                && !vectordb.has_collection(ctx, collection.clone()).await?
+            {
+                return Err(error);
+            }
+            tracing::debug!(collection = %collection.as_str(), "shard collection created");
+        }
+        self.mark_ready(shard);
+        Ok(collection)
+    }
+}
+
 struct InsertBatchTask {
     request_ctx: RequestContext,
     vectordb: Arc<dyn VectorDbPort>,
-    collection_name: semantic_code_domain::CollectionName,
+    collection_name: CollectionName,
     index_mode: IndexMode,
     telemetry: Option<Arc<dyn TelemetryPort>>,
-    documents: Vec<semantic_code_ports::VectorDocumentForInsert>,
+    documents: Vec<VectorDocumentForInsert>,
+    on_duplicate_id: OnDuplicateId,
+    shards: Option<Arc<ShardRouter>>,
     stats: Arc<super::types::IndexStageStatsCollector>,
 }
 
@@ -26,6 +132,8 @@ impl InsertBatchTask {
             index_mode: ctx.input.index_mode,
             telemetry: ctx.deps.telemetry.clone(),
             documents: embedded.documents,
+            on_duplicate_id: ctx.input.on_duplicate_id,
+            shards: ctx.shards.clone(),
             stats: Arc::clone(&ctx.stats),
         }
     }
@@ -38,27 +146,40 @@ impl InsertBatchTask {
             index_mode,
             telemetry,
             documents,
+            on_duplicate_id,
+            shards,
             stats,
         } = self;
 
         request_ctx.ensure_not_cancelled("index_codebase.insert_batch")?;
+        request_ctx.ensure_writable("index_codebase.insert_batch")?;
+        let documents = resolve_duplicate_ids(documents, on_duplicate_id)?;
 
         let insert_started = Instant::now();
-        let document_count = u64::try_from(documents.len()).unwrap_or(u64::MAX);
         let timer = telemetry
             .as_ref()
             .map(|telemetry| telemetry.start_timer("index.insert_batch", None));
 
-        let result = match index_mode {
-            IndexMode::Hybrid => {
-                vectordb
-                    .insert_hybrid(&request_ctx, collection_name, documents)
-                    .await
+        let result = match shards {
+            Some(router) => {
+                insert_sharded(
+                    &request_ctx,
+                    vectordb.as_ref(),
+                    &router,
+                    index_mode,
+                    documents,
+                )
+                .await
             },
-            IndexMode::Dense => {
-                vectordb
-                    .insert(&request_ctx, collection_name, documents)
-                    .await
+            None => {
+                insert_documents(
+                    &request_ctx,
+                    vectordb.as_ref(),
+                    collection_name,
+                    index_mode,
+                    documents,
+                )
+                .await
             },
         };
         stats.record_provider_insert_batch(insert_started.elapsed());
@@ -67,12 +188,116 @@ impl InsertBatchTask {
             timer.stop();
         }
 
-        if result.is_ok() {
-            stats.record_insert(document_count, insert_started.elapsed());
+        let outcomes = result?;
+        let inserted = settle_outcomes(&outcomes, &stats, telemetry.as_deref())?;
+        stats.record_insert(inserted, insert_started.elapsed());
+        Ok(())
+    }
+}
+
+/// Apply `policy` to chunk ids repeated within one batch, preserving order.
+///
+/// `Overwrite` passes the batch through unchanged; the local index keeps the
+/// last document for a repeated id.
+pub(super) fn resolve_duplicate_ids(
+    documents: Vec<VectorDocumentForInsert>,
+    policy: OnDuplicateId,
+) -> Result<Vec<VectorDocumentForInsert>> {
+    if policy == OnDuplicateId::Overwrite {
+        return Ok(documents);
+    }
+    let mut seen: HashSet<Box<str>> = HashSet::with_capacity(documents.len());
+    let mut kept = Vec::with_capacity(documents.len());
+    for document in documents {
+        if seen.insert(document.id.clone()) {
+            kept.push(document);
+        } else if policy == OnDuplicateId::Error {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::new("vector", "duplicate_id"),
+                format!("duplicate chunk id `{}` in insert batch", document.id),
+            )
+            .with_metadata("id", document.id.to_string()));
+        } else {
+            tracing::debug!(document_id = %document.id, "skipping duplicate chunk id in insert batch");
         }
+    }
+    Ok(kept)
+}
 
-        result
+/// Record rejected documents and return how many were inserted.
+///
+/// A batch where every document failed is indistinguishable from a provider
+/// outage, so it aborts the run like an all-or-nothing insert error.
+fn settle_outcomes(
+    outcomes: &[InsertOutcome],
+    stats: &super::types::IndexStageStatsCollector,
+    telemetry: Option<&dyn TelemetryPort>,
+) -> Result<u64> {
+    let failed: Vec<(&str, &ErrorEnvelope)> = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            InsertOutcome::Failed { id, error } => Some((&**id, error)),
+            InsertOutcome::Inserted { .. } => None,
+        })
+        .collect();
+    if let Some((_, error)) = failed.first()
+        && failed.len() == outcomes.len()
+    {
+        return Err((*error).clone());
+    }
+
+    for (id, error) in &failed {
+        tracing::warn!(document_id = id, error = %error, "vector DB rejected document; skipping");
+    }
+    if !failed.is_empty() {
+        stats.record_insert_failures(failed.iter().map(|(id, _)| *id));
+        if let Some(telemetry) = telemetry {
+            telemetry.increment_counter(
+                "index.insert_documents_rejected",
+                u64::try_from(failed.len()).unwrap_or(u64::MAX),
+                None,
+            );
+        }
+    }
+    Ok(u64::try_from(outcomes.len() - failed.len()).unwrap_or(u64::MAX))
+}
+
+async fn insert_documents(
+    ctx: &RequestContext,
+    vectordb: &dyn VectorDbPort,
+    collection_name: CollectionName,
+    index_mode: IndexMode,
+    documents: Vec<VectorDocumentForInsert>,
+) -> Result<Vec<InsertOutcome>> {
+    match index_mode {
+        IndexMode::Hybrid => {
+            vectordb
+                .insert_hybrid_with_outcomes(ctx, collection_name, documents)
+                .await
+        },
+        IndexMode::Dense => {
+            vectordb
+                .insert_with_outcomes(ctx, collection_name, documents)
+                .await
+        },
+    }
+}
+
+async fn insert_sharded(
+    ctx: &RequestContext,
+    vectordb: &dyn VectorDbPort,
+    router: &ShardRouter,
+    index_mode: IndexMode,
+    documents: Vec<VectorDocumentForInsert>,
+) -> Result<Vec<InsertOutcome>> {
+    let mut outcomes = Vec::with_capacity(documents.len());
+    for (shard, documents) in router.route(documents) {
+        ctx.ensure_not_cancelled("index_codebase.insert_shard")?;
+        let collection_name = router.ensure_shard(ctx, vectordb, shard).await?;
+        outcomes
+            .extend(insert_documents(ctx, vectordb, collection_name, index_mode, documents).await?);
     }
+    Ok(outcomes)
 }
 
 pub(super) fn schedule_insert_batch<'a>(
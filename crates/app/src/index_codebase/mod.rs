@@ -2,6 +2,7 @@
 
 mod change_detector;
 mod embedder;
+mod input_builder;
 mod inserter;
 mod scanner;
 mod splitter;
@@ -10,25 +11,37 @@ mod types;
 #[cfg(test)]
 pub use change_detector::{delete_file_chunks_by_relative_path, normalize_change_set};
 pub use change_detector::{
-    delete_modified_files, delete_removed_files, detect_changes, emit_progress, total_changes,
+    delete_modified_file, delete_modified_files, delete_removed_files, detect_changes,
+    emit_progress, retain_existing_files, total_changes,
 };
+pub use input_builder::{DEFAULT_EMBEDDING_BATCH_SIZE, IndexCodebaseInputBuilder};
+pub use scanner::{FileScan, list_indexable_files};
 pub use types::{
-    EmbedFunctionStats, EmbedStageStats, FunctionTimingStats, IndexCodebaseDeps,
-    IndexCodebaseInput, IndexCodebaseOutput, IndexCodebaseStatus, IndexProgress, IndexStageStats,
-    InsertFunctionStats, InsertStageStats, PrepareFunctionStats, PrepareStageStats,
+    CollectionPolicy, DEFAULT_SHUTDOWN_TIMEOUT, EmbedFunctionStats, EmbedStageStats,
+    FunctionTimingStats, IndexCodebaseDeps, IndexCodebaseInput, IndexCodebaseOutput,
+    IndexCodebaseStatus, IndexProgress, IndexStageStats, InsertFunctionStats, InsertStageStats,
+    LanguageEmbeddingRoutes, MAX_RECORDED_INSERT_FAILURES, PrepareFunctionStats, PrepareStageStats,
     ScanFunctionStats, ScanStageStats, SplitFunctionStats, SplitStageStats,
 };
 
 use crate::generated::IndexPipelineState;
-use embedder::{drain_one_embedding_batch, flush_pending_batches, schedule_embedding_batch};
-use inserter::drain_one_insert_batch;
+use crate::sharding::{shard_collection_names, validate_shard_count};
+use embedder::{
+    drain_one_embedding_batch, fit_chunks_to_embedding_input, flush_pending_batches,
+    schedule_embedding_batch,
+};
+use inserter::{ShardRouter, drain_one_insert_batch};
 use scanner::file_extension_of;
-use semantic_code_domain::{Chunk, IndexMode, MAX_CHUNK_CHARS};
+use semantic_code_domain::{
+    Chunk, CollectionName, IndexMode, MAX_CHUNK_CHARS, validate_extra_metadata,
+};
 use semantic_code_ports::DetectDimensionOptions;
-use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use semantic_code_shared::{
+    ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result, with_cancellation_grace,
+};
 use std::marker::PhantomData;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use types::{
     FileResult, IndexPipeline, IndexRunContext, IndexStageStatsCollector, IndexState,
     IndexWorkerPools, IndexingLimits, PendingChunk, ProgressTracker,
@@ -128,6 +141,7 @@ impl IndexPipeline<Scanned> {
                 indexed_files: state.indexed_files,
                 total_chunks: state.total_chunks,
                 status: state.status,
+                failed_files: state.batch.failed_files.into_iter().collect(),
                 stage_stats: ctx.stats.snapshot(),
             },
         ))
@@ -210,26 +224,50 @@ pub async fn index_codebase(
     input: IndexCodebaseInput,
 ) -> Result<IndexCodebaseOutput> {
     ctx.ensure_not_cancelled("index_codebase")?;
+    ctx.ensure_writable("index_codebase")?;
 
     let pipeline = IndexPipeline::<Prepared>::new();
     let mut progress = ProgressTracker::new(input.on_progress.clone());
     let stats = Arc::new(IndexStageStatsCollector::new());
 
+    validate_shard_count(input.shard_count)?;
+    validate_extra_metadata(&input.extra_metadata).map_err(ErrorEnvelope::from)?;
     progress.emit("Preparing collection...", 0, 100, Some(0));
     let prepare_started = Instant::now();
-    ensure_collection(ctx, deps, &input, stats.as_ref()).await?;
+    let shards = if let Some(shard_count) = input.shard_count {
+        Some(Arc::new(
+            prepare_shards(ctx, deps, &input, shard_count, stats.as_ref()).await?,
+        ))
+    } else {
+        ensure_collection(ctx, deps, &input, stats.as_ref()).await?;
+        None
+    };
     stats.record_prepare(prepare_started.elapsed());
     tracing::debug!("index collection prepared");
 
     progress.emit("Scanning files...", 0, 100, Some(5));
     let scan_started = Instant::now();
-    let files = scanner::load_index_files(ctx, deps, &input, &stats).await?;
-    tracing::debug!(file_count = files.len(), "index scan completed");
+    let scanner::IndexFiles {
+        files,
+        limit_reached: file_limit_reached,
+    } = scanner::load_index_files(ctx, deps, &input, &stats).await?;
+    tracing::debug!(
+        file_count = files.len(),
+        file_limit_reached,
+        "index scan completed"
+    );
     stats.record_scan(
         u64::try_from(files.len()).unwrap_or(u64::MAX),
         scan_started.elapsed(),
     );
     let pipeline = pipeline.scanned();
+    if file_limit_reached && let Some(logger) = deps.logger.as_ref() {
+        logger.warn(
+            "index.scan.max_files_reached",
+            "Stopped scanning after reaching the max file count",
+            None,
+        );
+    }
 
     if files.is_empty() {
         progress.emit("No files to index", 100, 100, Some(100));
@@ -238,13 +276,14 @@ pub async fn index_codebase(
             indexed_files: 0,
             total_chunks: 0,
             status: IndexCodebaseStatus::Completed,
+            failed_files: Vec::new(),
             stage_stats: stats.snapshot(),
         });
     }
 
     let limits = IndexingLimits::from_input(&input);
-    let pools = IndexWorkerPools::new(ctx, &limits)?;
-    let run_ctx = IndexRunContext::new(ctx, deps, &input, &files, &limits, &pools, stats);
+    let mut pools = IndexWorkerPools::new(ctx, &limits)?;
+    let run_ctx = IndexRunContext::new(ctx, deps, &input, &files, &limits, &pools, shards, stats);
     tracing::debug!(
         file_count = files.len(),
         embedding_batch_size = limits.embedding_batch_size.get(),
@@ -252,11 +291,29 @@ pub async fn index_codebase(
         "starting index pipeline workers"
     );
 
-    let output = pipeline.run(&run_ctx, &mut progress).await;
-
-    pools.stop().await;
+    // A hung embed or insert must not outlive the shutdown deadline: once
+    // cancelled, the pipeline gets `timeout` to settle before it is dropped.
+    let timeout = input.shutdown_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT);
+    let mut pipeline_settled = false;
+    let output = with_cancellation_grace(ctx, timeout, "index_codebase.shutdown", async {
+        let output = pipeline.run(&run_ctx, &mut progress).await;
+        pipeline_settled = true;
+        output
+    })
+    .await;
+
+    // An abandoned pipeline already spent the grace period.
+    let remaining = if pipeline_settled {
+        timeout
+    } else {
+        Duration::ZERO
+    };
+    stop_worker_pools(ctx, deps, &mut pools, remaining).await;
 
-    let (pipeline, output) = output?;
+    let (pipeline, mut output) = output?;
+    if file_limit_reached {
+        output.status = IndexCodebaseStatus::LimitReached;
+    }
     let _pipeline = pipeline.completed();
     tracing::debug!(
         indexed_files = output.indexed_files,
@@ -267,6 +324,28 @@ pub async fn index_codebase(
     Ok(output)
 }
 
+/// Stop the worker pools, giving a cancelled run `timeout` to drain.
+async fn stop_worker_pools(
+    ctx: &RequestContext,
+    deps: &IndexCodebaseDeps,
+    pools: &mut IndexWorkerPools,
+    timeout: Duration,
+) {
+    if !ctx.is_cancelled() {
+        pools.stop().await;
+        return;
+    }
+    if !pools.stop_with_timeout(timeout).await
+        && let Some(logger) = deps.logger.as_ref()
+    {
+        logger.warn(
+            "index.shutdown.forced",
+            "Aborted in-flight index tasks after the shutdown timeout; partial inserts may remain",
+            None,
+        );
+    }
+}
+
 #[tracing::instrument(
     name = "app.index.process_files",
     skip_all,
@@ -314,14 +393,28 @@ async fn process_files<'a>(
             relative_path,
             language,
             chunks,
+            mtime_ms,
         } = result
         else {
             continue;
         };
+        // Ids and metadata use the canonical path; reads used the on-disk one.
+        let relative_path = ctx
+            .batch
+            .input
+            .path_normalization
+            .apply(relative_path.as_ref());
+        let chunks =
+            fit_chunks_to_embedding_input(&ctx.batch, relative_path.as_ref(), language, chunks)?;
 
         for chunk in chunks {
             ctx.ctx.ensure_not_cancelled("index_codebase.chunk_loop")?;
 
+            if is_below_min_chunk_chars(&chunk.content, ctx.batch.input.min_chunk_chars) {
+                ctx.stats.record_split_dropped_small(1);
+                continue;
+            }
+
             if state.total_chunks >= ctx.limits.chunk_limit.get() {
                 state.status = IndexCodebaseStatus::LimitReached;
                 tracing::debug!(
@@ -331,8 +424,11 @@ async fn process_files<'a>(
                 break;
             }
 
-            let content =
-                Chunk::<MAX_CHUNK_CHARS>::new(chunk.content).map_err(ErrorEnvelope::from)?;
+            let content = match ctx.batch.input.max_chunk_chars {
+                Some(max) => Chunk::<MAX_CHUNK_CHARS>::with_max(chunk.content, max),
+                None => Chunk::<MAX_CHUNK_CHARS>::new(chunk.content),
+            }
+            .map_err(ErrorEnvelope::from)?;
             state.batch.pending.push(PendingChunk {
                 relative_path: relative_path.clone(),
                 span: chunk.span,
@@ -341,6 +437,7 @@ async fn process_files<'a>(
                 language: chunk.language.unwrap_or(language),
                 content,
                 file_extension: file_extension_of(relative_path.as_ref()),
+                mtime_ms,
             });
             state.total_chunks += 1;
 
@@ -369,6 +466,12 @@ async fn process_files<'a>(
     Ok(())
 }
 
+/// Returns true when trimmed `content` is shorter than `min_chars` characters.
+fn is_below_min_chunk_chars(content: &str, min_chars: u32) -> bool {
+    let min_chars = min_chars as usize;
+    min_chars > 0 && content.trim().chars().take(min_chars).count() < min_chars
+}
+
 #[tracing::instrument(
     name = "app.index.finalize_batches",
     skip_all,
@@ -423,8 +526,10 @@ async fn ensure_collection(
         .has_collection(ctx, input.collection_name.clone())
         .await?;
     stats.record_prepare_has_collection(has_collection_started.elapsed());
+    let policy = input.collection_policy;
 
     if exists && input.force_reindex {
+        ensure_drop_allowed(input, &input.collection_name)?;
         let drop_started = Instant::now();
         deps.vectordb
             .drop_collection(ctx, input.collection_name.clone())
@@ -433,9 +538,20 @@ async fn ensure_collection(
     }
 
     if exists && !input.force_reindex {
+        if !policy.allow_drop {
+            ensure_dimension_matches(ctx, deps, input, stats).await?;
+        }
         return Ok(());
     }
 
+    if !exists && !policy.create_if_missing {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::not_found(),
+            "collection does not exist and the collection policy forbids creating it",
+        )
+        .with_metadata("collection", input.collection_name.as_str().to_owned()));
+    }
+
     let detect_started = Instant::now();
     let dimension = deps
         .embedding
@@ -443,16 +559,30 @@ async fn ensure_collection(
         .await?;
     stats.record_prepare_detect_dimension(detect_started.elapsed());
 
+    let description = input
+        .collection_description
+        .clone()
+        .unwrap_or_else(|| default_collection_description(input));
     let create_started = Instant::now();
     match input.index_mode {
         IndexMode::Hybrid => {
             deps.vectordb
-                .create_hybrid_collection(ctx, input.collection_name.clone(), dimension, None)
+                .create_hybrid_collection(
+                    ctx,
+                    input.collection_name.clone(),
+                    dimension,
+                    Some(description),
+                )
                 .await?;
         },
         IndexMode::Dense => {
             deps.vectordb
-                .create_collection(ctx, input.collection_name.clone(), dimension, None)
+                .create_collection(
+                    ctx,
+                    input.collection_name.clone(),
+                    dimension,
+                    Some(description),
+                )
                 .await?;
         },
     }
@@ -460,6 +590,121 @@ async fn ensure_collection(
 
     Ok(())
 }
+
+fn ensure_drop_allowed(input: &IndexCodebaseInput, collection: &CollectionName) -> Result<()> {
+    if input.collection_policy.allow_drop {
+        return Ok(());
+    }
+    Err(ErrorEnvelope::expected(
+        ErrorCode::new("index", "collection_drop_forbidden"),
+        "the collection policy forbids dropping an existing collection",
+    )
+    .with_metadata("collection", collection.as_str().to_owned()))
+}
+
+/// Fail when an existing collection was built with a different dimension.
+///
+/// Only used when recreation is forbidden; providers that cannot report a
+/// dimension are trusted.
+async fn ensure_dimension_matches(
+    ctx: &RequestContext,
+    deps: &IndexCodebaseDeps,
+    input: &IndexCodebaseInput,
+    stats: &IndexStageStatsCollector,
+) -> Result<()> {
+    let Some(existing) = deps
+        .vectordb
+        .collection_dimension(ctx, input.collection_name.clone())
+        .await?
+    else {
+        return Ok(());
+    };
+    let detect_started = Instant::now();
+    let dimension = deps
+        .embedding
+        .detect_dimension(ctx, DetectDimensionOptions::default().into())
+        .await?;
+    stats.record_prepare_detect_dimension(detect_started.elapsed());
+    if existing == dimension {
+        return Ok(());
+    }
+    Err(ErrorEnvelope::expected(
+        ErrorCode::new("index", "collection_dimension_mismatch"),
+        format!(
+            "collection has dimension {existing}, embeddings have {dimension}, and the collection policy forbids recreating it"
+        ),
+    )
+    .with_metadata("collection", input.collection_name.as_str().to_owned()))
+}
+
+/// Drop existing shards on force reindex and resolve what shard creation needs.
+///
+/// Shard collections themselves are created lazily by the inserter.
+#[tracing::instrument(
+    name = "app.index.prepare_shards",
+    skip_all,
+    fields(
+        collection = %input.collection_name.as_str(),
+        shard_count = shard_count,
+        force_reindex = input.force_reindex,
+    )
+)]
+async fn prepare_shards(
+    ctx: &RequestContext,
+    deps: &IndexCodebaseDeps,
+    input: &IndexCodebaseInput,
+    shard_count: u32,
+    stats: &IndexStageStatsCollector,
+) -> Result<ShardRouter> {
+    let collections = shard_collection_names(&input.collection_name, shard_count)?;
+    if input.force_reindex {
+        for collection in &collections {
+            let has_collection_started = Instant::now();
+            let exists = deps
+                .vectordb
+                .has_collection(ctx, collection.clone())
+                .await?;
+            stats.record_prepare_has_collection(has_collection_started.elapsed());
+            if exists {
+                ensure_drop_allowed(input, collection)?;
+                let drop_started = Instant::now();
+                deps.vectordb
+                    .drop_collection(ctx, collection.clone())
+                    .await?;
+                stats.record_prepare_drop_collection(drop_started.elapsed());
+            }
+        }
+    }
+
+    let detect_started = Instant::now();
+    let dimension = deps
+        .embedding
+        .detect_dimension(ctx, DetectDimensionOptions::default().into())
+        .await?;
+    stats.record_prepare_detect_dimension(detect_started.elapsed());
+
+    let description = input
+        .collection_description
+        .clone()
+        .unwrap_or_else(|| default_collection_description(input));
+    Ok(ShardRouter::new(
+        collections,
+        input.index_mode,
+        dimension,
+        description,
+    ))
+}
+
+/// Derive the collection description from the codebase root and index mode.
+fn default_collection_description(input: &IndexCodebaseInput) -> Box<str> {
+    format!(
+        "semantic-code {} index for {}",
+        input.index_mode.as_str(),
+        input.codebase_root.display()
+    )
+    .into_boxed_str()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -496,16 +741,24 @@ mod tests {
     }
 
     use crate::{SemanticSearchDeps, SemanticSearchInput, semantic_search};
-    use semantic_code_domain::{EmbeddingProviderId, VectorDbProviderId};
+    use semantic_code_adapters::LocalVectorDbBuilder;
+    use semantic_code_config::{SnapshotStorageMode, VectorSnapshotFormat};
+    use semantic_code_domain::{
+        EmbeddingProviderId, OnDuplicateId, OversizeChunkPolicy, PathNormalization,
+        SearchExclusions, VectorDbProviderId,
+    };
     use semantic_code_ports::{
         BoxFuture, CodeChunk, CollectionName, DetectDimensionRequest, EmbedBatchRequest,
         EmbedRequest, EmbeddingPort, EmbeddingProviderInfo, EmbeddingVector, FileSystemDirEntry,
         FileSystemEntryKind, FileSystemPort, FileSystemStat, HybridSearchBatchRequest,
-        HybridSearchResult, IgnoreMatchInput, IgnorePort, Language, LineSpan, PathPolicyPort,
-        SplitOptions, SplitterPort, VectorDbPort, VectorDbProviderInfo, VectorDocument,
-        VectorDocumentForInsert, VectorSearchRequest, VectorSearchResponse, VectorSearchResult,
+        HybridSearchResult, IgnoreMatchInput, IgnorePort, InsertOutcome, Language, LineSpan,
+        PathPolicyPort, SplitOptions, SplitterPort, VectorDbPort, VectorDbProviderInfo,
+        VectorDocument, VectorDocumentForInsert, VectorDocumentMetadata, VectorSearchRequest,
+        VectorSearchResponse, VectorSearchResult,
     };
-    use std::collections::HashMap;
+    use semantic_code_shared::CancellationToken;
+    use semantic_code_vector::HnswKernel;
+    use std::collections::{BTreeMap, HashMap};
     use std::num::NonZeroUsize;
     use std::path::PathBuf;
     use std::sync::{Arc, Mutex};
@@ -548,7 +801,7 @@ mod tests {
 
     impl IgnorePort for TestIgnore {
         fn is_ignored(&self, input: &IgnoreMatchInput) -> bool {
-            input.ignore_patterns.iter().any(|pattern| {
+            input.ignore_layers.iter().flatten().any(|pattern| {
                 !pattern.is_empty() && input.relative_path.contains(pattern.as_ref())
             })
         }
@@ -571,6 +824,7 @@ mod tests {
     struct TestFileSystemState {
         files: HashMap<String, String>,
         dirs: HashMap<String, Vec<FileSystemDirEntry>>,
+        read_dirs: Vec<String>,
     }
 
     impl TestFileSystem {
@@ -586,6 +840,11 @@ mod tests {
             state.add_dir_entry(dir, name, FileSystemEntryKind::File);
             state.ensure_dirs(dir);
         }
+
+        fn read_dirs(&self) -> Vec<String> {
+            let state = self.state.lock().expect("test file system state lock");
+            state.read_dirs.clone()
+        }
     }
 
     impl TestFileSystemState {
@@ -630,7 +889,8 @@ mod tests {
             dir: semantic_code_ports::SafeRelativePath,
         ) -> BoxFuture<'_, Result<Vec<FileSystemDirEntry>>> {
             Box::pin(async move {
-                let state = self.state.lock().expect("test file system state lock");
+                let mut state = self.state.lock().expect("test file system state lock");
+                state.read_dirs.push(dir.as_str().to_string());
                 Ok(state.dirs.get(dir.as_str()).cloned().unwrap_or_default())
             })
         }
@@ -724,11 +984,48 @@ mod tests {
         fn set_chunk_overlap(&self, _chunk_overlap: usize) {}
     }
 
+    /// Yields one chunk per line, last line first.
+    #[derive(Clone)]
+    struct ReversedSplitter;
+
+    impl SplitterPort for ReversedSplitter {
+        fn split(
+            &self,
+            _ctx: &RequestContext,
+            code: Box<str>,
+            language: Language,
+            options: SplitOptions,
+        ) -> BoxFuture<'_, Result<Vec<CodeChunk>>> {
+            Box::pin(async move {
+                let mut out = Vec::new();
+                let lines: Vec<&str> = code.lines().collect();
+                for (index, line) in lines.iter().enumerate().rev() {
+                    let line_number = index as u32 + 1;
+                    out.push(CodeChunk {
+                        content: (*line).into(),
+                        span: LineSpan::new(line_number, line_number)
+                            .map_err(ErrorEnvelope::from)?,
+                        fragment_start_byte: None,
+                        fragment_end_byte: None,
+                        language: Some(language),
+                        file_path: options.file_path.clone(),
+                    });
+                }
+                Ok(out)
+            })
+        }
+
+        fn set_chunk_size(&self, _chunk_size: usize) {}
+
+        fn set_chunk_overlap(&self, _chunk_overlap: usize) {}
+    }
+
     #[derive(Clone)]
     struct TestEmbedding {
         provider: EmbeddingProviderInfo,
         vector: Arc<[f32]>,
         batch_error: Option<ErrorEnvelope>,
+        embedded_texts: Arc<Mutex<Vec<Box<str>>>>,
     }
 
     impl TestEmbedding {
@@ -737,18 +1034,33 @@ mod tests {
                 provider: EmbeddingProviderInfo {
                     id: embedding_provider("openai"),
                     name: "test".into(),
+                    max_input_chars: None,
                 },
                 vector: Arc::from(vec![0.0, 0.1, 0.2]),
                 batch_error: None,
+                embedded_texts: Arc::new(Mutex::new(Vec::new())),
             }
         }
 
+        fn embedded_texts(&self) -> Vec<Box<str>> {
+            self.embedded_texts
+                .lock()
+                .expect("embedded texts lock")
+                .clone()
+        }
+
         fn with_batch_error(error: ErrorEnvelope) -> Self {
             Self {
                 batch_error: Some(error),
                 ..Self::new()
             }
         }
+
+        fn with_max_input_chars(max_input_chars: u32) -> Self {
+            let mut embedding = Self::new();
+            embedding.provider.max_input_chars = Some(max_input_chars);
+            embedding
+        }
     }
 
     impl EmbeddingPort for TestEmbedding {
@@ -781,11 +1093,16 @@ mod tests {
         ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
             let vector = Arc::clone(&self.vector);
             let batch_error = self.batch_error.clone();
+            let embedded_texts = Arc::clone(&self.embedded_texts);
             Box::pin(async move {
                 if let Some(error) = batch_error {
                     return Err(error);
                 }
                 let texts = request.texts;
+                embedded_texts
+                    .lock()
+                    .expect("embedded texts lock")
+                    .extend(texts.iter().cloned());
                 Ok(texts
                     .into_iter()
                     .map(|_| EmbeddingVector::new(Arc::clone(&vector)))
@@ -806,6 +1123,7 @@ mod tests {
                 provider: EmbeddingProviderInfo {
                     id: embedding_provider("openai"),
                     name: "slow".into(),
+                    max_input_chars: None,
                 },
                 delay,
             }
@@ -858,12 +1176,120 @@ mod tests {
         }
     }
 
+    /// Never resolves a batch, simulating a provider that hangs.
+    struct HangingEmbedding {
+        provider: EmbeddingProviderInfo,
+    }
+
+    impl HangingEmbedding {
+        fn new() -> Self {
+            Self {
+                provider: EmbeddingProviderInfo {
+                    id: embedding_provider("openai"),
+                    name: "hanging".into(),
+                    max_input_chars: None,
+                },
+            }
+        }
+    }
+
+    impl EmbeddingPort for HangingEmbedding {
+        fn provider(&self) -> &EmbeddingProviderInfo {
+            &self.provider
+        }
+
+        fn detect_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _request: DetectDimensionRequest,
+        ) -> BoxFuture<'_, Result<u32>> {
+            Box::pin(async move { Ok(3) })
+        }
+
+        fn embed(
+            &self,
+            _ctx: &RequestContext,
+            _request: EmbedRequest,
+        ) -> BoxFuture<'_, Result<EmbeddingVector>> {
+            Box::pin(std::future::pending())
+        }
+
+        fn embed_batch(
+            &self,
+            _ctx: &RequestContext,
+            _request: EmbedBatchRequest,
+        ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+            Box::pin(std::future::pending())
+        }
+    }
+
+    /// Fails every `fail_every`-th batch call (starting with the first).
+    struct FlakyEmbedding {
+        inner: TestEmbedding,
+        fail_every: usize,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl FlakyEmbedding {
+        fn new(fail_every: usize) -> Self {
+            Self {
+                inner: TestEmbedding::new(),
+                fail_every,
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl EmbeddingPort for FlakyEmbedding {
+        fn provider(&self) -> &EmbeddingProviderInfo {
+            self.inner.provider()
+        }
+
+        fn detect_dimension(
+            &self,
+            ctx: &RequestContext,
+            request: DetectDimensionRequest,
+        ) -> BoxFuture<'_, Result<u32>> {
+            self.inner.detect_dimension(ctx, request)
+        }
+
+        fn embed(
+            &self,
+            ctx: &RequestContext,
+            request: EmbedRequest,
+        ) -> BoxFuture<'_, Result<EmbeddingVector>> {
+            self.inner.embed(ctx, request)
+        }
+
+        fn embed_batch(
+            &self,
+            ctx: &RequestContext,
+            request: EmbedBatchRequest,
+        ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call.is_multiple_of(self.fail_every) {
+                return Box::pin(async move {
+                    Err(ErrorEnvelope::unexpected(
+                        ErrorCode::timeout(),
+                        "embed backend hiccup",
+                        ErrorClass::Retriable,
+                    ))
+                });
+            }
+            self.inner.embed_batch(ctx, request)
+        }
+    }
+
     #[derive(Clone)]
     struct SpyVectorDb {
         provider: VectorDbProviderInfo,
         inserted: Arc<Mutex<Vec<VectorDocumentForInsert>>>,
         exists: Arc<Mutex<bool>>,
+        dimension: Arc<Mutex<Option<u32>>>,
+        drops: Arc<Mutex<usize>>,
+        descriptions: Arc<Mutex<Vec<Option<Box<str>>>>>,
         insert_error: Option<ErrorEnvelope>,
+        reject_marker: Option<&'static str>,
     }
 
     impl SpyVectorDb {
@@ -875,10 +1301,25 @@ mod tests {
                 },
                 inserted: Arc::new(Mutex::new(Vec::new())),
                 exists: Arc::new(Mutex::new(false)),
+                dimension: Arc::new(Mutex::new(None)),
+                drops: Arc::new(Mutex::new(0)),
+                descriptions: Arc::new(Mutex::new(Vec::new())),
                 insert_error: None,
+                reject_marker: None,
             }
         }
 
+        fn with_existing_collection(dimension: u32) -> Self {
+            let spy = Self::new();
+            *spy.exists.lock().expect("exists lock") = true;
+            *spy.dimension.lock().expect("dimension lock") = Some(dimension);
+            spy
+        }
+
+        fn drop_count(&self) -> usize {
+            *self.drops.lock().expect("drops lock")
+        }
+
         fn with_insert_error(error: ErrorEnvelope) -> Self {
             Self {
                 insert_error: Some(error),
@@ -886,6 +1327,41 @@ mod tests {
             }
         }
 
+        fn rejecting_content(marker: &'static str) -> Self {
+            Self {
+                reject_marker: Some(marker),
+                ..Self::new()
+            }
+        }
+
+        fn insert_rejecting(
+            &self,
+            documents: Vec<VectorDocumentForInsert>,
+        ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+            let inserted = self.inserted.clone();
+            let marker = self.reject_marker;
+            Box::pin(async move {
+                let mut guard = inserted.lock().expect("inserted lock");
+                let mut outcomes = Vec::with_capacity(documents.len());
+                for document in documents {
+                    let id = document.id.clone();
+                    if marker.is_some_and(|marker| document.content.contains(marker)) {
+                        outcomes.push(InsertOutcome::Failed {
+                            id,
+                            error: ErrorEnvelope::expected(
+                                ErrorCode::new("vector", "vdb_row_rejected"),
+                                "row rejected",
+                            ),
+                        });
+                    } else {
+                        guard.push(document);
+                        outcomes.push(InsertOutcome::Inserted { id });
+                    }
+                }
+                Ok(outcomes)
+            })
+        }
+
         fn inserted_paths(&self) -> Vec<String> {
             let guard = self.inserted.lock().expect("inserted lock");
             guard
@@ -893,6 +1369,15 @@ mod tests {
                 .map(|doc| doc.metadata.relative_path.as_ref().to_string())
                 .collect()
         }
+
+        fn inserted_contents(&self) -> Vec<String> {
+            let guard = self.inserted.lock().expect("inserted lock");
+            guard.iter().map(|doc| doc.content.to_string()).collect()
+        }
+
+        fn created_descriptions(&self) -> Vec<Option<Box<str>>> {
+            self.descriptions.lock().expect("descriptions lock").clone()
+        }
     }
 
     impl VectorDbPort for SpyVectorDb {
@@ -905,10 +1390,15 @@ mod tests {
             _ctx: &RequestContext,
             _collection_name: CollectionName,
             _dimension: u32,
-            _description: Option<Box<str>>,
+            description: Option<Box<str>>,
         ) -> BoxFuture<'_, Result<()>> {
             let exists = self.exists.clone();
+            let descriptions = self.descriptions.clone();
             Box::pin(async move {
+                descriptions
+                    .lock()
+                    .expect("descriptions lock")
+                    .push(description);
                 let mut guard = exists.lock().expect("exists lock");
                 *guard = true;
                 Ok(())
@@ -920,10 +1410,15 @@ mod tests {
             _ctx: &RequestContext,
             _collection_name: CollectionName,
             _dimension: u32,
-            _description: Option<Box<str>>,
+            description: Option<Box<str>>,
         ) -> BoxFuture<'_, Result<()>> {
             let exists = self.exists.clone();
+            let descriptions = self.descriptions.clone();
             Box::pin(async move {
+                descriptions
+                    .lock()
+                    .expect("descriptions lock")
+                    .push(description);
                 let mut guard = exists.lock().expect("exists lock");
                 *guard = true;
                 Ok(())
@@ -936,13 +1431,24 @@ mod tests {
             _collection_name: CollectionName,
         ) -> BoxFuture<'_, Result<()>> {
             let exists = self.exists.clone();
+            let drops = self.drops.clone();
             Box::pin(async move {
+                *drops.lock().expect("drops lock") += 1;
                 let mut guard = exists.lock().expect("exists lock");
                 *guard = false;
                 Ok(())
             })
         }
 
+        fn collection_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> BoxFuture<'_, Result<Option<u32>>> {
+            let dimension = self.dimension.clone();
+            Box::pin(async move { Ok(*dimension.lock().expect("dimension lock")) })
+        }
+
         fn has_collection(
             &self,
             _ctx: &RequestContext,
@@ -995,6 +1501,22 @@ mod tests {
             })
         }
 
+        fn insert_with_outcomes(
+            &self,
+            ctx: &RequestContext,
+            collection_name: CollectionName,
+            documents: Vec<VectorDocumentForInsert>,
+        ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+            if self.reject_marker.is_none() {
+                let ids = documents.iter().map(|doc| doc.id.clone()).collect();
+                let insert = self.insert(ctx, collection_name, documents);
+                return Box::pin(async move {
+                    semantic_code_ports::insert_outcomes_from_batch(ids, insert.await)
+                });
+            }
+            self.insert_rejecting(documents)
+        }
+
         fn search(
             &self,
             _ctx: &RequestContext,
@@ -1069,9 +1591,115 @@ mod tests {
             ignore: Arc::new(TestIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         }
     }
 
+    #[test]
+    fn builder_defaults_match_default_input() -> Result<()> {
+        let collection_name =
+            CollectionName::parse("code_chunks_builder").map_err(ErrorEnvelope::from)?;
+        // `default_input` pins small limits to keep tests fast; everything
+        // else should be the builder's default.
+        let built = IndexCodebaseInput::builder("/tmp", collection_name.clone())
+            .embedding_batch_size(4)
+            .chunk_limit(100)
+            .max_in_flight_files(2)
+            .max_in_flight_embedding_batches(2)
+            .max_in_flight_inserts(2)
+            .build()?;
+        let expected = default_input(collection_name);
+
+        assert_eq!(built.codebase_root, expected.codebase_root);
+        assert_eq!(built.collection_name, expected.collection_name);
+        assert_eq!(built.index_mode, expected.index_mode);
+        assert_eq!(built.supported_extensions, expected.supported_extensions);
+        assert_eq!(built.ignore_patterns, expected.ignore_patterns);
+        assert_eq!(
+            built.additional_ignore_patterns,
+            expected.additional_ignore_patterns
+        );
+        assert_eq!(built.file_list, expected.file_list);
+        assert_eq!(built.force_reindex, expected.force_reindex);
+        assert_eq!(
+            built.collection_description,
+            expected.collection_description
+        );
+        assert_eq!(built.collection_policy, expected.collection_policy);
+        assert!(built.on_progress.is_none());
+        assert_eq!(built.embedding_batch_size, expected.embedding_batch_size);
+        assert_eq!(built.chunk_limit, expected.chunk_limit);
+        assert_eq!(built.max_files, expected.max_files);
+        assert_eq!(built.max_file_size_bytes, expected.max_file_size_bytes);
+        assert_eq!(
+            built.max_file_size_bytes_by_extension,
+            expected.max_file_size_bytes_by_extension
+        );
+        assert_eq!(built.path_normalization, expected.path_normalization);
+        assert_eq!(built.oversize_chunk_policy, expected.oversize_chunk_policy);
+        assert_eq!(built.skip_binary, expected.skip_binary);
+        assert_eq!(built.document_template, expected.document_template);
+        assert_eq!(built.extra_metadata, expected.extra_metadata);
+        assert_eq!(built.record_mtime, expected.record_mtime);
+        assert_eq!(built.min_chunk_chars, expected.min_chunk_chars);
+        assert_eq!(built.max_chunk_chars, expected.max_chunk_chars);
+        assert_eq!(built.max_buffered_chunks, expected.max_buffered_chunks);
+        assert_eq!(
+            built.max_buffered_embeddings,
+            expected.max_buffered_embeddings
+        );
+        assert_eq!(built.max_in_flight_files, expected.max_in_flight_files);
+        assert_eq!(
+            built.max_in_flight_embedding_batches,
+            expected.max_in_flight_embedding_batches
+        );
+        assert_eq!(built.max_in_flight_inserts, expected.max_in_flight_inserts);
+        assert_eq!(built.shard_count, expected.shard_count);
+        assert_eq!(
+            built.max_consecutive_failures,
+            expected.max_consecutive_failures
+        );
+        assert_eq!(built.max_total_failures, expected.max_total_failures);
+        assert_eq!(built.on_duplicate_id, expected.on_duplicate_id);
+        assert_eq!(built.shutdown_timeout, expected.shutdown_timeout);
+
+        let unbounded = IndexCodebaseInput::builder("/tmp", expected.collection_name).build()?;
+        assert_eq!(unbounded.embedding_batch_size, DEFAULT_EMBEDDING_BATCH_SIZE);
+        assert_eq!(unbounded.chunk_limit, NonZeroUsize::MAX);
+        assert_eq!(unbounded.max_in_flight_files, None);
+        Ok(())
+    }
+
+    #[test]
+    fn builder_rejects_zero_limits() -> Result<()> {
+        let collection_name =
+            CollectionName::parse("code_chunks_builder").map_err(ErrorEnvelope::from)?;
+        let result = IndexCodebaseInput::builder("/tmp", collection_name.clone())
+            .max_in_flight_inserts(0)
+            .build();
+        let error = result.err().ok_or_else(|| {
+            ErrorEnvelope::expected(ErrorCode::internal(), "zero limit should be rejected")
+        })?;
+        assert_eq!(error.code, ErrorCode::invalid_input());
+        assert_eq!(
+            error.metadata.get("field").map(String::as_str),
+            Some("maxInFlightInserts")
+        );
+
+        let error = IndexCodebaseInput::builder("/tmp", collection_name)
+            .shard_count(0)
+            .build()
+            .err()
+            .ok_or_else(|| {
+                ErrorEnvelope::expected(ErrorCode::internal(), "zero shards should be rejected")
+            })?;
+        assert_eq!(
+            error.metadata.get("field").map(String::as_str),
+            Some("shardCount")
+        );
+        Ok(())
+    }
+
     fn default_input(collection_name: CollectionName) -> IndexCodebaseInput {
         IndexCodebaseInput {
             codebase_root: PathBuf::from("/tmp"),
@@ -1079,13 +1707,25 @@ mod tests {
             index_mode: IndexMode::Dense,
             supported_extensions: None,
             ignore_patterns: None,
+            additional_ignore_patterns: Vec::new(),
             file_list: None,
             force_reindex: false,
+            collection_description: None,
+            collection_policy: CollectionPolicy::default(),
             on_progress: None,
             embedding_batch_size: NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN),
             chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
             max_files: None,
             max_file_size_bytes: None,
+            max_file_size_bytes_by_extension: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::default(),
+            skip_binary: true,
+            document_template: None,
+            extra_metadata: BTreeMap::new(),
+            record_mtime: false,
+            min_chunk_chars: 0,
+            max_chunk_chars: None,
             max_buffered_chunks: None,
             max_buffered_embeddings: None,
             max_in_flight_files: Some(NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN)),
@@ -1093,6 +1733,11 @@ mod tests {
                 NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
             ),
             max_in_flight_inserts: Some(NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN)),
+            shard_count: None,
+            max_consecutive_failures: None,
+            max_total_failures: None,
+            on_duplicate_id: OnDuplicateId::default(),
+            shutdown_timeout: None,
         }
     }
 
@@ -1101,6 +1746,16 @@ mod tests {
         deps: &IndexCodebaseDeps,
         index_input: IndexCodebaseInput,
         query: &str,
+    ) -> Result<crate::SemanticSearchOutput> {
+        index_then_search_with_context(ctx, deps, index_input, query, None).await
+    }
+
+    async fn index_then_search_with_context(
+        ctx: &RequestContext,
+        deps: &IndexCodebaseDeps,
+        index_input: IndexCodebaseInput,
+        query: &str,
+        context_lines: Option<u32>,
     ) -> Result<crate::SemanticSearchOutput> {
         let collection_name = index_input.collection_name.clone();
         let index_mode = index_input.index_mode;
@@ -1113,15 +1768,25 @@ mod tests {
                 vectordb: Arc::clone(&deps.vectordb),
                 logger: None,
                 telemetry: None,
+                filesystem: Some(Arc::clone(&deps.filesystem)),
             },
             SemanticSearchInput {
                 codebase_root: codebase_root.into_boxed_str(),
                 collection_name,
                 index_mode,
                 query: query.to_owned().into_boxed_str(),
+                query_template: None,
+                normalize_query: false,
                 top_k: Some(5),
                 threshold: Some(0.0),
                 query_vector: None,
+                include_histogram: false,
+                shard_count: None,
+                group_by_file: false,
+                exclusions: SearchExclusions::default(),
+                recency_boost: None,
+                context_lines,
+                on_progress: None,
             },
         )
         .await
@@ -1161,7 +1826,492 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
+    #[derive(Default)]
+    struct RecordingSpanExporter {
+        spans: Mutex<Vec<semantic_code_adapters::OtelSpan>>,
+    }
+
+    impl semantic_code_adapters::OtelSpanExporter for RecordingSpanExporter {
+        fn export(&self, span: semantic_code_adapters::OtelSpan) {
+            self.spans
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(span);
+        }
+    }
+
+    #[tokio::test]
+    async fn index_run_emits_otel_spans_with_standard_attributes() -> Result<()> {
+        use semantic_code_adapters::{OTEL_ATTR_OPERATION, OTEL_ATTR_SERVICE_NAME, OtelTelemetry};
+
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+        fs.add_file("src/b.rs", "fn b() {}\n");
+
+        let exporter = Arc::new(RecordingSpanExporter::default());
+        let mut deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            Arc::new(SpyVectorDb::new()),
+            Arc::new(TestSplitter::new(1)),
+        );
+        deps.telemetry = Some(Arc::new(OtelTelemetry::new(exporter.clone())));
+
+        let input =
+            default_input(CollectionName::parse("code_chunks_otel").map_err(ErrorEnvelope::from)?);
+        let ctx = RequestContext::new_request();
+        index_codebase(&ctx, &deps, input).await?;
+
+        let spans = exporter
+            .spans
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        for name in ["index.embed_batch", "index.insert_batch"] {
+            let span = spans
+                .iter()
+                .find(|span| span.name.as_ref() == name)
+                .expect("index run should emit span");
+            assert_eq!(
+                span.attributes
+                    .get(OTEL_ATTR_SERVICE_NAME)
+                    .map(AsRef::as_ref),
+                Some(semantic_code_adapters::DEFAULT_OTEL_SERVICE_NAME)
+            );
+            assert_eq!(
+                span.attributes.get(OTEL_ATTR_OPERATION).map(AsRef::as_ref),
+                Some(name)
+            );
+            assert!(!span.is_error);
+            assert_eq!(span.trace_id.len(), 32);
+            assert_eq!(span.span_id.len(), 16);
+        }
+        Ok(())
+    }
+
+    async fn index_paths_with_normalization(
+        normalization: PathNormalization,
+    ) -> Result<(Vec<String>, IndexCodebaseOutput)> {
+        let fs = TestFileSystem::default();
+        fs.add_file("Src/Lib.rs", "fn upper() {}\n");
+        fs.add_file("src/lib.rs", "fn lower() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_case").map_err(ErrorEnvelope::from)?);
+        input.path_normalization = normalization;
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+        let mut paths = vectordb.inserted_paths();
+        paths.sort();
+        Ok((paths, output))
+    }
+
+    #[tokio::test]
+    async fn lowercase_path_normalization_collapses_case_variants() -> Result<()> {
+        let (paths, output) = index_paths_with_normalization(PathNormalization::Lowercase).await?;
+        assert_eq!(paths, vec!["src/lib.rs"]);
+        assert_eq!(output.indexed_files, 1);
+
+        let (paths, output) = index_paths_with_normalization(PathNormalization::AsIs).await?;
+        assert_eq!(paths, vec!["Src/Lib.rs", "src/lib.rs"]);
+        assert_eq!(output.indexed_files, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn document_template_is_prepended_before_embedding() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let embedding = Arc::new(TestEmbedding::new());
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            embedding.clone(),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let mut input = default_input(
+            CollectionName::parse("code_chunks_template").map_err(ErrorEnvelope::from)?,
+        );
+        input.document_template = Some("passage: ".into());
+
+        let ctx = RequestContext::new_request();
+        index_codebase(&ctx, &deps, input).await?;
+
+        let texts = embedding.embedded_texts();
+        let inserted = vectordb.inserted.lock().expect("inserted lock");
+        assert_eq!(texts.len(), 1);
+        assert!(!inserted[0].content.starts_with("passage: "));
+        assert_eq!(
+            texts[0].as_ref(),
+            format!("passage: {}", inserted[0].content)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn chunks_are_embedded_by_the_provider_routed_for_their_language() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+        fs.add_file("docs/readme.md", "# Title\n");
+
+        let default = Arc::new(TestEmbedding::new());
+        let code = Arc::new(TestEmbedding::new());
+        let docs = Arc::new(TestEmbedding::new());
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let mut deps = test_deps(
+            fs,
+            default.clone(),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        deps.embedding_routes = LanguageEmbeddingRoutes::new()
+            .with_provider("code", code.clone())
+            .with_provider("docs", docs.clone())
+            .with_route(Language::Rust, "code")?
+            .with_route(Language::Markdown, "docs")?;
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        let code_texts = code.embedded_texts();
+        let docs_texts = docs.embedded_texts();
+        assert_eq!(code_texts.len(), 1);
+        assert!(code_texts[0].contains("fn a() {}"));
+        assert_eq!(docs_texts.len(), 1);
+        assert!(docs_texts[0].contains("# Title"));
+        assert!(default.embedded_texts().is_empty());
+        assert_eq!(vectordb.inserted.lock().expect("inserted lock").len(), 2);
+        assert_eq!(output.total_chunks, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn routes_to_unregistered_providers_are_rejected() {
+        let error = LanguageEmbeddingRoutes::new()
+            .with_route(Language::Rust, "missing")
+            .err();
+        assert!(error.is_some());
+    }
+
+    #[tokio::test]
+    async fn chunks_are_inserted_in_span_order_regardless_of_splitter_order() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(ReversedSplitter),
+        );
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        let inserted = vectordb.inserted.lock().expect("inserted lock");
+        let order: Vec<(u32, &str)> = inserted
+            .iter()
+            .map(|doc| (doc.metadata.span.start_line(), doc.content.as_ref()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![(1, "fn a() {}"), (2, "fn b() {}"), (3, "fn c() {}")]
+        );
+        assert_eq!(output.total_chunks, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn chunks_below_min_chunk_chars_are_dropped_before_embedding() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n}\n");
+
+        let embedding = Arc::new(TestEmbedding::new());
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            embedding.clone(),
+            vectordb.clone(),
+            Arc::new(ReversedSplitter),
+        );
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.min_chunk_chars = 2;
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        let inserted = vectordb.inserted.lock().expect("inserted lock");
+        let contents: Vec<&str> = inserted.iter().map(|doc| doc.content.as_ref()).collect();
+        assert_eq!(contents, vec!["fn a() {}"]);
+        assert_eq!(embedding.embedded_texts().len(), 1);
+        assert_eq!(output.stage_stats.split.dropped_small, 1);
+        assert_eq!(output.total_chunks, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn chunks_over_runtime_max_chunk_chars_are_rejected() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n}\n");
+
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            Arc::new(SpyVectorDb::new()),
+            Arc::new(ReversedSplitter),
+        );
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.max_chunk_chars = Some(4);
+
+        let ctx = RequestContext::new_request();
+        let error = index_codebase(&ctx, &deps, input).await.err();
+        assert!(
+            matches!(&error, Some(envelope) if envelope.code == ErrorCode::new("domain", "chunk_too_large")),
+            "unexpected result: {error:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn per_extension_size_limits_override_global_limit() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("dist/app.min.js", &"x".repeat(100));
+        fs.add_file("docs/guide.md", &"# guide\n".repeat(60));
+        fs.add_file("src/util.js", &"y".repeat(100));
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.max_file_size_bytes = Some(150);
+        input.max_file_size_bytes_by_extension =
+            BTreeMap::from([("md".into(), 1_000), ("min.js".into(), 64)]);
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        let mut paths = vectordb.inserted_paths();
+        paths.sort();
+        assert_eq!(paths, vec!["docs/guide.md", "src/util.js"]);
+        assert_eq!(output.indexed_files, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ensure_collection_passes_description_to_vectordb() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        let ctx = RequestContext::new_request();
+        index_codebase(&ctx, &deps, input).await?;
+        assert_eq!(
+            vectordb.created_descriptions(),
+            vec![Some("semantic-code dense index for /tmp".into())]
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.force_reindex = true;
+        input.collection_description = Some("custom description".into());
+        index_codebase(&ctx, &deps, input).await?;
+        assert_eq!(
+            vectordb.created_descriptions().last().cloned().flatten(),
+            Some("custom description".into())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn collection_policy_without_drop_rejects_mismatched_collection() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let embedding = Arc::new(TestEmbedding::new());
+        let vectordb = Arc::new(SpyVectorDb::with_existing_collection(1024));
+        let deps = test_deps(
+            fs,
+            embedding,
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let collection = CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?;
+        let policy = CollectionPolicy {
+            create_if_missing: true,
+            allow_drop: false,
+        };
+        let ctx = RequestContext::new_request();
+
+        let mut input = default_input(collection.clone());
+        input.collection_policy = policy;
+        let error = index_codebase(&ctx, &deps, input)
+            .await
+            .err()
+            .map(|error| error.code);
+        assert_eq!(
+            error,
+            Some(ErrorCode::new("index", "collection_dimension_mismatch"))
+        );
+
+        let mut input = default_input(collection);
+        input.collection_policy = policy;
+        input.force_reindex = true;
+        let error = index_codebase(&ctx, &deps, input)
+            .await
+            .err()
+            .map(|error| error.code);
+        assert_eq!(
+            error,
+            Some(ErrorCode::new("index", "collection_drop_forbidden"))
+        );
+
+        assert_eq!(vectordb.drop_count(), 0);
+        assert!(vectordb.inserted_paths().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn extra_metadata_is_stored_and_filterable_in_local_vectordb() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+        fs.add_file("src/b.rs", "fn b() {}\n");
+
+        let root =
+            std::env::temp_dir().join(format!("sca-index-extra-metadata-{}", std::process::id()));
+        let local: Arc<dyn VectorDbPort> = Arc::new(
+            LocalVectorDbBuilder::new(
+                root.clone(),
+                Arc::new(HnswKernel::with_ef_search(32)),
+                CancellationToken::new(),
+            )
+            .storage_mode(SnapshotStorageMode::Custom(root.clone()))
+            .snapshot_format(VectorSnapshotFormat::V1)
+            .build()?,
+        );
+        let mut deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            Arc::new(SpyVectorDb::new()),
+            Arc::new(TestSplitter::new(1)),
+        );
+        deps.vectordb = Arc::clone(&local);
+        let collection = CollectionName::parse("code_chunks_extra").map_err(ErrorEnvelope::from)?;
+        let mut input = default_input(collection.clone());
+        input.extra_metadata = BTreeMap::from([("team".into(), "core".into())]);
+
+        let ctx = RequestContext::new_request();
+        index_codebase(&ctx, &deps, input).await?;
+
+        let search = |filter: &'static str| {
+            let local = Arc::clone(&local);
+            let collection = collection.clone();
+            let ctx = ctx.clone();
+            async move {
+                local
+                    .search(
+                        &ctx,
+                        VectorSearchRequest {
+                            collection_name: collection,
+                            query_vector: Arc::from(vec![0.0, 0.1, 0.2]),
+                            options: semantic_code_ports::VectorSearchOptions {
+                                top_k: Some(10),
+                                filter_expr: Some(filter.into()),
+                                exclusions: SearchExclusions::default(),
+                                threshold: None,
+                            },
+                        },
+                    )
+                    .await
+            }
+        };
+        let matched = search("extra.team == 'core'").await?;
+        let mut paths = matched
+            .results
+            .iter()
+            .map(|result| result.document.metadata.relative_path.clone())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec![Box::from("src/a.rs"), Box::from("src/b.rs")]);
+        assert!(matched.results.iter().all(|result| {
+            result
+                .document
+                .metadata
+                .extra
+                .get("team")
+                .map(AsRef::as_ref)
+                == Some("core")
+        }));
+
+        let other = search("extra.team == 'infra'").await?;
+        assert!(other.results.is_empty());
+
+        let _ = std::fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_extra_metadata_is_rejected_before_indexing() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let mut input = default_input(
+            CollectionName::parse("code_chunks_extra_invalid").map_err(ErrorEnvelope::from)?,
+        );
+        input.extra_metadata = BTreeMap::from([("bad key".into(), "core".into())]);
+
+        let ctx = RequestContext::new_request();
+        let error = index_codebase(&ctx, &deps, input)
+            .await
+            .err()
+            .map(|error| error.code);
+        assert_eq!(
+            error,
+            Some(ErrorCode::new("domain", "invalid_extra_metadata"))
+        );
+        assert!(vectordb.inserted_paths().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
     async fn chunk_limit_stops_indexing() -> Result<()> {
         let fs = TestFileSystem::default();
         fs.add_file("src/a.rs", "fn a() {}\n");
@@ -1171,20 +2321,112 @@ mod tests {
             fs,
             Arc::new(TestEmbedding::new()),
             vectordb,
-            Arc::new(TestSplitter::new(3)),
+            Arc::new(TestSplitter::new(3)),
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.chunk_limit = NonZeroUsize::new(1).unwrap_or(NonZeroUsize::MIN);
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        assert_eq!(output.status, IndexCodebaseStatus::LimitReached);
+        assert_eq!(output.total_chunks, 1);
+        assert_eq!(output.stage_stats.embed.chunks, 1);
+        assert_eq!(output.stage_stats.insert.chunks, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn binary_files_with_text_extensions_are_skipped() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+        fs.add_file("src/blob.rs", "const DATA: &[u8] = b\"\0\0\x01\";\n\0\0");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let collection = CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?;
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, default_input(collection.clone())).await?;
+        assert_eq!(vectordb.inserted_paths(), vec!["src/a.rs"]);
+        assert_eq!(output.stage_stats.scan.files, 1);
+        assert_eq!(output.stage_stats.scan.skipped_binary, 1);
+
+        let mut input = default_input(collection);
+        input.force_reindex = true;
+        input.skip_binary = false;
+        let output = index_codebase(&ctx, &deps, input).await?;
+        assert_eq!(output.indexed_files, 2);
+        assert_eq!(output.stage_stats.scan.skipped_binary, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn additional_ignore_negation_re_includes_configured_exclusion() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+        fs.add_file("src/gen/keep.rs", "fn keep() {}\n");
+        fs.add_file("src/gen/skip.rs", "fn skip() {}\n");
+        fs.add_file("target/out.rs", "fn out() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let mut deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        deps.ignore = Arc::new(semantic_code_adapters::IgnoreMatcher::new());
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.ignore_patterns = Some(vec!["keep.rs".into(), "skip.rs".into()]);
+        input.additional_ignore_patterns = vec!["!keep.rs".into()];
+
+        let ctx = RequestContext::new_request();
+        index_codebase(&ctx, &deps, input).await?;
+
+        let mut paths = vectordb.inserted_paths();
+        paths.sort();
+        assert_eq!(paths, vec!["src/a.rs", "src/gen/keep.rs"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_files_stops_enumeration_and_reports_limit() -> Result<()> {
+        let fs = TestFileSystem::default();
+        for dir in ["a", "b", "c"] {
+            for name in ["one", "two", "three"] {
+                fs.add_file(&format!("{dir}/{name}.rs"), "fn f() {}\n");
+            }
+        }
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs.clone(),
+            Arc::new(TestEmbedding::new()),
+            vectordb,
+            Arc::new(TestSplitter::new(1)),
         );
 
         let mut input =
             default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
-        input.chunk_limit = NonZeroUsize::new(1).unwrap_or(NonZeroUsize::MIN);
+        input.max_files = NonZeroUsize::new(2);
 
         let ctx = RequestContext::new_request();
         let output = index_codebase(&ctx, &deps, input).await?;
 
         assert_eq!(output.status, IndexCodebaseStatus::LimitReached);
-        assert_eq!(output.total_chunks, 1);
-        assert_eq!(output.stage_stats.embed.chunks, 1);
-        assert_eq!(output.stage_stats.insert.chunks, 1);
+        assert_eq!(output.stage_stats.scan.files, 2);
+        assert_eq!(output.indexed_files, 2);
+        // The cap is hit inside `a/`; `b/` and `c/` are never listed.
+        assert_eq!(fs.read_dirs(), vec![".".to_string(), "a".to_string()]);
         Ok(())
     }
 
@@ -1217,6 +2459,225 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn rejected_documents_are_skipped_and_recorded() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::rejecting_content(":1:"));
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(3)),
+        );
+
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        assert_eq!(output.status, IndexCodebaseStatus::Completed);
+        assert_eq!(vectordb.inserted_paths().len(), 2);
+        assert_eq!(output.stage_stats.insert.chunks, 2);
+        assert_eq!(output.stage_stats.insert.failed_chunks, 1);
+        assert_eq!(output.stage_stats.insert.failed_ids.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn oversized_chunks_are_split_to_provider_limit() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\nfn b() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::with_max_input_chars(20)),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        // "rust:0:fn a() {}\nfn b() {}\n" is 27 chars; it splits after the first line.
+        assert_eq!(output.status, IndexCodebaseStatus::Completed);
+        assert_eq!(output.total_chunks, 2);
+        assert_eq!(vectordb.inserted_paths().len(), 2);
+        // The splitter produced one chunk; the oversize policy split it in two.
+        assert_eq!(output.stage_stats.split.chunks, 1);
+        assert_eq!(output.stage_stats.split.oversized_chunks, 1);
+        Ok(())
+    }
+
+    async fn index_with_oversize_policy(
+        policy: OversizeChunkPolicy,
+    ) -> (Arc<SpyVectorDb>, Result<IndexCodebaseOutput>) {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\nfn b() {}\n");
+        fs.add_file("src/b.rs", "fn c() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::with_max_input_chars(20)),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(1)),
+        );
+        let output = match CollectionName::parse("code_chunks_test") {
+            Ok(collection) => {
+                let mut input = default_input(collection);
+                input.oversize_chunk_policy = policy;
+                index_codebase(&RequestContext::new_request(), &deps, input).await
+            },
+            Err(error) => Err(ErrorEnvelope::from(error)),
+        };
+        (vectordb, output)
+    }
+
+    #[tokio::test]
+    async fn truncate_policy_keeps_the_leading_piece_of_oversized_chunks() -> Result<()> {
+        let (vectordb, output) = index_with_oversize_policy(OversizeChunkPolicy::Truncate).await;
+        let output = output?;
+
+        assert_eq!(output.total_chunks, 2);
+        assert_eq!(output.stage_stats.split.oversized_chunks, 1);
+        let mut contents = vectordb.inserted_contents();
+        contents.sort();
+        assert_eq!(
+            contents,
+            vec!["rust:0:fn a() {}\nfn ", "rust:0:fn c() {}\n"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skip_policy_drops_oversized_chunks() -> Result<()> {
+        let (vectordb, output) = index_with_oversize_policy(OversizeChunkPolicy::Skip).await;
+        let output = output?;
+
+        assert_eq!(output.status, IndexCodebaseStatus::Completed);
+        assert_eq!(output.total_chunks, 1);
+        assert_eq!(output.stage_stats.split.oversized_chunks, 1);
+        assert_eq!(vectordb.inserted_paths(), vec!["src/b.rs"]);
+        Ok(())
+    }
+
+    fn duplicate_id_batch() -> Result<Vec<VectorDocumentForInsert>> {
+        let span = LineSpan::new(1, 1).map_err(ErrorEnvelope::from)?;
+        Ok([("dup", "first"), ("other", "other"), ("dup", "second")]
+            .into_iter()
+            .map(|(id, content)| VectorDocumentForInsert {
+                id: id.into(),
+                vector: Arc::from(vec![1.0, 0.0]),
+                content: content.into(),
+                metadata: VectorDocumentMetadata {
+                    relative_path: "src/a.rs".into(),
+                    language: None,
+                    file_extension: Some("rs".into()),
+                    span,
+                    fragment_start_byte: None,
+                    fragment_end_byte: None,
+                    node_kind: None,
+                    extra: BTreeMap::new(),
+                    mtime_ms: None,
+                },
+            })
+            .collect())
+    }
+
+    #[test]
+    fn duplicate_ids_in_an_insert_batch_follow_the_policy() -> Result<()> {
+        let contents = |documents: &[VectorDocumentForInsert]| -> Vec<String> {
+            documents
+                .iter()
+                .map(|document| format!("{}={}", document.id, document.content))
+                .collect()
+        };
+
+        let overwrite =
+            inserter::resolve_duplicate_ids(duplicate_id_batch()?, OnDuplicateId::Overwrite)?;
+        assert_eq!(
+            contents(&overwrite),
+            ["dup=first", "other=other", "dup=second"]
+        );
+
+        let skip = inserter::resolve_duplicate_ids(duplicate_id_batch()?, OnDuplicateId::Skip)?;
+        assert_eq!(contents(&skip), ["dup=first", "other=other"]);
+
+        let error =
+            inserter::resolve_duplicate_ids(duplicate_id_batch()?, OnDuplicateId::Error).err();
+        assert_eq!(
+            error.as_ref().map(|error| error.code.clone()),
+            Some(ErrorCode::new("vector", "duplicate_id"))
+        );
+        assert_eq!(
+            error.and_then(|error| error.metadata.get("id").cloned()),
+            Some("dup".to_owned())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_policy_fails_the_run_on_oversized_chunks() {
+        let (vectordb, output) = index_with_oversize_policy(OversizeChunkPolicy::Error).await;
+
+        let error = output.err();
+        assert_eq!(
+            error.as_ref().map(|error| error.code.clone()),
+            Some(ErrorCode::new("index", "chunk_too_large"))
+        );
+        assert_eq!(
+            error.and_then(|error| error.metadata.get("file").cloned()),
+            Some("src/a.rs".to_owned())
+        );
+        assert!(vectordb.inserted_paths().is_empty());
+    }
+
+    #[tokio::test]
+    async fn oversize_policy_counts_the_document_template() -> Result<()> {
+        let run = |policy: OversizeChunkPolicy| async move {
+            let fs = TestFileSystem::default();
+            fs.add_file("src/b.rs", "fn c() {}\n");
+            let embedding = Arc::new(TestEmbedding::with_max_input_chars(20));
+            let vectordb = Arc::new(SpyVectorDb::new());
+            let deps = test_deps(
+                fs,
+                embedding.clone(),
+                vectordb.clone(),
+                Arc::new(TestSplitter::new(1)),
+            );
+            let mut input = default_input(
+                CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?,
+            );
+            input.oversize_chunk_policy = policy;
+            input.document_template = Some("doc: ".into());
+            let output = index_codebase(&RequestContext::new_request(), &deps, input).await;
+            Ok::<_, ErrorEnvelope>((embedding.embedded_texts(), output))
+        };
+
+        // "rust:0:fn c() {}\n" fits the 20-char limit alone but not behind "doc: ".
+        let (_, output) = run(OversizeChunkPolicy::Error).await?;
+        let error = output.err();
+        assert_eq!(
+            error.as_ref().map(|error| error.code.clone()),
+            Some(ErrorCode::new("index", "chunk_too_large"))
+        );
+        assert_eq!(
+            error.and_then(|error| error.metadata.get("chars").cloned()),
+            Some("22".to_owned())
+        );
+
+        let (texts, output) = run(OversizeChunkPolicy::Split).await?;
+        assert_eq!(output?.stage_stats.split.oversized_chunks, 1);
+        assert_eq!(texts.len(), 2);
+        assert!(texts.iter().all(|text| text.chars().count() <= 20));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn index_then_search_succeeds_on_healthy_pipeline() -> Result<()> {
         let fs = TestFileSystem::default();
@@ -1245,6 +2706,117 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn context_lines_extend_search_content_beyond_the_span() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "one\ntwo\nthree\nfour\nfive\n");
+
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::new()),
+            Arc::new(SpyVectorDb::new()),
+            Arc::new(ReversedSplitter),
+        );
+
+        let input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+
+        let ctx = RequestContext::new_request();
+        let output = index_then_search_with_context(&ctx, &deps, input, "three", Some(1)).await?;
+
+        let content_at = |line: u32| {
+            output
+                .results
+                .iter()
+                .find(|result| result.key.span.start_line() == line)
+                .map(|result| (result.key.span.end_line(), result.content.as_deref()))
+        };
+        assert_eq!(content_at(3), Some((3, Some("two\nthree\nfour"))));
+        assert_eq!(content_at(1), Some((1, Some("one\ntwo"))));
+        assert_eq!(content_at(5), Some((5, Some("four\nfive"))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn intermittent_embed_failures_under_threshold_complete() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(FlakyEmbedding::new(2)),
+            vectordb.clone(),
+            Arc::new(TestSplitter::new(6)),
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.embedding_batch_size = NonZeroUsize::MIN;
+        input.max_consecutive_failures = NonZeroUsize::new(1);
+        input.max_total_failures = NonZeroUsize::new(3);
+
+        let ctx = RequestContext::new_request();
+        let output = index_codebase(&ctx, &deps, input).await?;
+
+        assert_eq!(output.status, IndexCodebaseStatus::Completed);
+        assert_eq!(output.stage_stats.embed.failed_batches, 3);
+        assert_eq!(output.stage_stats.embed.failed_chunks, 3);
+        assert_eq!(output.stage_stats.insert.chunks, 3);
+        assert_eq!(vectordb.inserted_paths().len(), 3);
+        assert_eq!(output.failed_files, vec![Box::<str>::from("src/a.rs")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn embed_failures_past_threshold_abort_the_run() -> Result<()> {
+        let fs = TestFileSystem::default();
+        fs.add_file("src/a.rs", "fn a() {}\n");
+
+        let deps = test_deps(
+            fs,
+            Arc::new(TestEmbedding::with_batch_error(ErrorEnvelope::unexpected(
+                ErrorCode::timeout(),
+                "embed backend unavailable",
+                ErrorClass::Retriable,
+            ))),
+            Arc::new(SpyVectorDb::new()),
+            Arc::new(TestSplitter::new(6)),
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.embedding_batch_size = NonZeroUsize::MIN;
+        input.max_consecutive_failures = NonZeroUsize::new(2);
+
+        let ctx = RequestContext::new_request();
+        let Err(error) = index_codebase(&ctx, &deps, input).await else {
+            return Err(ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "expected index_codebase to abort past the failure threshold",
+                ErrorClass::NonRetriable,
+            ));
+        };
+
+        assert_eq!(
+            error.code,
+            ErrorCode::new("index", "embed_failure_threshold")
+        );
+        assert_eq!(
+            error.metadata.get("threshold").map(String::as_str),
+            Some("maxConsecutiveFailures")
+        );
+        assert_eq!(
+            error
+                .metadata
+                .get("consecutiveFailures")
+                .map(String::as_str),
+            Some("3")
+        );
+        assert!(error.message.contains("embed backend unavailable"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn index_then_search_fails_loudly_on_embed_failure() -> Result<()> {
         let fs = TestFileSystem::default();
@@ -1371,6 +2943,41 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_with_hung_embed_returns_within_shutdown_timeout() -> Result<()> {
+        let fs = TestFileSystem::default();
+        for index in 0..4 {
+            fs.add_file(&format!("src/{index}.rs"), "fn a() {}\n");
+        }
+
+        let vectordb = Arc::new(SpyVectorDb::new());
+        let deps = test_deps(
+            fs,
+            Arc::new(HangingEmbedding::new()),
+            Arc::clone(&vectordb),
+            Arc::new(TestSplitter::new(1)),
+        );
+
+        let mut input =
+            default_input(CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?);
+        input.shutdown_timeout = Some(Duration::from_millis(50));
+
+        let ctx = RequestContext::new_request();
+        let ctx_clone = ctx.clone();
+        let handle = tokio::spawn(async move { index_codebase(&ctx_clone, &deps, input).await });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        ctx.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+        assert!(
+            matches!(result, Ok(Ok(Err(ref error))) if error.is_cancelled()),
+            "index run should stop with a cancelled error within the shutdown deadline"
+        );
+        assert!(vectordb.inserted_paths().is_empty());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn progress_is_monotonic() -> Result<()> {
         let fs = TestFileSystem::default();
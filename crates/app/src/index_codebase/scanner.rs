@@ -1,15 +1,74 @@
 //! File discovery and ignore policy for `index_codebase`.
 
 use super::types::{IndexCodebaseDeps, IndexCodebaseInput, IndexStageStatsCollector};
-use semantic_code_ports::{FileSystemEntryKind, FileSystemPortExt, IgnoreMatchInput};
+use semantic_code_domain::{CONTEXT_IGNORE_FILE, PathNormalization, ignore_layers};
+use semantic_code_ports::{
+    FileSystemEntryKind, FileSystemPort, FileSystemPortExt, IgnoreMatchInput, IgnorePort,
+    LoggerPort, PathPolicyPort, symlink_escape_code,
+};
 use semantic_code_shared::{RequestContext, Result};
 use std::collections::{HashSet, VecDeque};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
 
 // TODO: refactor repeated optional logger/telemetry checks with a helper mapper.
-const CONTEXT_IGNORE_FILE: &str = ".contextignore";
+/// Leading bytes inspected when deciding whether a file is binary.
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
 
+/// Ports and options that drive file discovery.
+///
+/// Borrowed from the index deps/input, or assembled directly by callers that
+/// need the indexable file set without the rest of the pipeline (e.g. prune).
+pub struct FileScan<'a> {
+    pub filesystem: &'a Arc<dyn FileSystemPort>,
+    pub path_policy: &'a Arc<dyn PathPolicyPort>,
+    pub ignore: &'a Arc<dyn IgnorePort>,
+    pub logger: Option<&'a Arc<dyn LoggerPort>>,
+    pub codebase_root: &'a PathBuf,
+    pub supported_extensions: Option<&'a Vec<Box<str>>>,
+    pub ignore_patterns: Option<&'a Vec<Box<str>>>,
+    /// Highest-precedence patterns (e.g. from the command line).
+    pub additional_ignore_patterns: &'a [Box<str>],
+    pub file_list: Option<&'a Vec<Box<str>>>,
+    pub max_files: Option<NonZeroUsize>,
+    pub path_normalization: PathNormalization,
+    pub skip_binary: bool,
+}
+
+impl<'a> FileScan<'a> {
+    fn from_index(deps: &'a IndexCodebaseDeps, input: &'a IndexCodebaseInput) -> Self {
+        Self {
+            filesystem: &deps.filesystem,
+            path_policy: &deps.path_policy,
+            ignore: &deps.ignore,
+            logger: deps.logger.as_ref(),
+            codebase_root: &input.codebase_root,
+            supported_extensions: input.supported_extensions.as_ref(),
+            ignore_patterns: input.ignore_patterns.as_ref(),
+            additional_ignore_patterns: &input.additional_ignore_patterns,
+            file_list: input.file_list.as_ref(),
+            max_files: input.max_files,
+            path_normalization: input.path_normalization,
+            skip_binary: input.skip_binary,
+        }
+    }
+}
+
+/// Files selected by the index scan.
+pub(super) struct IndexFiles {
+    /// Relative paths, sorted.
+    pub files: Vec<Box<str>>,
+    /// Whether `max_files` cut enumeration short.
+    pub limit_reached: bool,
+}
+
+/// Discover the files to index, sorted by relative path.
+///
+/// The order is independent of directory iteration order, so chunk batches,
+/// insert order, and the resulting snapshot are reproducible across runs.
+/// Enumeration stops as soon as `max_files` candidates are found.
 #[tracing::instrument(
     name = "app.index.scan",
     skip_all,
@@ -23,7 +82,7 @@ const CONTEXT_IGNORE_FILE: &str = ".contextignore";
             .ignore_patterns
             .as_ref()
             .is_some_and(|values| !values.is_empty()),
-        max_files = input.max_files.map(std::num::NonZeroUsize::get),
+        max_files = input.max_files.map(NonZeroUsize::get),
     )
 )]
 pub(super) async fn load_index_files(
@@ -31,35 +90,74 @@ pub(super) async fn load_index_files(
     deps: &IndexCodebaseDeps,
     input: &IndexCodebaseInput,
     stats: &Arc<IndexStageStatsCollector>,
+) -> Result<IndexFiles> {
+    let (files, limit_reached) =
+        collect_files(ctx, &FileScan::from_index(deps, input), stats).await?;
+    Ok(IndexFiles {
+        files,
+        limit_reached,
+    })
+}
+
+/// Discover the files `index` would pick up, without recording stage stats.
+///
+/// Sorted by relative path, like the index scan. Callers that need the full
+/// set should leave `max_files` unset.
+pub async fn list_indexable_files(
+    ctx: &RequestContext,
+    scan: &FileScan<'_>,
 ) -> Result<Vec<Box<str>>> {
-    let ignore_patterns = load_ignore_patterns(ctx, deps, input, stats).await?;
-    let raw_files = if let Some(file_list) = input.file_list.as_ref() {
+    let stats = Arc::new(IndexStageStatsCollector::new());
+    let (files, _) = collect_files(ctx, scan, &stats).await?;
+    Ok(files)
+}
+
+/// Returns the sorted file list and whether `max_files` truncated it.
+async fn collect_files(
+    ctx: &RequestContext,
+    scan: &FileScan<'_>,
+    stats: &Arc<IndexStageStatsCollector>,
+) -> Result<(Vec<Box<str>>, bool)> {
+    let ignore_layers = load_ignore_patterns(ctx, scan, stats).await?;
+    let (raw_files, scan_truncated) = if let Some(file_list) = scan.file_list {
         let mut files: Vec<Box<str>> = file_list
             .iter()
             .map(|path| normalize_relative_path(path).into_boxed_str())
             .collect();
         files.sort();
-        files
+        (files, false)
     } else {
-        scan_code_files(ctx, deps, input, &ignore_patterns, stats).await?
+        scan_code_files(ctx, scan, &ignore_layers, stats).await?
     };
 
-    let files = filter_files(ctx, deps, raw_files, input, &ignore_patterns, stats)?;
-    tracing::debug!(file_count = files.len(), "index scan finalized file list");
-    Ok(files)
+    // Both sources are sorted and filtering keeps relative order; indexing
+    // reproducibility depends on it.
+    let (files, filter_truncated) = filter_files(ctx, scan, raw_files, &ignore_layers, stats)?;
+    let files = if scan.skip_binary {
+        drop_binary_files(ctx, scan, files, stats).await?
+    } else {
+        files
+    };
+    debug_assert!(files.is_sorted(), "index file list must be sorted");
+    let limit_reached = scan_truncated || filter_truncated;
+    tracing::debug!(
+        file_count = files.len(),
+        limit_reached,
+        "index scan finalized file list"
+    );
+    Ok((files, limit_reached))
 }
 
 async fn scan_code_files(
     ctx: &RequestContext,
-    deps: &IndexCodebaseDeps,
-    input: &IndexCodebaseInput,
-    ignore_patterns: &[Box<str>],
+    scan: &FileScan<'_>,
+    ignore_layers: &[Vec<Box<str>>],
     stats: &Arc<IndexStageStatsCollector>,
-) -> Result<Vec<Box<str>>> {
+) -> Result<(Vec<Box<str>>, bool)> {
     let started = Instant::now();
-    let supported_extensions = normalize_extensions(input.supported_extensions.as_ref());
+    let supported_extensions = normalize_extensions(scan.supported_extensions);
     let filter_by_ext = !supported_extensions.is_empty();
-    let fs = deps.filesystem.session(input.codebase_root.clone());
+    let fs = scan.filesystem.session(scan.codebase_root.clone());
 
     let mut dirs: VecDeque<String> = VecDeque::from([String::from(".")]);
     let mut files: Vec<String> = Vec::new();
@@ -67,14 +165,14 @@ async fn scan_code_files(
     while let Some(dir) = dirs.pop_front() {
         ctx.ensure_not_cancelled("index_codebase.scan")?;
 
-        let safe_dir = deps.path_policy.to_safe_relative_path(&dir)?;
+        let safe_dir = scan.path_policy.to_safe_relative_path(&dir)?;
         let entries = match fs.read_dir(ctx, safe_dir).await {
             Ok(entries) => entries,
             Err(error) => {
-                if error.is_cancelled() {
+                if error.is_cancelled() || error.code == symlink_escape_code() {
                     return Err(error);
                 }
-                if let Some(logger) = deps.logger.as_ref() {
+                if let Some(logger) = scan.logger {
                     logger.warn(
                         "index.scan.dir_read_failed",
                         "Cannot read directory during scan",
@@ -92,8 +190,8 @@ async fn scan_code_files(
             ctx.ensure_not_cancelled("index_codebase.scan_entry")?;
 
             let rel = join_relative(&dir, entry.name.as_ref());
-            if deps.ignore.is_ignored(&IgnoreMatchInput {
-                ignore_patterns: ignore_patterns.to_vec(),
+            if scan.ignore.is_ignored(&IgnoreMatchInput {
+                ignore_layers: ignore_layers.to_vec(),
                 relative_path: rel.clone().into_boxed_str(),
             }) {
                 continue;
@@ -114,14 +212,16 @@ async fn scan_code_files(
                         }
                     }
                     files.push(rel);
-                    if input.max_files.is_some_and(|max| files.len() >= max.get()) {
+                    if scan.max_files.is_some_and(|max| files.len() >= max.get()) {
+                        // Stop walking the tree, not just indexing: a root
+                        // like `$HOME` must not be enumerated in full.
                         let mut out = files
                             .into_iter()
                             .map(String::into_boxed_str)
                             .collect::<Vec<_>>();
                         out.sort();
                         stats.record_scan_code_files(started.elapsed());
-                        return Ok(out);
+                        return Ok((out, true));
                     }
                 },
                 FileSystemEntryKind::Other => {},
@@ -135,30 +235,32 @@ async fn scan_code_files(
         .collect::<Vec<_>>();
     out.sort();
     stats.record_scan_code_files(started.elapsed());
-    Ok(out)
+    Ok((out, false))
 }
 
 fn filter_files(
     ctx: &RequestContext,
-    deps: &IndexCodebaseDeps,
+    scan: &FileScan<'_>,
     raw_files: Vec<Box<str>>,
-    input: &IndexCodebaseInput,
-    ignore_patterns: &[Box<str>],
+    ignore_layers: &[Vec<Box<str>>],
     stats: &Arc<IndexStageStatsCollector>,
-) -> Result<Vec<Box<str>>> {
+) -> Result<(Vec<Box<str>>, bool)> {
     let started = Instant::now();
     ctx.ensure_not_cancelled("index_codebase.filter_files")?;
 
-    let supported_extensions = normalize_extensions(input.supported_extensions.as_ref());
+    let supported_extensions = normalize_extensions(scan.supported_extensions);
     let filter_by_ext = !supported_extensions.is_empty();
 
     let mut files = Vec::new();
-    for rel in raw_files {
+    let mut canonical_paths = HashSet::new();
+    let mut truncated = false;
+    let mut remaining = raw_files.into_iter();
+    for rel in remaining.by_ref() {
         ctx.ensure_not_cancelled("index_codebase.filter_files")?;
 
         let normalized = normalize_relative_path(rel.as_ref());
-        if deps.ignore.is_ignored(&IgnoreMatchInput {
-            ignore_patterns: ignore_patterns.to_vec(),
+        if scan.ignore.is_ignored(&IgnoreMatchInput {
+            ignore_layers: ignore_layers.to_vec(),
             relative_path: normalized.clone().into_boxed_str(),
         }) {
             continue;
@@ -174,41 +276,89 @@ fn filter_files(
             }
         }
 
+        if !canonical_paths.insert(scan.path_normalization.apply(&normalized)) {
+            if let Some(logger) = scan.logger {
+                logger.warn(
+                    "index.scan.duplicate_canonical_path",
+                    "Skipping file whose normalized path duplicates another file",
+                    None,
+                );
+            }
+            continue;
+        }
+
         files.push(normalized.into_boxed_str());
-        if input.max_files.is_some_and(|max| files.len() >= max.get()) {
+        if scan.max_files.is_some_and(|max| files.len() >= max.get()) {
+            truncated = remaining.len() > 0;
             break;
         }
     }
 
     files.sort();
     stats.record_filter_files(started.elapsed());
-    Ok(files)
+    Ok((files, truncated))
 }
 
-async fn load_ignore_patterns(
+/// Drop files whose leading bytes look binary, keeping the input order.
+///
+/// Files that cannot be read are kept; the split stage reports read errors.
+async fn drop_binary_files(
     ctx: &RequestContext,
-    deps: &IndexCodebaseDeps,
-    input: &IndexCodebaseInput,
+    scan: &FileScan<'_>,
+    files: Vec<Box<str>>,
     stats: &Arc<IndexStageStatsCollector>,
 ) -> Result<Vec<Box<str>>> {
+    let fs = scan.filesystem.session(scan.codebase_root.clone());
+    let mut kept = Vec::with_capacity(files.len());
+    for rel in files {
+        ctx.ensure_not_cancelled("index_codebase.detect_binary")?;
+
+        let safe_path = scan.path_policy.to_safe_relative_path(&rel)?;
+        match fs.read_file_head(ctx, safe_path, BINARY_SNIFF_BYTES).await {
+            Ok(head) if looks_binary(&head) => {
+                stats.record_scan_skipped_binary();
+                if let Some(logger) = scan.logger {
+                    logger.warn(
+                        "index.scan.skipped_binary",
+                        "Skipping file whose contents look binary",
+                        None,
+                    );
+                }
+            },
+            Err(error) if error.is_cancelled() => return Err(error),
+            _ => kept.push(rel),
+        }
+    }
+    Ok(kept)
+}
+
+/// NUL bytes or malformed UTF-8 mark content as binary. A multi-byte sequence
+/// cut off by the sniff window is not treated as malformed.
+fn looks_binary(head: &[u8]) -> bool {
+    head.contains(&0) || std::str::from_utf8(head).is_err_and(|error| error.error_len().is_some())
+}
+
+/// Read `.contextignore` and build the ignore layers (see [`ignore_layers`]).
+async fn load_ignore_patterns(
+    ctx: &RequestContext,
+    scan: &FileScan<'_>,
+    stats: &Arc<IndexStageStatsCollector>,
+) -> Result<Vec<Vec<Box<str>>>> {
     let started = Instant::now();
-    let mut patterns = input.ignore_patterns.clone().unwrap_or_default();
-    patterns.push(CONTEXT_IGNORE_FILE.into());
+    let configured = scan.ignore_patterns.map_or(&[][..], Vec::as_slice);
 
-    let safe_path = deps
+    let safe_path = scan
         .path_policy
         .to_safe_relative_path(CONTEXT_IGNORE_FILE)?;
-    let fs = deps.filesystem.session(input.codebase_root.clone());
-    match fs.read_file_text(ctx, safe_path).await {
-        Ok(contents) => {
-            patterns.extend(parse_context_ignore(&contents));
-        },
+    let fs = scan.filesystem.session(scan.codebase_root.clone());
+    let context_ignore = match fs.read_file_text(ctx, safe_path).await {
+        Ok(contents) => Some(contents),
         Err(error) => {
             if error.is_cancelled() {
                 return Err(error);
             }
             if error.code != semantic_code_shared::ErrorCode::not_found()
-                && let Some(logger) = deps.logger.as_ref()
+                && let Some(logger) = scan.logger
             {
                 logger.warn(
                     "index.ignore.read_failed",
@@ -216,22 +366,17 @@ async fn load_ignore_patterns(
                     None,
                 );
             }
+            None
         },
-    }
+    };
 
-    patterns.sort();
-    patterns.dedup();
+    let layers = ignore_layers(
+        configured,
+        context_ignore.as_deref(),
+        scan.additional_ignore_patterns,
+    );
     stats.record_scan_load_ignore_patterns(started.elapsed());
-    Ok(patterns)
-}
-
-fn parse_context_ignore(contents: &str) -> Vec<Box<str>> {
-    contents
-        .lines()
-        .map(str::trim)
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .map(|line| line.to_owned().into_boxed_str())
-        .collect()
+    Ok(layers)
 }
 
 fn normalize_extensions(values: Option<&Vec<Box<str>>>) -> HashSet<Box<str>> {
@@ -2,10 +2,10 @@
 
 use super::scanner::file_extension_of;
 use super::types::{FileResult, FileTaskContext, IndexStageStatsCollector};
-use semantic_code_domain::Language;
+use semantic_code_domain::{Language, OversizeChunkPolicy};
 use semantic_code_ports::{CodeChunk, FileSystemEntryKind, SplitOptions};
-use semantic_code_shared::{RequestContext, Result};
-use std::collections::HashMap;
+use semantic_code_shared::{ErrorCode, ErrorEnvelope, RequestContext, Result};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
@@ -46,6 +46,7 @@ struct SpawnFileTaskContext {
     safe_file: semantic_code_ports::SafeRelativePath,
     relative_path: Box<str>,
     max_file_size_bytes: Option<u64>,
+    record_mtime: bool,
     stats: Arc<IndexStageStatsCollector>,
 }
 
@@ -60,8 +61,13 @@ impl SpawnFileTaskContext {
             deps: task_ctx.deps.clone(),
             codebase_root: task_ctx.codebase_root.clone(),
             safe_file,
+            max_file_size_bytes: max_file_size_for_path(
+                relative_path.as_ref(),
+                task_ctx.max_file_size_bytes,
+                task_ctx.max_file_size_bytes_by_extension,
+            ),
             relative_path,
-            max_file_size_bytes: task_ctx.max_file_size_bytes,
+            record_mtime: task_ctx.record_mtime,
             stats: Arc::clone(&task_ctx.stats),
         })
     }
@@ -74,24 +80,26 @@ impl SpawnFileTaskContext {
             safe_file,
             relative_path,
             max_file_size_bytes,
+            record_mtime,
             stats,
         } = self;
 
         let mut split_timer = SplitStatsGuard::new(Arc::clone(&stats));
         request_ctx.ensure_not_cancelled("index_codebase.file_task")?;
 
-        if !file_passes_size_check(
+        let FileStatCheck::Index { mtime_ms } = file_passes_size_check(
             &request_ctx,
             &deps,
             &codebase_root,
             &safe_file,
             max_file_size_bytes,
+            record_mtime,
             stats.as_ref(),
         )
         .await?
-        {
+        else {
             return Ok(FileResult::Skipped);
-        }
+        };
 
         let Some(code) = read_file_text_or_skip(
             &request_ctx,
@@ -126,6 +134,7 @@ impl SpawnFileTaskContext {
             relative_path,
             language,
             chunks,
+            mtime_ms,
         })
     }
 }
@@ -154,44 +163,75 @@ pub(super) fn submit_file_task<'a>(
     Ok(())
 }
 
+/// Resolve the size limit for a file from the longest matching extension override.
+///
+/// Keys are dot-separated suffixes (`md`, `min.js`), so `app.min.js` prefers a
+/// `min.js` entry over `js`. Files without a matching entry use `default`.
+fn max_file_size_for_path(
+    relative_path: &str,
+    default: Option<u64>,
+    by_extension: &BTreeMap<Box<str>, u64>,
+) -> Option<u64> {
+    if by_extension.is_empty() {
+        return default;
+    }
+    let file_name = relative_path
+        .rsplit('/')
+        .next()
+        .unwrap_or(relative_path)
+        .to_ascii_lowercase();
+    by_extension
+        .iter()
+        .filter(|(ext, _)| {
+            file_name
+                .strip_suffix(ext.as_ref())
+                .and_then(|stem| stem.strip_suffix('.'))
+                .is_some_and(|stem| !stem.is_empty())
+        })
+        .max_by_key(|(ext, _)| ext.len())
+        .map_or(default, |(_, limit)| Some(*limit))
+}
+
+/// Outcome of the pre-read stat.
+enum FileStatCheck {
+    /// Skip the file (not a regular file, over the size limit, or unstattable).
+    Skip,
+    /// Index the file, recording its modification time when known.
+    Index { mtime_ms: Option<u64> },
+}
+
+/// Stat the file when a size limit applies or its mtime is recorded.
+///
+/// Without a limit the stat only supplies the mtime, so a stat error keeps the
+/// file (without an mtime) instead of skipping it.
 async fn file_passes_size_check(
     ctx: &RequestContext,
     deps: &super::types::IndexCodebaseDeps,
     codebase_root: &Path,
     safe_file: &semantic_code_ports::SafeRelativePath,
     max_file_size_bytes: Option<u64>,
+    record_mtime: bool,
     stats: &IndexStageStatsCollector,
-) -> Result<bool> {
+) -> Result<FileStatCheck> {
     let started = Instant::now();
-    let Some(max_file_size_bytes) = max_file_size_bytes else {
+    if max_file_size_bytes.is_none() && !record_mtime {
         stats.record_split_file_passes_size_check(started.elapsed());
-        return Ok(true);
-    };
-
+        return Ok(FileStatCheck::Index { mtime_ms: None });
+    }
     let stat = deps
         .filesystem
         .stat(ctx, codebase_root.to_path_buf(), safe_file.clone())
         .await;
-    match stat {
-        Ok(stat) => {
-            if stat.kind != FileSystemEntryKind::File {
-                return Ok(false);
-            }
-            if stat.size_bytes > max_file_size_bytes {
-                if let Some(logger) = deps.logger.as_ref() {
-                    logger.warn(
-                        "index.file.skipped_max_size",
-                        "Skipping file over maxFileSizeBytes",
-                        None,
-                    );
-                }
-                return Ok(false);
-            }
-        },
+    let stat = match stat {
+        Ok(stat) => stat,
         Err(error) => {
             if error.is_cancelled() {
                 return Err(error);
             }
+            if max_file_size_bytes.is_none() {
+                stats.record_split_file_passes_size_check(started.elapsed());
+                return Ok(FileStatCheck::Index { mtime_ms: None });
+            }
             if let Some(logger) = deps.logger.as_ref() {
                 logger.warn(
                     "index.file.skipped_stat_error",
@@ -199,12 +239,31 @@ async fn file_passes_size_check(
                     None,
                 );
             }
-            return Ok(false);
+            return Ok(FileStatCheck::Skip);
         },
+    };
+
+    if let Some(max_file_size_bytes) = max_file_size_bytes {
+        if stat.kind != FileSystemEntryKind::File {
+            return Ok(FileStatCheck::Skip);
+        }
+        if stat.size_bytes > max_file_size_bytes {
+            if let Some(logger) = deps.logger.as_ref() {
+                logger.warn(
+                    "index.file.skipped_max_size",
+                    "Skipping file over maxFileSizeBytes",
+                    None,
+                );
+            }
+            return Ok(FileStatCheck::Skip);
+        }
     }
 
     stats.record_split_file_passes_size_check(started.elapsed());
-    Ok(true)
+    // Adapters report 0 when the platform cannot supply a modification time.
+    Ok(FileStatCheck::Index {
+        mtime_ms: Some(stat.mtime_ms).filter(|mtime_ms| record_mtime && *mtime_ms > 0),
+    })
 }
 
 async fn read_file_text_or_skip(
@@ -241,6 +300,20 @@ async fn read_file_text_or_skip(
     }
 }
 
+/// Order chunks by span so embedding and insert order never depends on the
+/// order the splitter yielded them in. The sort is stable, so chunks sharing a
+/// span keep their relative order.
+fn sort_chunks_by_span(chunks: &mut [CodeChunk]) {
+    chunks.sort_by_key(|chunk| {
+        (
+            chunk.span.start_line(),
+            chunk.span.end_line(),
+            chunk.fragment_start_byte,
+            chunk.fragment_end_byte,
+        )
+    });
+}
+
 async fn split_file_or_skip(
     ctx: &RequestContext,
     deps: &super::types::IndexCodebaseDeps,
@@ -262,7 +335,8 @@ async fn split_file_or_skip(
         )
         .await
     {
-        Ok(chunks) => {
+        Ok(mut chunks) => {
+            sort_chunks_by_span(&mut chunks);
             stats.record_split_file_or_skip(started.elapsed());
             Ok(Some(chunks))
         },
@@ -283,6 +357,130 @@ async fn split_file_or_skip(
     }
 }
 
+/// Apply `policy` to chunks whose embedding input would exceed the provider's
+/// input limit.
+///
+/// `template_chars` is what the document template adds to each chunk, so the
+/// content budget is the limit minus that. Returns the surviving chunks and
+/// how many were oversized; `Error` fails on the first oversized chunk with
+/// `index:chunk_too_large`.
+pub(super) fn apply_oversize_policy(
+    chunks: Vec<CodeChunk>,
+    max_input_chars: Option<u32>,
+    template_chars: usize,
+    policy: OversizeChunkPolicy,
+    file_path: &str,
+) -> Result<(Vec<CodeChunk>, u64)> {
+    let Some(max_input) = max_input_chars
+        .and_then(|limit| usize::try_from(limit).ok())
+        .filter(|limit| *limit > 0)
+    else {
+        return Ok((chunks, 0));
+    };
+    // A template longer than the limit still leaves one char per piece.
+    let limit = max_input.saturating_sub(template_chars).max(1);
+
+    match policy {
+        OversizeChunkPolicy::Split => Ok(split_oversized_chunks(chunks, limit)),
+        OversizeChunkPolicy::Error => {
+            if let Some(chunk) = chunks
+                .iter()
+                .find(|chunk| chunk.content.chars().count() > limit)
+            {
+                let input_chars = chunk.content.chars().count().saturating_add(template_chars);
+                return Err(ErrorEnvelope::expected(
+                    ErrorCode::new("index", "chunk_too_large"),
+                    "chunk exceeds the embedding provider input limit",
+                )
+                .with_metadata("file", file_path.to_owned())
+                .with_metadata("startLine", chunk.span.start_line().to_string())
+                .with_metadata("chars", input_chars.to_string())
+                .with_metadata("templateChars", template_chars.to_string())
+                .with_metadata("maxInputChars", max_input.to_string()));
+            }
+            Ok((chunks, 0))
+        },
+        OversizeChunkPolicy::Truncate | OversizeChunkPolicy::Skip => {
+            let mut oversized = 0_u64;
+            let mut out = Vec::with_capacity(chunks.len());
+            for mut chunk in chunks {
+                let Some((cut, _)) = chunk.content.char_indices().nth(limit) else {
+                    out.push(chunk);
+                    continue;
+                };
+                oversized = oversized.saturating_add(1);
+                if policy == OversizeChunkPolicy::Truncate {
+                    let base = chunk.fragment_start_byte.unwrap_or(0);
+                    chunk.content = chunk.content.get(..cut).unwrap_or_default().into();
+                    chunk.fragment_start_byte = Some(base);
+                    chunk.fragment_end_byte = u32::try_from(cut)
+                        .ok()
+                        .and_then(|cut| base.checked_add(cut));
+                    out.push(chunk);
+                }
+            }
+            Ok((out, oversized))
+        },
+    }
+}
+
+/// Split chunks longer than `limit` chars into line-aligned pieces.
+///
+/// Pieces keep the original line span and carry fragment byte offsets, so their
+/// chunk ids stay distinct. Returns the chunks and how many were oversized.
+fn split_oversized_chunks(chunks: Vec<CodeChunk>, limit: usize) -> (Vec<CodeChunk>, u64) {
+    let mut oversized = 0_u64;
+    let mut out = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        if chunk.content.chars().count() <= limit {
+            out.push(chunk);
+            continue;
+        }
+        oversized = oversized.saturating_add(1);
+        let base = chunk.fragment_start_byte.unwrap_or(0);
+        for (offset, piece) in char_limited_pieces(&chunk.content, limit) {
+            let start = u32::try_from(offset)
+                .ok()
+                .and_then(|offset| base.checked_add(offset));
+            let end = u32::try_from(offset.saturating_add(piece.len()))
+                .ok()
+                .and_then(|end| base.checked_add(end));
+            out.push(CodeChunk {
+                content: piece.into(),
+                span: chunk.span,
+                fragment_start_byte: start,
+                fragment_end_byte: end,
+                language: chunk.language,
+                file_path: chunk.file_path.clone(),
+            });
+        }
+    }
+    (out, oversized)
+}
+
+/// Cut `content` into `(byte_offset, piece)` pairs of at most `limit` chars,
+/// breaking after the last newline in each window when there is one.
+fn char_limited_pieces(content: &str, limit: usize) -> Vec<(usize, &str)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    while let Some(rest) = content.get(start..).filter(|rest| !rest.is_empty()) {
+        let window_end = rest
+            .char_indices()
+            .nth(limit)
+            .map_or(rest.len(), |(index, _)| index);
+        let end = if window_end < rest.len() {
+            rest.get(..window_end)
+                .and_then(|window| window.rfind('\n'))
+                .map_or(window_end, |newline| newline + 1)
+        } else {
+            window_end
+        };
+        pieces.push((start, rest.get(..end).unwrap_or(rest)));
+        start += end;
+    }
+    pieces
+}
+
 fn language_from_extension(ext: Option<&str>) -> Language {
     ext.map_or(Language::Text, Language::from_extension)
 }
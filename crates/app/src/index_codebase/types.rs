@@ -1,18 +1,25 @@
 //! Shared types for `index_codebase` pipeline.
 
-use semantic_code_domain::{Chunk, CollectionName, IndexMode, Language, LineSpan, MAX_CHUNK_CHARS};
+use super::inserter::ShardRouter;
+use semantic_code_domain::{
+    Chunk, CollectionName, IndexMode, Language, LineSpan, MAX_CHUNK_CHARS, OnDuplicateId,
+    OversizeChunkPolicy, PathNormalization,
+};
 use semantic_code_ports::{
     CodeChunk, EmbeddingPort, FileSystemPort, IgnorePort, LoggerPort, PathPolicyPort, SplitterPort,
     TelemetryPort, VectorDbPort, VectorDocumentForInsert,
 };
-use semantic_code_shared::{RequestContext, Result, WorkerPool, WorkerPoolOptions};
+use semantic_code_shared::{
+    ErrorCode, ErrorEnvelope, RequestContext, Result, WorkerPool, WorkerPoolOptions,
+};
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
 use std::marker::PhantomData;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::pin::Pin;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
@@ -57,6 +64,8 @@ pub struct PrepareStageStats {
 pub struct ScanStageStats {
     /// Files discovered for indexing.
     pub files: u64,
+    /// Files dropped because their leading bytes looked binary.
+    pub skipped_binary: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level rollups inside the scan stage.
@@ -72,6 +81,13 @@ pub struct FunctionTimingStats {
     pub duration_ms: u64,
 }
 
+impl FunctionTimingStats {
+    const fn absorb(&mut self, other: &Self) {
+        self.calls = self.calls.saturating_add(other.calls);
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+    }
+}
+
 /// Function-level prepare breakdown.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct PrepareFunctionStats {
@@ -101,8 +117,13 @@ pub struct ScanFunctionStats {
 pub struct SplitStageStats {
     /// Files processed by the splitter.
     pub files: u64,
-    /// Chunks produced by the splitter.
+    /// Chunks produced by the splitter, before the oversize policy runs.
     pub chunks: u64,
+    /// Chunks whose embedding input exceeded the provider limit (split,
+    /// truncated, or skipped).
+    pub oversized_chunks: u64,
+    /// Chunks dropped for being shorter than the configured minimum length.
+    pub dropped_small: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level rollups inside the split stage.
@@ -129,6 +150,10 @@ pub struct EmbedStageStats {
     pub batches: u64,
     /// Chunks embedded.
     pub chunks: u64,
+    /// Embedding batches that failed and were skipped under the failure thresholds.
+    pub failed_batches: u64,
+    /// Chunks in skipped embedding batches (not indexed).
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level rollups inside the embed stage.
@@ -155,12 +180,19 @@ pub struct InsertStageStats {
     pub batches: u64,
     /// Chunks inserted.
     pub chunks: u64,
+    /// Chunks the vector DB rejected and the run skipped.
+    pub failed_chunks: u64,
+    /// Identifiers of skipped chunks (capped at [`MAX_RECORDED_INSERT_FAILURES`]).
+    pub failed_ids: Vec<Box<str>>,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level rollups inside the insert stage.
     pub breakdown: InsertFunctionStats,
 }
 
+/// Maximum number of rejected chunk ids kept in [`InsertStageStats::failed_ids`].
+pub const MAX_RECORDED_INSERT_FAILURES: usize = 64;
+
 /// Function-level insert breakdown.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct InsertFunctionStats {
@@ -185,12 +217,124 @@ pub struct IndexStageStats {
     pub insert: InsertStageStats,
 }
 
+impl IndexStageStats {
+    /// Fold another run's stats into these; counts and durations add up.
+    ///
+    /// `insert.failed_ids` stays capped at [`MAX_RECORDED_INSERT_FAILURES`].
+    pub fn absorb(&mut self, other: &Self) {
+        self.prepare.absorb(&other.prepare);
+        self.scan.absorb(&other.scan);
+        self.split.absorb(&other.split);
+        self.embed.absorb(&other.embed);
+        self.insert.absorb(&other.insert);
+    }
+}
+
+impl PrepareStageStats {
+    const fn absorb(&mut self, other: &Self) {
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+        let (ours, theirs) = (&mut self.breakdown, &other.breakdown);
+        ours.has_collection.absorb(&theirs.has_collection);
+        ours.drop_collection.absorb(&theirs.drop_collection);
+        ours.detect_dimension.absorb(&theirs.detect_dimension);
+        ours.create_collection.absorb(&theirs.create_collection);
+    }
+}
+
+impl ScanStageStats {
+    const fn absorb(&mut self, other: &Self) {
+        self.files = self.files.saturating_add(other.files);
+        self.skipped_binary = self.skipped_binary.saturating_add(other.skipped_binary);
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+        let (ours, theirs) = (&mut self.breakdown, &other.breakdown);
+        ours.load_ignore_patterns
+            .absorb(&theirs.load_ignore_patterns);
+        ours.scan_code_files.absorb(&theirs.scan_code_files);
+        ours.filter_files.absorb(&theirs.filter_files);
+    }
+}
+
+impl SplitStageStats {
+    const fn absorb(&mut self, other: &Self) {
+        self.files = self.files.saturating_add(other.files);
+        self.chunks = self.chunks.saturating_add(other.chunks);
+        self.oversized_chunks = self.oversized_chunks.saturating_add(other.oversized_chunks);
+        self.dropped_small = self.dropped_small.saturating_add(other.dropped_small);
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+        let (ours, theirs) = (&mut self.breakdown, &other.breakdown);
+        ours.file_passes_size_check
+            .absorb(&theirs.file_passes_size_check);
+        ours.read_file_text_or_skip
+            .absorb(&theirs.read_file_text_or_skip);
+        ours.split_file_or_skip.absorb(&theirs.split_file_or_skip);
+        ours.await_file_task.absorb(&theirs.await_file_task);
+    }
+}
+
+impl EmbedStageStats {
+    const fn absorb(&mut self, other: &Self) {
+        self.batches = self.batches.saturating_add(other.batches);
+        self.chunks = self.chunks.saturating_add(other.chunks);
+        self.failed_batches = self.failed_batches.saturating_add(other.failed_batches);
+        self.failed_chunks = self.failed_chunks.saturating_add(other.failed_chunks);
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+        let (ours, theirs) = (&mut self.breakdown, &other.breakdown);
+        ours.queue_latency.absorb(&theirs.queue_latency);
+        ours.provider_embed_batch
+            .absorb(&theirs.provider_embed_batch);
+        ours.build_insert_documents
+            .absorb(&theirs.build_insert_documents);
+        ours.await_embedding_task
+            .absorb(&theirs.await_embedding_task);
+    }
+}
+
+impl InsertStageStats {
+    fn absorb(&mut self, other: &Self) {
+        self.batches = self.batches.saturating_add(other.batches);
+        self.chunks = self.chunks.saturating_add(other.chunks);
+        self.failed_chunks = self.failed_chunks.saturating_add(other.failed_chunks);
+        let room = MAX_RECORDED_INSERT_FAILURES.saturating_sub(self.failed_ids.len());
+        self.failed_ids
+            .extend(other.failed_ids.iter().take(room).cloned());
+        self.duration_ms = self.duration_ms.saturating_add(other.duration_ms);
+        let (ours, theirs) = (&mut self.breakdown, &other.breakdown);
+        ours.provider_insert_batch
+            .absorb(&theirs.provider_insert_batch);
+        ours.await_insert_task.absorb(&theirs.await_insert_task);
+    }
+}
+
+/// Guards on how indexing may create or recreate the target collection.
+///
+/// The default allows both, matching the historical behavior. Teams sharing a
+/// collection can set `allow_drop = false` so a forced reindex or an existing
+/// collection with a different dimension fails instead of being rebuilt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionPolicy {
+    /// Create the collection when it does not exist yet.
+    ///
+    /// Shard collections are created lazily on insert and are not gated.
+    pub create_if_missing: bool,
+    /// Allow dropping an existing collection to recreate it.
+    pub allow_drop: bool,
+}
+
+impl Default for CollectionPolicy {
+    fn default() -> Self {
+        Self {
+            create_if_missing: true,
+            allow_drop: true,
+        }
+    }
+}
+
 /// Completion status for indexing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IndexCodebaseStatus {
     /// Completed successfully.
     Completed,
-    /// Stopped because the chunk limit was reached.
+    /// Stopped because the chunk limit or the `max_files` scan cap was reached.
     LimitReached,
 }
 
@@ -203,6 +347,9 @@ pub struct IndexCodebaseOutput {
     pub total_chunks: usize,
     /// Completion status.
     pub status: IndexCodebaseStatus,
+    /// Files whose chunks were partly or wholly skipped by tolerated
+    /// embedding failures (sorted). They are not fully indexed.
+    pub failed_files: Vec<Box<str>>,
     /// Stage-level ingestion stats.
     pub stage_stats: IndexStageStats,
 }
@@ -218,12 +365,23 @@ pub struct IndexCodebaseInput {
     pub index_mode: IndexMode,
     /// Allowed file extensions (normalized, optional).
     pub supported_extensions: Option<Vec<Box<str>>>,
-    /// Ignore patterns (normalized, optional).
+    /// Ignore patterns (normalized, optional), e.g. `sync.ignorePatterns`.
+    ///
+    /// Layered above the built-in defaults, so `!pattern` re-includes a default.
     pub ignore_patterns: Option<Vec<Box<str>>>,
+    /// Ignore patterns layered above `ignore_patterns` and `.contextignore`,
+    /// e.g. passed on the command line; `!pattern` re-includes lower matches.
+    pub additional_ignore_patterns: Vec<Box<str>>,
     /// Optional explicit file list (relative paths) to index.
     pub file_list: Option<Vec<Box<str>>>,
     /// Force reindex (drop collection if it exists).
     pub force_reindex: bool,
+    /// Optional description attached to the collection on creation.
+    ///
+    /// When unset, a description derived from the codebase root and index mode is used.
+    pub collection_description: Option<Box<str>>,
+    /// Whether the collection may be created or dropped by this run.
+    pub collection_policy: CollectionPolicy,
     /// Optional progress callback.
     pub on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
     /// Embedding batch size (chunks per batch).
@@ -234,6 +392,41 @@ pub struct IndexCodebaseInput {
     pub max_files: Option<NonZeroUsize>,
     /// Skip files larger than this size.
     pub max_file_size_bytes: Option<u64>,
+    /// Per-extension size limits (keys without a leading dot, e.g. `min.js`).
+    ///
+    /// The longest matching suffix wins; unlisted files use `max_file_size_bytes`.
+    pub max_file_size_bytes_by_extension: BTreeMap<Box<str>, u64>,
+    /// Canonicalization applied to relative paths for chunk ids and metadata.
+    ///
+    /// Files whose canonical paths collide are indexed once (first in sort order).
+    pub path_normalization: PathNormalization,
+    /// Handling of chunks whose embedding input (content plus document
+    /// template) exceeds the routed provider's input limit.
+    ///
+    /// Enforced as chunks are queued for embedding.
+    pub oversize_chunk_policy: OversizeChunkPolicy,
+    /// Skip files whose leading bytes contain NUL bytes or invalid UTF-8.
+    pub skip_binary: bool,
+    /// Instruction prefix prepended to chunk content before embedding.
+    ///
+    /// Stored content and chunk ids use the original text.
+    pub document_template: Option<Box<str>>,
+    /// Custom key/value fields attached to every indexed document's metadata.
+    ///
+    /// Validated against the domain bounds before any collection work starts.
+    pub extra_metadata: BTreeMap<Box<str>, Box<str>>,
+    /// Record each file's modification time as `mtime_ms` in chunk metadata.
+    ///
+    /// Needed for query-time recency boosting. Off by default because stored
+    /// mtimes make snapshots of identical trees differ.
+    pub record_mtime: bool,
+    /// Chunks shorter than this many characters (after trimming) are dropped
+    /// before embedding; `0` keeps every chunk.
+    pub min_chunk_chars: u32,
+    /// Runtime cap on chunk content length (characters), e.g. `core.maxChunkChars`.
+    ///
+    /// `None` keeps only the `MAX_CHUNK_CHARS` hard limit.
+    pub max_chunk_chars: Option<usize>,
     /// Maximum buffered chunks (best-effort).
     pub max_buffered_chunks: Option<NonZeroUsize>,
     /// Maximum buffered embeddings (best-effort).
@@ -244,8 +437,36 @@ pub struct IndexCodebaseInput {
     pub max_in_flight_embedding_batches: Option<NonZeroUsize>,
     /// Max in-flight insert batches (default 1).
     pub max_in_flight_inserts: Option<NonZeroUsize>,
+    /// Optional shard count.
+    ///
+    /// When set, each document is routed to `<collection>_shard_<n>` by a hash of
+    /// its chunk id; shard collections are created on first insert.
+    pub shard_count: Option<u32>,
+    /// Abort once more than this many embedding batches fail back to back
+    /// (after provider retries).
+    ///
+    /// When both failure thresholds are unset the run aborts on the first
+    /// failed batch; when only one is set the other is unlimited.
+    pub max_consecutive_failures: Option<NonZeroUsize>,
+    /// Abort once more than this many embedding batches fail in total.
+    ///
+    /// Chunks in tolerated failed batches are skipped and counted in
+    /// [`EmbedStageStats::failed_chunks`].
+    pub max_total_failures: Option<NonZeroUsize>,
+    /// Handling of a chunk id repeated within one insert batch.
+    ///
+    /// Defaults to `Overwrite` (last wins); `Error` surfaces id-generation bugs.
+    pub on_duplicate_id: OnDuplicateId,
+    /// How long a cancelled run waits for in-flight tasks before aborting them.
+    ///
+    /// `None` uses [`DEFAULT_SHUTDOWN_TIMEOUT`]. Aborted tasks may leave
+    /// partial inserts behind.
+    pub shutdown_timeout: Option<Duration>,
 }
 
+/// Default grace period for in-flight tasks after a cancelled index run.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Dependencies required by the index use-case.
 #[derive(Clone)]
 pub struct IndexCodebaseDeps {
@@ -265,6 +486,65 @@ pub struct IndexCodebaseDeps {
     pub logger: Option<Arc<dyn LoggerPort>>,
     /// Optional telemetry.
     pub telemetry: Option<Arc<dyn TelemetryPort>>,
+    /// Per-language embedding providers; unrouted chunks use `embedding`.
+    pub embedding_routes: LanguageEmbeddingRoutes,
+}
+
+/// Embedding ports keyed by provider id, plus the language rules that pick one.
+///
+/// The embedder stage consults these per chunk; languages without a rule fall
+/// back to the default embedding port.
+#[derive(Clone, Default)]
+pub struct LanguageEmbeddingRoutes {
+    providers: BTreeMap<Box<str>, Arc<dyn EmbeddingPort>>,
+    by_language: BTreeMap<Language, Box<str>>,
+}
+
+impl LanguageEmbeddingRoutes {
+    /// Create an empty route set (every chunk uses the default port).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `port` under `provider_id`, replacing any previous port.
+    #[must_use]
+    pub fn with_provider(
+        mut self,
+        provider_id: impl Into<Box<str>>,
+        port: Arc<dyn EmbeddingPort>,
+    ) -> Self {
+        self.providers.insert(provider_id.into(), port);
+        self
+    }
+
+    /// Route `language` chunks to the provider registered as `provider_id`.
+    pub fn with_route(mut self, language: Language, provider_id: &str) -> Result<Self> {
+        if !self.providers.contains_key(provider_id) {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                format!("no embedding provider registered for route: {provider_id}"),
+            )
+            .with_metadata("language", language.as_str())
+            .with_metadata("provider", provider_id));
+        }
+        self.by_language.insert(language, provider_id.into());
+        Ok(self)
+    }
+
+    /// Return the routed port for `language`, if a rule matches.
+    #[must_use]
+    pub fn resolve(&self, language: Language) -> Option<&Arc<dyn EmbeddingPort>> {
+        self.by_language
+            .get(&language)
+            .and_then(|provider_id| self.providers.get(provider_id))
+    }
+
+    /// Returns true when no language has a route.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_language.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -361,6 +641,21 @@ impl IndexWorkerPools {
         () = self.embedding.stop().await;
         () = self.insert.stop().await;
     }
+
+    /// Stop all pools, force-aborting workers still busy after `timeout`.
+    ///
+    /// Returns `true` when every pool drained gracefully. A forced stop may
+    /// leave partial inserts in the vector DB; the next run's change detection
+    /// or a force reindex cleans them up.
+    pub(super) async fn stop_with_timeout(&mut self, timeout: Duration) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        let mut drained = true;
+        for pool in [&mut self.files, &mut self.embedding, &mut self.insert] {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            drained &= pool.stop_with_timeout(remaining).await;
+        }
+        drained
+    }
 }
 
 pub(super) struct ProgressTracker {
@@ -406,6 +701,7 @@ pub(super) struct IndexStageStatsCollector {
     prepare_create_collection_calls: AtomicU64,
     prepare_create_collection_duration_ms: AtomicU64,
     scan_files: AtomicU64,
+    scan_skipped_binary: AtomicU64,
     scan_duration_ms: AtomicU64,
     scan_load_ignore_patterns_calls: AtomicU64,
     scan_load_ignore_patterns_duration_ms: AtomicU64,
@@ -415,6 +711,8 @@ pub(super) struct IndexStageStatsCollector {
     scan_filter_files_duration_ms: AtomicU64,
     split_files: AtomicU64,
     split_chunks: AtomicU64,
+    split_oversized_chunks: AtomicU64,
+    split_dropped_small: AtomicU64,
     split_duration_ms: AtomicU64,
     split_file_passes_size_check_calls: AtomicU64,
     split_file_passes_size_check_duration_ms: AtomicU64,
@@ -426,6 +724,8 @@ pub(super) struct IndexStageStatsCollector {
     split_await_file_task_duration_ms: AtomicU64,
     embed_batches: AtomicU64,
     embed_chunks: AtomicU64,
+    embed_failed_batches: AtomicU64,
+    embed_failed_chunks: AtomicU64,
     embed_duration_ms: AtomicU64,
     embed_queue_latency_calls: AtomicU64,
     embed_queue_latency_duration_ms: AtomicU64,
@@ -437,6 +737,8 @@ pub(super) struct IndexStageStatsCollector {
     embed_await_embedding_task_duration_ms: AtomicU64,
     insert_batches: AtomicU64,
     insert_chunks: AtomicU64,
+    insert_failed_chunks: AtomicU64,
+    insert_failed_ids: Mutex<Vec<Box<str>>>,
     insert_duration_ms: AtomicU64,
     insert_provider_insert_batch_calls: AtomicU64,
     insert_provider_insert_batch_duration_ms: AtomicU64,
@@ -457,6 +759,7 @@ impl IndexStageStatsCollector {
             prepare_create_collection_calls: AtomicU64::new(0),
             prepare_create_collection_duration_ms: AtomicU64::new(0),
             scan_files: AtomicU64::new(0),
+            scan_skipped_binary: AtomicU64::new(0),
             scan_duration_ms: AtomicU64::new(0),
             scan_load_ignore_patterns_calls: AtomicU64::new(0),
             scan_load_ignore_patterns_duration_ms: AtomicU64::new(0),
@@ -466,6 +769,8 @@ impl IndexStageStatsCollector {
             scan_filter_files_duration_ms: AtomicU64::new(0),
             split_files: AtomicU64::new(0),
             split_chunks: AtomicU64::new(0),
+            split_oversized_chunks: AtomicU64::new(0),
+            split_dropped_small: AtomicU64::new(0),
             split_duration_ms: AtomicU64::new(0),
             split_file_passes_size_check_calls: AtomicU64::new(0),
             split_file_passes_size_check_duration_ms: AtomicU64::new(0),
@@ -477,6 +782,8 @@ impl IndexStageStatsCollector {
             split_await_file_task_duration_ms: AtomicU64::new(0),
             embed_batches: AtomicU64::new(0),
             embed_chunks: AtomicU64::new(0),
+            embed_failed_batches: AtomicU64::new(0),
+            embed_failed_chunks: AtomicU64::new(0),
             embed_duration_ms: AtomicU64::new(0),
             embed_queue_latency_calls: AtomicU64::new(0),
             embed_queue_latency_duration_ms: AtomicU64::new(0),
@@ -488,6 +795,8 @@ impl IndexStageStatsCollector {
             embed_await_embedding_task_duration_ms: AtomicU64::new(0),
             insert_batches: AtomicU64::new(0),
             insert_chunks: AtomicU64::new(0),
+            insert_failed_chunks: AtomicU64::new(0),
+            insert_failed_ids: Mutex::new(Vec::new()),
             insert_duration_ms: AtomicU64::new(0),
             insert_provider_insert_batch_calls: AtomicU64::new(0),
             insert_provider_insert_batch_duration_ms: AtomicU64::new(0),
@@ -549,6 +858,10 @@ impl IndexStageStatsCollector {
             .fetch_add(duration_ms(duration), Ordering::AcqRel);
     }
 
+    pub(super) fn record_scan_skipped_binary(&self) {
+        self.scan_skipped_binary.fetch_add(1, Ordering::AcqRel);
+    }
+
     pub(super) fn record_filter_files(&self, duration: Duration) {
         self.scan_filter_files_calls.fetch_add(1, Ordering::AcqRel);
         self.scan_filter_files_duration_ms
@@ -576,6 +889,15 @@ impl IndexStageStatsCollector {
             .fetch_add(duration_ms(duration), Ordering::AcqRel);
     }
 
+    pub(super) fn record_split_oversized_chunks(&self, chunks: u64) {
+        self.split_oversized_chunks
+            .fetch_add(chunks, Ordering::AcqRel);
+    }
+
+    pub(super) fn record_split_dropped_small(&self, chunks: u64) {
+        self.split_dropped_small.fetch_add(chunks, Ordering::AcqRel);
+    }
+
     pub(super) fn record_split_file_or_skip(&self, duration: Duration) {
         self.split_split_file_or_skip_calls
             .fetch_add(1, Ordering::AcqRel);
@@ -625,6 +947,11 @@ impl IndexStageStatsCollector {
             .fetch_add(duration_ms(duration), Ordering::AcqRel);
     }
 
+    pub(super) fn record_embed_failure(&self, chunks: u64) {
+        self.embed_failed_batches.fetch_add(1, Ordering::AcqRel);
+        self.embed_failed_chunks.fetch_add(chunks, Ordering::AcqRel);
+    }
+
     pub(super) fn record_insert(&self, chunks: u64, duration: Duration) {
         self.insert_batches.fetch_add(1, Ordering::AcqRel);
         self.insert_chunks.fetch_add(chunks, Ordering::AcqRel);
@@ -632,6 +959,21 @@ impl IndexStageStatsCollector {
             .fetch_add(duration_ms(duration), Ordering::AcqRel);
     }
 
+    pub(super) fn record_insert_failures<'a>(&self, ids: impl IntoIterator<Item = &'a str>) {
+        let mut failed = 0_u64;
+        let mut recorded = self.insert_failed_ids.lock().ok();
+        for id in ids {
+            failed = failed.saturating_add(1);
+            if let Some(recorded) = recorded.as_mut()
+                && recorded.len() < MAX_RECORDED_INSERT_FAILURES
+            {
+                recorded.push(id.into());
+            }
+        }
+        self.insert_failed_chunks
+            .fetch_add(failed, Ordering::AcqRel);
+    }
+
     pub(super) fn record_provider_insert_batch(&self, duration: Duration) {
         self.insert_provider_insert_batch_calls
             .fetch_add(1, Ordering::AcqRel);
@@ -680,6 +1022,7 @@ impl IndexStageStatsCollector {
     fn scan_snapshot(&self) -> ScanStageStats {
         ScanStageStats {
             files: self.scan_files.load(Ordering::Acquire),
+            skipped_binary: self.scan_skipped_binary.load(Ordering::Acquire),
             duration_ms: self.scan_duration_ms.load(Ordering::Acquire),
             breakdown: ScanFunctionStats {
                 load_ignore_patterns: Self::load_timing_stats(
@@ -702,6 +1045,8 @@ impl IndexStageStatsCollector {
         SplitStageStats {
             files: self.split_files.load(Ordering::Acquire),
             chunks: self.split_chunks.load(Ordering::Acquire),
+            oversized_chunks: self.split_oversized_chunks.load(Ordering::Acquire),
+            dropped_small: self.split_dropped_small.load(Ordering::Acquire),
             duration_ms: self.split_duration_ms.load(Ordering::Acquire),
             breakdown: SplitFunctionStats {
                 file_passes_size_check: Self::load_timing_stats(
@@ -728,6 +1073,8 @@ impl IndexStageStatsCollector {
         EmbedStageStats {
             batches: self.embed_batches.load(Ordering::Acquire),
             chunks: self.embed_chunks.load(Ordering::Acquire),
+            failed_batches: self.embed_failed_batches.load(Ordering::Acquire),
+            failed_chunks: self.embed_failed_chunks.load(Ordering::Acquire),
             duration_ms: self.embed_duration_ms.load(Ordering::Acquire),
             breakdown: EmbedFunctionStats {
                 queue_latency: Self::load_timing_stats(
@@ -754,6 +1101,12 @@ impl IndexStageStatsCollector {
         InsertStageStats {
             batches: self.insert_batches.load(Ordering::Acquire),
             chunks: self.insert_chunks.load(Ordering::Acquire),
+            failed_chunks: self.insert_failed_chunks.load(Ordering::Acquire),
+            failed_ids: self
+                .insert_failed_ids
+                .lock()
+                .map(|ids| ids.clone())
+                .unwrap_or_default(),
             duration_ms: self.insert_duration_ms.load(Ordering::Acquire),
             breakdown: InsertFunctionStats {
                 provider_insert_batch: Self::load_timing_stats(
@@ -787,6 +1140,8 @@ pub(super) struct FileTaskContext<'a> {
     pub(super) files: &'a [Box<str>],
     pub(super) codebase_root: PathBuf,
     pub(super) max_file_size_bytes: Option<u64>,
+    pub(super) max_file_size_bytes_by_extension: &'a BTreeMap<Box<str>, u64>,
+    pub(super) record_mtime: bool,
     pub(super) stats: Arc<IndexStageStatsCollector>,
 }
 
@@ -798,6 +1153,8 @@ impl<'a> FileTaskContext<'a> {
         files: &'a [Box<str>],
         codebase_root: PathBuf,
         max_file_size_bytes: Option<u64>,
+        max_file_size_bytes_by_extension: &'a BTreeMap<Box<str>, u64>,
+        record_mtime: bool,
         stats: Arc<IndexStageStatsCollector>,
     ) -> Self {
         Self {
@@ -807,6 +1164,8 @@ impl<'a> FileTaskContext<'a> {
             files,
             codebase_root,
             max_file_size_bytes,
+            max_file_size_bytes_by_extension,
+            record_mtime,
             stats,
         }
     }
@@ -821,6 +1180,7 @@ pub(super) struct BatchContext<'a> {
     pub(super) embedding_batch_size: NonZeroUsize,
     pub(super) max_pending_embedding_batches: usize,
     pub(super) max_pending_insert_batches: usize,
+    pub(super) shards: Option<Arc<ShardRouter>>,
     pub(super) stats: Arc<IndexStageStatsCollector>,
 }
 
@@ -832,6 +1192,7 @@ impl<'a> BatchContext<'a> {
         embedding_pool: &'a WorkerPool,
         insert_pool: &'a WorkerPool,
         limits: &IndexingLimits,
+        shards: Option<Arc<ShardRouter>>,
         stats: Arc<IndexStageStatsCollector>,
     ) -> Self {
         Self {
@@ -843,6 +1204,7 @@ impl<'a> BatchContext<'a> {
             embedding_batch_size: limits.embedding_batch_size,
             max_pending_embedding_batches: limits.max_pending_embedding_batches,
             max_pending_insert_batches: limits.max_pending_insert_batches,
+            shards,
             stats,
         }
     }
@@ -851,9 +1213,15 @@ impl<'a> BatchContext<'a> {
 pub(super) struct BatchState<'a> {
     pub(super) pending: Vec<PendingChunk>,
     pub(super) embedding_tasks: Vec<BoxFuture<'a, Result<EmbeddedBatch>>>,
+    /// Bookkeeping for each scheduled embedding batch, parallel to `embedding_tasks`.
+    pub(super) embedding_batches: Vec<ScheduledEmbeddingBatch>,
     pub(super) insert_tasks: Vec<InsertTask<'a>>,
     pub(super) next_batch_to_insert: usize,
     pub(super) next_insert_to_await: usize,
+    pub(super) consecutive_embed_failures: usize,
+    pub(super) total_embed_failures: usize,
+    /// Files that lost chunks to a tolerated embedding failure.
+    pub(super) failed_files: BTreeSet<Box<str>>,
 }
 
 impl BatchState<'_> {
@@ -861,13 +1229,23 @@ impl BatchState<'_> {
         Self {
             pending: Vec::new(),
             embedding_tasks: Vec::new(),
+            embedding_batches: Vec::new(),
             insert_tasks: Vec::new(),
             next_batch_to_insert: 0,
             next_insert_to_await: 0,
+            consecutive_embed_failures: 0,
+            total_embed_failures: 0,
+            failed_files: BTreeSet::new(),
         }
     }
 }
 
+/// Chunk count and distinct source files of one scheduled embedding batch.
+pub(super) struct ScheduledEmbeddingBatch {
+    pub(super) chunks: usize,
+    pub(super) relative_paths: Vec<Box<str>>,
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct PendingChunk {
     pub(super) relative_path: Box<str>,
@@ -877,6 +1255,7 @@ pub(super) struct PendingChunk {
     pub(super) language: Language,
     pub(super) content: Chunk<MAX_CHUNK_CHARS>,
     pub(super) file_extension: Option<Box<str>>,
+    pub(super) mtime_ms: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -895,6 +1274,7 @@ pub(super) enum FileResult {
         relative_path: Box<str>,
         language: Language,
         chunks: Vec<CodeChunk>,
+        mtime_ms: Option<u64>,
     },
 }
 
@@ -962,6 +1342,7 @@ impl<'a> IndexRunContext<'a> {
         files: &'a [Box<str>],
         limits: &'a IndexingLimits,
         pools: &'a IndexWorkerPools,
+        shards: Option<Arc<ShardRouter>>,
         stats: Arc<IndexStageStatsCollector>,
     ) -> Self {
         let file_tasks = FileTaskContext::new(
@@ -971,6 +1352,8 @@ impl<'a> IndexRunContext<'a> {
             files,
             input.codebase_root.clone(),
             input.max_file_size_bytes,
+            &input.max_file_size_bytes_by_extension,
+            input.record_mtime,
             Arc::clone(&stats),
         );
         let batch = BatchContext::new(
@@ -980,6 +1363,7 @@ impl<'a> IndexRunContext<'a> {
             &pools.embedding,
             &pools.insert,
             limits,
+            shards,
             Arc::clone(&stats),
         );
 
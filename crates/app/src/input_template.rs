@@ -0,0 +1,12 @@
+//! Embedding input templates for instruction-tuned models.
+
+/// Prepend an optional instruction template to an embedding input.
+///
+/// Templates are applied verbatim; `None` returns the text unchanged.
+#[must_use]
+pub fn apply_input_template(template: Option<&str>, text: &str) -> Box<str> {
+    template.map_or_else(
+        || text.into(),
+        |template| format!("{template}{text}").into_boxed_str(),
+    )
+}
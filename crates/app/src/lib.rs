@@ -5,9 +5,17 @@
 
 mod calibrate_bq1;
 mod clear_index;
+mod export_index;
+mod import_index;
 mod index_codebase;
+mod input_template;
+mod list_indexed_files;
+mod migrate_index;
+mod prune_index;
 mod reindex_by_change;
 mod semantic_search;
+mod sharding;
+mod verify_index;
 
 /// Generated FSM definitions for indexing pipeline.
 mod generated {
@@ -28,19 +36,34 @@ pub use calibrate_bq1::{
     observe_bq1_search,
 };
 pub use clear_index::{ClearIndexDeps, ClearIndexInput, clear_index};
+pub use export_index::{ExportIndexDeps, ExportIndexInput, ExportIndexOutput, export_index};
 pub use generated::{INDEX_PIPELINE_STATES, INDEX_PIPELINE_TRANSITIONS, IndexPipelineState};
+pub use import_index::{ImportIndexDeps, ImportIndexInput, ImportIndexOutput, import_index};
 pub use index_codebase::{
-    EmbedFunctionStats, EmbedStageStats, FunctionTimingStats, IndexCodebaseDeps,
-    IndexCodebaseInput, IndexCodebaseOutput, IndexCodebaseStatus, IndexProgress, IndexStageStats,
-    InsertFunctionStats, InsertStageStats, PrepareFunctionStats, PrepareStageStats,
-    ScanFunctionStats, ScanStageStats, SplitFunctionStats, SplitStageStats, index_codebase,
+    CollectionPolicy, DEFAULT_EMBEDDING_BATCH_SIZE, DEFAULT_SHUTDOWN_TIMEOUT, EmbedFunctionStats,
+    EmbedStageStats, FunctionTimingStats, IndexCodebaseDeps, IndexCodebaseInput,
+    IndexCodebaseInputBuilder, IndexCodebaseOutput, IndexCodebaseStatus, IndexProgress,
+    IndexStageStats, InsertFunctionStats, InsertStageStats, LanguageEmbeddingRoutes,
+    MAX_RECORDED_INSERT_FAILURES, PrepareFunctionStats, PrepareStageStats, ScanFunctionStats,
+    ScanStageStats, SplitFunctionStats, SplitStageStats, index_codebase,
 };
+pub use input_template::apply_input_template;
+pub use list_indexed_files::{
+    IndexedFileSummary, ListIndexedFilesDeps, ListIndexedFilesInput, list_indexed_files,
+};
+pub use migrate_index::{MigrateIndexDeps, MigrateIndexInput, MigrateIndexOutput, migrate_index};
 pub use placeholder::app_crate_version;
+pub use prune_index::{PruneIndexDeps, PruneIndexInput, PruneIndexOutput, prune_index};
 pub use reindex_by_change::{
     ReindexByChangeDeps, ReindexByChangeInput, ReindexByChangeOutput, reindex_by_change,
 };
 pub use semantic_search::{
-    SemanticSearchDeps, SemanticSearchInput, SemanticSearchOutput, semantic_search,
+    SearchPhase, SearchTimings, SemanticSearchDeps, SemanticSearchInput, SemanticSearchOutput,
+    semantic_search,
+};
+pub use sharding::index_collection_names;
+pub use verify_index::{
+    VerifyDrift, VerifyIndexDeps, VerifyIndexInput, VerifyReport, VerifyStatus, verify_index,
 };
 
 #[cfg(test)]
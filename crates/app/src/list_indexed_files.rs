@@ -0,0 +1,355 @@
+//! Enumerate indexed files and their chunk counts.
+
+use crate::sharding::index_collection_names;
+use semantic_code_domain::CollectionName;
+use semantic_code_ports::VectorDbPort;
+use semantic_code_shared::{RequestContext, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+/// Rows fetched per `query_paged` call while aggregating.
+const LIST_PAGE_SIZE: NonZeroU32 = match NonZeroU32::new(1_000) {
+    Some(size) => size,
+    None => NonZeroU32::MIN,
+};
+
+/// Input payload for listing indexed files.
+#[derive(Debug, Clone)]
+pub struct ListIndexedFilesInput {
+    /// Target collection name.
+    pub collection_name: CollectionName,
+    /// Shard count the index was built with; every shard collection is listed.
+    pub shard_count: Option<u32>,
+}
+
+/// Dependencies required by list-indexed-files.
+#[derive(Clone)]
+pub struct ListIndexedFilesDeps {
+    /// Vector DB adapter.
+    pub vectordb: Arc<dyn VectorDbPort>,
+}
+
+/// Per-file aggregate of indexed chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedFileSummary {
+    /// Path relative to the codebase root.
+    pub relative_path: Box<str>,
+    /// Number of indexed chunks for the file.
+    pub chunk_count: u64,
+    /// Language recorded for the file's chunks, when known.
+    pub language: Option<Box<str>>,
+}
+
+/// List indexed files with chunk counts, sorted by relative path.
+///
+/// Aggregates a paged `query_paged` scan of the whole collection (every shard
+/// for a sharded index), holding one page of rows at a time. Missing
+/// collections contribute nothing.
+#[tracing::instrument(
+    name = "app.list_indexed_files",
+    skip_all,
+    fields(collection = %input.collection_name.as_str())
+)]
+pub async fn list_indexed_files(
+    ctx: &RequestContext,
+    deps: &ListIndexedFilesDeps,
+    input: ListIndexedFilesInput,
+) -> Result<Vec<IndexedFileSummary>> {
+    ctx.ensure_not_cancelled("list_indexed_files.start")?;
+    let mut files: BTreeMap<Box<str>, (u64, Option<Box<str>>)> = BTreeMap::new();
+    for collection_name in index_collection_names(&input.collection_name, input.shard_count)? {
+        let exists = deps
+            .vectordb
+            .has_collection(ctx, collection_name.clone())
+            .await?;
+        if exists {
+            count_collection_files(ctx, deps, collection_name, &mut files).await?;
+        }
+    }
+
+    Ok(files
+        .into_iter()
+        .map(
+            |(relative_path, (chunk_count, language))| IndexedFileSummary {
+                relative_path,
+                chunk_count,
+                language,
+            },
+        )
+        .collect())
+}
+
+/// Add one collection's rows to the per-file chunk counts.
+async fn count_collection_files(
+    ctx: &RequestContext,
+    deps: &ListIndexedFilesDeps,
+    collection_name: CollectionName,
+    files: &mut BTreeMap<Box<str>, (u64, Option<Box<str>>)>,
+) -> Result<()> {
+    let mut page_token = None;
+    loop {
+        ctx.ensure_not_cancelled("list_indexed_files.query")?;
+        let (rows, next) = deps
+            .vectordb
+            .query_paged(
+                ctx,
+                collection_name.clone(),
+                Box::from(""),
+                vec![Box::from("relativePath"), Box::from("language")],
+                LIST_PAGE_SIZE,
+                page_token,
+            )
+            .await?;
+        for row in rows {
+            let Some(path) = row.get("relativePath").and_then(Value::as_str) else {
+                continue;
+            };
+            let entry = files.entry(Box::from(path)).or_insert((0, None));
+            entry.0 = entry.0.saturating_add(1);
+            if entry.1.is_none() {
+                entry.1 = row.get("language").and_then(Value::as_str).map(Box::from);
+            }
+        }
+        if next.is_none() {
+            return Ok(());
+        }
+        page_token = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_domain::VectorDbProviderId;
+    use semantic_code_ports::{
+        VectorDbProviderInfo, VectorDbRow, VectorDocumentForInsert, VectorSearchResponse,
+    };
+    use semantic_code_shared::ErrorEnvelope;
+
+    type FileRows = Vec<(&'static str, &'static str)>;
+
+    struct FixedRowsVectorDb {
+        provider: VectorDbProviderInfo,
+        rows: BTreeMap<&'static str, FileRows>,
+    }
+
+    impl FixedRowsVectorDb {
+        fn new(rows: FileRows) -> Result<Self> {
+            Self::with_collections(vec![("code_chunks_ls", rows)])
+        }
+
+        fn with_collections(collections: Vec<(&'static str, FileRows)>) -> Result<Self> {
+            Ok(Self {
+                provider: VectorDbProviderInfo {
+                    id: VectorDbProviderId::parse("local").map_err(ErrorEnvelope::from)?,
+                    name: "fixed-rows".into(),
+                },
+                rows: collections.into_iter().collect(),
+            })
+        }
+    }
+
+    impl VectorDbPort for FixedRowsVectorDb {
+        fn provider(&self) -> &VectorDbProviderInfo {
+            &self.provider
+        }
+
+        fn create_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn create_hybrid_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn drop_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn has_collection(
+            &self,
+            _ctx: &RequestContext,
+            collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<bool>> {
+            let exists = self.rows.contains_key(collection_name.as_str());
+            Box::pin(async move { Ok(exists) })
+        }
+
+        fn collection_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u32>>> {
+            Box::pin(async move { Ok(None) })
+        }
+
+        fn count(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u64>>> {
+            Box::pin(async move { Ok(None) })
+        }
+
+        fn list_collections(
+            &self,
+            _ctx: &RequestContext,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<CollectionName>>> {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn insert(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _documents: Vec<VectorDocumentForInsert>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn insert_hybrid(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _documents: Vec<VectorDocumentForInsert>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn search(
+            &self,
+            _ctx: &RequestContext,
+            _request: semantic_code_ports::VectorSearchRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<VectorSearchResponse>> {
+            Box::pin(async move {
+                Ok(VectorSearchResponse {
+                    results: Vec::new(),
+                    stats: None,
+                })
+            })
+        }
+
+        fn hybrid_search(
+            &self,
+            _ctx: &RequestContext,
+            _request: semantic_code_ports::HybridSearchBatchRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<semantic_code_ports::HybridSearchResult>>>
+        {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn delete(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _ids: Vec<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn query(
+            &self,
+            _ctx: &RequestContext,
+            collection_name: CollectionName,
+            _filter: Box<str>,
+            _output_fields: Vec<Box<str>>,
+            _limit: Option<u32>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<VectorDbRow>>> {
+            let rows = self
+                .rows
+                .get(collection_name.as_str())
+                .into_iter()
+                .flatten()
+                .map(|(path, language)| {
+                    let mut row = VectorDbRow::new();
+                    row.insert("relativePath".into(), Value::String((*path).to_owned()));
+                    row.insert("language".into(), Value::String((*language).to_owned()));
+                    row
+                })
+                .collect();
+            Box::pin(async move { Ok(rows) })
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_files_sorted_with_chunk_counts() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let deps = ListIndexedFilesDeps {
+            vectordb: Arc::new(FixedRowsVectorDb::new(vec![
+                ("src/main.rs", "rust"),
+                ("lib/util.py", "python"),
+                ("src/main.rs", "rust"),
+                ("src/main.rs", "rust"),
+            ])?),
+        };
+        let input = ListIndexedFilesInput {
+            collection_name: CollectionName::parse("code_chunks_ls")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
+        };
+
+        let files = list_indexed_files(&ctx, &deps, input).await?;
+        assert_eq!(
+            files,
+            vec![
+                IndexedFileSummary {
+                    relative_path: "lib/util.py".into(),
+                    chunk_count: 1,
+                    language: Some("python".into()),
+                },
+                IndexedFileSummary {
+                    relative_path: "src/main.rs".into(),
+                    chunk_count: 3,
+                    language: Some("rust".into()),
+                },
+            ]
+        );
+        Ok(())
+    }
+    #[tokio::test]
+    async fn sharded_index_lists_files_across_every_shard() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        // Shard 2 was never created; shards are created on demand.
+        let deps = ListIndexedFilesDeps {
+            vectordb: Arc::new(FixedRowsVectorDb::with_collections(vec![
+                (
+                    "code_chunks_ls_shard_0",
+                    vec![("src/main.rs", "rust"), ("lib/util.py", "python")],
+                ),
+                ("code_chunks_ls_shard_1", vec![("src/main.rs", "rust")]),
+                ("code_chunks_ls", vec![("stale.rs", "rust")]),
+            ])?),
+        };
+        let input = ListIndexedFilesInput {
+            collection_name: CollectionName::parse("code_chunks_ls")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: Some(3),
+        };
+
+        let files = list_indexed_files(&ctx, &deps, input).await?;
+        let counts: Vec<(&str, u64)> = files
+            .iter()
+            .map(|file| (file.relative_path.as_ref(), file.chunk_count))
+            .collect();
+        assert_eq!(counts, vec![("lib/util.py", 1), ("src/main.rs", 2)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,611 @@
+//! Copy an indexed collection from one vector DB backend to another.
+
+use crate::IndexProgress;
+use crate::sharding::index_collection_names;
+use semantic_code_domain::{CollectionName, IndexMode, VectorDocumentMetadata};
+use semantic_code_ports::{VectorDbPort, VectorDbRow, VectorDocumentForInsert};
+use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use serde_json::Value;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+const QUERY_FIELDS: [&str; 4] = ["id", "content", "vector", "metadata"];
+
+/// Input payload for migrating an index between backends.
+#[derive(Clone)]
+pub struct MigrateIndexInput {
+    /// Collection name, shared by source and destination.
+    pub collection_name: CollectionName,
+    /// Shard count the index was built with; each shard collection is copied
+    /// to a destination collection of the same name.
+    pub shard_count: Option<u32>,
+    /// Index mode used when creating the destination collection and inserting.
+    pub index_mode: IndexMode,
+    /// Maximum documents per destination insert call.
+    pub batch_size: NonZeroUsize,
+    /// Optional progress callback, invoked after each inserted batch.
+    pub on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
+}
+
+/// Dependencies required by migrate-index.
+#[derive(Clone)]
+pub struct MigrateIndexDeps {
+    /// Backend the documents are read from.
+    pub source: Arc<dyn VectorDbPort>,
+    /// Backend the documents are written to.
+    pub destination: Arc<dyn VectorDbPort>,
+}
+
+/// Result of a completed migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrateIndexOutput {
+    /// Migrated collection name.
+    pub collection_name: CollectionName,
+    /// Vector dimension shared by source and destination.
+    pub dimension: u32,
+    /// Number of documents copied.
+    pub migrated_documents: u64,
+    /// Whether a destination collection was created by this run.
+    pub created_collection: bool,
+}
+
+/// Copy every document of a collection into another backend, preserving ids
+/// and metadata.
+///
+/// Reads the source with a full `query` scan, so it needs a provider that
+/// returns `vector` and `metadata` output fields. The destination dimension is
+/// checked before any write; a missing destination collection is created with
+/// the source dimension. A sharded index copies every shard that exists.
+#[tracing::instrument(
+    name = "app.migrate_index",
+    skip_all,
+    fields(
+        collection = %input.collection_name.as_str(),
+        source = %deps.source.provider().id.as_str(),
+        destination = %deps.destination.provider().id.as_str(),
+    )
+)]
+pub async fn migrate_index(
+    ctx: &RequestContext,
+    deps: &MigrateIndexDeps,
+    input: MigrateIndexInput,
+) -> Result<MigrateIndexOutput> {
+    ctx.ensure_not_cancelled("migrate_index.start")?;
+    ctx.ensure_writable("migrate_index")?;
+    let collection_name = input.collection_name.clone();
+    let mut sources = Vec::new();
+    for shard_collection in index_collection_names(&collection_name, input.shard_count)? {
+        if deps
+            .source
+            .has_collection(ctx, shard_collection.clone())
+            .await?
+        {
+            sources.push(shard_collection);
+        }
+    }
+    if sources.is_empty() {
+        return Err(
+            ErrorEnvelope::expected(ErrorCode::not_found(), "source collection not found")
+                .with_metadata("collection", collection_name.as_str().to_owned()),
+        );
+    }
+
+    let mut shards = Vec::with_capacity(sources.len());
+    let mut dimension = None;
+    let mut created_collection = false;
+    for shard_collection in sources {
+        let (shard_dimension, documents) = read_source(ctx, deps, &shard_collection).await?;
+        created_collection |= prepare_destination(
+            ctx,
+            deps,
+            &shard_collection,
+            input.index_mode,
+            shard_dimension,
+        )
+        .await?;
+        dimension.get_or_insert(shard_dimension);
+        shards.push((shard_collection, documents));
+    }
+    let dimension = dimension.unwrap_or_default();
+
+    let total = shards
+        .iter()
+        .map(|(_, documents)| u64::try_from(documents.len()).unwrap_or(u64::MAX))
+        .fold(0_u64, u64::saturating_add);
+    let mut migrated_documents = 0_u64;
+    for (shard_collection, documents) in shards {
+        let mut documents = documents.into_iter().peekable();
+        while documents.peek().is_some() {
+            ctx.ensure_not_cancelled("migrate_index.insert")?;
+            let batch: Vec<_> = documents.by_ref().take(input.batch_size.get()).collect();
+            let batch_len = u64::try_from(batch.len()).unwrap_or(u64::MAX);
+            match input.index_mode {
+                IndexMode::Hybrid => {
+                    deps.destination
+                        .insert_hybrid(ctx, shard_collection.clone(), batch)
+                        .await?;
+                },
+                IndexMode::Dense => {
+                    deps.destination
+                        .insert(ctx, shard_collection.clone(), batch)
+                        .await?;
+                },
+            }
+            migrated_documents = migrated_documents.saturating_add(batch_len);
+            if let Some(on_progress) = input.on_progress.as_ref() {
+                on_progress(IndexProgress {
+                    phase: "Migrating documents".into(),
+                    current: migrated_documents,
+                    total,
+                    percentage: percentage(migrated_documents, total),
+                });
+            }
+        }
+    }
+
+    Ok(MigrateIndexOutput {
+        collection_name,
+        dimension,
+        migrated_documents,
+        created_collection,
+    })
+}
+
+/// Read one source collection's dimension and documents.
+async fn read_source(
+    ctx: &RequestContext,
+    deps: &MigrateIndexDeps,
+    collection_name: &CollectionName,
+) -> Result<(u32, Vec<VectorDocumentForInsert>)> {
+    let Some(dimension) = deps
+        .source
+        .collection_dimension(ctx, collection_name.clone())
+        .await?
+    else {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::new("migrate", "source_dimension_unknown"),
+            "source backend does not report the collection dimension",
+        ));
+    };
+
+    ctx.ensure_not_cancelled("migrate_index.query")?;
+    let rows = deps
+        .source
+        .query(
+            ctx,
+            collection_name.clone(),
+            Box::from(""),
+            QUERY_FIELDS.into_iter().map(Box::from).collect(),
+            None,
+        )
+        .await?;
+    let documents = rows
+        .into_iter()
+        .map(|row| document_from_row(row, dimension))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((dimension, documents))
+}
+
+/// Ensure the destination collection exists with the source dimension.
+///
+/// Returns whether the collection was created.
+async fn prepare_destination(
+    ctx: &RequestContext,
+    deps: &MigrateIndexDeps,
+    collection_name: &CollectionName,
+    index_mode: IndexMode,
+    dimension: u32,
+) -> Result<bool> {
+    let exists = deps
+        .destination
+        .has_collection(ctx, collection_name.clone())
+        .await?;
+    if exists {
+        let destination_dimension = deps
+            .destination
+            .collection_dimension(ctx, collection_name.clone())
+            .await?;
+        if let Some(actual) = destination_dimension.filter(|actual| *actual != dimension) {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::new("migrate", "dimension_mismatch"),
+                format!("destination collection has dimension {actual}, source has {dimension}"),
+            )
+            .with_metadata("collection", collection_name.as_str().to_owned()));
+        }
+        return Ok(false);
+    }
+
+    let description = Some(
+        format!(
+            "semantic-code {} index migrated from {}",
+            index_mode.as_str(),
+            deps.source.provider().id.as_str()
+        )
+        .into_boxed_str(),
+    );
+    match index_mode {
+        IndexMode::Hybrid => {
+            deps.destination
+                .create_hybrid_collection(ctx, collection_name.clone(), dimension, description)
+                .await?;
+        },
+        IndexMode::Dense => {
+            deps.destination
+                .create_collection(ctx, collection_name.clone(), dimension, description)
+                .await?;
+        },
+    }
+    Ok(true)
+}
+
+fn document_from_row(mut row: VectorDbRow, dimension: u32) -> Result<VectorDocumentForInsert> {
+    let Some(Value::String(id)) = row.remove("id") else {
+        return Err(invalid_row("row is missing a string `id`", None));
+    };
+    let Some(Value::String(content)) = row.remove("content") else {
+        return Err(invalid_row("row is missing `content`", Some(&id)));
+    };
+    let vector = match row.remove("vector") {
+        Some(Value::Array(values)) => values.iter().map(json_f32).collect::<Option<Vec<f32>>>(),
+        _ => None,
+    };
+    let Some(vector) = vector else {
+        return Err(invalid_row(
+            "row is missing a numeric `vector`; the source backend cannot export vectors",
+            Some(&id),
+        ));
+    };
+    if u32::try_from(vector.len()).ok() != Some(dimension) {
+        return Err(invalid_row(
+            "row vector length differs from the collection dimension",
+            Some(&id),
+        ));
+    }
+    let Some(metadata) = take_row_metadata(&mut row) else {
+        return Err(invalid_row(
+            "row has missing or malformed `metadata`",
+            Some(&id),
+        ));
+    };
+
+    Ok(VectorDocumentForInsert {
+        id: id.into_boxed_str(),
+        vector: Arc::from(vector),
+        content: content.into_boxed_str(),
+        metadata,
+    })
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "vector components were f32 before JSON widened them to f64"
+)]
+pub fn json_f32(value: &Value) -> Option<f32> {
+    value.as_f64().map(|value| value as f32)
+}
+
+/// Remove and parse the `metadata` field of a query row.
+///
+/// Milvus stores metadata as a JSON string column; the local backend returns an object.
+pub fn take_row_metadata(row: &mut VectorDbRow) -> Option<VectorDocumentMetadata> {
+    match row.remove("metadata") {
+        Some(Value::String(raw)) => serde_json::from_str::<VectorDocumentMetadata>(&raw).ok(),
+        Some(value @ Value::Object(_)) => {
+            serde_json::from_value::<VectorDocumentMetadata>(value).ok()
+        },
+        _ => None,
+    }
+}
+
+fn invalid_row(message: &str, id: Option<&str>) -> ErrorEnvelope {
+    let error = ErrorEnvelope::unexpected(
+        ErrorCode::new("migrate", "invalid_source_row"),
+        message,
+        ErrorClass::NonRetriable,
+    );
+    match id {
+        Some(id) => error.with_metadata("id", id.to_owned()),
+        None => error,
+    }
+}
+
+fn percentage(current: u64, total: u64) -> u8 {
+    if total == 0 {
+        return 100;
+    }
+    u8::try_from(current.saturating_mul(100) / total).unwrap_or(100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_adapters::LocalVectorDbBuilder;
+    use semantic_code_config::{SnapshotStorageMode, VectorSnapshotFormat};
+    use semantic_code_domain::{LineSpan, VectorDbProviderId};
+    use semantic_code_ports::{
+        BoxFuture, HybridSearchBatchRequest, HybridSearchResult, VectorDbProviderInfo,
+        VectorSearchRequest, VectorSearchResponse,
+    };
+    use semantic_code_shared::CancellationToken;
+    use semantic_code_vector::HnswKernel;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    struct SpyVectorDb {
+        provider: VectorDbProviderInfo,
+        dimension: Mutex<Option<u32>>,
+        inserted: Mutex<Vec<VectorDocumentForInsert>>,
+    }
+
+    impl SpyVectorDb {
+        fn new(dimension: Option<u32>) -> Result<Self> {
+            Ok(Self {
+                provider: VectorDbProviderInfo {
+                    id: VectorDbProviderId::parse("milvus_grpc").map_err(ErrorEnvelope::from)?,
+                    name: "spy".into(),
+                },
+                dimension: Mutex::new(dimension),
+                inserted: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn record_create(&self, dimension: u32) -> BoxFuture<'_, Result<()>> {
+            *self.dimension.lock().expect("dimension lock") = Some(dimension);
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn record_insert(
+            &self,
+            documents: Vec<VectorDocumentForInsert>,
+        ) -> BoxFuture<'_, Result<()>> {
+            self.inserted
+                .lock()
+                .expect("inserted lock")
+                .extend(documents);
+            Box::pin(async move { Ok(()) })
+        }
+    }
+
+    impl VectorDbPort for SpyVectorDb {
+        fn provider(&self) -> &VectorDbProviderInfo {
+            &self.provider
+        }
+
+        fn create_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> BoxFuture<'_, Result<()>> {
+            self.record_create(dimension)
+        }
+
+        fn create_hybrid_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> BoxFuture<'_, Result<()>> {
+            self.record_create(dimension)
+        }
+
+        fn drop_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn has_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> BoxFuture<'_, Result<bool>> {
+            let exists = self.dimension.lock().expect("dimension lock").is_some();
+            Box::pin(async move { Ok(exists) })
+        }
+
+        fn collection_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> BoxFuture<'_, Result<Option<u32>>> {
+            let dimension = *self.dimension.lock().expect("dimension lock");
+            Box::pin(async move { Ok(dimension) })
+        }
+
+        fn list_collections(
+            &self,
+            _ctx: &RequestContext,
+        ) -> BoxFuture<'_, Result<Vec<CollectionName>>> {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn insert(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            documents: Vec<VectorDocumentForInsert>,
+        ) -> BoxFuture<'_, Result<()>> {
+            self.record_insert(documents)
+        }
+
+        fn insert_hybrid(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            documents: Vec<VectorDocumentForInsert>,
+        ) -> BoxFuture<'_, Result<()>> {
+            self.record_insert(documents)
+        }
+
+        fn search(
+            &self,
+            _ctx: &RequestContext,
+            _request: VectorSearchRequest,
+        ) -> BoxFuture<'_, Result<VectorSearchResponse>> {
+            Box::pin(async move {
+                Ok(VectorSearchResponse {
+                    results: Vec::new(),
+                    stats: None,
+                })
+            })
+        }
+
+        fn hybrid_search(
+            &self,
+            _ctx: &RequestContext,
+            _request: HybridSearchBatchRequest,
+        ) -> BoxFuture<'_, Result<Vec<HybridSearchResult>>> {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn delete(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _ids: Vec<Box<str>>,
+        ) -> BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn query(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _filter: Box<str>,
+            _output_fields: Vec<Box<str>>,
+            _limit: Option<u32>,
+        ) -> BoxFuture<'_, Result<Vec<VectorDbRow>>> {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+    }
+
+    async fn local_source(
+        ctx: &RequestContext,
+        collection_name: &CollectionName,
+    ) -> Result<(Arc<dyn VectorDbPort>, Vec<VectorDocumentForInsert>)> {
+        let root = std::env::temp_dir().join(format!(
+            "sca-migrate-{}-{}",
+            std::process::id(),
+            collection_name.as_str()
+        ));
+        let db = LocalVectorDbBuilder::new(
+            root.clone(),
+            Arc::new(HnswKernel::with_ef_search(32)),
+            CancellationToken::new(),
+        )
+        .storage_mode(SnapshotStorageMode::Custom(root))
+        .snapshot_format(VectorSnapshotFormat::V1)
+        .build()?;
+        db.create_collection(ctx, collection_name.clone(), 3, None)
+            .await?;
+        let documents = ["src/a.rs", "src/b.rs", "src/c.rs"]
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                let seed = f32::from(u8::try_from(index).unwrap_or(0));
+                Ok(VectorDocumentForInsert {
+                    id: format!("chunk-{index}").into_boxed_str(),
+                    vector: Arc::from(vec![1.0, seed, 0.5]),
+                    content: format!("fn f{index}() {{}}").into_boxed_str(),
+                    metadata: VectorDocumentMetadata {
+                        relative_path: (*path).into(),
+                        language: None,
+                        file_extension: Some("rs".into()),
+                        span: LineSpan::new(1, 2).map_err(ErrorEnvelope::from)?,
+                        fragment_start_byte: None,
+                        fragment_end_byte: None,
+                        node_kind: None,
+                        extra: BTreeMap::new(),
+                        mtime_ms: None,
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        db.insert(ctx, collection_name.clone(), documents.clone())
+            .await?;
+        Ok((Arc::new(db), documents))
+    }
+
+    #[tokio::test]
+    async fn local_index_migrates_into_spy_with_ids_and_metadata() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_migrate").map_err(ErrorEnvelope::from)?;
+        let (source, mut expected) = local_source(&ctx, &collection_name).await?;
+        let destination = Arc::new(SpyVectorDb::new(None)?);
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_sink = Arc::clone(&progress);
+
+        let output = migrate_index(
+            &ctx,
+            &MigrateIndexDeps {
+                source,
+                destination: destination.clone(),
+            },
+            MigrateIndexInput {
+                collection_name: collection_name.clone(),
+                shard_count: None,
+                index_mode: IndexMode::Dense,
+                batch_size: NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
+                on_progress: Some(Arc::new(move |event: IndexProgress| {
+                    progress_sink
+                        .lock()
+                        .expect("progress lock")
+                        .push(event.current);
+                })),
+            },
+        )
+        .await?;
+
+        assert_eq!(output.migrated_documents, 3);
+        assert_eq!(output.dimension, 3);
+        assert!(output.created_collection);
+        assert_eq!(*progress.lock().expect("progress lock"), vec![2, 3]);
+        let mut inserted = destination.inserted.lock().expect("inserted lock").clone();
+        inserted.sort_by(|left, right| left.id.cmp(&right.id));
+        expected.sort_by(|left, right| left.id.cmp(&right.id));
+        assert_eq!(inserted, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dimension_mismatch_aborts_before_writing() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_migrate_dim").map_err(ErrorEnvelope::from)?;
+        let (source, _) = local_source(&ctx, &collection_name).await?;
+        let destination = Arc::new(SpyVectorDb::new(Some(8))?);
+
+        let error = migrate_index(
+            &ctx,
+            &MigrateIndexDeps {
+                source,
+                destination: destination.clone(),
+            },
+            MigrateIndexInput {
+                collection_name,
+                shard_count: None,
+                index_mode: IndexMode::Dense,
+                batch_size: NonZeroUsize::MIN,
+                on_progress: None,
+            },
+        )
+        .await
+        .expect_err("dimension mismatch should fail");
+
+        assert_eq!(error.code, ErrorCode::new("migrate", "dimension_mismatch"));
+        assert!(
+            destination
+                .inserted
+                .lock()
+                .expect("inserted lock")
+                .is_empty()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Remove indexed chunks whose source files no longer exist.
+
+use crate::index_codebase::{FileScan, list_indexable_files};
+use crate::sharding::index_collection_names;
+use semantic_code_domain::{CollectionName, PathNormalization};
+use semantic_code_ports::{FileSystemPort, IgnorePort, LoggerPort, PathPolicyPort, VectorDbPort};
+use semantic_code_shared::{RequestContext, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Input payload for pruning an index.
+#[derive(Debug, Clone)]
+pub struct PruneIndexInput {
+    /// Codebase root directory (absolute path).
+    pub codebase_root: PathBuf,
+    /// Target collection name.
+    pub collection_name: CollectionName,
+    /// Shard count the index was built with; every shard collection is pruned.
+    pub shard_count: Option<u32>,
+    /// Allowed file extensions (normalized, optional).
+    pub supported_extensions: Option<Vec<Box<str>>>,
+    /// Ignore patterns (normalized, optional).
+    pub ignore_patterns: Option<Vec<Box<str>>>,
+    /// Highest-precedence ignore patterns (e.g. from the command line).
+    pub additional_ignore_patterns: Vec<Box<str>>,
+    /// Canonicalization applied to relative paths at index time.
+    pub path_normalization: PathNormalization,
+}
+
+/// Dependencies required by prune-index.
+#[derive(Clone)]
+pub struct PruneIndexDeps {
+    /// Vector DB adapter.
+    pub vectordb: Arc<dyn VectorDbPort>,
+    /// Filesystem adapter.
+    pub filesystem: Arc<dyn FileSystemPort>,
+    /// Path policy adapter.
+    pub path_policy: Arc<dyn PathPolicyPort>,
+    /// Ignore matcher adapter.
+    pub ignore: Arc<dyn IgnorePort>,
+    /// Optional logger.
+    pub logger: Option<Arc<dyn LoggerPort>>,
+}
+
+/// Counts reported by prune-index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneIndexOutput {
+    /// Files found on disk that `index` would pick up.
+    pub scanned_files: usize,
+    /// Distinct `relativePath`s present in the index before pruning.
+    pub indexed_files: usize,
+    /// Relative paths whose chunks were removed, sorted.
+    pub pruned_files: Vec<Box<str>>,
+    /// Number of chunks removed.
+    pub removed_chunks: u64,
+}
+
+/// Delete chunks for indexed files that no longer exist on disk.
+///
+/// The live file set is discovered with the same extension and ignore rules as
+/// `index`, so files that became ignored are pruned too. Indexed paths are read
+/// with a full `query` scan of every shard; missing collections prune nothing.
+#[tracing::instrument(
+    name = "app.prune_index",
+    skip_all,
+    fields(collection = %input.collection_name.as_str())
+)]
+pub async fn prune_index(
+    ctx: &RequestContext,
+    deps: &PruneIndexDeps,
+    input: PruneIndexInput,
+) -> Result<PruneIndexOutput> {
+    ctx.ensure_not_cancelled("prune_index.start")?;
+    ctx.ensure_writable("prune_index")?;
+    let mut collections = Vec::new();
+    for collection_name in index_collection_names(&input.collection_name, input.shard_count)? {
+        if deps
+            .vectordb
+            .has_collection(ctx, collection_name.clone())
+            .await?
+        {
+            collections.push(collection_name);
+        }
+    }
+    if collections.is_empty() {
+        return Ok(PruneIndexOutput {
+            scanned_files: 0,
+            indexed_files: 0,
+            pruned_files: Vec::new(),
+            removed_chunks: 0,
+        });
+    }
+
+    let scan = FileScan {
+        filesystem: &deps.filesystem,
+        path_policy: &deps.path_policy,
+        ignore: &deps.ignore,
+        logger: deps.logger.as_ref(),
+        codebase_root: &input.codebase_root,
+        supported_extensions: input.supported_extensions.as_ref(),
+        ignore_patterns: input.ignore_patterns.as_ref(),
+        additional_ignore_patterns: &input.additional_ignore_patterns,
+        file_list: None,
+        max_files: None,
+        path_normalization: input.path_normalization,
+        // Prune only needs to know which files still exist.
+        skip_binary: false,
+    };
+    let files = list_indexable_files(ctx, &scan).await?;
+    let live: HashSet<Box<str>> = files
+        .iter()
+        .map(|path| input.path_normalization.apply(path))
+        .collect();
+
+    let mut ids_by_path: BTreeMap<Box<str>, Vec<(usize, Box<str>)>> = BTreeMap::new();
+    for (slot, collection_name) in collections.iter().enumerate() {
+        collect_indexed_ids(ctx, deps, collection_name, slot, &mut ids_by_path).await?;
+    }
+
+    let indexed_files = ids_by_path.len();
+    let mut pruned_files = Vec::new();
+    let mut removed_chunks = 0_u64;
+    for (path, ids) in ids_by_path {
+        if live.contains(&path) || ids.is_empty() {
+            continue;
+        }
+        let count = u64::try_from(ids.len()).unwrap_or(u64::MAX);
+        for (slot, collection_name) in collections.iter().enumerate() {
+            let shard_ids: Vec<Box<str>> = ids
+                .iter()
+                .filter(|(id_slot, _)| *id_slot == slot)
+                .map(|(_, id)| id.clone())
+                .collect();
+            if shard_ids.is_empty() {
+                continue;
+            }
+            ctx.ensure_not_cancelled("prune_index.delete")?;
+            deps.vectordb
+                .delete(ctx, collection_name.clone(), shard_ids)
+                .await?;
+        }
+        removed_chunks = removed_chunks.saturating_add(count);
+        pruned_files.push(path);
+    }
+
+    if let Some(logger) = deps.logger.as_ref() {
+        let mut fields = BTreeMap::new();
+        fields.insert("scannedFiles".into(), Value::from(files.len()));
+        fields.insert("indexedFiles".into(), Value::from(indexed_files));
+        fields.insert("prunedFiles".into(), Value::from(pruned_files.len()));
+        fields.insert("removedChunks".into(), Value::from(removed_chunks));
+        logger.info(
+            "backend.pruneIndex.completed",
+            "Prune index completed",
+            Some(fields),
+        );
+    }
+
+    Ok(PruneIndexOutput {
+        scanned_files: files.len(),
+        indexed_files,
+        pruned_files,
+        removed_chunks,
+    })
+}
+
+/// Record the chunk ids of one collection by `relativePath`, tagged with the
+/// collection's `slot` so deletes go back to the shard that holds them.
+async fn collect_indexed_ids(
+    ctx: &RequestContext,
+    deps: &PruneIndexDeps,
+    collection_name: &CollectionName,
+    slot: usize,
+    ids_by_path: &mut BTreeMap<Box<str>, Vec<(usize, Box<str>)>>,
+) -> Result<()> {
+    ctx.ensure_not_cancelled("prune_index.query")?;
+    let rows = deps
+        .vectordb
+        .query(
+            ctx,
+            collection_name.clone(),
+            Box::from(""),
+            vec![Box::from("id"), Box::from("relativePath")],
+            None,
+        )
+        .await?;
+
+    for row in rows {
+        let Some(path) = row.get("relativePath").and_then(Value::as_str) else {
+            continue;
+        };
+        let ids = ids_by_path.entry(Box::from(path)).or_default();
+        if let Some(id) = row.get("id").and_then(Value::as_str)
+            && !id.trim().is_empty()
+        {
+            ids.push((slot, Box::from(id)));
+        }
+    }
+    Ok(())
+}
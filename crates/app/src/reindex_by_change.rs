@@ -1,10 +1,13 @@
 //! Reindex changed files by diffing snapshots.
 
 use crate::index_codebase::{
-    IndexCodebaseInput, IndexProgress, delete_modified_files, delete_removed_files, detect_changes,
-    emit_progress, index_codebase, total_changes,
+    CollectionPolicy, IndexCodebaseInput, IndexCodebaseOutput, IndexProgress, IndexStageStats,
+    LanguageEmbeddingRoutes, delete_modified_file, delete_modified_files, delete_removed_files,
+    detect_changes, emit_progress, index_codebase, retain_existing_files, total_changes,
+};
+use semantic_code_domain::{
+    CollectionName, IndexMode, OnDuplicateId, OversizeChunkPolicy, PathNormalization,
 };
-use semantic_code_domain::{CollectionName, IndexMode};
 use semantic_code_ports::{
     EmbeddingPort, FileChangeSet, FileSyncPort, FileSystemPort, IgnorePort, LoggerPort,
     PathPolicyPort, SplitterPort, TelemetryPort, VectorDbPort,
@@ -31,6 +34,8 @@ pub struct ReindexByChangeInput {
     pub supported_extensions: Option<Vec<Box<str>>>,
     /// Ignore patterns (normalized, optional).
     pub ignore_patterns: Option<Vec<Box<str>>>,
+    /// Highest-precedence ignore patterns (e.g. from the command line).
+    pub additional_ignore_patterns: Vec<Box<str>>,
     /// Embedding batch size (chunks per batch).
     pub embedding_batch_size: NonZeroUsize,
     /// Maximum number of chunks to index.
@@ -39,6 +44,24 @@ pub struct ReindexByChangeInput {
     pub max_files: Option<NonZeroUsize>,
     /// Skip files larger than this size.
     pub max_file_size_bytes: Option<u64>,
+    /// Per-extension size limits (keys without a leading dot, e.g. `min.js`).
+    pub max_file_size_bytes_by_extension: BTreeMap<Box<str>, u64>,
+    /// Canonicalization applied to relative paths for chunk ids and metadata.
+    pub path_normalization: PathNormalization,
+    /// Handling of chunks longer than the embedding provider's input limit.
+    pub oversize_chunk_policy: OversizeChunkPolicy,
+    /// Skip files whose leading bytes look binary.
+    pub skip_binary: bool,
+    /// Instruction prefix prepended to chunk content before embedding.
+    pub document_template: Option<Box<str>>,
+    /// Custom key/value fields attached to every reindexed document's metadata.
+    pub extra_metadata: BTreeMap<Box<str>, Box<str>>,
+    /// Record file modification times in chunk metadata (for recency boosting).
+    pub record_mtime: bool,
+    /// Minimum chunk length (characters, after trimming) kept for embedding.
+    pub min_chunk_chars: u32,
+    /// Runtime cap on chunk content length (bytes); `None` keeps the hard limit.
+    pub max_chunk_chars: Option<usize>,
     /// Maximum buffered chunks (best-effort).
     pub max_buffered_chunks: Option<NonZeroUsize>,
     /// Maximum buffered embeddings (best-effort).
@@ -49,12 +72,28 @@ pub struct ReindexByChangeInput {
     pub max_in_flight_embedding_batches: Option<NonZeroUsize>,
     /// Max in-flight insert batches (default 1).
     pub max_in_flight_inserts: Option<NonZeroUsize>,
+    /// Shard count the index was built with; `None` for an unsharded index.
+    ///
+    /// Deletes and inserts then target the `<collection>_shard_<n>` collections.
+    pub shard_count: Option<u32>,
+    /// Embedding failure thresholds; see
+    /// [`IndexCodebaseInput::max_consecutive_failures`].
+    pub max_consecutive_failures: Option<NonZeroUsize>,
+    /// See [`IndexCodebaseInput::max_total_failures`].
+    pub max_total_failures: Option<NonZeroUsize>,
+    /// Process changed files one at a time: delete a file's old chunks, then
+    /// insert its new ones before moving on.
+    ///
+    /// Keeps the index from missing every changed file at once, at the cost
+    /// of one index run per file. When off, all deletes run before a single
+    /// index run over the changed files.
+    pub atomic_per_file: bool,
     /// Optional progress callback.
     pub on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
 }
 
 /// Output returned by reindex-by-change.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReindexByChangeOutput {
     /// Added files count.
     pub added: usize,
@@ -62,6 +101,16 @@ pub struct ReindexByChangeOutput {
     pub removed: usize,
     /// Modified files count.
     pub modified: usize,
+    /// Stage stats of the run that re-indexed changed files.
+    ///
+    /// Summed over the per-file runs when `atomic_per_file` is set. `None`
+    /// when no added or modified file needed indexing.
+    pub stage_stats: Option<IndexStageStats>,
+    /// Files left partly indexed by tolerated embedding failures (sorted).
+    ///
+    /// They are invalidated in the sync snapshot, so the next reindex picks
+    /// them up again.
+    pub failed_files: Vec<Box<str>>,
 }
 
 /// Dependencies required by reindex-by-change.
@@ -85,6 +134,8 @@ pub struct ReindexByChangeDeps {
     pub logger: Option<Arc<dyn LoggerPort>>,
     /// Optional telemetry sink.
     pub telemetry: Option<Arc<dyn TelemetryPort>>,
+    /// Per-language embedding providers; unrouted chunks use `embedding`.
+    pub embedding_routes: LanguageEmbeddingRoutes,
 }
 
 /// Reindex files based on snapshot changes.
@@ -246,6 +297,22 @@ struct ReindexRemoved<'a> {
 }
 
 impl<'a> ReindexRemoved<'a> {
+    async fn reindex_per_file(mut self) -> Result<ReindexCompleted> {
+        let indexed = reindex_files_one_by_one(
+            self.ctx,
+            self.deps,
+            self.input,
+            &self.changes,
+            self.total,
+            &mut self.processed,
+        )
+        .await?;
+        Ok(ReindexCompleted {
+            changes: self.changes,
+            indexed,
+        })
+    }
+
     async fn delete_modified(mut self) -> Result<ReindexModified<'a>> {
         delete_modified_files(
             self.ctx,
@@ -274,23 +341,67 @@ struct ReindexModified<'a> {
 
 impl ReindexModified<'_> {
     async fn reindex_changed(self) -> Result<ReindexCompleted> {
-        reindex_changed_files(self.ctx, self.deps, self.input, &self.changes).await?;
+        let indexed = reindex_changed_files(self.ctx, self.deps, self.input, &self.changes).await?;
         Ok(ReindexCompleted {
             changes: self.changes,
+            indexed,
         })
     }
 }
 
+/// Outcome of the index runs over added and modified files.
+#[derive(Default)]
+struct ReindexedFiles {
+    stage_stats: Option<IndexStageStats>,
+    failed_files: Vec<Box<str>>,
+}
+
+impl ReindexedFiles {
+    fn absorb(&mut self, output: IndexCodebaseOutput) {
+        match self.stage_stats.as_mut() {
+            Some(stats) => stats.absorb(&output.stage_stats),
+            None => self.stage_stats = Some(output.stage_stats),
+        }
+        self.failed_files.extend(output.failed_files);
+    }
+}
+
 struct ReindexCompleted {
     changes: FileChangeSet,
+    indexed: ReindexedFiles,
 }
 
 impl ReindexCompleted {
-    const fn output(&self) -> ReindexByChangeOutput {
+    /// Invalidate partly indexed files in the sync snapshot so the next
+    /// reindex retries them.
+    async fn invalidate_failed_files(
+        &self,
+        ctx: &RequestContext,
+        deps: &ReindexByChangeDeps,
+    ) -> Result<()> {
+        let failed_files = &self.indexed.failed_files;
+        if failed_files.is_empty() {
+            return Ok(());
+        }
+        if let Some(logger) = deps.logger.as_ref() {
+            logger.warn(
+                "backend.reindex.partial",
+                "Some files lost chunks to embedding failures; they will be retried",
+                Some(log_fields_partial(failed_files.len())),
+            );
+        }
+        deps.file_sync
+            .invalidate_files(ctx, failed_files.clone())
+            .await
+    }
+
+    fn output(self) -> ReindexByChangeOutput {
         ReindexByChangeOutput {
             added: self.changes.added.len(),
             removed: self.changes.removed.len(),
             modified: self.changes.modified.len(),
+            stage_stats: self.indexed.stage_stats,
+            failed_files: self.indexed.failed_files,
         }
     }
 }
@@ -310,6 +421,7 @@ async fn run_reindex(
     started_at: Instant,
 ) -> Result<ReindexByChangeOutput> {
     ctx.ensure_not_cancelled("reindex_by_change.start")?;
+    ctx.ensure_writable("reindex_by_change")?;
 
     emit_progress(
         input.on_progress.as_ref(),
@@ -346,13 +458,19 @@ async fn run_reindex(
             added: 0,
             removed: 0,
             modified: 0,
+            stage_stats: None,
+            failed_files: Vec::new(),
         });
     }
 
     let total = detected.total;
     let removed = detected.delete_removed().await?;
-    let modified = removed.delete_modified().await?;
-    let completed = modified.reindex_changed().await?;
+    let completed = if input.atomic_per_file {
+        removed.reindex_per_file().await?
+    } else {
+        removed.delete_modified().await?.reindex_changed().await?
+    };
+    completed.invalidate_failed_files(ctx, deps).await?;
     let changes = &completed.changes;
     tracing::debug!(
         added = changes.added.len(),
@@ -410,11 +528,13 @@ async fn reindex_changed_files(
     deps: &ReindexByChangeDeps,
     input: &ReindexByChangeInput,
     changes: &FileChangeSet,
-) -> Result<()> {
+) -> Result<ReindexedFiles> {
     let files_to_index = files_to_index(&changes.added, &changes.modified);
+    let files_to_index = retain_existing_files(ctx, deps, input, files_to_index).await?;
+    let mut indexed = ReindexedFiles::default();
     if files_to_index.is_empty() {
         tracing::debug!("no changed files require reindex");
-        return Ok(());
+        return Ok(indexed);
     }
     tracing::debug!(
         file_count = files_to_index.len(),
@@ -426,27 +546,101 @@ async fn reindex_changed_files(
         telemetry.start_timer("backend.reindex.indexChangedFiles", Some(&file_count_tags))
     });
 
-    let index_input = IndexCodebaseInput {
+    let output = index_codebase(ctx, &index_deps(deps), index_input(input, files_to_index)).await?;
+    if let Some(timer) = index_timer.as_ref() {
+        timer.stop();
+    }
+
+    indexed.absorb(output);
+    Ok(indexed)
+}
+
+/// Delete and re-insert each changed file in turn (`atomic_per_file`).
+///
+/// Added and modified files are visited in path order; a modified file's old
+/// chunks are deleted right before its new chunks are indexed.
+#[tracing::instrument(
+    name = "app.reindex_by_change.reindex_files_one_by_one",
+    skip_all,
+    fields(
+        collection = %input.collection_name.as_str(),
+        added = changes.added.len(),
+        modified = changes.modified.len(),
+    )
+)]
+async fn reindex_files_one_by_one(
+    ctx: &RequestContext,
+    deps: &ReindexByChangeDeps,
+    input: &ReindexByChangeInput,
+    changes: &FileChangeSet,
+    total: usize,
+    processed: &mut usize,
+) -> Result<ReindexedFiles> {
+    let index_deps = index_deps(deps);
+    let mut indexed = ReindexedFiles::default();
+    for relative_path in files_to_index(&changes.added, &changes.modified) {
+        ctx.ensure_not_cancelled("reindex_by_change.per_file_loop")?;
+        if changes.modified.contains(&relative_path) {
+            delete_modified_file(ctx, deps, input, &relative_path).await?;
+        }
+        let existing = retain_existing_files(ctx, deps, input, vec![relative_path.clone()]).await?;
+        if !existing.is_empty() {
+            let output = index_codebase(ctx, &index_deps, index_input(input, existing)).await?;
+            indexed.absorb(output);
+        }
+        *processed += 1;
+        emit_progress(
+            input.on_progress.as_ref(),
+            &format!("Reindexed {relative_path}"),
+            *processed as u64,
+            total as u64,
+            None,
+        );
+    }
+    Ok(indexed)
+}
+
+fn index_input(input: &ReindexByChangeInput, files: Vec<Box<str>>) -> IndexCodebaseInput {
+    IndexCodebaseInput {
         codebase_root: input.codebase_root.clone(),
         collection_name: input.collection_name.clone(),
         index_mode: input.index_mode,
         supported_extensions: input.supported_extensions.clone(),
         ignore_patterns: input.ignore_patterns.clone(),
-        file_list: Some(files_to_index),
+        additional_ignore_patterns: input.additional_ignore_patterns.clone(),
+        file_list: Some(files),
         force_reindex: false,
+        collection_description: None,
+        collection_policy: CollectionPolicy::default(),
         on_progress: None,
         embedding_batch_size: input.embedding_batch_size,
         chunk_limit: input.chunk_limit,
         max_files: input.max_files,
         max_file_size_bytes: input.max_file_size_bytes,
+        max_file_size_bytes_by_extension: input.max_file_size_bytes_by_extension.clone(),
+        path_normalization: input.path_normalization,
+        oversize_chunk_policy: input.oversize_chunk_policy,
+        skip_binary: input.skip_binary,
+        document_template: input.document_template.clone(),
+        extra_metadata: input.extra_metadata.clone(),
+        record_mtime: input.record_mtime,
+        min_chunk_chars: input.min_chunk_chars,
+        max_chunk_chars: input.max_chunk_chars,
         max_buffered_chunks: input.max_buffered_chunks,
         max_buffered_embeddings: input.max_buffered_embeddings,
         max_in_flight_files: input.max_in_flight_files,
         max_in_flight_embedding_batches: input.max_in_flight_embedding_batches,
         max_in_flight_inserts: input.max_in_flight_inserts,
-    };
+        shard_count: input.shard_count,
+        max_consecutive_failures: input.max_consecutive_failures,
+        max_total_failures: input.max_total_failures,
+        on_duplicate_id: OnDuplicateId::Overwrite,
+        shutdown_timeout: None,
+    }
+}
 
-    let index_deps = crate::index_codebase::IndexCodebaseDeps {
+fn index_deps(deps: &ReindexByChangeDeps) -> crate::index_codebase::IndexCodebaseDeps {
+    crate::index_codebase::IndexCodebaseDeps {
         embedding: deps.embedding.clone(),
         vectordb: deps.vectordb.clone(),
         splitter: deps.splitter.clone(),
@@ -455,14 +649,8 @@ async fn reindex_changed_files(
         ignore: deps.ignore.clone(),
         logger: deps.logger.clone(),
         telemetry: deps.telemetry.clone(),
-    };
-
-    let _ = index_codebase(ctx, &index_deps, index_input).await?;
-    if let Some(timer) = index_timer.as_ref() {
-        timer.stop();
+        embedding_routes: deps.embedding_routes.clone(),
     }
-
-    Ok(())
 }
 
 fn files_to_index(added: &[Box<str>], modified: &[Box<str>]) -> Vec<Box<str>> {
@@ -552,6 +740,15 @@ fn log_fields_completed(
     fields
 }
 
+fn log_fields_partial(failed_files: usize) -> BTreeMap<Box<str>, Value> {
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "failedFiles".to_owned().into_boxed_str(),
+        Value::from(failed_files),
+    );
+    fields
+}
+
 fn log_fields_abort(index_mode: IndexMode, duration_ms: u64) -> BTreeMap<Box<str>, Value> {
     let mut fields = BTreeMap::new();
     fields.insert(
@@ -624,6 +821,7 @@ mod tests {
             ignore: Arc::new(NoopIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
         let ctx = RequestContext::new_request();
         delete_file_chunks_by_relative_path(
@@ -667,6 +865,7 @@ mod tests {
             ignore: Arc::new(NoopIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
 
         let progress = Arc::new(Mutex::new(Vec::new()));
@@ -682,15 +881,29 @@ mod tests {
                 index_mode: IndexMode::Dense,
                 supported_extensions: None,
                 ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
                 embedding_batch_size: NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
                 chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
                 max_files: None,
                 max_file_size_bytes: None,
+                max_file_size_bytes_by_extension: BTreeMap::new(),
+                path_normalization: PathNormalization::AsIs,
+                oversize_chunk_policy: OversizeChunkPolicy::Split,
+                skip_binary: true,
+                document_template: None,
+                extra_metadata: BTreeMap::new(),
+                record_mtime: false,
+                min_chunk_chars: 0,
+                max_chunk_chars: None,
                 max_buffered_chunks: None,
                 max_buffered_embeddings: None,
                 max_in_flight_files: None,
                 max_in_flight_embedding_batches: None,
                 max_in_flight_inserts: None,
+                shard_count: None,
+                max_consecutive_failures: None,
+                max_total_failures: None,
+                atomic_per_file: false,
                 on_progress: Some(Arc::new(move |event| {
                     let mut guard = progress_handle
                         .lock()
@@ -735,6 +948,7 @@ mod tests {
             ignore: Arc::new(NoopIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
         let ctx = RequestContext::new_request();
         let output = reindex_by_change(
@@ -747,15 +961,29 @@ mod tests {
                 index_mode: IndexMode::Dense,
                 supported_extensions: None,
                 ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
                 embedding_batch_size: NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN),
                 chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
                 max_files: None,
                 max_file_size_bytes: None,
+                max_file_size_bytes_by_extension: BTreeMap::new(),
+                path_normalization: PathNormalization::AsIs,
+                oversize_chunk_policy: OversizeChunkPolicy::Split,
+                skip_binary: true,
+                document_template: None,
+                extra_metadata: BTreeMap::new(),
+                record_mtime: false,
+                min_chunk_chars: 0,
+                max_chunk_chars: None,
                 max_buffered_chunks: None,
                 max_buffered_embeddings: None,
                 max_in_flight_files: None,
                 max_in_flight_embedding_batches: None,
                 max_in_flight_inserts: None,
+                shard_count: None,
+                max_consecutive_failures: None,
+                max_total_failures: None,
+                atomic_per_file: false,
                 on_progress: None,
             },
         )
@@ -823,6 +1051,282 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn tolerated_embed_failures_invalidate_partly_indexed_files() -> Result<()> {
+        let file_sync = Arc::new(StaticFileSync::new(FileChangeSet {
+            added: vec!["src/new.rs".into()],
+            removed: Vec::new(),
+            modified: vec!["src/lib.rs".into()],
+        }));
+        let vectordb = Arc::new(SpyVectorDb::new()?);
+        let filesystem = Arc::new(StaticFileSystem::new([
+            ("src/lib.rs", "pub fn broken() { 0 }\n"),
+            ("src/new.rs", "pub fn added() { 1 }\n"),
+        ]));
+        let deps = ReindexByChangeDeps {
+            file_sync: file_sync.clone(),
+            vectordb: vectordb.clone(),
+            embedding: Arc::new(NoopEmbedding::failing_on("broken")?),
+            splitter: Arc::new(ChunkingSplitter),
+            filesystem,
+            path_policy: Arc::new(NoopPathPolicy),
+            ignore: Arc::new(NoopIgnore),
+            logger: None,
+            telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
+        };
+        let ctx = RequestContext::new_request();
+        let output = reindex_by_change(
+            &ctx,
+            &deps,
+            ReindexByChangeInput {
+                codebase_root: PathBuf::from("/tmp/repo"),
+                collection_name: CollectionName::parse("code_chunks_test")
+                    .map_err(ErrorEnvelope::from)?,
+                index_mode: IndexMode::Dense,
+                supported_extensions: None,
+                ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
+                embedding_batch_size: NonZeroUsize::MIN,
+                chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
+                max_files: None,
+                max_file_size_bytes: None,
+                max_file_size_bytes_by_extension: BTreeMap::new(),
+                path_normalization: PathNormalization::AsIs,
+                oversize_chunk_policy: OversizeChunkPolicy::Split,
+                skip_binary: true,
+                document_template: None,
+                extra_metadata: BTreeMap::new(),
+                record_mtime: false,
+                min_chunk_chars: 0,
+                max_chunk_chars: None,
+                max_buffered_chunks: None,
+                max_buffered_embeddings: None,
+                max_in_flight_files: None,
+                max_in_flight_embedding_batches: None,
+                max_in_flight_inserts: None,
+                shard_count: None,
+                max_consecutive_failures: None,
+                max_total_failures: NonZeroUsize::new(1),
+                atomic_per_file: false,
+                on_progress: None,
+            },
+        )
+        .await?;
+
+        let failed: Vec<Box<str>> = vec!["src/lib.rs".into()];
+        assert_eq!(output.failed_files, failed);
+        assert_eq!(file_sync.invalidated(), failed);
+        assert_eq!(
+            output.stage_stats.map(|stats| stats.embed.failed_batches),
+            Some(1)
+        );
+        let state = vectordb.state.lock().map_err(|_| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "spy vectordb lock poisoned",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        assert_eq!(inserted_paths(&state.inserted), "src/new.rs");
+        drop(state);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn atomic_per_file_interleaves_delete_and_insert() -> Result<()> {
+        let file_sync = Arc::new(StaticFileSync::new(FileChangeSet {
+            added: vec!["src/new.rs".into()],
+            removed: Vec::new(),
+            modified: vec!["src/lib.rs".into(), "src/a.rs".into()],
+        }));
+        let vectordb = Arc::new(SpyVectorDb::new()?);
+        let filesystem = Arc::new(StaticFileSystem::new([
+            ("src/a.rs", "pub fn a() { 2 }\n"),
+            ("src/lib.rs", "pub fn original() { 0 }\n"),
+            ("src/new.rs", "pub fn added() { 1 }\n"),
+        ]));
+        let deps = ReindexByChangeDeps {
+            file_sync,
+            vectordb: vectordb.clone(),
+            embedding: Arc::new(NoopEmbedding::new()?),
+            splitter: Arc::new(ChunkingSplitter),
+            filesystem,
+            path_policy: Arc::new(NoopPathPolicy),
+            ignore: Arc::new(NoopIgnore),
+            logger: None,
+            telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
+        };
+        let ctx = RequestContext::new_request();
+        let output = reindex_by_change(
+            &ctx,
+            &deps,
+            ReindexByChangeInput {
+                codebase_root: PathBuf::from("/tmp/repo"),
+                collection_name: CollectionName::parse("code_chunks_test")
+                    .map_err(ErrorEnvelope::from)?,
+                index_mode: IndexMode::Dense,
+                supported_extensions: None,
+                ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
+                embedding_batch_size: NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN),
+                chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
+                max_files: None,
+                max_file_size_bytes: None,
+                max_file_size_bytes_by_extension: BTreeMap::new(),
+                path_normalization: PathNormalization::AsIs,
+                oversize_chunk_policy: OversizeChunkPolicy::Split,
+                skip_binary: true,
+                document_template: None,
+                extra_metadata: BTreeMap::new(),
+                record_mtime: false,
+                min_chunk_chars: 0,
+                max_chunk_chars: None,
+                max_buffered_chunks: None,
+                max_buffered_embeddings: None,
+                max_in_flight_files: None,
+                max_in_flight_embedding_batches: None,
+                max_in_flight_inserts: None,
+                shard_count: None,
+                max_consecutive_failures: None,
+                max_total_failures: None,
+                atomic_per_file: true,
+                on_progress: None,
+            },
+        )
+        .await?;
+
+        assert_eq!(output.added, 1);
+        assert_eq!(output.modified, 2);
+        let inserted_chunks = output
+            .stage_stats
+            .map(|stage_stats| stage_stats.insert.chunks);
+        assert_eq!(inserted_chunks, Some(3));
+
+        let operations = vectordb
+            .state
+            .lock()
+            .map_err(|_| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "spy vectordb lock poisoned",
+                    ErrorClass::NonRetriable,
+                )
+            })?
+            .operations
+            .clone();
+        assert_eq!(
+            operations.iter().map(AsRef::as_ref).collect::<Vec<&str>>(),
+            vec![
+                "query src/a.rs",
+                "delete chunk_c",
+                "insert src/a.rs",
+                "query src/lib.rs",
+                "delete chunk_a,chunk_b",
+                "insert src/lib.rs",
+                "insert src/new.rs",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sharded_reindex_targets_shard_collections() -> Result<()> {
+        let file_sync = Arc::new(StaticFileSync::new(FileChangeSet {
+            added: Vec::new(),
+            removed: vec!["src/a.rs".into()],
+            modified: vec!["src/lib.rs".into()],
+        }));
+        let vectordb = Arc::new(SpyVectorDb::new()?);
+        let filesystem = Arc::new(StaticFileSystem::new([(
+            "src/lib.rs",
+            "pub fn original() { 0 }\n",
+        )]));
+        let deps = ReindexByChangeDeps {
+            file_sync,
+            vectordb: vectordb.clone(),
+            embedding: Arc::new(NoopEmbedding::new()?),
+            splitter: Arc::new(ChunkingSplitter),
+            filesystem,
+            path_policy: Arc::new(NoopPathPolicy),
+            ignore: Arc::new(NoopIgnore),
+            logger: None,
+            telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
+        };
+        let ctx = RequestContext::new_request();
+        let output = reindex_by_change(
+            &ctx,
+            &deps,
+            ReindexByChangeInput {
+                codebase_root: PathBuf::from("/tmp/repo"),
+                collection_name: CollectionName::parse("code_chunks_test")
+                    .map_err(ErrorEnvelope::from)?,
+                index_mode: IndexMode::Dense,
+                supported_extensions: None,
+                ignore_patterns: None,
+                additional_ignore_patterns: Vec::new(),
+                embedding_batch_size: NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN),
+                chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
+                max_files: None,
+                max_file_size_bytes: None,
+                max_file_size_bytes_by_extension: BTreeMap::new(),
+                path_normalization: PathNormalization::AsIs,
+                oversize_chunk_policy: OversizeChunkPolicy::Split,
+                skip_binary: true,
+                document_template: None,
+                extra_metadata: BTreeMap::new(),
+                record_mtime: false,
+                min_chunk_chars: 0,
+                max_chunk_chars: None,
+                max_buffered_chunks: None,
+                max_buffered_embeddings: None,
+                max_in_flight_files: None,
+                max_in_flight_embedding_batches: None,
+                max_in_flight_inserts: None,
+                shard_count: Some(2),
+                max_consecutive_failures: None,
+                max_total_failures: None,
+                atomic_per_file: false,
+                on_progress: None,
+            },
+        )
+        .await?;
+        assert_eq!(output.removed, 1);
+        assert_eq!(output.modified, 1);
+
+        let collections = vectordb
+            .state
+            .lock()
+            .map_err(|_| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "spy vectordb lock poisoned",
+                    ErrorClass::NonRetriable,
+                )
+            })?
+            .collections
+            .clone();
+        let collections = collections.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+        for shard in ["code_chunks_test_shard_0", "code_chunks_test_shard_1"] {
+            assert!(collections.contains(&format!("query {shard}").as_str()));
+            assert!(collections.contains(&format!("delete {shard}").as_str()));
+        }
+        assert!(
+            collections
+                .iter()
+                .any(|entry| entry.starts_with("insert code_chunks_test_shard_"))
+        );
+        assert!(
+            collections
+                .iter()
+                .all(|entry| entry.contains("code_chunks_test_shard_")),
+            "the unsharded base collection must not be touched: {collections:?}"
+        );
+        Ok(())
+    }
+
     #[derive(Clone)]
     struct SpyVectorDb {
         provider: VectorDbProviderInfo,
@@ -835,6 +1339,10 @@ mod tests {
         deleted_ids: Vec<Box<str>>,
         inserted: Vec<VectorDocumentForInsert>,
         actions: Vec<SpyVectorDbAction>,
+        /// Ordered log of the form `"<action> <subject>"`.
+        operations: Vec<Box<str>>,
+        /// Ordered log of the form `"<action> <collection>"`.
+        collections: Vec<Box<str>>,
     }
 
     #[derive(Debug, PartialEq, Eq)]
@@ -907,7 +1415,7 @@ mod tests {
         fn insert(
             &self,
             _ctx: &RequestContext,
-            _collection_name: CollectionName,
+            collection_name: CollectionName,
             documents: Vec<VectorDocumentForInsert>,
         ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
             let state = self.state.clone();
@@ -920,6 +1428,9 @@ mod tests {
                     )
                 })?;
                 guard.actions.push(SpyVectorDbAction::Insert);
+                let operation = format!("insert {}", inserted_paths(&documents));
+                guard.operations.push(operation.into_boxed_str());
+                record_collection(&mut guard, "insert", &collection_name);
                 guard.inserted.extend(documents);
                 Ok(())
             })
@@ -941,6 +1452,8 @@ mod tests {
                     )
                 })?;
                 guard.actions.push(SpyVectorDbAction::Insert);
+                let operation = format!("insert {}", inserted_paths(&documents));
+                guard.operations.push(operation.into_boxed_str());
                 guard.inserted.extend(documents);
                 Ok(())
             })
@@ -971,7 +1484,7 @@ mod tests {
         fn delete(
             &self,
             _ctx: &RequestContext,
-            _collection_name: CollectionName,
+            collection_name: CollectionName,
             ids: Vec<Box<str>>,
         ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
             let state = self.state.clone();
@@ -984,6 +1497,9 @@ mod tests {
                     )
                 })?;
                 guard.actions.push(SpyVectorDbAction::Delete);
+                let operation = format!("delete {}", ids.join(","));
+                guard.operations.push(operation.into_boxed_str());
+                record_collection(&mut guard, "delete", &collection_name);
                 guard.deleted_ids = ids;
                 Ok(())
             })
@@ -992,7 +1508,7 @@ mod tests {
         fn query(
             &self,
             _ctx: &RequestContext,
-            _collection_name: CollectionName,
+            collection_name: CollectionName,
             filter: Box<str>,
             _output_fields: Vec<Box<str>>,
             _limit: Option<u32>,
@@ -1008,6 +1524,12 @@ mod tests {
                 })?;
                 guard.last_filter = Some(filter.clone());
                 guard.actions.push(SpyVectorDbAction::Query);
+                let operation = format!(
+                    "query {}",
+                    extract_relative_path(&filter).unwrap_or(&filter)
+                );
+                guard.operations.push(operation.into_boxed_str());
+                record_collection(&mut guard, "query", &collection_name);
                 match row_ids_for_filter(filter.as_ref()) {
                     ids if ids.is_empty() => Ok(Vec::new()),
                     ids => Ok(ids.iter().map(|id| row_with_id(id)).collect()),
@@ -1016,9 +1538,24 @@ mod tests {
         }
     }
 
+    fn record_collection(state: &mut SpyVectorDbState, action: &str, collection: &CollectionName) {
+        let entry = format!("{action} {}", collection.as_str());
+        state.collections.push(entry.into_boxed_str());
+    }
+
+    fn inserted_paths(documents: &[VectorDocumentForInsert]) -> String {
+        let mut paths = documents
+            .iter()
+            .map(|doc| doc.metadata.relative_path.as_ref())
+            .collect::<Vec<_>>();
+        paths.dedup();
+        paths.join(",")
+    }
+
     fn row_ids_for_filter(filter: &str) -> Vec<&'static str> {
         match extract_relative_path(filter) {
             Some("src/lib.rs") => vec!["chunk_a", "chunk_b"],
+            Some("src/a.rs") => vec!["chunk_c"],
             _ => Vec::new(),
         }
     }
@@ -1045,6 +1582,7 @@ mod tests {
     #[derive(Clone)]
     struct NoopEmbedding {
         provider: EmbeddingProviderInfo,
+        fail_on: Option<&'static str>,
     }
 
     impl NoopEmbedding {
@@ -1053,7 +1591,17 @@ mod tests {
                 provider: EmbeddingProviderInfo {
                     id: EmbeddingProviderId::parse("openai").map_err(ErrorEnvelope::from)?,
                     name: "noop".into(),
+                    max_input_chars: None,
                 },
+                fail_on: None,
+            })
+        }
+
+        /// Fail every batch with a text containing `needle`.
+        fn failing_on(needle: &'static str) -> Result<Self> {
+            Ok(Self {
+                fail_on: Some(needle),
+                ..Self::new()?
             })
         }
     }
@@ -1084,7 +1632,17 @@ mod tests {
             _ctx: &RequestContext,
             request: EmbedBatchRequest,
         ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<EmbeddingVector>>> {
+            let fail_on = self.fail_on;
             Box::pin(async move {
+                if let Some(needle) = fail_on
+                    && request.texts.iter().any(|text| text.contains(needle))
+                {
+                    return Err(ErrorEnvelope::unexpected(
+                        ErrorCode::timeout(),
+                        "embed backend unavailable",
+                        ErrorClass::Retriable,
+                    ));
+                }
                 Ok(request
                     .texts
                     .into_iter()
@@ -1226,6 +1784,14 @@ mod tests {
             Box::pin(async move { Ok(FileChangeSet::default()) })
         }
 
+        fn invalidate_files(
+            &self,
+            _ctx: &RequestContext,
+            _relative_paths: Vec<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
         fn delete_snapshot(
             &self,
             _ctx: &RequestContext,
@@ -1238,11 +1804,22 @@ mod tests {
     #[derive(Clone)]
     struct StaticFileSync {
         changes: FileChangeSet,
+        invalidated: Arc<Mutex<Vec<Box<str>>>>,
     }
 
     impl StaticFileSync {
         fn new(changes: FileChangeSet) -> Self {
-            Self { changes }
+            Self {
+                changes,
+                invalidated: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        fn invalidated(&self) -> Vec<Box<str>> {
+            self.invalidated
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone()
         }
     }
 
@@ -1264,6 +1841,21 @@ mod tests {
             Box::pin(async move { Ok(changes) })
         }
 
+        fn invalidate_files(
+            &self,
+            _ctx: &RequestContext,
+            relative_paths: Vec<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            let invalidated = self.invalidated.clone();
+            Box::pin(async move {
+                invalidated
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .extend(relative_paths);
+                Ok(())
+            })
+        }
+
         fn delete_snapshot(
             &self,
             _ctx: &RequestContext,
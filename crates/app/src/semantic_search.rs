@@ -1,21 +1,56 @@
 //! Semantic search use-case (dense + hybrid).
 
+use crate::apply_input_template;
+use crate::sharding::{index_collection_names, validate_shard_count};
 use semantic_code_domain::{
-    CollectionName, IndexMode, SearchResult, SearchResultKey, SearchStats, compare_search_results,
+    CollectionName, IndexMode, LineSpan, RecencyBoost, SCORE_HISTOGRAM_BUCKETS, SearchDiagnostics,
+    SearchExclusions, SearchResult, SearchResultKey, SearchStats, compare_search_results,
+    group_results_by_file,
 };
 use semantic_code_ports::{
-    EmbeddingPort, HybridSearchBatchRequest, HybridSearchData, HybridSearchOptions,
-    HybridSearchRequest, LogFields, LoggerPort, RerankStrategy, RerankStrategyKind, TelemetryPort,
-    TelemetryTags, VectorDbPort, VectorSearchOptions, VectorSearchRequest,
+    EmbeddingPort, FileSystemPort, FileSystemPortExt, FileSystemSession, HybridSearchBatchRequest,
+    HybridSearchData, HybridSearchOptions, HybridSearchRequest, LogFields, LoggerPort,
+    RerankStrategy, RerankStrategyKind, SafeRelativePath, TelemetryPort, TelemetryTags,
+    VectorDbPort, VectorSearchOptions, VectorSearchRequest,
 };
 use semantic_code_shared::{ErrorEnvelope, RequestContext, Result};
 use serde_json::Value;
 use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Search phase reported through [`SemanticSearchInput::on_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchPhase {
+    /// Embedding the query text (skipped for pre-computed query vectors).
+    EmbeddingQuery,
+    /// Querying the vector database.
+    Searching,
+    /// Ordering and post-processing candidates.
+    Reranking,
+    /// Search finished.
+    Done,
+}
+
+impl SearchPhase {
+    /// Stable phase label.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::EmbeddingQuery => "embedding_query",
+            Self::Searching => "searching",
+            Self::Reranking => "reranking",
+            Self::Done => "done",
+        }
+    }
+}
 
 /// Input payload for semantic search.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct SemanticSearchInput {
     /// Root identifier for the codebase (for logging only).
     pub codebase_root: Box<str>,
@@ -25,6 +60,16 @@ pub struct SemanticSearchInput {
     pub index_mode: IndexMode,
     /// Query text to embed.
     pub query: Box<str>,
+    /// Instruction prefix prepended to the query before embedding.
+    ///
+    /// Ignored when `query_vector` is provided.
+    pub query_template: Option<Box<str>>,
+    /// Lowercase the query and strip diacritics before embedding.
+    ///
+    /// A hint for natural-language queries: embeddings are not literal, so
+    /// this narrows casing/accent variance rather than guaranteeing identical
+    /// results. Applied before `query_template`; off by default.
+    pub normalize_query: bool,
     /// Optional top-k override (defaults to 5).
     pub top_k: Option<u32>,
     /// Optional score threshold (defaults to 0.0 — no filtering).
@@ -32,8 +77,68 @@ pub struct SemanticSearchInput {
     /// Pre-computed query embedding vector. When provided, embedding inference
     /// is skipped and this vector is used directly for similarity search.
     pub query_vector: Option<semantic_code_ports::EmbeddingVector>,
+    /// Attach a score histogram of the candidate set to the output diagnostics.
+    pub include_histogram: bool,
+    /// Shard count used at index time; search fans out across every shard.
+    pub shard_count: Option<u32>,
+    /// Keep only the best-scoring chunk per file before applying `top_k`.
+    pub group_by_file: bool,
+    /// Paths and languages removed from the candidate set.
+    pub exclusions: SearchExclusions,
+    /// Re-rank candidates toward recently modified files; off when `None`.
+    ///
+    /// Applied after `threshold`, which still compares base similarity scores.
+    pub recency_boost: Option<RecencyBoost>,
+    /// Widen each result's content by this many lines before and after its
+    /// span, read from [`SemanticSearchDeps::filesystem`].
+    ///
+    /// Result keys keep the matched span. Results whose file cannot be read
+    /// keep the indexed chunk content.
+    pub context_lines: Option<u32>,
+    /// Optional progress callback invoked as the search moves through phases.
+    pub on_progress: Option<Arc<dyn Fn(SearchPhase) + Send + Sync>>,
+}
+
+impl fmt::Debug for SemanticSearchInput {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SemanticSearchInput")
+            .field("codebase_root", &self.codebase_root)
+            .field("collection_name", &self.collection_name)
+            .field("index_mode", &self.index_mode)
+            .field("query", &self.query)
+            .field("query_template", &self.query_template)
+            .field("normalize_query", &self.normalize_query)
+            .field("top_k", &self.top_k)
+            .field("threshold", &self.threshold)
+            .field("query_vector", &self.query_vector)
+            .field("include_histogram", &self.include_histogram)
+            .field("shard_count", &self.shard_count)
+            .field("group_by_file", &self.group_by_file)
+            .field("exclusions", &self.exclusions)
+            .field("recency_boost", &self.recency_boost)
+            .field("context_lines", &self.context_lines)
+            .field("has_progress_callback", &self.on_progress.is_some())
+            .finish()
+    }
 }
 
+impl SemanticSearchInput {
+    fn report_progress(&self, phase: SearchPhase) {
+        if let Some(callback) = self.on_progress.as_ref() {
+            callback(phase);
+        }
+    }
+}
+
+/// Candidate multiplier when grouping by file, so collapsing chunks from the
+/// same file still leaves enough distinct files to fill `top_k`.
+const GROUP_BY_FILE_OVERFETCH: u32 = 4;
+
+/// Minimum candidate pool fetched when a score histogram is requested, so the
+/// histogram describes the distribution beyond the returned `top_k`.
+const HISTOGRAM_MIN_CANDIDATES: u32 = 100;
+
 /// Semantic search output payload.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SemanticSearchOutput {
@@ -41,6 +146,19 @@ pub struct SemanticSearchOutput {
     pub results: Vec<SearchResult>,
     /// Optional vector-search diagnostics.
     pub stats: Option<SearchStats>,
+    /// Opt-in diagnostics computed over the candidate set.
+    pub diagnostics: Option<SearchDiagnostics>,
+    /// Wall-clock time spent in each search phase.
+    pub timings: SearchTimings,
+}
+
+/// Wall-clock milliseconds spent in each phase of a semantic search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchTimings {
+    /// Query embedding; `0` when a pre-computed vector was supplied.
+    pub embed_ms: u64,
+    /// Vector DB search across every searched collection, including reranking.
+    pub search_ms: u64,
 }
 
 /// Dependencies required by semantic search.
@@ -54,6 +172,9 @@ pub struct SemanticSearchDeps {
     pub logger: Option<Arc<dyn LoggerPort>>,
     /// Optional telemetry sink.
     pub telemetry: Option<Arc<dyn TelemetryPort>>,
+    /// Filesystem used to read surrounding lines for
+    /// [`SemanticSearchInput::context_lines`]; context is skipped when `None`.
+    pub filesystem: Option<Arc<dyn FileSystemPort>>,
 }
 
 /// Execute semantic search for the given input.
@@ -66,6 +187,9 @@ pub struct SemanticSearchDeps {
         query_len = input.query.len(),
         top_k = input.top_k.unwrap_or(5),
         has_threshold = input.threshold.is_some(),
+        include_histogram = input.include_histogram,
+        group_by_file = input.group_by_file,
+        recency_boost = input.recency_boost.is_some(),
     )
 )]
 pub async fn semantic_search(
@@ -161,28 +285,61 @@ async fn run_search(
     started_at: Instant,
 ) -> Result<SemanticSearchOutput> {
     ctx.ensure_not_cancelled("semantic_search.start")?;
+    validate_shard_count(input.shard_count)?;
 
-    let has_collection = deps
-        .vectordb
-        .has_collection(ctx, input.collection_name.clone())
-        .await?;
-    if !has_collection {
+    let collections = existing_collections(ctx, deps, input).await?;
+    if collections.is_empty() {
         tracing::debug!("collection missing; returning empty search results");
         log_completed(deps, input, top_k, threshold, 0, started_at);
+        input.report_progress(SearchPhase::Done);
         return Ok(SemanticSearchOutput {
             results: Vec::new(),
             stats: None,
+            diagnostics: None,
+            timings: SearchTimings::default(),
         });
     }
 
+    let embed_started_at = Instant::now();
     let embedding = if let Some(vector) = input.query_vector.clone() {
         tracing::debug!("using pre-computed query vector; skipping embedding inference");
         vector
     } else {
+        input.report_progress(SearchPhase::EmbeddingQuery);
         embed_query(ctx, deps, input).await?
     };
-    let search_output = search_vectordb(ctx, deps, input, embedding, top_k, threshold).await?;
-    let ordered = rerank_results(deps, input, search_output.results);
+    let embed_ms = duration_ms(embed_started_at);
+    let mut candidate_k = if input.group_by_file {
+        top_k.saturating_mul(GROUP_BY_FILE_OVERFETCH)
+    } else {
+        top_k
+    };
+    if input.include_histogram {
+        candidate_k = candidate_k.max(HISTOGRAM_MIN_CANDIDATES);
+    }
+    input.report_progress(SearchPhase::Searching);
+    let search_started_at = Instant::now();
+    let search_output = search_vectordb(
+        ctx,
+        deps,
+        input,
+        &collections,
+        embedding,
+        candidate_k,
+        threshold,
+    )
+    .await?;
+    input.report_progress(SearchPhase::Reranking);
+    let mut ordered = rerank_results(deps, input, search_output.results);
+    if input.group_by_file {
+        ordered = group_results_by_file(ordered);
+    }
+    if candidate_k > top_k || collections.len() > 1 {
+        // Shards, file grouping and histograms all over-collect; keep the global top-k.
+        ordered.truncate(usize::try_from(top_k).unwrap_or(usize::MAX));
+    }
+    expand_result_context(ctx, deps, input, &mut ordered).await?;
+    let search_ms = duration_ms(search_started_at);
 
     if let Some(telemetry) = deps.telemetry.as_ref() {
         telemetry.increment_counter(
@@ -194,13 +351,119 @@ async fn run_search(
 
     log_completed(deps, input, top_k, threshold, ordered.len(), started_at);
     tracing::debug!(result_count = ordered.len(), "semantic search completed");
+    input.report_progress(SearchPhase::Done);
 
     Ok(SemanticSearchOutput {
         results: ordered,
         stats: search_output.stats,
+        diagnostics: search_output.diagnostics,
+        timings: SearchTimings {
+            embed_ms,
+            search_ms,
+        },
     })
 }
 
+/// Replace each result's content with its span widened by
+/// `input.context_lines` lines read from the filesystem.
+async fn expand_result_context(
+    ctx: &RequestContext,
+    deps: &SemanticSearchDeps,
+    input: &SemanticSearchInput,
+    results: &mut [SearchResult],
+) -> Result<()> {
+    let Some(context_lines) = input.context_lines.filter(|lines| *lines > 0) else {
+        return Ok(());
+    };
+    let Some(filesystem) = deps.filesystem.as_ref() else {
+        return Ok(());
+    };
+    let session = filesystem.session(PathBuf::from(input.codebase_root.as_ref()));
+    let mut files: BTreeMap<Box<str>, Option<Box<str>>> = BTreeMap::new();
+    for result in results.iter_mut().filter(|result| result.content.is_some()) {
+        let path = &result.key.relative_path;
+        if !files.contains_key(path) {
+            let text = read_result_file(ctx, &session, path).await?;
+            files.insert(path.clone(), text);
+        }
+        let Some(Some(text)) = files.get(path) else {
+            continue;
+        };
+        if let Some(expanded) = lines_around(text, result.key.span, context_lines) {
+            result.content = Some(expanded);
+        }
+    }
+    Ok(())
+}
+
+/// Read a result's source file; unreadable files yield `None` so the indexed
+/// chunk content is kept.
+async fn read_result_file(
+    ctx: &RequestContext,
+    session: &FileSystemSession<'_>,
+    relative_path: &str,
+) -> Result<Option<Box<str>>> {
+    let Ok(path) = SafeRelativePath::new(relative_path) else {
+        return Ok(None);
+    };
+    match session.read_file_text(ctx, path).await {
+        Ok(text) => Ok(Some(text)),
+        Err(error) if error.is_cancelled() => Err(error),
+        Err(error) => {
+            tracing::debug!(
+                path = relative_path,
+                error = %error,
+                "context lines unavailable; keeping chunk content"
+            );
+            Ok(None)
+        },
+    }
+}
+
+/// Lines of `text` covering `span` plus `context_lines` on each side, clamped
+/// to the file. `None` when the file no longer contains the span.
+fn lines_around(text: &str, span: LineSpan, context_lines: u32) -> Option<Box<str>> {
+    let line_count = text.lines().count();
+    let end = usize::try_from(span.end_line()).ok()?;
+    if end > line_count {
+        return None;
+    }
+    let context = usize::try_from(context_lines).unwrap_or(usize::MAX);
+    let first = usize::try_from(span.start_line())
+        .ok()?
+        .saturating_sub(1)
+        .saturating_sub(context);
+    let last = end.saturating_add(context).min(line_count);
+    let expanded = text
+        .lines()
+        .skip(first)
+        .take(last - first)
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(expanded.into_boxed_str())
+}
+
+/// Resolve the collections to search: the target collection, or every shard
+/// when the index is sharded. Missing collections are skipped.
+async fn existing_collections(
+    ctx: &RequestContext,
+    deps: &SemanticSearchDeps,
+    input: &SemanticSearchInput,
+) -> Result<Vec<CollectionName>> {
+    let candidates = index_collection_names(&input.collection_name, input.shard_count)?;
+    let mut existing = Vec::with_capacity(candidates.len());
+    for collection in candidates {
+        if deps
+            .vectordb
+            .has_collection(ctx, collection.clone())
+            .await?
+        {
+            existing.push(collection);
+        }
+    }
+    Ok(existing)
+}
+
 #[tracing::instrument(
     name = "app.semantic_search.embed_query",
     skip_all,
@@ -226,9 +489,17 @@ async fn embed_query(
         .telemetry
         .as_ref()
         .map(|telemetry| telemetry.start_timer("backend.search.embed", Some(&embed_tags)));
+    let query = if input.normalize_query {
+        normalize_query_text(&input.query)
+    } else {
+        input.query.clone()
+    };
     let embedding = deps
         .embedding
-        .embed(ctx, input.query.clone().into())
+        .embed(
+            ctx,
+            apply_input_template(input.query_template.as_deref(), &query).into(),
+        )
         .await?;
     if let Some(timer) = embed_timer.as_ref() {
         timer.stop();
@@ -237,6 +508,16 @@ async fn embed_query(
     Ok(embedding)
 }
 
+/// Lowercase and drop combining marks after canonical decomposition (NFD).
+fn normalize_query_text(query: &str) -> Box<str> {
+    query
+        .nfd()
+        .filter(|ch| !is_combining_mark(*ch))
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .into_boxed_str()
+}
+
 #[tracing::instrument(
     name = "app.semantic_search.search_vectordb",
     skip_all,
@@ -246,12 +527,14 @@ async fn embed_query(
         provider = deps.vectordb.provider().id.as_str(),
         top_k = top_k,
         threshold = threshold,
+        collection_count = collections.len(),
     )
 )]
 async fn search_vectordb(
     ctx: &RequestContext,
     deps: &SemanticSearchDeps,
     input: &SemanticSearchInput,
+    collections: &[CollectionName],
     embedding: semantic_code_ports::EmbeddingVector,
     top_k: u32,
     threshold: f32,
@@ -273,27 +556,83 @@ async fn search_vectordb(
         .map(|telemetry| telemetry.start_timer("backend.search.vectordb", Some(&vectordb_tags)));
 
     let vector = embedding.into_vector();
+    let mut candidates = Vec::new();
+    let mut stats = None;
+    for collection_name in collections {
+        let (shard_candidates, shard_stats) = search_collection(
+            ctx,
+            deps,
+            input,
+            collection_name.clone(),
+            &vector,
+            top_k,
+            threshold,
+        )
+        .await?;
+        candidates.extend(shard_candidates);
+        if stats.is_none() {
+            stats = shard_stats;
+        }
+    }
+
+    if let Some(timer) = vectordb_timer.as_ref() {
+        timer.stop();
+    }
+    let diagnostics = input.include_histogram.then(|| {
+        // Bucket the full candidate pool before the threshold and top-k cut.
+        let histogram = score_histogram(candidates.iter().map(|(result, _)| result));
+        if input.index_mode == IndexMode::Dense {
+            candidates.retain(|(result, _)| result.score >= threshold);
+        }
+        SearchDiagnostics {
+            score_histogram: Some(histogram),
+        }
+    });
+    let output = SemanticSearchOutput {
+        results: apply_recency_boost(candidates, input.recency_boost.as_ref()),
+        stats,
+        diagnostics,
+        timings: SearchTimings::default(),
+    };
+    tracing::debug!(
+        result_count = output.results.len(),
+        method = method,
+        "vector search completed"
+    );
+
+    Ok(output)
+}
+
+/// A mapped search result paired with its file's indexed modification time.
+type Candidate = (SearchResult, Option<u64>);
+
+async fn search_collection(
+    ctx: &RequestContext,
+    deps: &SemanticSearchDeps,
+    input: &SemanticSearchInput,
+    collection_name: CollectionName,
+    vector: &Arc<[f32]>,
+    top_k: u32,
+    threshold: f32,
+) -> Result<(Vec<Candidate>, Option<SearchStats>)> {
     let output = match input.index_mode {
         IndexMode::Hybrid => {
-            let requests = hybrid_requests(&vector, input.query.clone(), top_k);
+            let requests = hybrid_requests(vector, input.query.clone(), top_k);
             let results = deps
                 .vectordb
                 .hybrid_search(
                     ctx,
                     HybridSearchBatchRequest {
-                        collection_name: input.collection_name.clone(),
+                        collection_name,
                         search_requests: requests,
-                        options: hybrid_options(top_k),
+                        options: hybrid_options(top_k, input.exclusions.clone()),
                     },
                 )
                 .await?
                 .into_iter()
                 .map(map_hybrid_result)
                 .collect::<Vec<_>>();
-            SemanticSearchOutput {
-                results,
-                stats: None,
-            }
+            (results, None)
         },
         IndexMode::Dense => {
             let response = deps
@@ -301,39 +640,46 @@ async fn search_vectordb(
                 .search(
                     ctx,
                     VectorSearchRequest {
-                        collection_name: input.collection_name.clone(),
-                        query_vector: vector,
+                        collection_name,
+                        query_vector: Arc::clone(vector),
                         options: VectorSearchOptions {
                             top_k: Some(top_k),
-                            threshold: Some(threshold),
+                            // With a histogram requested, `search_vectordb` applies the
+                            // threshold so the histogram still sees every candidate.
+                            threshold: (!input.include_histogram).then_some(threshold),
                             filter_expr: None,
+                            exclusions: input.exclusions.clone(),
                         },
                     },
                 )
                 .await?;
-            SemanticSearchOutput {
-                results: response
-                    .results
-                    .into_iter()
-                    .map(map_vector_result)
-                    .collect::<Vec<_>>(),
-                stats: response.stats,
-            }
+            let results = response
+                .results
+                .into_iter()
+                .map(map_vector_result)
+                .collect::<Vec<_>>();
+            (results, response.stats)
         },
     };
-
-    if let Some(timer) = vectordb_timer.as_ref() {
-        timer.stop();
-    }
-    tracing::debug!(
-        result_count = output.results.len(),
-        method = method,
-        "vector search completed"
-    );
-
     Ok(output)
 }
 
+/// Scale each candidate's score by its recency factor, dropping the mtimes.
+fn apply_recency_boost(
+    candidates: Vec<Candidate>,
+    boost: Option<&RecencyBoost>,
+) -> Vec<SearchResult> {
+    candidates
+        .into_iter()
+        .map(|(mut result, mtime_ms)| {
+            if let Some(boost) = boost {
+                result.score *= boost.factor(mtime_ms);
+            }
+            result
+        })
+        .collect()
+}
+
 #[tracing::instrument(
     name = "app.semantic_search.rerank",
     skip_all,
@@ -363,6 +709,34 @@ fn rerank_results(
     results
 }
 
+/// Upper-exclusive bucket edges for [`score_histogram`]; the last bucket is
+/// closed so a perfect `1.0` score lands in it.
+const SCORE_HISTOGRAM_EDGES: [f32; SCORE_HISTOGRAM_BUCKETS - 1] =
+    [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9];
+
+/// Count candidate scores into equal-width buckets over `[0, 1]`.
+///
+/// Out-of-range scores are clamped into the first/last bucket; `NaN` scores are
+/// skipped.
+fn score_histogram<'a>(
+    results: impl IntoIterator<Item = &'a SearchResult>,
+) -> [u32; SCORE_HISTOGRAM_BUCKETS] {
+    let mut buckets = [0_u32; SCORE_HISTOGRAM_BUCKETS];
+    for result in results {
+        if result.score.is_nan() {
+            continue;
+        }
+        let index = SCORE_HISTOGRAM_EDGES
+            .iter()
+            .take_while(|edge| result.score >= **edge)
+            .count();
+        if let Some(slot) = buckets.get_mut(index) {
+            *slot = slot.saturating_add(1);
+        }
+    }
+    buckets
+}
+
 fn log_completed(
     deps: &SemanticSearchDeps,
     input: &SemanticSearchInput,
@@ -510,7 +884,7 @@ fn hybrid_requests(dense: &Arc<[f32]>, query: Box<str>, top_k: u32) -> Vec<Hybri
     ]
 }
 
-fn hybrid_options(top_k: u32) -> HybridSearchOptions {
+fn hybrid_options(top_k: u32, exclusions: SearchExclusions) -> HybridSearchOptions {
     let mut params = BTreeMap::new();
     params.insert("k".to_owned().into_boxed_str(), Value::from(100));
     HybridSearchOptions {
@@ -520,12 +894,13 @@ fn hybrid_options(top_k: u32) -> HybridSearchOptions {
         }),
         limit: Some(top_k),
         filter_expr: None,
+        exclusions,
     }
 }
 
-fn map_vector_result(result: semantic_code_ports::VectorSearchResult) -> SearchResult {
+fn map_vector_result(result: semantic_code_ports::VectorSearchResult) -> Candidate {
     let metadata = result.document.metadata;
-    SearchResult {
+    let mapped = SearchResult {
         key: SearchResultKey {
             relative_path: metadata.relative_path,
             span: metadata.span,
@@ -533,12 +908,13 @@ fn map_vector_result(result: semantic_code_ports::VectorSearchResult) -> SearchR
         content: Some(result.document.content),
         language: metadata.language,
         score: result.score,
-    }
+    };
+    (mapped, metadata.mtime_ms)
 }
 
-fn map_hybrid_result(result: semantic_code_ports::HybridSearchResult) -> SearchResult {
+fn map_hybrid_result(result: semantic_code_ports::HybridSearchResult) -> Candidate {
     let metadata = result.document.metadata;
-    SearchResult {
+    let mapped = SearchResult {
         key: SearchResultKey {
             relative_path: metadata.relative_path,
             span: metadata.span,
@@ -546,7 +922,8 @@ fn map_hybrid_result(result: semantic_code_ports::HybridSearchResult) -> SearchR
         content: Some(result.document.content),
         language: metadata.language,
         score: result.score,
-    }
+    };
+    (mapped, metadata.mtime_ms)
 }
 
 #[cfg(test)]
@@ -569,6 +946,8 @@ mod tests {
         provider: EmbeddingProviderInfo,
         vector: Arc<[f32]>,
         calls: Arc<AtomicUsize>,
+        inputs: Arc<Mutex<Vec<Box<str>>>>,
+        delay: Option<std::time::Duration>,
     }
 
     impl TestEmbedding {
@@ -576,13 +955,21 @@ mod tests {
             let provider = EmbeddingProviderInfo {
                 id: EmbeddingProviderId::parse("openai").map_err(ErrorEnvelope::from)?,
                 name: "test".into(),
+                max_input_chars: None,
             };
             Ok(Self {
                 provider,
                 vector: Arc::from(vector),
                 calls: Arc::new(AtomicUsize::new(0)),
+                inputs: Arc::new(Mutex::new(Vec::new())),
+                delay: None,
             })
         }
+
+        const fn with_delay(mut self, delay: std::time::Duration) -> Self {
+            self.delay = Some(delay);
+            self
+        }
     }
 
     impl EmbeddingPort for TestEmbedding {
@@ -608,12 +995,19 @@ mod tests {
         fn embed(
             &self,
             _ctx: &RequestContext,
-            _request: EmbedRequest,
+            request: EmbedRequest,
         ) -> semantic_code_ports::BoxFuture<'_, SharedResult<EmbeddingVector>> {
+            if let Ok(mut inputs) = self.inputs.lock() {
+                inputs.push(request.text);
+            }
             let vector = Arc::clone(&self.vector);
             let calls = self.calls.clone();
+            let delay = self.delay;
             Box::pin(async move {
                 calls.fetch_add(1, Ordering::SeqCst);
+                if let Some(delay) = delay {
+                    tokio::time::sleep(delay).await;
+                }
                 Ok(EmbeddingVector::new(vector))
             })
         }
@@ -854,6 +1248,8 @@ mod tests {
                     fragment_start_byte: None,
                     fragment_end_byte: None,
                     node_kind: None,
+                    extra: BTreeMap::new(),
+                    mtime_ms: None,
                 },
             },
             score,
@@ -875,6 +1271,7 @@ mod tests {
             vectordb,
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -884,9 +1281,18 @@ mod tests {
                 .map_err(ErrorEnvelope::from)?,
             index_mode: IndexMode::Dense,
             query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: Some(10),
             threshold: Some(0.0),
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let output = semantic_search(&ctx, &deps, input).await?;
@@ -900,6 +1306,259 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn recency_boost_ranks_recent_file_above_equal_scoring_older_file() -> SharedResult<()> {
+        const DAY_MS: u64 = 24 * 60 * 60 * 1_000;
+        let reference_ms = 400 * DAY_MS;
+        let mut older = result_doc("a_old.rs", 1, 2, 0.8)?;
+        older.document.metadata.mtime_ms = Some(reference_ms - 90 * DAY_MS);
+        let mut recent = result_doc("b_recent.rs", 1, 2, 0.8)?;
+        recent.document.metadata.mtime_ms = Some(reference_ms - DAY_MS);
+        let vectordb = Arc::new(TestVectorDb::new(vec![older, recent])?);
+        let embedding = Arc::new(TestEmbedding::new(vec![0.1, 0.2, 0.3])?);
+        let deps = SemanticSearchDeps {
+            embedding,
+            vectordb,
+            logger: None,
+            telemetry: None,
+            filesystem: None,
+        };
+
+        let ctx = RequestContext::new_request();
+        let input = |recency_boost| -> SharedResult<SemanticSearchInput> {
+            Ok(SemanticSearchInput {
+                codebase_root: "/tmp".into(),
+                collection_name: CollectionName::parse("code_chunks_test")
+                    .map_err(ErrorEnvelope::from)?,
+                index_mode: IndexMode::Dense,
+                query: "hello".into(),
+                query_template: None,
+                normalize_query: false,
+                top_k: Some(10),
+                threshold: Some(0.0),
+                query_vector: None,
+                include_histogram: false,
+                shard_count: None,
+                group_by_file: false,
+                exclusions: SearchExclusions::default(),
+                recency_boost,
+                context_lines: None,
+                on_progress: None,
+            })
+        };
+        let paths = |output: &SemanticSearchOutput| -> Vec<Box<str>> {
+            output
+                .results
+                .iter()
+                .map(|result| result.key.relative_path.clone())
+                .collect()
+        };
+
+        let unboosted = semantic_search(&ctx, &deps, input(None)?).await?;
+        assert_eq!(
+            paths(&unboosted),
+            vec!["a_old.rs".into(), "b_recent.rs".into()]
+        );
+
+        let boost = RecencyBoost {
+            half_life_ms: 30 * DAY_MS,
+            reference_ms,
+        };
+        let boosted = semantic_search(&ctx, &deps, input(Some(boost))?).await?;
+        assert_eq!(
+            paths(&boosted),
+            vec!["b_recent.rs".into(), "a_old.rs".into()]
+        );
+        let scores: Vec<f32> = boosted.results.iter().map(|result| result.score).collect();
+        assert!(scores.iter().all(|score| *score < 0.8));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn progress_callback_reports_phases_in_order() -> SharedResult<()> {
+        let results = vec![result_doc("a.rs", 1, 2, 0.9)?];
+        let vectordb = Arc::new(TestVectorDb::new(results)?);
+        let embedding = Arc::new(
+            TestEmbedding::new(vec![0.1, 0.2, 0.3])?
+                .with_delay(std::time::Duration::from_millis(20)),
+        );
+        let deps = SemanticSearchDeps {
+            embedding,
+            vectordb,
+            logger: None,
+            telemetry: None,
+            filesystem: None,
+        };
+        let phases = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&phases);
+
+        let ctx = RequestContext::new_request();
+        let input = SemanticSearchInput {
+            codebase_root: "/tmp".into(),
+            collection_name: CollectionName::parse("code_chunks_test")
+                .map_err(ErrorEnvelope::from)?,
+            index_mode: IndexMode::Dense,
+            query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
+            top_k: Some(1),
+            threshold: None,
+            query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: Some(Arc::new(move |phase| {
+                if let Ok(mut guard) = recorded.lock() {
+                    guard.push(phase);
+                }
+            })),
+        };
+
+        semantic_search(&ctx, &deps, input).await?;
+        let phases = phases
+            .lock()
+            .map_err(|_| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "progress lock poisoned",
+                    ErrorClass::NonRetriable,
+                )
+            })?
+            .clone();
+        assert_eq!(
+            phases,
+            vec![
+                SearchPhase::EmbeddingQuery,
+                SearchPhase::Searching,
+                SearchPhase::Reranking,
+                SearchPhase::Done,
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn normalize_query_embeds_case_and_accent_variants_identically() -> SharedResult<()> {
+        let vectordb = Arc::new(TestVectorDb::new(vec![result_doc("a.rs", 1, 2, 0.9)?])?);
+        let embedding = Arc::new(TestEmbedding::new(vec![0.1, 0.2, 0.3])?);
+        let deps = SemanticSearchDeps {
+            embedding: embedding.clone(),
+            vectordb,
+            logger: None,
+            telemetry: None,
+            filesystem: None,
+        };
+        let ctx = RequestContext::new_request();
+        let collection_name =
+            CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?;
+
+        for query in ["Café", "cafe"] {
+            let input = SemanticSearchInput {
+                codebase_root: "/tmp".into(),
+                collection_name: collection_name.clone(),
+                index_mode: IndexMode::Dense,
+                query: query.into(),
+                query_template: None,
+                normalize_query: true,
+                top_k: Some(1),
+                threshold: None,
+                query_vector: None,
+                include_histogram: false,
+                shard_count: None,
+                group_by_file: false,
+                exclusions: SearchExclusions::default(),
+                recency_boost: None,
+                context_lines: None,
+                on_progress: None,
+            };
+            semantic_search(&ctx, &deps, input).await?;
+        }
+
+        let inputs = embedding
+            .inputs
+            .lock()
+            .map_err(|_| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::internal(),
+                    "embedding inputs lock poisoned",
+                    ErrorClass::NonRetriable,
+                )
+            })?
+            .clone();
+        assert_eq!(inputs, vec![Box::<str>::from("cafe"), Box::from("cafe")]);
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_query_text_folds_case_and_diacritics() {
+        assert_eq!(&*normalize_query_text("naïve RÉSUMÉ"), "naive resume");
+        assert_eq!(&*normalize_query_text("plain ascii"), "plain ascii");
+    }
+
+    #[tokio::test]
+    async fn group_by_file_keeps_best_chunk_per_file() -> SharedResult<()> {
+        let results = vec![
+            result_doc("a.rs", 1, 2, 0.4)?,
+            result_doc("a.rs", 10, 12, 0.8)?,
+            result_doc("b.rs", 1, 2, 0.6)?,
+        ];
+        let vectordb = Arc::new(TestVectorDb::new(results)?);
+        let embedding = Arc::new(TestEmbedding::new(vec![0.1, 0.2, 0.3])?);
+        let deps = SemanticSearchDeps {
+            embedding,
+            vectordb: vectordb.clone(),
+            logger: None,
+            telemetry: None,
+            filesystem: None,
+        };
+
+        let ctx = RequestContext::new_request();
+        let input = SemanticSearchInput {
+            codebase_root: "/tmp".into(),
+            collection_name: CollectionName::parse("code_chunks_test")
+                .map_err(ErrorEnvelope::from)?,
+            index_mode: IndexMode::Dense,
+            query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
+            top_k: Some(2),
+            threshold: Some(0.0),
+            query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: true,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
+        };
+
+        let output = semantic_search(&ctx, &deps, input).await?;
+        let keys: Vec<(&str, u32)> = output
+            .results
+            .iter()
+            .map(|result| {
+                (
+                    result.key.relative_path.as_ref(),
+                    result.key.span.start_line(),
+                )
+            })
+            .collect();
+        assert_eq!(keys, vec![("a.rs", 10), ("b.rs", 1)]);
+        let options = vectordb.last_search_options()?.ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "missing search options",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        assert_eq!(options.top_k, Some(2 * GROUP_BY_FILE_OVERFETCH));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn threshold_is_forwarded_to_vectordb() -> SharedResult<()> {
         let vectordb = Arc::new(TestVectorDb::new(Vec::new())?);
@@ -909,6 +1568,7 @@ mod tests {
             vectordb: vectordb.clone(),
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -918,9 +1578,18 @@ mod tests {
                 .map_err(ErrorEnvelope::from)?,
             index_mode: IndexMode::Dense,
             query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: None,
             threshold: Some(0.7),
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let _ = semantic_search(&ctx, &deps, input).await?;
@@ -944,6 +1613,7 @@ mod tests {
             vectordb: vectordb.clone(),
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -955,9 +1625,18 @@ mod tests {
                 .map_err(ErrorEnvelope::from)?,
             index_mode: IndexMode::Dense,
             query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: None,
             threshold: None,
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let result = semantic_search(&ctx, &deps, input).await;
@@ -976,6 +1655,7 @@ mod tests {
             vectordb: vectordb.clone(),
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -985,9 +1665,18 @@ mod tests {
                 .map_err(ErrorEnvelope::from)?,
             index_mode: IndexMode::Hybrid,
             query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: Some(3),
             threshold: None,
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let output = semantic_search(&ctx, &deps, input).await?;
@@ -1032,6 +1721,7 @@ mod tests {
             vectordb,
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -1041,9 +1731,18 @@ mod tests {
                 .map_err(ErrorEnvelope::from)?,
             index_mode: IndexMode::Dense,
             query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: Some(3),
             threshold: Some(0.0),
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let output = semantic_search(&ctx, &deps, input).await?;
@@ -1059,4 +1758,78 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn score_histogram_counts_candidates_before_threshold_and_top_k() -> SharedResult<()> {
+        let results = vec![
+            result_doc("a.rs", 1, 2, -0.2)?,
+            result_doc("b.rs", 1, 2, 0.05)?,
+            result_doc("c.rs", 1, 2, 0.1)?,
+            result_doc("d.rs", 1, 2, 0.15)?,
+            result_doc("e.rs", 1, 2, 0.55)?,
+            result_doc("f.rs", 1, 2, 0.95)?,
+            result_doc("g.rs", 1, 2, 1.0)?,
+        ];
+        let vectordb = Arc::new(TestVectorDb::new(results)?);
+        let embedding = Arc::new(TestEmbedding::new(vec![0.1, 0.2, 0.3])?);
+        let deps = SemanticSearchDeps {
+            embedding,
+            vectordb: vectordb.clone(),
+            logger: None,
+            telemetry: None,
+            filesystem: None,
+        };
+
+        let ctx = RequestContext::new_request();
+        let input = SemanticSearchInput {
+            codebase_root: "/tmp".into(),
+            collection_name: CollectionName::parse("code_chunks_test")
+                .map_err(ErrorEnvelope::from)?,
+            index_mode: IndexMode::Dense,
+            query: "hello".into(),
+            query_template: None,
+            normalize_query: false,
+            top_k: Some(2),
+            threshold: Some(0.5),
+            query_vector: None,
+            include_histogram: true,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
+        };
+
+        let output = semantic_search(&ctx, &deps, input).await?;
+        assert!(output.stats.is_none());
+        let diagnostics = output.diagnostics.ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "missing search diagnostics",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        assert_eq!(
+            diagnostics.score_histogram,
+            Some([2, 2, 0, 0, 0, 1, 0, 0, 0, 2])
+        );
+
+        let options = vectordb.last_search_options()?.ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "missing search options",
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        assert_eq!(options.threshold, None);
+        assert_eq!(options.top_k, Some(HISTOGRAM_MIN_CANDIDATES));
+        let paths: Vec<&str> = output
+            .results
+            .iter()
+            .map(|result| result.key.relative_path.as_ref())
+            .collect();
+        assert_eq!(paths, vec!["g.rs", "f.rs"]);
+        Ok(())
+    }
 }
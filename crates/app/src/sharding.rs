@@ -0,0 +1,100 @@
+//! Deterministic routing of documents across sharded collections.
+//!
+//! A sharded index stores each document in `<collection>_shard_<n>`, where
+//! `n` is derived from a stable hash of the document id. Indexing and search
+//! share these helpers so both sides agree on the shard layout.
+
+use semantic_code_domain::CollectionName;
+use semantic_code_shared::{ErrorCode, ErrorEnvelope, Result};
+
+const SHARD_SUFFIX: &str = "_shard_";
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Reject a zero shard count; `None` means the index is not sharded.
+pub fn validate_shard_count(shard_count: Option<u32>) -> Result<()> {
+    if shard_count == Some(0) {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "shard count must be at least 1",
+        )
+        .with_metadata("field", "shardCount"));
+    }
+    Ok(())
+}
+
+/// Return the shard index for a document id.
+///
+/// Uses FNV-1a so the mapping is stable across processes and toolchains.
+pub fn shard_for_id(id: &str, shard_count: u32) -> u32 {
+    let hash = id.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    let shard = hash % u64::from(shard_count.max(1));
+    u32::try_from(shard).unwrap_or(0)
+}
+
+/// Build the collection name for a single shard.
+pub fn shard_collection_name(base: &CollectionName, shard: u32) -> Result<CollectionName> {
+    CollectionName::parse(format!("{}{SHARD_SUFFIX}{shard}", base.as_str()))
+        .map_err(ErrorEnvelope::from)
+}
+
+/// Build the collection names for every shard, ordered by shard index.
+pub fn shard_collection_names(
+    base: &CollectionName,
+    shard_count: u32,
+) -> Result<Vec<CollectionName>> {
+    (0..shard_count)
+        .map(|shard| shard_collection_name(base, shard))
+        .collect()
+}
+
+/// Resolve the collections an index occupies: every shard when sharded,
+/// otherwise the base collection alone.
+pub fn index_collection_names(
+    base: &CollectionName,
+    shard_count: Option<u32>,
+) -> Result<Vec<CollectionName>> {
+    validate_shard_count(shard_count)?;
+    shard_count.map_or_else(
+        || Ok(vec![base.clone()]),
+        |shard_count| shard_collection_names(base, shard_count),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_route_to_expected_shards() -> Result<()> {
+        let base = CollectionName::parse("code_chunks_test").map_err(ErrorEnvelope::from)?;
+
+        let first = shard_for_id("chunk_0001", 4);
+        let second = shard_for_id("chunk_0002", 4);
+        assert_eq!(first, 0);
+        assert_eq!(second, 1);
+        assert_eq!(shard_for_id("chunk_0001", 4), first);
+
+        assert_eq!(
+            shard_collection_name(&base, first)?.as_str(),
+            "code_chunks_test_shard_0"
+        );
+        assert_eq!(
+            shard_collection_name(&base, second)?.as_str(),
+            "code_chunks_test_shard_1"
+        );
+        assert_eq!(shard_collection_names(&base, 4)?.len(), 4);
+        assert_eq!(index_collection_names(&base, Some(4))?.len(), 4);
+        assert_eq!(index_collection_names(&base, None)?, vec![base]);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_shard_count_is_rejected() {
+        assert!(validate_shard_count(Some(0)).is_err());
+        assert!(validate_shard_count(Some(1)).is_ok());
+        assert!(validate_shard_count(None).is_ok());
+    }
+}
@@ -0,0 +1,589 @@
+//! Verify that an index collection matches its on-disk snapshots.
+
+use crate::sharding::index_collection_names;
+use semantic_code_domain::CollectionName;
+use semantic_code_ports::{LoggerPort, TelemetryPort, VectorDbPort};
+use semantic_code_shared::{RequestContext, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Maximum number of dangling paths echoed back in a report.
+const DANGLING_SAMPLE_LIMIT: usize = 10;
+
+/// Input payload for verifying an index.
+#[derive(Debug, Clone)]
+pub struct VerifyIndexInput {
+    /// Target collection name.
+    pub collection_name: CollectionName,
+    /// Shard count the index was built with; counts and dimensions are
+    /// checked across every shard collection.
+    pub shard_count: Option<u32>,
+    /// Document count recorded by the vector snapshot, when available.
+    pub expected_count: Option<u64>,
+    /// Vector dimension recorded by the vector snapshot, when available.
+    pub expected_dimension: Option<u32>,
+    /// Relative paths tracked by the sync snapshot.
+    ///
+    /// When provided, indexed documents whose `relativePath` is not tracked are
+    /// reported as dangling. Callers should only set this for providers where a
+    /// full scan is cheap.
+    pub tracked_paths: Option<BTreeSet<Box<str>>>,
+}
+
+/// Dependencies required by verify-index.
+#[derive(Clone)]
+pub struct VerifyIndexDeps {
+    /// Vector DB adapter.
+    pub vectordb: Arc<dyn VectorDbPort>,
+    /// Optional logger.
+    pub logger: Option<Arc<dyn LoggerPort>>,
+    /// Optional telemetry sink.
+    pub telemetry: Option<Arc<dyn TelemetryPort>>,
+}
+
+/// Overall verification outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyStatus {
+    /// Snapshot and vector DB agree.
+    Ok,
+    /// At least one mismatch was detected.
+    Drift,
+}
+
+/// A single mismatch between snapshot and vector DB state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyDrift {
+    /// The snapshot exists but the collection is missing from the vector DB.
+    MissingCollection,
+    /// Document counts differ.
+    CountMismatch {
+        /// Count recorded by the snapshot.
+        expected: u64,
+        /// Count reported by the vector DB.
+        actual: u64,
+    },
+    /// Vector dimensions differ.
+    DimensionMismatch {
+        /// Dimension recorded by the snapshot.
+        expected: u32,
+        /// Dimension reported by the vector DB.
+        actual: u32,
+    },
+    /// Indexed documents reference paths the sync snapshot does not track.
+    DanglingDocuments {
+        /// Number of dangling documents.
+        count: u64,
+        /// Sample of dangling relative paths (bounded).
+        sample: Vec<Box<str>>,
+    },
+}
+
+/// Structured result of an index verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// Verified collection name.
+    pub collection_name: CollectionName,
+    /// Overall status.
+    pub status: VerifyStatus,
+    /// Whether the collection (any shard, for a sharded index) exists in the
+    /// vector DB.
+    pub collection_exists: bool,
+    /// Document count reported by the vector DB, when supported.
+    pub vector_count: Option<u64>,
+    /// Vector dimension reported by the vector DB, when supported.
+    pub vector_dimension: Option<u32>,
+    /// Detected mismatches (empty when status is `Ok`).
+    pub drift: Vec<VerifyDrift>,
+}
+
+/// Compare snapshot expectations against the vector DB without reindexing.
+#[tracing::instrument(
+    name = "app.verify_index",
+    skip_all,
+    fields(collection = %input.collection_name.as_str())
+)]
+pub async fn verify_index(
+    ctx: &RequestContext,
+    deps: &VerifyIndexDeps,
+    input: VerifyIndexInput,
+) -> Result<VerifyReport> {
+    let started_at = Instant::now();
+    let total_timer = deps
+        .telemetry
+        .as_ref()
+        .map(|telemetry| telemetry.start_timer("backend.verifyIndex.total", None));
+
+    let result = verify_index_inner(ctx, deps, &input).await;
+
+    if let Some(timer) = total_timer.as_ref() {
+        timer.stop();
+    }
+
+    match result {
+        Ok(report) => {
+            if let Some(telemetry) = deps.telemetry.as_ref() {
+                let counter = match report.status {
+                    VerifyStatus::Ok => "backend.verifyIndex.ok",
+                    VerifyStatus::Drift => "backend.verifyIndex.drift",
+                };
+                telemetry.increment_counter(counter, 1, None);
+            }
+            if let Some(logger) = deps.logger.as_ref() {
+                logger.info(
+                    "backend.verifyIndex.completed",
+                    "Verify index completed",
+                    Some(log_fields_completed(&report, started_at)),
+                );
+            }
+            Ok(report)
+        },
+        Err(error) => {
+            if let Some(telemetry) = deps.telemetry.as_ref() {
+                telemetry.increment_counter("backend.verifyIndex.failed", 1, None);
+            }
+            tracing::warn!(
+                error = %error,
+                cancelled = error.is_cancelled(),
+                "verify index failed"
+            );
+            Err(error)
+        },
+    }
+}
+
+async fn verify_index_inner(
+    ctx: &RequestContext,
+    deps: &VerifyIndexDeps,
+    input: &VerifyIndexInput,
+) -> Result<VerifyReport> {
+    ctx.ensure_not_cancelled("verify_index.start")?;
+    let collection_name = input.collection_name.clone();
+    let mut drift = Vec::new();
+
+    let mut collections = Vec::new();
+    for shard_collection in index_collection_names(&collection_name, input.shard_count)? {
+        if deps
+            .vectordb
+            .has_collection(ctx, shard_collection.clone())
+            .await?
+        {
+            collections.push(shard_collection);
+        }
+    }
+    if collections.is_empty() {
+        if input.expected_count.is_some() || input.expected_dimension.is_some() {
+            drift.push(VerifyDrift::MissingCollection);
+        }
+        return Ok(build_report(collection_name, false, None, None, drift));
+    }
+
+    ctx.ensure_not_cancelled("verify_index.count")?;
+    let vector_count = count_documents(ctx, deps, &collections).await?;
+    if let (Some(expected), Some(actual)) = (input.expected_count, vector_count)
+        && expected != actual
+    {
+        drift.push(VerifyDrift::CountMismatch { expected, actual });
+    }
+
+    ctx.ensure_not_cancelled("verify_index.dimension")?;
+    let mut vector_dimension = None;
+    for shard_collection in &collections {
+        let actual = deps
+            .vectordb
+            .collection_dimension(ctx, shard_collection.clone())
+            .await?;
+        vector_dimension = vector_dimension.or(actual);
+        if let (Some(expected), Some(actual)) = (input.expected_dimension, actual)
+            && expected != actual
+        {
+            drift.push(VerifyDrift::DimensionMismatch { expected, actual });
+            break;
+        }
+    }
+
+    if let Some(tracked_paths) = input.tracked_paths.as_ref() {
+        ctx.ensure_not_cancelled("verify_index.dangling")?;
+        if let Some(dangling) =
+            find_dangling_documents(ctx, deps, &collections, tracked_paths).await?
+        {
+            drift.push(dangling);
+        }
+    }
+
+    Ok(build_report(
+        collection_name,
+        true,
+        vector_count,
+        vector_dimension,
+        drift,
+    ))
+}
+
+/// Sum document counts across `collections`; `None` when any backend
+/// collection does not report a count.
+async fn count_documents(
+    ctx: &RequestContext,
+    deps: &VerifyIndexDeps,
+    collections: &[CollectionName],
+) -> Result<Option<u64>> {
+    let mut total = 0_u64;
+    for collection_name in collections {
+        let Some(count) = deps.vectordb.count(ctx, collection_name.clone()).await? else {
+            return Ok(None);
+        };
+        total = total.saturating_add(count);
+    }
+    Ok(Some(total))
+}
+
+async fn find_dangling_documents(
+    ctx: &RequestContext,
+    deps: &VerifyIndexDeps,
+    collections: &[CollectionName],
+    tracked_paths: &BTreeSet<Box<str>>,
+) -> Result<Option<VerifyDrift>> {
+    let mut count = 0_u64;
+    let mut sample = BTreeSet::new();
+    for collection_name in collections {
+        let rows = deps
+            .vectordb
+            .query(
+                ctx,
+                collection_name.clone(),
+                Box::from(""),
+                vec![Box::from("relativePath")],
+                None,
+            )
+            .await?;
+        for row in rows {
+            let Some(path) = row.get("relativePath").and_then(Value::as_str) else {
+                continue;
+            };
+            if tracked_paths.contains(path) {
+                continue;
+            }
+            count = count.saturating_add(1);
+            if sample.len() < DANGLING_SAMPLE_LIMIT {
+                sample.insert(Box::<str>::from(path));
+            }
+        }
+    }
+
+    if count == 0 {
+        return Ok(None);
+    }
+    Ok(Some(VerifyDrift::DanglingDocuments {
+        count,
+        sample: sample.into_iter().collect(),
+    }))
+}
+
+const fn build_report(
+    collection_name: CollectionName,
+    collection_exists: bool,
+    vector_count: Option<u64>,
+    vector_dimension: Option<u32>,
+    drift: Vec<VerifyDrift>,
+) -> VerifyReport {
+    let status = if drift.is_empty() {
+        VerifyStatus::Ok
+    } else {
+        VerifyStatus::Drift
+    };
+    VerifyReport {
+        collection_name,
+        status,
+        collection_exists,
+        vector_count,
+        vector_dimension,
+        drift,
+    }
+}
+
+fn duration_ms(started_at: Instant) -> u64 {
+    u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+fn log_fields_completed(report: &VerifyReport, started_at: Instant) -> BTreeMap<Box<str>, Value> {
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "collectionName".to_owned().into_boxed_str(),
+        Value::String(report.collection_name.as_str().to_owned()),
+    );
+    fields.insert(
+        "status".to_owned().into_boxed_str(),
+        Value::String(
+            match report.status {
+                VerifyStatus::Ok => "ok",
+                VerifyStatus::Drift => "drift",
+            }
+            .to_owned(),
+        ),
+    );
+    fields.insert(
+        "driftCount".to_owned().into_boxed_str(),
+        Value::from(report.drift.len()),
+    );
+    fields.insert(
+        "durationMs".to_owned().into_boxed_str(),
+        Value::from(duration_ms(started_at)),
+    );
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semantic_code_domain::VectorDbProviderId;
+    use semantic_code_ports::{
+        VectorDbProviderInfo, VectorDbRow, VectorDocumentForInsert, VectorSearchResponse,
+    };
+    use semantic_code_shared::ErrorEnvelope;
+
+    #[derive(Clone)]
+    struct FixedStateVectorDb {
+        provider: VectorDbProviderInfo,
+        count: u64,
+        dimension: u32,
+        paths: Vec<&'static str>,
+    }
+
+    impl FixedStateVectorDb {
+        fn new(count: u64, dimension: u32, paths: Vec<&'static str>) -> Result<Self> {
+            Ok(Self {
+                provider: VectorDbProviderInfo {
+                    id: VectorDbProviderId::parse("local").map_err(ErrorEnvelope::from)?,
+                    name: "fixed-state".into(),
+                },
+                count,
+                dimension,
+                paths,
+            })
+        }
+    }
+
+    impl VectorDbPort for FixedStateVectorDb {
+        fn provider(&self) -> &VectorDbProviderInfo {
+            &self.provider
+        }
+
+        fn create_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn create_hybrid_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _dimension: u32,
+            _description: Option<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn drop_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn has_collection(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<bool>> {
+            Box::pin(async move { Ok(true) })
+        }
+
+        fn collection_dimension(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u32>>> {
+            let dimension = self.dimension;
+            Box::pin(async move { Ok(Some(dimension)) })
+        }
+
+        fn count(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Option<u64>>> {
+            let count = self.count;
+            Box::pin(async move { Ok(Some(count)) })
+        }
+
+        fn list_collections(
+            &self,
+            _ctx: &RequestContext,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<CollectionName>>> {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn insert(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _documents: Vec<VectorDocumentForInsert>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn insert_hybrid(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _documents: Vec<VectorDocumentForInsert>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn search(
+            &self,
+            _ctx: &RequestContext,
+            _request: semantic_code_ports::VectorSearchRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<VectorSearchResponse>> {
+            Box::pin(async move {
+                Ok(VectorSearchResponse {
+                    results: Vec::new(),
+                    stats: None,
+                })
+            })
+        }
+
+        fn hybrid_search(
+            &self,
+            _ctx: &RequestContext,
+            _request: semantic_code_ports::HybridSearchBatchRequest,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<semantic_code_ports::HybridSearchResult>>>
+        {
+            Box::pin(async move { Ok(Vec::new()) })
+        }
+
+        fn delete(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _ids: Vec<Box<str>>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<()>> {
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn query(
+            &self,
+            _ctx: &RequestContext,
+            _collection_name: CollectionName,
+            _filter: Box<str>,
+            _output_fields: Vec<Box<str>>,
+            _limit: Option<u32>,
+        ) -> semantic_code_ports::BoxFuture<'_, Result<Vec<VectorDbRow>>> {
+            let rows = self
+                .paths
+                .iter()
+                .map(|path| {
+                    let mut row = VectorDbRow::new();
+                    row.insert("relativePath".into(), Value::String((*path).to_owned()));
+                    row
+                })
+                .collect();
+            Box::pin(async move { Ok(rows) })
+        }
+    }
+
+    fn deps(vectordb: FixedStateVectorDb) -> VerifyIndexDeps {
+        VerifyIndexDeps {
+            vectordb: Arc::new(vectordb),
+            logger: None,
+            telemetry: None,
+        }
+    }
+
+    fn tracked(paths: &[&str]) -> BTreeSet<Box<str>> {
+        paths.iter().map(|path| Box::<str>::from(*path)).collect()
+    }
+
+    #[tokio::test]
+    async fn verify_index_reports_ok_for_matching_state() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let deps = deps(FixedStateVectorDb::new(2, 8, vec!["src/a.rs", "src/b.rs"])?);
+        let input = VerifyIndexInput {
+            collection_name: CollectionName::parse("code_chunks_verify")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
+            expected_count: Some(2),
+            expected_dimension: Some(8),
+            tracked_paths: Some(tracked(&["src/a.rs", "src/b.rs"])),
+        };
+
+        let report = verify_index(&ctx, &deps, input).await?;
+        assert_eq!(report.status, VerifyStatus::Ok);
+        assert!(report.collection_exists);
+        assert_eq!(report.vector_count, Some(2));
+        assert_eq!(report.vector_dimension, Some(8));
+        assert!(report.drift.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_index_reports_count_mismatch() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let deps = deps(FixedStateVectorDb::new(3, 8, vec!["src/a.rs"])?);
+        let input = VerifyIndexInput {
+            collection_name: CollectionName::parse("code_chunks_verify")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
+            expected_count: Some(5),
+            expected_dimension: Some(8),
+            tracked_paths: None,
+        };
+
+        let report = verify_index(&ctx, &deps, input).await?;
+        assert_eq!(report.status, VerifyStatus::Drift);
+        assert_eq!(
+            report.drift,
+            vec![VerifyDrift::CountMismatch {
+                expected: 5,
+                actual: 3,
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_index_reports_dangling_documents() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let deps = deps(FixedStateVectorDb::new(
+            2,
+            8,
+            vec!["src/a.rs", "src/gone.rs"],
+        )?);
+        let input = VerifyIndexInput {
+            collection_name: CollectionName::parse("code_chunks_verify")
+                .map_err(ErrorEnvelope::from)?,
+            shard_count: None,
+            expected_count: None,
+            expected_dimension: None,
+            tracked_paths: Some(tracked(&["src/a.rs"])),
+        };
+
+        let report = verify_index(&ctx, &deps, input).await?;
+        assert_eq!(
+            report.drift,
+            vec![VerifyDrift::DanglingDocuments {
+                count: 1,
+                sample: vec!["src/gone.rs".into()],
+            }]
+        );
+        Ok(())
+    }
+}
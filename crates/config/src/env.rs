@@ -6,16 +6,20 @@
 //! - safe (secret values are redacted in error metadata)
 
 use crate::schema::{
-    BackendConfig, DfrrBq1Threshold, DfrrSearchConfig, EmbeddingCacheDiskProvider,
-    EmbeddingRoutingMode, ValidatedBackendConfig, VectorKernelKind, VectorSearchStrategy,
+    BackendConfig, ConfigWarning, DfrrBq1Threshold, DfrrSearchConfig, EmbeddingCacheDiskProvider,
+    EmbeddingRoutingMode, OnnxExecutionProvider, ValidatedBackendConfig, VectorKernelKind,
+    VectorSearchStrategy,
 };
 use crate::storage::VectorSnapshotFormat;
-use semantic_code_domain::IndexMode;
+use semantic_code_domain::{IndexMode, OversizeChunkPolicy, PathNormalization};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, REDACTED_VALUE, SecretString, is_secret_key};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use url::Url;
 
+/// Suffix of the companion var naming a file that holds a secret (e.g. `OPENAI_API_KEY_FILE`).
+pub const SECRET_FILE_SUFFIX: &str = "_FILE";
+
 /// Env var: core timeout in milliseconds.
 pub const ENV_CORE_TIMEOUT_MS: &str = "SCA_CORE_TIMEOUT_MS";
 /// Env var: core max concurrency.
@@ -41,6 +45,8 @@ pub const ENV_CORE_RETRY_BASE_DELAY_MS: &str = "SCA_CORE_RETRY_BASE_DELAY_MS";
 pub const ENV_CORE_RETRY_MAX_DELAY_MS: &str = "SCA_CORE_RETRY_MAX_DELAY_MS";
 /// Env var: retry jitter ratio percent.
 pub const ENV_CORE_RETRY_JITTER_RATIO_PCT: &str = "SCA_CORE_RETRY_JITTER_RATIO_PCT";
+/// Env var: deterministic seed for randomized behavior.
+pub const ENV_CORE_SEED: &str = "SCA_CORE_SEED";
 
 /// Env var: embedding provider identifier.
 pub const ENV_EMBEDDING_PROVIDER: &str = "SCA_EMBEDDING_PROVIDER";
@@ -58,6 +64,10 @@ pub const ENV_EMBEDDING_TIMEOUT_MS_ALIAS: &str = "EMBEDDING_TIMEOUT_MS";
 pub const ENV_EMBEDDING_BATCH_SIZE: &str = "SCA_EMBEDDING_BATCH_SIZE";
 /// Env var: embedding batch size (alias).
 pub const ENV_EMBEDDING_BATCH_SIZE_ALIAS: &str = "EMBEDDING_BATCH_SIZE";
+/// Env var: max back-to-back failed embedding batches tolerated while indexing.
+pub const ENV_EMBEDDING_MAX_CONSECUTIVE_FAILURES: &str = "SCA_EMBEDDING_MAX_CONSECUTIVE_FAILURES";
+/// Env var: max failed embedding batches tolerated per index run.
+pub const ENV_EMBEDDING_MAX_TOTAL_FAILURES: &str = "SCA_EMBEDDING_MAX_TOTAL_FAILURES";
 /// Env var: embedding dimension override.
 pub const ENV_EMBEDDING_DIMENSION: &str = "SCA_EMBEDDING_DIMENSION";
 /// Env var: embedding dimension override (alias).
@@ -125,6 +135,18 @@ pub const ENV_EMBEDDING_ONNX_DOWNLOAD_ALIAS: &str = "EMBEDDING_ONNX_DOWNLOAD";
 pub const ENV_EMBEDDING_ONNX_SESSION_POOL_SIZE: &str = "SCA_EMBEDDING_ONNX_SESSION_POOL_SIZE";
 /// Env var: ONNX session pool size (alias).
 pub const ENV_EMBEDDING_ONNX_SESSION_POOL_SIZE_ALIAS: &str = "EMBEDDING_ONNX_SESSION_POOL_SIZE";
+/// Env var: ONNX intra-op thread count.
+pub const ENV_EMBEDDING_ONNX_INTRA_OP_THREADS: &str = "SCA_EMBEDDING_ONNX_INTRA_OP_THREADS";
+/// Env var: ONNX intra-op thread count (alias).
+pub const ENV_EMBEDDING_ONNX_INTRA_OP_THREADS_ALIAS: &str = "EMBEDDING_ONNX_INTRA_OP_THREADS";
+/// Env var: ONNX inter-op thread count.
+pub const ENV_EMBEDDING_ONNX_INTER_OP_THREADS: &str = "SCA_EMBEDDING_ONNX_INTER_OP_THREADS";
+/// Env var: ONNX inter-op thread count (alias).
+pub const ENV_EMBEDDING_ONNX_INTER_OP_THREADS_ALIAS: &str = "EMBEDDING_ONNX_INTER_OP_THREADS";
+/// Env var: ONNX execution provider.
+pub const ENV_EMBEDDING_ONNX_EP: &str = "SCA_EMBEDDING_ONNX_EP";
+/// Env var: ONNX execution provider (alias).
+pub const ENV_EMBEDDING_ONNX_EP_ALIAS: &str = "EMBEDDING_ONNX_EP";
 /// Env var: embedding cache enabled.
 pub const ENV_EMBEDDING_CACHE_ENABLED: &str = "SCA_EMBEDDING_CACHE_ENABLED";
 /// Env var: embedding cache max entries.
@@ -166,6 +188,14 @@ pub const ENV_VOYAGE_MODEL: &str = "VOYAGEAI_MODEL";
 pub const ENV_OLLAMA_MODEL: &str = "OLLAMA_MODEL";
 /// Env var: Ollama host URL.
 pub const ENV_OLLAMA_HOST: &str = "OLLAMA_HOST";
+/// Env var: Azure OpenAI API key (secret).
+pub const ENV_AZURE_OPENAI_API_AUTH: &str = "AZURE_OPENAI_API_KEY";
+/// Env var: Azure OpenAI resource endpoint.
+pub const ENV_AZURE_OPENAI_ENDPOINT: &str = "AZURE_OPENAI_ENDPOINT";
+/// Env var: Azure OpenAI embedding deployment name.
+pub const ENV_AZURE_OPENAI_DEPLOYMENT: &str = "AZURE_OPENAI_DEPLOYMENT";
+/// Env var: Azure OpenAI REST API version.
+pub const ENV_AZURE_OPENAI_API_VERSION: &str = "AZURE_OPENAI_API_VERSION";
 
 /// Env var: vector DB provider identifier.
 pub const ENV_VECTOR_DB_PROVIDER: &str = "SCA_VECTOR_DB_PROVIDER";
@@ -177,10 +207,16 @@ pub const ENV_VECTOR_DB_TIMEOUT_MS: &str = "SCA_VECTOR_DB_TIMEOUT_MS";
 pub const ENV_VECTOR_DB_INDEX_TIMEOUT_MS: &str = "SCA_VECTOR_DB_INDEX_TIMEOUT_MS";
 /// Env var: vector DB batch size.
 pub const ENV_VECTOR_DB_BATCH_SIZE: &str = "SCA_VECTOR_DB_BATCH_SIZE";
+/// Env var: vector DB shard count.
+pub const ENV_VECTOR_DB_SHARD_COUNT: &str = "SCA_VECTOR_DB_SHARD_COUNT";
+/// Env var: max values in one search filter membership list.
+pub const ENV_VECTOR_DB_MAX_FILTER_VALUES: &str = "SCA_VECTOR_DB_MAX_FILTER_VALUES";
 /// Env var: vector DB local snapshot format (`v1` | `v2`).
 pub const ENV_VECTOR_DB_SNAPSHOT_FORMAT: &str = "SCA_VECTOR_DB_SNAPSHOT_FORMAT";
 /// Env var: max bytes allowed per local snapshot write.
 pub const ENV_VECTOR_DB_SNAPSHOT_MAX_BYTES: &str = "SCA_VECTOR_DB_SNAPSHOT_MAX_BYTES";
+/// Env var: gzip-compress local v1 JSON snapshots.
+pub const ENV_VECTOR_DB_SNAPSHOT_GZIP: &str = "SCA_VECTOR_DB_SNAPSHOT_GZIP";
 /// Env var: local vector search strategy.
 pub const ENV_VECTOR_DB_SEARCH_STRATEGY: &str = "SCA_VECTOR_DB_SEARCH_STRATEGY";
 /// Env var: local vector kernel family.
@@ -196,6 +232,8 @@ pub const ENV_VECTOR_DB_BASE_URL: &str = "SCA_VECTOR_DB_BASE_URL";
 pub const ENV_VECTOR_DB_ADDRESS: &str = "SCA_VECTOR_DB_ADDRESS";
 /// Env var: vector DB database name.
 pub const ENV_VECTOR_DB_DATABASE: &str = "SCA_VECTOR_DB_DATABASE";
+/// Env var: collection naming template (e.g. `team_{chunks}_{hash}`).
+pub const ENV_VECTOR_DB_COLLECTION_TEMPLATE: &str = "SCA_VECTOR_DB_COLLECTION_TEMPLATE";
 /// Env var: vector DB SSL enablement (true/false).
 pub const ENV_VECTOR_DB_SSL: &str = "SCA_VECTOR_DB_SSL";
 /// Env var: vector DB auth token (secret).
@@ -230,8 +268,27 @@ pub const ENV_SYNC_MAX_FILES: &str = "SCA_SYNC_MAX_FILES";
 pub const ENV_SYNC_MAX_CHUNKS: &str = "SCA_SYNC_MAX_CHUNKS";
 /// Env var: sync max file size in bytes.
 pub const ENV_SYNC_MAX_FILE_SIZE_BYTES: &str = "SCA_SYNC_MAX_FILE_SIZE_BYTES";
+/// Env var: per-extension max file sizes as CSV of `ext=bytes` pairs.
+pub const ENV_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES: &str =
+    "SCA_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES";
+/// Env var: relative path normalization (`asis` or `lowercase`).
+pub const ENV_SYNC_PATH_NORMALIZATION: &str = "SCA_SYNC_PATH_NORMALIZATION";
+/// Env var: oversize chunk policy (`truncate`, `split`, `skip`, or `error`).
+pub const ENV_SYNC_OVERSIZE_CHUNK_POLICY: &str = "SCA_SYNC_OVERSIZE_CHUNK_POLICY";
 
 const MAX_CSV_ITEMS: usize = 10_000;
+/// Secret env vars that also accept a [`SECRET_FILE_SUFFIX`] companion.
+const SECRET_ENV_KEYS: &[&str] = &[
+    ENV_EMBEDDING_API_AUTH,
+    ENV_EMBEDDING_API_AUTH_ALIAS,
+    ENV_OPENAI_API_AUTH,
+    ENV_GEMINI_API_AUTH,
+    ENV_VOYAGE_API_AUTH,
+    ENV_AZURE_OPENAI_API_AUTH,
+    ENV_VECTOR_DB_TOKEN,
+    ENV_VECTOR_DB_PASSWORD,
+];
+
 const STD_ENV_KEYS: &[&str] = &[
     ENV_CORE_TIMEOUT_MS,
     ENV_CORE_MAX_CONCURRENCY,
@@ -245,6 +302,7 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_CORE_RETRY_BASE_DELAY_MS,
     ENV_CORE_RETRY_MAX_DELAY_MS,
     ENV_CORE_RETRY_JITTER_RATIO_PCT,
+    ENV_CORE_SEED,
     ENV_EMBEDDING_PROVIDER,
     ENV_EMBEDDING_PROVIDER_ALIAS,
     ENV_EMBEDDING_MODEL,
@@ -253,6 +311,8 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_EMBEDDING_TIMEOUT_MS_ALIAS,
     ENV_EMBEDDING_BATCH_SIZE,
     ENV_EMBEDDING_BATCH_SIZE_ALIAS,
+    ENV_EMBEDDING_MAX_CONSECUTIVE_FAILURES,
+    ENV_EMBEDDING_MAX_TOTAL_FAILURES,
     ENV_EMBEDDING_DIMENSION,
     ENV_EMBEDDING_DIMENSION_ALIAS,
     ENV_EMBEDDING_BASE_URL,
@@ -283,6 +343,12 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_EMBEDDING_ONNX_DOWNLOAD_ALIAS,
     ENV_EMBEDDING_ONNX_SESSION_POOL_SIZE,
     ENV_EMBEDDING_ONNX_SESSION_POOL_SIZE_ALIAS,
+    ENV_EMBEDDING_ONNX_INTRA_OP_THREADS,
+    ENV_EMBEDDING_ONNX_INTRA_OP_THREADS_ALIAS,
+    ENV_EMBEDDING_ONNX_INTER_OP_THREADS,
+    ENV_EMBEDDING_ONNX_INTER_OP_THREADS_ALIAS,
+    ENV_EMBEDDING_ONNX_EP,
+    ENV_EMBEDDING_ONNX_EP_ALIAS,
     ENV_EMBEDDING_CACHE_ENABLED,
     ENV_EMBEDDING_CACHE_MAX_ENTRIES,
     ENV_EMBEDDING_CACHE_MAX_BYTES,
@@ -305,13 +371,20 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_VOYAGE_MODEL,
     ENV_OLLAMA_MODEL,
     ENV_OLLAMA_HOST,
+    ENV_AZURE_OPENAI_API_AUTH,
+    ENV_AZURE_OPENAI_ENDPOINT,
+    ENV_AZURE_OPENAI_DEPLOYMENT,
+    ENV_AZURE_OPENAI_API_VERSION,
     ENV_VECTOR_DB_PROVIDER,
     ENV_VECTOR_DB_INDEX_MODE,
     ENV_VECTOR_DB_TIMEOUT_MS,
     ENV_VECTOR_DB_INDEX_TIMEOUT_MS,
     ENV_VECTOR_DB_BATCH_SIZE,
+    ENV_VECTOR_DB_SHARD_COUNT,
+    ENV_VECTOR_DB_MAX_FILTER_VALUES,
     ENV_VECTOR_DB_SNAPSHOT_FORMAT,
     ENV_VECTOR_DB_SNAPSHOT_MAX_BYTES,
+    ENV_VECTOR_DB_SNAPSHOT_GZIP,
     ENV_VECTOR_DB_VECTOR_KERNEL,
     ENV_VECTOR_DB_SEARCH_STRATEGY,
     ENV_VECTOR_DB_EXPERIMENTAL_U8_SEARCH,
@@ -321,6 +394,7 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_VECTOR_DB_BASE_URL,
     ENV_VECTOR_DB_ADDRESS,
     ENV_VECTOR_DB_DATABASE,
+    ENV_VECTOR_DB_COLLECTION_TEMPLATE,
     ENV_VECTOR_DB_SSL,
     ENV_VECTOR_DB_TOKEN,
     ENV_VECTOR_DB_USERNAME,
@@ -333,6 +407,9 @@ const STD_ENV_KEYS: &[&str] = &[
     ENV_SYNC_MAX_FILES,
     ENV_SYNC_MAX_CHUNKS,
     ENV_SYNC_MAX_FILE_SIZE_BYTES,
+    ENV_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES,
+    ENV_SYNC_PATH_NORMALIZATION,
+    ENV_SYNC_OVERSIZE_CHUNK_POLICY,
 ];
 
 /// Typed env-derived overrides for `BackendConfig`.
@@ -362,6 +439,8 @@ pub struct BackendEnv {
     pub core_retry_max_delay_ms: Option<u64>,
     /// Override for `core.retry.jitterRatioPct`.
     pub core_retry_jitter_ratio_pct: Option<u32>,
+    /// Override for `core.seed`.
+    pub core_seed: Option<u64>,
 
     /// Override for `embedding.provider`.
     pub embedding_provider: Option<Box<str>>,
@@ -371,6 +450,10 @@ pub struct BackendEnv {
     pub embedding_timeout_ms: Option<u64>,
     /// Override for `embedding.batchSize`.
     pub embedding_batch_size: Option<u32>,
+    /// Override for `embedding.maxConsecutiveFailures`.
+    pub embedding_max_consecutive_failures: Option<u32>,
+    /// Override for `embedding.maxTotalFailures`.
+    pub embedding_max_total_failures: Option<u32>,
     /// Override for `embedding.dimension`.
     pub embedding_dimension: Option<u32>,
     /// Override for `embedding.baseUrl`.
@@ -401,6 +484,12 @@ pub struct BackendEnv {
     pub embedding_onnx_download_on_missing: Option<bool>,
     /// Override for `embedding.onnx.sessionPoolSize`.
     pub embedding_onnx_session_pool_size: Option<u32>,
+    /// Override for `embedding.onnx.intraOpThreads`.
+    pub embedding_onnx_intra_op_threads: Option<u32>,
+    /// Override for `embedding.onnx.interOpThreads`.
+    pub embedding_onnx_inter_op_threads: Option<u32>,
+    /// Override for `embedding.onnx.executionProvider`.
+    pub embedding_onnx_execution_provider: Option<OnnxExecutionProvider>,
     /// Override for `embedding.cache.enabled`.
     pub embedding_cache_enabled: Option<bool>,
     /// Override for `embedding.cache.maxEntries`.
@@ -443,6 +532,14 @@ pub struct BackendEnv {
     pub ollama_model: Option<Box<str>>,
     /// Provider-specific Ollama host URL.
     pub ollama_host: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI API key (secret).
+    pub azure_openai_api_key: Option<SecretString>,
+    /// Provider-specific Azure OpenAI endpoint.
+    pub azure_openai_endpoint: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI deployment name.
+    pub azure_openai_deployment: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI API version.
+    pub azure_openai_api_version: Option<Box<str>>,
 
     /// Override for `vectorDb.provider`.
     pub vector_db_provider: Option<Box<str>>,
@@ -454,10 +551,16 @@ pub struct BackendEnv {
     pub vector_db_index_timeout_ms: Option<u64>,
     /// Override for `vectorDb.batchSize`.
     pub vector_db_batch_size: Option<u32>,
+    /// Override for `vectorDb.shardCount`.
+    pub vector_db_shard_count: Option<u32>,
+    /// Override for `vectorDb.maxFilterValues`.
+    pub vector_db_max_filter_values: Option<u32>,
     /// Override for `vectorDb.snapshotFormat`.
     pub vector_db_snapshot_format: Option<VectorSnapshotFormat>,
     /// Override for `vectorDb.snapshotMaxBytes`.
     pub vector_db_snapshot_max_bytes: Option<u64>,
+    /// Override for `vectorDb.snapshotGzip`.
+    pub vector_db_snapshot_gzip: Option<bool>,
     /// Override for `vectorDb.vectorKernel`.
     pub vector_db_vector_kernel: Option<VectorKernelKind>,
     /// Override for `vectorDb.searchStrategy`.
@@ -476,6 +579,8 @@ pub struct BackendEnv {
     pub vector_db_address: Option<Box<str>>,
     /// Override for `vectorDb.database`.
     pub vector_db_database: Option<Box<str>>,
+    /// Override for `vectorDb.collectionTemplate`.
+    pub vector_db_collection_template: Option<Box<str>>,
     /// Override for `vectorDb.ssl`.
     pub vector_db_ssl: Option<bool>,
     /// Secret: vector DB token (not persisted in config).
@@ -501,6 +606,12 @@ pub struct BackendEnv {
     pub sync_max_chunks: Option<u32>,
     /// Override for `sync.maxFileSizeBytes`.
     pub sync_max_file_size_bytes: Option<u64>,
+    /// Override for `sync.perExtensionMaxFileSizeBytes` (full replacement).
+    pub sync_per_extension_max_file_size_bytes: Option<BTreeMap<Box<str>, u64>>,
+    /// Override for `sync.pathNormalization`.
+    pub sync_path_normalization: Option<PathNormalization>,
+    /// Override for `sync.oversizeChunkPolicy`.
+    pub sync_oversize_chunk_policy: Option<OversizeChunkPolicy>,
 }
 
 #[expect(
@@ -520,6 +631,7 @@ struct CoreEnvOverrides {
     core_retry_base_delay_ms: Option<u64>,
     core_retry_max_delay_ms: Option<u64>,
     core_retry_jitter_ratio_pct: Option<u32>,
+    core_seed: Option<u64>,
 }
 
 struct EmbeddingEnvOverrides {
@@ -527,6 +639,8 @@ struct EmbeddingEnvOverrides {
     model: Option<Box<str>>,
     timeout_ms: Option<u64>,
     batch_size: Option<u32>,
+    max_consecutive_failures: Option<u32>,
+    max_total_failures: Option<u32>,
     dimension: Option<u32>,
     base_url: Option<Box<str>>,
     local_first: Option<bool>,
@@ -542,6 +656,9 @@ struct EmbeddingEnvOverrides {
     onnx_repo: Option<Box<str>>,
     onnx_download_on_missing: Option<bool>,
     onnx_session_pool_size: Option<u32>,
+    onnx_intra_op_threads: Option<u32>,
+    onnx_inter_op_threads: Option<u32>,
+    onnx_execution_provider: Option<OnnxExecutionProvider>,
     cache_enabled: Option<bool>,
     cache_max_entries: Option<u32>,
     cache_max_bytes: Option<u64>,
@@ -566,6 +683,10 @@ struct ProviderEnvOverrides {
     voyage_model: Option<Box<str>>,
     ollama_model: Option<Box<str>>,
     ollama_host: Option<Box<str>>,
+    azure_openai_api_key: Option<SecretString>,
+    azure_openai_endpoint: Option<Box<str>>,
+    azure_openai_deployment: Option<Box<str>>,
+    azure_openai_api_version: Option<Box<str>>,
 }
 
 struct VectorDbEnvOverrides {
@@ -574,8 +695,11 @@ struct VectorDbEnvOverrides {
     timeout_ms: Option<u64>,
     index_timeout_ms: Option<u64>,
     batch_size: Option<u32>,
+    shard_count: Option<u32>,
+    max_filter_values: Option<u32>,
     snapshot_format: Option<VectorSnapshotFormat>,
     snapshot_max_bytes: Option<u64>,
+    snapshot_gzip: Option<bool>,
     vector_kernel: Option<VectorKernelKind>,
     search_strategy: Option<VectorSearchStrategy>,
     experimental_u8_search: Option<bool>,
@@ -585,6 +709,7 @@ struct VectorDbEnvOverrides {
     base_url: Option<Box<str>>,
     address: Option<Box<str>>,
     database: Option<Box<str>>,
+    collection_template: Option<Box<str>>,
     ssl: Option<bool>,
     token: Option<SecretString>,
     username: Option<Box<str>>,
@@ -600,6 +725,9 @@ struct SyncEnvOverrides {
     max_files: Option<u32>,
     max_chunks: Option<u32>,
     max_file_size_bytes: Option<u64>,
+    per_extension_max_file_size_bytes: Option<BTreeMap<Box<str>, u64>>,
+    path_normalization: Option<PathNormalization>,
+    oversize_chunk_policy: Option<OversizeChunkPolicy>,
 }
 
 struct EmbeddingCoreEnvOverrides {
@@ -607,6 +735,8 @@ struct EmbeddingCoreEnvOverrides {
     model: Option<Box<str>>,
     timeout_ms: Option<u64>,
     batch_size: Option<u32>,
+    max_consecutive_failures: Option<u32>,
+    max_total_failures: Option<u32>,
     dimension: Option<u32>,
     base_url: Option<Box<str>>,
     local_first: Option<bool>,
@@ -625,6 +755,9 @@ struct EmbeddingOnnxEnvOverrides {
     repo: Option<Box<str>>,
     download_on_missing: Option<bool>,
     session_pool_size: Option<u32>,
+    intra_op_threads: Option<u32>,
+    inter_op_threads: Option<u32>,
+    execution_provider: Option<OnnxExecutionProvider>,
 }
 
 struct EmbeddingCacheEnvOverrides {
@@ -656,6 +789,7 @@ fn parse_core_env(map: &BTreeMap<String, String>) -> Result<CoreEnvOverrides, En
         core_retry_base_delay_ms: parse_optional_u64(map, ENV_CORE_RETRY_BASE_DELAY_MS)?,
         core_retry_max_delay_ms: parse_optional_u64(map, ENV_CORE_RETRY_MAX_DELAY_MS)?,
         core_retry_jitter_ratio_pct: parse_optional_u32(map, ENV_CORE_RETRY_JITTER_RATIO_PCT)?,
+        core_seed: parse_optional_u64(map, ENV_CORE_SEED)?,
     })
 }
 
@@ -670,6 +804,8 @@ fn parse_embedding_env(
         model: core.model,
         timeout_ms: core.timeout_ms,
         batch_size: core.batch_size,
+        max_consecutive_failures: core.max_consecutive_failures,
+        max_total_failures: core.max_total_failures,
         dimension: core.dimension,
         base_url: core.base_url,
         local_first: core.local_first,
@@ -685,6 +821,9 @@ fn parse_embedding_env(
         onnx_repo: onnx.repo,
         onnx_download_on_missing: onnx.download_on_missing,
         onnx_session_pool_size: onnx.session_pool_size,
+        onnx_intra_op_threads: onnx.intra_op_threads,
+        onnx_inter_op_threads: onnx.inter_op_threads,
+        onnx_execution_provider: onnx.execution_provider,
         cache_enabled: cache.enabled,
         cache_max_entries: cache.max_entries,
         cache_max_bytes: cache.max_bytes,
@@ -721,6 +860,8 @@ fn parse_embedding_core_env(
             map,
             &[ENV_EMBEDDING_BATCH_SIZE, ENV_EMBEDDING_BATCH_SIZE_ALIAS],
         )?,
+        max_consecutive_failures: parse_optional_u32(map, ENV_EMBEDDING_MAX_CONSECUTIVE_FAILURES)?,
+        max_total_failures: parse_optional_u32(map, ENV_EMBEDDING_MAX_TOTAL_FAILURES)?,
         dimension: parse_optional_u32_any(
             map,
             &[ENV_EMBEDDING_DIMENSION, ENV_EMBEDDING_DIMENSION_ALIAS],
@@ -815,6 +956,24 @@ fn parse_embedding_onnx_env(
                 ENV_EMBEDDING_ONNX_SESSION_POOL_SIZE_ALIAS,
             ],
         )?,
+        intra_op_threads: parse_optional_u32_any(
+            map,
+            &[
+                ENV_EMBEDDING_ONNX_INTRA_OP_THREADS,
+                ENV_EMBEDDING_ONNX_INTRA_OP_THREADS_ALIAS,
+            ],
+        )?,
+        inter_op_threads: parse_optional_u32_any(
+            map,
+            &[
+                ENV_EMBEDDING_ONNX_INTER_OP_THREADS,
+                ENV_EMBEDDING_ONNX_INTER_OP_THREADS_ALIAS,
+            ],
+        )?,
+        execution_provider: parse_optional_onnx_execution_provider_any(
+            map,
+            &[ENV_EMBEDDING_ONNX_EP, ENV_EMBEDDING_ONNX_EP_ALIAS],
+        )?,
     })
 }
 
@@ -849,6 +1008,10 @@ fn parse_provider_env(
         voyage_model: parse_optional_trimmed_string(map, ENV_VOYAGE_MODEL)?,
         ollama_model: parse_optional_trimmed_string(map, ENV_OLLAMA_MODEL)?,
         ollama_host: parse_optional_url_string(map, ENV_OLLAMA_HOST)?,
+        azure_openai_api_key: parse_optional_secret(map, ENV_AZURE_OPENAI_API_AUTH)?,
+        azure_openai_endpoint: parse_optional_url_string(map, ENV_AZURE_OPENAI_ENDPOINT)?,
+        azure_openai_deployment: parse_optional_trimmed_string(map, ENV_AZURE_OPENAI_DEPLOYMENT)?,
+        azure_openai_api_version: parse_optional_trimmed_string(map, ENV_AZURE_OPENAI_API_VERSION)?,
     })
 }
 
@@ -861,8 +1024,11 @@ fn parse_vectordb_env(
         timeout_ms: parse_optional_u64(map, ENV_VECTOR_DB_TIMEOUT_MS)?,
         index_timeout_ms: parse_optional_u64(map, ENV_VECTOR_DB_INDEX_TIMEOUT_MS)?,
         batch_size: parse_optional_u32(map, ENV_VECTOR_DB_BATCH_SIZE)?,
+        shard_count: parse_optional_u32(map, ENV_VECTOR_DB_SHARD_COUNT)?,
+        max_filter_values: parse_optional_u32(map, ENV_VECTOR_DB_MAX_FILTER_VALUES)?,
         snapshot_format: parse_optional_vector_snapshot_format(map, ENV_VECTOR_DB_SNAPSHOT_FORMAT)?,
         snapshot_max_bytes: parse_optional_u64(map, ENV_VECTOR_DB_SNAPSHOT_MAX_BYTES)?,
+        snapshot_gzip: parse_optional_bool(map, ENV_VECTOR_DB_SNAPSHOT_GZIP)?,
         vector_kernel: parse_optional_vector_kernel_kind(map, ENV_VECTOR_DB_VECTOR_KERNEL)?,
         search_strategy: parse_optional_vector_search_strategy(map, ENV_VECTOR_DB_SEARCH_STRATEGY)?,
         experimental_u8_search: parse_optional_bool(map, ENV_VECTOR_DB_EXPERIMENTAL_U8_SEARCH)?,
@@ -875,6 +1041,7 @@ fn parse_vectordb_env(
         base_url: parse_optional_url_string(map, ENV_VECTOR_DB_BASE_URL)?,
         address: parse_optional_trimmed_string(map, ENV_VECTOR_DB_ADDRESS)?,
         database: parse_optional_trimmed_string(map, ENV_VECTOR_DB_DATABASE)?,
+        collection_template: parse_optional_trimmed_string(map, ENV_VECTOR_DB_COLLECTION_TEMPLATE)?,
         ssl: parse_optional_bool(map, ENV_VECTOR_DB_SSL)?,
         token: parse_optional_secret(map, ENV_VECTOR_DB_TOKEN)?,
         username: parse_optional_trimmed_string(map, ENV_VECTOR_DB_USERNAME)?,
@@ -901,6 +1068,15 @@ fn parse_sync_env(map: &BTreeMap<String, String>) -> Result<SyncEnvOverrides, En
         max_files: parse_optional_u32(map, ENV_SYNC_MAX_FILES)?,
         max_chunks: parse_optional_u32(map, ENV_SYNC_MAX_CHUNKS)?,
         max_file_size_bytes: parse_optional_u64(map, ENV_SYNC_MAX_FILE_SIZE_BYTES)?,
+        per_extension_max_file_size_bytes: parse_optional_csv_size_map(
+            map,
+            ENV_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES,
+        )?,
+        path_normalization: parse_optional_path_normalization(map, ENV_SYNC_PATH_NORMALIZATION)?,
+        oversize_chunk_policy: parse_optional_oversize_chunk_policy(
+            map,
+            ENV_SYNC_OVERSIZE_CHUNK_POLICY,
+        )?,
     })
 }
 
@@ -926,10 +1102,13 @@ impl BackendEnv {
             core_retry_base_delay_ms: core.core_retry_base_delay_ms,
             core_retry_max_delay_ms: core.core_retry_max_delay_ms,
             core_retry_jitter_ratio_pct: core.core_retry_jitter_ratio_pct,
+            core_seed: core.core_seed,
             embedding_provider: embedding.provider,
             embedding_model: embedding.model,
             embedding_timeout_ms: embedding.timeout_ms,
             embedding_batch_size: embedding.batch_size,
+            embedding_max_consecutive_failures: embedding.max_consecutive_failures,
+            embedding_max_total_failures: embedding.max_total_failures,
             embedding_dimension: embedding.dimension,
             embedding_base_url: embedding.base_url,
             embedding_local_first: embedding.local_first,
@@ -945,6 +1124,9 @@ impl BackendEnv {
             embedding_onnx_repo: embedding.onnx_repo,
             embedding_onnx_download_on_missing: embedding.onnx_download_on_missing,
             embedding_onnx_session_pool_size: embedding.onnx_session_pool_size,
+            embedding_onnx_intra_op_threads: embedding.onnx_intra_op_threads,
+            embedding_onnx_inter_op_threads: embedding.onnx_inter_op_threads,
+            embedding_onnx_execution_provider: embedding.onnx_execution_provider,
             embedding_cache_enabled: embedding.cache_enabled,
             embedding_cache_max_entries: embedding.cache_max_entries,
             embedding_cache_max_bytes: embedding.cache_max_bytes,
@@ -966,13 +1148,20 @@ impl BackendEnv {
             voyage_model: providers.voyage_model,
             ollama_model: providers.ollama_model,
             ollama_host: providers.ollama_host,
+            azure_openai_api_key: providers.azure_openai_api_key,
+            azure_openai_endpoint: providers.azure_openai_endpoint,
+            azure_openai_deployment: providers.azure_openai_deployment,
+            azure_openai_api_version: providers.azure_openai_api_version,
             vector_db_provider: vectordb.provider,
             vector_db_index_mode: vectordb.index_mode,
             vector_db_timeout_ms: vectordb.timeout_ms,
             vector_db_index_timeout_ms: vectordb.index_timeout_ms,
             vector_db_batch_size: vectordb.batch_size,
+            vector_db_shard_count: vectordb.shard_count,
+            vector_db_max_filter_values: vectordb.max_filter_values,
             vector_db_snapshot_format: vectordb.snapshot_format,
             vector_db_snapshot_max_bytes: vectordb.snapshot_max_bytes,
+            vector_db_snapshot_gzip: vectordb.snapshot_gzip,
             vector_db_vector_kernel: vectordb.vector_kernel,
             vector_db_search_strategy: vectordb.search_strategy,
             vector_db_experimental_u8_search: vectordb.experimental_u8_search,
@@ -982,6 +1171,7 @@ impl BackendEnv {
             vector_db_base_url: vectordb.base_url,
             vector_db_address: vectordb.address,
             vector_db_database: vectordb.database,
+            vector_db_collection_template: vectordb.collection_template,
             vector_db_ssl: vectordb.ssl,
             vector_db_token: vectordb.token,
             vector_db_username: vectordb.username,
@@ -991,12 +1181,22 @@ impl BackendEnv {
                 .dfrr_bq1_percentile_assist_sample_count,
             vector_db_dfrr_bq1_percentile_assist_target_rank: vectordb
                 .dfrr_bq1_percentile_assist_target_rank,
+            ..Self::from_sync_overrides(sync)
+        })
+    }
+
+    fn from_sync_overrides(sync: SyncEnvOverrides) -> Self {
+        Self {
             sync_allowed_extensions: sync.allowed_extensions,
             sync_ignore_patterns: sync.ignore_patterns,
             sync_max_files: sync.max_files,
             sync_max_chunks: sync.max_chunks,
             sync_max_file_size_bytes: sync.max_file_size_bytes,
-        })
+            sync_per_extension_max_file_size_bytes: sync.per_extension_max_file_size_bytes,
+            sync_path_normalization: sync.path_normalization,
+            sync_oversize_chunk_policy: sync.oversize_chunk_policy,
+            ..Self::default()
+        }
     }
 
     /// Parse env overrides from the current process environment.
@@ -1007,6 +1207,12 @@ impl BackendEnv {
                 map.insert(name.to_string(), value);
             }
         }
+        for &name in SECRET_ENV_KEYS {
+            let file_var = secret_file_var(name);
+            if let Ok(value) = std::env::var(&file_var) {
+                map.insert(file_var, value);
+            }
+        }
 
         Self::from_map(&map)
     }
@@ -1018,14 +1224,144 @@ pub fn apply_env_overrides(
     env: &BackendEnv,
 ) -> Result<ValidatedBackendConfig, ErrorEnvelope> {
     let mut config = base;
-    apply_core_env_overrides(&mut config, env);
-    apply_embedding_env_overrides(&mut config, env);
-    apply_vector_db_env_overrides(&mut config, env);
-    apply_sync_env_overrides(&mut config, env);
+    apply_all_env_overrides(&mut config, env);
 
     config.validate_and_normalize().map_err(Into::into)
 }
 
+/// A config field whose file value was replaced by an env override.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvOverrideShadow {
+    /// Dotted config field path (e.g. `core.timeoutMs`).
+    pub field: Box<str>,
+    /// Value from the config file/overrides (redacted when the field is secret).
+    pub file_value: Box<str>,
+    /// Value set by env (redacted when the field is secret).
+    pub env_value: Box<str>,
+}
+
+impl From<EnvOverrideShadow> for ConfigWarning {
+    fn from(shadow: EnvOverrideShadow) -> Self {
+        Self {
+            message: format!(
+                "env override replaces config file value {} with {}",
+                shadow.file_value, shadow.env_value
+            )
+            .into_boxed_str(),
+            field: shadow.field,
+        }
+    }
+}
+
+/// Apply env overrides like [`apply_env_overrides`], also reporting shadowed fields.
+///
+/// A field is reported when the file/overrides set it away from its default and
+/// env then replaced it with a different value.
+pub fn apply_env_overrides_with_report(
+    base: BackendConfig,
+    env: &BackendEnv,
+) -> Result<(ValidatedBackendConfig, Vec<EnvOverrideShadow>), ErrorEnvelope> {
+    let defaults = flatten_config_values(&BackendConfig::default());
+    let file_values = flatten_config_values(&base);
+    let mut config = base;
+    apply_all_env_overrides(&mut config, env);
+    let env_values = flatten_config_values(&config);
+
+    let shadows = file_values
+        .iter()
+        .filter(|(field, file_value)| defaults.get(*field) != Some(*file_value))
+        .filter_map(|(field, file_value)| {
+            let env_value = env_values.get(field)?;
+            (env_value != file_value).then(|| EnvOverrideShadow {
+                field: field.as_str().into(),
+                file_value: redact_value(field, &render_config_value(file_value)).into(),
+                env_value: redact_value(field, &render_config_value(env_value)).into(),
+            })
+        })
+        .collect();
+
+    let validated = config.validate_and_normalize()?;
+    Ok((validated, shadows))
+}
+
+/// A config field whose value differs between two configs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFieldChange {
+    /// Dotted config field path (e.g. `core.timeoutMs`).
+    pub field: Box<str>,
+    /// Value in the base config (redacted when the field is secret).
+    pub before: Box<str>,
+    /// Value in the changed config (redacted when the field is secret).
+    pub after: Box<str>,
+}
+
+/// List the fields whose values differ between `base` and `changed`, sorted by path.
+///
+/// Fields present on only one side are compared against `null`.
+#[must_use]
+pub fn diff_config(base: &BackendConfig, changed: &BackendConfig) -> Vec<ConfigFieldChange> {
+    let before_values = flatten_config_values(base);
+    let after_values = flatten_config_values(changed);
+    let fields: BTreeSet<&String> = before_values.keys().chain(after_values.keys()).collect();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let before = before_values.get(field).unwrap_or(&serde_json::Value::Null);
+            let after = after_values.get(field).unwrap_or(&serde_json::Value::Null);
+            (before != after).then(|| ConfigFieldChange {
+                field: field.as_str().into(),
+                before: redact_value(field, &render_config_value(before)).into(),
+                after: redact_value(field, &render_config_value(after)).into(),
+            })
+        })
+        .collect()
+}
+
+fn apply_all_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
+    apply_core_env_overrides(config, env);
+    apply_embedding_env_overrides(config, env);
+    apply_vector_db_env_overrides(config, env);
+    apply_sync_env_overrides(config, env);
+}
+
+fn flatten_config_values(config: &BackendConfig) -> BTreeMap<String, serde_json::Value> {
+    let mut values = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(config) {
+        flatten_value_into(&mut values, String::new(), value);
+    }
+    values
+}
+
+fn flatten_value_into(
+    values: &mut BTreeMap<String, serde_json::Value>,
+    prefix: String,
+    value: serde_json::Value,
+) {
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_value_into(values, path, child);
+            }
+        },
+        leaf => {
+            values.insert(prefix, leaf);
+        },
+    }
+}
+
+fn render_config_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
 const fn apply_core_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
     let mapper = EnvConfigMapper::new(config);
     EnvConfigMapper::set_u64(&mut mapper.config.core.timeout_ms, env.core_timeout_ms);
@@ -1073,6 +1409,7 @@ const fn apply_core_env_overrides(config: &mut BackendConfig, env: &BackendEnv)
         &mut mapper.config.core.retry.jitter_ratio_pct,
         env.core_retry_jitter_ratio_pct,
     );
+    EnvConfigMapper::set_opt_u64(&mut mapper.config.core.seed, env.core_seed);
 }
 
 fn apply_embedding_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
@@ -1093,6 +1430,14 @@ fn apply_embedding_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
         &mut mapper.config.embedding.batch_size,
         env.embedding_batch_size,
     );
+    EnvConfigMapper::set_opt_u32(
+        &mut mapper.config.embedding.max_consecutive_failures,
+        env.embedding_max_consecutive_failures,
+    );
+    EnvConfigMapper::set_opt_u32(
+        &mut mapper.config.embedding.max_total_failures,
+        env.embedding_max_total_failures,
+    );
     EnvConfigMapper::set_opt_u32(
         &mut mapper.config.embedding.dimension,
         env.embedding_dimension,
@@ -1154,6 +1499,17 @@ fn apply_embedding_onnx_env_overrides(config: &mut BackendConfig, env: &BackendE
         &mut mapper.config.embedding.onnx.session_pool_size,
         env.embedding_onnx_session_pool_size,
     );
+    EnvConfigMapper::set_u32(
+        &mut mapper.config.embedding.onnx.intra_op_threads,
+        env.embedding_onnx_intra_op_threads,
+    );
+    EnvConfigMapper::set_u32(
+        &mut mapper.config.embedding.onnx.inter_op_threads,
+        env.embedding_onnx_inter_op_threads,
+    );
+    if let Some(provider) = env.embedding_onnx_execution_provider {
+        mapper.config.embedding.onnx.execution_provider = provider;
+    }
 }
 
 fn apply_embedding_cache_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
@@ -1215,6 +1571,14 @@ fn apply_vector_db_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
         &mut mapper.config.vector_db.batch_size,
         env.vector_db_batch_size,
     );
+    EnvConfigMapper::set_opt_u32(
+        &mut mapper.config.vector_db.shard_count,
+        env.vector_db_shard_count,
+    );
+    EnvConfigMapper::set_u32(
+        &mut mapper.config.vector_db.max_filter_values,
+        env.vector_db_max_filter_values,
+    );
     EnvConfigMapper::set_opt_vector_snapshot_format(
         &mut mapper.config.vector_db.snapshot_format,
         env.vector_db_snapshot_format,
@@ -1223,6 +1587,10 @@ fn apply_vector_db_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
         &mut mapper.config.vector_db.snapshot_max_bytes,
         env.vector_db_snapshot_max_bytes,
     );
+    EnvConfigMapper::set_bool(
+        &mut mapper.config.vector_db.snapshot_gzip,
+        env.vector_db_snapshot_gzip,
+    );
     EnvConfigMapper::set_opt_vector_kernel_kind(
         &mut mapper.config.vector_db.vector_kernel,
         env.vector_db_vector_kernel,
@@ -1268,6 +1636,10 @@ fn apply_vector_db_connection_env_overrides(config: &mut BackendConfig, env: &Ba
         &mut mapper.config.vector_db.database,
         env.vector_db_database.as_deref(),
     );
+    EnvConfigMapper::set_opt_box_str(
+        &mut mapper.config.vector_db.collection_template,
+        env.vector_db_collection_template.as_deref(),
+    );
     EnvConfigMapper::set_bool(&mut mapper.config.vector_db.ssl, env.vector_db_ssl);
     EnvConfigMapper::set_opt_box_str(
         &mut mapper.config.vector_db.token,
@@ -1331,6 +1703,18 @@ fn apply_sync_env_overrides(config: &mut BackendConfig, env: &BackendEnv) {
         &mut mapper.config.sync.max_file_size_bytes,
         env.sync_max_file_size_bytes,
     );
+    EnvConfigMapper::set_clone(
+        &mut mapper.config.sync.per_extension_max_file_size_bytes,
+        env.sync_per_extension_max_file_size_bytes.as_ref(),
+    );
+    EnvConfigMapper::set_opt_path_normalization(
+        &mut mapper.config.sync.path_normalization,
+        env.sync_path_normalization,
+    );
+    EnvConfigMapper::set_opt_oversize_chunk_policy(
+        &mut mapper.config.sync.oversize_chunk_policy,
+        env.sync_oversize_chunk_policy,
+    );
 }
 
 struct EnvConfigMapper<'a> {
@@ -1384,6 +1768,24 @@ impl<'a> EnvConfigMapper<'a> {
         }
     }
 
+    const fn set_opt_path_normalization(
+        field: &mut PathNormalization,
+        value: Option<PathNormalization>,
+    ) {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+
+    const fn set_opt_oversize_chunk_policy(
+        field: &mut OversizeChunkPolicy,
+        value: Option<OversizeChunkPolicy>,
+    ) {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+
     const fn set_opt_vector_snapshot_format(
         field: &mut VectorSnapshotFormat,
         value: Option<VectorSnapshotFormat>,
@@ -1446,6 +1848,27 @@ pub enum EnvParseError {
         /// Env var name.
         var: &'static str,
     },
+    /// A `*_FILE` secret companion pointed at a file that was empty after trimming.
+    EmptySecretFile {
+        /// Env var name (without the `_FILE` suffix).
+        var: &'static str,
+        /// Path read from the `_FILE` companion.
+        path: String,
+    },
+    /// A `*_FILE` secret companion pointed at a file that could not be read.
+    UnreadableSecretFile {
+        /// Env var name (without the `_FILE` suffix).
+        var: &'static str,
+        /// Path read from the `_FILE` companion.
+        path: String,
+        /// I/O failure description.
+        reason: String,
+    },
+    /// A secret was set both directly and via `*_FILE` with different values.
+    ConflictingSecret {
+        /// Env var name (without the `_FILE` suffix).
+        var: &'static str,
+    },
     /// Boolean env var had an invalid value.
     InvalidBool {
         /// Env var name.
@@ -1512,6 +1935,11 @@ impl EnvParseError {
             Self::EmptyValue { .. } | Self::EmptySecret { .. } => {
                 ErrorCode::new("config", "empty_env_var")
             },
+            Self::EmptySecretFile { .. } => ErrorCode::new("config", "empty_secret_file"),
+            Self::UnreadableSecretFile { .. } => ErrorCode::new("config", "unreadable_secret_file"),
+            Self::ConflictingSecret { .. } => {
+                ErrorCode::new("config", "conflicting_secret_sources")
+            },
             Self::InvalidBool { .. } => ErrorCode::new("config", "invalid_env_bool"),
             Self::InvalidInt { .. } => ErrorCode::new("config", "invalid_env_int"),
             Self::InvalidFloat { .. } => ErrorCode::new("config", "invalid_env_float"),
@@ -1530,6 +1958,22 @@ impl fmt::Display for EnvParseError {
             Self::EmptyValue { var } | Self::EmptySecret { var } => {
                 write!(formatter, "{var} must be non-empty")
             },
+            Self::EmptySecretFile { var, .. } => {
+                write!(
+                    formatter,
+                    "{var}{SECRET_FILE_SUFFIX} points to an empty file"
+                )
+            },
+            Self::UnreadableSecretFile { var, reason, .. } => {
+                write!(
+                    formatter,
+                    "{var}{SECRET_FILE_SUFFIX} could not be read: {reason}"
+                )
+            },
+            Self::ConflictingSecret { var } => write!(
+                formatter,
+                "{var} and {var}{SECRET_FILE_SUFFIX} are both set to different values"
+            ),
             Self::InvalidBool { var, .. } => write!(formatter, "{var} must be a boolean"),
             Self::InvalidInt { var, .. } => write!(formatter, "{var} must be an integer"),
             Self::InvalidFloat { var, .. } => {
@@ -1562,9 +2006,17 @@ impl From<EnvParseError> for ErrorEnvelope {
         let mut envelope = Self::expected(code, message);
 
         match error {
-            EnvParseError::EmptyValue { var } | EnvParseError::EmptySecret { var } => {
+            EnvParseError::EmptyValue { var }
+            | EnvParseError::EmptySecret { var }
+            | EnvParseError::ConflictingSecret { var } => {
                 envelope = envelope.with_metadata("env_var", var);
             },
+            EnvParseError::EmptySecretFile { var, path }
+            | EnvParseError::UnreadableSecretFile { var, path, .. } => {
+                envelope = envelope
+                    .with_metadata("env_var", format!("{var}{SECRET_FILE_SUFFIX}"))
+                    .with_metadata("path", path);
+            },
             EnvParseError::InvalidBool { var, value }
             | EnvParseError::InvalidInt { var, value }
             | EnvParseError::InvalidFloat { var, value }
@@ -1620,20 +2072,69 @@ fn parse_optional_trimmed_string_any(
     Ok(None)
 }
 
+/// Parse a secret from `var` or its `{var}_FILE` companion.
+///
+/// The companion names a file whose trimmed contents become the secret
+/// (Docker/Kubernetes secrets style). When both are set they must agree;
+/// differing values are rejected rather than silently picking one.
 fn parse_optional_secret(
     map: &BTreeMap<String, String>,
     var: &'static str,
 ) -> Result<Option<SecretString>, EnvParseError> {
-    let Some(raw) = map.get(var) else {
-        return Ok(None);
+    let direct = match map.get(var) {
+        Some(raw) => {
+            let trimmed = raw.trim();
+            if trimmed.is_empty() {
+                return Err(EnvParseError::EmptySecret { var });
+            }
+            Some(trimmed)
+        },
+        None => None,
     };
+    let from_file = read_secret_file(map, var)?;
 
-    let trimmed = raw.trim();
+    match (direct, from_file) {
+        (Some(direct), Some(from_file)) if direct != from_file => {
+            Err(EnvParseError::ConflictingSecret { var })
+        },
+        (Some(direct), _) => Ok(Some(SecretString::new(direct.to_owned()))),
+        (None, Some(from_file)) => Ok(Some(SecretString::new(from_file))),
+        (None, None) => Ok(None),
+    }
+}
+
+fn read_secret_file(
+    map: &BTreeMap<String, String>,
+    var: &'static str,
+) -> Result<Option<String>, EnvParseError> {
+    let Some(path) = map.get(&secret_file_var(var)) else {
+        return Ok(None);
+    };
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(EnvParseError::EmptySecretFile {
+            var,
+            path: path.to_owned(),
+        });
+    }
+    let contents =
+        std::fs::read_to_string(path).map_err(|error| EnvParseError::UnreadableSecretFile {
+            var,
+            path: path.to_owned(),
+            reason: error.to_string(),
+        })?;
+    let trimmed = contents.trim();
     if trimmed.is_empty() {
-        return Err(EnvParseError::EmptySecret { var });
+        return Err(EnvParseError::EmptySecretFile {
+            var,
+            path: path.to_owned(),
+        });
     }
+    Ok(Some(trimmed.to_owned()))
+}
 
-    Ok(Some(SecretString::new(trimmed.to_owned())))
+fn secret_file_var(var: &str) -> String {
+    format!("{var}{SECRET_FILE_SUFFIX}")
 }
 
 fn parse_optional_secret_any(
@@ -1641,7 +2142,7 @@ fn parse_optional_secret_any(
     vars: &[&'static str],
 ) -> Result<Option<SecretString>, EnvParseError> {
     for var in vars {
-        if map.contains_key(*var) {
+        if map.contains_key(*var) || map.contains_key(&secret_file_var(var)) {
             return parse_optional_secret(map, var);
         }
     }
@@ -1821,6 +2322,44 @@ fn parse_optional_index_mode(
     }
 }
 
+fn parse_optional_path_normalization(
+    map: &BTreeMap<String, String>,
+    var: &'static str,
+) -> Result<Option<PathNormalization>, EnvParseError> {
+    let Some(raw) = map.get(var) else {
+        return Ok(None);
+    };
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err(EnvParseError::EmptyValue { var });
+    }
+    PathNormalization::parse(trimmed)
+        .map(Some)
+        .ok_or_else(|| EnvParseError::InvalidEnum {
+            var,
+            value: raw.clone(),
+        })
+}
+
+fn parse_optional_oversize_chunk_policy(
+    map: &BTreeMap<String, String>,
+    var: &'static str,
+) -> Result<Option<OversizeChunkPolicy>, EnvParseError> {
+    let Some(raw) = map.get(var) else {
+        return Ok(None);
+    };
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err(EnvParseError::EmptyValue { var });
+    }
+    OversizeChunkPolicy::parse(trimmed)
+        .map(Some)
+        .ok_or_else(|| EnvParseError::InvalidEnum {
+            var,
+            value: raw.clone(),
+        })
+}
+
 fn parse_optional_vector_snapshot_format(
     map: &BTreeMap<String, String>,
     var: &'static str,
@@ -1923,6 +2462,28 @@ fn parse_optional_routing_mode_any(
     Ok(None)
 }
 
+fn parse_optional_onnx_execution_provider_any(
+    map: &BTreeMap<String, String>,
+    vars: &[&'static str],
+) -> Result<Option<OnnxExecutionProvider>, EnvParseError> {
+    for var in vars {
+        let Some(raw) = map.get(*var) else {
+            continue;
+        };
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err(EnvParseError::EmptyValue { var });
+        }
+        let parsed =
+            OnnxExecutionProvider::parse(trimmed).ok_or_else(|| EnvParseError::InvalidEnum {
+                var,
+                value: trimmed.to_string(),
+            })?;
+        return Ok(Some(parsed));
+    }
+    Ok(None)
+}
+
 fn parse_optional_cache_disk_provider(
     map: &BTreeMap<String, String>,
     var: &'static str,
@@ -2026,6 +2587,49 @@ fn parse_optional_csv_extensions(
     Ok(Some(normalized))
 }
 
+/// Parse `ext=bytes` pairs; key normalization is left to schema validation.
+fn parse_optional_csv_size_map(
+    map: &BTreeMap<String, String>,
+    var: &'static str,
+) -> Result<Option<BTreeMap<Box<str>, u64>>, EnvParseError> {
+    let Some(raw) = map.get(var) else {
+        return Ok(None);
+    };
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(Some(BTreeMap::new()));
+    }
+
+    let items = parse_csv(trimmed);
+    if items.len() > MAX_CSV_ITEMS {
+        return Err(EnvParseError::CsvTooLarge {
+            var,
+            len: items.len(),
+            max: MAX_CSV_ITEMS,
+        });
+    }
+
+    let mut parsed = BTreeMap::new();
+    for item in items {
+        let Some((ext, bytes)) = item.split_once('=') else {
+            return Err(EnvParseError::InvalidExtensionEntry { var, entry: item });
+        };
+        let ext = ext.trim();
+        if ext.is_empty() {
+            return Err(EnvParseError::InvalidExtensionEntry { var, entry: item });
+        }
+        let bytes = bytes
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| EnvParseError::InvalidInt {
+                var,
+                value: item.clone(),
+            })?;
+        parsed.insert(Box::from(ext), bytes);
+    }
+    Ok(Some(parsed))
+}
+
 fn parse_optional_csv_patterns(
     map: &BTreeMap<String, String>,
     var: &'static str,
@@ -2131,6 +2735,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn per_extension_size_map_parses_pairs() -> Result<(), Box<dyn Error>> {
+        let mut map = BTreeMap::new();
+        map.insert(
+            ENV_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES.to_string(),
+            "min.js=50000, md = 8000000".to_string(),
+        );
+        let env = BackendEnv::from_map(&map)?;
+
+        let values = env
+            .sync_per_extension_max_file_size_bytes
+            .as_ref()
+            .ok_or_else(|| std::io::Error::other("missing size map"))?;
+        assert_eq!(values.get("min.js"), Some(&50_000));
+        assert_eq!(values.get("md"), Some(&8_000_000));
+
+        map.insert(
+            ENV_SYNC_PER_EXTENSION_MAX_FILE_SIZE_BYTES.to_string(),
+            "md=big".to_string(),
+        );
+        assert!(matches!(
+            BackendEnv::from_map(&map),
+            Err(EnvParseError::InvalidInt { .. })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn url_validation_accepts_http_and_https() -> Result<(), Box<dyn Error>> {
         let mut map = BTreeMap::new();
@@ -2192,6 +2823,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn onnx_execution_provider_env_parses_and_rejects_unknown() -> Result<(), Box<dyn Error>> {
+        let mut map = BTreeMap::new();
+        map.insert(ENV_EMBEDDING_ONNX_EP.to_string(), "CoreML".to_string());
+        let env = BackendEnv::from_map(&map)?;
+        assert_eq!(
+            env.embedding_onnx_execution_provider,
+            Some(OnnxExecutionProvider::CoreMl)
+        );
+
+        map.insert(ENV_EMBEDDING_ONNX_EP.to_string(), "tpu".to_string());
+        let error = BackendEnv::from_map(&map).err();
+        assert!(matches!(error, Some(EnvParseError::InvalidEnum { .. })));
+        Ok(())
+    }
+
     #[test]
     fn vector_db_vector_kernel_env_parses_value() -> Result<(), Box<dyn Error>> {
         let mut map = BTreeMap::new();
@@ -2326,6 +2973,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vector_db_shard_count_env_applies() -> Result<(), Box<dyn Error>> {
+        let mut map = BTreeMap::new();
+        map.insert(ENV_VECTOR_DB_SHARD_COUNT.to_string(), "4".to_string());
+        let env = BackendEnv::from_map(&map)?;
+
+        let config = apply_env_overrides(BackendConfig::default(), &env)?;
+        assert_eq!(config.vector_db.shard_count, Some(4));
+        Ok(())
+    }
+
     #[test]
     fn secret_values_are_redacted_in_error_metadata() -> Result<(), Box<dyn Error>> {
         let mut map = BTreeMap::new();
@@ -2347,4 +3005,119 @@ mod tests {
         );
         Ok(())
     }
+
+    fn write_secret_file(name: &str, contents: &str) -> Result<std::path::PathBuf, Box<dyn Error>> {
+        let dir = std::env::temp_dir().join(format!("sca-secret-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(name);
+        std::fs::write(&path, contents)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn secret_file_companion_supplies_trimmed_secret() -> Result<(), Box<dyn Error>> {
+        let path = write_secret_file("openai", "  sk-from-file\n")?;
+        let mut map = BTreeMap::new();
+        map.insert(
+            secret_file_var(ENV_OPENAI_API_AUTH),
+            path.to_string_lossy().into_owned(),
+        );
+
+        let secret = parse_optional_secret(&map, ENV_OPENAI_API_AUTH)?
+            .ok_or_else(|| std::io::Error::other("expected secret from file"))?;
+        assert_eq!(secret.expose(), "sk-from-file");
+
+        let secret = parse_optional_secret_any(&map, &["MISSING_KEY", ENV_OPENAI_API_AUTH])?
+            .ok_or_else(|| std::io::Error::other("expected secret from file"))?;
+        assert_eq!(secret.expose(), "sk-from-file");
+
+        // Matching direct and file values are accepted.
+        map.insert(ENV_OPENAI_API_AUTH.to_string(), "sk-from-file".to_string());
+        assert!(parse_optional_secret(&map, ENV_OPENAI_API_AUTH)?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn secret_file_rejects_empty_contents_and_conflicts() -> Result<(), Box<dyn Error>> {
+        let empty = write_secret_file("empty", "  \n")?;
+        let mut map = BTreeMap::new();
+        map.insert(
+            secret_file_var(ENV_GEMINI_API_AUTH),
+            empty.to_string_lossy().into_owned(),
+        );
+        let envelope: ErrorEnvelope = parse_optional_secret(&map, ENV_GEMINI_API_AUTH)
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected empty secret file error"))?
+            .into();
+        assert_eq!(envelope.code, ErrorCode::new("config", "empty_secret_file"));
+
+        let filled = write_secret_file("filled", "sk-file")?;
+        let mut map = BTreeMap::new();
+        map.insert(ENV_GEMINI_API_AUTH.to_string(), "sk-direct".to_string());
+        map.insert(
+            secret_file_var(ENV_GEMINI_API_AUTH),
+            filled.to_string_lossy().into_owned(),
+        );
+        let envelope: ErrorEnvelope = parse_optional_secret(&map, ENV_GEMINI_API_AUTH)
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected conflicting secret error"))?
+            .into();
+        assert_eq!(
+            envelope.code,
+            ErrorCode::new("config", "conflicting_secret_sources")
+        );
+        assert!(!envelope.message.contains("sk-"));
+        Ok(())
+    }
+
+    #[test]
+    fn env_override_over_file_value_is_reported_as_shadow() -> Result<(), Box<dyn Error>> {
+        let mut base = BackendConfig::default();
+        base.core.timeout_ms = 45_000;
+        base.embedding.batch_size = 32;
+        let mut map = BTreeMap::new();
+        map.insert(ENV_CORE_TIMEOUT_MS.to_string(), "12345".to_string());
+        map.insert(ENV_CORE_MAX_CONCURRENCY.to_string(), "4".to_string());
+        let env = BackendEnv::from_map(&map)?;
+
+        let (config, shadows) = apply_env_overrides_with_report(base, &env)?;
+
+        assert_eq!(config.core.timeout_ms, 12345);
+        assert_eq!(
+            shadows,
+            vec![EnvOverrideShadow {
+                field: "core.timeoutMs".into(),
+                file_value: "45000".into(),
+                env_value: "12345".into(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn diff_config_reports_only_changed_fields() {
+        let base = BackendConfig::default();
+        let mut changed = base.clone();
+        changed.core.timeout_ms = 12_345;
+
+        assert_eq!(
+            diff_config(&base, &changed),
+            vec![ConfigFieldChange {
+                field: "core.timeoutMs".into(),
+                before: base.core.timeout_ms.to_string().into(),
+                after: "12345".into(),
+            }]
+        );
+        assert!(diff_config(&base, &base).is_empty());
+    }
+
+    #[test]
+    fn secret_file_keys_are_read_from_std_env() {
+        for key in SECRET_ENV_KEYS {
+            assert!(
+                STD_ENV_KEYS.contains(key),
+                "{key} missing from STD_ENV_KEYS"
+            );
+        }
+    }
 }
@@ -15,26 +15,34 @@ mod runtime;
 mod schema;
 /// Storage and persistence configuration.
 mod storage;
+/// Provider-specific starter config templates.
+mod templates;
 
 pub use schema::{
-    BackendConfig, ConfigSchemaError, DfrrBq1Threshold, DfrrBq1ThresholdMode, DfrrQueryStrategy,
-    DfrrSearchConfig, EmbeddingCacheDiskProvider, EmbeddingConfig, EmbeddingRoutingMode,
-    HnswBuildConfig, HnswSearchConfig, ValidatedBackendConfig, VectorKernelKind,
+    BackendConfig, ConfigLimits, ConfigSchemaError, ConfigWarning, DfrrBq1Threshold,
+    DfrrBq1ThresholdMode, DfrrQueryStrategy, DfrrSearchConfig, EmbeddingCacheDiskProvider,
+    EmbeddingCacheKeyHash, EmbeddingConfig, EmbeddingRoutingMode, HnswBuildConfig,
+    HnswSearchConfig, OnnxExecutionProvider, ValidatedBackendConfig, VectorKernelKind,
     VectorSearchStrategy,
 };
 
+pub use env::{ConfigFieldChange, EnvOverrideShadow, diff_config};
 pub use load::{
-    load_backend_config_from_path, load_backend_config_from_sources, load_backend_config_std_env,
-    to_pretty_json, to_pretty_toml,
+    STDIN_CONFIG_PATH, is_stdin_config_path, load_backend_config_from_path,
+    load_backend_config_from_path_with_report, load_backend_config_from_sources,
+    load_backend_config_std_env, to_pretty_json, to_pretty_toml,
 };
 pub use requests::{
-    ClearIndexRequestDto, IndexRequestDto, ReindexByChangeRequestDto, SearchRequestDto,
+    ClearIndexRequestDto, DEFAULT_MAX_FILTER_VALUES, FilterComparison, FilterExpr, FilterOp,
+    IndexRequestDto, ReindexByChangeRequestDto, SearchRequestDto, SearchRequestLimits,
     ValidatedClearIndexRequest, ValidatedIndexRequest, ValidatedReindexByChangeRequest,
     ValidatedSearchRequest, validate_clear_index_request, validate_index_request,
     validate_reindex_by_change_request, validate_search_request,
+    validate_search_request_with_limits,
 };
 pub use runtime::{RuntimeEnv, load_runtime_env_from_map, load_runtime_env_std_env};
 pub use storage::{SnapshotStorageMode, VectorSnapshotFormat};
+pub use templates::ConfigTemplate;
 
 /// Returns the config crate version.
 #[must_use]
@@ -3,24 +3,38 @@
 //! The loader is responsible for deterministic merge order and surfacing
 //! user-facing errors as typed `ErrorEnvelope`s.
 
-use crate::env::{BackendEnv, apply_env_overrides};
-use crate::schema::VectorDbIndexConfig;
+use crate::env::{
+    BackendEnv, EnvOverrideShadow, apply_env_overrides, apply_env_overrides_with_report,
+};
+use crate::schema::{VectorDbGrpcConfig, VectorDbIndexConfig};
 use crate::{
-    BackendConfig, DfrrSearchConfig, EmbeddingCacheDiskProvider, EmbeddingRoutingMode,
-    HnswBuildConfig, HnswSearchConfig, ValidatedBackendConfig, VectorKernelKind,
-    VectorSearchStrategy, VectorSnapshotFormat,
+    BackendConfig, DfrrSearchConfig, EmbeddingCacheDiskProvider, EmbeddingCacheKeyHash,
+    EmbeddingRoutingMode, HnswBuildConfig, HnswSearchConfig, OnnxExecutionProvider,
+    ValidatedBackendConfig, VectorKernelKind, VectorSearchStrategy, VectorSnapshotFormat,
 };
-use semantic_code_domain::IndexMode;
+use semantic_code_domain::{IndexMode, Language, OversizeChunkPolicy, PathNormalization};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope};
 use serde::Deserialize;
 use std::collections::BTreeMap;
+use std::io::Read;
 use std::path::Path;
+use std::sync::OnceLock;
 use tracing::{debug, instrument};
 
+/// Config path that reads the config payload from stdin instead of a file.
+pub const STDIN_CONFIG_PATH: &str = "-";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ConfigFormat {
     Json,
     Toml,
+    Yaml,
+}
+
+/// Returns true when `path` selects stdin (`--config -`).
+#[must_use]
+pub fn is_stdin_config_path(path: &Path) -> bool {
+    path.as_os_str() == STDIN_CONFIG_PATH
 }
 
 /// Load the backend config from sources using a deterministic precedence order.
@@ -74,6 +88,9 @@ fn load_backend_config_from_sources_with_env(
 }
 
 /// Load the backend config from an optional file path.
+///
+/// A path of [`STDIN_CONFIG_PATH`] reads the config from stdin and detects the
+/// format from the content (JSON, then TOML, then YAML).
 #[instrument(
     name = "config.load.from_path",
     skip_all,
@@ -96,6 +113,49 @@ fn load_backend_config_from_path_with_env(
     overrides_json: Option<&str>,
     env: &BackendEnv,
 ) -> Result<ValidatedBackendConfig, ErrorEnvelope> {
+    load_backend_config_from_path_with_stdin(config_path, overrides_json, env, read_stdin_config)
+}
+
+fn load_backend_config_from_path_with_stdin(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    env: &BackendEnv,
+    read_stdin: impl FnOnce() -> Result<String, ErrorEnvelope>,
+) -> Result<ValidatedBackendConfig, ErrorEnvelope> {
+    let config = read_config_from_path(config_path, overrides_json, read_stdin)?;
+
+    // env is applied last and also validates/normalizes the resulting config.
+    debug!("applying environment overrides and validating config");
+    apply_env_overrides(config, env)
+}
+
+/// Load the backend config from an optional file path, also reporting env shadows.
+///
+/// Same precedence as [`load_backend_config_from_path`]; the report lists fields
+/// whose file/override value was replaced by an env override.
+#[instrument(
+    name = "config.load.from_path_with_report",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn load_backend_config_from_path_with_report(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    env: &BTreeMap<String, String>,
+) -> Result<(ValidatedBackendConfig, Vec<EnvOverrideShadow>), ErrorEnvelope> {
+    let parsed_env = BackendEnv::from_map(env).map_err(ErrorEnvelope::from)?;
+    let config = read_config_from_path(config_path, overrides_json, read_stdin_config)?;
+    apply_env_overrides_with_report(config, &parsed_env)
+}
+
+fn read_config_from_path(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    read_stdin: impl FnOnce() -> Result<String, ErrorEnvelope>,
+) -> Result<BackendConfig, ErrorEnvelope> {
     debug!(
         has_config_path = config_path.is_some(),
         has_overrides_json = overrides_json.is_some(),
@@ -103,6 +163,10 @@ fn load_backend_config_from_path_with_env(
     );
     let mut config = match config_path {
         None => BackendConfig::default(),
+        Some(path) if is_stdin_config_path(path) => {
+            let config_text = read_stdin()?;
+            parse_config_detected(&config_text)?
+        },
         Some(path) => {
             let config_text = read_config_file(path)?;
             let format = detect_config_format(path)?;
@@ -117,9 +181,7 @@ fn load_backend_config_from_path_with_env(
         apply_overrides(&mut config, &overrides);
     }
 
-    // env is applied last and also validates/normalizes the resulting config.
-    debug!("applying environment overrides and validating config");
-    apply_env_overrides(config, env)
+    Ok(config)
 }
 
 /// Load the backend config from std env and an optional file path.
@@ -194,9 +256,79 @@ fn parse_config_unvalidated(
             )
             .with_metadata("source", "config")
         }),
+        ConfigFormat::Yaml => serde_yaml_ng::from_str(input).map_err(|error| {
+            ErrorEnvelope::expected(
+                ErrorCode::new("config", "invalid_yaml"),
+                format!("invalid config YAML: {error}"),
+            )
+            .with_metadata("source", "config")
+        }),
     }
 }
 
+/// Parse a config payload whose format is unknown (stdin has no extension).
+///
+/// Formats are tried in a fixed order; when none parses, the JSON error is
+/// reported for payloads that look like JSON and the TOML error otherwise.
+fn parse_config_detected(input: &str) -> Result<BackendConfig, ErrorEnvelope> {
+    if input.trim().is_empty() {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::new("config", "empty_config"),
+            "config read from stdin is empty",
+        )
+        .with_metadata("source", "stdin"));
+    }
+
+    // Report the error from the format the payload most resembles.
+    let preferred = if input.trim_start().starts_with('{') {
+        ConfigFormat::Json
+    } else {
+        ConfigFormat::Toml
+    };
+    let mut reported = None;
+    for format in [ConfigFormat::Json, ConfigFormat::Toml, ConfigFormat::Yaml] {
+        match parse_config_unvalidated(input, format) {
+            Ok(config) => {
+                debug!(format = ?format, "detected stdin config format");
+                return Ok(config);
+            },
+            Err(error) if format == preferred => reported = Some(error),
+            Err(_) => {},
+        }
+    }
+
+    let error = reported.unwrap_or_else(|| {
+        ErrorEnvelope::expected(
+            ErrorCode::new("config", "unsupported_format"),
+            "config read from stdin is not valid JSON, TOML, or YAML",
+        )
+    });
+    Err(error.with_metadata("source", "stdin"))
+}
+
+fn read_stdin_config() -> Result<String, ErrorEnvelope> {
+    // Several loaders may run per CLI command; stdin can only be consumed once.
+    static STDIN_CONFIG: OnceLock<String> = OnceLock::new();
+    if let Some(text) = STDIN_CONFIG.get() {
+        return Ok(text.clone());
+    }
+    let text = read_config_reader(std::io::stdin().lock())?;
+    Ok(STDIN_CONFIG.get_or_init(|| text).clone())
+}
+
+fn read_config_reader(mut reader: impl Read) -> Result<String, ErrorEnvelope> {
+    debug!("reading backend config from stdin");
+    let mut text = String::new();
+    reader.read_to_string(&mut text).map_err(|error| {
+        ErrorEnvelope::expected(
+            ErrorCode::new("config", "config_file_io"),
+            format!("failed to read config from stdin: {error}"),
+        )
+        .with_metadata("path", STDIN_CONFIG_PATH)
+    })?;
+    Ok(text)
+}
+
 fn parse_overrides_json(input: &str) -> Result<BackendConfigOverrides, ErrorEnvelope> {
     debug!(bytes = input.len(), "parsing backend override payload");
     serde_json::from_str(input).map_err(|error| {
@@ -281,6 +413,8 @@ struct CoreConfigOverrides {
     max_chunk_chars: Option<u32>,
     #[serde(default)]
     retry: RetryConfigOverrides,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -312,6 +446,10 @@ struct EmbeddingConfigOverrides {
     #[serde(skip_serializing_if = "Option::is_none")]
     batch_size: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    max_consecutive_failures: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_total_failures: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     local_first: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     local_only: Option<bool>,
@@ -323,6 +461,17 @@ struct EmbeddingConfigOverrides {
     onnx: EmbeddingOnnxOverrides,
     #[serde(default)]
     cache: EmbeddingCacheOverrides,
+    #[serde(default)]
+    input_template: EmbeddingInputTemplateOverrides,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+struct EmbeddingInputTemplateOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<Box<str>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -332,6 +481,8 @@ struct EmbeddingRoutingOverrides {
     mode: Option<EmbeddingRoutingMode>,
     #[serde(default)]
     split: EmbeddingSplitOverrides,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by_language: Option<BTreeMap<Language, Box<str>>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -348,6 +499,8 @@ struct EmbeddingJobsOverrides {
     progress_interval_ms: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     cancel_poll_interval_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_history_limit: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -365,6 +518,12 @@ struct EmbeddingOnnxOverrides {
     download_on_missing: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     session_pool_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    intra_op_threads: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inter_op_threads: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution_provider: Option<OnnxExecutionProvider>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -388,6 +547,8 @@ struct EmbeddingCacheOverrides {
     disk_table: Option<Box<str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     disk_max_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_hash: Option<EmbeddingCacheKeyHash>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
@@ -404,6 +565,8 @@ struct VectorDbConfigOverrides {
     #[serde(skip_serializing_if = "Option::is_none")]
     database: Option<Box<str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    collection_template: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     token: Option<Box<str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     username: Option<Box<str>>,
@@ -418,12 +581,20 @@ struct VectorDbConfigOverrides {
     #[serde(skip_serializing_if = "Option::is_none")]
     index: Option<VectorDbIndexConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    grpc: Option<VectorDbGrpcConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     batch_size: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    shard_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_filter_values: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     snapshot_format: Option<VectorSnapshotFormat>,
     #[serde(skip_serializing_if = "Option::is_none")]
     snapshot_max_bytes: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot_gzip: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     vector_kernel: Option<VectorKernelKind>,
     #[serde(skip_serializing_if = "Option::is_none")]
     search_strategy: Option<VectorSearchStrategy>,
@@ -451,11 +622,25 @@ struct SyncConfigOverrides {
     #[serde(skip_serializing_if = "Option::is_none")]
     ignore_patterns: Option<Vec<Box<str>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    additional_ignore_patterns: Option<Vec<Box<str>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_files: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_chunks: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    min_chunk_chars: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_file_size_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_extension_max_file_size_bytes: Option<BTreeMap<Box<str>, u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_normalization: Option<PathNormalization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oversize_chunk_policy: Option<OversizeChunkPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skip_binary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    follow_symlinks: Option<bool>,
 }
 
 fn apply_overrides(config: &mut BackendConfig, overrides: &BackendConfigOverrides) {
@@ -508,6 +693,7 @@ const fn apply_core_overrides(config: &mut BackendConfig, overrides: &CoreConfig
         &mut mapper.config.core.max_chunk_chars,
         overrides.max_chunk_chars,
     );
+    OverrideMapper::set_opt_u64(&mut mapper.config.core.seed, overrides.seed);
     apply_retry_overrides(config, &overrides.retry);
 }
 
@@ -554,6 +740,14 @@ fn apply_embedding_overrides(config: &mut BackendConfig, overrides: &EmbeddingCo
         &mut mapper.config.embedding.batch_size,
         overrides.batch_size,
     );
+    OverrideMapper::set_opt_u32(
+        &mut mapper.config.embedding.max_consecutive_failures,
+        overrides.max_consecutive_failures,
+    );
+    OverrideMapper::set_opt_u32(
+        &mut mapper.config.embedding.max_total_failures,
+        overrides.max_total_failures,
+    );
     OverrideMapper::set_bool(
         &mut mapper.config.embedding.local_first,
         overrides.local_first,
@@ -567,9 +761,25 @@ fn apply_embedding_overrides(config: &mut BackendConfig, overrides: &EmbeddingCo
     apply_embedding_jobs_overrides(config, &overrides.jobs);
     apply_embedding_onnx_overrides(config, &overrides.onnx);
     apply_embedding_cache_overrides(config, &overrides.cache);
+    apply_embedding_input_template_overrides(config, &overrides.input_template);
+}
+
+fn apply_embedding_input_template_overrides(
+    config: &mut BackendConfig,
+    overrides: &EmbeddingInputTemplateOverrides,
+) {
+    let mapper = OverrideMapper::new(config);
+    OverrideMapper::set_opt_box_str(
+        &mut mapper.config.embedding.input_template.document,
+        overrides.document.as_deref(),
+    );
+    OverrideMapper::set_opt_box_str(
+        &mut mapper.config.embedding.input_template.query,
+        overrides.query.as_deref(),
+    );
 }
 
-const fn apply_embedding_routing_overrides(
+fn apply_embedding_routing_overrides(
     config: &mut BackendConfig,
     overrides: &EmbeddingRoutingOverrides,
 ) {
@@ -579,6 +789,9 @@ const fn apply_embedding_routing_overrides(
     if overrides.split.max_remote_batches.is_some() {
         config.embedding.routing.split.max_remote_batches = overrides.split.max_remote_batches;
     }
+    if let Some(by_language) = overrides.by_language.as_ref() {
+        config.embedding.routing.by_language.clone_from(by_language);
+    }
 }
 
 const fn apply_embedding_jobs_overrides(
@@ -594,6 +807,10 @@ const fn apply_embedding_jobs_overrides(
         &mut mapper.config.embedding.jobs.cancel_poll_interval_ms,
         overrides.cancel_poll_interval_ms,
     );
+    OverrideMapper::set_u32(
+        &mut mapper.config.embedding.jobs.status_history_limit,
+        overrides.status_history_limit,
+    );
 }
 
 fn apply_embedding_onnx_overrides(config: &mut BackendConfig, overrides: &EmbeddingOnnxOverrides) {
@@ -622,6 +839,17 @@ fn apply_embedding_onnx_overrides(config: &mut BackendConfig, overrides: &Embedd
         &mut mapper.config.embedding.onnx.session_pool_size,
         overrides.session_pool_size,
     );
+    OverrideMapper::set_u32(
+        &mut mapper.config.embedding.onnx.intra_op_threads,
+        overrides.intra_op_threads,
+    );
+    OverrideMapper::set_u32(
+        &mut mapper.config.embedding.onnx.inter_op_threads,
+        overrides.inter_op_threads,
+    );
+    if let Some(provider) = overrides.execution_provider {
+        mapper.config.embedding.onnx.execution_provider = provider;
+    }
 }
 
 fn apply_embedding_cache_overrides(
@@ -664,9 +892,13 @@ fn apply_embedding_cache_overrides(
         &mut mapper.config.embedding.cache.disk_max_bytes,
         overrides.disk_max_bytes,
     );
+    if let Some(key_hash) = overrides.key_hash {
+        mapper.config.embedding.cache.key_hash = key_hash;
+    }
 }
 
 fn apply_vector_db_overrides(config: &mut BackendConfig, overrides: &VectorDbConfigOverrides) {
+    apply_vector_db_kernel_overrides(config, overrides);
     let mapper = OverrideMapper::new(config);
     OverrideMapper::set_opt_box_str(
         &mut mapper.config.vector_db.provider,
@@ -688,6 +920,10 @@ fn apply_vector_db_overrides(config: &mut BackendConfig, overrides: &VectorDbCon
         &mut mapper.config.vector_db.database,
         overrides.database.as_deref(),
     );
+    OverrideMapper::set_opt_box_str(
+        &mut mapper.config.vector_db.collection_template,
+        overrides.collection_template.as_deref(),
+    );
     OverrideMapper::set_opt_box_str(
         &mut mapper.config.vector_db.token,
         overrides.token.as_deref(),
@@ -712,10 +948,21 @@ fn apply_vector_db_overrides(config: &mut BackendConfig, overrides: &VectorDbCon
     if let Some(index) = overrides.index.as_ref() {
         mapper.config.vector_db.index = index.clone();
     }
+    if let Some(grpc) = overrides.grpc {
+        mapper.config.vector_db.grpc = grpc;
+    }
     OverrideMapper::set_u32(
         &mut mapper.config.vector_db.batch_size,
         overrides.batch_size,
     );
+    OverrideMapper::set_opt_u32(
+        &mut mapper.config.vector_db.shard_count,
+        overrides.shard_count,
+    );
+    OverrideMapper::set_u32(
+        &mut mapper.config.vector_db.max_filter_values,
+        overrides.max_filter_values,
+    );
     OverrideMapper::set_opt_snapshot_format(
         &mut mapper.config.vector_db.snapshot_format,
         overrides.snapshot_format,
@@ -724,6 +971,17 @@ fn apply_vector_db_overrides(config: &mut BackendConfig, overrides: &VectorDbCon
         &mut mapper.config.vector_db.snapshot_max_bytes,
         overrides.snapshot_max_bytes,
     );
+    OverrideMapper::set_bool(
+        &mut mapper.config.vector_db.snapshot_gzip,
+        overrides.snapshot_gzip,
+    );
+}
+
+fn apply_vector_db_kernel_overrides(
+    config: &mut BackendConfig,
+    overrides: &VectorDbConfigOverrides,
+) {
+    let mapper = OverrideMapper::new(config);
     if overrides.vector_kernel.is_some() {
         mapper.config.vector_db.vector_kernel = overrides.vector_kernel;
     }
@@ -770,12 +1028,37 @@ fn apply_sync_overrides(config: &mut BackendConfig, overrides: &SyncConfigOverri
         &mut mapper.config.sync.ignore_patterns,
         overrides.ignore_patterns.as_ref(),
     );
+    OverrideMapper::set_clone(
+        &mut mapper.config.sync.additional_ignore_patterns,
+        overrides.additional_ignore_patterns.as_ref(),
+    );
     OverrideMapper::set_u32(&mut mapper.config.sync.max_files, overrides.max_files);
     OverrideMapper::set_opt_u32(&mut mapper.config.sync.max_chunks, overrides.max_chunks);
+    OverrideMapper::set_u32(
+        &mut mapper.config.sync.min_chunk_chars,
+        overrides.min_chunk_chars,
+    );
     OverrideMapper::set_u64(
         &mut mapper.config.sync.max_file_size_bytes,
         overrides.max_file_size_bytes,
     );
+    OverrideMapper::set_clone(
+        &mut mapper.config.sync.per_extension_max_file_size_bytes,
+        overrides.per_extension_max_file_size_bytes.as_ref(),
+    );
+    OverrideMapper::set_opt_path_normalization(
+        &mut mapper.config.sync.path_normalization,
+        overrides.path_normalization,
+    );
+    OverrideMapper::set_opt_oversize_chunk_policy(
+        &mut mapper.config.sync.oversize_chunk_policy,
+        overrides.oversize_chunk_policy,
+    );
+    OverrideMapper::set_bool(&mut mapper.config.sync.skip_binary, overrides.skip_binary);
+    OverrideMapper::set_bool(
+        &mut mapper.config.sync.follow_symlinks,
+        overrides.follow_symlinks,
+    );
 }
 
 struct OverrideMapper<'a> {
@@ -823,6 +1106,24 @@ impl<'a> OverrideMapper<'a> {
         }
     }
 
+    const fn set_opt_path_normalization(
+        field: &mut PathNormalization,
+        value: Option<PathNormalization>,
+    ) {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+
+    const fn set_opt_oversize_chunk_policy(
+        field: &mut OversizeChunkPolicy,
+        value: Option<OversizeChunkPolicy>,
+    ) {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+
     const fn set_opt_snapshot_format(
         field: &mut VectorSnapshotFormat,
         value: Option<VectorSnapshotFormat>,
@@ -959,6 +1260,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn core_seed_defaults_unset_and_env_overrides_file() -> Result<(), Box<dyn std::error::Error>> {
+        let defaults =
+            load_backend_config_from_sources_with_env(None, None, &BackendEnv::default())?;
+        assert_eq!(defaults.core.seed, None);
+
+        let overrides_json = r#"{ "core": { "seed": 7 } }"#;
+        let env = BackendEnv {
+            core_seed: Some(42),
+            ..BackendEnv::default()
+        };
+        let config = load_backend_config_from_sources_with_env(None, Some(overrides_json), &env)?;
+        assert_eq!(config.core.seed, Some(42));
+        Ok(())
+    }
+
     // =========================================================================
     // ERROR PRECEDENCE TESTS (Code Review Fix 5)
     // =========================================================================
@@ -1059,4 +1376,46 @@ mod tests {
         assert_eq!(error.code, ErrorCode::new("config", "invalid_timeout"));
         Ok(())
     }
+
+    #[test]
+    fn stdin_config_matches_file_config() -> Result<(), Box<dyn std::error::Error>> {
+        let config_json = r#"{
+          "version": 1,
+          "core": { "timeoutMs": 45000, "maxConcurrency": 6 },
+          "embedding": { "batchSize": 16 }
+        }"#;
+        let dir = std::env::temp_dir().join(format!("sca-config-stdin-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let file_path = dir.join("config.json");
+        std::fs::write(&file_path, config_json)?;
+
+        let env = BackendEnv::default();
+        let from_file = load_backend_config_from_path_with_env(Some(&file_path), None, &env);
+        let from_stdin = load_backend_config_from_path_with_stdin(
+            Some(Path::new(STDIN_CONFIG_PATH)),
+            None,
+            &env,
+            || read_config_reader(config_json.as_bytes()),
+        );
+        std::fs::remove_dir_all(&dir)?;
+
+        let (from_file, from_stdin) = (from_file?, from_stdin?);
+        assert_eq!(from_stdin.as_ref(), from_file.as_ref());
+        assert_eq!(from_stdin.core.timeout_ms, 45000);
+        Ok(())
+    }
+
+    #[test]
+    fn stdin_config_detects_toml_and_yaml() -> Result<(), Box<dyn std::error::Error>> {
+        let toml = parse_config_detected("version = 1\n[core]\ntimeoutMs = 45000\n")?;
+        assert_eq!(toml.core.timeout_ms, 45000);
+        let yaml = parse_config_detected("version: 1\ncore:\n  timeoutMs: 45000\n")?;
+        assert_eq!(yaml.core.timeout_ms, 45000);
+
+        let error = parse_config_detected("  \n")
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected empty stdin error"))?;
+        assert_eq!(error.code, ErrorCode::new("config", "empty_config"));
+        Ok(())
+    }
 }
@@ -4,13 +4,14 @@
 //! passed into use-cases. Validation here is limited to:
 //! - shape (required fields, trimming)
 //! - bounds (topK/threshold)
-//! - provider-facing allowlists (filterExpr)
+//! - provider-facing allowlists (filterExpr, excludePaths, excludeLanguages)
 //!
 //! Domain invariants (e.g. `CollectionName` pattern) are delegated to domain
 //! constructors and not duplicated here.
 
+use crate::schema::BackendConfig;
 use schemars::JsonSchema;
-use semantic_code_domain::CollectionName;
+use semantic_code_domain::{CollectionName, Language, SearchExclusions, is_valid_extra_key};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope, Validate, Validated, ValidationError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -75,6 +76,22 @@ pub struct SearchRequestDto {
     /// Optional hint to include content payloads.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_content: Option<bool>,
+    /// Optional lines of surrounding source to add around included content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(field = "contextLines", range(min = 0, max = 200))]
+    pub context_lines: Option<u32>,
+    /// Optional flag to include a candidate score histogram in search stats.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_histogram: Option<bool>,
+    /// Optional flag to keep only the best-scoring chunk per file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by_file: Option<bool>,
+    /// Optional relative-path globs to exclude (`*` and `?` wildcards).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_paths: Option<Vec<String>>,
+    /// Optional language identifiers to exclude (e.g. `markdown`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_languages: Option<Vec<String>>,
 }
 
 /// Reindex-by-change request payload (boundary DTO).
@@ -113,6 +130,10 @@ pub struct ClearIndexRequestDto {
     /// Root path of the codebase to clear.
     #[validate(field = "codebaseRoot", non_empty)]
     pub codebase_root: String,
+    /// Optional explicit collection to clear instead of the derived one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(field = "collectionName", non_empty)]
+    pub collection_name: Option<String>,
 }
 
 /// Validated index request.
@@ -142,8 +163,18 @@ pub struct SearchRequest {
     pub threshold: Option<f64>,
     /// Optional validated filter expression (trimmed).
     pub filter_expr: Option<Box<str>>,
+    /// Canonical rendering of `filter_expr` (sorted conjuncts, normalized whitespace).
+    pub normalized_filter: Option<Box<str>>,
     /// Optional include-content hint.
     pub include_content: Option<bool>,
+    /// Optional lines of surrounding source around included content.
+    pub context_lines: Option<u32>,
+    /// Optional score-histogram flag.
+    pub include_histogram: Option<bool>,
+    /// Optional group-by-file flag.
+    pub group_by_file: Option<bool>,
+    /// Validated path and language exclusions.
+    pub exclusions: SearchExclusions,
 }
 
 /// Validated search request proof.
@@ -164,6 +195,8 @@ pub type ValidatedReindexByChangeRequest = Validated<ReindexByChangeRequest>;
 pub struct ClearIndexRequest {
     /// Normalized codebase root.
     pub codebase_root: PathBuf,
+    /// Optional validated collection name override.
+    pub collection_name: Option<CollectionName>,
 }
 
 /// Validated clear-index request proof.
@@ -210,14 +243,39 @@ pub enum RequestValidationError {
 }
 
 impl RequestValidationError {
-    fn error_code(&self) -> ErrorCode {
-        match self {
-            Self::EmptyField { .. } => ErrorCode::new("config", "empty_field"),
-            Self::InvalidField { .. } => ErrorCode::new("config", "invalid_field"),
-            Self::InvalidCodebaseRoot { .. } => ErrorCode::new("config", "invalid_codebase_root"),
-            Self::OutOfRange { .. } => ErrorCode::new("config", "out_of_range"),
-            Self::UnsupportedFilterExpr { .. } => ErrorCode::new("config", "invalid_filter_expr"),
-        }
+    /// Stable `request:*` code for this failure.
+    ///
+    /// Known fields get a dedicated code (`request:empty_query`,
+    /// `request:top_k_out_of_range`, ...); any other field falls back to the
+    /// generic `request:empty_field` / `request:invalid_field` /
+    /// `request:out_of_range`.
+    #[must_use]
+    pub fn error_code(&self) -> ErrorCode {
+        let code = match self {
+            Self::EmptyField { field } => match *field {
+                "codebaseRoot" => "empty_codebase_root",
+                "collectionName" => "empty_collection_name",
+                "query" => "empty_query",
+                "excludePaths" => "empty_exclude_path",
+                _ => "empty_field",
+            },
+            Self::InvalidField { field, .. } => match *field {
+                "query" => "invalid_query",
+                "excludePaths" => "invalid_exclude_path",
+                "excludeLanguages" => "invalid_exclude_language",
+                _ => "invalid_field",
+            },
+            Self::InvalidCodebaseRoot { .. } => "invalid_codebase_root",
+            Self::OutOfRange { field, .. } => match *field {
+                "topK" => "top_k_out_of_range",
+                "threshold" => "threshold_out_of_range",
+                "contextLines" => "context_lines_out_of_range",
+                "filterExpr" => "filter_values_out_of_range",
+                _ => "out_of_range",
+            },
+            Self::UnsupportedFilterExpr { .. } => "invalid_filter",
+        };
+        ErrorCode::new("request", code)
     }
 }
 
@@ -315,13 +373,7 @@ pub fn validate_index_request(
     dto.validate().map_err(ErrorEnvelope::from)?;
     let codebase_root = validate_codebase_root(&dto.codebase_root)?;
 
-    let collection_name = match dto.collection_name.as_deref() {
-        None => None,
-        Some(raw) => {
-            let trimmed = raw.trim();
-            Some(CollectionName::parse(trimmed).map_err(ErrorEnvelope::from)?)
-        },
-    };
+    let collection_name = validate_collection_name(dto.collection_name.as_deref())?;
 
     Ok(Validated::new(IndexRequest {
         codebase_root,
@@ -330,34 +382,87 @@ pub fn validate_index_request(
     }))
 }
 
-/// Validate and normalize a search request.
+/// Default cap on the number of values in one `in (...)` / `not in (...)` list.
+pub const DEFAULT_MAX_FILTER_VALUES: u32 = 1_024;
+
+/// Limits applied while validating search requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchRequestLimits {
+    /// Maximum number of values in one membership (`in` / `not in`) list.
+    pub max_filter_values: u32,
+}
+
+impl Default for SearchRequestLimits {
+    fn default() -> Self {
+        Self {
+            max_filter_values: DEFAULT_MAX_FILTER_VALUES,
+        }
+    }
+}
+
+impl SearchRequestLimits {
+    /// Limits configured for a backend (`vectorDb.maxFilterValues`).
+    #[must_use]
+    pub const fn from_config(config: &BackendConfig) -> Self {
+        Self {
+            max_filter_values: config.vector_db.max_filter_values,
+        }
+    }
+}
+
+/// Validate and normalize a search request with the default limits.
 pub fn validate_search_request(
     dto: &SearchRequestDto,
+) -> Result<ValidatedSearchRequest, ErrorEnvelope> {
+    validate_search_request_with_limits(dto, SearchRequestLimits::default())
+}
+
+/// Validate and normalize a search request.
+///
+/// Membership lists longer than `limits.max_filter_values` fail with
+/// `request:filter_values_out_of_range`.
+pub fn validate_search_request_with_limits(
+    dto: &SearchRequestDto,
+    limits: SearchRequestLimits,
 ) -> Result<ValidatedSearchRequest, ErrorEnvelope> {
     dto.validate().map_err(ErrorEnvelope::from)?;
     let codebase_root = validate_codebase_root(&dto.codebase_root)?;
     let query = require_trimmed("query", &dto.query)?;
 
-    let filter_expr = match dto.filter_expr.as_deref() {
-        None => None,
+    let (filter_expr, normalized_filter) = match dto.filter_expr.as_deref() {
+        None => (None, None),
         Some(raw) => {
             let trimmed = raw.trim();
             if trimmed.is_empty() {
-                None
+                (None, None)
             } else {
-                validate_filter_expr_allowlist(trimmed)?;
-                Some(trimmed.to_owned().into_boxed_str())
+                let parsed = validate_filter_expr_allowlist(trimmed)?;
+                validate_filter_value_count(&parsed, limits.max_filter_values)?;
+                (
+                    Some(trimmed.to_owned().into_boxed_str()),
+                    Some(parsed.to_string().into_boxed_str()),
+                )
             }
         },
     };
 
+    let exclusions = validate_search_exclusions(
+        dto.exclude_paths.as_deref().unwrap_or_default(),
+        dto.exclude_languages.as_deref().unwrap_or_default(),
+    )?;
+
     Ok(Validated::new(SearchRequest {
         codebase_root,
         query,
         top_k: dto.top_k,
         threshold: dto.threshold,
         filter_expr,
+        normalized_filter,
         include_content: dto.include_content,
+        context_lines: dto.context_lines,
+        include_histogram: dto.include_histogram,
+        group_by_file: dto.group_by_file,
+        exclusions,
     }))
 }
 
@@ -376,7 +481,16 @@ pub fn validate_clear_index_request(
 ) -> Result<ValidatedClearIndexRequest, ErrorEnvelope> {
     dto.validate().map_err(ErrorEnvelope::from)?;
     let codebase_root = validate_codebase_root(&dto.codebase_root)?;
-    Ok(Validated::new(ClearIndexRequest { codebase_root }))
+    let collection_name = validate_collection_name(dto.collection_name.as_deref())?;
+    Ok(Validated::new(ClearIndexRequest {
+        codebase_root,
+        collection_name,
+    }))
+}
+
+fn validate_collection_name(raw: Option<&str>) -> Result<Option<CollectionName>, ErrorEnvelope> {
+    raw.map(|raw| CollectionName::parse(raw.trim()).map_err(ErrorEnvelope::from))
+        .transpose()
 }
 
 fn require_trimmed(field: &'static str, value: &str) -> Result<Box<str>, ErrorEnvelope> {
@@ -422,69 +536,319 @@ fn validate_codebase_root(raw: &str) -> Result<PathBuf, ErrorEnvelope> {
     Ok(PathBuf::from(trimmed))
 }
 
+/// Comparison operator in a [`FilterExpr`] conjunct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FilterOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    NotEq,
+    /// `in (...)`
+    In,
+    /// `not in (...)`
+    NotIn,
+    /// `startsWith` (case-sensitive prefix match)
+    StartsWith,
+    /// `endsWith` (case-sensitive suffix match)
+    EndsWith,
+    /// `contains` (case-sensitive substring match)
+    Contains,
+}
+
+impl FilterOp {
+    /// Operator token as written in filter expressions.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::NotEq => "!=",
+            Self::In => "in",
+            Self::NotIn => "not in",
+            Self::StartsWith => "startsWith",
+            Self::EndsWith => "endsWith",
+            Self::Contains => "contains",
+        }
+    }
+
+    /// Whether the operator matches part of a string value.
+    #[must_use]
+    pub const fn is_string_match(self) -> bool {
+        matches!(self, Self::StartsWith | Self::EndsWith | Self::Contains)
+    }
+
+    /// Whether the operator takes a parenthesized value list.
+    #[must_use]
+    pub const fn is_membership(self) -> bool {
+        matches!(self, Self::In | Self::NotIn)
+    }
+}
+
+/// A single `field <op> '<value>'` or `field [not] in ('<a>', '<b>')` comparison.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FilterComparison {
+    /// Field name (left-hand side).
+    pub field: Box<str>,
+    /// Comparison operator.
+    pub op: FilterOp,
+    /// Unquoted comparison values: exactly one for `==` / `!=`, sorted and
+    /// de-duplicated for membership lists.
+    pub values: Vec<Box<str>>,
+}
+
+impl fmt::Display for FilterComparison {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} {} ", self.field, self.op.as_str())?;
+        if self.op.is_membership() {
+            formatter.write_str("(")?;
+        }
+        for (index, value) in self.values.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str(", ")?;
+            }
+            let quote = if value.contains('\'') { '"' } else { '\'' };
+            write!(formatter, "{quote}{value}{quote}")?;
+        }
+        if self.op.is_membership() {
+            formatter.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsed filter expression: a conjunction (`&&`) of field comparisons.
+///
+/// Conjuncts are kept sorted and de-duplicated, so `Display` yields a
+/// canonical form that is identical for equivalent inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterExpr {
+    conjuncts: Vec<FilterComparison>,
+}
+
+impl FilterExpr {
+    /// Parse a filter expression without applying the field allowlist.
+    ///
+    /// Returns `None` when the input does not match the grammar.
+    #[must_use]
+    pub fn parse(expr: &str) -> Option<Self> {
+        if expr.contains(['\n', '\r']) {
+            return None;
+        }
+        let mut conjuncts = split_conjuncts(expr)?
+            .into_iter()
+            .map(parse_comparison)
+            .collect::<Option<Vec<_>>>()?;
+        conjuncts.sort();
+        conjuncts.dedup();
+        Some(Self { conjuncts })
+    }
+
+    /// Comparisons in canonical order.
+    #[must_use]
+    pub fn conjuncts(&self) -> &[FilterComparison] {
+        &self.conjuncts
+    }
+}
+
+impl fmt::Display for FilterExpr {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, conjunct) in self.conjuncts.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str(" && ")?;
+            }
+            write!(formatter, "{conjunct}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Allowlist grammar for filter expressions.
 ///
-/// Currently supported:
+/// Currently supported, optionally joined with `&&`:
 /// - `relativePath == '<value>'`
 /// - `relativePath != '<value>'`
 /// - `language == '<value>'`
 /// - `fileExtension == '<value>'`
+/// - `extra.<key> == '<value>'` (custom metadata supplied at index time)
+/// - `<field> in ('<a>', '<b>', ...)` and `<field> not in (...)` for any of
+///   the fields above
+/// - `<field> startsWith '<value>'`, `endsWith`, and `contains` for the
+///   free-form string fields (`relativePath`, `fileExtension`, `extra.<key>`);
+///   matching is case-sensitive
 ///
 /// Where `<value>` is a single-quoted or double-quoted string with no newlines.
-pub fn validate_filter_expr_allowlist(expr: &str) -> Result<(), ErrorEnvelope> {
-    if expr.contains('\n') || expr.contains('\r') {
-        return Err(RequestValidationError::UnsupportedFilterExpr {
+/// Returns the parsed expression on success.
+pub fn validate_filter_expr_allowlist(expr: &str) -> Result<FilterExpr, ErrorEnvelope> {
+    let unsupported = || {
+        ErrorEnvelope::from(RequestValidationError::UnsupportedFilterExpr {
             expr: expr.to_owned(),
+        })
+    };
+    let parsed = FilterExpr::parse(expr).ok_or_else(unsupported)?;
+    let allowed = parsed.conjuncts().iter().all(|conjunct| {
+        let field = conjunct.field.as_ref();
+        let free_form = matches!(field, "relativePath" | "fileExtension")
+            || field.strip_prefix("extra.").is_some_and(is_valid_extra_key);
+        let known = free_form || field == "language";
+        let typed = free_form || !conjunct.op.is_string_match();
+        known && typed && conjunct.values.iter().all(|value| !value.is_empty())
+    });
+    if allowed {
+        Ok(parsed)
+    } else {
+        Err(unsupported())
+    }
+}
+
+/// Reject membership lists longer than `max` values.
+fn validate_filter_value_count(expr: &FilterExpr, max: u32) -> Result<(), ErrorEnvelope> {
+    let max_values = usize::try_from(max).unwrap_or(usize::MAX);
+    let longest = expr
+        .conjuncts()
+        .iter()
+        .map(|conjunct| conjunct.values.len())
+        .max()
+        .unwrap_or_default();
+    if longest > max_values {
+        return Err(RequestValidationError::OutOfRange {
+            field: "filterExpr",
+            value: longest.to_string(),
+            min: "1".to_owned(),
+            max: max.to_string(),
         }
         .into());
     }
+    Ok(())
+}
 
-    let (field, op, value) = parse_simple_comparison(expr).ok_or_else(|| {
-        ErrorEnvelope::from(RequestValidationError::UnsupportedFilterExpr {
-            expr: expr.to_owned(),
+/// Allowlist for search exclusions.
+///
+/// `excludePaths` entries are relative-path globs that must not contain quotes,
+/// backslashes, newlines, or NUL (they are embedded in provider expressions).
+/// `excludeLanguages` entries must be canonical language identifiers.
+fn validate_search_exclusions(
+    paths: &[String],
+    languages: &[String],
+) -> Result<SearchExclusions, ErrorEnvelope> {
+    let exclude_paths = paths
+        .iter()
+        .map(|raw| {
+            let trimmed = raw.trim();
+            if trimmed.is_empty() {
+                return Err(RequestValidationError::EmptyField {
+                    field: "excludePaths",
+                });
+            }
+            if trimmed.contains(['\'', '"', '\\', '\n', '\r', '\0']) {
+                return Err(RequestValidationError::InvalidField {
+                    field: "excludePaths",
+                    reason: "contains quotes, backslashes, or control characters",
+                });
+            }
+            Ok(trimmed.to_owned().into_boxed_str())
         })
-    })?;
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let exclude_languages = languages
+        .iter()
+        .map(|raw| {
+            Language::parse(raw.trim()).ok_or(RequestValidationError::InvalidField {
+                field: "excludeLanguages",
+                reason: "unknown language identifier",
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let allowed_field = matches!(field, "relativePath" | "language" | "fileExtension");
-    let allowed_op = matches!(op, "==" | "!=");
-    let non_empty_value = !value.is_empty();
+    Ok(SearchExclusions {
+        exclude_paths,
+        exclude_languages,
+    })
+}
 
-    if allowed_field && allowed_op && non_empty_value {
-        Ok(())
-    } else {
-        Err(RequestValidationError::UnsupportedFilterExpr {
-            expr: expr.to_owned(),
+/// Split on `&&` outside quoted values; every conjunct must be non-empty.
+fn split_conjuncts(input: &str) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    let mut chars = input.char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, '&') if chars.peek().is_some_and(|&(_, next)| next == '&') => {
+                chars.next();
+                parts.push(input[start..idx].trim());
+                start = idx + 2;
+            },
+            _ => {},
         }
-        .into())
     }
+    parts.push(input[start..].trim());
+    if parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    Some(parts)
 }
 
-fn parse_simple_comparison(input: &str) -> Option<(&str, &str, &str)> {
+fn parse_comparison(input: &str) -> Option<FilterComparison> {
     let input = input.trim();
-    let (field, rest) = split_once_ws(input)?;
+    let field_end = input
+        .find(|ch: char| !(ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.')))
+        .unwrap_or(input.len());
+    let (field, rest) = input.split_at(field_end);
+    if field.is_empty() {
+        return None;
+    }
     let rest = rest.trim_start();
-
-    let (op, rest) = if let Some(rest) = rest.strip_prefix("==") {
-        ("==", rest)
+    let (op, values) = if let Some(rest) = rest.strip_prefix("==") {
+        (FilterOp::Eq, vec![strip_quotes(rest)?.into()])
     } else if let Some(rest) = rest.strip_prefix("!=") {
-        ("!=", rest)
+        (FilterOp::NotEq, vec![strip_quotes(rest)?.into()])
+    } else if let Some(rest) = strip_keyword(rest, "in") {
+        (FilterOp::In, parse_value_list(rest)?)
+    } else if let Some(rest) = strip_keyword(rest, "not").and_then(|rest| strip_keyword(rest, "in"))
+    {
+        (FilterOp::NotIn, parse_value_list(rest)?)
     } else {
-        return None;
+        let (op, rest) = [FilterOp::StartsWith, FilterOp::EndsWith, FilterOp::Contains]
+            .into_iter()
+            .find_map(|op| strip_keyword(rest, op.as_str()).map(|rest| (op, rest)))?;
+        (op, vec![strip_quotes(rest)?.into()])
     };
+    Some(FilterComparison {
+        field: field.into(),
+        op,
+        values,
+    })
+}
 
-    let value = rest.trim_start();
-    let unquoted = strip_quotes(value)?;
-    Some((field, op, unquoted))
+/// Strip a leading keyword that is followed by whitespace or `(`.
+fn strip_keyword<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = input.strip_prefix(keyword)?;
+    rest.starts_with(|ch: char| ch.is_whitespace() || ch == '(')
+        .then(|| rest.trim_start())
 }
 
-fn split_once_ws(input: &str) -> Option<(&str, &str)> {
-    for (idx, ch) in input.char_indices() {
-        if ch.is_whitespace() {
-            let (left, right) = input.split_at(idx);
-            return Some((left, right));
+/// Parse `('<a>', '<b>', ...)` into sorted, de-duplicated unquoted values.
+fn parse_value_list(input: &str) -> Option<Vec<Box<str>>> {
+    let inner = input.trim().strip_prefix('(')?.strip_suffix(')')?;
+    let mut values = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    for (idx, ch) in inner.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None, '\'' | '"') => quote = Some(ch),
+            (None, ',') => {
+                values.push(Box::from(strip_quotes(&inner[start..idx])?));
+                start = idx + 1;
+            },
+            _ => {},
         }
     }
-    None
+    values.push(Box::from(strip_quotes(&inner[start..])?));
+    values.sort();
+    values.dedup();
+    Some(values)
 }
 
 fn strip_quotes(input: &str) -> Option<&str> {
@@ -516,6 +880,11 @@ mod tests {
             threshold: Some(0.5),
             filter_expr: None,
             include_content: Some(true),
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let validated = validate_search_request(&dto)?;
@@ -534,11 +903,16 @@ mod tests {
             threshold: None,
             filter_expr: None,
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "out_of_range"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "top_k_out_of_range"))
         );
     }
 
@@ -551,11 +925,16 @@ mod tests {
             threshold: Some(1.5),
             filter_expr: None,
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "out_of_range"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "threshold_out_of_range"))
         );
     }
 
@@ -568,11 +947,16 @@ mod tests {
             threshold: None,
             filter_expr: Some("score > 0.5".to_string()),
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "invalid_filter_expr"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_filter"))
         );
     }
 
@@ -585,23 +969,69 @@ mod tests {
             threshold: None,
             filter_expr: Some("relativePath\n== 'a'".to_string()),
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "invalid_filter_expr"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_filter"))
         );
     }
 
+    #[test]
+    fn validates_search_exclusions() -> Result<(), Box<dyn Error>> {
+        let mut dto = SearchRequestDto {
+            codebase_root: "/tmp/repo".to_string(),
+            query: "hello".to_string(),
+            top_k: None,
+            threshold: None,
+            filter_expr: None,
+            include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: Some(vec![" *.test.rs ".to_string()]),
+            exclude_languages: Some(vec!["markdown".to_string()]),
+        };
+
+        let validated = validate_search_request(&dto)?;
+        assert_eq!(
+            validated.exclusions,
+            SearchExclusions {
+                exclude_paths: vec!["*.test.rs".into()],
+                exclude_languages: vec![Language::Markdown],
+            }
+        );
+
+        dto.exclude_languages = Some(vec!["klingon".to_string()]);
+        let error = validate_search_request(&dto).err();
+        assert!(
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_exclude_language"))
+        );
+
+        dto.exclude_languages = None;
+        dto.exclude_paths = Some(vec!["src/\"x".to_string()]);
+        let error = validate_search_request(&dto).err();
+        assert!(
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_exclude_path"))
+        );
+        Ok(())
+    }
+
     #[test]
     fn rejects_codebase_root_with_url_scheme() {
         let dto = ClearIndexRequestDto {
             codebase_root: "https://example.com/repo".to_string(),
+            collection_name: None,
         };
 
         let error = validate_clear_index_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "invalid_codebase_root"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_codebase_root"))
         );
     }
 
@@ -613,7 +1043,7 @@ mod tests {
 
         let error = validate_reindex_by_change_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "invalid_codebase_root"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_codebase_root"))
         );
     }
 
@@ -626,11 +1056,16 @@ mod tests {
             threshold: None,
             filter_expr: None,
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "empty_field"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "empty_query"))
         );
     }
 
@@ -643,11 +1078,94 @@ mod tests {
             threshold: None,
             filter_expr: None,
             include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
         };
 
         let error = validate_search_request(&dto).err();
         assert!(
-            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("config", "invalid_field"))
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("request", "invalid_query"))
+        );
+    }
+
+    type SearchEdit = fn(&mut SearchRequestDto);
+
+    #[test]
+    fn each_validation_failure_has_a_distinct_code() {
+        let search = |edit: SearchEdit| {
+            let mut dto = SearchRequestDto {
+                codebase_root: "/tmp/repo".to_string(),
+                query: "hello".to_string(),
+                top_k: None,
+                threshold: None,
+                filter_expr: None,
+                include_content: None,
+                context_lines: None,
+                include_histogram: None,
+                group_by_file: None,
+                exclude_paths: None,
+                exclude_languages: None,
+            };
+            edit(&mut dto);
+            validate_search_request(&dto)
+                .err()
+                .map(|envelope| envelope.code.to_string())
+        };
+
+        let cases: [(SearchEdit, &str); 10] = [
+            (
+                |dto| dto.codebase_root = " ".to_string(),
+                "request:empty_codebase_root",
+            ),
+            (
+                |dto| dto.codebase_root = "s3://bucket".to_string(),
+                "request:invalid_codebase_root",
+            ),
+            (|dto| dto.query = String::new(), "request:empty_query"),
+            (
+                |dto| dto.query = "a\0b".to_string(),
+                "request:invalid_query",
+            ),
+            (|dto| dto.top_k = Some(51), "request:top_k_out_of_range"),
+            (
+                |dto| dto.threshold = Some(-0.1),
+                "request:threshold_out_of_range",
+            ),
+            (
+                |dto| dto.context_lines = Some(201),
+                "request:context_lines_out_of_range",
+            ),
+            (
+                |dto| dto.filter_expr = Some("a || b".to_string()),
+                "request:invalid_filter",
+            ),
+            (
+                |dto| dto.exclude_paths = Some(vec![" ".to_string()]),
+                "request:empty_exclude_path",
+            ),
+            (
+                |dto| dto.exclude_languages = Some(vec!["klingon".to_string()]),
+                "request:invalid_exclude_language",
+            ),
+        ];
+        for (edit, expected) in cases {
+            assert_eq!(search(edit).as_deref(), Some(expected));
+        }
+
+        let dto = IndexRequestDto {
+            codebase_root: "/tmp/repo".to_string(),
+            collection_name: Some("  ".to_string()),
+            force_reindex: None,
+        };
+        assert_eq!(
+            validate_index_request(&dto)
+                .err()
+                .map(|envelope| envelope.code.to_string())
+                .as_deref(),
+            Some("request:empty_collection_name")
         );
     }
 
@@ -665,11 +1183,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clear_request_accepts_explicit_collection_name() -> Result<(), ErrorEnvelope> {
+        let dto = ClearIndexRequestDto {
+            codebase_root: "/tmp/repo".to_string(),
+            collection_name: Some(" code_chunks_orphan ".to_string()),
+        };
+        let request = validate_clear_index_request(&dto)?;
+        assert_eq!(
+            request
+                .as_ref()
+                .collection_name
+                .as_ref()
+                .map(CollectionName::as_str),
+            Some("code_chunks_orphan")
+        );
+
+        let dto = ClearIndexRequestDto {
+            collection_name: Some("bad-name".to_string()),
+            ..dto
+        };
+        let error = validate_clear_index_request(&dto).err();
+        assert!(
+            matches!(error, Some(envelope) if envelope.code == ErrorCode::new("domain", "invalid_collection_name"))
+        );
+        Ok(())
+    }
+
     #[test]
     fn filter_expr_allowlist_accepts_simple_comparisons() -> Result<(), Box<dyn Error>> {
         validate_filter_expr_allowlist("relativePath == 'src/main.rs'")?;
         validate_filter_expr_allowlist("language != \"rust\"")?;
         validate_filter_expr_allowlist("fileExtension == 'rs'")?;
+        validate_filter_expr_allowlist("language == 'rust' && relativePath != 'a.rs'")?;
+        validate_filter_expr_allowlist("extra.team == 'core'")?;
+        assert!(validate_filter_expr_allowlist("extra. == 'core'").is_err());
+        assert!(validate_filter_expr_allowlist("extra.a.b == 'core'").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn filter_expr_accepts_membership_lists() -> Result<(), Box<dyn Error>> {
+        let parsed = validate_filter_expr_allowlist(
+            "relativePath in ('src/b.rs', \"src/a.rs\",'src/b.rs') && language not in ('md')",
+        )?;
+        assert_eq!(
+            parsed.to_string(),
+            "language not in ('md') && relativePath in ('src/a.rs', 'src/b.rs')"
+        );
+        assert!(validate_filter_expr_allowlist("relativePath in ()").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath in ('a', )").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath in 'a'").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath inside ('a')").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn filter_expr_accepts_string_matches_on_string_fields() -> Result<(), Box<dyn Error>> {
+        let parsed = validate_filter_expr_allowlist(
+            "relativePath startsWith 'src/' && fileExtension endsWith \"s\"",
+        )?;
+        assert_eq!(
+            parsed.to_string(),
+            "fileExtension endsWith 's' && relativePath startsWith 'src/'"
+        );
+        validate_filter_expr_allowlist("extra.team contains 'core'")?;
+        assert!(validate_filter_expr_allowlist("language startsWith 'ru'").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath startsWith ''").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath startsWith ('a')").is_err());
+        assert!(validate_filter_expr_allowlist("relativePath startswith 'a'").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn search_request_caps_membership_list_size() -> Result<(), Box<dyn Error>> {
+        let values = (0..5)
+            .map(|index| format!("'src/{index}.rs'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let dto = SearchRequestDto {
+            codebase_root: "/tmp/repo".to_string(),
+            query: "hello".to_string(),
+            top_k: None,
+            threshold: None,
+            filter_expr: Some(format!("relativePath in ({values})")),
+            include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
+        };
+        validate_search_request(&dto)?;
+
+        let mut config = BackendConfig::default();
+        config.vector_db.max_filter_values = 4;
+        let limits = SearchRequestLimits::from_config(&config);
+        let error = validate_search_request_with_limits(&dto, limits).err();
+        assert_eq!(
+            error.map(|envelope| envelope.code),
+            Some(ErrorCode::new("request", "filter_values_out_of_range"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn filter_expr_conjunct_order_and_spacing_normalize_identically() -> Result<(), Box<dyn Error>>
+    {
+        let spaced = FilterExpr::parse("a=='x' && b=='y'").ok_or("spaced expr rejected")?;
+        let swapped = FilterExpr::parse("b=='y'&&a=='x'").ok_or("swapped expr rejected")?;
+        assert_eq!(spaced, swapped);
+        assert_eq!(spaced.to_string(), "a == 'x' && b == 'y'");
+        assert_eq!(swapped.to_string(), spaced.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn search_request_carries_normalized_filter() -> Result<(), Box<dyn Error>> {
+        let dto = SearchRequestDto {
+            codebase_root: "/tmp/repo".to_string(),
+            query: "hello".to_string(),
+            top_k: None,
+            threshold: None,
+            filter_expr: Some("  language=='rust' &&   relativePath == \"src/x.rs\" ".to_string()),
+            include_content: None,
+            context_lines: None,
+            include_histogram: None,
+            group_by_file: None,
+            exclude_paths: None,
+            exclude_languages: None,
+        };
+        let validated = validate_search_request(&dto)?;
+        assert_eq!(
+            validated.normalized_filter.as_deref(),
+            Some("language == 'rust' && relativePath == 'src/x.rs'")
+        );
         Ok(())
     }
 }
@@ -26,6 +26,8 @@ pub struct RuntimeEnv {
     pub gemini_api_key: Option<SecretString>,
     /// Provider-specific Voyage API key.
     pub voyage_api_key: Option<SecretString>,
+    /// Provider-specific Azure OpenAI API key.
+    pub azure_openai_api_key: Option<SecretString>,
 
     /// Provider-specific OpenAI base URL.
     pub openai_base_url: Option<Box<str>>,
@@ -43,6 +45,12 @@ pub struct RuntimeEnv {
     pub ollama_model: Option<Box<str>>,
     /// Provider-specific Ollama host URL.
     pub ollama_host: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI endpoint.
+    pub azure_openai_endpoint: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI deployment name.
+    pub azure_openai_deployment: Option<Box<str>>,
+    /// Provider-specific Azure OpenAI API version.
+    pub azure_openai_api_version: Option<Box<str>>,
 
     /// Secret: vector DB token.
     pub vector_db_token: Option<SecretString>,
@@ -60,6 +68,7 @@ impl From<BackendEnv> for RuntimeEnv {
             openai_api_key: value.openai_api_key,
             gemini_api_key: value.gemini_api_key,
             voyage_api_key: value.voyage_api_key,
+            azure_openai_api_key: value.azure_openai_api_key,
             openai_base_url: value.openai_base_url,
             openai_model: value.openai_model,
             gemini_base_url: value.gemini_base_url,
@@ -68,6 +77,9 @@ impl From<BackendEnv> for RuntimeEnv {
             voyage_model: value.voyage_model,
             ollama_model: value.ollama_model,
             ollama_host: value.ollama_host,
+            azure_openai_endpoint: value.azure_openai_endpoint,
+            azure_openai_deployment: value.azure_openai_deployment,
+            azure_openai_api_version: value.azure_openai_api_version,
             vector_db_token: value.vector_db_token,
             vector_db_password: value.vector_db_password,
         }
@@ -6,11 +6,16 @@
 //! - Normalization enforces stable ordering for list fields.
 
 use crate::storage::{SnapshotStorageMode, VectorSnapshotFormat};
-use semantic_code_domain::IndexMode;
+use semantic_code_domain::{
+    DEFAULT_IGNORE_PATTERNS, IndexMode, Language, OversizeChunkPolicy, PathNormalization,
+    validate_collection_template,
+};
 use semantic_code_shared::{BoundedU32, BoundedU64, ErrorCode, ErrorEnvelope};
 use serde::{Deserialize, Serialize, de};
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 use std::fmt;
+use std::fmt::Write as _;
 use url::Url;
 
 /// Sanitizes a URL for error messages by stripping credentials.
@@ -63,22 +68,30 @@ const EMBEDDING_TIMEOUT_MIN_MS: u64 = 1_000;
 const EMBEDDING_TIMEOUT_MAX_MS: u64 = 1_200_000;
 const EMBEDDING_BATCH_SIZE_MIN: u32 = 1;
 const EMBEDDING_BATCH_SIZE_MAX: u32 = 8_192;
+const EMBEDDING_FAILURES_MIN: u32 = 1;
+const EMBEDDING_FAILURES_MAX: u32 = 1_000_000;
 const EMBEDDING_DIMENSION_MIN: u32 = 1;
 const EMBEDDING_DIMENSION_MAX: u32 = 65_536;
 const EMBEDDING_ONNX_SESSION_POOL_MIN: u32 = 1;
 const EMBEDDING_ONNX_SESSION_POOL_MAX: u32 = 64;
+const EMBEDDING_ONNX_THREADS_MIN: u32 = 0;
+const EMBEDDING_ONNX_THREADS_MAX: u32 = 256;
 const EMBEDDING_ROUTING_REMOTE_BATCHES_MIN: u32 = 1;
 const EMBEDDING_ROUTING_REMOTE_BATCHES_MAX: u32 = 1_000_000;
 const EMBEDDING_JOB_PROGRESS_MIN_MS: u64 = 50;
 const EMBEDDING_JOB_PROGRESS_MAX_MS: u64 = 60_000;
 const EMBEDDING_JOB_CANCEL_POLL_MIN_MS: u64 = 50;
 const EMBEDDING_JOB_CANCEL_POLL_MAX_MS: u64 = 60_000;
+const EMBEDDING_JOB_STATUS_HISTORY_MIN: u32 = 0;
+const EMBEDDING_JOB_STATUS_HISTORY_MAX: u32 = 100;
 const EMBEDDING_CACHE_MAX_ENTRIES_MIN: u32 = 1;
 const EMBEDDING_CACHE_MAX_ENTRIES_MAX: u32 = 100_000;
 const EMBEDDING_CACHE_MAX_BYTES_MIN: u64 = 1;
 const EMBEDDING_CACHE_MAX_BYTES_MAX: u64 = 10_000_000_000;
 const EMBEDDING_CACHE_DISK_MAX_BYTES_MIN: u64 = 1;
 const EMBEDDING_CACHE_DISK_MAX_BYTES_MAX: u64 = 100_000_000_000;
+const EMBEDDING_CACHE_WARMUP_CONCURRENCY_MIN: u32 = 1;
+const EMBEDDING_CACHE_WARMUP_CONCURRENCY_MAX: u32 = 256;
 
 const VECTOR_DB_TIMEOUT_MIN_MS: u64 = 1_000;
 const VECTOR_DB_TIMEOUT_MAX_MS: u64 = 1_200_000;
@@ -86,9 +99,17 @@ const VECTOR_DB_INDEX_TIMEOUT_MIN_MS: u64 = 1_000;
 const VECTOR_DB_INDEX_TIMEOUT_MAX_MS: u64 = 3_600_000;
 const VECTOR_DB_BATCH_SIZE_MIN: u32 = 1;
 const VECTOR_DB_BATCH_SIZE_MAX: u32 = 16_384;
+const VECTOR_DB_SHARD_COUNT_MIN: u32 = 1;
+const VECTOR_DB_SHARD_COUNT_MAX: u32 = 64;
+const VECTOR_DB_MAX_FILTER_VALUES_MIN: u32 = 1;
+const VECTOR_DB_MAX_FILTER_VALUES_MAX: u32 = 65_536;
 const VECTOR_DB_SNAPSHOT_MAX_BYTES_MIN: u64 = 1;
 const VECTOR_DB_SNAPSHOT_MAX_BYTES_MAX: u64 = 100_000_000_000;
 const VECTOR_DB_INDEX_PARAMS_MAX: usize = 128;
+const VECTOR_DB_GRPC_KEEP_ALIVE_MIN_MS: u64 = 1_000;
+const VECTOR_DB_GRPC_KEEP_ALIVE_MAX_MS: u64 = 3_600_000;
+const VECTOR_DB_GRPC_MAX_CHANNELS_MIN: u32 = 1;
+const VECTOR_DB_GRPC_MAX_CHANNELS_MAX: u32 = 64;
 
 const SYNC_MAX_FILES_MIN: u32 = 1;
 const SYNC_MAX_FILES_MAX: u32 = 10_000_000;
@@ -138,12 +159,25 @@ impl BackendConfig {
         self.embedding.validate()?;
         self.vector_db.normalize();
         self.vector_db.validate()?;
-        self.sync.normalize_and_validate()?;
+        self.sync
+            .normalize_and_validate(self.core.max_chunk_chars)?;
 
         let limits = ConfigLimits::new(&self)?;
         Ok(ValidatedBackendConfig { raw: self, limits })
     }
 
+    /// Validate and normalize the config, also returning non-fatal warnings.
+    ///
+    /// Warnings flag settings that are valid but likely suboptimal; they never
+    /// cause validation to fail.
+    pub fn validate_and_normalize_with_warnings(
+        self,
+    ) -> Result<(ValidatedBackendConfig, Vec<ConfigWarning>), ConfigSchemaError> {
+        let validated = self.validate_and_normalize()?;
+        let warnings = validated.warnings();
+        Ok((validated, warnings))
+    }
+
     const fn validate_version(&self) -> Result<(), ConfigSchemaError> {
         if self.version != CURRENT_CONFIG_VERSION {
             return Err(ConfigSchemaError::UnsupportedVersion {
@@ -180,6 +214,102 @@ impl ValidatedBackendConfig {
     pub fn into_inner(self) -> BackendConfig {
         self.raw
     }
+
+    /// Collect non-fatal warnings for valid but suboptimal settings.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<ConfigWarning> {
+        let cpu_count = std::thread::available_parallelism()
+            .ok()
+            .and_then(|count| u32::try_from(count.get()).ok());
+        collect_config_warnings(&self.raw, cpu_count)
+    }
+
+    /// Stable SHA-256 (lowercase hex) over the settings that shape stored
+    /// embeddings and chunk ids.
+    ///
+    /// Covers provider selection, model, dimension, document input template,
+    /// and path normalization. Operational settings (timeouts, batch sizes,
+    /// concurrency, caching) are excluded, so a changed fingerprint means the
+    /// embedding cache and index are stale and a full reindex is needed.
+    #[must_use]
+    pub fn fingerprint(&self) -> Box<str> {
+        let embedding = &self.raw.embedding;
+        let canonical = serde_json::json!({
+            "embedding.provider": embedding.provider,
+            "embedding.model": embedding.model,
+            "embedding.dimension": embedding.dimension,
+            "embedding.localFirst": embedding.local_first,
+            "embedding.localOnly": embedding.local_only,
+            "embedding.onnx.repo": embedding.onnx.repo,
+            "embedding.onnx.modelDir": embedding.onnx.model_dir,
+            "embedding.onnx.modelFilename": embedding.onnx.model_filename,
+            "embedding.routing.byLanguage": embedding.routing.by_language,
+            "embedding.inputTemplate.document": embedding.input_template.document,
+            "sync.pathNormalization": self.raw.sync.path_normalization,
+        });
+        let digest = Sha256::digest(canonical.to_string().as_bytes());
+        let mut hex = String::with_capacity(digest.len() * 2);
+        for byte in digest {
+            let _ = write!(hex, "{byte:02x}");
+        }
+        hex.into_boxed_str()
+    }
+}
+
+/// Non-fatal config finding reported alongside a validated config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigWarning {
+    /// Dotted config field path (e.g. `embedding.batchSize`).
+    pub field: Box<str>,
+    /// Human-readable explanation.
+    pub message: Box<str>,
+}
+
+impl ConfigWarning {
+    fn new(field: &str, message: impl Into<Box<str>>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// `maxConcurrency` above this multiple of the CPU count is flagged.
+const MAX_CONCURRENCY_CPU_FACTOR: u32 = 4;
+
+fn collect_config_warnings(config: &BackendConfig, cpu_count: Option<u32>) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+    if config.embedding.batch_size == 1 {
+        warnings.push(ConfigWarning::new(
+            "embedding.batchSize",
+            "batch size 1 sends one embedding request per chunk; indexing will be slow",
+        ));
+    }
+    if config.vector_db.batch_size == 1 {
+        warnings.push(ConfigWarning::new(
+            "vectorDb.batchSize",
+            "batch size 1 inserts one document per request; indexing will be slow",
+        ));
+    }
+    if let Some(cpu_count) = cpu_count
+        && config.core.max_concurrency > cpu_count.saturating_mul(MAX_CONCURRENCY_CPU_FACTOR)
+    {
+        warnings.push(ConfigWarning::new(
+            "core.maxConcurrency",
+            format!(
+                "{} is far above the {cpu_count} available CPUs; extra workers add contention",
+                config.core.max_concurrency
+            ),
+        ));
+    }
+    warnings
 }
 
 impl AsRef<BackendConfig> for ValidatedBackendConfig {
@@ -371,6 +501,11 @@ pub struct CoreConfig {
     /// Retry policy for transient failures.
     #[serde(default)]
     pub retry: RetryConfig,
+    /// Optional seed making randomized behavior (retry jitter) deterministic.
+    ///
+    /// Leave unset in production; set it to reproduce a run exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 impl Default for CoreConfig {
@@ -385,6 +520,7 @@ impl Default for CoreConfig {
             max_buffered_embeddings: None,
             max_chunk_chars: CORE_MAX_CHUNK_CHARS_DEFAULT,
             retry: RetryConfig::default(),
+            seed: None,
         }
     }
 }
@@ -540,6 +676,12 @@ pub struct OnnxEmbeddingConfig {
     pub download_on_missing: bool,
     /// Number of ONNX sessions to keep in the pool.
     pub session_pool_size: u32,
+    /// Execution provider used to run ONNX sessions.
+    pub execution_provider: OnnxExecutionProvider,
+    /// Threads used within a single operator (0 = ONNX Runtime default).
+    pub intra_op_threads: u32,
+    /// Threads used to run independent operators in parallel (0 = ONNX Runtime default).
+    pub inter_op_threads: u32,
 }
 
 impl Default for OnnxEmbeddingConfig {
@@ -551,6 +693,9 @@ impl Default for OnnxEmbeddingConfig {
             repo: None,
             download_on_missing: true,
             session_pool_size: 1,
+            execution_provider: OnnxExecutionProvider::Cpu,
+            intra_op_threads: 0,
+            inter_op_threads: 0,
         }
     }
 }
@@ -564,6 +709,52 @@ impl OnnxEmbeddingConfig {
     }
 }
 
+/// Execution provider for ONNX sessions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnnxExecutionProvider {
+    /// Run on the CPU (always available).
+    #[default]
+    Cpu,
+    /// Apple `CoreML` (macOS/iOS).
+    CoreMl,
+    /// NVIDIA CUDA.
+    Cuda,
+    /// Pick the fastest available provider, falling back to CPU.
+    Auto,
+}
+
+impl OnnxExecutionProvider {
+    /// Return the canonical config string for this provider.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::CoreMl => "coreml",
+            Self::Cuda => "cuda",
+            Self::Auto => "auto",
+        }
+    }
+
+    /// Parse an execution provider from user or env input.
+    pub fn parse(input: &str) -> Option<Self> {
+        let normalized = input.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "cpu" => Some(Self::Cpu),
+            "coreml" | "core_ml" | "core-ml" => Some(Self::CoreMl),
+            "cuda" => Some(Self::Cuda),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OnnxExecutionProvider {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
 /// Embedding routing mode for hybrid/local selection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -639,11 +830,32 @@ pub struct EmbeddingRoutingConfig {
     /// Split routing settings.
     #[serde(default)]
     pub split: EmbeddingSplitConfig,
+    /// Provider id per chunk language (e.g. `rust = "voyage"`); other languages
+    /// use the default provider.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub by_language: BTreeMap<Language, Box<str>>,
 }
 
 impl EmbeddingRoutingConfig {
+    fn normalize(&mut self) {
+        for provider in self.by_language.values_mut() {
+            *provider = provider.trim().to_ascii_lowercase().into_boxed_str();
+        }
+    }
+
     fn validate(&self) -> Result<(), ConfigSchemaError> {
         self.split.validate()?;
+        if self
+            .by_language
+            .values()
+            .any(|provider| provider.is_empty())
+        {
+            return Err(ConfigSchemaError::InvalidEmbeddingConfig {
+                section: "embedding.routing",
+                field: "byLanguage",
+                reason: "provider ids must be non-empty".to_owned(),
+            });
+        }
         Ok(())
     }
 }
@@ -656,6 +868,8 @@ pub struct EmbeddingJobsConfig {
     pub progress_interval_ms: u64,
     /// Cancel polling interval for background jobs (ms).
     pub cancel_poll_interval_ms: u64,
+    /// Number of recent jobs reported by `status`.
+    pub status_history_limit: u32,
 }
 
 impl Default for EmbeddingJobsConfig {
@@ -663,6 +877,7 @@ impl Default for EmbeddingJobsConfig {
         Self {
             progress_interval_ms: 250,
             cancel_poll_interval_ms: 250,
+            status_history_limit: 10,
         }
     }
 }
@@ -683,6 +898,13 @@ impl EmbeddingJobsConfig {
             EMBEDDING_JOB_CANCEL_POLL_MIN_MS,
             EMBEDDING_JOB_CANCEL_POLL_MAX_MS,
         )?;
+        validate_limit_u32(
+            "embedding.jobs",
+            "statusHistoryLimit",
+            self.status_history_limit,
+            EMBEDDING_JOB_STATUS_HISTORY_MIN,
+            EMBEDDING_JOB_STATUS_HISTORY_MAX,
+        )?;
         Ok(())
     }
 }
@@ -707,6 +929,15 @@ pub struct EmbeddingConfig {
     pub timeout_ms: u64,
     /// Batch size for embedding calls.
     pub batch_size: u32,
+    /// Abort indexing once more than this many batches fail back to back
+    /// (after retries). Unset with `maxTotalFailures` also unset aborts on the
+    /// first failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_failures: Option<u32>,
+    /// Abort indexing once more than this many batches fail in total; files of
+    /// tolerated failures are reindexed on the next reindex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_failures: Option<u32>,
     /// Prefer local ONNX embeddings over remote providers.
     pub local_first: bool,
     /// Force local ONNX embeddings only.
@@ -723,6 +954,9 @@ pub struct EmbeddingConfig {
     /// Embedding cache configuration.
     #[serde(default)]
     pub cache: EmbeddingCacheConfig,
+    /// Instruction prefixes prepended to embedding inputs.
+    #[serde(default)]
+    pub input_template: EmbeddingInputTemplateConfig,
 }
 
 impl Default for EmbeddingConfig {
@@ -734,12 +968,15 @@ impl Default for EmbeddingConfig {
             dimension: None,
             timeout_ms: 60_000,
             batch_size: 32,
+            max_consecutive_failures: None,
+            max_total_failures: None,
             local_first: false,
             local_only: false,
             onnx: OnnxEmbeddingConfig::default(),
             routing: EmbeddingRoutingConfig::default(),
             jobs: EmbeddingJobsConfig::default(),
             cache: EmbeddingCacheConfig::default(),
+            input_template: EmbeddingInputTemplateConfig::default(),
         }
     }
 }
@@ -750,6 +987,7 @@ impl EmbeddingConfig {
         normalize_optional_trimmed(&mut self.model);
         normalize_optional_trimmed(&mut self.base_url);
         self.onnx.normalize();
+        self.routing.normalize();
         self.cache.normalize();
     }
 
@@ -780,6 +1018,20 @@ impl EmbeddingConfig {
             EMBEDDING_BATCH_SIZE_MIN,
             EMBEDDING_BATCH_SIZE_MAX,
         )?;
+        validate_optional_limit_u32(
+            "embedding",
+            "maxConsecutiveFailures",
+            self.max_consecutive_failures,
+            EMBEDDING_FAILURES_MIN,
+            EMBEDDING_FAILURES_MAX,
+        )?;
+        validate_optional_limit_u32(
+            "embedding",
+            "maxTotalFailures",
+            self.max_total_failures,
+            EMBEDDING_FAILURES_MIN,
+            EMBEDDING_FAILURES_MAX,
+        )?;
         validate_limit_u32(
             "embedding.onnx",
             "sessionPoolSize",
@@ -787,9 +1039,57 @@ impl EmbeddingConfig {
             EMBEDDING_ONNX_SESSION_POOL_MIN,
             EMBEDDING_ONNX_SESSION_POOL_MAX,
         )?;
+        validate_limit_u32(
+            "embedding.onnx",
+            "intraOpThreads",
+            self.onnx.intra_op_threads,
+            EMBEDDING_ONNX_THREADS_MIN,
+            EMBEDDING_ONNX_THREADS_MAX,
+        )?;
+        validate_limit_u32(
+            "embedding.onnx",
+            "interOpThreads",
+            self.onnx.inter_op_threads,
+            EMBEDDING_ONNX_THREADS_MIN,
+            EMBEDDING_ONNX_THREADS_MAX,
+        )?;
         self.routing.validate()?;
         self.jobs.validate()?;
         self.cache.validate()?;
+        self.input_template.validate()?;
+        Ok(())
+    }
+}
+
+/// Instruction prefixes for instruction-tuned embedding models (e.g. E5, Nomic).
+///
+/// Templates are prepended verbatim (no trimming), so trailing spaces such as
+/// `"query: "` are preserved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct EmbeddingInputTemplateConfig {
+    /// Prefix applied to chunk content at index time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<str>>,
+    /// Prefix applied to search queries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<Box<str>>,
+}
+
+impl EmbeddingInputTemplateConfig {
+    fn validate(&self) -> Result<(), ConfigSchemaError> {
+        for (field, template) in [("document", &self.document), ("query", &self.query)] {
+            if template
+                .as_deref()
+                .is_some_and(|template| template.trim().is_empty())
+            {
+                return Err(ConfigSchemaError::InvalidEmbeddingConfig {
+                    section: "embedding.inputTemplate",
+                    field,
+                    reason: "template must be non-empty when set".to_owned(),
+                });
+            }
+        }
         Ok(())
     }
 }
@@ -820,6 +1120,10 @@ pub struct EmbeddingCacheConfig {
     pub disk_table: Option<Box<str>>,
     /// Maximum disk cache bytes.
     pub disk_max_bytes: u64,
+    /// Maximum concurrent lookups when warming the cache for a batch.
+    pub warmup_concurrency: u32,
+    /// Hash used to derive cache keys; changing it invalidates existing entries.
+    pub key_hash: EmbeddingCacheKeyHash,
 }
 
 impl Default for EmbeddingCacheConfig {
@@ -834,6 +1138,8 @@ impl Default for EmbeddingCacheConfig {
             disk_connection: None,
             disk_table: None,
             disk_max_bytes: 1024 * 1024 * 1024,
+            warmup_concurrency: 8,
+            key_hash: EmbeddingCacheKeyHash::Sha256,
         }
     }
 }
@@ -870,6 +1176,13 @@ impl EmbeddingCacheConfig {
                 EMBEDDING_CACHE_DISK_MAX_BYTES_MIN,
                 EMBEDDING_CACHE_DISK_MAX_BYTES_MAX,
             )?;
+            validate_limit_u32(
+                "embedding.cache",
+                "warmupConcurrency",
+                self.warmup_concurrency,
+                EMBEDDING_CACHE_WARMUP_CONCURRENCY_MIN,
+                EMBEDDING_CACHE_WARMUP_CONCURRENCY_MAX,
+            )?;
             let provider = self
                 .disk_provider
                 .unwrap_or(EmbeddingCacheDiskProvider::Sqlite);
@@ -956,6 +1269,17 @@ pub enum EmbeddingCacheDiskProvider {
     Mssql,
 }
 
+/// Hash algorithm used to derive embedding cache keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingCacheKeyHash {
+    /// SHA-256 (collision resistant).
+    #[default]
+    Sha256,
+    /// BLAKE3 (faster; requires the `cache-blake3` feature).
+    Blake3,
+}
+
 /// Local vector search strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -1343,6 +1667,9 @@ pub struct VectorDbConfig {
     /// Optional database name for the vector DB provider.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database: Option<Box<str>>,
+    /// Optional collection naming template (`{chunks}` and `{hash}` placeholders).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_template: Option<Box<str>>,
     /// Optional auth token (kept in memory; not serialized).
     #[serde(skip_serializing)]
     pub token: Option<Box<str>>,
@@ -1362,8 +1689,19 @@ pub struct VectorDbConfig {
     pub index_timeout_ms: u64,
     /// Index configuration for dense and sparse vector fields.
     pub index: VectorDbIndexConfig,
+    /// gRPC connection reuse settings (Milvus gRPC provider).
+    pub grpc: VectorDbGrpcConfig,
     /// Batch size for inserts/deletes.
     pub batch_size: u32,
+    /// Split the index across this many `<collection>_shard_<n>` collections.
+    ///
+    /// Recorded in the manifest when indexing; changing it requires a force
+    /// reindex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard_count: Option<u32>,
+    /// Maximum number of values in one search filter membership list
+    /// (`in` / `not in`).
+    pub max_filter_values: u32,
     /// Snapshot persistence mode for local vector DBs.
     pub snapshot_storage: SnapshotStorageMode,
     /// Snapshot format used by the local vector DB.
@@ -1371,6 +1709,8 @@ pub struct VectorDbConfig {
     /// Optional max bytes allowed per local snapshot write.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot_max_bytes: Option<u64>,
+    /// Gzip-compress v1 JSON snapshots (`<collection>.json.gz`).
+    pub snapshot_gzip: bool,
     /// Explicit local kernel family override.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vector_kernel: Option<VectorKernelKind>,
@@ -1420,6 +1760,7 @@ impl Default for VectorDbConfig {
             address: None,
             base_url: None,
             database: None,
+            collection_template: None,
             token: None,
             username: None,
             password: None,
@@ -1428,10 +1769,14 @@ impl Default for VectorDbConfig {
             timeout_ms: 60_000,
             index_timeout_ms: 60_000,
             index: VectorDbIndexConfig::default(),
+            grpc: VectorDbGrpcConfig::default(),
             batch_size: 128,
+            shard_count: None,
+            max_filter_values: crate::requests::DEFAULT_MAX_FILTER_VALUES,
             snapshot_storage: SnapshotStorageMode::default(),
             snapshot_format: VectorSnapshotFormat::default(),
             snapshot_max_bytes: None,
+            snapshot_gzip: false,
             vector_kernel: None,
             search_strategy: None,
             experimental_u8_search: false,
@@ -1479,6 +1824,48 @@ impl VectorDbIndexConfig {
     }
 }
 
+/// gRPC channel reuse and keep-alive settings.
+///
+/// The adapter opens `maxChannels` channels once and reuses them for every
+/// operation instead of reconnecting per call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct VectorDbGrpcConfig {
+    /// HTTP/2 keep-alive ping interval (ms) for idle and active channels.
+    pub keep_alive_ms: u64,
+    /// Number of pooled channels; operations are spread round-robin.
+    pub max_channels: u32,
+}
+
+impl Default for VectorDbGrpcConfig {
+    fn default() -> Self {
+        Self {
+            keep_alive_ms: 30_000,
+            max_channels: 1,
+        }
+    }
+}
+
+impl VectorDbGrpcConfig {
+    fn validate(self) -> Result<(), ConfigSchemaError> {
+        validate_limit_u64(
+            "vectorDb.grpc",
+            "keepAliveMs",
+            self.keep_alive_ms,
+            VECTOR_DB_GRPC_KEEP_ALIVE_MIN_MS,
+            VECTOR_DB_GRPC_KEEP_ALIVE_MAX_MS,
+        )?;
+        validate_limit_u32(
+            "vectorDb.grpc",
+            "maxChannels",
+            self.max_channels,
+            VECTOR_DB_GRPC_MAX_CHANNELS_MIN,
+            VECTOR_DB_GRPC_MAX_CHANNELS_MAX,
+        )?;
+        Ok(())
+    }
+}
+
 /// Vector DB index spec for a single field.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -1526,6 +1913,7 @@ impl VectorDbConfig {
         normalize_optional_trimmed(&mut self.address);
         normalize_optional_trimmed(&mut self.base_url);
         normalize_optional_trimmed(&mut self.database);
+        normalize_optional_trimmed(&mut self.collection_template);
         normalize_optional_trimmed(&mut self.token);
         normalize_optional_trimmed(&mut self.username);
         normalize_optional_trimmed(&mut self.password);
@@ -1557,6 +1945,20 @@ impl VectorDbConfig {
             VECTOR_DB_BATCH_SIZE_MIN,
             VECTOR_DB_BATCH_SIZE_MAX,
         )?;
+        validate_optional_limit_u32(
+            "vectorDb",
+            "shardCount",
+            self.shard_count,
+            VECTOR_DB_SHARD_COUNT_MIN,
+            VECTOR_DB_SHARD_COUNT_MAX,
+        )?;
+        validate_limit_u32(
+            "vectorDb",
+            "maxFilterValues",
+            self.max_filter_values,
+            VECTOR_DB_MAX_FILTER_VALUES_MIN,
+            VECTOR_DB_MAX_FILTER_VALUES_MAX,
+        )?;
         if let Some(snapshot_max_bytes) = self.snapshot_max_bytes {
             validate_limit_u64(
                 "vectorDb",
@@ -1567,7 +1969,17 @@ impl VectorDbConfig {
             )?;
         }
         validate_snapshot_storage("vectorDb", "snapshotStorage", &self.snapshot_storage)?;
+        if let Some(template) = self.collection_template.as_deref() {
+            validate_collection_template(template).map_err(|error| {
+                ConfigSchemaError::InvalidCollectionTemplate {
+                    section: "vectorDb",
+                    field: "collectionTemplate",
+                    reason: error.to_string(),
+                }
+            })?;
+        }
         self.index.validate()?;
+        self.grpc.validate()?;
         if let Some(ref hnsw) = self.hnsw_build {
             hnsw.validate()?;
         }
@@ -1604,12 +2016,33 @@ pub struct SyncConfig {
     pub allowed_extensions: Vec<Box<str>>,
     /// Ignore patterns applied during scan.
     pub ignore_patterns: Vec<Box<str>>,
+    /// Highest-precedence ignore patterns, layered above `ignore_patterns` and
+    /// `.contextignore` (set by the CLI `--ignore` flag).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additional_ignore_patterns: Vec<Box<str>>,
     /// Maximum number of files considered during a scan.
     pub max_files: u32,
     /// Optional maximum number of chunks considered during a scan.
     pub max_chunks: Option<u32>,
+    /// Chunks shorter than this many characters (after trimming whitespace) are
+    /// dropped before embedding; `0` keeps every chunk.
+    pub min_chunk_chars: u32,
     /// Maximum file size (bytes) for reading contents.
     pub max_file_size_bytes: u64,
+    /// Per-extension overrides for `max_file_size_bytes` (keys like `md` or `min.js`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub per_extension_max_file_size_bytes: BTreeMap<Box<str>, u64>,
+    /// Canonicalization of relative paths used for chunk ids and metadata.
+    pub path_normalization: PathNormalization,
+    /// Handling of chunks longer than the embedding provider's input limit.
+    pub oversize_chunk_policy: OversizeChunkPolicy,
+    /// Skip files whose leading bytes contain NUL bytes or invalid UTF-8.
+    pub skip_binary: bool,
+    /// Follow symlinked files and directories while scanning.
+    ///
+    /// Links resolving outside the codebase root are refused and links back
+    /// into the walked path are skipped.
+    pub follow_symlinks: bool,
 }
 
 impl Default for SyncConfig {
@@ -1617,17 +2050,40 @@ impl Default for SyncConfig {
         Self {
             allowed_extensions: default_allowed_extensions(),
             ignore_patterns: default_ignore_patterns(),
+            additional_ignore_patterns: Vec::new(),
             max_files: 250_000,
             max_chunks: None,
+            min_chunk_chars: 0,
             max_file_size_bytes: 2_000_000,
+            per_extension_max_file_size_bytes: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::Split,
+            skip_binary: true,
+            follow_symlinks: false,
         }
     }
 }
 
 impl SyncConfig {
-    fn normalize_and_validate(&mut self) -> Result<(), ConfigSchemaError> {
+    /// Largest file size any extension may be read at.
+    ///
+    /// Filesystem adapters enforce this ceiling; the scanner applies the
+    /// per-extension limits on top of it.
+    #[must_use]
+    pub fn max_file_size_ceiling_bytes(&self) -> u64 {
+        self.per_extension_max_file_size_bytes
+            .values()
+            .copied()
+            .fold(self.max_file_size_bytes, u64::max)
+    }
+
+    fn normalize_and_validate(&mut self, max_chunk_chars: u32) -> Result<(), ConfigSchemaError> {
         self.allowed_extensions = normalize_extensions(&self.allowed_extensions)?;
         self.ignore_patterns = normalize_ignore_patterns(&self.ignore_patterns)?;
+        self.additional_ignore_patterns =
+            normalize_pattern_entries(&self.additional_ignore_patterns)?;
+        self.per_extension_max_file_size_bytes =
+            normalize_extension_size_limits(&self.per_extension_max_file_size_bytes)?;
 
         if self.allowed_extensions.len() > SYNC_ALLOWED_EXTENSIONS_MAX {
             return Err(ConfigSchemaError::ListTooLarge {
@@ -1647,6 +2103,15 @@ impl SyncConfig {
             });
         }
 
+        if self.additional_ignore_patterns.len() > SYNC_IGNORE_PATTERNS_MAX {
+            return Err(ConfigSchemaError::ListTooLarge {
+                section: "sync",
+                field: "additionalIgnorePatterns",
+                len: self.additional_ignore_patterns.len(),
+                max: SYNC_IGNORE_PATTERNS_MAX,
+            });
+        }
+
         validate_limit_u32(
             "sync",
             "maxFiles",
@@ -1663,6 +2128,14 @@ impl SyncConfig {
                 SYNC_MAX_CHUNKS_MAX,
             )?;
         }
+        // Must stay below `core.maxChunkChars` or every chunk would be dropped.
+        validate_limit_u32(
+            "sync",
+            "minChunkChars",
+            self.min_chunk_chars,
+            0,
+            max_chunk_chars.saturating_sub(1),
+        )?;
         validate_limit_u64(
             "sync",
             "maxFileSizeBytes",
@@ -1670,6 +2143,23 @@ impl SyncConfig {
             SYNC_MAX_FILE_SIZE_MIN_BYTES,
             SYNC_MAX_FILE_SIZE_MAX_BYTES,
         )?;
+        if self.per_extension_max_file_size_bytes.len() > SYNC_ALLOWED_EXTENSIONS_MAX {
+            return Err(ConfigSchemaError::ListTooLarge {
+                section: "sync",
+                field: "perExtensionMaxFileSizeBytes",
+                len: self.per_extension_max_file_size_bytes.len(),
+                max: SYNC_ALLOWED_EXTENSIONS_MAX,
+            });
+        }
+        for limit in self.per_extension_max_file_size_bytes.values() {
+            validate_limit_u64(
+                "sync",
+                "perExtensionMaxFileSizeBytes",
+                *limit,
+                SYNC_MAX_FILE_SIZE_MIN_BYTES,
+                SYNC_MAX_FILE_SIZE_MAX_BYTES,
+            )?;
+        }
 
         Ok(())
     }
@@ -1750,6 +2240,13 @@ pub enum ConfigSchemaError {
         /// Invalid path value.
         path: String,
     },
+    /// Snapshot storage mode keyword is invalid.
+    InvalidSnapshotStorageMode {
+        /// Value provided.
+        value: String,
+        /// Human readable reason.
+        reason: &'static str,
+    },
     /// A cache config value is invalid.
     InvalidCacheConfig {
         /// Schema section (e.g. `embedding.cache`).
@@ -1768,6 +2265,24 @@ pub enum ConfigSchemaError {
         /// Human readable reason.
         reason: String,
     },
+    /// An embedding config value is invalid.
+    InvalidEmbeddingConfig {
+        /// Schema section (e.g. `embedding.inputTemplate`).
+        section: &'static str,
+        /// Field name in the config file.
+        field: &'static str,
+        /// Human readable reason.
+        reason: String,
+    },
+    /// The collection naming template is invalid.
+    InvalidCollectionTemplate {
+        /// Schema section (e.g. `vectorDb`).
+        section: &'static str,
+        /// Field name in the config file.
+        field: &'static str,
+        /// Human readable reason.
+        reason: String,
+    },
 }
 
 impl ConfigSchemaError {
@@ -1780,11 +2295,17 @@ impl ConfigSchemaError {
             Self::InvalidExtension { .. } => ErrorCode::new("config", "invalid_extension"),
             Self::InvalidIgnorePattern { .. } => ErrorCode::new("config", "invalid_ignore_pattern"),
             Self::InvalidUrl { .. } => ErrorCode::new("config", "invalid_url"),
-            Self::InvalidSnapshotStoragePath { .. } => {
+            Self::InvalidSnapshotStoragePath { .. } | Self::InvalidSnapshotStorageMode { .. } => {
                 ErrorCode::new("config", "invalid_snapshot_storage")
             },
             Self::InvalidCacheConfig { .. } => ErrorCode::new("config", "invalid_cache_config"),
             Self::InvalidIndexConfig { .. } => ErrorCode::new("config", "invalid_index_config"),
+            Self::InvalidEmbeddingConfig { .. } => {
+                ErrorCode::new("config", "invalid_embedding_config")
+            },
+            Self::InvalidCollectionTemplate { .. } => {
+                ErrorCode::new("config", "invalid_collection_template")
+            },
         }
     }
 }
@@ -1842,6 +2363,12 @@ impl fmt::Display for ConfigSchemaError {
                     "invalid snapshot storage path for {section}.{field}"
                 )
             },
+            Self::InvalidSnapshotStorageMode { value, reason } => {
+                write!(
+                    formatter,
+                    "invalid snapshot storage mode `{value}`: {reason}"
+                )
+            },
             Self::InvalidCacheConfig {
                 section,
                 field,
@@ -1858,6 +2385,19 @@ impl fmt::Display for ConfigSchemaError {
                 formatter,
                 "invalid index config for {section}.{field}: {reason}"
             ),
+            Self::InvalidEmbeddingConfig {
+                section,
+                field,
+                reason,
+            } => write!(
+                formatter,
+                "invalid embedding config for {section}.{field}: {reason}"
+            ),
+            Self::InvalidCollectionTemplate {
+                section,
+                field,
+                reason,
+            } => write!(formatter, "invalid {section}.{field}: {reason}"),
         }
     }
 }
@@ -1916,53 +2456,68 @@ impl From<ConfigSchemaError> for ErrorEnvelope {
                     .with_metadata("len", len.to_string())
                     .with_metadata("max", max.to_string());
             },
-            ConfigSchemaError::InvalidExtension { extension } => {
-                envelope = envelope.with_metadata("extension", extension);
-            },
-            ConfigSchemaError::InvalidIgnorePattern { pattern } => {
-                envelope = envelope.with_metadata("pattern", pattern);
-            },
-            ConfigSchemaError::InvalidUrl {
-                section,
-                field,
-                url,
-            } => {
-                envelope = envelope
-                    .with_metadata("section", section)
-                    .with_metadata("field", field)
-                    .with_metadata("url", sanitize_url_for_error(&url));
-            },
-            ConfigSchemaError::InvalidSnapshotStoragePath {
-                section,
-                field,
-                path,
-            } => {
-                envelope = envelope
-                    .with_metadata("section", section)
-                    .with_metadata("field", field)
-                    .with_metadata("path", path);
-            },
-            ConfigSchemaError::InvalidCacheConfig {
-                section,
-                field,
-                reason,
-            }
-            | ConfigSchemaError::InvalidIndexConfig {
-                section,
-                field,
-                reason,
-            } => {
-                envelope = envelope
-                    .with_metadata("section", section)
-                    .with_metadata("field", field)
-                    .with_metadata("reason", reason);
-            },
+            other => envelope = with_detail_metadata(envelope, other),
         }
 
         envelope
     }
 }
 
+/// Attach metadata for the path/pattern/reason-bearing schema errors.
+fn with_detail_metadata(envelope: ErrorEnvelope, error: ConfigSchemaError) -> ErrorEnvelope {
+    match error {
+        ConfigSchemaError::InvalidExtension { extension } => {
+            envelope.with_metadata("extension", extension)
+        },
+        ConfigSchemaError::InvalidIgnorePattern { pattern } => {
+            envelope.with_metadata("pattern", pattern)
+        },
+        ConfigSchemaError::InvalidUrl {
+            section,
+            field,
+            url,
+        } => envelope
+            .with_metadata("section", section)
+            .with_metadata("field", field)
+            .with_metadata("url", sanitize_url_for_error(&url)),
+        ConfigSchemaError::InvalidSnapshotStoragePath {
+            section,
+            field,
+            path,
+        } => envelope
+            .with_metadata("section", section)
+            .with_metadata("field", field)
+            .with_metadata("path", path),
+        ConfigSchemaError::InvalidSnapshotStorageMode { value, .. } => {
+            envelope.with_metadata("value", value)
+        },
+        ConfigSchemaError::InvalidCacheConfig {
+            section,
+            field,
+            reason,
+        }
+        | ConfigSchemaError::InvalidIndexConfig {
+            section,
+            field,
+            reason,
+        }
+        | ConfigSchemaError::InvalidEmbeddingConfig {
+            section,
+            field,
+            reason,
+        }
+        | ConfigSchemaError::InvalidCollectionTemplate {
+            section,
+            field,
+            reason,
+        } => envelope
+            .with_metadata("section", section)
+            .with_metadata("field", field)
+            .with_metadata("reason", reason),
+        _ => envelope,
+    }
+}
+
 fn validate_index_spec(
     section: &'static str,
     field_prefix: &'static str,
@@ -2262,7 +2817,45 @@ fn normalize_extensions(input: &[Box<str>]) -> Result<Vec<Box<str>>, ConfigSchem
     Ok(normalized)
 }
 
+/// Normalize per-extension size keys; unlike `allowedExtensions`, keys may
+/// span several dot-separated segments (`min.js`) to target compound suffixes.
+fn normalize_extension_size_limits(
+    input: &BTreeMap<Box<str>, u64>,
+) -> Result<BTreeMap<Box<str>, u64>, ConfigSchemaError> {
+    let mut normalized = BTreeMap::new();
+    for (ext, limit) in input {
+        let trimmed = ext.trim();
+        let trimmed = trimmed.strip_prefix("*.").unwrap_or(trimmed);
+        let trimmed = trimmed.strip_prefix('.').unwrap_or(trimmed);
+        let candidate = trimmed.to_ascii_lowercase();
+
+        let valid = candidate.split('.').all(|segment| {
+            !segment.is_empty() && segment.chars().all(|ch| ch.is_ascii_alphanumeric())
+        });
+        if !valid {
+            return Err(ConfigSchemaError::InvalidExtension {
+                extension: trimmed.to_owned(),
+            });
+        }
+
+        normalized.insert(candidate.into_boxed_str(), *limit);
+    }
+    Ok(normalized)
+}
+
 fn normalize_ignore_patterns(input: &[Box<str>]) -> Result<Vec<Box<str>>, ConfigSchemaError> {
+    let mut normalized = normalize_pattern_entries(input)?;
+    if !normalized
+        .iter()
+        .any(|pattern| pattern.as_ref() == ".context/")
+    {
+        normalized.push(".context/".into());
+        normalized.sort_unstable();
+    }
+    Ok(normalized)
+}
+
+fn normalize_pattern_entries(input: &[Box<str>]) -> Result<Vec<Box<str>>, ConfigSchemaError> {
     let mut normalized = Vec::with_capacity(input.len());
     for pattern in input {
         let raw = pattern.as_ref().trim();
@@ -2276,13 +2869,6 @@ fn normalize_ignore_patterns(input: &[Box<str>]) -> Result<Vec<Box<str>>, Config
         normalized.push(collapsed.into_boxed_str());
     }
 
-    if !normalized
-        .iter()
-        .any(|pattern| pattern.as_ref() == ".context/")
-    {
-        normalized.push(".context/".into());
-    }
-
     normalized.sort_unstable();
     normalized.dedup();
     Ok(normalized)
@@ -2327,12 +2913,10 @@ fn default_allowed_extensions() -> Vec<Box<str>> {
 }
 
 fn default_ignore_patterns() -> Vec<Box<str>> {
-    vec![
-        ".context/".into(),
-        ".git/".into(),
-        "node_modules/".into(),
-        "target/".into(),
-    ]
+    DEFAULT_IGNORE_PATTERNS
+        .iter()
+        .map(|pattern| Box::from(*pattern))
+        .collect()
 }
 
 #[cfg(test)]
@@ -2379,6 +2963,149 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn unknown_onnx_execution_provider_is_rejected() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "embedding": { "onnx": { "executionProvider": "cuda" } }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(
+            config.embedding.onnx.execution_provider,
+            OnnxExecutionProvider::Cuda
+        );
+
+        let payload = serde_json::json!({
+            "version": 1,
+            "embedding": { "onnx": { "executionProvider": "tpu" } }
+        });
+        let error = parse_backend_config_json(&payload.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected validation error"))?;
+        assert_eq!(error.code, ErrorCode::new("config", "invalid_json"));
+        Ok(())
+    }
+
+    #[test]
+    fn onnx_thread_counts_round_trip() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "embedding": { "onnx": { "intraOpThreads": 8, "interOpThreads": 2 } }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(config.embedding.onnx.intra_op_threads, 8);
+        assert_eq!(config.embedding.onnx.inter_op_threads, 2);
+
+        let serialized = serde_json::to_string(config.as_ref())?;
+        let reparsed = parse_backend_config_json(&serialized)?;
+        assert_eq!(reparsed.embedding.onnx, config.embedding.onnx);
+        Ok(())
+    }
+
+    #[test]
+    fn onnx_thread_counts_out_of_range_are_rejected() -> Result<(), Box<dyn Error>> {
+        for field in ["intraOpThreads", "interOpThreads"] {
+            let payload = serde_json::json!({
+                "version": 1,
+                "embedding": { "onnx": { field: 1024 } }
+            });
+            let error = parse_backend_config_json(&payload.to_string())
+                .err()
+                .ok_or_else(|| std::io::Error::other("expected validation error"))?;
+            assert_eq!(error.code, ErrorCode::new("config", "invalid_limit"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hnsw_build_round_trip() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "vectorDb": { "hnswBuild": { "maxNbConnection": 48, "efConstruction": 400 } }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(
+            config.vector_db.hnsw_build,
+            Some(HnswBuildConfig {
+                max_nb_connection: 48,
+                ef_construction: 400,
+            })
+        );
+
+        let serialized = serde_json::to_string(config.as_ref())?;
+        let reparsed = parse_backend_config_json(&serialized)?;
+        assert_eq!(reparsed.vector_db.hnsw_build, config.vector_db.hnsw_build);
+        Ok(())
+    }
+
+    #[test]
+    fn hnsw_ef_construction_below_minimum_is_rejected() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "vectorDb": { "hnswBuild": { "efConstruction": HNSW_EF_CONSTRUCTION_MIN - 1 } }
+        });
+        let error = parse_backend_config_json(&payload.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected validation error"))?;
+        assert_eq!(error.code, ErrorCode::new("config", "invalid_limit"));
+        Ok(())
+    }
+
+    #[test]
+    fn fingerprint_tracks_embedding_fields_only() -> Result<(), Box<dyn Error>> {
+        let fingerprint = |payload: serde_json::Value| -> Result<Box<str>, ErrorEnvelope> {
+            Ok(parse_backend_config_json(&payload.to_string())?.fingerprint())
+        };
+        let base = fingerprint(serde_json::json!({
+            "version": 1,
+            "embedding": { "provider": "openai", "model": "text-embedding-3-small" }
+        }))?;
+        assert_eq!(base.len(), 64);
+
+        let other_model = fingerprint(serde_json::json!({
+            "version": 1,
+            "embedding": { "provider": "openai", "model": "text-embedding-3-large" }
+        }))?;
+        assert_ne!(base, other_model);
+
+        let other_timeout = fingerprint(serde_json::json!({
+            "version": 1,
+            "core": { "timeoutMs": 5000 },
+            "embedding": { "provider": "openai", "model": "text-embedding-3-small" }
+        }))?;
+        assert_eq!(base, other_timeout);
+        Ok(())
+    }
+
+    #[test]
+    fn vector_db_grpc_defaults_and_bounds() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "vectorDb": { "grpc": { "maxChannels": 4 } }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(
+            config.vector_db.grpc,
+            VectorDbGrpcConfig {
+                keep_alive_ms: 30_000,
+                max_channels: 4,
+            }
+        );
+
+        for grpc in [
+            serde_json::json!({ "maxChannels": 0 }),
+            serde_json::json!({ "maxChannels": VECTOR_DB_GRPC_MAX_CHANNELS_MAX + 1 }),
+            serde_json::json!({ "keepAliveMs": VECTOR_DB_GRPC_KEEP_ALIVE_MIN_MS - 1 }),
+        ] {
+            let payload = serde_json::json!({ "version": 1, "vectorDb": { "grpc": grpc } });
+            let error = parse_backend_config_json(&payload.to_string())
+                .err()
+                .ok_or_else(|| std::io::Error::other("expected validation error"))?;
+            assert_eq!(error.code, ErrorCode::new("config", "invalid_limit"));
+        }
+        Ok(())
+    }
+
     #[test]
     fn normalization_is_deterministic() -> Result<(), Box<dyn Error>> {
         let payload = serde_json::json!({
@@ -2412,6 +3139,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn per_extension_size_limits_are_normalized_and_validated() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "sync": {
+                "maxFileSizeBytes": 1_000,
+                "perExtensionMaxFileSizeBytes": { ".MIN.JS": 500, "*.md": 5_000 }
+            }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        let keys: Vec<&str> = config
+            .sync
+            .per_extension_max_file_size_bytes
+            .keys()
+            .map(AsRef::as_ref)
+            .collect();
+        assert_eq!(keys, vec!["md", "min.js"]);
+        assert_eq!(config.sync.max_file_size_ceiling_bytes(), 5_000);
+
+        let invalid = serde_json::json!({
+            "version": 1,
+            "sync": { "perExtensionMaxFileSizeBytes": { "min..js": 500 } }
+        });
+        let error = parse_backend_config_json(&invalid.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected invalid extension error"))?;
+        assert_eq!(error.code, ErrorCode::new("config", "invalid_extension"));
+
+        let zero = serde_json::json!({
+            "version": 1,
+            "sync": { "perExtensionMaxFileSizeBytes": { "md": 0 } }
+        });
+        assert!(parse_backend_config_json(&zero.to_string()).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn max_list_sizes_are_enforced() -> Result<(), Box<dyn Error>> {
         let extensions: Vec<String> = (0..=SYNC_ALLOWED_EXTENSIONS_MAX)
@@ -2553,6 +3317,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn min_chunk_chars_must_stay_below_max_chunk_chars() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "core": { "maxChunkChars": 500 },
+            "sync": { "minChunkChars": 499 }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(config.sync.min_chunk_chars, 499);
+
+        let invalid = serde_json::json!({
+            "version": 1,
+            "core": { "maxChunkChars": 500 },
+            "sync": { "minChunkChars": 500 }
+        });
+        let error = parse_backend_config_json(&invalid.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected invalid limit error"))?;
+        assert_eq!(error.code, ErrorCode::new("config", "invalid_limit"));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_template_requires_hash_placeholder() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "vectorDb": { "collectionTemplate": " team_{chunks}_{hash} " }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(
+            config.vector_db.collection_template.as_deref(),
+            Some("team_{chunks}_{hash}")
+        );
+
+        let invalid = serde_json::json!({
+            "version": 1,
+            "vectorDb": { "collectionTemplate": "team_{chunks}" }
+        });
+        let error = parse_backend_config_json(&invalid.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected invalid template error"))?;
+        assert_eq!(
+            error.code,
+            ErrorCode::new("config", "invalid_collection_template")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn embedding_input_template_keeps_spacing_and_rejects_blank() -> Result<(), Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "version": 1,
+            "embedding": { "inputTemplate": { "document": "passage: ", "query": "query: " } }
+        });
+        let config = parse_backend_config_json(&payload.to_string())?;
+        assert_eq!(
+            config.embedding.input_template.document.as_deref(),
+            Some("passage: ")
+        );
+        assert_eq!(
+            config.embedding.input_template.query.as_deref(),
+            Some("query: ")
+        );
+
+        let invalid = serde_json::json!({
+            "version": 1,
+            "embedding": { "inputTemplate": { "query": "  " } }
+        });
+        let error = parse_backend_config_json(&invalid.to_string())
+            .err()
+            .ok_or_else(|| std::io::Error::other("expected blank template error"))?;
+        assert_eq!(
+            error.code,
+            ErrorCode::new("config", "invalid_embedding_config")
+        );
+        Ok(())
+    }
+
     #[test]
     fn dfrr_search_config_defaults_when_absent() -> Result<(), Box<dyn Error>> {
         let payload = serde_json::json!({
@@ -2795,4 +3637,31 @@ mod tests {
         assert_eq!(prewarm.len(), 2);
         Ok(())
     }
+
+    #[test]
+    fn batch_size_one_warns_but_validates() -> Result<(), Box<dyn Error>> {
+        let mut config = BackendConfig::default();
+        config.embedding.batch_size = 1;
+
+        let (validated, warnings) = config.validate_and_normalize_with_warnings()?;
+        assert_eq!(validated.embedding.batch_size, 1);
+        assert!(
+            warnings
+                .iter()
+                .any(|warning| warning.field.as_ref() == "embedding.batchSize")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn config_warnings_flag_concurrency_above_cpu_count() {
+        let mut config = BackendConfig::default();
+        assert!(collect_config_warnings(&config, Some(4)).is_empty());
+
+        config.core.max_concurrency = 64;
+        let warnings = collect_config_warnings(&config, Some(4));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field.as_ref(), "core.maxConcurrency");
+        assert!(collect_config_warnings(&config, None).is_empty());
+    }
 }
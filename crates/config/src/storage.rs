@@ -1,8 +1,12 @@
 //! Snapshot storage configuration.
 
+use crate::schema::ConfigSchemaError;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+/// Directory name used under the per-user cache root.
+const USER_CACHE_DIR: &str = "semantic-code";
+
 /// Persistence mode for local vector snapshots.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -14,9 +18,48 @@ pub enum SnapshotStorageMode {
     Project,
     /// Store under a custom absolute path.
     Custom(PathBuf),
+    /// Store under the per-user cache directory, shared across codebases.
+    UserCache,
 }
 
 impl SnapshotStorageMode {
+    /// Parse a storage mode keyword: `disabled`, `project`, `user-cache`, or
+    /// `custom:<path>` (`custom=<path>` is also accepted).
+    ///
+    /// Keywords are case-insensitive; the custom path keeps its original case.
+    pub fn parse(value: &str) -> Result<Self, ConfigSchemaError> {
+        let normalized = value.trim();
+        let invalid = |reason: &'static str| ConfigSchemaError::InvalidSnapshotStorageMode {
+            value: normalized.to_owned(),
+            reason,
+        };
+        if normalized.is_empty() {
+            return Err(invalid("storage mode cannot be empty"));
+        }
+
+        let lower = normalized.to_ascii_lowercase();
+        match lower.as_str() {
+            "disabled" => Ok(Self::Disabled),
+            "project" => Ok(Self::Project),
+            "user-cache" | "usercache" => Ok(Self::UserCache),
+            "custom" => Err(invalid(
+                "custom storage mode requires a path (custom:/path)",
+            )),
+            _ if lower.starts_with("custom:") || lower.starts_with("custom=") => {
+                let path = normalized["custom:".len()..].trim();
+                if path.is_empty() {
+                    return Err(invalid(
+                        "custom storage mode requires a path (custom:/path)",
+                    ));
+                }
+                Ok(Self::Custom(PathBuf::from(path)))
+            },
+            _ => Err(invalid(
+                "expected disabled, project, user-cache, or custom:<path>",
+            )),
+        }
+    }
+
     /// Resolve the snapshot root directory for a codebase.
     #[must_use]
     pub fn resolve_root(&self, codebase_root: &Path) -> Option<PathBuf> {
@@ -24,10 +67,25 @@ impl SnapshotStorageMode {
             Self::Disabled => None,
             Self::Project => Some(codebase_root.join(".context")),
             Self::Custom(path) => Some(path.clone()),
+            Self::UserCache => Some(user_cache_root().join(USER_CACHE_DIR)),
         }
     }
 }
 
+/// Platform cache directory: `$XDG_CACHE_HOME`, then `$HOME/.cache`, then
+/// `%LOCALAPPDATA%`, falling back to the system temp dir.
+fn user_cache_root() -> PathBuf {
+    let absolute_var = |name: &str| {
+        std::env::var_os(name)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+    };
+    absolute_var("XDG_CACHE_HOME")
+        .or_else(|| absolute_var("HOME").map(|home| home.join(".cache")))
+        .or_else(|| absolute_var("LOCALAPPDATA"))
+        .unwrap_or_else(std::env::temp_dir)
+}
+
 /// Snapshot format used by the local vector DB.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -49,3 +107,46 @@ impl VectorSnapshotFormat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_each_keyword() -> Result<(), ConfigSchemaError> {
+        assert_eq!(
+            SnapshotStorageMode::parse("disabled")?,
+            SnapshotStorageMode::Disabled
+        );
+        assert_eq!(
+            SnapshotStorageMode::parse(" Project ")?,
+            SnapshotStorageMode::Project
+        );
+        assert_eq!(
+            SnapshotStorageMode::parse("user-cache")?,
+            SnapshotStorageMode::UserCache
+        );
+        assert_eq!(
+            SnapshotStorageMode::parse("custom:/tmp/Snapshots")?,
+            SnapshotStorageMode::Custom(PathBuf::from("/tmp/Snapshots"))
+        );
+        assert_eq!(
+            SnapshotStorageMode::parse("CUSTOM=/tmp/snapshots")?,
+            SnapshotStorageMode::Custom(PathBuf::from("/tmp/snapshots"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rejects_empty_and_invalid_values() {
+        for value in ["", "   ", "custom", "custom:", "custom:  ", "memory"] {
+            assert!(
+                matches!(
+                    SnapshotStorageMode::parse(value),
+                    Err(ConfigSchemaError::InvalidSnapshotStorageMode { .. })
+                ),
+                "expected `{value}` to be rejected"
+            );
+        }
+    }
+}
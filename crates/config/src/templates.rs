@@ -0,0 +1,210 @@
+//! Provider-specific starter configs written by `init --template`.
+
+use crate::schema::CURRENT_CONFIG_VERSION;
+use crate::storage::SnapshotStorageMode;
+
+/// Starter config template for a provider combination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigTemplate {
+    /// Local ONNX embeddings with the local vector index.
+    LocalOnnx,
+    /// Ollama embeddings with the local vector index.
+    LocalOllama,
+    /// `OpenAI` embeddings with the local vector index.
+    LocalOpenAi,
+    /// `OpenAI` embeddings with a Milvus vector DB.
+    MilvusOpenAi,
+}
+
+impl ConfigTemplate {
+    /// Every template, in display order.
+    pub const ALL: [Self; 4] = [
+        Self::LocalOnnx,
+        Self::LocalOllama,
+        Self::LocalOpenAi,
+        Self::MilvusOpenAi,
+    ];
+
+    /// Stable template name used on the CLI.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::LocalOnnx => "local-onnx",
+            Self::LocalOllama => "local-ollama",
+            Self::LocalOpenAi => "local-openai",
+            Self::MilvusOpenAi => "milvus-openai",
+        }
+    }
+
+    /// Look up a template by name (case-insensitive).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Comma-separated template names, for error messages.
+    #[must_use]
+    pub fn names() -> String {
+        Self::ALL
+            .iter()
+            .map(|template| template.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Render the commented TOML starter config.
+    ///
+    /// A `storage_mode` is written into the `[vectorDb]` table; otherwise the
+    /// schema default (`project`) applies.
+    #[must_use]
+    pub fn render(self, storage_mode: Option<&SnapshotStorageMode>) -> String {
+        let mut output = format!(
+            "# Starter config generated by `sca init --template {}`.\n\
+             # Every field is optional; omitted fields keep their defaults.\n\
+             # See docs/reference/config-schema.md and docs/reference/env-vars.md.\n\n\
+             version = {CURRENT_CONFIG_VERSION}\n\n",
+            self.name()
+        );
+        output.push_str(self.embedding_section());
+        output.push('\n');
+        output.push_str("[vectorDb]\n");
+        if let Some(line) = storage_mode.and_then(snapshot_storage_line) {
+            output.push_str(&line);
+        }
+        output.push_str(self.vector_db_fields());
+        output
+    }
+
+    const fn embedding_section(self) -> &'static str {
+        match self {
+            Self::LocalOnnx => {
+                "[embedding]\n\
+                 # Embeds on this machine; no API key needed.\n\
+                 provider = \"onnx\"\n\
+                 localOnly = true\n\n\
+                 [embedding.onnx]\n\
+                 # Hugging Face repo downloaded on first use\n\
+                 # (env: SCA_EMBEDDING_ONNX_REPO).\n\
+                 repo = \"Xenova/all-MiniLM-L6-v2\"\n\
+                 downloadOnMissing = true\n\
+                 # Point at pre-downloaded assets instead (env: SCA_EMBEDDING_ONNX_MODEL_DIR).\n\
+                 # modelDir = \"/path/to/model\"\n\
+                 # cpu, coreml, cuda, or auto (env: SCA_EMBEDDING_ONNX_EP).\n\
+                 executionProvider = \"cpu\"\n"
+            },
+            Self::LocalOllama => {
+                "[embedding]\n\
+                 # Requires a running Ollama server (env: OLLAMA_HOST).\n\
+                 provider = \"ollama\"\n\
+                 # Pull the model first: `ollama pull nomic-embed-text` (env: OLLAMA_MODEL).\n\
+                 model = \"nomic-embed-text\"\n\
+                 baseUrl = \"http://localhost:11434\"\n"
+            },
+            Self::LocalOpenAi | Self::MilvusOpenAi => {
+                "[embedding]\n\
+                 # Set OPENAI_API_KEY in the environment; keys are never read from this file.\n\
+                 provider = \"openai\"\n\
+                 # env: SCA_EMBEDDING_MODEL\n\
+                 model = \"text-embedding-3-small\"\n\
+                 # Must match the model's output size (env: SCA_EMBEDDING_DIMENSION).\n\
+                 dimension = 1536\n\
+                 # Uncomment for an OpenAI-compatible proxy (env: SCA_EMBEDDING_BASE_URL).\n\
+                 # baseUrl = \"https://api.openai.com/v1\"\n"
+            },
+        }
+    }
+
+    const fn vector_db_fields(self) -> &'static str {
+        match self {
+            Self::LocalOnnx | Self::LocalOllama | Self::LocalOpenAi => {
+                "# Local index stored alongside the codebase (env: SCA_VECTOR_DB_PROVIDER).\n\
+                 provider = \"local\"\n\
+                 # dense or hybrid (env: SCA_VECTOR_DB_INDEX_MODE).\n\
+                 indexMode = \"dense\"\n"
+            },
+            Self::MilvusOpenAi => {
+                "# Milvus over gRPC; use \"milvus-rest\" for the REST API\n\
+                 # (env: SCA_VECTOR_DB_PROVIDER).\n\
+                 provider = \"milvus\"\n\
+                 # env: SCA_VECTOR_DB_ADDRESS\n\
+                 address = \"localhost:19530\"\n\
+                 # Set SCA_VECTOR_DB_TOKEN for Zilliz Cloud or an auth-enabled server.\n\
+                 # database = \"default\"\n\
+                 # ssl = true\n\
+                 # dense or hybrid (env: SCA_VECTOR_DB_INDEX_MODE).\n\
+                 indexMode = \"dense\"\n"
+            },
+        }
+    }
+}
+
+fn snapshot_storage_line(mode: &SnapshotStorageMode) -> Option<String> {
+    let value = toml::Value::try_from(mode).ok()?;
+    Some(format!("snapshotStorage = {value}\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BackendConfig;
+    use std::path::PathBuf;
+
+    fn parse(rendered: &str) -> Result<BackendConfig, toml::de::Error> {
+        toml::from_str(rendered)
+    }
+
+    #[test]
+    fn every_template_validates() -> Result<(), Box<dyn std::error::Error>> {
+        for template in ConfigTemplate::ALL {
+            let config = parse(&template.render(None))?;
+            let validated = config
+                .validate_and_normalize()
+                .map_err(|error| format!("{}: {error:?}", template.name()))?;
+            assert!(
+                validated.embedding.provider.is_some(),
+                "{}",
+                template.name()
+            );
+            assert_eq!(
+                validated.vector_db.snapshot_storage,
+                SnapshotStorageMode::Project
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn storage_mode_is_written_into_vector_db() -> Result<(), Box<dyn std::error::Error>> {
+        for mode in [
+            SnapshotStorageMode::Disabled,
+            SnapshotStorageMode::UserCache,
+            SnapshotStorageMode::Custom(PathBuf::from("/tmp/sca-snapshots")),
+        ] {
+            let rendered = ConfigTemplate::MilvusOpenAi.render(Some(&mode));
+            let validated = parse(&rendered)?
+                .validate_and_normalize()
+                .map_err(|error| format!("{error:?}"))?;
+            assert_eq!(validated.vector_db.snapshot_storage, mode);
+            assert_eq!(
+                validated.vector_db.address.as_deref(),
+                Some("localhost:19530")
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for template in ConfigTemplate::ALL {
+            assert_eq!(ConfigTemplate::from_name(template.name()), Some(template));
+        }
+        assert_eq!(
+            ConfigTemplate::from_name(" Milvus-OpenAI "),
+            Some(ConfigTemplate::MilvusOpenAi)
+        );
+        assert_eq!(ConfigTemplate::from_name("pinecone"), None);
+    }
+}
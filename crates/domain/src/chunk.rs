@@ -1,4 +1,4 @@
-//! Chunk content with compile-time max length.
+//! Chunk content with a compile-time max length and optional runtime cap.
 
 use semantic_code_shared::{ErrorCode, ErrorEnvelope};
 use std::fmt;
@@ -6,22 +6,25 @@ use std::fmt;
 /// Hard upper bound for chunk content length.
 pub const MAX_CHUNK_CHARS: usize = 20_000;
 
-/// Error when chunk content exceeds the maximum size.
+/// Chunk construction errors.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ChunkError {
-    /// Observed length.
-    pub length: usize,
-    /// Maximum allowed length.
-    pub max: usize,
+pub enum ChunkError {
+    /// Content is longer than the effective maximum.
+    TooLong {
+        /// Observed length (characters).
+        len: usize,
+        /// Maximum allowed length (characters).
+        max: usize,
+    },
 }
 
 impl fmt::Display for ChunkError {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            formatter,
-            "chunk length {} exceeds max {}",
-            self.length, self.max
-        )
+        match self {
+            Self::TooLong { len, max } => {
+                write!(formatter, "chunk length {len} exceeds max {max}")
+            },
+        }
     }
 }
 
@@ -29,12 +32,13 @@ impl std::error::Error for ChunkError {}
 
 impl From<ChunkError> for ErrorEnvelope {
     fn from(error: ChunkError) -> Self {
+        let ChunkError::TooLong { len, max } = error;
         Self::expected(
             ErrorCode::new("domain", "chunk_too_large"),
             error.to_string(),
         )
-        .with_metadata("length", error.length.to_string())
-        .with_metadata("max", error.max.to_string())
+        .with_metadata("length", len.to_string())
+        .with_metadata("max", max.to_string())
     }
 }
 
@@ -45,10 +49,18 @@ pub struct Chunk<const MAX: usize>(Box<str>);
 impl<const MAX: usize> Chunk<MAX> {
     /// Validate and build a chunk from content.
     pub fn new(content: impl Into<Box<str>>) -> Result<Self, ChunkError> {
+        Self::with_max(content, MAX)
+    }
+
+    /// Validate against a runtime max (e.g. `core.maxChunkChars`).
+    ///
+    /// The effective limit is `max` clamped to the compile-time `MAX`.
+    pub fn with_max(content: impl Into<Box<str>>, max: usize) -> Result<Self, ChunkError> {
         let content = content.into();
-        let length = content.len();
-        if length > MAX {
-            return Err(ChunkError { length, max: MAX });
+        let max = max.min(MAX);
+        let len = content.chars().count();
+        if len > max {
+            return Err(ChunkError::TooLong { len, max });
         }
         Ok(Self(content))
     }
@@ -71,3 +83,34 @@ impl<const MAX: usize> AsRef<str> for Chunk<MAX> {
         self.as_str()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_max_accepts_content_at_the_limit() -> Result<(), ChunkError> {
+        let chunk = Chunk::<MAX_CHUNK_CHARS>::with_max("abcd", 4)?;
+        assert_eq!(chunk.as_str(), "abcd");
+        Ok(())
+    }
+
+    #[test]
+    fn runtime_max_rejects_content_over_the_limit() {
+        let error = Chunk::<MAX_CHUNK_CHARS>::with_max("abcde", 4).err();
+        assert_eq!(error, Some(ChunkError::TooLong { len: 5, max: 4 }));
+    }
+
+    #[test]
+    fn runtime_max_counts_characters_not_bytes() -> Result<(), ChunkError> {
+        let chunk = Chunk::<MAX_CHUNK_CHARS>::with_max("déjà", 4)?;
+        assert_eq!(chunk.as_str(), "déjà");
+        Ok(())
+    }
+
+    #[test]
+    fn runtime_max_never_exceeds_the_compile_time_max() {
+        let error = Chunk::<4>::with_max("abcde", 100).err();
+        assert_eq!(error, Some(ChunkError::TooLong { len: 5, max: 4 }));
+    }
+}
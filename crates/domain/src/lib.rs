@@ -40,16 +40,24 @@ pub use calibration::{
     CalibrationState, CalibrationTopK, EmaState, TargetRecall,
 };
 pub use chunk::{Chunk, ChunkError, MAX_CHUNK_CHARS};
-pub use metadata::{ChunkMetadata, DocumentMetadata, MetadataError, VectorDocumentMetadata};
+pub use metadata::{
+    ChunkMetadata, DocumentMetadata, MAX_EXTRA_METADATA_ENTRIES, MAX_EXTRA_METADATA_KEY_LENGTH,
+    MAX_EXTRA_METADATA_VALUE_LENGTH, MetadataError, VectorDocumentMetadata, is_valid_extra_key,
+    validate_extra_metadata,
+};
 pub use primitives::{
-    ChunkId, ChunkIdInput, CodebaseId, CollectionName, CollectionNamingInput, DocumentId,
-    EmbeddingProviderId, IndexMode, PrimitiveError, VectorDbProviderId, derive_chunk_id,
-    derive_codebase_id, derive_collection_name,
+    COLLECTION_TEMPLATE_CHUNKS_PLACEHOLDER, COLLECTION_TEMPLATE_HASH_PLACEHOLDER,
+    CONTEXT_IGNORE_FILE, ChunkId, ChunkIdInput, CodebaseId, CollectionName, CollectionNamingInput,
+    DEFAULT_COLLECTION_TEMPLATE, DEFAULT_IGNORE_PATTERNS, DocumentId, EmbeddingProviderId,
+    IndexMode, OnDuplicateId, OversizeChunkPolicy, PINNED_IGNORE_PATTERNS, PathNormalization,
+    PrimitiveError, VectorDbProviderId, derive_chunk_id, derive_codebase_id,
+    derive_collection_name, ignore_layers, parse_context_ignore, validate_collection_template,
 };
 pub use search::{
-    SearchFilter, SearchOptions, SearchQuery, SearchResult, SearchResultKey, compare_search_results,
+    RecencyBoost, SearchExclusions, SearchFilter, SearchOptions, SearchQuery, SearchResult,
+    SearchResultKey, compare_search_results, group_results_by_file, path_glob_matches,
 };
-pub use search_stats::SearchStats;
+pub use search_stats::{SCORE_HISTOGRAM_BUCKETS, SearchDiagnostics, SearchStats};
 pub use spans::{Language, LineSpan};
 pub use states::{IndexStatus, IndexingState, ProgressEvent};
 
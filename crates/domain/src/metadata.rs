@@ -3,8 +3,16 @@
 use crate::{ChunkId, DocumentId, Language, LineSpan};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
+/// Maximum number of custom metadata entries attached to a vector document.
+pub const MAX_EXTRA_METADATA_ENTRIES: usize = 32;
+/// Maximum length (in bytes) of a custom metadata key.
+pub const MAX_EXTRA_METADATA_KEY_LENGTH: usize = 64;
+/// Maximum length (in bytes) of a custom metadata value.
+pub const MAX_EXTRA_METADATA_VALUE_LENGTH: usize = 256;
+
 /// Validation failures for domain metadata.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MetadataError {
@@ -20,6 +28,23 @@ pub enum MetadataError {
         /// Ending line (1-indexed).
         end_line: u32,
     },
+    /// Custom metadata keys must be non-empty, bounded, and use `[A-Za-z0-9_-]`.
+    InvalidExtraKey {
+        /// Length of the rejected key.
+        key_length: usize,
+    },
+    /// Custom metadata values must be bounded in size.
+    ExtraValueTooLong {
+        /// Key whose value exceeded the bound.
+        key: Box<str>,
+        /// Length of the rejected value.
+        value_length: usize,
+    },
+    /// Too many custom metadata entries were supplied.
+    TooManyExtraEntries {
+        /// Number of entries supplied.
+        entries: usize,
+    },
 }
 
 impl MetadataError {
@@ -27,6 +52,11 @@ impl MetadataError {
         match self {
             Self::EmptyRelativePath { .. } => ErrorCode::new("domain", "invalid_relative_path"),
             Self::InvalidLineSpan { .. } => ErrorCode::new("domain", "invalid_line_span"),
+            Self::InvalidExtraKey { .. }
+            | Self::ExtraValueTooLong { .. }
+            | Self::TooManyExtraEntries { .. } => {
+                ErrorCode::new("domain", "invalid_extra_metadata")
+            },
         }
     }
 }
@@ -38,6 +68,18 @@ impl fmt::Display for MetadataError {
             Self::InvalidLineSpan { .. } => {
                 formatter.write_str("span startLine/endLine must be valid")
             },
+            Self::InvalidExtraKey { .. } => write!(
+                formatter,
+                "extra metadata keys must be 1-{MAX_EXTRA_METADATA_KEY_LENGTH} characters of [A-Za-z0-9_-]"
+            ),
+            Self::ExtraValueTooLong { .. } => write!(
+                formatter,
+                "extra metadata values must be at most {MAX_EXTRA_METADATA_VALUE_LENGTH} bytes"
+            ),
+            Self::TooManyExtraEntries { .. } => write!(
+                formatter,
+                "extra metadata must have at most {MAX_EXTRA_METADATA_ENTRIES} entries"
+            ),
         }
     }
 }
@@ -60,6 +102,17 @@ impl From<MetadataError> for ErrorEnvelope {
                     .with_metadata("start_line", start_line.to_string())
                     .with_metadata("end_line", end_line.to_string());
             },
+            MetadataError::InvalidExtraKey { key_length } => {
+                envelope = envelope.with_metadata("key_length", key_length.to_string());
+            },
+            MetadataError::ExtraValueTooLong { key, value_length } => {
+                envelope = envelope
+                    .with_metadata("key", key)
+                    .with_metadata("value_length", value_length.to_string());
+            },
+            MetadataError::TooManyExtraEntries { entries } => {
+                envelope = envelope.with_metadata("entries", entries.to_string());
+            },
         }
 
         envelope
@@ -264,6 +317,12 @@ pub struct VectorDocumentMetadata {
     /// Optional structural hint (e.g. AST node kind).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub node_kind: Option<Box<str>>,
+    /// Custom key/value fields supplied by the caller at index time.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<Box<str>, Box<str>>,
+    /// Source file modification time (Unix epoch milliseconds) at index time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mtime_ms: Option<u64>,
 }
 
 impl VectorDocumentMetadata {
@@ -271,6 +330,7 @@ impl VectorDocumentMetadata {
     pub fn validate(&self) -> Result<(), MetadataError> {
         ensure_non_empty(self.relative_path.as_ref())?;
         validate_span(self.span)?;
+        validate_extra_metadata(&self.extra)?;
         Ok(())
     }
 }
@@ -285,10 +345,45 @@ impl From<&ChunkMetadata> for VectorDocumentMetadata {
             fragment_start_byte: None,
             fragment_end_byte: None,
             node_kind: metadata.node_kind.clone(),
+            extra: BTreeMap::new(),
+            mtime_ms: None,
         }
     }
 }
 
+/// Validate a custom metadata map against the key/value/entry bounds.
+pub fn validate_extra_metadata(extra: &BTreeMap<Box<str>, Box<str>>) -> Result<(), MetadataError> {
+    if extra.len() > MAX_EXTRA_METADATA_ENTRIES {
+        return Err(MetadataError::TooManyExtraEntries {
+            entries: extra.len(),
+        });
+    }
+    for (key, value) in extra {
+        if !is_valid_extra_key(key) {
+            return Err(MetadataError::InvalidExtraKey {
+                key_length: key.len(),
+            });
+        }
+        if value.len() > MAX_EXTRA_METADATA_VALUE_LENGTH {
+            return Err(MetadataError::ExtraValueTooLong {
+                key: key.clone(),
+                value_length: value.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns true when `key` is usable as a custom metadata key.
+#[must_use]
+pub fn is_valid_extra_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_EXTRA_METADATA_KEY_LENGTH
+        && key
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-')
+}
+
 fn parse_relative_path(path: &str) -> Result<Box<str>, MetadataError> {
     let trimmed = ensure_non_empty(path)?;
     Ok(trimmed.to_owned().into_boxed_str())
@@ -398,6 +493,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vector_metadata_validates_extra_fields() -> Result<(), Box<dyn Error>> {
+        let mut metadata = VectorDocumentMetadata {
+            relative_path: "src/lib.rs".into(),
+            language: None,
+            file_extension: None,
+            span: LineSpan::new(1, 2)?,
+            fragment_start_byte: None,
+            fragment_end_byte: None,
+            node_kind: None,
+            mtime_ms: None,
+            extra: BTreeMap::from([("team".into(), "core".into())]),
+        };
+        metadata.validate()?;
+
+        metadata.extra.insert("".into(), "x".into());
+        assert!(matches!(
+            metadata.validate(),
+            Err(MetadataError::InvalidExtraKey { key_length: 0 })
+        ));
+
+        metadata.extra = BTreeMap::from([(
+            "team".into(),
+            "x".repeat(MAX_EXTRA_METADATA_VALUE_LENGTH + 1).into(),
+        )]);
+        assert!(matches!(
+            metadata.validate(),
+            Err(MetadataError::ExtraValueTooLong { .. })
+        ));
+
+        metadata.extra = (0..=MAX_EXTRA_METADATA_ENTRIES)
+            .map(|index| (format!("k{index}").into(), "v".into()))
+            .collect();
+        assert!(matches!(
+            metadata.validate(),
+            Err(MetadataError::TooManyExtraEntries { .. })
+        ));
+        Ok(())
+    }
+
     proptest! {
         #[test]
         fn document_metadata_accepts_valid_paths(path in valid_relative_path()) {
@@ -59,6 +59,14 @@ pub enum PrimitiveError {
         /// Ending line (1-indexed).
         end_line: u32,
     },
+    /// Collection naming template is missing a placeholder or renders an
+    /// invalid `CollectionName`.
+    InvalidCollectionTemplate {
+        /// Template that failed validation.
+        template: String,
+        /// Human readable reason.
+        reason: &'static str,
+    },
     /// Derived codebase id is invalid (invariant violation).
     DerivedCodebaseIdInvalid {
         /// Candidate codebase id that failed validation.
@@ -87,6 +95,9 @@ impl PrimitiveError {
             | Self::DerivedCollectionNameInvalid { .. } => {
                 ErrorCode::new("domain", "invalid_collection_name")
             },
+            Self::InvalidCollectionTemplate { .. } => {
+                ErrorCode::new("domain", "invalid_collection_template")
+            },
             Self::InvalidDocumentId { .. } => ErrorCode::new("domain", "invalid_document_id"),
             Self::InvalidChunkId { .. } | Self::DerivedChunkIdInvalid { .. } => {
                 ErrorCode::new("domain", "invalid_chunk_id")
@@ -123,6 +134,9 @@ impl fmt::Display for PrimitiveError {
             Self::InvalidCollectionName { .. } => {
                 formatter.write_str("CollectionName must match /^[a-zA-Z][a-zA-Z0-9_]*$/")
             },
+            Self::InvalidCollectionTemplate { reason, .. } => {
+                write!(formatter, "collection template is invalid: {reason}")
+            },
             Self::InvalidDocumentId { .. } => formatter.write_str("DocumentId must be non-empty"),
             Self::InvalidChunkId { .. } => formatter.write_str("ChunkId must be non-empty"),
             Self::InvalidEmbeddingProviderId { .. } => {
@@ -172,6 +186,11 @@ impl From<PrimitiveError> for ErrorEnvelope {
             PrimitiveError::InvalidCollectionName { input } => {
                 envelope = envelope.with_metadata("input", input);
             },
+            PrimitiveError::InvalidCollectionTemplate { template, reason } => {
+                envelope = envelope
+                    .with_metadata("template", template)
+                    .with_metadata("reason", reason);
+            },
             PrimitiveError::LineSpanNonPositive {
                 start_line,
                 end_line,
@@ -571,6 +590,216 @@ impl fmt::Display for IndexMode {
     }
 }
 
+/// Canonicalization applied to relative file paths before they feed chunk ids
+/// and metadata.
+///
+/// Separators are always normalized to `/`; `Lowercase` additionally folds
+/// ASCII case so `Src/Lib.rs` and `src/lib.rs` map to one document set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PathNormalization {
+    /// Keep path casing as reported by the filesystem.
+    #[default]
+    AsIs,
+    /// Fold ASCII letters to lowercase.
+    Lowercase,
+}
+
+impl PathNormalization {
+    /// Returns the canonical string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AsIs => "asis",
+            Self::Lowercase => "lowercase",
+        }
+    }
+
+    /// Parse the canonical (case-insensitive) string representation.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "asis" => Some(Self::AsIs),
+            "lowercase" => Some(Self::Lowercase),
+            _ => None,
+        }
+    }
+
+    /// Canonicalize a relative path (separators first, then casing).
+    #[must_use]
+    pub fn apply(self, relative_path: &str) -> Box<str> {
+        let normalized = relative_path.replace('\\', "/");
+        match self {
+            Self::AsIs => normalized.into_boxed_str(),
+            Self::Lowercase => normalized.to_ascii_lowercase().into_boxed_str(),
+        }
+    }
+}
+
+impl fmt::Display for PathNormalization {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// How indexing treats a chunk longer than the embedding provider accepts.
+///
+/// `Split` re-chunks by the provider limit, `Truncate` keeps the leading
+/// piece, `Skip` drops the chunk, and `Error` fails the run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OversizeChunkPolicy {
+    /// Cut the chunk at the limit (on a char boundary) and drop the rest.
+    Truncate,
+    /// Re-chunk into consecutive pieces that each fit the limit.
+    #[default]
+    Split,
+    /// Drop the chunk; it is still counted in split stats.
+    Skip,
+    /// Fail the run with `index:chunk_too_large`.
+    Error,
+}
+
+impl OversizeChunkPolicy {
+    /// Returns the canonical string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Truncate => "truncate",
+            Self::Split => "split",
+            Self::Skip => "skip",
+            Self::Error => "error",
+        }
+    }
+
+    /// Parse the canonical (case-insensitive) string representation.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "truncate" => Some(Self::Truncate),
+            "split" => Some(Self::Split),
+            "skip" => Some(Self::Skip),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OversizeChunkPolicy {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// How indexing treats a chunk id repeated within one insert batch.
+///
+/// `Overwrite` passes the batch through unchanged (the local index keeps the
+/// last document), `Skip` keeps the first, and `Error` fails the batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnDuplicateId {
+    /// Pass repeats through; the last document for an id wins locally.
+    #[default]
+    Overwrite,
+    /// Fail the batch with `vector:duplicate_id`, naming the id.
+    Error,
+    /// The first document for an id wins; later repeats are dropped.
+    Skip,
+}
+
+impl OnDuplicateId {
+    /// Returns the canonical string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Overwrite => "overwrite",
+            Self::Error => "error",
+            Self::Skip => "skip",
+        }
+    }
+
+    /// Parse the canonical (case-insensitive) string representation.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "overwrite" => Some(Self::Overwrite),
+            "error" => Some(Self::Error),
+            "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OnDuplicateId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Template placeholder for the index-mode token (`code_chunks` or
+/// `hybrid_code_chunks`).
+pub const COLLECTION_TEMPLATE_CHUNKS_PLACEHOLDER: &str = "{chunks}";
+/// Template placeholder for the 8-character codebase path hash.
+pub const COLLECTION_TEMPLATE_HASH_PLACEHOLDER: &str = "{hash}";
+/// Template matching the built-in `code_chunks_<hash>` naming.
+pub const DEFAULT_COLLECTION_TEMPLATE: &str = "{chunks}_{hash}";
+
+/// Built-in ignore patterns (build artifacts, VCS and tool state).
+///
+/// Index scans apply these beneath configured patterns, so a configured
+/// `!target/` can re-include a default.
+pub const DEFAULT_IGNORE_PATTERNS: &[&str] = &[".context/", ".git/", "node_modules/", "target/"];
+
+/// Per-codebase ignore file read from the codebase root.
+pub const CONTEXT_IGNORE_FILE: &str = ".contextignore";
+
+/// Tool state that no ignore layer may re-include.
+pub const PINNED_IGNORE_PATTERNS: &[&str] = &[".context/", CONTEXT_IGNORE_FILE];
+
+/// Build the ignore layers for a scan, lowest precedence first.
+///
+/// Built-in defaults < configured patterns and `.contextignore` < additional
+/// patterns < pinned tool state. Each layer is sorted and deduplicated, so the
+/// result depends only on the pattern sets, not on their input order.
+#[must_use]
+pub fn ignore_layers(
+    configured: &[Box<str>],
+    context_ignore: Option<&str>,
+    additional: &[Box<str>],
+) -> Vec<Vec<Box<str>>> {
+    let mut patterns = configured.to_vec();
+    if let Some(contents) = context_ignore {
+        patterns.extend(parse_context_ignore(contents));
+    }
+    vec![
+        static_ignore_layer(DEFAULT_IGNORE_PATTERNS),
+        sorted_ignore_layer(patterns),
+        sorted_ignore_layer(additional.to_vec()),
+        static_ignore_layer(PINNED_IGNORE_PATTERNS),
+    ]
+}
+
+/// Parse `.contextignore` contents: one pattern per line, `#` starts a comment.
+#[must_use]
+pub fn parse_context_ignore(contents: &str) -> Vec<Box<str>> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_owned().into_boxed_str())
+        .collect()
+}
+
+fn static_ignore_layer(patterns: &[&str]) -> Vec<Box<str>> {
+    sorted_ignore_layer(patterns.iter().map(|pattern| Box::from(*pattern)).collect())
+}
+
+fn sorted_ignore_layer(mut patterns: Vec<Box<str>>) -> Vec<Box<str>> {
+    patterns.sort();
+    patterns.dedup();
+    patterns
+}
+
 /// Inputs required to derive a deterministic collection name.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CollectionNamingInput {
@@ -578,6 +807,8 @@ pub struct CollectionNamingInput {
     pub codebase_root: PathBuf,
     /// Indexing mode that determines the name prefix.
     pub index_mode: IndexMode,
+    /// Optional naming template (defaults to [`DEFAULT_COLLECTION_TEMPLATE`]).
+    pub template: Option<Box<str>>,
 }
 
 impl CollectionNamingInput {
@@ -586,29 +817,88 @@ impl CollectionNamingInput {
         Self {
             codebase_root: codebase_root.into(),
             index_mode,
+            template: None,
         }
     }
+
+    /// Use a custom naming template instead of the default.
+    #[must_use]
+    pub fn with_template(mut self, template: Option<Box<str>>) -> Self {
+        self.template = template;
+        self
+    }
+}
+
+/// Validate a collection naming template.
+///
+/// Templates must contain both `{chunks}` and `{hash}` exactly once, use no
+/// other placeholders, and render to a valid [`CollectionName`].
+pub fn validate_collection_template(template: &str) -> Result<(), PrimitiveError> {
+    let invalid = |reason| PrimitiveError::InvalidCollectionTemplate {
+        template: template.to_owned(),
+        reason,
+    };
+    if template
+        .matches(COLLECTION_TEMPLATE_HASH_PLACEHOLDER)
+        .count()
+        != 1
+    {
+        return Err(invalid("must contain the {hash} placeholder exactly once"));
+    }
+    if template
+        .matches(COLLECTION_TEMPLATE_CHUNKS_PLACEHOLDER)
+        .count()
+        != 1
+    {
+        return Err(invalid(
+            "must contain the {chunks} placeholder exactly once",
+        ));
+    }
+    let rendered = render_collection_template(template, IndexMode::Hybrid, "00000000");
+    if rendered.contains(['{', '}']) {
+        return Err(invalid(
+            "only {chunks} and {hash} placeholders are supported",
+        ));
+    }
+    if !is_valid_collection_name(&rendered) {
+        return Err(invalid(
+            "must render to a name matching /^[a-zA-Z][a-zA-Z0-9_]*$/",
+        ));
+    }
+    Ok(())
 }
 
 /// Derive a deterministic collection name for a codebase and index mode.
 pub fn derive_collection_name(
     input: &CollectionNamingInput,
 ) -> Result<CollectionName, PrimitiveError> {
+    let template = input
+        .template
+        .as_deref()
+        .unwrap_or(DEFAULT_COLLECTION_TEMPLATE);
+    validate_collection_template(template)?;
+
     let normalized = normalize_root_path(&input.codebase_root);
     let normalized = normalized.to_string_lossy();
     let digest = md5::compute(normalized.as_bytes());
     let hash = format!("{digest:x}");
-    let prefix = match input.index_mode {
-        IndexMode::Hybrid => "hybrid_code_chunks",
-        IndexMode::Dense => "code_chunks",
-    };
     let hash_prefix: String = hash.chars().take(8).collect();
-    let candidate = format!("{prefix}_{hash_prefix}");
+    let candidate = render_collection_template(template, input.index_mode, &hash_prefix);
 
     CollectionName::parse(candidate.as_str())
         .map_err(|_| PrimitiveError::DerivedCollectionNameInvalid { candidate })
 }
 
+fn render_collection_template(template: &str, index_mode: IndexMode, hash: &str) -> String {
+    let chunks = match index_mode {
+        IndexMode::Hybrid => "hybrid_code_chunks",
+        IndexMode::Dense => "code_chunks",
+    };
+    template
+        .replace(COLLECTION_TEMPLATE_CHUNKS_PLACEHOLDER, chunks)
+        .replace(COLLECTION_TEMPLATE_HASH_PLACEHOLDER, hash)
+}
+
 fn trimmed_non_empty(input: &str) -> Option<&str> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -639,6 +929,23 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn path_normalization_folds_case_and_separators() {
+        assert_eq!(
+            PathNormalization::AsIs.apply("Src\\Lib.rs").as_ref(),
+            "Src/Lib.rs"
+        );
+        assert_eq!(
+            PathNormalization::Lowercase.apply("Src\\Lib.rs").as_ref(),
+            "src/lib.rs"
+        );
+        assert_eq!(
+            PathNormalization::parse(" LowerCase "),
+            Some(PathNormalization::Lowercase)
+        );
+        assert_eq!(PathNormalization::parse("upper"), None);
+    }
+
     #[test]
     fn codebase_id_requires_non_empty_input() {
         let error = CodebaseId::parse("   ").err();
@@ -685,6 +992,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn derive_collection_name_applies_template() -> Result<(), PrimitiveError> {
+        let input = CollectionNamingInput::new("repo", IndexMode::Dense)
+            .with_template(Some("team_a_{chunks}_{hash}_prod".into()));
+        let first = derive_collection_name(&input)?;
+        let second = derive_collection_name(&input)?;
+        let default =
+            derive_collection_name(&CollectionNamingInput::new("repo", IndexMode::Dense))?;
+
+        assert_eq!(first, second);
+        assert_eq!(CollectionName::parse(first.as_str())?, first);
+        assert_eq!(first.as_str(), format!("team_a_{}_prod", default.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_template_rejects_missing_or_unknown_placeholders() {
+        for template in [
+            "team_{chunks}",
+            "team_{hash}",
+            "{chunks}_{hash}_{env}",
+            "{hash}_{chunks}",
+            "team-{chunks}_{hash}",
+        ] {
+            let error = validate_collection_template(template).err();
+            assert!(
+                matches!(
+                    error,
+                    Some(PrimitiveError::InvalidCollectionTemplate { .. })
+                ),
+                "expected {template} to be rejected"
+            );
+        }
+    }
+
     #[test]
     fn derive_chunk_id_distinguishes_fragment_offsets() -> Result<(), PrimitiveError> {
         let span = LineSpan::new(10, 20).expect("valid test span");
@@ -696,6 +1038,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn ignore_layers_order_defaults_configured_additional_pinned() {
+        let layers = ignore_layers(
+            &["dist/".into(), "build/".into()],
+            Some("# comment\n\n  generated/  \nbuild/\n"),
+            &["!keep.rs".into()],
+        );
+
+        let expected: Vec<Vec<Box<str>>> = [
+            DEFAULT_IGNORE_PATTERNS,
+            &["build/", "dist/", "generated/"],
+            &["!keep.rs"],
+            &[".context/", ".contextignore"],
+        ]
+        .iter()
+        .map(|layer| layer.iter().map(|pattern| Box::from(*pattern)).collect())
+        .collect();
+        assert_eq!(layers, expected);
+    }
+
     proptest! {
         #[test]
         fn collection_name_accepts_valid_inputs(name in valid_collection_name()) {
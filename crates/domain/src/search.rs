@@ -3,6 +3,7 @@
 use crate::{Language, LineSpan};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::HashSet;
 
 /// Provider-specific search filters.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -11,6 +12,73 @@ pub struct SearchFilter {
     /// Provider-specific filter expression (e.g. Milvus expr).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter_expr: Option<Box<str>>,
+    /// Exclusions applied on top of `filter_expr`.
+    #[serde(flatten)]
+    pub exclusions: SearchExclusions,
+}
+
+/// Paths and languages removed from search results.
+///
+/// Exclusions combine with inclusion filters using AND semantics: a candidate
+/// must match the inclusion filter and match none of the exclusions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchExclusions {
+    /// Relative-path globs to exclude (`*` matches any run, `?` one character).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_paths: Vec<Box<str>>,
+    /// Languages to exclude.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_languages: Vec<Language>,
+}
+
+impl SearchExclusions {
+    /// Returns true when nothing is excluded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.exclude_paths.is_empty() && self.exclude_languages.is_empty()
+    }
+
+    /// Returns true when a candidate with this path and language is excluded.
+    #[must_use]
+    pub fn excludes(&self, relative_path: &str, language: Option<Language>) -> bool {
+        language.is_some_and(|language| self.exclude_languages.contains(&language))
+            || self
+                .exclude_paths
+                .iter()
+                .any(|pattern| path_glob_matches(pattern, relative_path))
+    }
+}
+
+/// Match a relative path against a glob where `*` matches any run of
+/// characters (including `/`) and `?` matches exactly one character.
+#[must_use]
+pub fn path_glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while let Some(&ch) = path.get(t) {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c == ch => {
+                p += 1;
+                t += 1;
+            },
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, t));
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern.iter().skip(p).all(|c| *c == '*')
 }
 
 /// Search query payload.
@@ -36,6 +104,52 @@ pub struct SearchOptions {
     /// Optional hint to include content payloads.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_content: Option<bool>,
+    /// Lines of surrounding source added before and after each result's span
+    /// when content is included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_lines: Option<u32>,
+    /// Keep only the best-scoring chunk per file before applying `top_k`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub group_by_file: bool,
+    /// Optional recency re-ranking; off when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recency_boost: Option<RecencyBoost>,
+}
+
+/// Query-time boost that favours recently modified files.
+///
+/// A re-ranking heuristic, not a relevance signal: each score is multiplied by
+/// `0.5^(age / half_life_ms)`, where age is `reference_ms` minus the file's
+/// indexed `mtime_ms`. Results without a stored mtime keep their score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecencyBoost {
+    /// Age at which a score is halved, in milliseconds. Zero disables decay.
+    pub half_life_ms: u64,
+    /// Reference time ages are measured from, in Unix epoch milliseconds.
+    pub reference_ms: u64,
+}
+
+impl RecencyBoost {
+    /// Decay factor in `(0, 1]` for a file modified at `mtime_ms`.
+    ///
+    /// Files modified after `reference_ms` count as age zero.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        reason = "ages are heuristic inputs; sub-millisecond precision is irrelevant"
+    )]
+    pub fn factor(&self, mtime_ms: Option<u64>) -> f32 {
+        let Some(mtime_ms) = mtime_ms else {
+            return 1.0;
+        };
+        if self.half_life_ms == 0 {
+            return 1.0;
+        }
+        let age_ms = self.reference_ms.saturating_sub(mtime_ms);
+        0.5_f64.powf(age_ms as f64 / self.half_life_ms as f64) as f32
+    }
 }
 
 /// Deterministic result key used for ordering.
@@ -89,11 +203,57 @@ pub fn compare_search_results(a: &SearchResult, b: &SearchResult) -> Ordering {
     a.key.span.end_line().cmp(&b.key.span.end_line())
 }
 
+/// Keep only the best-scoring result per `relativePath`.
+///
+/// Results are sorted with [`compare_search_results`] first, so the surviving
+/// chunk for each file is deterministic even when scores tie.
+#[must_use]
+pub fn group_results_by_file(mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+    results.sort_by(compare_search_results);
+    let mut seen = HashSet::with_capacity(results.len());
+    results.retain(|result| seen.insert(result.key.relative_path.clone()));
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::error::Error;
 
+    #[test]
+    fn exclusions_match_globs_and_languages() {
+        let exclusions = SearchExclusions {
+            exclude_paths: vec!["*.test.rs".into(), "vendor/?/*".into()],
+            exclude_languages: vec![Language::Markdown],
+        };
+
+        assert!(exclusions.excludes("src/a.test.rs", Some(Language::Rust)));
+        assert!(exclusions.excludes("vendor/x/lib.rs", Some(Language::Rust)));
+        assert!(exclusions.excludes("README.md", Some(Language::Markdown)));
+        assert!(!exclusions.excludes("src/a.rs", Some(Language::Rust)));
+        assert!(!exclusions.excludes("vendor/xy/lib.rs", None));
+        assert!(SearchExclusions::default().is_empty());
+    }
+
+    #[test]
+    fn recency_boost_halves_score_per_half_life() {
+        let boost = RecencyBoost {
+            half_life_ms: 1_000,
+            reference_ms: 10_000,
+        };
+
+        assert!((boost.factor(Some(10_000)) - 1.0).abs() < f32::EPSILON);
+        assert!((boost.factor(Some(9_000)) - 0.5).abs() < 1e-6);
+        assert!((boost.factor(Some(8_000)) - 0.25).abs() < 1e-6);
+        assert!((boost.factor(Some(20_000)) - 1.0).abs() < f32::EPSILON);
+        assert!((boost.factor(None) - 1.0).abs() < f32::EPSILON);
+        let disabled = RecencyBoost {
+            half_life_ms: 0,
+            ..boost
+        };
+        assert!((disabled.factor(Some(0)) - 1.0).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn search_result_ordering_matches_contract() -> Result<(), Box<dyn Error>> {
         let span = LineSpan::new(1, 2)?;
@@ -140,6 +300,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn group_by_file_keeps_best_chunk_per_path() -> Result<(), Box<dyn Error>> {
+        let result = |path: &str, start: u32, score: f32| -> Result<SearchResult, Box<dyn Error>> {
+            Ok(SearchResult {
+                key: SearchResultKey {
+                    relative_path: path.into(),
+                    span: LineSpan::new(start, start + 1)?,
+                },
+                content: None,
+                language: None,
+                score,
+            })
+        };
+        let weaker = result("src/lib.rs", 1, 0.4)?;
+        let stronger = result("src/lib.rs", 10, 0.8)?;
+        let other = result("src/main.rs", 1, 0.6)?;
+        let tie_late = result("src/tie.rs", 20, 0.5)?;
+        let tie_early = result("src/tie.rs", 5, 0.5)?;
+
+        let grouped = group_results_by_file(vec![
+            weaker,
+            other.clone(),
+            tie_late,
+            stronger.clone(),
+            tie_early.clone(),
+        ]);
+        assert_eq!(grouped, vec![stronger, other, tie_early]);
+        Ok(())
+    }
+
     #[test]
     fn search_result_serializes_with_camel_case() -> Result<(), Box<dyn Error>> {
         let span = LineSpan::new(1, 2)?;
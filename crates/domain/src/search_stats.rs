@@ -22,3 +22,18 @@ pub struct SearchStats {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index_size: Option<u64>,
 }
+
+/// Opt-in search diagnostics computed by the search use-case itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDiagnostics {
+    /// Candidate score counts in ten equal-width buckets over `[0, 1]`.
+    ///
+    /// Counted over the candidate set before the threshold and `top_k` cut;
+    /// scores outside the range are clamped into the first or last bucket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_histogram: Option<[u32; SCORE_HISTOGRAM_BUCKETS]>,
+}
+
+/// Number of buckets in [`SearchDiagnostics::score_histogram`].
+pub const SCORE_HISTOGRAM_BUCKETS: usize = 10;
@@ -5,8 +5,12 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Inclusive line span with 1-indexed boundaries.
+///
+/// The wire format is exactly `{ "startLine": u32, "endLine": u32 }` with
+/// `1 <= startLine <= endLine`. [`LineSpan::new`] enforces the invariant;
+/// deserialized spans are checked by the owning metadata's `validate`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct LineSpan {
     start_line: u32,
     end_line: u32,
@@ -49,7 +53,7 @@ impl LineSpan {
 }
 
 /// Canonical language identifiers derived from file extensions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
     /// `TypeScript` source files.
@@ -117,6 +121,33 @@ impl Language {
         }
     }
 
+    /// Parse a canonical identifier produced by [`Language::as_str`].
+    #[must_use]
+    pub fn parse(id: &str) -> Option<Self> {
+        let language = match id {
+            "typescript" => Self::TypeScript,
+            "javascript" => Self::JavaScript,
+            "python" => Self::Python,
+            "java" => Self::Java,
+            "cpp" => Self::Cpp,
+            "c" => Self::C,
+            "csharp" => Self::CSharp,
+            "go" => Self::Go,
+            "rust" => Self::Rust,
+            "php" => Self::Php,
+            "ruby" => Self::Ruby,
+            "swift" => Self::Swift,
+            "kotlin" => Self::Kotlin,
+            "scala" => Self::Scala,
+            "objective-c" => Self::ObjectiveC,
+            "jupyter" => Self::Jupyter,
+            "markdown" => Self::Markdown,
+            "text" => Self::Text,
+            _ => return None,
+        };
+        Some(language)
+    }
+
     /// Derive a language identifier from a file extension.
     #[must_use]
     pub fn from_extension(extension: &str) -> Self {
@@ -183,6 +214,17 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn line_span_json_shape_is_stable() -> Result<(), Box<dyn std::error::Error>> {
+        let span = LineSpan::new(3, 7)?;
+        let json = serde_json::to_string(&span)?;
+        assert_eq!(json, r#"{"startLine":3,"endLine":7}"#);
+        assert_eq!(serde_json::from_str::<LineSpan>(&json)?, span);
+
+        assert!(serde_json::from_str::<LineSpan>(r#"{"from":3,"to":7}"#).is_err());
+        Ok(())
+    }
+
     #[test]
     fn language_from_extension_maps_values() {
         assert_eq!(Language::from_extension(".ts"), Language::TypeScript);
@@ -5,17 +5,23 @@
 
 use std::collections::BTreeMap;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::instrument;
 
 mod types;
 pub use types::{
-    ApiV1ErrorDto, ApiV1ErrorKind, BuildInfo, ClearIndexRequest, CliConfigSummary, CliInitStatus,
-    CliManifestStatus, CliStatus, CliStorageEstimate, IndexCodebaseOutput, IndexCodebaseStatus,
-    IndexEmbedStats, IndexInsertStats, IndexRequest, IndexScanStats, IndexSplitStats,
-    IndexStageStats, InfraError, JobEmbedStats, JobError, JobInsertStats, JobKind, JobProgress,
+    ApiV1ErrorDto, ApiV1ErrorKind, BuildInfo, ClearIndexRequest, CliConfigSummary, CliEmbedReport,
+    CliIndexState, CliInitStatus, CliManifestStatus, CliStatus, CliStorageEstimate,
+    ConfigFieldChange, ConfigWarning, DoctorCheck, DoctorReport, DoctorStatus, EffectiveLimits,
+    ExportOutput, ImportOutput, IndexCodebaseOutput, IndexCodebaseStatus, IndexEmbedStats,
+    IndexInsertStats, IndexRequest, IndexScanStats, IndexSplitStats, IndexStageStats,
+    IndexedFileSummary, InfraError, JobEmbedStats, JobError, JobInsertStats, JobKind, JobProgress,
     JobRequest, JobResult, JobScanStats, JobSplitStats, JobStageStats, JobState, JobStatus,
-    ReindexByChangeOutput, ReindexByChangeRequest, RequestKind, SearchOutput, SearchRequest,
-    SearchResult, SearchStats, SnapshotStatus, SnapshotStorageMode, StorageThresholdStatus,
+    JobSummary, MigrateOutput, MigrateProgress, PruneOutput, ReindexByChangeOutput,
+    ReindexByChangeRequest, RequestKind, SearchDiagnostics, SearchOutput, SearchPhase,
+    SearchQueryOptions, SearchRequest, SearchResult, SearchStats, SearchTimings, SnapshotStatus,
+    SnapshotStorageMode, StorageThresholdStatus, StoredVectorReport, VerifyDrift, VerifyReport,
+    VerifyStatus,
 };
 
 /// Placeholder module for the facade layer.
@@ -28,10 +34,12 @@ mod placeholder {
 }
 
 pub use placeholder::facade_crate_version;
+pub use semantic_code_config::{ConfigTemplate, STDIN_CONFIG_PATH, is_stdin_config_path};
 pub use semantic_code_domain::{
     CalibrationParamError, CalibrationParams, CalibrationPrecision, CalibrationQueryCount,
-    CalibrationState, CalibrationTopK, TargetRecall,
+    CalibrationState, CalibrationTopK, CollectionName, IndexMode, TargetRecall,
 };
+pub use semantic_code_infra::{LogLevel, RequestAttributes};
 
 /// Returns build metadata for the current binary.
 #[must_use]
@@ -68,24 +76,36 @@ pub fn validate_index_request_for_root(codebase_root: &Path) -> Result<IndexRequ
 }
 
 /// Validate a search request from CLI primitives.
+///
+/// Limits such as `vectorDb.maxFilterValues` come from the effective config.
 #[instrument(name = "facade.validate_search_request_for_query", skip_all)]
 pub fn validate_search_request_for_query(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
     codebase_root: &Path,
     query: &str,
-    top_k: Option<u32>,
-    threshold: Option<f32>,
-    filter_expr: Option<&str>,
-    include_content: bool,
+    options: &SearchQueryOptions<'_>,
 ) -> Result<SearchRequest, InfraError> {
+    let limits = semantic_code_infra::search_request_limits_local(
+        config_path,
+        overrides_json,
+        codebase_root,
+    )?;
     let request = semantic_code_config::SearchRequestDto {
         codebase_root: codebase_root.to_string_lossy().to_string(),
         query: query.to_string(),
-        top_k,
-        threshold: threshold.map(f64::from),
-        filter_expr: filter_expr.map(str::to_owned),
-        include_content: include_content.then_some(true),
+        top_k: options.top_k,
+        threshold: options.threshold.map(f64::from),
+        filter_expr: options.filter_expr.map(str::to_owned),
+        include_content: options.include_content.then_some(true),
+        context_lines: options.context_lines,
+        include_histogram: options.include_histogram.then_some(true),
+        group_by_file: options.group_by_file.then_some(true),
+        exclude_paths: (!options.exclude_paths.is_empty()).then(|| options.exclude_paths.to_vec()),
+        exclude_languages: (!options.exclude_languages.is_empty())
+            .then(|| options.exclude_languages.to_vec()),
     };
-    semantic_code_config::validate_search_request(&request)
+    semantic_code_config::validate_search_request_with_limits(&request, limits)
         .map(Into::into)
         .map_err(Into::into)
 }
@@ -107,9 +127,11 @@ pub fn validate_reindex_request_for_root(
 #[instrument(name = "facade.validate_clear_request_for_root", skip_all)]
 pub fn validate_clear_request_for_root(
     codebase_root: &Path,
+    collection_name: Option<&str>,
 ) -> Result<ClearIndexRequest, InfraError> {
     let request = semantic_code_config::ClearIndexRequestDto {
         codebase_root: codebase_root.to_string_lossy().to_string(),
+        collection_name: collection_name.map(str::to_owned),
     };
     semantic_code_config::validate_clear_index_request(&request)
         .map(Into::into)
@@ -218,6 +240,69 @@ pub fn load_effective_config_json(
         .map_err(Into::into)
 }
 
+/// Load and validate the effective config, returning pretty JSON and warnings.
+#[instrument(
+    name = "facade.load_effective_config_with_warnings",
+    skip_all,
+    fields(
+        env_size = env.len(),
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn load_effective_config_with_warnings(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> Result<(String, Vec<ConfigWarning>), InfraError> {
+    let (config_json, warnings) =
+        semantic_code_infra::load_effective_config_with_warnings(env, config_path, overrides_json)?;
+    Ok((config_json, warnings.into_iter().map(Into::into).collect()))
+}
+
+/// List the effective config fields changed by `overrides_json`, with secrets redacted.
+#[instrument(
+    name = "facade.load_effective_config_diff",
+    skip_all,
+    fields(
+        env_size = env.len(),
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn load_effective_config_diff(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> Result<Vec<ConfigFieldChange>, InfraError> {
+    let changes =
+        semantic_code_infra::load_effective_config_diff(env, config_path, overrides_json)?;
+    Ok(changes.into_iter().map(Into::into).collect())
+}
+
+/// Load and validate the effective config, returning its resolved numeric limits.
+///
+/// Lets external schedulers size their own work (e.g. to `coreMaxConcurrency`)
+/// without re-parsing the config JSON.
+#[instrument(
+    name = "facade.effective_limits",
+    skip_all,
+    fields(
+        env_size = env.len(),
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn effective_limits(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> Result<EffectiveLimits, InfraError> {
+    semantic_code_infra::load_effective_limits(env, config_path, overrides_json)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
 /// Run the in-memory index smoke test.
 #[instrument(name = "facade.run_index_smoke", skip_all)]
 pub fn run_index_smoke() -> Result<(), InfraError> {
@@ -277,10 +362,42 @@ pub fn run_search_local(
     request: &SearchRequest,
 ) -> Result<SearchOutput, InfraError> {
     semantic_code_infra::run_search_local(config_path, overrides_json, request.as_validated())
-        .map(Into::into)
+        .map(|output| with_normalized_filter(output.into(), request))
         .map_err(Into::into)
 }
 
+/// Run a local semantic search, reporting each phase to `on_progress`.
+#[instrument(
+    name = "facade.run_search_local_with_progress",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_search_local_with_progress(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    request: &SearchRequest,
+    on_progress: Arc<dyn Fn(SearchPhase) + Send + Sync>,
+) -> Result<SearchOutput, InfraError> {
+    let on_progress: Arc<dyn Fn(semantic_code_infra::SearchPhase) + Send + Sync> =
+        Arc::new(move |phase| on_progress(phase.into()));
+    semantic_code_infra::run_search_local_with_progress(
+        config_path,
+        overrides_json,
+        request.as_validated(),
+        Some(on_progress),
+    )
+    .map(|output| with_normalized_filter(output.into(), request))
+    .map_err(Into::into)
+}
+
+fn with_normalized_filter(mut output: SearchOutput, request: &SearchRequest) -> SearchOutput {
+    output.normalized_filter = request.normalized_filter().map(str::to_owned);
+    output
+}
+
 /// A pre-warmed search session for running multiple queries without re-loading
 /// the index. Created via [`open_search_session`].
 pub struct SearchSession(semantic_code_infra::LocalSearchSession);
@@ -431,6 +548,7 @@ pub fn run_job(root: &Path, job_id: &str) -> Result<JobStatus, InfraError> {
     fields(
         has_config_path = config_path.is_some(),
         has_storage_mode = storage_mode.is_some(),
+        template = template.map(ConfigTemplate::name),
         force
     )
 )]
@@ -438,18 +556,55 @@ pub fn run_init_local(
     config_path: Option<&Path>,
     codebase_root: &Path,
     storage_mode: Option<SnapshotStorageMode>,
+    template: Option<ConfigTemplate>,
     force: bool,
 ) -> Result<CliInitStatus, InfraError> {
     semantic_code_infra::run_init_local(
         config_path,
         codebase_root,
         storage_mode.map(Into::into),
+        template,
         force,
     )
     .map(Into::into)
     .map_err(Into::into)
 }
 
+/// Derive the collection name a codebase indexes into, without touching disk.
+///
+/// `template` is the `vectorDb.collectionTemplate` value; `None` uses the
+/// built-in `{chunks}_{hash}` naming.
+#[instrument(name = "facade.derive_collection_name_for", skip_all, fields(index_mode = %index_mode))]
+pub fn derive_collection_name_for(
+    codebase_root: &Path,
+    index_mode: IndexMode,
+    template: Option<&str>,
+) -> Result<CollectionName, InfraError> {
+    let input = semantic_code_domain::CollectionNamingInput::new(codebase_root, index_mode)
+        .with_template(template.map(Into::into));
+    semantic_code_domain::derive_collection_name(&input)
+        .map_err(|error| semantic_code_shared::ErrorEnvelope::from(error).into())
+}
+
+/// Derive the collection name for a codebase using its configured mode and template.
+#[instrument(
+    name = "facade.derive_collection_name_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn derive_collection_name_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<CollectionName, InfraError> {
+    let naming =
+        semantic_code_infra::resolve_collection_naming(config_path, overrides_json, codebase_root)?;
+    derive_collection_name_for(codebase_root, naming.index_mode, naming.template.as_deref())
+}
+
 /// Read local CLI status information.
 #[instrument(
     name = "facade.read_status_local",
@@ -469,6 +624,222 @@ pub fn read_status_local(
         .map_err(Into::into)
 }
 
+/// Resolve the configured `core.timeoutMs` for a codebase.
+#[instrument(
+    name = "facade.resolve_core_timeout_ms",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn resolve_core_timeout_ms(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<u64, InfraError> {
+    semantic_code_infra::resolve_core_timeout_ms(config_path, overrides_json, codebase_root)
+        .map_err(Into::into)
+}
+
+/// Install user-supplied attributes attached to every request's logs and telemetry.
+///
+/// Only the first call per process takes effect; returns `false` otherwise.
+#[instrument(
+    name = "facade.install_request_attributes",
+    skip_all,
+    fields(attribute_count = attributes.len())
+)]
+pub fn install_request_attributes(attributes: RequestAttributes) -> bool {
+    semantic_code_infra::install_request_attributes(attributes)
+}
+
+/// Forbid mutations (index, reindex, clear, init, prune, migrate, import) for the rest of the process.
+///
+/// Mutating commands then fail with a `read_only` error before any write.
+#[instrument(name = "facade.install_read_only_mode", skip_all)]
+pub fn install_read_only_mode() {
+    semantic_code_infra::install_read_only_mode();
+}
+
+/// Install the minimum level for structured logs emitted by local commands.
+///
+/// Overrides `SCA_LOG_LEVEL`; only the first call per process takes effect.
+#[instrument(name = "facade.install_log_level", skip_all, fields(level = ?level))]
+pub fn install_log_level(level: LogLevel) -> bool {
+    semantic_code_infra::install_log_level(level)
+}
+
+/// Verify that local snapshots agree with the vector DB without reindexing.
+#[instrument(
+    name = "facade.run_verify_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_verify_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<VerifyReport, InfraError> {
+    semantic_code_infra::run_verify_local(config_path, overrides_json, codebase_root)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+/// List indexed files with their chunk counts, sorted by relative path.
+#[instrument(
+    name = "facade.list_indexed_files_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn list_indexed_files_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<Vec<IndexedFileSummary>, InfraError> {
+    semantic_code_infra::list_indexed_files_local(config_path, overrides_json, codebase_root)
+        .map(|files| files.into_iter().map(Into::into).collect())
+        .map_err(Into::into)
+}
+
+/// Summarize the stored vector of one chunk id, for debugging rankings.
+#[instrument(
+    name = "facade.get_vector_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn get_vector_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    id: &str,
+) -> Result<StoredVectorReport, InfraError> {
+    let vector =
+        semantic_code_infra::get_vector_local(config_path, overrides_json, codebase_root, id)?;
+    let l2_norm = vector
+        .as_slice()
+        .iter()
+        .map(|value| value * value)
+        .sum::<f32>()
+        .sqrt();
+    Ok(StoredVectorReport {
+        id: id.into(),
+        dimension: vector.dimension(),
+        l2_norm,
+    })
+}
+
+/// Remove indexed chunks for files that no longer exist on disk.
+#[instrument(
+    name = "facade.run_prune_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_prune_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<PruneOutput, InfraError> {
+    semantic_code_infra::run_prune_local(config_path, overrides_json, codebase_root)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+/// Copy the local index into another vector DB backend without reindexing.
+///
+/// `destination_overrides_json` describes the destination backend (at least
+/// `vectorDb.provider`) on top of the loaded config.
+#[instrument(
+    name = "facade.run_migrate_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_migrate_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    destination_overrides_json: &str,
+    codebase_root: &Path,
+    on_progress: Option<Arc<dyn Fn(MigrateProgress) + Send + Sync>>,
+) -> Result<MigrateOutput, InfraError> {
+    let on_progress = on_progress.map(|on_progress| {
+        let on_progress: Arc<dyn Fn(semantic_code_infra::IndexProgress) + Send + Sync> =
+            Arc::new(move |progress| on_progress(progress.into()));
+        on_progress
+    });
+    semantic_code_infra::run_migrate_local(
+        config_path,
+        overrides_json,
+        destination_overrides_json,
+        codebase_root,
+        on_progress,
+    )
+    .map(Into::into)
+    .map_err(Into::into)
+}
+
+/// Export every indexed document to `out_path` as JSONL, sorted by id.
+#[instrument(
+    name = "facade.run_export_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some(),
+        include_vectors
+    )
+)]
+pub fn run_export_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    out_path: &Path,
+    include_vectors: bool,
+) -> Result<ExportOutput, InfraError> {
+    semantic_code_infra::run_export_local(
+        config_path,
+        overrides_json,
+        codebase_root,
+        out_path,
+        include_vectors,
+    )
+    .map(Into::into)
+    .map_err(Into::into)
+}
+
+/// Bulk-load documents from a JSONL dump without re-embedding.
+#[instrument(
+    name = "facade.run_import_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_import_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    in_path: &Path,
+) -> Result<ImportOutput, InfraError> {
+    semantic_code_infra::run_import_local(config_path, overrides_json, codebase_root, in_path)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
 /// Estimate local storage requirements for indexing.
 #[instrument(
     name = "facade.estimate_storage_local",
@@ -521,6 +892,66 @@ pub fn ensure_storage_headroom_local(
     .map_err(Into::into)
 }
 
+/// Embed one text with the configured provider and summarize the vector.
+#[instrument(
+    name = "facade.run_embed_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_embed_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    text: &str,
+) -> Result<CliEmbedReport, InfraError> {
+    semantic_code_infra::run_embed_local(config_path, overrides_json, codebase_root, text)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+/// Detect the vector dimension the configured embedding provider produces.
+///
+/// Runs the provider's dimension probe without indexing; the result is cached
+/// for the process.
+#[instrument(
+    name = "facade.detect_embedding_dimension",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn detect_embedding_dimension(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> Result<u32, InfraError> {
+    semantic_code_infra::detect_embedding_dimension(env, config_path, overrides_json, codebase_root)
+        .map_err(Into::into)
+}
+
+/// Diagnose common local setup problems.
+#[instrument(
+    name = "facade.run_doctor_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+#[must_use]
+pub fn run_doctor_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> DoctorReport {
+    semantic_code_infra::run_doctor_local(config_path, overrides_json, codebase_root).into()
+}
+
 /// Run BQ1 threshold calibration against the local vector index.
 #[instrument(
     name = "facade.run_calibrate_local",
@@ -569,4 +1000,32 @@ mod tests {
         assert!(!api_version.is_empty());
         assert!(!app_version_value.is_empty());
     }
+
+    #[test]
+    fn derived_collection_name_is_deterministic_and_honors_template() -> Result<(), InfraError> {
+        let root = Path::new("/tmp/sca-facade-naming");
+
+        let first = derive_collection_name_for(root, IndexMode::Dense, None)?;
+        let second = derive_collection_name_for(root, IndexMode::Dense, None)?;
+        assert_eq!(first, second);
+        assert!(first.as_str().starts_with("code_chunks_"));
+
+        let hybrid = derive_collection_name_for(root, IndexMode::Hybrid, None)?;
+        let templated =
+            derive_collection_name_for(root, IndexMode::Hybrid, Some("team_{chunks}_{hash}"))?;
+        assert_eq!(templated.as_str(), format!("team_{}", hybrid.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn effective_limits_reflect_overrides() -> Result<(), InfraError> {
+        let env = BTreeMap::new();
+        let overrides = r#"{"core":{"maxConcurrency":3,"maxInFlightInserts":2}}"#;
+
+        let limits = effective_limits(&env, None, Some(overrides))?;
+
+        assert_eq!(limits.core_max_concurrency, 3);
+        assert_eq!(limits.core_max_in_flight_inserts, Some(2));
+        Ok(())
+    }
 }
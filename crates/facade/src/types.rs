@@ -1,9 +1,10 @@
 //! Facade-owned DTOs for CLI-facing contracts.
 
 use semantic_code_domain::{
-    CollectionName, IndexMode, Language, LineSpan, SearchStats as DomainSearchStats,
+    CollectionName, IndexMode, Language, LineSpan, SCORE_HISTOGRAM_BUCKETS,
+    SearchDiagnostics as DomainSearchDiagnostics, SearchStats as DomainSearchStats,
 };
-use semantic_code_shared::ErrorEnvelope;
+use semantic_code_shared::{BoundedU32, ErrorEnvelope};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
@@ -134,17 +135,23 @@ pub enum SnapshotStorageMode {
     Project,
     /// Store under a custom absolute path.
     Custom(PathBuf),
+    /// Store under the per-user cache directory, shared across codebases.
+    UserCache,
 }
 
 impl SnapshotStorageMode {
+    /// Parse a storage mode keyword (`disabled`, `project`, `user-cache`, or
+    /// `custom:<path>`).
+    pub fn parse(value: &str) -> Result<Self, InfraError> {
+        semantic_code_config::SnapshotStorageMode::parse(value)
+            .map(Self::from)
+            .map_err(|error| ErrorEnvelope::from(error).into())
+    }
+
     /// Resolve the snapshot root directory for a codebase.
     #[must_use]
     pub fn resolve_root(&self, codebase_root: &Path) -> Option<PathBuf> {
-        match self {
-            Self::Disabled => None,
-            Self::Project => Some(codebase_root.join(".context")),
-            Self::Custom(path) => Some(path.clone()),
-        }
+        semantic_code_config::SnapshotStorageMode::from(self.clone()).resolve_root(codebase_root)
     }
 }
 
@@ -154,6 +161,7 @@ impl From<semantic_code_config::SnapshotStorageMode> for SnapshotStorageMode {
             semantic_code_config::SnapshotStorageMode::Disabled => Self::Disabled,
             semantic_code_config::SnapshotStorageMode::Project => Self::Project,
             semantic_code_config::SnapshotStorageMode::Custom(path) => Self::Custom(path),
+            semantic_code_config::SnapshotStorageMode::UserCache => Self::UserCache,
         }
     }
 }
@@ -164,6 +172,7 @@ impl From<SnapshotStorageMode> for semantic_code_config::SnapshotStorageMode {
             SnapshotStorageMode::Disabled => Self::Disabled,
             SnapshotStorageMode::Project => Self::Project,
             SnapshotStorageMode::Custom(path) => Self::Custom(path),
+            SnapshotStorageMode::UserCache => Self::UserCache,
         }
     }
 }
@@ -246,6 +255,24 @@ impl From<DomainSearchStats> for SearchStats {
     }
 }
 
+/// Opt-in search diagnostics returned by facade search APIs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDiagnostics {
+    /// Candidate score counts in ten equal-width buckets over `[0, 1]`,
+    /// counted before the threshold and `topK` cut.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_histogram: Option<[u32; SCORE_HISTOGRAM_BUCKETS]>,
+}
+
+impl From<DomainSearchDiagnostics> for SearchDiagnostics {
+    fn from(value: DomainSearchDiagnostics) -> Self {
+        Self {
+            score_histogram: value.score_histogram,
+        }
+    }
+}
+
 /// Search output payload returned by facade APIs.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -255,6 +282,15 @@ pub struct SearchOutput {
     /// Optional vector-search diagnostics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<SearchStats>,
+    /// Opt-in diagnostics, such as the score histogram.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<SearchDiagnostics>,
+    /// Canonical form of the request `filterExpr`, when one was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized_filter: Option<String>,
+    /// Wall-clock phase timings; reported by `--profile`, not serialized.
+    #[serde(skip)]
+    pub timings: SearchTimings,
 }
 
 impl From<semantic_code_app::SemanticSearchOutput> for SearchOutput {
@@ -262,6 +298,28 @@ impl From<semantic_code_app::SemanticSearchOutput> for SearchOutput {
         Self {
             results: value.results.into_iter().map(Into::into).collect(),
             stats: value.stats.map(Into::into),
+            diagnostics: value.diagnostics.map(Into::into),
+            normalized_filter: None,
+            timings: value.timings.into(),
+        }
+    }
+}
+
+/// Wall-clock milliseconds spent in each phase of a search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchTimings {
+    /// Query embedding; `0` when a pre-computed vector was supplied.
+    pub embed_ms: u64,
+    /// Vector DB search, including reranking.
+    pub search_ms: u64,
+}
+
+impl From<semantic_code_app::SearchTimings> for SearchTimings {
+    fn from(value: semantic_code_app::SearchTimings) -> Self {
+        Self {
+            embed_ms: value.embed_ms,
+            search_ms: value.search_ms,
         }
     }
 }
@@ -300,6 +358,9 @@ pub struct ApiV1ErrorDto {
     pub message: String,
     /// Error kind (expected vs invariant).
     pub kind: ApiV1ErrorKind,
+    /// Whether the failure is transient and the request may be retried.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub retriable: bool,
     /// Optional metadata map.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<ApiV1ErrorMeta>,
@@ -311,6 +372,7 @@ impl From<semantic_code_api::v1::ApiV1ErrorDto> for ApiV1ErrorDto {
             code: value.code,
             message: value.message,
             kind: value.kind.into(),
+            retriable: value.retriable,
             meta: value.meta,
         }
     }
@@ -332,6 +394,29 @@ impl From<semantic_code_config::ValidatedIndexRequest> for IndexRequest {
     }
 }
 
+/// Optional search flags accepted by [`crate::validate_search_request_for_query`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchQueryOptions<'a> {
+    /// Optional top-k override.
+    pub top_k: Option<u32>,
+    /// Optional score threshold.
+    pub threshold: Option<f32>,
+    /// Optional filter expression.
+    pub filter_expr: Option<&'a str>,
+    /// Include content payloads in results.
+    pub include_content: bool,
+    /// Lines of surrounding source added around included content.
+    pub context_lines: Option<u32>,
+    /// Include a candidate score histogram in search diagnostics.
+    pub include_histogram: bool,
+    /// Keep only the best-scoring chunk per file.
+    pub group_by_file: bool,
+    /// Relative-path globs to exclude.
+    pub exclude_paths: &'a [String],
+    /// Language identifiers to exclude.
+    pub exclude_languages: &'a [String],
+}
+
 /// Facade-owned validated search request.
 #[derive(Debug, Clone)]
 pub struct SearchRequest(semantic_code_config::ValidatedSearchRequest);
@@ -340,6 +425,12 @@ impl SearchRequest {
     pub(crate) const fn as_validated(&self) -> &semantic_code_config::ValidatedSearchRequest {
         &self.0
     }
+
+    /// Canonical form of the validated filter expression, if any.
+    #[must_use]
+    pub fn normalized_filter(&self) -> Option<&str> {
+        self.0.normalized_filter.as_deref()
+    }
 }
 
 impl From<semantic_code_config::ValidatedSearchRequest> for SearchRequest {
@@ -401,6 +492,44 @@ impl From<semantic_code_app::IndexCodebaseStatus> for IndexCodebaseStatus {
     }
 }
 
+/// Semantic search phase reported to progress callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchPhase {
+    /// Embedding the query text.
+    EmbeddingQuery,
+    /// Querying the vector database.
+    Searching,
+    /// Ordering and post-processing candidates.
+    Reranking,
+    /// Search finished.
+    Done,
+}
+
+impl SearchPhase {
+    /// Stable phase label.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::EmbeddingQuery => "embedding_query",
+            Self::Searching => "searching",
+            Self::Reranking => "reranking",
+            Self::Done => "done",
+        }
+    }
+}
+
+impl From<semantic_code_app::SearchPhase> for SearchPhase {
+    fn from(value: semantic_code_app::SearchPhase) -> Self {
+        match value {
+            semantic_code_app::SearchPhase::EmbeddingQuery => Self::EmbeddingQuery,
+            semantic_code_app::SearchPhase::Searching => Self::Searching,
+            semantic_code_app::SearchPhase::Reranking => Self::Reranking,
+            semantic_code_app::SearchPhase::Done => Self::Done,
+        }
+    }
+}
+
 /// Scan stage stats for indexing output.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -485,6 +614,8 @@ impl From<semantic_code_app::ScanFunctionStats> for IndexScanBreakdown {
 pub struct IndexScanStats {
     /// Files discovered for indexing.
     pub files: u64,
+    /// Files skipped because their contents looked binary.
+    pub skipped_binary: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level scan breakdown.
@@ -495,6 +626,7 @@ impl From<semantic_code_app::ScanStageStats> for IndexScanStats {
     fn from(value: semantic_code_app::ScanStageStats) -> Self {
         Self {
             files: value.files,
+            skipped_binary: value.skipped_binary,
             duration_ms: value.duration_ms,
             breakdown: value.breakdown.into(),
         }
@@ -530,6 +662,12 @@ pub struct IndexSplitStats {
     pub files: u64,
     /// Chunks produced by the splitter.
     pub chunks: u64,
+    /// Chunks over the embedding provider input limit (split, truncated, or skipped).
+    #[serde(default)]
+    pub oversized_chunks: u64,
+    /// Chunks dropped for being shorter than `sync.minChunkChars`.
+    #[serde(default)]
+    pub dropped_small: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level split breakdown.
@@ -541,6 +679,8 @@ impl From<semantic_code_app::SplitStageStats> for IndexSplitStats {
         Self {
             files: value.files,
             chunks: value.chunks,
+            oversized_chunks: value.oversized_chunks,
+            dropped_small: value.dropped_small,
             duration_ms: value.duration_ms,
             breakdown: value.breakdown.into(),
         }
@@ -576,6 +716,12 @@ pub struct IndexEmbedStats {
     pub batches: u64,
     /// Chunks embedded.
     pub chunks: u64,
+    /// Embedding batches that failed and were skipped.
+    #[serde(default)]
+    pub failed_batches: u64,
+    /// Chunks in skipped embedding batches.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level embed breakdown.
@@ -587,6 +733,8 @@ impl From<semantic_code_app::EmbedStageStats> for IndexEmbedStats {
         Self {
             batches: value.batches,
             chunks: value.chunks,
+            failed_batches: value.failed_batches,
+            failed_chunks: value.failed_chunks,
             duration_ms: value.duration_ms,
             breakdown: value.breakdown.into(),
         }
@@ -618,6 +766,9 @@ pub struct IndexInsertStats {
     pub batches: u64,
     /// Chunks inserted.
     pub chunks: u64,
+    /// Chunks rejected by the vector DB and skipped.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level insert breakdown.
@@ -629,6 +780,7 @@ impl From<semantic_code_app::InsertStageStats> for IndexInsertStats {
         Self {
             batches: value.batches,
             chunks: value.chunks,
+            failed_chunks: value.failed_chunks,
             duration_ms: value.duration_ms,
             breakdown: value.breakdown.into(),
         }
@@ -673,6 +825,9 @@ pub struct IndexCodebaseOutput {
     pub total_chunks: usize,
     /// Completion status.
     pub status: IndexCodebaseStatus,
+    /// Files left partly indexed by tolerated embedding failures.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_files: Vec<Box<str>>,
     /// Stage-level ingestion stats.
     pub stage_stats: IndexStageStats,
 }
@@ -683,13 +838,14 @@ impl From<semantic_code_app::IndexCodebaseOutput> for IndexCodebaseOutput {
             indexed_files: value.indexed_files,
             total_chunks: value.total_chunks,
             status: value.status.into(),
+            failed_files: value.failed_files,
             stage_stats: value.stage_stats.into(),
         }
     }
 }
 
 /// Output returned by reindex-by-change through facade APIs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReindexByChangeOutput {
     /// Added files count.
@@ -698,6 +854,13 @@ pub struct ReindexByChangeOutput {
     pub removed: usize,
     /// Modified files count.
     pub modified: usize,
+    /// Stage stats of re-indexing changed files, when any were indexed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_stats: Option<IndexStageStats>,
+    /// Files left partly indexed by tolerated embedding failures; the next
+    /// reindex retries them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_files: Vec<Box<str>>,
 }
 
 impl From<semantic_code_app::ReindexByChangeOutput> for ReindexByChangeOutput {
@@ -706,6 +869,245 @@ impl From<semantic_code_app::ReindexByChangeOutput> for ReindexByChangeOutput {
             added: value.added,
             removed: value.removed,
             modified: value.modified,
+            stage_stats: value.stage_stats.map(Into::into),
+            failed_files: value.failed_files,
+        }
+    }
+}
+
+/// Overall verification outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerifyStatus {
+    /// Snapshot and vector DB agree.
+    Ok,
+    /// At least one mismatch was detected.
+    Drift,
+}
+
+impl From<semantic_code_app::VerifyStatus> for VerifyStatus {
+    fn from(value: semantic_code_app::VerifyStatus) -> Self {
+        match value {
+            semantic_code_app::VerifyStatus::Ok => Self::Ok,
+            semantic_code_app::VerifyStatus::Drift => Self::Drift,
+        }
+    }
+}
+
+/// A single mismatch between snapshot and vector DB state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum VerifyDrift {
+    /// The snapshot exists but the collection is missing from the vector DB.
+    MissingCollection,
+    /// Document counts differ.
+    #[serde(rename_all = "camelCase")]
+    CountMismatch {
+        /// Count recorded by the snapshot.
+        expected: u64,
+        /// Count reported by the vector DB.
+        actual: u64,
+    },
+    /// Vector dimensions differ.
+    #[serde(rename_all = "camelCase")]
+    DimensionMismatch {
+        /// Dimension recorded by the snapshot.
+        expected: u32,
+        /// Dimension reported by the vector DB.
+        actual: u32,
+    },
+    /// Indexed documents reference paths the sync snapshot does not track.
+    #[serde(rename_all = "camelCase")]
+    DanglingDocuments {
+        /// Number of dangling documents.
+        count: u64,
+        /// Sample of dangling relative paths (bounded).
+        sample: Vec<Box<str>>,
+    },
+}
+
+impl From<semantic_code_app::VerifyDrift> for VerifyDrift {
+    fn from(value: semantic_code_app::VerifyDrift) -> Self {
+        match value {
+            semantic_code_app::VerifyDrift::MissingCollection => Self::MissingCollection,
+            semantic_code_app::VerifyDrift::CountMismatch { expected, actual } => {
+                Self::CountMismatch { expected, actual }
+            },
+            semantic_code_app::VerifyDrift::DimensionMismatch { expected, actual } => {
+                Self::DimensionMismatch { expected, actual }
+            },
+            semantic_code_app::VerifyDrift::DanglingDocuments { count, sample } => {
+                Self::DanglingDocuments { count, sample }
+            },
+        }
+    }
+}
+
+/// Index/snapshot consistency report returned by verify.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyReport {
+    /// Verified collection name.
+    pub collection_name: CollectionName,
+    /// Overall status.
+    pub status: VerifyStatus,
+    /// Whether the collection exists in the vector DB.
+    pub collection_exists: bool,
+    /// Document count reported by the vector DB, when supported.
+    pub vector_count: Option<u64>,
+    /// Vector dimension reported by the vector DB, when supported.
+    pub vector_dimension: Option<u32>,
+    /// Detected mismatches (empty when status is `ok`).
+    pub drift: Vec<VerifyDrift>,
+}
+
+impl From<semantic_code_app::VerifyReport> for VerifyReport {
+    fn from(value: semantic_code_app::VerifyReport) -> Self {
+        Self {
+            collection_name: value.collection_name,
+            status: value.status.into(),
+            collection_exists: value.collection_exists,
+            vector_count: value.vector_count,
+            vector_dimension: value.vector_dimension,
+            drift: value.drift.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Per-file aggregate of indexed chunks returned by `ls`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedFileSummary {
+    /// Path relative to the codebase root.
+    pub relative_path: Box<str>,
+    /// Number of indexed chunks for the file.
+    pub chunk_count: u64,
+    /// Language recorded for the file's chunks, when known.
+    pub language: Option<Box<str>>,
+}
+
+impl From<semantic_code_app::IndexedFileSummary> for IndexedFileSummary {
+    fn from(value: semantic_code_app::IndexedFileSummary) -> Self {
+        Self {
+            relative_path: value.relative_path,
+            chunk_count: value.chunk_count,
+            language: value.language,
+        }
+    }
+}
+
+/// Result of migrating an index to another vector DB backend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateOutput {
+    /// Migrated collection name.
+    pub collection_name: Box<str>,
+    /// Vector dimension shared by source and destination.
+    pub dimension: u32,
+    /// Number of documents copied.
+    pub migrated_documents: u64,
+    /// Whether the destination collection was created by this run.
+    pub created_collection: bool,
+}
+
+impl From<semantic_code_app::MigrateIndexOutput> for MigrateOutput {
+    fn from(value: semantic_code_app::MigrateIndexOutput) -> Self {
+        Self {
+            collection_name: value.collection_name.as_str().into(),
+            dimension: value.dimension,
+            migrated_documents: value.migrated_documents,
+            created_collection: value.created_collection,
+        }
+    }
+}
+
+/// Result of exporting an index to a JSONL file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOutput {
+    /// Exported collection name.
+    pub collection_name: Box<str>,
+    /// Number of documents written, one per line.
+    pub exported_documents: u64,
+}
+
+impl From<semantic_code_app::ExportIndexOutput> for ExportOutput {
+    fn from(value: semantic_code_app::ExportIndexOutput) -> Self {
+        Self {
+            collection_name: value.collection_name.as_str().into(),
+            exported_documents: value.exported_documents,
+        }
+    }
+}
+
+/// Result of importing documents from a JSONL file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOutput {
+    /// Target collection name.
+    pub collection_name: Box<str>,
+    /// Vector dimension of the imported documents.
+    pub dimension: u32,
+    /// Number of documents inserted.
+    pub imported_documents: u64,
+    /// Whether the collection was created by this run.
+    pub created_collection: bool,
+}
+
+impl From<semantic_code_app::ImportIndexOutput> for ImportOutput {
+    fn from(value: semantic_code_app::ImportIndexOutput) -> Self {
+        Self {
+            collection_name: value.collection_name.as_str().into(),
+            dimension: value.dimension,
+            imported_documents: value.imported_documents,
+            created_collection: value.created_collection,
+        }
+    }
+}
+
+/// Result of pruning chunks for files that no longer exist on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneOutput {
+    /// Files found on disk that `index` would pick up.
+    pub scanned_files: usize,
+    /// Distinct indexed paths before pruning.
+    pub indexed_files: usize,
+    /// Relative paths whose chunks were removed, sorted.
+    pub pruned_files: Vec<Box<str>>,
+    /// Number of chunks removed.
+    pub removed_chunks: u64,
+}
+
+impl From<semantic_code_app::PruneIndexOutput> for PruneOutput {
+    fn from(value: semantic_code_app::PruneIndexOutput) -> Self {
+        Self {
+            scanned_files: value.scanned_files,
+            indexed_files: value.indexed_files,
+            pruned_files: value.pruned_files,
+            removed_chunks: value.removed_chunks,
+        }
+    }
+}
+
+/// Progress update emitted while migrating documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateProgress {
+    /// Documents copied so far.
+    pub current: u64,
+    /// Total documents to copy.
+    pub total: u64,
+    /// Completion percentage (0-100).
+    pub percentage: u8,
+}
+
+impl From<semantic_code_app::IndexProgress> for MigrateProgress {
+    fn from(value: semantic_code_app::IndexProgress) -> Self {
+        Self {
+            current: value.current,
+            total: value.total,
+            percentage: value.percentage,
         }
     }
 }
@@ -755,6 +1157,226 @@ impl From<semantic_code_infra::SnapshotStatus> for SnapshotStatus {
     }
 }
 
+/// Embedding probe summary for the hidden `embed` command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliEmbedReport {
+    /// Provider id that produced the vector.
+    pub provider: Box<str>,
+    /// Vector dimensionality.
+    pub dimension: u32,
+    /// L2 norm of the vector.
+    pub l2_norm: f32,
+    /// Leading vector components.
+    pub preview: Vec<f32>,
+}
+
+impl From<semantic_code_infra::CliEmbedReport> for CliEmbedReport {
+    fn from(value: semantic_code_infra::CliEmbedReport) -> Self {
+        Self {
+            provider: value.provider,
+            dimension: value.dimension,
+            l2_norm: value.l2_norm,
+            preview: value.preview,
+        }
+    }
+}
+
+/// Stored vector summary for the hidden `debug get-vector` command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredVectorReport {
+    /// Chunk id the vector is stored under.
+    pub id: Box<str>,
+    /// Vector dimensionality.
+    pub dimension: u32,
+    /// L2 norm of the vector.
+    pub l2_norm: f32,
+}
+
+/// Outcome of a single `doctor` check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DoctorStatus {
+    /// The check succeeded.
+    Pass,
+    /// The check found something worth attention that does not block usage.
+    Warn,
+    /// The check failed.
+    Fail,
+}
+
+impl DoctorStatus {
+    /// Stable status label.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+impl From<semantic_code_infra::DoctorStatus> for DoctorStatus {
+    fn from(value: semantic_code_infra::DoctorStatus) -> Self {
+        match value {
+            semantic_code_infra::DoctorStatus::Pass => Self::Pass,
+            semantic_code_infra::DoctorStatus::Warn => Self::Warn,
+            semantic_code_infra::DoctorStatus::Fail => Self::Fail,
+        }
+    }
+}
+
+/// One `doctor` check result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorCheck {
+    /// Stable check name.
+    pub name: Box<str>,
+    /// Check outcome.
+    pub status: DoctorStatus,
+    /// What was observed.
+    pub message: Box<str>,
+    /// Suggested remediation when the check did not pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Box<str>>,
+}
+
+impl From<semantic_code_infra::DoctorCheck> for DoctorCheck {
+    fn from(value: semantic_code_infra::DoctorCheck) -> Self {
+        Self {
+            name: value.name,
+            status: value.status.into(),
+            message: value.message,
+            hint: value.hint,
+        }
+    }
+}
+
+/// Ordered `doctor` results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorReport {
+    /// Check results in execution order.
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Returns true when any check failed.
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == DoctorStatus::Fail)
+    }
+}
+
+impl From<semantic_code_infra::DoctorReport> for DoctorReport {
+    fn from(value: semantic_code_infra::DoctorReport) -> Self {
+        Self {
+            checks: value.checks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Non-fatal config warning surfaced by `config check`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigWarning {
+    /// Dotted config field path (e.g. `embedding.batchSize`).
+    pub field: Box<str>,
+    /// Human-readable explanation.
+    pub message: Box<str>,
+}
+
+impl From<semantic_code_config::ConfigWarning> for ConfigWarning {
+    fn from(value: semantic_code_config::ConfigWarning) -> Self {
+        Self {
+            field: value.field,
+            message: value.message,
+        }
+    }
+}
+
+/// Effective config field changed by overrides, surfaced by `config check --show-diff`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigFieldChange {
+    /// Dotted config field path (e.g. `core.timeoutMs`).
+    pub field: Box<str>,
+    /// Value before overrides (redacted when the field is secret).
+    pub before: Box<str>,
+    /// Value after overrides (redacted when the field is secret).
+    pub after: Box<str>,
+}
+
+impl From<semantic_code_config::ConfigFieldChange> for ConfigFieldChange {
+    fn from(value: semantic_code_config::ConfigFieldChange) -> Self {
+        Self {
+            field: value.field,
+            before: value.before,
+            after: value.after,
+        }
+    }
+}
+
+/// Resolved numeric limits of the effective config, as plain integers.
+///
+/// Optional caps are `None` when the config leaves them unset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveLimits {
+    /// Core timeout (ms).
+    pub core_timeout_ms: u64,
+    /// Core max concurrency.
+    pub core_max_concurrency: u32,
+    /// Core max chunk chars.
+    pub core_max_chunk_chars: u32,
+    /// Embedding batch size.
+    pub embedding_batch_size: u32,
+    /// Vector DB batch size.
+    pub vector_db_batch_size: u32,
+    /// Sync max files.
+    pub sync_max_files: u32,
+    /// Sync max chunks.
+    pub sync_max_chunks: Option<u32>,
+    /// Sync max file size (bytes).
+    pub sync_max_file_size_bytes: u64,
+    /// Max in-flight file tasks.
+    pub core_max_in_flight_files: Option<u32>,
+    /// Max in-flight embedding batches.
+    pub core_max_in_flight_embedding_batches: Option<u32>,
+    /// Max in-flight insert batches.
+    pub core_max_in_flight_inserts: Option<u32>,
+    /// Max buffered chunks.
+    pub core_max_buffered_chunks: Option<u32>,
+    /// Max buffered embeddings.
+    pub core_max_buffered_embeddings: Option<u32>,
+}
+
+impl From<semantic_code_config::ConfigLimits> for EffectiveLimits {
+    fn from(value: semantic_code_config::ConfigLimits) -> Self {
+        Self {
+            core_timeout_ms: value.core_timeout_ms.get(),
+            core_max_concurrency: value.core_max_concurrency.get(),
+            core_max_chunk_chars: value.core_max_chunk_chars.get(),
+            embedding_batch_size: value.embedding_batch_size.get(),
+            vector_db_batch_size: value.vector_db_batch_size.get(),
+            sync_max_files: value.sync_max_files.get(),
+            sync_max_chunks: value.sync_max_chunks.map(BoundedU32::get),
+            sync_max_file_size_bytes: value.sync_max_file_size_bytes.get(),
+            core_max_in_flight_files: value.core_max_in_flight_files.map(BoundedU32::get),
+            core_max_in_flight_embedding_batches: value
+                .core_max_in_flight_embedding_batches
+                .map(BoundedU32::get),
+            core_max_in_flight_inserts: value.core_max_in_flight_inserts.map(BoundedU32::get),
+            core_max_buffered_chunks: value.core_max_buffered_chunks.map(BoundedU32::get),
+            core_max_buffered_embeddings: value.core_max_buffered_embeddings.map(BoundedU32::get),
+        }
+    }
+}
+
 /// Minimal config summary for CLI status output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -843,6 +1465,36 @@ pub struct CliStatus {
     pub sync_snapshot: SnapshotStatus,
     /// Effective config summary.
     pub config: CliConfigSummary,
+    /// Recent background jobs, active first, then by completion time.
+    pub recent_jobs: Vec<JobSummary>,
+    /// Coarse index state derived from the manifest and vector snapshot.
+    pub index_state: CliIndexState,
+}
+
+/// Coarse index state reported by `status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum CliIndexState {
+    /// The codebase was initialized but never indexed.
+    NotIndexed,
+    /// Indexing ran but produced no vectors.
+    Empty,
+    /// Indexing produced vectors.
+    #[serde(rename_all = "camelCase")]
+    Indexed {
+        /// Number of stored vectors, when known.
+        vectors: Option<u64>,
+    },
+}
+
+impl From<semantic_code_infra::CliIndexState> for CliIndexState {
+    fn from(value: semantic_code_infra::CliIndexState) -> Self {
+        match value {
+            semantic_code_infra::CliIndexState::NotIndexed => Self::NotIndexed,
+            semantic_code_infra::CliIndexState::Empty => Self::Empty,
+            semantic_code_infra::CliIndexState::Indexed { vectors } => Self::Indexed { vectors },
+        }
+    }
 }
 
 impl From<semantic_code_infra::CliStatus> for CliStatus {
@@ -861,6 +1513,8 @@ impl From<semantic_code_infra::CliStatus> for CliStatus {
             vector_snapshot: value.vector_snapshot.into(),
             sync_snapshot: value.sync_snapshot.into(),
             config: value.config.into(),
+            recent_jobs: value.recent_jobs.into_iter().map(Into::into).collect(),
+            index_state: value.index_state.into(),
         }
     }
 }
@@ -1038,6 +1692,37 @@ impl From<semantic_code_infra::JobState> for JobState {
     }
 }
 
+/// Compact view of a job for status history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSummary {
+    /// Job identifier.
+    pub id: Box<str>,
+    /// Job kind.
+    pub kind: JobKind,
+    /// Job state.
+    pub state: JobState,
+    /// Job creation time (ms since epoch).
+    pub created_at_ms: u64,
+    /// Job last update time (ms since epoch).
+    pub updated_at_ms: u64,
+    /// Elapsed time between creation and the last update (ms).
+    pub duration_ms: u64,
+}
+
+impl From<semantic_code_infra::JobSummary> for JobSummary {
+    fn from(value: semantic_code_infra::JobSummary) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind.into(),
+            state: value.state.into(),
+            created_at_ms: value.created_at_ms,
+            updated_at_ms: value.updated_at_ms,
+            duration_ms: value.duration_ms,
+        }
+    }
+}
+
 /// Background job request payload.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1176,6 +1861,9 @@ pub struct JobScanBreakdown {
 pub struct JobScanStats {
     /// Files discovered for indexing.
     pub files: u64,
+    /// Files skipped because their contents looked binary.
+    #[serde(default)]
+    pub skipped_binary: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level scan breakdown.
@@ -1200,6 +1888,12 @@ pub struct JobSplitStats {
     pub files: u64,
     /// Chunks produced by the splitter.
     pub chunks: u64,
+    /// Chunks over the embedding provider input limit (split, truncated, or skipped).
+    #[serde(default)]
+    pub oversized_chunks: u64,
+    /// Chunks dropped for being shorter than `sync.minChunkChars`.
+    #[serde(default)]
+    pub dropped_small: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level split breakdown.
@@ -1224,6 +1918,12 @@ pub struct JobEmbedStats {
     pub batches: u64,
     /// Chunks embedded.
     pub chunks: u64,
+    /// Embedding batches that failed and were skipped.
+    #[serde(default)]
+    pub failed_batches: u64,
+    /// Chunks in skipped embedding batches.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level embed breakdown.
@@ -1246,6 +1946,9 @@ pub struct JobInsertStats {
     pub batches: u64,
     /// Chunks inserted.
     pub chunks: u64,
+    /// Chunks rejected by the vector DB and skipped.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level insert breakdown.
@@ -1325,6 +2028,7 @@ macro_rules! facade_job_stage_stats {
             },
             scan: JobScanStats {
                 files: $stats.scan.files,
+                skipped_binary: $stats.scan.skipped_binary,
                 duration_ms: $stats.scan.duration_ms,
                 breakdown: JobScanBreakdown {
                     load_ignore_patterns: infra_job_timing_stats!(
@@ -1337,6 +2041,8 @@ macro_rules! facade_job_stage_stats {
             split: JobSplitStats {
                 files: $stats.split.files,
                 chunks: $stats.split.chunks,
+                oversized_chunks: $stats.split.oversized_chunks,
+                dropped_small: $stats.split.dropped_small,
                 duration_ms: $stats.split.duration_ms,
                 breakdown: JobSplitBreakdown {
                     file_passes_size_check: infra_job_timing_stats!(
@@ -1356,6 +2062,8 @@ macro_rules! facade_job_stage_stats {
             embed: JobEmbedStats {
                 batches: $stats.embed.batches,
                 chunks: $stats.embed.chunks,
+                failed_batches: $stats.embed.failed_batches,
+                failed_chunks: $stats.embed.failed_chunks,
                 duration_ms: $stats.embed.duration_ms,
                 breakdown: JobEmbedBreakdown {
                     queue_latency: infra_job_timing_stats!($stats.embed.breakdown.queue_latency),
@@ -1373,6 +2081,7 @@ macro_rules! facade_job_stage_stats {
             insert: JobInsertStats {
                 batches: $stats.insert.batches,
                 chunks: $stats.insert.chunks,
+                failed_chunks: $stats.insert.failed_chunks,
                 duration_ms: $stats.insert.duration_ms,
                 breakdown: JobInsertBreakdown {
                     provider_insert_batch: infra_job_timing_stats!(
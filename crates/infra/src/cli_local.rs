@@ -4,9 +4,13 @@ use crate::calibration_persist::{apply_ema_observation, clear_ema_sidecars};
 use crate::cli_calibration::{read_calibration, write_calibration};
 use crate::cli_manifest::{
     CliManifest, append_context_gitignore, config_path as context_config_path,
-    ensure_default_config, read_manifest, touch_manifest, write_manifest,
+    ensure_default_config, mark_manifest_indexed, read_manifest, write_manifest,
 };
-use crate::embedding_factory::build_embedding_port_with_telemetry;
+use crate::embedding_factory::{
+    build_embedding_port_with_telemetry, build_language_embedding_routes,
+};
+use crate::jobs::{JobSummary, list_recent_jobs};
+use crate::request_attributes::{ensure_writable, new_seeded_request_context};
 use crate::vectordb_factory::{
     DfrrPrewarmPlanSummary, LocalKernelBuilder, build_vectordb_port, summarize_dfrr_prewarm_plan,
 };
@@ -14,34 +18,41 @@ use crate::{InfraError, InfraResult};
 use semantic_code_adapters::{
     IgnoreMatcher, JsonLogger, JsonTelemetry, LocalCalibrationAdapter, LocalFileSync,
     LocalFileSystem, LocalPathPolicy, StderrLogSink, TaggedTelemetry, TreeSitterSplitter,
+    read_v1_snapshot_payload,
 };
 use semantic_code_app::{
-    CalibrateBq1Deps, CalibrateBq1Input, ClearIndexDeps, ClearIndexInput, IndexCodebaseDeps,
-    IndexCodebaseInput, IndexCodebaseOutput, IndexProgress, ReindexByChangeDeps,
-    ReindexByChangeInput, ReindexByChangeOutput, SemanticSearchDeps, SemanticSearchInput,
-    SemanticSearchOutput, calibrate_bq1, clear_index, index_codebase, reindex_by_change,
-    semantic_search,
+    CalibrateBq1Deps, CalibrateBq1Input, ClearIndexDeps, ClearIndexInput, CollectionPolicy,
+    ExportIndexDeps, ExportIndexInput, ExportIndexOutput, ImportIndexDeps, ImportIndexInput,
+    ImportIndexOutput, IndexCodebaseDeps, IndexCodebaseInput, IndexCodebaseOutput, IndexProgress,
+    IndexedFileSummary, ListIndexedFilesDeps, ListIndexedFilesInput, MigrateIndexDeps,
+    MigrateIndexInput, MigrateIndexOutput, PruneIndexDeps, PruneIndexInput, PruneIndexOutput,
+    ReindexByChangeDeps, ReindexByChangeInput, ReindexByChangeOutput, SearchPhase,
+    SemanticSearchDeps, SemanticSearchInput, SemanticSearchOutput, VerifyIndexDeps,
+    VerifyIndexInput, VerifyReport, apply_input_template, calibrate_bq1, clear_index, export_index,
+    import_index, index_codebase, index_collection_names, list_indexed_files, migrate_index,
+    prune_index, reindex_by_change, semantic_search, verify_index,
 };
 use semantic_code_config::{
-    BackendConfig, RuntimeEnv, SnapshotStorageMode, ValidatedBackendConfig,
-    ValidatedClearIndexRequest, ValidatedIndexRequest, ValidatedReindexByChangeRequest,
-    ValidatedSearchRequest, VectorSearchStrategy, load_backend_config_from_path,
-    load_backend_config_std_env, load_runtime_env_std_env, to_pretty_toml,
+    BackendConfig, ConfigTemplate, RuntimeEnv, SearchRequestLimits, SnapshotStorageMode,
+    ValidatedBackendConfig, ValidatedClearIndexRequest, ValidatedIndexRequest,
+    ValidatedReindexByChangeRequest, ValidatedSearchRequest, VectorSearchStrategy,
+    is_stdin_config_path, load_backend_config_from_path, load_backend_config_std_env,
+    load_runtime_env_std_env, to_pretty_toml,
 };
 use semantic_code_domain::{
     CalibrationParams, CalibrationState, CollectionName, CollectionNamingInput,
-    EmbeddingProviderId, IndexMode, derive_collection_name,
+    EmbeddingProviderId, IndexMode, OnDuplicateId, SearchExclusions, derive_collection_name,
 };
 use semantic_code_ports::{
     EmbedBatchRequest, EmbedRequest, EmbeddingPort, EmbeddingProviderInfo, EmbeddingVector,
-    LogFields, LogLevel, LoggerPort, TelemetryPort, TelemetryTags,
+    LogFields, LogLevel, LoggerPort, TelemetryPort, TelemetryTags, VectorDbPort,
 };
 use semantic_code_shared::{
     BoundedU32, ErrorClass, ErrorCode, ErrorEnvelope, REDACTED_VALUE, RequestContext,
 };
 use semantic_code_vector::VectorSearchBackend;
 use serde_json::Value;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
@@ -53,6 +64,9 @@ const VECTOR_SNAPSHOT_DIR: &str = "vector";
 const VECTOR_COLLECTIONS_DIR: &str = "collections";
 const SYNC_SNAPSHOT_DIR: &str = "sync";
 const SNAPSHOT_FILE_EXT: &str = "json";
+const SNAPSHOT_GZIP_SUFFIX: &str = ".gz";
+const VECTOR_SNAPSHOT_V2_DIR_SUFFIX: &str = ".v2";
+const VECTOR_SNAPSHOT_V2_RECORDS_META_FILE: &str = "records.meta.jsonl";
 
 /// Summary of local CLI status information.
 #[derive(Debug, Clone)]
@@ -65,6 +79,24 @@ pub struct CliStatus {
     pub sync_snapshot: SnapshotStatus,
     /// Effective config summary.
     pub config: CliConfigSummary,
+    /// Recent background jobs, active first, then by completion time.
+    pub recent_jobs: Vec<JobSummary>,
+    /// Coarse index state derived from the manifest and vector snapshot.
+    pub index_state: CliIndexState,
+}
+
+/// Coarse index state reported by `status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliIndexState {
+    /// The codebase was initialized but never indexed.
+    NotIndexed,
+    /// Indexing ran but produced no vectors (e.g. no indexable files).
+    Empty,
+    /// Indexing produced vectors; the count is unknown for remote providers.
+    Indexed {
+        /// Number of stored vectors, when a local snapshot records it.
+        vectors: Option<u64>,
+    },
 }
 
 /// Summary of init command results.
@@ -163,6 +195,7 @@ fn build_index_input(
     request: &ValidatedIndexRequest,
     on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
 ) -> InfraResult<IndexCodebaseInput> {
+    let shard_count = resolve_index_shard_count(config, manifest, request)?;
     let request = request.as_ref();
     Ok(IndexCodebaseInput {
         codebase_root: request.codebase_root.clone(),
@@ -173,8 +206,11 @@ fn build_index_input(
         index_mode: manifest.index_mode,
         supported_extensions: Some(config.sync.allowed_extensions.clone()),
         ignore_patterns: Some(config.sync.ignore_patterns.clone()),
+        additional_ignore_patterns: config.sync.additional_ignore_patterns.clone(),
         file_list: None,
         force_reindex: request.force_reindex,
+        collection_description: None,
+        collection_policy: CollectionPolicy::default(),
         on_progress,
         embedding_batch_size: nonzero_usize_from_u32(
             config.limits().embedding_batch_size.get(),
@@ -191,6 +227,18 @@ fn build_index_input(
             "sync max files",
         )?),
         max_file_size_bytes: Some(config.limits().sync_max_file_size_bytes.get()),
+        max_file_size_bytes_by_extension: config.sync.per_extension_max_file_size_bytes.clone(),
+        path_normalization: config.sync.path_normalization,
+        oversize_chunk_policy: config.sync.oversize_chunk_policy,
+        skip_binary: config.sync.skip_binary,
+        document_template: config.embedding.input_template.document.clone(),
+        extra_metadata: BTreeMap::new(),
+        record_mtime: false,
+        min_chunk_chars: config.sync.min_chunk_chars,
+        max_chunk_chars: Some(usize_from_u32(
+            config.limits().core_max_chunk_chars.get(),
+            "core max chunk chars",
+        )?),
         max_buffered_chunks: opt_nonzero_usize_from_u32(
             config
                 .limits()
@@ -226,9 +274,65 @@ fn build_index_input(
                 .map(BoundedU32::get),
             "core max in-flight inserts",
         )?,
+        shard_count,
+        max_consecutive_failures: opt_nonzero_usize_from_u32(
+            config.embedding.max_consecutive_failures,
+            "embedding max consecutive failures",
+        )?,
+        max_total_failures: opt_nonzero_usize_from_u32(
+            config.embedding.max_total_failures,
+            "embedding max total failures",
+        )?,
+        on_duplicate_id: OnDuplicateId::Overwrite,
+        shutdown_timeout: None,
     })
 }
 
+/// Pick the shard count for an index run.
+///
+/// The configured count wins, but switching away from a recorded sharded
+/// layout needs a force reindex so stale shards are dropped first.
+fn resolve_index_shard_count(
+    config: &ValidatedBackendConfig,
+    manifest: &CliManifest,
+    request: &ValidatedIndexRequest,
+) -> InfraResult<Option<u32>> {
+    let request = request.as_ref();
+    let configured = config.vector_db.shard_count;
+    if request.collection_name.is_some() || request.force_reindex {
+        return Ok(configured);
+    }
+    match manifest.shard_count {
+        Some(recorded) if configured != Some(recorded) => Err(ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "vectorDb.shardCount differs from the indexed layout; rerun with --force",
+        )
+        .with_metadata("recorded", recorded.to_string())
+        .with_metadata(
+            "configured",
+            configured.map_or_else(|| "none".to_owned(), |count| count.to_string()),
+        )),
+        _ => Ok(configured),
+    }
+}
+
+/// Record the shard layout of a finished index run in the manifest.
+fn record_index_shard_count(
+    codebase_root: &Path,
+    manifest: CliManifest,
+    shard_count: Option<u32>,
+) -> InfraResult<CliManifest> {
+    if manifest.shard_count == shard_count {
+        return Ok(manifest);
+    }
+    let manifest = CliManifest {
+        shard_count,
+        ..manifest
+    };
+    write_manifest(codebase_root, &manifest)?;
+    Ok(manifest)
+}
+
 fn build_splitter(config: &ValidatedBackendConfig) -> InfraResult<TreeSitterSplitter> {
     let splitter = TreeSitterSplitter::default();
     splitter.set_max_chunk_chars(usize_from_u32(
@@ -264,7 +368,7 @@ pub fn run_index_local_with_progress(
     let (config, env) = load_config_with_env(config_path.as_deref(), overrides_json)?;
     let manifest = ensure_manifest(codebase_root, &config, init_if_missing)?;
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
@@ -274,7 +378,15 @@ pub fn run_index_local_with_progress(
         codebase_root,
         scoped_telemetry.clone(),
     )?;
+    let embedding_routes = build_language_embedding_routes(
+        &config,
+        &env,
+        codebase_root,
+        &embedding,
+        scoped_telemetry.as_ref(),
+    )?;
     let input = build_index_input(&config, &manifest, request, on_progress.clone())?;
+    let index_shard_count = input.shard_count;
     let dfrr_prewarm_summary = summarize_dfrr_prewarm_plan(&config)?;
 
     // Capture auto-calibrate flag before config is moved into the async closure.
@@ -299,15 +411,20 @@ pub fn run_index_local_with_progress(
         let splitter = build_splitter(&config)?;
         let deps = IndexCodebaseDeps {
             embedding,
+            embedding_routes,
             vectordb,
             splitter: Arc::new(splitter),
-            filesystem: Arc::new(LocalFileSystem::new(Some(config.sync.max_file_size_bytes))),
+            filesystem: Arc::new(
+                LocalFileSystem::new(Some(config.sync.max_file_size_ceiling_bytes()))
+                    .with_follow_symlinks(config.sync.follow_symlinks),
+            ),
             path_policy: Arc::new(LocalPathPolicy::new()),
             ignore: Arc::new(IgnoreMatcher::new()),
             logger: scoped_logger,
             telemetry: scoped_telemetry,
         };
         let collection_name = input.collection_name.clone();
+        let shard_count = input.shard_count;
         let result = index_codebase(&ctx, &deps, input).await;
         let result = match result {
             Ok(output) => {
@@ -316,7 +433,8 @@ pub fn run_index_local_with_progress(
                     &dfrr_prewarm_summary,
                     PostIndexPhase::Start,
                 );
-                deps.vectordb.flush(&ctx, collection_name).await?;
+                flush_index_collections(&ctx, &deps.vectordb, &collection_name, shard_count)
+                    .await?;
                 emit_post_index_progress(
                     on_progress.as_ref(),
                     &dfrr_prewarm_summary,
@@ -329,7 +447,12 @@ pub fn run_index_local_with_progress(
         finalize_cancel_watcher(cancel_handle).await?;
         result
     })?;
-    touch_manifest(&codebase_root, &manifest)?;
+    let manifest = if request.as_ref().collection_name.is_none() {
+        record_index_shard_count(&codebase_root, manifest, index_shard_count)?
+    } else {
+        manifest
+    };
+    mark_manifest_indexed(&codebase_root, &manifest)?;
 
     // Auto-calibrate BQ1 threshold if configured and no calibration file exists.
     if should_auto_calibrate {
@@ -339,7 +462,27 @@ pub fn run_index_local_with_progress(
     Ok(output)
 }
 
+/// Search request limits from the codebase's effective config.
+pub fn search_request_limits_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<SearchRequestLimits> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    Ok(SearchRequestLimits::from_config(&config))
+}
+
 /// Run a local semantic search.
+pub fn run_search_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    request: &ValidatedSearchRequest,
+) -> InfraResult<SemanticSearchOutput> {
+    run_search_local_with_progress(config_path, overrides_json, request, None)
+}
+
+/// Run a local semantic search, reporting phase transitions to `on_progress`.
 #[tracing::instrument(
     name = "cli.search.local",
     skip_all,
@@ -347,12 +490,14 @@ pub fn run_index_local_with_progress(
         correlation_id = tracing::field::Empty,
         has_config_path = config_path.is_some(),
         has_overrides = overrides_json.is_some(),
+        has_progress_callback = on_progress.is_some(),
     )
 )]
-pub fn run_search_local(
+pub fn run_search_local_with_progress(
     config_path: Option<&Path>,
     overrides_json: Option<&str>,
     request: &ValidatedSearchRequest,
+    on_progress: Option<Arc<dyn Fn(SearchPhase) + Send + Sync>>,
 ) -> InfraResult<SemanticSearchOutput> {
     let request = request.as_ref();
     let codebase_root = request.codebase_root.as_path();
@@ -360,7 +505,7 @@ pub fn run_search_local(
     let (config, env) = load_config_with_env(config_path.as_deref(), overrides_json)?;
     let manifest = ensure_manifest(codebase_root, &config, false)?;
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
@@ -375,12 +520,25 @@ pub fn run_search_local(
         collection_name: manifest.collection_name.clone(),
         index_mode: manifest.index_mode,
         query: request.query.clone(),
+        query_template: config.embedding.input_template.query.clone(),
+        normalize_query: false,
         top_k: request.top_k,
         threshold: request
             .threshold
             .map(|value| f32_from_f64(value, "threshold"))
             .transpose()?,
         query_vector: None,
+        include_histogram: request.include_histogram.unwrap_or(false),
+        shard_count: manifest.shard_count,
+        group_by_file: request.group_by_file.unwrap_or(false),
+        exclusions: request.exclusions.clone(),
+        recency_boost: None,
+        context_lines: request
+            .include_content
+            .unwrap_or(false)
+            .then_some(request.context_lines)
+            .flatten(),
+        on_progress,
     };
 
     let snapshot_storage = manifest.snapshot_storage;
@@ -392,6 +550,9 @@ pub fn run_search_local(
             vectordb,
             logger: scoped_logger,
             telemetry: scoped_telemetry,
+            filesystem: Some(Arc::new(LocalFileSystem::new(Some(
+                config.sync.max_file_size_ceiling_bytes(),
+            )))),
         };
         let output = semantic_search(&ctx, &deps, input).await?;
 
@@ -414,7 +575,10 @@ pub struct LocalSearchSession {
     deps: SemanticSearchDeps,
     collection_name: CollectionName,
     index_mode: IndexMode,
+    shard_count: Option<u32>,
     codebase_root: Box<str>,
+    query_template: Option<Box<str>>,
+    seed: Option<u64>,
     runtime: tokio::runtime::Runtime,
 }
 
@@ -436,6 +600,7 @@ impl QueryVectorsOnlyEmbedding {
             info: EmbeddingProviderInfo {
                 id,
                 name: "Query vectors only".into(),
+                max_input_chars: None,
             },
         })
     }
@@ -487,16 +652,25 @@ impl LocalSearchSession {
         top_k: Option<u32>,
         threshold: Option<f32>,
     ) -> InfraResult<SemanticSearchOutput> {
-        let ctx = RequestContext::new_request();
+        let ctx = new_seeded_request_context(self.seed);
         let deps = self.deps.clone();
         let input = SemanticSearchInput {
             codebase_root: self.codebase_root.clone(),
             collection_name: self.collection_name.clone(),
             index_mode: self.index_mode,
             query: query.into(),
+            query_template: self.query_template.clone(),
+            normalize_query: false,
             top_k,
             threshold,
             query_vector: None,
+            include_histogram: false,
+            shard_count: self.shard_count,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
         self.runtime
             .block_on(async { semantic_search(&ctx, &deps, input).await })
@@ -510,28 +684,40 @@ impl LocalSearchSession {
         top_k: Option<u32>,
         threshold: Option<f32>,
     ) -> InfraResult<SemanticSearchOutput> {
-        let ctx = RequestContext::new_request();
+        let ctx = new_seeded_request_context(self.seed);
         let deps = self.deps.clone();
         let input = SemanticSearchInput {
             codebase_root: self.codebase_root.clone(),
             collection_name: self.collection_name.clone(),
             index_mode: self.index_mode,
             query: query_label.into(),
+            query_template: None,
+            normalize_query: false,
             top_k,
             threshold,
             query_vector: Some(vector),
+            include_histogram: false,
+            shard_count: self.shard_count,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
         self.runtime
             .block_on(async { semantic_search(&ctx, &deps, input).await })
     }
 
     /// Embed a query string and return the raw vector.
+    ///
+    /// The configured query template is applied before embedding.
     pub fn embed(&self, query: &str) -> InfraResult<EmbeddingVector> {
-        let ctx = RequestContext::new_request();
+        let ctx = new_seeded_request_context(self.seed);
         let deps = self.deps.clone();
+        let text = apply_input_template(self.query_template.as_deref(), query);
         self.runtime.block_on(async {
             ctx.ensure_not_cancelled("embed")?;
-            deps.embedding.embed(&ctx, query.into()).await
+            deps.embedding.embed(&ctx, text.into()).await
         })
     }
 }
@@ -580,7 +766,7 @@ pub fn open_search_session_with_options(
     let (config, env) = load_config_with_env(config_path.as_deref(), overrides_json)?;
     let manifest = ensure_manifest(codebase_root, &config, false)?;
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
     let embedding: Arc<dyn EmbeddingPort> = if query_vectors_only {
@@ -614,6 +800,7 @@ pub fn open_search_session_with_options(
         vectordb,
         logger: scoped_logger,
         telemetry: scoped_telemetry,
+        filesystem: None,
     };
 
     tracing::info!(
@@ -625,7 +812,10 @@ pub fn open_search_session_with_options(
         deps,
         collection_name: manifest.collection_name,
         index_mode: manifest.index_mode,
+        shard_count: manifest.shard_count,
         codebase_root: codebase_root.to_string_lossy().to_string().into_boxed_str(),
+        query_template: config.embedding.input_template.query.clone(),
+        seed: config.core.seed,
         runtime,
     })
 }
@@ -725,18 +915,26 @@ pub fn run_clear_local(
         snapshot_storage.clone(),
     ));
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
+    // An explicit collection is cleared on its own; the manifest's shard
+    // layout only describes the manifest collection.
+    let clears_manifest_collection = request.collection_name.is_none();
     let input = ClearIndexInput {
         codebase_root: codebase_root.to_path_buf(),
-        collection_name: manifest.collection_name,
+        collection_name: request
+            .collection_name
+            .clone()
+            .unwrap_or_else(|| manifest.collection_name.clone()),
+        shard_count: manifest.shard_count.filter(|_| clears_manifest_collection),
     };
 
     let codebase_root = codebase_root.to_path_buf();
+    let codebase_root_async = codebase_root.clone();
     run_async_with_ctx(ctx, move |ctx| async move {
-        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        let vectordb = build_vectordb_port(&config, &codebase_root_async, snapshot_storage).await?;
         let deps = ClearIndexDeps {
             vectordb,
             file_sync,
@@ -744,9 +942,385 @@ pub fn run_clear_local(
             telemetry: scoped_telemetry,
         };
         clear_index(&ctx, &deps, input).await
+    })?;
+    if clears_manifest_collection {
+        let manifest = CliManifest {
+            shard_count: None,
+            indexed_at_ms: None,
+            ..manifest
+        };
+        write_manifest(&codebase_root, &manifest)?;
+    }
+    Ok(())
+}
+
+/// Verify that the vector snapshot and sync snapshot agree with the vector DB.
+#[tracing::instrument(
+    name = "cli.verify.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+    )
+)]
+pub fn run_verify_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<VerifyReport> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let expected = read_vector_snapshot_summary(codebase_root, &manifest)?;
+    let tracked_paths = sync_snapshot_path(codebase_root, &snapshot_storage)
+        .map(|path| read_sync_tracked_paths(&path))
+        .transpose()?
+        .flatten();
+    let observability = observability_from_env();
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+    let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
+    let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
+
+    let codebase_root = codebase_root.to_path_buf();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        // Dangling-document scans read every row, so only run them locally.
+        let tracked_paths = if vectordb.provider().id.as_str() == "local" {
+            tracked_paths
+        } else {
+            None
+        };
+        let input = VerifyIndexInput {
+            collection_name: manifest.collection_name,
+            shard_count: manifest.shard_count,
+            expected_count: expected.count,
+            expected_dimension: expected.dimension,
+            tracked_paths,
+        };
+        let deps = VerifyIndexDeps {
+            vectordb,
+            logger: scoped_logger,
+            telemetry: scoped_telemetry,
+        };
+        verify_index(&ctx, &deps, input).await
     })
 }
 
+/// List indexed files with their chunk counts, sorted by relative path.
+#[tracing::instrument(
+    name = "cli.ls.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+    )
+)]
+pub fn list_indexed_files_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<Vec<IndexedFileSummary>> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+
+    let codebase_root = codebase_root.to_path_buf();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        let deps = ListIndexedFilesDeps { vectordb };
+        let input = ListIndexedFilesInput {
+            collection_name: manifest.collection_name,
+            shard_count: manifest.shard_count,
+        };
+        list_indexed_files(&ctx, &deps, input).await
+    })
+}
+
+/// Export every indexed document to `out_path` as JSON lines sorted by id.
+///
+/// `include_vectors` adds each document's dense vector to its line. The file
+/// is created (or truncated) before the collection is read.
+#[tracing::instrument(
+    name = "cli.export.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+        include_vectors = include_vectors,
+    )
+)]
+pub fn run_export_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    out_path: &Path,
+    include_vectors: bool,
+) -> InfraResult<ExportIndexOutput> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+    let file = std::fs::File::create(out_path).map_err(|error| {
+        InfraError::from(error).with_metadata("path", out_path.display().to_string())
+    })?;
+
+    let codebase_root = codebase_root.to_path_buf();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        let deps = ExportIndexDeps { vectordb };
+        let input = ExportIndexInput {
+            collection_name: manifest.collection_name,
+            shard_count: manifest.shard_count,
+            include_vectors,
+        };
+        let mut out = std::io::BufWriter::new(file);
+        export_index(&ctx, &deps, input, &mut out).await
+    })
+}
+
+/// Bulk-load documents from a JSONL dump at `in_path` without re-embedding.
+///
+/// Lines use the `export --with-vectors` shape. The codebase's collection is
+/// created when missing (initializing the manifest if needed) and flushed
+/// once every document is inserted.
+#[tracing::instrument(
+    name = "cli.import.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+    )
+)]
+pub fn run_import_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    in_path: &Path,
+) -> InfraResult<ImportIndexOutput> {
+    ensure_writable("import")?;
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, true)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let batch_size = nonzero_usize_from_u32(config.vector_db.batch_size, "vectorDb.batchSize")?;
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+    let file = std::fs::File::open(in_path).map_err(|error| {
+        InfraError::from(error).with_metadata("path", in_path.display().to_string())
+    })?;
+    let input = ImportIndexInput {
+        collection_name: manifest.collection_name.clone(),
+        shard_count: manifest.shard_count,
+        index_mode: manifest.index_mode,
+        batch_size,
+    };
+
+    let codebase_root_async = codebase_root.to_path_buf();
+    let output = run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root_async, snapshot_storage).await?;
+        let deps = ImportIndexDeps { vectordb };
+        let collection_name = input.collection_name.clone();
+        let shard_count = input.shard_count;
+        let mut reader = std::io::BufReader::new(file);
+        let output = import_index(&ctx, &deps, input, &mut reader).await?;
+        flush_index_collections(&ctx, &deps.vectordb, &collection_name, shard_count).await?;
+        Ok(output)
+    })?;
+    mark_manifest_indexed(codebase_root, &manifest)?;
+    Ok(output)
+}
+
+/// Read the stored dense vector for one chunk id from the codebase's collection.
+///
+/// Fails with `core:not_found` when the collection has no document with `id`.
+#[tracing::instrument(
+    name = "cli.get_vector.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+    )
+)]
+pub fn get_vector_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    id: &str,
+) -> InfraResult<EmbeddingVector> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+
+    let codebase_root = codebase_root.to_path_buf();
+    let id: Box<str> = id.into();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        vectordb
+            .get_vector(&ctx, manifest.collection_name, id.clone())
+            .await?
+            .ok_or_else(|| {
+                ErrorEnvelope::expected(ErrorCode::not_found(), "no stored vector for id")
+                    .with_metadata("id", id.to_string())
+            })
+    })
+}
+
+/// Remove chunks for indexed files that no longer exist under the codebase root.
+#[tracing::instrument(
+    name = "cli.prune.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+    )
+)]
+pub fn run_prune_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<PruneIndexOutput> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let observability = observability_from_env();
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+    let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
+    let input = PruneIndexInput {
+        codebase_root: codebase_root.to_path_buf(),
+        collection_name: manifest.collection_name,
+        shard_count: manifest.shard_count,
+        supported_extensions: Some(config.sync.allowed_extensions.clone()),
+        ignore_patterns: Some(config.sync.ignore_patterns.clone()),
+        additional_ignore_patterns: config.sync.additional_ignore_patterns.clone(),
+        path_normalization: config.sync.path_normalization,
+    };
+
+    let codebase_root = codebase_root.to_path_buf();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let vectordb = build_vectordb_port(&config, &codebase_root, snapshot_storage).await?;
+        let deps = PruneIndexDeps {
+            vectordb,
+            filesystem: Arc::new(
+                LocalFileSystem::new(Some(config.sync.max_file_size_ceiling_bytes()))
+                    .with_follow_symlinks(config.sync.follow_symlinks),
+            ),
+            path_policy: Arc::new(LocalPathPolicy::new()),
+            ignore: Arc::new(IgnoreMatcher::new()),
+            logger: scoped_logger,
+        };
+        let collection_name = input.collection_name.clone();
+        let shard_count = input.shard_count;
+        let output = prune_index(&ctx, &deps, input).await?;
+        if output.removed_chunks > 0 {
+            flush_index_collections(&ctx, &deps.vectordb, &collection_name, shard_count).await?;
+        }
+        Ok(output)
+    })
+}
+
+/// Copy the local index into another vector DB backend.
+///
+/// `destination_overrides_json` is applied on top of the loaded config to
+/// describe the destination (at least `vectorDb.provider`). Ids, content,
+/// vectors, and metadata are copied as-is, so no embedding calls are made.
+#[tracing::instrument(
+    name = "cli.migrate.local",
+    skip_all,
+    fields(
+        correlation_id = tracing::field::Empty,
+        has_config_path = config_path.is_some(),
+        has_overrides = overrides_json.is_some(),
+        has_progress_callback = on_progress.is_some(),
+    )
+)]
+pub fn run_migrate_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    destination_overrides_json: &str,
+    codebase_root: &Path,
+    on_progress: Option<Arc<dyn Fn(IndexProgress) + Send + Sync>>,
+) -> InfraResult<MigrateIndexOutput> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    let destination_overrides = merge_overrides_json(overrides_json, destination_overrides_json)?;
+    let destination_config = load_config(config_path.as_deref(), Some(&destination_overrides))?;
+    let manifest = ensure_manifest(codebase_root, &config, false)?;
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let batch_size = nonzero_usize_from_u32(config.vector_db.batch_size, "vectorDb.batchSize")?;
+    let ctx = new_seeded_request_context(config.core.seed);
+    tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
+
+    let codebase_root = codebase_root.to_path_buf();
+    run_async_with_ctx(ctx, move |ctx| async move {
+        let source = build_vectordb_port(&config, &codebase_root, snapshot_storage.clone()).await?;
+        let destination =
+            build_vectordb_port(&destination_config, &codebase_root, snapshot_storage).await?;
+        if source.provider().id == destination.provider().id {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                format!(
+                    "destination provider matches the source ({})",
+                    source.provider().id.as_str()
+                ),
+            ));
+        }
+        let deps = MigrateIndexDeps {
+            source,
+            destination,
+        };
+        let input = MigrateIndexInput {
+            collection_name: manifest.collection_name,
+            shard_count: manifest.shard_count,
+            index_mode: manifest.index_mode,
+            batch_size,
+            on_progress,
+        };
+        migrate_index(&ctx, &deps, input).await
+    })
+}
+
+/// Deep-merge `patch_json` into `base_json` (both config override objects).
+fn merge_overrides_json(base_json: Option<&str>, patch_json: &str) -> InfraResult<String> {
+    fn merge(base: &mut Value, patch: Value) {
+        match (base, patch) {
+            (Value::Object(base), Value::Object(patch)) => {
+                for (key, value) in patch {
+                    merge(base.entry(key).or_insert(Value::Null), value);
+                }
+            },
+            (base, patch) => *base = patch,
+        }
+    }
+
+    let mut base = match base_json {
+        Some(raw) => parse_snapshot_json(raw, "overrides")?,
+        None => Value::Object(serde_json::Map::new()),
+    };
+    merge(
+        &mut base,
+        parse_snapshot_json(patch_json, "destination overrides")?,
+    );
+    Ok(base.to_string())
+}
+
 /// Run BQ1 threshold calibration against the local vector index.
 ///
 /// Loads the kernel and snapshot independently (bypassing `LocalVectorDb`)
@@ -773,7 +1347,7 @@ pub fn run_calibrate_local(
     let config = load_config(config_path.as_deref(), overrides_json)?;
     let manifest = ensure_manifest(codebase_root, &config, false)?;
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
@@ -861,6 +1435,7 @@ fn build_reindex_input(
         index_mode: manifest.index_mode,
         supported_extensions: Some(config.sync.allowed_extensions.clone()),
         ignore_patterns: Some(config.sync.ignore_patterns.clone()),
+        additional_ignore_patterns: config.sync.additional_ignore_patterns.clone(),
         embedding_batch_size: nonzero_usize_from_u32(
             config.limits().embedding_batch_size.get(),
             "embedding batch size",
@@ -876,6 +1451,18 @@ fn build_reindex_input(
             "sync max files",
         )?),
         max_file_size_bytes: Some(config.limits().sync_max_file_size_bytes.get()),
+        max_file_size_bytes_by_extension: config.sync.per_extension_max_file_size_bytes.clone(),
+        path_normalization: config.sync.path_normalization,
+        oversize_chunk_policy: config.sync.oversize_chunk_policy,
+        skip_binary: config.sync.skip_binary,
+        document_template: config.embedding.input_template.document.clone(),
+        extra_metadata: BTreeMap::new(),
+        record_mtime: false,
+        min_chunk_chars: config.sync.min_chunk_chars,
+        max_chunk_chars: Some(usize_from_u32(
+            config.limits().core_max_chunk_chars.get(),
+            "core max chunk chars",
+        )?),
         max_buffered_chunks: opt_nonzero_usize_from_u32(
             config
                 .limits()
@@ -911,6 +1498,16 @@ fn build_reindex_input(
                 .map(BoundedU32::get),
             "core max in-flight inserts",
         )?,
+        shard_count: manifest.shard_count,
+        max_consecutive_failures: opt_nonzero_usize_from_u32(
+            config.embedding.max_consecutive_failures,
+            "embedding max consecutive failures",
+        )?,
+        max_total_failures: opt_nonzero_usize_from_u32(
+            config.embedding.max_total_failures,
+            "embedding max total failures",
+        )?,
+        atomic_per_file: false,
         on_progress,
     })
 }
@@ -948,7 +1545,7 @@ pub fn run_reindex_local_with_progress(
     let (config, env) = load_config_with_env(config_path.as_deref(), overrides_json)?;
     let manifest = ensure_manifest(codebase_root, &config, false)?;
     let observability = observability_from_env();
-    let ctx = RequestContext::new_request();
+    let ctx = new_seeded_request_context(config.core.seed);
     tracing::Span::current().record("correlation_id", ctx.correlation_id().as_str());
     let scoped_logger = scope_logger(observability.logger.as_ref(), &ctx);
     let scoped_telemetry = scope_telemetry(observability.telemetry.as_ref(), &ctx);
@@ -958,12 +1555,20 @@ pub fn run_reindex_local_with_progress(
         codebase_root,
         scoped_telemetry.clone(),
     )?;
+    let embedding_routes = build_language_embedding_routes(
+        &config,
+        &env,
+        codebase_root,
+        &embedding,
+        scoped_telemetry.as_ref(),
+    )?;
     let input = build_reindex_input(&config, &manifest, request, on_progress.clone())?;
     let dfrr_prewarm_summary = summarize_dfrr_prewarm_plan(&config)?;
 
-    let snapshot_storage = manifest.snapshot_storage;
-    let codebase_root = request.as_ref().codebase_root.clone();
-    run_async_with_ctx(ctx, move |ctx| async move {
+    let snapshot_storage = manifest.snapshot_storage.clone();
+    let codebase_root_async = request.as_ref().codebase_root.clone();
+    let output = run_async_with_ctx(ctx, move |ctx| async move {
+        let codebase_root = codebase_root_async;
         let cancel_handle = spawn_cancel_watcher(
             &ctx,
             cancel_path,
@@ -976,14 +1581,19 @@ pub fn run_reindex_local_with_progress(
             file_sync: Arc::new(LocalFileSync::new(codebase_root, snapshot_storage)),
             vectordb,
             embedding,
+            embedding_routes,
             splitter: Arc::new(splitter),
-            filesystem: Arc::new(LocalFileSystem::new(Some(config.sync.max_file_size_bytes))),
+            filesystem: Arc::new(
+                LocalFileSystem::new(Some(config.sync.max_file_size_ceiling_bytes()))
+                    .with_follow_symlinks(config.sync.follow_symlinks),
+            ),
             path_policy: Arc::new(LocalPathPolicy::new()),
             ignore: Arc::new(IgnoreMatcher::new()),
             logger: scoped_logger,
             telemetry: scoped_telemetry,
         };
         let collection_name = input.collection_name.clone();
+        let shard_count = input.shard_count;
         let result = reindex_by_change(&ctx, &deps, input).await;
         let result = match result {
             Ok(output) => {
@@ -992,7 +1602,8 @@ pub fn run_reindex_local_with_progress(
                     &dfrr_prewarm_summary,
                     PostIndexPhase::Start,
                 );
-                deps.vectordb.flush(&ctx, collection_name).await?;
+                flush_index_collections(&ctx, &deps.vectordb, &collection_name, shard_count)
+                    .await?;
                 emit_post_index_progress(
                     on_progress.as_ref(),
                     &dfrr_prewarm_summary,
@@ -1004,7 +1615,28 @@ pub fn run_reindex_local_with_progress(
         };
         finalize_cancel_watcher(cancel_handle).await?;
         result
-    })
+    })?;
+    mark_manifest_indexed(codebase_root, &manifest)?;
+    Ok(output)
+}
+
+/// Flush every collection the index occupies; shards that were never
+/// written are skipped.
+async fn flush_index_collections(
+    ctx: &RequestContext,
+    vectordb: &Arc<dyn VectorDbPort>,
+    collection_name: &CollectionName,
+    shard_count: Option<u32>,
+) -> InfraResult<()> {
+    let Some(shard_count) = shard_count else {
+        return vectordb.flush(ctx, collection_name.clone()).await;
+    };
+    for collection in index_collection_names(collection_name, Some(shard_count))? {
+        if vectordb.has_collection(ctx, collection.clone()).await? {
+            vectordb.flush(ctx, collection).await?;
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1044,22 +1676,38 @@ fn emit_post_index_progress(
 }
 
 /// Initialize config and manifest for a codebase.
+///
+/// With a `template`, the written config is that template's commented starter
+/// config instead of the serialized defaults.
 pub fn run_init_local(
     config_path: Option<&Path>,
     codebase_root: &Path,
     storage_mode: Option<SnapshotStorageMode>,
+    template: Option<ConfigTemplate>,
     force: bool,
 ) -> InfraResult<CliInitStatus> {
+    ensure_writable("init")?;
+    if config_path.is_some_and(is_stdin_config_path) {
+        return Err(ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "init writes a config file; stdin config is not supported",
+        )
+        .with_metadata("field", "config"));
+    }
     let config_path =
         config_path.map_or_else(|| context_config_path(codebase_root), Path::to_path_buf);
     let manifest_path = crate::cli_manifest::manifest_path(codebase_root);
 
     let created_config = if force || !config_path.is_file() {
-        let mut config = BackendConfig::default();
-        if let Some(storage_mode) = storage_mode {
-            config.vector_db.snapshot_storage = storage_mode;
-        }
-        let payload = to_pretty_toml(&config)?;
+        let payload = if let Some(template) = template {
+            template.render(storage_mode.as_ref())
+        } else {
+            let mut config = BackendConfig::default();
+            if let Some(storage_mode) = storage_mode {
+                config.vector_db.snapshot_storage = storage_mode;
+            }
+            to_pretty_toml(&config)?
+        };
         if let Some(parent) = config_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -1084,11 +1732,9 @@ pub fn run_init_local(
         }
     }
 
-    let collection_name = derive_collection_name(&CollectionNamingInput::new(
-        codebase_root.to_path_buf(),
-        validated.vector_db.index_mode,
-    ))
-    .map_err(ErrorEnvelope::from)?;
+    let collection_name =
+        derive_collection_name(&collection_naming_input(codebase_root, &validated))
+            .map_err(ErrorEnvelope::from)?;
     let manifest = CliManifest::new(
         codebase_root,
         collection_name.as_str(),
@@ -1106,6 +1752,36 @@ pub fn run_init_local(
     })
 }
 
+/// Resolve the configured `core.timeoutMs` for a codebase.
+pub fn resolve_core_timeout_ms(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<u64> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    Ok(config.core.timeout_ms)
+}
+
+/// Resolve the collection naming inputs (index mode and template) for a codebase.
+pub fn resolve_collection_naming(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<CollectionNamingInput> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_config(config_path.as_deref(), overrides_json)?;
+    Ok(collection_naming_input(codebase_root, &config))
+}
+
+fn collection_naming_input(
+    codebase_root: &Path,
+    config: &ValidatedBackendConfig,
+) -> CollectionNamingInput {
+    CollectionNamingInput::new(codebase_root.to_path_buf(), config.vector_db.index_mode)
+        .with_template(config.vector_db.collection_template.clone())
+}
+
 /// Read local CLI status information.
 pub fn read_status_local(
     config_path: Option<&Path>,
@@ -1147,15 +1823,49 @@ pub fn read_status_local(
         max_buffered_chunks: config.core.max_buffered_chunks,
         max_buffered_embeddings: config.core.max_buffered_embeddings,
     };
+    let history_limit =
+        usize::try_from(config.embedding.jobs.status_history_limit).unwrap_or(usize::MAX);
+    let recent_jobs = list_recent_jobs(codebase_root, history_limit)?;
+    let index_state = resolve_index_state(codebase_root, &manifest, &config)?;
 
     Ok(CliStatus {
         manifest,
         vector_snapshot,
         sync_snapshot,
         config: config_summary,
+        recent_jobs,
+        index_state,
     })
 }
 
+/// Classify the index as not indexed, empty, or populated.
+///
+/// The local provider skips writing a snapshot when nothing was inserted, so an
+/// indexed manifest without a snapshot means an empty index there.
+fn resolve_index_state(
+    codebase_root: &Path,
+    manifest: &CliManifest,
+    config: &ValidatedBackendConfig,
+) -> InfraResult<CliIndexState> {
+    let summary = read_vector_snapshot_summary(codebase_root, manifest)?;
+    let state = match summary.count {
+        Some(0) => CliIndexState::Empty,
+        Some(count) => CliIndexState::Indexed {
+            vectors: Some(count),
+        },
+        None if manifest.indexed_at_ms.is_none() => CliIndexState::NotIndexed,
+        None => {
+            let provider = config.vector_db.provider.as_deref().unwrap_or("local");
+            if provider.trim().eq_ignore_ascii_case("local") {
+                CliIndexState::Empty
+            } else {
+                CliIndexState::Indexed { vectors: None }
+            }
+        },
+    };
+    Ok(state)
+}
+
 fn cache_provider_str(provider: semantic_code_config::EmbeddingCacheDiskProvider) -> Box<str> {
     let value = match provider {
         semantic_code_config::EmbeddingCacheDiskProvider::Sqlite => "sqlite",
@@ -1216,12 +1926,10 @@ fn ensure_manifest(
     if !init_if_missing {
         return Err(missing_manifest_error());
     }
+    ensure_writable("init_manifest")?;
 
-    let collection_name = derive_collection_name(&CollectionNamingInput::new(
-        codebase_root.to_path_buf(),
-        config.vector_db.index_mode,
-    ))
-    .map_err(ErrorEnvelope::from)?;
+    let collection_name = derive_collection_name(&collection_naming_input(codebase_root, config))
+        .map_err(ErrorEnvelope::from)?;
     let manifest = CliManifest::new(
         codebase_root,
         collection_name.as_str(),
@@ -1234,7 +1942,7 @@ fn ensure_manifest(
     Ok(manifest)
 }
 
-fn resolve_config_path(config_path: Option<&Path>, codebase_root: &Path) -> Option<PathBuf> {
+pub fn resolve_config_path(config_path: Option<&Path>, codebase_root: &Path) -> Option<PathBuf> {
     config_path.map_or_else(
         || {
             let default_path = context_config_path(codebase_root);
@@ -1274,7 +1982,7 @@ fn normalize_root(path: &Path) -> PathBuf {
     std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
 }
 
-fn vector_snapshot_status(
+pub fn vector_snapshot_status(
     codebase_root: &Path,
     manifest: &CliManifest,
 ) -> InfraResult<SnapshotStatus> {
@@ -1292,14 +2000,14 @@ fn vector_snapshot_status(
         });
     };
 
-    if !path.is_file() {
+    let Some(path) = existing_vector_snapshot_file(&path) else {
         return Ok(SnapshotStatus {
             path: Some(path),
             exists: false,
             updated_at_ms: None,
             record_count: None,
         });
-    }
+    };
 
     Ok(SnapshotStatus {
         path: Some(path.clone()),
@@ -1309,6 +2017,17 @@ fn vector_snapshot_status(
     })
 }
 
+/// Resolve the on-disk v1 snapshot, falling back to its gzip sibling.
+fn existing_vector_snapshot_file(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let mut gzip_path = path.as_os_str().to_owned();
+    gzip_path.push(SNAPSHOT_GZIP_SUFFIX);
+    let gzip_path = PathBuf::from(gzip_path);
+    gzip_path.is_file().then_some(gzip_path)
+}
+
 fn sync_snapshot_status(
     codebase_root: &Path,
     storage_mode: &SnapshotStorageMode,
@@ -1367,21 +2086,137 @@ fn sync_snapshot_path(codebase_root: &Path, storage_mode: &SnapshotStorageMode)
     )
 }
 
-fn read_vector_record_count(path: &Path) -> InfraResult<usize> {
+/// Document count and dimension recorded by the on-disk vector snapshot.
+#[derive(Debug, Clone, Copy, Default)]
+struct VectorSnapshotSummary {
+    count: Option<u64>,
+    dimension: Option<u32>,
+}
+
+/// Read the vector snapshot summary across every collection the index
+/// occupies; shard counts are summed and the first recorded dimension wins.
+fn read_vector_snapshot_summary(
+    codebase_root: &Path,
+    manifest: &CliManifest,
+) -> InfraResult<VectorSnapshotSummary> {
+    let mut summary = VectorSnapshotSummary::default();
+    for collection_name in index_collection_names(&manifest.collection_name, manifest.shard_count)?
+    {
+        let shard = read_collection_snapshot_summary(
+            codebase_root,
+            &collection_name,
+            &manifest.snapshot_storage,
+        )?;
+        summary.count = match (summary.count, shard.count) {
+            (Some(total), Some(count)) => Some(total.saturating_add(count)),
+            (total, count) => total.or(count),
+        };
+        summary.dimension = summary.dimension.or(shard.dimension);
+    }
+    Ok(summary)
+}
+
+/// Read one collection's snapshot summary, preferring the v2 records sidecar header.
+fn read_collection_snapshot_summary(
+    codebase_root: &Path,
+    collection_name: &CollectionName,
+    snapshot_storage: &SnapshotStorageMode,
+) -> InfraResult<VectorSnapshotSummary> {
+    let Some(v1_path) = vector_snapshot_path(codebase_root, collection_name, snapshot_storage)
+    else {
+        return Ok(VectorSnapshotSummary::default());
+    };
+
+    let v2_records_meta = v1_path
+        .with_file_name(format!(
+            "{}{VECTOR_SNAPSHOT_V2_DIR_SUFFIX}",
+            collection_name.as_str()
+        ))
+        .join(VECTOR_SNAPSHOT_V2_RECORDS_META_FILE);
+    if v2_records_meta.is_file() {
+        let contents = std::fs::read_to_string(&v2_records_meta)?;
+        let header = contents.lines().next().unwrap_or_default();
+        let value = parse_snapshot_json(header, "vector snapshot header")?;
+        return Ok(VectorSnapshotSummary {
+            count: value.get("count").and_then(Value::as_u64),
+            dimension: json_u32(&value, "dimension"),
+        });
+    }
+
+    if let Some(value) = read_vector_snapshot_json(&v1_path)? {
+        return Ok(VectorSnapshotSummary {
+            count: value
+                .get("records")
+                .and_then(Value::as_array)
+                .map(|records| records.len() as u64),
+            dimension: json_u32(&value, "dimension"),
+        });
+    }
+
+    Ok(VectorSnapshotSummary::default())
+}
+
+/// Read the relative paths tracked by the sync snapshot, if it exists.
+fn read_sync_tracked_paths(path: &Path) -> InfraResult<Option<BTreeSet<Box<str>>>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
     let contents = std::fs::read_to_string(path)?;
-    let value: Value = serde_json::from_str(&contents).map_err(|error| {
+    let value = parse_snapshot_json(&contents, "sync snapshot")?;
+    let paths = value
+        .get("fileHashes")
+        .and_then(Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| entry.get(0).and_then(Value::as_str))
+                .map(Box::<str>::from)
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(Some(paths))
+}
+
+fn parse_snapshot_json(contents: &str, label: &str) -> InfraResult<Value> {
+    serde_json::from_str(contents).map_err(|error| {
         ErrorEnvelope::unexpected(
             ErrorCode::invalid_input(),
-            format!("vector snapshot parse failed: {error}"),
+            format!("{label} parse failed: {error}"),
             ErrorClass::NonRetriable,
         )
-    })?;
+    })
+}
+
+fn json_u32(value: &Value, key: &str) -> Option<u32> {
+    value
+        .get(key)
+        .and_then(Value::as_u64)
+        .and_then(|raw| u32::try_from(raw).ok())
+}
+
+fn read_vector_record_count(path: &Path) -> InfraResult<usize> {
+    let value = read_vector_snapshot_json(path)?.unwrap_or_default();
     Ok(value
         .get("records")
         .and_then(|records| records.as_array())
         .map_or(0, Vec::len))
 }
 
+/// Read a v1 vector snapshot, transparently handling gzip-compressed files.
+fn read_vector_snapshot_json(path: &Path) -> InfraResult<Option<Value>> {
+    read_v1_snapshot_payload(path)?
+        .map(|payload| {
+            serde_json::from_slice(&payload).map_err(|error| {
+                ErrorEnvelope::unexpected(
+                    ErrorCode::invalid_input(),
+                    format!("vector snapshot parse failed: {error}"),
+                    ErrorClass::NonRetriable,
+                )
+            })
+        })
+        .transpose()
+}
+
 fn file_mtime_ms(path: &Path) -> InfraResult<u64> {
     let metadata = std::fs::metadata(path)?;
     let modified = metadata.modified().map_err(InfraError::from)?;
@@ -1459,6 +2294,9 @@ const LOG_FORMAT_ENV: &str = "SCA_LOG_FORMAT";
 const LOG_LEVEL_ENV: &str = "SCA_LOG_LEVEL";
 const TELEMETRY_FORMAT_ENV: &str = "SCA_TELEMETRY_FORMAT";
 const TRACE_SAMPLE_RATE_ENV: &str = "SCA_TRACE_SAMPLE_RATE";
+const OTEL_ENDPOINT_ENV: &str = "SCA_OTEL_ENDPOINT";
+#[cfg(feature = "otlp")]
+const OTEL_SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
 
 fn observability_from_env() -> Observability {
     let log_enabled = env_is_json(LOG_FORMAT_ENV);
@@ -1471,10 +2309,12 @@ fn observability_from_env() -> Observability {
         "resolved local observability flags from environment"
     );
 
+    let otel_telemetry = otel_telemetry_from_env();
+
     if !log_enabled && !telemetry_enabled {
         return Observability {
             logger: None,
-            telemetry: None,
+            telemetry: otel_telemetry,
         };
     }
 
@@ -1488,7 +2328,9 @@ fn observability_from_env() -> Observability {
     } else {
         None
     };
-    let telemetry: Option<Arc<dyn TelemetryPort>> = if telemetry_enabled {
+    let telemetry: Option<Arc<dyn TelemetryPort>> = if otel_telemetry.is_some() {
+        otel_telemetry
+    } else if telemetry_enabled {
         Some(Arc::new(
             JsonTelemetry::new(Arc::clone(&sink)).with_span_sample_rate(sample_rate),
         ))
@@ -1506,6 +2348,38 @@ fn observability_from_env() -> Observability {
     Observability { logger, telemetry }
 }
 
+/// Build OpenTelemetry span telemetry exporting to `SCA_OTEL_ENDPOINT`, if set.
+#[cfg(feature = "otlp")]
+fn otel_telemetry_from_env() -> Option<Arc<dyn TelemetryPort>> {
+    let endpoint = std::env::var(OTEL_ENDPOINT_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())?;
+    let service_name = std::env::var(OTEL_SERVICE_NAME_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| semantic_code_adapters::DEFAULT_OTEL_SERVICE_NAME.to_owned());
+    match semantic_code_adapters::OtlpHttpExporter::new(endpoint.trim(), &service_name) {
+        Ok(exporter) => Some(Arc::new(
+            semantic_code_adapters::OtelTelemetry::new(Arc::new(exporter))
+                .with_service_name(service_name),
+        )),
+        Err(error) => {
+            tracing::warn!(error = %error, "failed to start OTLP exporter; OTel spans disabled");
+            None
+        },
+    }
+}
+
+#[cfg(not(feature = "otlp"))]
+fn otel_telemetry_from_env() -> Option<Arc<dyn TelemetryPort>> {
+    if std::env::var_os(OTEL_ENDPOINT_ENV).is_some() {
+        tracing::warn!(
+            "{OTEL_ENDPOINT_ENV} is set but this build lacks the `otlp` feature; OTel export disabled"
+        );
+    }
+    None
+}
+
 fn env_is_json(key: &str) -> bool {
     std::env::var(key)
         .ok()
@@ -1513,10 +2387,14 @@ fn env_is_json(key: &str) -> bool {
 }
 
 fn parse_log_level() -> LogLevel {
+    if let Some(level) = crate::request_attributes::installed_log_level() {
+        return level;
+    }
     let value = std::env::var(LOG_LEVEL_ENV)
         .ok()
         .map(|value| value.to_ascii_lowercase());
     match value.as_deref() {
+        Some("trace") => LogLevel::Trace,
         Some("debug") => LogLevel::Debug,
         Some("warn") => LogLevel::Warn,
         Some("error") => LogLevel::Error,
@@ -1538,6 +2416,9 @@ fn scope_logger(
 ) -> Option<Arc<dyn LoggerPort>> {
     let logger = logger?;
     let mut fields = LogFields::new();
+    for (key, value) in ctx.attributes() {
+        fields.insert(key.clone().into_boxed_str(), Value::String(value.clone()));
+    }
     fields.insert(
         "correlationId".to_owned().into_boxed_str(),
         Value::String(ctx.correlation_id().as_str().to_string()),
@@ -1545,12 +2426,15 @@ fn scope_logger(
     Some(Arc::from(logger.child(fields)))
 }
 
-fn scope_telemetry(
+pub fn scope_telemetry(
     telemetry: Option<&Arc<dyn TelemetryPort>>,
     ctx: &RequestContext,
 ) -> Option<Arc<dyn TelemetryPort>> {
     let telemetry = telemetry?;
     let mut tags = TelemetryTags::new();
+    for (key, value) in ctx.attributes() {
+        tags.insert(key.clone().into_boxed_str(), value.clone().into_boxed_str());
+    }
     tags.insert(
         "correlationId".to_owned().into_boxed_str(),
         ctx.correlation_id().as_str().to_string().into_boxed_str(),
@@ -1620,3 +2504,148 @@ async fn finalize_cancel_watcher(handle: Option<tokio::task::JoinHandle<()>>) ->
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CliIndexState, read_status_local, run_index_local, run_init_local};
+    use crate::InfraResult;
+    use crate::cli_manifest::{mark_manifest_indexed, touch_manifest_with_interval};
+    use semantic_code_config::{
+        ConfigTemplate, IndexRequestDto, SnapshotStorageMode, load_backend_config_from_path,
+        validate_index_request,
+    };
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(prefix: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        std::env::temp_dir().join(format!("{prefix}-{nanos}"))
+    }
+
+    #[test]
+    fn init_template_writes_commented_starter_config() -> InfraResult<()> {
+        let temp = temp_dir("cli-init-template");
+        std::fs::create_dir_all(&temp)?;
+        let status = run_init_local(
+            None,
+            &temp,
+            Some(SnapshotStorageMode::Disabled),
+            Some(ConfigTemplate::MilvusOpenAi),
+            false,
+        )?;
+        assert!(status.created_config);
+
+        let written = std::fs::read_to_string(&status.config_path)?;
+        assert!(written.contains("# Set OPENAI_API_KEY"));
+        let config =
+            load_backend_config_from_path(Some(&status.config_path), None, &BTreeMap::new())?;
+        assert_eq!(config.vector_db.provider.as_deref(), Some("milvus"));
+        assert_eq!(
+            config.vector_db.snapshot_storage,
+            SnapshotStorageMode::Disabled
+        );
+
+        std::fs::remove_dir_all(&temp)?;
+        Ok(())
+    }
+
+    #[test]
+    fn status_distinguishes_not_indexed_from_empty_index() -> InfraResult<()> {
+        let temp = temp_dir("cli-status-state");
+        std::fs::create_dir_all(&temp)?;
+        run_init_local(None, &temp, None, None, false)?;
+
+        let status = read_status_local(None, None, &temp)?;
+        assert_eq!(status.index_state, CliIndexState::NotIndexed);
+
+        // A plain touch is not an index run.
+        touch_manifest_with_interval(&temp, &status.manifest, 0)?;
+        let status = read_status_local(None, None, &temp)?;
+        assert_eq!(status.index_state, CliIndexState::NotIndexed);
+
+        mark_manifest_indexed(&temp, &status.manifest)?;
+        let status = read_status_local(None, None, &temp)?;
+        assert_eq!(status.index_state, CliIndexState::Empty);
+
+        std::fs::remove_dir_all(&temp)?;
+        Ok(())
+    }
+
+    /// Write the same tree in a different creation order to defeat any reliance
+    /// on directory iteration order.
+    fn write_tree(root: &Path, reversed: bool) -> InfraResult<()> {
+        let mut files: Vec<(String, String)> = (0..24)
+            .map(|index| {
+                let dir = match index % 3 {
+                    0 => "src",
+                    1 => "src/nested",
+                    _ => "lib",
+                };
+                (
+                    format!("{dir}/file_{index:02}.rs"),
+                    format!("fn item_{index}() -> u32 {{\n    {index}\n}}\n"),
+                )
+            })
+            .collect();
+        if reversed {
+            files.reverse();
+        }
+        for (relative, content) in files {
+            let path = root.join(relative);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    fn index_tree(root: &Path) -> InfraResult<BTreeMap<String, Vec<u8>>> {
+        let overrides = r#"{"core":{"maxInFlightFiles":4},"embedding":{"provider":"test","dimension":8,"batchSize":3},"vectorDb":{"provider":"local"}}"#;
+        let request = validate_index_request(&IndexRequestDto {
+            codebase_root: root.to_string_lossy().to_string(),
+            collection_name: None,
+            force_reindex: None,
+        })?;
+        run_index_local(None, Some(overrides), &request, true)?;
+
+        let collections = root.join(".context/vector/collections");
+        let mut snapshot = BTreeMap::new();
+        for entry in std::fs::read_dir(collections)? {
+            let bundle = entry?.path();
+            if bundle.extension().is_none_or(|extension| extension != "v2") {
+                continue;
+            }
+            for file in std::fs::read_dir(bundle)? {
+                let file = file?;
+                snapshot.insert(
+                    file.file_name().to_string_lossy().to_string(),
+                    std::fs::read(file.path())?,
+                );
+            }
+        }
+        Ok(snapshot)
+    }
+
+    #[test]
+    fn indexing_the_same_tree_twice_produces_identical_snapshots() -> InfraResult<()> {
+        let first = temp_dir("cli-reproducible-a");
+        let second = temp_dir("cli-reproducible-b");
+        write_tree(&first, false)?;
+        write_tree(&second, true)?;
+
+        let first_snapshot = index_tree(&first);
+        let second_snapshot = index_tree(&second);
+        std::fs::remove_dir_all(&first)?;
+        std::fs::remove_dir_all(&second)?;
+        let (first_snapshot, second_snapshot) = (first_snapshot?, second_snapshot?);
+
+        assert!(first_snapshot.contains_key("ids.json"));
+        assert_eq!(first_snapshot, second_snapshot);
+        Ok(())
+    }
+}
@@ -5,7 +5,11 @@ use semantic_code_config::{BackendConfig, SnapshotStorageMode, to_pretty_toml};
 use semantic_code_domain::{CollectionName, IndexMode};
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope};
 use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
 use std::io;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -13,6 +17,17 @@ const CLI_MANIFEST_VERSION: u32 = 1;
 const CONTEXT_DIR_NAME: &str = ".context";
 const MANIFEST_FILE_NAME: &str = "manifest.json";
 const CONFIG_FILE_NAME: &str = "config.toml";
+#[cfg(unix)]
+const PRIVATE_FILE_MODE: u32 = 0o600;
+
+/// Permissions applied to files written through [`write_atomic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileAccess {
+    /// Owner read/write only; used for files that may carry secrets.
+    Private,
+    /// Keep the permissions of the file being replaced (user-owned files).
+    PreserveExisting,
+}
 
 /// Manifest persisted for local CLI operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +47,16 @@ pub struct CliManifest {
     pub created_at_ms: u64,
     /// Manifest last updated timestamp (milliseconds since epoch).
     pub updated_at_ms: u64,
+    /// Timestamp of the last persisted touch (milliseconds since epoch, 0 if never).
+    #[serde(default)]
+    pub last_touched_ms: u64,
+    /// Shard count used by the last index run; `None` for an unsharded index.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_count: Option<u32>,
+    /// Completion time of the last index, reindex, or import run (milliseconds
+    /// since epoch); `None` until the codebase is indexed and after a clear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at_ms: Option<u64>,
 }
 
 impl CliManifest {
@@ -55,6 +80,9 @@ impl CliManifest {
             snapshot_storage,
             created_at_ms: now_ms,
             updated_at_ms: now_ms,
+            last_touched_ms: 0,
+            shard_count: None,
+            indexed_at_ms: None,
         })
     }
 }
@@ -90,8 +118,11 @@ pub fn write_manifest(root: &Path, manifest: &CliManifest) -> InfraResult<()> {
         )
     })?;
     payload.push('\n');
-    std::fs::write(context_dir.join(MANIFEST_FILE_NAME), payload)?;
-    Ok(())
+    write_atomic(
+        &context_dir.join(MANIFEST_FILE_NAME),
+        payload.as_bytes(),
+        FileAccess::Private,
+    )
 }
 
 /// Write the default config to `.context/config.toml` if it does not exist.
@@ -103,14 +134,47 @@ pub fn ensure_default_config(root: &Path) -> InfraResult<()> {
     let context_dir = context_dir(root);
     std::fs::create_dir_all(&context_dir)?;
     let payload = to_pretty_toml(&BackendConfig::default())?;
-    std::fs::write(path, payload)?;
-    Ok(())
+    // Config files may later hold API keys or vector DB credentials.
+    write_atomic(&path, payload.as_bytes(), FileAccess::Private)
 }
 
-/// Update the manifest updated timestamp and persist it.
-pub fn touch_manifest(root: &Path, manifest: &CliManifest) -> InfraResult<CliManifest> {
+/// Update the manifest timestamps and persist them, unless the last touch
+/// happened less than `interval_ms` ago.
+///
+/// When the write is skipped the manifest is returned unchanged. Read-only
+/// commands never touch the manifest, and index runs use
+/// [`mark_manifest_indexed`], which always writes.
+#[cfg_attr(
+    not(test),
+    expect(dead_code, reason = "no command debounces manifest touches yet")
+)]
+pub fn touch_manifest_with_interval(
+    root: &Path,
+    manifest: &CliManifest,
+    interval_ms: u64,
+) -> InfraResult<CliManifest> {
+    let now_ms = now_epoch_ms()?;
+    if now_ms.saturating_sub(manifest.last_touched_ms) < interval_ms {
+        return Ok(manifest.clone());
+    }
     let mut updated = manifest.clone();
-    updated.updated_at_ms = now_epoch_ms()?;
+    updated.updated_at_ms = now_ms;
+    updated.last_touched_ms = now_ms;
+    write_manifest(root, &updated)?;
+    Ok(updated)
+}
+
+/// Record a finished index, reindex, or import run and persist the manifest.
+///
+/// Unlike [`touch_manifest_with_interval`], this always writes.
+pub fn mark_manifest_indexed(root: &Path, manifest: &CliManifest) -> InfraResult<CliManifest> {
+    let now_ms = now_epoch_ms()?;
+    let updated = CliManifest {
+        updated_at_ms: now_ms,
+        last_touched_ms: now_ms,
+        indexed_at_ms: Some(now_ms),
+        ..manifest.clone()
+    };
     write_manifest(root, &updated)?;
     Ok(updated)
 }
@@ -132,8 +196,7 @@ pub fn append_context_gitignore(root: &Path) -> InfraResult<()> {
         updated.push('\n');
     }
     updated.push_str(".context/\n");
-    std::fs::write(path, updated)?;
-    Ok(())
+    write_atomic(&path, updated.as_bytes(), FileAccess::PreserveExisting)
 }
 
 fn context_dir(root: &Path) -> PathBuf {
@@ -150,6 +213,93 @@ pub fn config_path(root: &Path) -> PathBuf {
     context_dir(root).join(CONFIG_FILE_NAME)
 }
 
+/// Replace `path` with `payload` via a sibling temp file and rename, so readers
+/// never observe a partially written file.
+fn write_atomic(path: &Path, payload: &[u8], access: FileAccess) -> InfraResult<()> {
+    let temp_path = write_temp_sibling(path, payload, access)?;
+    if let Err(error) = std::fs::rename(&temp_path, path) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(InfraError::from(error));
+    }
+    Ok(())
+}
+
+/// Write `payload` to a fresh temp file next to `path` and return its path.
+fn write_temp_sibling(path: &Path, payload: &[u8], access: FileAccess) -> InfraResult<PathBuf> {
+    let parent = path.parent().ok_or_else(|| {
+        ErrorEnvelope::unexpected(
+            ErrorCode::internal(),
+            "atomic write path has no parent directory",
+            ErrorClass::NonRetriable,
+        )
+    })?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                "atomic write path has no file name",
+                ErrorClass::NonRetriable,
+            )
+        })?
+        .to_string_lossy()
+        .to_string();
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    for attempt in 0..32u32 {
+        let candidate = parent.join(format!(
+            ".{file_name}.tmp-{}-{nonce}-{attempt}",
+            std::process::id()
+        ));
+        let mut options = OpenOptions::new();
+        options.create_new(true).write(true);
+        #[cfg(unix)]
+        {
+            if access == FileAccess::Private {
+                options.mode(PRIVATE_FILE_MODE);
+            }
+        }
+        match options.open(&candidate) {
+            Ok(mut file) => {
+                if let Err(error) = fill_temp_file(&mut file, path, payload, access) {
+                    let _ = std::fs::remove_file(&candidate);
+                    return Err(InfraError::from(error));
+                }
+                return Ok(candidate);
+            },
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {},
+            Err(error) => return Err(InfraError::from(error)),
+        }
+    }
+
+    Err(ErrorEnvelope::unexpected(
+        ErrorCode::internal(),
+        "failed to create temporary file for atomic write",
+        ErrorClass::NonRetriable,
+    ))
+}
+
+fn fill_temp_file(
+    file: &mut std::fs::File,
+    target: &Path,
+    payload: &[u8],
+    access: FileAccess,
+) -> io::Result<()> {
+    file.write_all(payload)?;
+    file.sync_all()?;
+    if access == FileAccess::PreserveExisting {
+        match std::fs::metadata(target) {
+            Ok(metadata) => file.set_permissions(metadata.permissions())?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {},
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
 fn now_epoch_ms() -> InfraResult<u64> {
     let duration = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -172,8 +322,9 @@ fn now_epoch_ms() -> InfraResult<u64> {
 #[cfg(test)]
 mod tests {
     use super::{
-        CliManifest, append_context_gitignore, config_path, ensure_default_config, read_manifest,
-        write_manifest,
+        CliManifest, FileAccess, append_context_gitignore, config_path, ensure_default_config,
+        manifest_path, mark_manifest_indexed, read_manifest, touch_manifest_with_interval,
+        write_manifest, write_temp_sibling,
     };
     use crate::InfraResult;
     use semantic_code_config::SnapshotStorageMode;
@@ -212,4 +363,110 @@ mod tests {
         assert!(config_path(&temp).is_file());
         Ok(())
     }
+
+    #[test]
+    fn interrupted_write_leaves_original_manifest_intact() -> InfraResult<()> {
+        let temp = temp_dir("cli-manifest-interrupted");
+        std::fs::create_dir_all(&temp)?;
+        let manifest = CliManifest::new(
+            &temp,
+            "code_chunks_local",
+            IndexMode::Dense,
+            SnapshotStorageMode::Project,
+        )?;
+        write_manifest(&temp, &manifest)?;
+        let path = manifest_path(&temp);
+        let original = std::fs::read_to_string(&path)?;
+
+        // Simulate a crash between writing the temp file and renaming it.
+        let staged = write_temp_sibling(&path, b"{ \"truncated", FileAccess::Private)?;
+
+        assert_eq!(std::fs::read_to_string(&path)?, original);
+        let loaded = read_manifest(&temp)?.expect("manifest");
+        assert_eq!(loaded.collection_name.as_str(), "code_chunks_local");
+        assert_ne!(staged, path);
+        Ok(())
+    }
+
+    #[test]
+    fn touches_within_interval_write_once() -> InfraResult<()> {
+        let temp = temp_dir("cli-manifest-touch");
+        std::fs::create_dir_all(&temp)?;
+        let manifest = CliManifest::new(
+            &temp,
+            "code_chunks_local",
+            IndexMode::Dense,
+            SnapshotStorageMode::Project,
+        )?;
+
+        let first = touch_manifest_with_interval(&temp, &manifest, 60_000)?;
+        assert!(first.last_touched_ms > 0);
+        let path = manifest_path(&temp);
+        let after_first = std::fs::read_to_string(&path)?;
+
+        // Remove the file so any second write would be observable.
+        std::fs::remove_file(&path)?;
+        let second = touch_manifest_with_interval(&temp, &first, 60_000)?;
+
+        assert!(!path.exists());
+        assert_eq!(second.last_touched_ms, first.last_touched_ms);
+        assert_eq!(second.updated_at_ms, first.updated_at_ms);
+
+        std::fs::write(&path, &after_first)?;
+        let third = touch_manifest_with_interval(&temp, &second, 0)?;
+        assert!(third.last_touched_ms >= first.last_touched_ms);
+        let loaded = read_manifest(&temp)?.expect("manifest");
+        assert_eq!(loaded.last_touched_ms, third.last_touched_ms);
+        Ok(())
+    }
+
+    #[test]
+    fn index_runs_always_write_the_manifest() -> InfraResult<()> {
+        let temp = temp_dir("cli-manifest-indexed");
+        std::fs::create_dir_all(&temp)?;
+        let manifest = CliManifest::new(
+            &temp,
+            "code_chunks_local",
+            IndexMode::Dense,
+            SnapshotStorageMode::Project,
+        )?;
+
+        let first = mark_manifest_indexed(&temp, &manifest)?;
+        let path = manifest_path(&temp);
+        std::fs::remove_file(&path)?;
+        let second = mark_manifest_indexed(&temp, &first)?;
+
+        assert!(path.exists());
+        assert!(second.last_touched_ms >= first.last_touched_ms);
+        assert_eq!(second.indexed_at_ms, Some(second.last_touched_ms));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn config_and_manifest_are_owner_only() -> InfraResult<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = temp_dir("cli-manifest-perms");
+        std::fs::create_dir_all(&temp)?;
+        let manifest = CliManifest::new(
+            &temp,
+            "code_chunks_local",
+            IndexMode::Dense,
+            SnapshotStorageMode::Project,
+        )?;
+        write_manifest(&temp, &manifest)?;
+        ensure_default_config(&temp)?;
+
+        for path in [manifest_path(&temp), config_path(&temp)] {
+            let mode = std::fs::metadata(&path)?.permissions().mode();
+            assert_eq!(
+                mode & 0o777,
+                0o600,
+                "unexpected mode for {}",
+                path.display()
+            );
+        }
+        Ok(())
+    }
 }
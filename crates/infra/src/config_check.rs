@@ -1,7 +1,10 @@
 //! Config loading helpers for CLI surfaces.
 
 use crate::InfraResult;
-use semantic_code_config::{load_backend_config_from_path, to_pretty_json};
+use semantic_code_config::{
+    ConfigFieldChange, ConfigLimits, ConfigWarning, diff_config, load_backend_config_from_path,
+    load_backend_config_from_path_with_report, to_pretty_json,
+};
 use std::collections::BTreeMap;
 use std::path::Path;
 
@@ -14,3 +17,42 @@ pub fn load_effective_config_json(
     let config = load_backend_config_from_path(config_path, overrides_json, env)?;
     to_pretty_json(&config)
 }
+
+/// Load and validate the effective config, also returning non-fatal warnings.
+///
+/// Env overrides that shadow a config file value are reported as warnings too.
+pub fn load_effective_config_with_warnings(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> InfraResult<(String, Vec<ConfigWarning>)> {
+    let (config, shadows) =
+        load_backend_config_from_path_with_report(config_path, overrides_json, env)?;
+    let mut warnings = config.warnings();
+    warnings.extend(shadows.into_iter().map(ConfigWarning::from));
+    Ok((to_pretty_json(&config)?, warnings))
+}
+
+/// List the effective config fields that `overrides_json` changes.
+///
+/// The baseline is the effective config (file + env) loaded without overrides;
+/// secret values are redacted.
+pub fn load_effective_config_diff(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> InfraResult<Vec<ConfigFieldChange>> {
+    let base = load_backend_config_from_path(config_path, None, env)?;
+    let effective = load_backend_config_from_path(config_path, overrides_json, env)?;
+    Ok(diff_config(&base, &effective))
+}
+
+/// Load and validate the effective config, returning its resolved numeric limits.
+pub fn load_effective_limits(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+) -> InfraResult<ConfigLimits> {
+    let config = load_backend_config_from_path(config_path, overrides_json, env)?;
+    Ok(*config.limits())
+}
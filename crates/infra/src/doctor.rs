@@ -0,0 +1,379 @@
+//! Setup diagnostics for local CLI surfaces.
+//!
+//! Runs a fixed sequence of checks (env, config, embedding, vector DB,
+//! snapshot) by composing the same helpers the real commands use, and reports
+//! each as pass/warn/fail with a remediation hint. Checks never abort the run;
+//! a check whose prerequisites failed is reported as skipped.
+
+use crate::cli_local::{resolve_config_path, vector_snapshot_status};
+use crate::cli_manifest::read_manifest;
+use crate::embedding_factory::build_embedding_port_with_telemetry;
+use crate::request_attributes::new_seeded_request_context;
+use crate::vectordb_factory::build_vectordb_port;
+use crate::{InfraError, InfraResult, validate_env_parsing};
+use semantic_code_config::{
+    RuntimeEnv, ValidatedBackendConfig, load_backend_config_from_path, load_backend_config_std_env,
+    load_runtime_env_from_map, load_runtime_env_std_env,
+};
+use semantic_code_domain::{CollectionNamingInput, derive_collection_name};
+use semantic_code_shared::ErrorEnvelope;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Outcome of a single doctor check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorStatus {
+    /// The check succeeded.
+    Pass,
+    /// The check found something worth attention that does not block usage.
+    Warn,
+    /// The check failed; commands depending on it will not work.
+    Fail,
+}
+
+impl DoctorStatus {
+    /// Stable status label.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+/// One diagnostic check result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    /// Stable check name (`env`, `config`, `embedding`, `vectorDb`, `snapshot`).
+    pub name: Box<str>,
+    /// Check outcome.
+    pub status: DoctorStatus,
+    /// What was observed.
+    pub message: Box<str>,
+    /// Suggested remediation when the check did not pass.
+    pub hint: Option<Box<str>>,
+}
+
+impl DoctorCheck {
+    fn pass(name: &str, message: impl Into<String>) -> Self {
+        Self::new(name, DoctorStatus::Pass, message, None)
+    }
+
+    fn warn(name: &str, message: impl Into<String>, hint: &str) -> Self {
+        Self::new(name, DoctorStatus::Warn, message, Some(hint))
+    }
+
+    fn fail(name: &str, error: &ErrorEnvelope, hint: &str) -> Self {
+        Self::new(name, DoctorStatus::Fail, error.message.clone(), Some(hint))
+    }
+
+    fn skipped(name: &str, prerequisite: &str) -> Self {
+        Self::new(
+            name,
+            DoctorStatus::Warn,
+            format!("skipped: {prerequisite} check failed"),
+            Some("fix the failing checks above and rerun `sca doctor`"),
+        )
+    }
+
+    fn new(
+        name: &str,
+        status: DoctorStatus,
+        message: impl Into<String>,
+        hint: Option<&str>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            message: message.into().into_boxed_str(),
+            hint: hint.map(Into::into),
+        }
+    }
+}
+
+/// Ordered doctor results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorReport {
+    /// Check results in execution order.
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Returns true when any check failed.
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == DoctorStatus::Fail)
+    }
+
+    /// Find a check by name.
+    #[must_use]
+    pub fn check(&self, name: &str) -> Option<&DoctorCheck> {
+        self.checks.iter().find(|check| check.name.as_ref() == name)
+    }
+}
+
+/// Where doctor reads environment overrides from.
+enum EnvSource<'a> {
+    Process,
+    #[cfg_attr(
+        not(test),
+        expect(dead_code, reason = "explicit env maps are only injected by tests")
+    )]
+    Map(&'a BTreeMap<String, String>),
+}
+
+impl EnvSource<'_> {
+    fn validate(&self) -> InfraResult<RuntimeEnv> {
+        match self {
+            Self::Process => load_runtime_env_std_env(),
+            Self::Map(env) => {
+                validate_env_parsing(env)?;
+                load_runtime_env_from_map(env)
+            },
+        }
+    }
+
+    fn load_config(
+        &self,
+        config_path: Option<&Path>,
+        overrides_json: Option<&str>,
+    ) -> InfraResult<ValidatedBackendConfig> {
+        match self {
+            Self::Process => load_backend_config_std_env(config_path, overrides_json),
+            Self::Map(env) => load_backend_config_from_path(config_path, overrides_json, env),
+        }
+    }
+}
+
+/// Diagnose the local setup using the process environment.
+#[tracing::instrument(
+    name = "infra.run_doctor_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn run_doctor_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> DoctorReport {
+    run_doctor(
+        config_path,
+        overrides_json,
+        codebase_root,
+        &EnvSource::Process,
+    )
+}
+
+fn run_doctor(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    env_source: &EnvSource<'_>,
+) -> DoctorReport {
+    let mut checks = Vec::new();
+
+    let runtime_env = match env_source.validate() {
+        Ok(env) => {
+            checks.push(DoctorCheck::pass("env", "environment overrides parsed"));
+            Some(env)
+        },
+        Err(error) => {
+            checks.push(DoctorCheck::fail(
+                "env",
+                &error,
+                "fix or unset the SCA_* / provider environment variables named above",
+            ));
+            None
+        },
+    };
+
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = match env_source.load_config(config_path.as_deref(), overrides_json) {
+        Ok(config) => {
+            let source = config_path.as_deref().map_or_else(
+                || "defaults (no config file found)".to_string(),
+                |path| path.display().to_string(),
+            );
+            checks.push(DoctorCheck::pass("config", format!("loaded {source}")));
+            Some(config)
+        },
+        Err(error) => {
+            checks.push(DoctorCheck::fail(
+                "config",
+                &error,
+                "run `sca config validate` to locate the problem, or `sca init` to write a fresh config",
+            ));
+            None
+        },
+    };
+
+    let (Some(config), Some(runtime_env)) = (config, runtime_env) else {
+        for name in ["embedding", "vectorDb", "snapshot"] {
+            checks.push(DoctorCheck::skipped(name, "env/config"));
+        }
+        return DoctorReport { checks };
+    };
+
+    checks.push(check_embedding(&config, &runtime_env, codebase_root));
+    checks.push(check_vectordb(&config, codebase_root));
+    checks.push(check_snapshot(codebase_root));
+    DoctorReport { checks }
+}
+
+fn check_embedding(
+    config: &ValidatedBackendConfig,
+    env: &RuntimeEnv,
+    codebase_root: &Path,
+) -> DoctorCheck {
+    match build_embedding_port_with_telemetry(config, env, codebase_root, None) {
+        Ok(port) => DoctorCheck::pass(
+            "embedding",
+            format!("provider {} ready", port.provider().id.as_str()),
+        ),
+        Err(error) => DoctorCheck::fail(
+            "embedding",
+            &error,
+            "set `embedding.provider` and its API key (e.g. OPENAI_API_KEY), or place ONNX assets under .context/models/onnx",
+        ),
+    }
+}
+
+fn check_vectordb(config: &ValidatedBackendConfig, codebase_root: &Path) -> DoctorCheck {
+    match probe_vectordb(config, codebase_root) {
+        Ok(provider) => DoctorCheck::pass("vectorDb", format!("provider {provider} reachable")),
+        Err(error) => DoctorCheck::fail(
+            "vectorDb",
+            &error,
+            "check `vectorDb.provider`, `vectorDb.address`/`baseUrl`, and that the server is running",
+        ),
+    }
+}
+
+/// Build the vector DB port and issue one `has_collection` round-trip.
+fn probe_vectordb(config: &ValidatedBackendConfig, codebase_root: &Path) -> InfraResult<Box<str>> {
+    let (collection_name, snapshot_storage) = match read_manifest(codebase_root)? {
+        Some(manifest) => (manifest.collection_name, manifest.snapshot_storage),
+        None => (
+            derive_collection_name(
+                &CollectionNamingInput::new(
+                    codebase_root.to_path_buf(),
+                    config.vector_db.index_mode,
+                )
+                .with_template(config.vector_db.collection_template.clone()),
+            )
+            .map_err(ErrorEnvelope::from)?,
+            config.vector_db.snapshot_storage.clone(),
+        ),
+    };
+    let ctx = new_seeded_request_context(config.core.seed);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    runtime.block_on(async {
+        let vectordb = build_vectordb_port(config, codebase_root, snapshot_storage).await?;
+        vectordb.has_collection(&ctx, collection_name).await?;
+        Ok::<_, InfraError>(vectordb.provider().id.as_str().into())
+    })
+}
+
+fn check_snapshot(codebase_root: &Path) -> DoctorCheck {
+    let manifest = match read_manifest(codebase_root) {
+        Ok(Some(manifest)) => manifest,
+        Ok(None) => {
+            return DoctorCheck::warn(
+                "snapshot",
+                "no index manifest found",
+                "run `sca index --init` to build the first index",
+            );
+        },
+        Err(error) => {
+            return DoctorCheck::fail(
+                "snapshot",
+                &error,
+                "remove .context/manifest.json and rerun `sca index --init`",
+            );
+        },
+    };
+    let status = vector_snapshot_status(codebase_root, &manifest).and_then(|status| {
+        if let Some(path) = status.path.as_deref().filter(|_| status.exists) {
+            std::fs::File::open(path)?;
+        }
+        Ok(status)
+    });
+    match status {
+        Ok(status) if status.exists => DoctorCheck::pass("snapshot", "vector snapshot readable"),
+        Ok(status) if status.path.is_none() => {
+            DoctorCheck::pass("snapshot", "snapshot storage disabled")
+        },
+        Ok(_) => DoctorCheck::warn(
+            "snapshot",
+            "vector snapshot not written yet",
+            "run `sca index` to build the index",
+        ),
+        Err(error) => DoctorCheck::fail(
+            "snapshot",
+            &error,
+            "run `sca reindex` to rebuild the snapshot",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_providers_report_failing_embedding_check() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let root = std::env::temp_dir().join(format!("sca-doctor-{}", std::process::id()));
+        std::fs::create_dir_all(&root)?;
+        let env = BTreeMap::new();
+
+        let report = run_doctor(
+            None,
+            Some(r#"{"embedding":{"provider":"openai"}}"#),
+            &root,
+            &EnvSource::Map(&env),
+        );
+
+        let status_of = |name: &str| report.check(name).map(|check| check.status);
+        assert_eq!(status_of("env"), Some(DoctorStatus::Pass));
+        assert_eq!(status_of("config"), Some(DoctorStatus::Pass));
+        assert_eq!(status_of("embedding"), Some(DoctorStatus::Fail));
+        assert_eq!(status_of("snapshot"), Some(DoctorStatus::Warn));
+        let embedding = report
+            .check("embedding")
+            .ok_or_else(|| std::io::Error::other("missing embedding check"))?;
+        assert!(embedding.message.contains("API key"));
+        assert!(embedding.hint.is_some());
+        assert!(report.has_failures());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_env_skips_dependent_checks() {
+        let root = std::env::temp_dir();
+        let mut env = BTreeMap::new();
+        env.insert("SCA_CORE_TIMEOUT_MS".to_string(), "soon".to_string());
+
+        let report = run_doctor(None, None, &root, &EnvSource::Map(&env));
+
+        assert_eq!(
+            report.check("env").map(|check| check.status),
+            Some(DoctorStatus::Fail)
+        );
+        let embedding = report.check("embedding");
+        assert_eq!(
+            embedding.map(|check| check.status),
+            Some(DoctorStatus::Warn)
+        );
+        assert!(embedding.is_some_and(|check| check.message.starts_with("skipped")));
+    }
+}
@@ -0,0 +1,177 @@
+//! Single-text embedding probe for debugging provider wiring.
+//!
+//! Builds the configured embedding port and embeds one text without touching
+//! the index. The report carries only vector statistics and the provider id,
+//! never config values, so credentials cannot leak into CLI output.
+
+use crate::InfraResult;
+use crate::cli_local::resolve_config_path;
+use crate::embedding_factory::build_embedding_port_with_telemetry;
+use crate::request_attributes::new_seeded_request_context;
+use semantic_code_config::{
+    load_backend_config_from_path, load_backend_config_std_env, load_runtime_env_from_map,
+    load_runtime_env_std_env,
+};
+use semantic_code_ports::{DetectDimensionOptions, EmbeddingVector};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Codebase root and embedding config fingerprint identifying a detection.
+type DimensionKey = (PathBuf, Box<str>);
+
+/// Detected dimensions keyed by codebase root and embedding config fingerprint.
+static DETECTED_DIMENSIONS: OnceLock<Mutex<HashMap<DimensionKey, u32>>> = OnceLock::new();
+
+/// Number of leading vector components included in the report preview.
+pub const EMBED_PREVIEW_LEN: usize = 8;
+
+/// Summary of one embedding call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliEmbedReport {
+    /// Provider id that produced the vector.
+    pub provider: Box<str>,
+    /// Vector dimensionality.
+    pub dimension: u32,
+    /// L2 norm of the vector.
+    pub l2_norm: f32,
+    /// Leading components (at most [`EMBED_PREVIEW_LEN`]).
+    pub preview: Vec<f32>,
+}
+
+impl CliEmbedReport {
+    fn from_vector(provider: &str, vector: &EmbeddingVector) -> Self {
+        let values = vector.as_slice();
+        let l2_norm = values.iter().map(|value| value * value).sum::<f32>().sqrt();
+        Self {
+            provider: provider.into(),
+            dimension: vector.dimension(),
+            l2_norm,
+            preview: values.iter().take(EMBED_PREVIEW_LEN).copied().collect(),
+        }
+    }
+}
+
+/// Embed `text` with the configured provider and summarize the result.
+#[tracing::instrument(
+    name = "infra.run_embed_local",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some(),
+        text_chars = text.chars().count()
+    )
+)]
+pub fn run_embed_local(
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+    text: &str,
+) -> InfraResult<CliEmbedReport> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_backend_config_std_env(config_path.as_deref(), overrides_json)?;
+    let env = load_runtime_env_std_env()?;
+    let embedding = build_embedding_port_with_telemetry(&config, &env, codebase_root, None)?;
+
+    let ctx = new_seeded_request_context(config.core.seed);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let vector = runtime.block_on(embedding.embed(&ctx, text.into()))?;
+    Ok(CliEmbedReport::from_vector(
+        embedding.provider().id.as_str(),
+        &vector,
+    ))
+}
+
+/// Detect the vector dimension the configured embedding provider produces.
+///
+/// The result is cached for the process per codebase root and embedding
+/// config fingerprint, so only the first call pays for the provider probe.
+#[tracing::instrument(
+    name = "infra.detect_embedding_dimension",
+    skip_all,
+    fields(
+        has_config_path = config_path.is_some(),
+        has_overrides_json = overrides_json.is_some()
+    )
+)]
+pub fn detect_embedding_dimension(
+    env: &BTreeMap<String, String>,
+    config_path: Option<&Path>,
+    overrides_json: Option<&str>,
+    codebase_root: &Path,
+) -> InfraResult<u32> {
+    let config_path = resolve_config_path(config_path, codebase_root);
+    let config = load_backend_config_from_path(config_path.as_deref(), overrides_json, env)?;
+    let key = (codebase_root.to_path_buf(), config.fingerprint());
+    let cache = DETECTED_DIMENSIONS.get_or_init(|| Mutex::new(HashMap::new()));
+    let cached = match cache.lock() {
+        Ok(guard) => guard.get(&key).copied(),
+        Err(poisoned) => poisoned.into_inner().get(&key).copied(),
+    };
+    if let Some(dimension) = cached {
+        tracing::debug!(dimension, "using cached embedding dimension");
+        return Ok(dimension);
+    }
+
+    let runtime_env = load_runtime_env_from_map(env)?;
+    let embedding =
+        build_embedding_port_with_telemetry(&config, &runtime_env, codebase_root, None)?;
+    let ctx = new_seeded_request_context(config.core.seed);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let dimension = runtime
+        .block_on(embedding.detect_dimension(&ctx, DetectDimensionOptions::default().into()))?;
+    match cache.lock() {
+        Ok(mut guard) => guard.insert(key, dimension),
+        Err(poisoned) => poisoned.into_inner().insert(key, dimension),
+    };
+    Ok(dimension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embed_reports_test_provider_dimension() -> InfraResult<()> {
+        let root = std::env::temp_dir().join(format!("sca-embed-probe-{}", std::process::id()));
+        std::fs::create_dir_all(&root)?;
+        let overrides = r#"{"embedding":{"provider":"test","dimension":16}}"#;
+
+        let report = run_embed_local(None, Some(overrides), &root, "fn main() {}");
+        std::fs::remove_dir_all(&root)?;
+        let report = report?;
+
+        assert_eq!(report.provider.as_ref(), "test");
+        assert_eq!(report.dimension, 16);
+        assert_eq!(report.preview.len(), EMBED_PREVIEW_LEN);
+        assert!(report.l2_norm.is_finite());
+        Ok(())
+    }
+
+    #[test]
+    fn detected_dimension_matches_test_provider() -> InfraResult<()> {
+        let root = std::env::temp_dir().join(format!("sca-detect-dim-{}", std::process::id()));
+        std::fs::create_dir_all(&root)?;
+        let env = BTreeMap::new();
+        let overrides = r#"{"embedding":{"provider":"test","dimension":24}}"#;
+
+        let first = detect_embedding_dimension(&env, None, Some(overrides), &root);
+        let cached = detect_embedding_dimension(&env, None, Some(overrides), &root);
+        let other = detect_embedding_dimension(
+            &env,
+            None,
+            Some(r#"{"embedding":{"provider":"test","dimension":12}}"#),
+            &root,
+        );
+        std::fs::remove_dir_all(&root)?;
+
+        assert_eq!(first?, 24);
+        assert_eq!(cached?, 24);
+        assert_eq!(other?, 12);
+        Ok(())
+    }
+}
@@ -3,14 +3,16 @@
 use crate::InfraResult;
 use crate::embedding_router::SplitEmbeddingRouter;
 use semantic_code_adapters::{
-    CachingEmbedding, DiskCacheProvider, EmbeddingCache, EmbeddingCacheConfig,
-    FixedDimensionEmbedding, GeminiEmbedding, GeminiEmbeddingConfig, OllamaEmbedding,
-    OllamaEmbeddingConfig, OnnxEmbedding, OnnxEmbeddingConfig, OpenAiEmbedding,
-    OpenAiEmbeddingConfig, TestEmbedding, VoyageEmbedding, VoyageEmbeddingConfig,
+    AzureOpenAiEmbedding, AzureOpenAiEmbeddingConfig, CacheKeyHash, CachingEmbedding,
+    DiskCacheProvider, EmbeddingCache, EmbeddingCacheConfig, FixedDimensionEmbedding,
+    GeminiEmbedding, GeminiEmbeddingConfig, OllamaEmbedding, OllamaEmbeddingConfig, OnnxEmbedding,
+    OnnxEmbeddingConfig, OpenAiEmbedding, OpenAiEmbeddingConfig, OrderedEmbedding, TestEmbedding,
+    VoyageEmbedding, VoyageEmbeddingConfig,
 };
+use semantic_code_app::LanguageEmbeddingRoutes;
 use semantic_code_config::{
-    EmbeddingCacheDiskProvider, EmbeddingConfig, EmbeddingRoutingMode, RuntimeEnv,
-    ValidatedBackendConfig,
+    EmbeddingCacheDiskProvider, EmbeddingCacheKeyHash, EmbeddingConfig, EmbeddingRoutingMode,
+    RuntimeEnv, ValidatedBackendConfig,
 };
 use semantic_code_ports::{EmbeddingPort, TelemetryPort};
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RetryPolicy, SecretString};
@@ -48,6 +50,7 @@ enum ProviderKind {
     Onnx,
     Ane,
     OpenAi,
+    AzureOpenAi,
     Gemini,
     Voyage,
     Ollama,
@@ -222,6 +225,14 @@ pub fn build_embedding_port_with_telemetry(
             ProviderKind::OpenAi,
             allow_test_fallback,
         ),
+        ProviderKind::AzureOpenAi => build_remote_with_fallback(
+            config,
+            env,
+            codebase_root,
+            local_mode,
+            ProviderKind::AzureOpenAi,
+            allow_test_fallback,
+        ),
         ProviderKind::Gemini => build_remote_with_fallback(
             config,
             env,
@@ -251,6 +262,48 @@ pub fn build_embedding_port_with_telemetry(
     wrap_with_resilience(port, config, env, codebase_root, telemetry)
 }
 
+/// Build the per-language embedding routes declared in `embedding.routing.byLanguage`.
+///
+/// Routes pointing at the default provider reuse `default`; every other provider
+/// gets its own port built from the same config with that provider selected.
+pub fn build_language_embedding_routes(
+    config: &ValidatedBackendConfig,
+    env: &RuntimeEnv,
+    codebase_root: &Path,
+    default: &Arc<dyn EmbeddingPort>,
+    telemetry: Option<&Arc<dyn TelemetryPort>>,
+) -> InfraResult<LanguageEmbeddingRoutes> {
+    let by_language = &config.embedding.routing.by_language;
+    let mut routes = LanguageEmbeddingRoutes::new();
+    let default_provider = config.embedding.provider.as_deref();
+    let mut providers: Vec<&str> = by_language.values().map(AsRef::as_ref).collect();
+    providers.sort_unstable();
+    providers.dedup();
+
+    for provider in providers {
+        let port = if Some(provider) == default_provider {
+            Arc::clone(default)
+        } else {
+            let mut raw = config.as_ref().clone();
+            raw.embedding.provider = Some(provider.into());
+            raw.embedding.routing.mode = None;
+            raw.embedding.routing.by_language.clear();
+            let provider_config = raw.validate_and_normalize().map_err(ErrorEnvelope::from)?;
+            build_embedding_port_with_telemetry(
+                &provider_config,
+                env,
+                codebase_root,
+                telemetry.cloned(),
+            )?
+        };
+        routes = routes.with_provider(provider, port);
+    }
+    for (language, provider) in by_language {
+        routes = routes.with_route(*language, provider)?;
+    }
+    Ok(routes)
+}
+
 fn build_auto(
     config: &ValidatedBackendConfig,
     env: &RuntimeEnv,
@@ -323,6 +376,7 @@ fn resolve_split_remote_provider(
 ) -> InfraResult<ProviderKind> {
     match provider {
         ProviderKind::OpenAi
+        | ProviderKind::AzureOpenAi
         | ProviderKind::Gemini
         | ProviderKind::Voyage
         | ProviderKind::Ollama => Ok(provider),
@@ -349,7 +403,7 @@ fn build_remote_with_fallback(
     provider: ProviderKind,
     allow_test_fallback: bool,
 ) -> InfraResult<Arc<dyn EmbeddingPort>> {
-    if local_mode == LocalMode::Disabled && !remote_ready(provider, env) {
+    if local_mode == LocalMode::Disabled && !remote_ready(provider, &config.embedding, env) {
         return fallback_or_missing(config, allow_test_fallback, provider);
     }
 
@@ -364,7 +418,7 @@ fn build_remote_with_fallback(
         }
     }
 
-    if remote_ready(provider, env) {
+    if remote_ready(provider, &config.embedding, env) {
         return build_remote(config, env, provider);
     }
 
@@ -406,6 +460,26 @@ fn build_remote(
                 OpenAiEmbedding::new(&adapter_config)?,
             ))
         },
+        ProviderKind::AzureOpenAi => {
+            let api_key = resolve_api_key(provider, env).ok_or_else(|| {
+                ErrorEnvelope::expected(
+                    ErrorCode::invalid_input(),
+                    "Azure OpenAI API key is required",
+                )
+            })?;
+            let api_key = api_key.into_inner();
+            let mut adapter_config =
+                AzureOpenAiEmbeddingConfig::from_embedding_config(api_key, &config.embedding);
+            adapter_config.endpoint = resolve_base_url(provider, &config.embedding, env);
+            adapter_config.deployment = resolve_model(provider, &config.embedding, env);
+            adapter_config
+                .api_version
+                .clone_from(&env.azure_openai_api_version);
+            Ok(wrap_embedding_fixed(
+                config.embedding.dimension,
+                AzureOpenAiEmbedding::new(&adapter_config)?,
+            ))
+        },
         ProviderKind::Gemini => {
             let api_key = resolve_api_key(provider, env).ok_or_else(|| {
                 ErrorEnvelope::expected(ErrorCode::invalid_input(), "Gemini API key is required")
@@ -466,6 +540,9 @@ fn wrap_with_resilience(
     };
     let timeout_ms = config.embedding.timeout_ms;
     let max_in_flight = max_in_flight_embedding_requests(config, &port);
+    // Adapters may complete batches out of order; restore input alignment
+    // before results reach the cache.
+    let port: Arc<dyn EmbeddingPort> = Arc::new(OrderedEmbedding::new(port));
 
     Ok(Arc::new(CachingEmbedding::new(
         port,
@@ -486,6 +563,7 @@ fn build_cache_namespace(
     let provider = port.provider().id.as_str();
     let provider_kind = match provider {
         "openai" => Some(ProviderKind::OpenAi),
+        "azure_openai" => Some(ProviderKind::AzureOpenAi),
         "gemini" => Some(ProviderKind::Gemini),
         "voyage" => Some(ProviderKind::Voyage),
         "ollama" => Some(ProviderKind::Ollama),
@@ -512,7 +590,10 @@ fn max_in_flight_embedding_requests(
     port: &Arc<dyn EmbeddingPort>,
 ) -> Option<usize> {
     let provider = port.provider().id.as_str();
-    if !matches!(provider, "openai" | "gemini" | "voyage" | "ollama") {
+    if !matches!(
+        provider,
+        "openai" | "azure_openai" | "gemini" | "voyage" | "ollama"
+    ) {
         return None;
     }
     config
@@ -545,6 +626,13 @@ fn build_cache_config(
         )
     })?;
 
+    let warmup_concurrency = usize::try_from(cache_config.warmup_concurrency).map_err(|_| {
+        ErrorEnvelope::expected(
+            ErrorCode::invalid_input(),
+            "embedding cache warmupConcurrency is too large",
+        )
+    })?;
+
     Ok(EmbeddingCacheConfig {
         enabled: cache_config.enabled,
         max_entries,
@@ -555,6 +643,11 @@ fn build_cache_config(
         disk_connection: cache_config.disk_connection.clone(),
         disk_table: cache_config.disk_table.clone(),
         disk_max_bytes: Some(cache_config.disk_max_bytes),
+        warmup_concurrency,
+        key_hash: match cache_config.key_hash {
+            EmbeddingCacheKeyHash::Sha256 => CacheKeyHash::Sha256,
+            EmbeddingCacheKeyHash::Blake3 => CacheKeyHash::Blake3,
+        },
     })
 }
 
@@ -575,11 +668,16 @@ fn default_cache_path(codebase_root: &Path) -> PathBuf {
         .join("cache.db")
 }
 
-fn remote_ready(provider: ProviderKind, env: &RuntimeEnv) -> bool {
+fn remote_ready(provider: ProviderKind, config: &EmbeddingConfig, env: &RuntimeEnv) -> bool {
     match provider {
         ProviderKind::OpenAi | ProviderKind::Gemini | ProviderKind::Voyage => {
             resolve_api_key(provider, env).is_some()
         },
+        ProviderKind::AzureOpenAi => {
+            resolve_api_key(provider, env).is_some()
+                && resolve_base_url(provider, config, env).is_some()
+                && resolve_model(provider, config, env).is_some()
+        },
         ProviderKind::Ollama => true,
         _ => false,
     }
@@ -591,6 +689,10 @@ fn resolve_api_key(provider: ProviderKind, env: &RuntimeEnv) -> Option<SecretStr
             .openai_api_key
             .clone()
             .or_else(|| env.embedding_api_key.clone()),
+        ProviderKind::AzureOpenAi => env
+            .azure_openai_api_key
+            .clone()
+            .or_else(|| env.embedding_api_key.clone()),
         ProviderKind::Gemini => env
             .gemini_api_key
             .clone()
@@ -610,6 +712,10 @@ fn resolve_model(
 ) -> Option<Box<str>> {
     match provider {
         ProviderKind::OpenAi => env.openai_model.clone().or_else(|| config.model.clone()),
+        ProviderKind::AzureOpenAi => env
+            .azure_openai_deployment
+            .clone()
+            .or_else(|| config.model.clone()),
         ProviderKind::Gemini => env.gemini_model.clone().or_else(|| config.model.clone()),
         ProviderKind::Voyage => env.voyage_model.clone().or_else(|| config.model.clone()),
         ProviderKind::Ollama => env.ollama_model.clone().or_else(|| config.model.clone()),
@@ -627,6 +733,10 @@ fn resolve_base_url(
             .openai_base_url
             .clone()
             .or_else(|| config.base_url.clone()),
+        ProviderKind::AzureOpenAi => env
+            .azure_openai_endpoint
+            .clone()
+            .or_else(|| config.base_url.clone()),
         ProviderKind::Gemini => env
             .gemini_base_url
             .clone()
@@ -644,6 +754,12 @@ const fn auto_remote_provider(env: &RuntimeEnv) -> Option<ProviderKind> {
     if env.openai_api_key.is_some() || env.embedding_api_key.is_some() {
         return Some(ProviderKind::OpenAi);
     }
+    if env.azure_openai_api_key.is_some()
+        && env.azure_openai_endpoint.is_some()
+        && env.azure_openai_deployment.is_some()
+    {
+        return Some(ProviderKind::AzureOpenAi);
+    }
     if env.gemini_api_key.is_some() {
         return Some(ProviderKind::Gemini);
     }
@@ -819,6 +935,9 @@ fn try_build_onnx(
         tokenizer_filename: config.embedding.onnx.tokenizer_filename.clone(),
         dimension: config.embedding.dimension,
         session_pool_size,
+        execution_provider: config.embedding.onnx.execution_provider,
+        intra_op_threads: usize::try_from(config.embedding.onnx.intra_op_threads).unwrap_or(0),
+        inter_op_threads: usize::try_from(config.embedding.onnx.inter_op_threads).unwrap_or(0),
     };
     Ok(Some(wrap_embedding_fixed(
         config.dimension,
@@ -1052,6 +1171,7 @@ fn parse_provider(value: Option<&str>) -> InfraResult<ProviderKind> {
         "onnx" | "local" => Ok(ProviderKind::Onnx),
         "ane" | "neural-engine" => Ok(ProviderKind::Ane),
         "openai" => Ok(ProviderKind::OpenAi),
+        "azure_openai" | "azure-openai" | "azure" => Ok(ProviderKind::AzureOpenAi),
         "gemini" => Ok(ProviderKind::Gemini),
         "voyage" | "voyageai" => Ok(ProviderKind::Voyage),
         "ollama" => Ok(ProviderKind::Ollama),
@@ -1076,6 +1196,10 @@ const fn resolve_local_mode(config: &EmbeddingConfig) -> LocalMode {
 fn missing_provider_error(provider: ProviderKind) -> ErrorEnvelope {
     let message = match provider {
         ProviderKind::OpenAi => "OpenAI API key is required".to_string(),
+        ProviderKind::AzureOpenAi => {
+            "Azure OpenAI requires AZURE_OPENAI_API_KEY, AZURE_OPENAI_ENDPOINT, and AZURE_OPENAI_DEPLOYMENT"
+                .to_string()
+        },
         ProviderKind::Gemini => "Gemini API key is required".to_string(),
         ProviderKind::Voyage => "Voyage API key is required".to_string(),
         ProviderKind::Ollama => "embedding provider ollama is not configured".to_string(),
@@ -33,6 +33,14 @@ impl SplitEmbeddingRouter {
         let provider = EmbeddingProviderInfo {
             id: EmbeddingProviderId::parse("hybrid").map_err(ErrorEnvelope::from)?,
             name: "Hybrid (local + remote)".to_string().into_boxed_str(),
+            // Chunks may go to either backend, so honor the tighter limit.
+            max_input_chars: match (
+                local.provider().max_input_chars,
+                remote.provider().max_input_chars,
+            ) {
+                (Some(left), Some(right)) => Some(left.min(right)),
+                (limit, None) | (None, limit) => limit,
+            },
         };
         Ok(Self {
             local,
@@ -176,6 +184,7 @@ mod tests {
                 provider: EmbeddingProviderInfo {
                     id: EmbeddingProviderId::parse(id).expect("provider id"),
                     name: id.to_string().into_boxed_str(),
+                    max_input_chars: None,
                 },
                 id,
             }
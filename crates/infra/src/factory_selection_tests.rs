@@ -6,11 +6,12 @@ use semantic_code_config::{
     BackendConfig, RuntimeEnv, SnapshotStorageMode, VectorKernelKind, VectorSearchStrategy,
     VectorSnapshotFormat,
 };
-use semantic_code_domain::{CollectionName, LineSpan, VectorDocumentMetadata};
+use semantic_code_domain::{CollectionName, LineSpan, SearchExclusions, VectorDocumentMetadata};
 use semantic_code_ports::{
     VectorDbPort, VectorDocumentForInsert, VectorSearchOptions, VectorSearchRequest,
 };
 use semantic_code_shared::ErrorCode;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -42,6 +43,8 @@ fn sample_metadata(path: &str) -> Result<VectorDocumentMetadata, Box<dyn std::er
         fragment_start_byte: None,
         fragment_end_byte: None,
         node_kind: None,
+        extra: BTreeMap::new(),
+        mtime_ms: None,
     })
 }
 
@@ -132,6 +135,7 @@ async fn vectordb_search_strategy_config_toggles_local_search_path()
         options: VectorSearchOptions {
             top_k: Some(1),
             filter_expr: None,
+            exclusions: SearchExclusions::default(),
             threshold: Some(0.1),
         },
     };
@@ -220,6 +224,33 @@ async fn embedding_openai_requires_api_key_message() {
     );
 }
 
+#[tokio::test]
+async fn embedding_azure_openai_uses_env_endpoint_and_deployment() {
+    let root = temp_dir("embedding-azure-openai").expect("temp dir");
+    let mut config = BackendConfig::default();
+    config.embedding.provider = Some("azure_openai".to_string().into_boxed_str());
+    let config = config.validate_and_normalize().expect("config");
+
+    let error = match build_embedding_port_with_telemetry(&config, &empty_env(), &root, None) {
+        Ok(_) => panic!("expected Azure OpenAI config to fail without env"),
+        Err(error) => error,
+    };
+    assert!(
+        error.message.contains("AZURE_OPENAI_ENDPOINT"),
+        "unexpected error message: {}",
+        error.message
+    );
+
+    let env = RuntimeEnv {
+        azure_openai_api_key: Some("example".to_string().into()), // pragma: allowlist secret
+        azure_openai_endpoint: Some("https://example.openai.azure.com".into()),
+        azure_openai_deployment: Some("embed-small".into()),
+        ..RuntimeEnv::default()
+    };
+    let port = build_embedding_port_with_telemetry(&config, &env, &root, None).expect("port");
+    assert_eq!(port.provider().id.as_str(), "azure_openai");
+}
+
 #[tokio::test]
 async fn onnx_missing_assets_error_is_user_friendly() {
     let root = temp_dir("embedding-onnx").expect("temp dir");
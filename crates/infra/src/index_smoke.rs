@@ -6,11 +6,16 @@ use semantic_code_adapters::{
     SelfCheckPathPolicy, SelfCheckSplitter, SelfCheckVectorDb,
 };
 use semantic_code_app::{
-    ClearIndexDeps, ClearIndexInput, IndexCodebaseDeps, IndexCodebaseInput, IndexCodebaseStatus,
-    SemanticSearchDeps, SemanticSearchInput, clear_index, index_codebase, semantic_search,
+    ClearIndexDeps, ClearIndexInput, CollectionPolicy, IndexCodebaseDeps, IndexCodebaseInput,
+    IndexCodebaseStatus, LanguageEmbeddingRoutes, SemanticSearchDeps, SemanticSearchInput,
+    clear_index, index_codebase, semantic_search,
+};
+use semantic_code_domain::{
+    CollectionName, IndexMode, OnDuplicateId, OversizeChunkPolicy, PathNormalization,
+    SearchExclusions,
 };
-use semantic_code_domain::{CollectionName, IndexMode};
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope, RequestContext};
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -35,6 +40,7 @@ pub fn run_index_smoke() -> InfraResult<()> {
             ignore: Arc::new(SelfCheckIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
 
         let input = IndexCodebaseInput {
@@ -44,18 +50,35 @@ pub fn run_index_smoke() -> InfraResult<()> {
             index_mode: IndexMode::Dense,
             supported_extensions: Some(vec![".rs".into()]),
             ignore_patterns: None,
+            additional_ignore_patterns: Vec::new(),
             file_list: None,
             force_reindex: true,
+            collection_description: None,
+            collection_policy: CollectionPolicy::default(),
             on_progress: None,
             embedding_batch_size: NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
             chunk_limit: NonZeroUsize::new(100).unwrap_or(NonZeroUsize::MIN),
             max_files: None,
             max_file_size_bytes: None,
+            max_file_size_bytes_by_extension: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::Split,
+            skip_binary: true,
+            document_template: None,
+            extra_metadata: BTreeMap::new(),
+            record_mtime: false,
+            min_chunk_chars: 0,
+            max_chunk_chars: None,
             max_buffered_chunks: None,
             max_buffered_embeddings: None,
             max_in_flight_files: Some(NonZeroUsize::MIN),
             max_in_flight_embedding_batches: Some(NonZeroUsize::MIN),
             max_in_flight_inserts: Some(NonZeroUsize::MIN),
+            shard_count: None,
+            max_consecutive_failures: None,
+            max_total_failures: None,
+            on_duplicate_id: OnDuplicateId::Overwrite,
+            shutdown_timeout: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -96,6 +119,7 @@ pub fn run_search_smoke() -> InfraResult<()> {
             ignore: Arc::new(SelfCheckIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
 
         let index_input = IndexCodebaseInput {
@@ -104,18 +128,35 @@ pub fn run_search_smoke() -> InfraResult<()> {
             index_mode: IndexMode::Dense,
             supported_extensions: Some(vec![".rs".into()]),
             ignore_patterns: None,
+            additional_ignore_patterns: Vec::new(),
             file_list: None,
             force_reindex: true,
+            collection_description: None,
+            collection_policy: CollectionPolicy::default(),
             on_progress: None,
             embedding_batch_size: NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
             chunk_limit: NonZeroUsize::new(50).unwrap_or(NonZeroUsize::MIN),
             max_files: None,
             max_file_size_bytes: None,
+            max_file_size_bytes_by_extension: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::Split,
+            skip_binary: true,
+            document_template: None,
+            extra_metadata: BTreeMap::new(),
+            record_mtime: false,
+            min_chunk_chars: 0,
+            max_chunk_chars: None,
             max_buffered_chunks: None,
             max_buffered_embeddings: None,
             max_in_flight_files: Some(NonZeroUsize::MIN),
             max_in_flight_embedding_batches: Some(NonZeroUsize::MIN),
             max_in_flight_inserts: Some(NonZeroUsize::MIN),
+            shard_count: None,
+            max_consecutive_failures: None,
+            max_total_failures: None,
+            on_duplicate_id: OnDuplicateId::Overwrite,
+            shutdown_timeout: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -133,6 +174,7 @@ pub fn run_search_smoke() -> InfraResult<()> {
             vectordb,
             logger: None,
             telemetry: None,
+            filesystem: None,
         };
 
         let input = SemanticSearchInput {
@@ -140,9 +182,18 @@ pub fn run_search_smoke() -> InfraResult<()> {
             collection_name: collection,
             index_mode: IndexMode::Dense,
             query: "ok".into(),
+            query_template: None,
+            normalize_query: false,
             top_k: Some(3),
             threshold: Some(0.0),
             query_vector: None,
+            include_histogram: false,
+            shard_count: None,
+            group_by_file: false,
+            exclusions: SearchExclusions::default(),
+            recency_boost: None,
+            context_lines: None,
+            on_progress: None,
         };
 
         let output = semantic_search(&ctx, &deps, input).await?;
@@ -182,6 +233,7 @@ pub fn run_clear_smoke() -> InfraResult<()> {
             ignore: Arc::new(SelfCheckIgnore),
             logger: None,
             telemetry: None,
+            embedding_routes: LanguageEmbeddingRoutes::default(),
         };
 
         let index_input = IndexCodebaseInput {
@@ -190,18 +242,35 @@ pub fn run_clear_smoke() -> InfraResult<()> {
             index_mode: IndexMode::Dense,
             supported_extensions: Some(vec![".rs".into()]),
             ignore_patterns: None,
+            additional_ignore_patterns: Vec::new(),
             file_list: None,
             force_reindex: true,
+            collection_description: None,
+            collection_policy: CollectionPolicy::default(),
             on_progress: None,
             embedding_batch_size: NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN),
             chunk_limit: NonZeroUsize::new(50).unwrap_or(NonZeroUsize::MIN),
             max_files: None,
             max_file_size_bytes: None,
+            max_file_size_bytes_by_extension: BTreeMap::new(),
+            path_normalization: PathNormalization::AsIs,
+            oversize_chunk_policy: OversizeChunkPolicy::Split,
+            skip_binary: true,
+            document_template: None,
+            extra_metadata: BTreeMap::new(),
+            record_mtime: false,
+            min_chunk_chars: 0,
+            max_chunk_chars: None,
             max_buffered_chunks: None,
             max_buffered_embeddings: None,
             max_in_flight_files: Some(NonZeroUsize::MIN),
             max_in_flight_embedding_batches: Some(NonZeroUsize::MIN),
             max_in_flight_inserts: Some(NonZeroUsize::MIN),
+            shard_count: None,
+            max_consecutive_failures: None,
+            max_total_failures: None,
+            on_duplicate_id: OnDuplicateId::Overwrite,
+            shutdown_timeout: None,
         };
 
         let ctx = RequestContext::new_request();
@@ -224,6 +293,7 @@ pub fn run_clear_smoke() -> InfraResult<()> {
         let input = ClearIndexInput {
             codebase_root: PathBuf::from("/tmp/self-check"),
             collection_name: collection,
+            shard_count: None,
         };
 
         clear_index(&ctx, &deps, input).await?;
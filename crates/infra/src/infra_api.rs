@@ -4,23 +4,42 @@ pub use crate::cli_calibration::{
     calibration_path, delete_calibration, read_calibration, write_calibration,
 };
 pub use crate::cli_local::{
-    CliConfigSummary, CliInitStatus, CliStatus, LocalSearchSession, SnapshotStatus,
-    open_search_session, open_search_session_with_options, read_status_local, run_calibrate_local,
-    run_clear_local, run_index_local, run_init_local, run_reindex_local, run_search_local,
+    CliConfigSummary, CliIndexState, CliInitStatus, CliStatus, LocalSearchSession, SnapshotStatus,
+    get_vector_local, list_indexed_files_local, open_search_session,
+    open_search_session_with_options, read_status_local, resolve_collection_naming,
+    resolve_core_timeout_ms, run_calibrate_local, run_clear_local, run_export_local,
+    run_import_local, run_index_local, run_init_local, run_migrate_local, run_prune_local,
+    run_reindex_local, run_search_local, run_search_local_with_progress, run_verify_local,
+    search_request_limits_local,
+};
+pub use crate::config_check::{
+    load_effective_config_diff, load_effective_config_json, load_effective_config_with_warnings,
+    load_effective_limits,
+};
+pub use crate::doctor::{DoctorCheck, DoctorReport, DoctorStatus, run_doctor_local};
+pub use crate::embed_probe::{
+    CliEmbedReport, EMBED_PREVIEW_LEN, detect_embedding_dimension, run_embed_local,
 };
-pub use crate::config_check::load_effective_config_json;
 pub use crate::env_check::{InfraError, InfraResult, validate_env_parsing};
 pub use crate::index_smoke::{run_clear_smoke, run_index_smoke, run_search_smoke};
 pub use crate::jobs::{
-    JobError, JobKind, JobProgress, JobRequest, JobResult, JobState, JobStatus, cancel_job,
-    create_job, read_job_status, run_job,
+    JobError, JobKind, JobProgress, JobRequest, JobResult, JobState, JobStatus, JobSummary,
+    cancel_job, create_job, list_recent_jobs, read_job_status, run_job,
+};
+pub use crate::request_attributes::{
+    install_log_level, install_read_only_mode, install_request_attributes,
 };
 pub use crate::request_check::{RequestKind, validate_request_json};
 pub use crate::storage_estimate::{
     CliStorageEstimate, StorageThresholdStatus, ensure_storage_headroom_local,
     estimate_storage_local,
 };
-pub use semantic_code_ports::EmbeddingVector;
+pub use semantic_code_app::{
+    ExportIndexOutput, ImportIndexOutput, IndexProgress, IndexedFileSummary, MigrateIndexOutput,
+    PruneIndexOutput, SearchPhase,
+};
+pub use semantic_code_ports::{EmbeddingVector, LogLevel};
+pub use semantic_code_shared::RequestAttributes;
 
 /// Crate version from Cargo metadata.
 #[must_use]
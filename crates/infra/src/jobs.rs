@@ -1,6 +1,7 @@
 //! Background job helpers for CLI workflows.
 
 use crate::cli_local::{run_index_local_with_progress, run_reindex_local_with_progress};
+use crate::request_attributes::ensure_writable;
 use crate::{InfraError, InfraResult};
 use semantic_code_app::{
     EmbedStageStats as AppEmbedStageStats, FunctionTimingStats as AppFunctionTimingStats,
@@ -10,7 +11,7 @@ use semantic_code_app::{
     SplitStageStats as AppSplitStageStats,
 };
 use semantic_code_config::{
-    IndexRequestDto, ReindexByChangeRequestDto, validate_index_request,
+    IndexRequestDto, ReindexByChangeRequestDto, is_stdin_config_path, validate_index_request,
     validate_reindex_by_change_request,
 };
 use semantic_code_shared::{ErrorClass, ErrorCode, ErrorEnvelope};
@@ -51,6 +52,14 @@ pub enum JobState {
     Cancelled,
 }
 
+impl JobState {
+    /// Returns true once the job can no longer change state.
+    #[must_use]
+    pub const fn is_terminal(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
 /// Background job request payload.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -83,6 +92,14 @@ impl JobRequest {
         overrides_json: Option<String>,
         init_if_missing: bool,
     ) -> InfraResult<Self> {
+        if config_path.is_some_and(is_stdin_config_path) {
+            // The worker runs detached and cannot see the caller's stdin.
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::invalid_input(),
+                "background jobs cannot read config from stdin",
+            )
+            .with_metadata("field", "config"));
+        }
         let created_at_ms = now_epoch_ms()?;
         let id = Uuid::new_v4().to_string().into_boxed_str();
         let root =
@@ -203,6 +220,9 @@ pub struct JobPrepareBreakdown {
 pub struct JobScanStats {
     /// Files discovered for indexing.
     pub files: u64,
+    /// Files skipped because their contents looked binary.
+    #[serde(default)]
+    pub skipped_binary: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level scan breakdown.
@@ -225,6 +245,12 @@ pub struct JobSplitStats {
     pub files: u64,
     /// Chunks produced by the splitter.
     pub chunks: u64,
+    /// Chunks re-split because they exceeded the embedding provider input limit.
+    #[serde(default)]
+    pub oversized_chunks: u64,
+    /// Chunks dropped for being shorter than `sync.minChunkChars`.
+    #[serde(default)]
+    pub dropped_small: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level split breakdown.
@@ -248,6 +274,12 @@ pub struct JobEmbedStats {
     pub batches: u64,
     /// Chunks embedded.
     pub chunks: u64,
+    /// Embedding batches that failed and were skipped.
+    #[serde(default)]
+    pub failed_batches: u64,
+    /// Chunks in skipped embedding batches.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level embed breakdown.
@@ -271,6 +303,9 @@ pub struct JobInsertStats {
     pub batches: u64,
     /// Chunks inserted.
     pub chunks: u64,
+    /// Chunks rejected by the vector DB and skipped.
+    #[serde(default)]
+    pub failed_chunks: u64,
     /// Elapsed time in milliseconds.
     pub duration_ms: u64,
     /// Function-level insert breakdown.
@@ -317,6 +352,7 @@ const fn job_prepare_stats(stats: &AppPrepareStageStats) -> JobPrepareStats {
 const fn job_scan_stats(stats: &AppScanStageStats) -> JobScanStats {
     JobScanStats {
         files: stats.files,
+        skipped_binary: stats.skipped_binary,
         duration_ms: stats.duration_ms,
         breakdown: JobScanBreakdown {
             load_ignore_patterns: job_timing_stats(&stats.breakdown.load_ignore_patterns),
@@ -330,6 +366,8 @@ const fn job_split_stats(stats: &AppSplitStageStats) -> JobSplitStats {
     JobSplitStats {
         files: stats.files,
         chunks: stats.chunks,
+        oversized_chunks: stats.oversized_chunks,
+        dropped_small: stats.dropped_small,
         duration_ms: stats.duration_ms,
         breakdown: JobSplitBreakdown {
             file_passes_size_check: job_timing_stats(&stats.breakdown.file_passes_size_check),
@@ -344,6 +382,8 @@ const fn job_embed_stats(stats: &AppEmbedStageStats) -> JobEmbedStats {
     JobEmbedStats {
         batches: stats.batches,
         chunks: stats.chunks,
+        failed_batches: stats.failed_batches,
+        failed_chunks: stats.failed_chunks,
         duration_ms: stats.duration_ms,
         breakdown: JobEmbedBreakdown {
             queue_latency: job_timing_stats(&stats.breakdown.queue_latency),
@@ -358,6 +398,7 @@ const fn job_insert_stats(stats: &AppInsertStageStats) -> JobInsertStats {
     JobInsertStats {
         batches: stats.batches,
         chunks: stats.chunks,
+        failed_chunks: stats.failed_chunks,
         duration_ms: stats.duration_ms,
         breakdown: JobInsertBreakdown {
             provider_insert_batch: job_timing_stats(&stats.breakdown.provider_insert_batch),
@@ -441,8 +482,40 @@ pub struct JobStatus {
     pub warnings: Vec<JobError>,
 }
 
+/// Compact view of a job for status history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSummary {
+    /// Job identifier.
+    pub id: Box<str>,
+    /// Job kind.
+    pub kind: JobKind,
+    /// Job state.
+    pub state: JobState,
+    /// Job creation time (ms since epoch).
+    pub created_at_ms: u64,
+    /// Job last update time (ms since epoch); the completion time for terminal jobs.
+    pub updated_at_ms: u64,
+    /// Elapsed time between creation and the last update (ms).
+    pub duration_ms: u64,
+}
+
+impl From<&JobStatus> for JobSummary {
+    fn from(status: &JobStatus) -> Self {
+        Self {
+            id: status.id.clone(),
+            kind: status.kind,
+            state: status.state,
+            created_at_ms: status.created_at_ms,
+            updated_at_ms: status.updated_at_ms,
+            duration_ms: status.updated_at_ms.saturating_sub(status.created_at_ms),
+        }
+    }
+}
+
 /// Create a new background job request and persist initial status.
 pub fn create_job(request: &JobRequest) -> InfraResult<JobStatus> {
+    ensure_writable("create_job")?;
     let job_dir = job_dir(&request.codebase_root, &request.id);
     std::fs::create_dir_all(&job_dir)?;
     write_job_request(&job_dir, request)?;
@@ -481,6 +554,41 @@ pub fn read_job_status(root: &Path, job_id: &str) -> InfraResult<JobStatus> {
     })
 }
 
+/// List up to `limit` recent jobs under the codebase root.
+///
+/// Queued and running jobs come first, followed by terminal jobs ordered by
+/// completion time (most recent first). Job directories whose status file is
+/// missing or unreadable are skipped so a half-written job never hides the rest.
+pub fn list_recent_jobs(root: &Path, limit: usize) -> InfraResult<Vec<JobSummary>> {
+    let jobs_dir = root.join(".context").join(JOBS_DIR_NAME);
+    let entries = match std::fs::read_dir(&jobs_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut jobs = Vec::new();
+    for entry in entries {
+        let path = entry?.path().join(JOB_STATUS_FILE);
+        let Ok(payload) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Ok(status) = serde_json::from_str::<JobStatus>(&payload) {
+            jobs.push(JobSummary::from(&status));
+        }
+    }
+
+    jobs.sort_by(|left, right| {
+        left.state
+            .is_terminal()
+            .cmp(&right.state.is_terminal())
+            .then_with(|| right.updated_at_ms.cmp(&left.updated_at_ms))
+            .then_with(|| left.id.cmp(&right.id))
+    });
+    jobs.truncate(limit);
+    Ok(jobs)
+}
+
 /// Cancel a running job (best-effort).
 pub fn cancel_job(root: &Path, job_id: &str) -> InfraResult<JobStatus> {
     let job_dir = job_dir(root, job_id);
@@ -796,6 +904,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn recent_jobs_list_running_and_terminal_jobs() -> InfraResult<()> {
+        let root = std::env::temp_dir().join(format!("sca-job-history-{}", Uuid::new_v4()));
+        let first = create_job(&JobRequest::new(JobKind::Index, &root, None, None, false)?)?;
+        let second = create_job(&JobRequest::new(
+            JobKind::Reindex,
+            &root,
+            None,
+            None,
+            false,
+        )?)?;
+
+        let completed = JobStatus {
+            state: JobState::Completed,
+            updated_at_ms: first.created_at_ms + 1_500,
+            ..first
+        };
+        write_job_status(&job_dir(&root, &completed.id), &completed)?;
+        let running = JobStatus {
+            state: JobState::Running,
+            updated_at_ms: second.created_at_ms + 10,
+            ..second
+        };
+        write_job_status(&job_dir(&root, &running.id), &running)?;
+
+        let jobs = list_recent_jobs(&root, 10)?;
+        let states: Vec<_> = jobs.iter().map(|job| (job.kind, job.state)).collect();
+        assert_eq!(
+            states,
+            vec![
+                (JobKind::Reindex, JobState::Running),
+                (JobKind::Index, JobState::Completed),
+            ]
+        );
+        assert_eq!(jobs.get(1).map(|job| job.duration_ms), Some(1_500));
+        assert_eq!(list_recent_jobs(&root, 1)?.len(), 1);
+
+        std::fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+
     #[test]
     fn stage_from_phase_maps_publish_telemetry() {
         assert_eq!(
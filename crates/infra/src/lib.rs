@@ -13,6 +13,10 @@ mod cli_local;
 mod cli_manifest;
 /// Config loading helpers used by CLI surfaces.
 mod config_check;
+/// Setup diagnostics for the `doctor` command.
+mod doctor;
+/// Single-text embedding probe for debugging.
+mod embed_probe;
 /// Embedding adapter selection helpers.
 mod embedding_factory;
 /// Embedding routing helpers.
@@ -25,6 +29,8 @@ mod index_smoke;
 mod infra_api;
 /// Background job helpers.
 mod jobs;
+/// Process-wide user-supplied request attributes.
+mod request_attributes;
 /// Request validation helpers used by CLI surfaces.
 mod request_check;
 /// Storage estimation and headroom preflight helpers.
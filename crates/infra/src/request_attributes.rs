@@ -0,0 +1,140 @@
+//! Process-wide user-supplied request attributes.
+//!
+//! CLI surfaces install attributes once at startup (e.g. from `--attr`); every
+//! request context created by local commands then carries them so the scoped
+//! logger and telemetry include them on each event. Read-only mode (e.g. from
+//! `--read-only`) and the structured logger level (e.g. from `-v`/`--quiet`)
+//! are installed the same way.
+
+use crate::InfraResult;
+use semantic_code_ports::LogLevel;
+use semantic_code_shared::{RandomSeed, RequestAttributes, RequestContext};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static REQUEST_ATTRIBUTES: OnceLock<RequestAttributes> = OnceLock::new();
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+static LOG_LEVEL: OnceLock<LogLevel> = OnceLock::new();
+
+/// Install attributes attached to every subsequent request context.
+///
+/// Only the first call takes effect; returns `false` when attributes were
+/// already installed.
+pub fn install_request_attributes(attributes: RequestAttributes) -> bool {
+    REQUEST_ATTRIBUTES.set(attributes).is_ok()
+}
+
+/// Forbid mutations in every subsequent request context of this process.
+pub fn install_read_only_mode() {
+    READ_ONLY.store(true, Ordering::Relaxed);
+}
+
+/// Install the minimum structured log level, taking precedence over `SCA_LOG_LEVEL`.
+///
+/// Only the first call takes effect; returns `false` when a level was
+/// already installed.
+pub fn install_log_level(level: LogLevel) -> bool {
+    LOG_LEVEL.set(level).is_ok()
+}
+
+/// The log level installed by [`install_log_level`], if any.
+pub fn installed_log_level() -> Option<LogLevel> {
+    LOG_LEVEL.get().copied()
+}
+
+/// Return a `read_only` error when read-only mode is installed.
+///
+/// For writes that happen outside a use case (config, manifest).
+pub fn ensure_writable(operation: &'static str) -> InfraResult<()> {
+    new_request_context().ensure_writable(operation)
+}
+
+/// Create a fresh request context carrying the installed attributes.
+pub fn new_request_context() -> RequestContext {
+    let ctx = RequestContext::new_request().with_read_only(READ_ONLY.load(Ordering::Relaxed));
+    match REQUEST_ATTRIBUTES.get() {
+        Some(attributes) if !attributes.is_empty() => ctx.with_attributes(attributes.clone()),
+        _ => ctx,
+    }
+}
+
+/// Create a request context carrying the installed attributes and, when
+/// `core.seed` is configured, a deterministic random seed.
+pub fn new_seeded_request_context(seed: Option<u64>) -> RequestContext {
+    let ctx = new_request_context();
+    match seed {
+        Some(seed) => ctx.with_seed(RandomSeed::new(seed)),
+        None => ctx,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli_local::scope_telemetry;
+    use semantic_code_ports::{TelemetryPort, TelemetryTags, TelemetryTimer};
+    use semantic_code_shared::REDACTED;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct RecordingTelemetry {
+        events: Mutex<Vec<(String, TelemetryTags)>>,
+    }
+
+    impl TelemetryPort for RecordingTelemetry {
+        fn increment_counter(&self, name: &str, _value: u64, tags: Option<&TelemetryTags>) {
+            if let Ok(mut events) = self.events.lock() {
+                events.push((name.to_owned(), tags.cloned().unwrap_or_default()));
+            }
+        }
+
+        fn record_timer_ms(&self, name: &str, _duration_ms: u64, tags: Option<&TelemetryTags>) {
+            self.increment_counter(name, 0, tags);
+        }
+
+        fn start_timer(
+            &self,
+            _name: &str,
+            _tags: Option<&TelemetryTags>,
+        ) -> Box<dyn TelemetryTimer> {
+            Box::new(NoopTimer)
+        }
+    }
+
+    struct NoopTimer;
+
+    impl TelemetryTimer for NoopTimer {
+        fn stop(&self) {}
+    }
+
+    #[test]
+    fn telemetry_events_carry_redacted_request_attributes() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut attributes = RequestAttributes::new();
+        attributes.insert("ticket".to_string(), "ENG-42".to_string());
+        attributes.insert("auth_token".to_string(), "sk-secret".to_string());
+        let ctx = RequestContext::new_request().with_attributes(attributes);
+
+        let recorder = Arc::new(RecordingTelemetry::default());
+        let inner: Arc<dyn TelemetryPort> = recorder.clone();
+        let scoped = scope_telemetry(Some(&inner), &ctx)
+            .ok_or_else(|| std::io::Error::other("missing scoped telemetry"))?;
+        scoped.increment_counter("test.counter", 1, None);
+
+        let events = recorder
+            .events
+            .lock()
+            .map_err(|_| std::io::Error::other("telemetry lock poisoned"))?;
+        let (name, tags) = events
+            .first()
+            .ok_or_else(|| std::io::Error::other("no telemetry event recorded"))?;
+        assert_eq!(name, "test.counter");
+        assert_eq!(tags.get("ticket").map(AsRef::as_ref), Some("ENG-42"));
+        assert_eq!(tags.get("auth_token").map(AsRef::as_ref), Some(REDACTED));
+        assert_eq!(
+            tags.get("correlationId").map(AsRef::as_ref),
+            Some(ctx.correlation_id().as_str())
+        );
+        Ok(())
+    }
+}
@@ -1,14 +1,16 @@
 //! Request validation helpers for CLI surfaces.
 
 use crate::InfraResult;
+use crate::cli_local::search_request_limits_local;
 use semantic_code_config::{
     ClearIndexRequestDto, IndexRequestDto, ReindexByChangeRequestDto, SearchRequestDto,
     validate_clear_index_request, validate_index_request, validate_reindex_by_change_request,
-    validate_search_request,
+    validate_search_request_with_limits,
 };
 use semantic_code_shared::{ErrorCode, ErrorEnvelope};
 use serde::de::DeserializeOwned;
 use std::fmt;
+use std::path::Path;
 
 /// Supported request kinds for validation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,6 +45,9 @@ impl fmt::Display for RequestKind {
 }
 
 /// Validate a request payload provided as JSON for the given kind.
+///
+/// Search requests are checked against the limits of the effective config for
+/// their `codebaseRoot`.
 pub fn validate_request_json(kind: RequestKind, input_json: &str) -> InfraResult<()> {
     match kind {
         RequestKind::Index => {
@@ -51,7 +56,8 @@ pub fn validate_request_json(kind: RequestKind, input_json: &str) -> InfraResult
         },
         RequestKind::Search => {
             let dto: SearchRequestDto = parse_request_json("search", input_json)?;
-            let _ = validate_search_request(&dto)?;
+            let limits = search_request_limits_local(None, None, Path::new(&dto.codebase_root))?;
+            let _ = validate_search_request_with_limits(&dto, limits)?;
         },
         RequestKind::ReindexByChange => {
             let dto: ReindexByChangeRequestDto = parse_request_json("reindexByChange", input_json)?;
@@ -4,13 +4,12 @@ use crate::InfraResult;
 use crate::cli_manifest::config_path as context_config_path;
 use semantic_code_adapters::IgnoreMatcher;
 use semantic_code_config::ValidatedBackendConfig;
-use semantic_code_domain::IndexMode;
+use semantic_code_domain::{CONTEXT_IGNORE_FILE, IndexMode, ignore_layers};
 use semantic_code_ports::{IgnoreMatchInput, IgnorePort};
 use semantic_code_shared::{ErrorCode, ErrorEnvelope};
 use std::collections::{HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 
-const CONTEXT_IGNORE_FILE: &str = ".contextignore";
 const CHUNK_UTILIZATION_NUM: u64 = 88;
 const CHUNK_UTILIZATION_DEN: u64 = 100;
 const METADATA_BYTES_PER_CHUNK: u64 = 1_000;
@@ -236,7 +235,7 @@ fn estimate_dimension_bounds(config: &ValidatedBackendConfig) -> (u32, u32) {
     match provider.as_str() {
         "test" => (8, 8),
         "onnx" | "local" => (384, 384),
-        "openai" => (1536, 1536),
+        "openai" | "azure_openai" | "azure-openai" | "azure" => (1536, 1536),
         "voyage" | "voyageai" => (1024, 1536),
         "gemini" | "ollama" => (768, 1536),
         _ => (384, 1536),
@@ -264,7 +263,7 @@ fn scan_indexable_files(
     let max_file_size_bytes = config.sync.max_file_size_bytes;
     let supported_extensions = normalize_extensions(&config.sync.allowed_extensions);
     let filter_by_extension = !supported_extensions.is_empty();
-    let ignore_patterns = load_ignore_patterns(codebase_root, &config.sync.ignore_patterns)?;
+    let ignore_layers = load_ignore_layers(codebase_root, config)?;
     let matcher = IgnoreMatcher::new();
 
     let mut dirs = VecDeque::from([String::from(".")]);
@@ -288,7 +287,7 @@ fn scan_indexable_files(
             };
             let name = entry.file_name().to_string_lossy().to_string();
             let relative_path = join_relative(&dir, &name);
-            if is_ignored(matcher, &ignore_patterns, &relative_path) {
+            if is_ignored(matcher, &ignore_layers, &relative_path) {
                 continue;
             }
 
@@ -352,29 +351,21 @@ fn scan_indexable_files(
     Ok(stats)
 }
 
-fn load_ignore_patterns(
+fn load_ignore_layers(
     codebase_root: &Path,
-    base_patterns: &[Box<str>],
-) -> InfraResult<Vec<Box<str>>> {
-    let mut patterns = base_patterns.to_vec();
-    patterns.push(CONTEXT_IGNORE_FILE.into());
+    config: &ValidatedBackendConfig,
+) -> InfraResult<Vec<Vec<Box<str>>>> {
     let context_ignore_path = codebase_root.join(CONTEXT_IGNORE_FILE);
-    if context_ignore_path.is_file() {
-        let contents = std::fs::read_to_string(context_ignore_path).map_err(ErrorEnvelope::from)?;
-        patterns.extend(parse_context_ignore(&contents));
-    }
-    patterns.sort();
-    patterns.dedup();
-    Ok(patterns)
-}
-
-fn parse_context_ignore(contents: &str) -> Vec<Box<str>> {
-    contents
-        .lines()
-        .map(str::trim)
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
-        .map(|line| line.to_owned().into_boxed_str())
-        .collect()
+    let context_ignore = if context_ignore_path.is_file() {
+        Some(std::fs::read_to_string(context_ignore_path).map_err(ErrorEnvelope::from)?)
+    } else {
+        None
+    };
+    Ok(ignore_layers(
+        &config.sync.ignore_patterns,
+        context_ignore.as_deref(),
+        &config.sync.additional_ignore_patterns,
+    ))
 }
 
 fn normalize_extensions(values: &[Box<str>]) -> HashSet<String> {
@@ -406,9 +397,13 @@ fn join_relative(parent: &str, child: &str) -> String {
     }
 }
 
-fn is_ignored(matcher: IgnoreMatcher, ignore_patterns: &[Box<str>], relative_path: &str) -> bool {
+fn is_ignored(
+    matcher: IgnoreMatcher,
+    ignore_layers: &[Vec<Box<str>>],
+    relative_path: &str,
+) -> bool {
     matcher.is_ignored(&IgnoreMatchInput {
-        ignore_patterns: ignore_patterns.to_vec(),
+        ignore_layers: ignore_layers.to_vec(),
         relative_path: relative_path.to_owned().into_boxed_str(),
     })
 }
@@ -132,6 +132,7 @@ pub async fn build_vectordb_port(
             )
             .storage_mode(snapshot_storage)
             .snapshot_format(config.vector_db.snapshot_format)
+            .snapshot_gzip(config.vector_db.snapshot_gzip)
             .force_reindex_on_kernel_change(config.vector_db.force_reindex_on_kernel_change)
             .search_strategy(search_strategy)
             .dfrr_prewarm_requests(dfrr_prewarm_requests);
@@ -659,6 +660,8 @@ async fn build_milvus_grpc(config: &ValidatedBackendConfig) -> InfraResult<Arc<d
         timeout_ms: config.vector_db.timeout_ms,
         index_timeout_ms: config.vector_db.index_timeout_ms,
         index_config,
+        keep_alive_ms: config.vector_db.grpc.keep_alive_ms,
+        max_channels: config.vector_db.grpc.max_channels,
     })
     .await
     .map_err(|error| enrich_milvus_connection_error(error, &address_for_error))?;
@@ -702,6 +705,7 @@ fn build_milvus_rest(config: &ValidatedBackendConfig) -> InfraResult<Arc<dyn Vec
             .as_deref()
             .map(|value| value.to_owned().into_boxed_str()),
         timeout_ms: config.vector_db.timeout_ms,
+        index_timeout_ms: config.vector_db.index_timeout_ms,
         index_config,
     })
     .map_err(|error| enrich_milvus_connection_error(error, &address_for_error))?;
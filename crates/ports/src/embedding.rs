@@ -109,6 +109,8 @@ pub struct EmbeddingProviderInfo {
     pub id: EmbeddingProviderId,
     /// Human-readable provider name.
     pub name: Box<str>,
+    /// Longest input (in chars) the provider embeds without truncation, when known.
+    pub max_input_chars: Option<u32>,
 }
 
 /// Options for dimension detection.
@@ -181,7 +183,26 @@ impl From<Vec<String>> for EmbedBatchRequest {
     }
 }
 
+/// An embedding tagged with the position of the input text it was produced from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedEmbedding {
+    /// Index of the source text within [`EmbedBatchRequest::texts`].
+    pub index: usize,
+    /// Embedding for that text.
+    pub vector: EmbeddingVector,
+}
+
 /// Boundary contract for embedding generation.
+///
+/// # Ordering invariant
+///
+/// [`EmbeddingPort::embed_batch`] must return exactly one vector per input
+/// text, positionally aligned with [`EmbedBatchRequest::texts`]: the vector at
+/// index `i` embeds `texts[i]`. Adapters that complete work out of order
+/// (e.g. internal parallel sub-batches) should override
+/// [`EmbeddingPort::embed_batch_indexed`] to report the source index of each
+/// vector; the infra embedding factory wraps every adapter in a guard that
+/// restores input order from those indices.
 pub trait EmbeddingPort: Send + Sync {
     /// Provider info for this implementation.
     fn provider(&self) -> &EmbeddingProviderInfo;
@@ -201,11 +222,33 @@ pub trait EmbeddingPort: Send + Sync {
     ) -> BoxFuture<'_, Result<EmbeddingVector>>;
 
     /// Embed multiple texts in a batch.
+    ///
+    /// Output is positionally aligned with `request.texts` (see the trait docs).
     fn embed_batch(
         &self,
         ctx: &RequestContext,
         request: EmbedBatchRequest,
     ) -> BoxFuture<'_, Result<Vec<EmbeddingVector>>>;
+
+    /// Embed multiple texts, tagging each vector with its source input index.
+    ///
+    /// Results may be returned in any order. The default implementation tags
+    /// [`EmbeddingPort::embed_batch`] output by position.
+    fn embed_batch_indexed(
+        &self,
+        ctx: &RequestContext,
+        request: EmbedBatchRequest,
+    ) -> BoxFuture<'_, Result<Vec<IndexedEmbedding>>> {
+        let future = self.embed_batch(ctx, request);
+        Box::pin(async move {
+            let vectors = future.await?;
+            Ok(vectors
+                .into_iter()
+                .enumerate()
+                .map(|(index, vector)| IndexedEmbedding { index, vector })
+                .collect())
+        })
+    }
 }
 
 mod sealed {
@@ -63,6 +63,17 @@ impl<'a> FileSystemSession<'a> {
             .read_file_text(ctx, self.codebase_root.clone(), file)
     }
 
+    /// Read up to `max_bytes` leading bytes of a file relative to the codebase root.
+    pub fn read_file_head(
+        &self,
+        ctx: &RequestContext,
+        file: SafeRelativePath,
+        max_bytes: usize,
+    ) -> BoxFuture<'_, Result<Vec<u8>>> {
+        self.fs
+            .read_file_head(ctx, self.codebase_root.clone(), file, max_bytes)
+    }
+
     /// Read file metadata relative to the codebase root.
     pub fn stat(
         &self,
@@ -71,6 +82,15 @@ impl<'a> FileSystemSession<'a> {
     ) -> BoxFuture<'_, Result<FileSystemStat>> {
         self.fs.stat(ctx, self.codebase_root.clone(), path)
     }
+
+    /// Check whether a path exists relative to the codebase root.
+    pub fn exists(
+        &self,
+        ctx: &RequestContext,
+        path: SafeRelativePath,
+    ) -> BoxFuture<'_, Result<bool>> {
+        self.fs.exists(ctx, self.codebase_root.clone(), path)
+    }
 }
 
 /// Extension helpers for filesystem ports.
@@ -94,6 +114,12 @@ impl FileSystemPortExt for Arc<dyn FileSystemPort> {
     }
 }
 
+/// Error code for a followed symlink that resolves outside the codebase root.
+#[must_use]
+pub fn symlink_escape_code() -> ErrorCode {
+    ErrorCode::new("sync", "symlink_escape")
+}
+
 fn normalize_relative_path(input: &str) -> Result<String> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -187,6 +213,9 @@ pub struct FileSystemStat {
 /// Note: `codebase_root` is an absolute path owned by the caller/infra composition.
 pub trait FileSystemPort: Send + Sync {
     /// Read and list directory entries.
+    ///
+    /// Adapters that follow symlinks fail with [`symlink_escape_code`] for a
+    /// link resolving outside `codebase_root`.
     fn read_dir(
         &self,
         ctx: &RequestContext,
@@ -202,6 +231,25 @@ pub trait FileSystemPort: Send + Sync {
         file: SafeRelativePath,
     ) -> BoxFuture<'_, Result<Box<str>>>;
 
+    /// Read up to `max_bytes` leading bytes of a file, without UTF-8 decoding.
+    ///
+    /// The default truncates [`Self::read_file_text`], so it cannot observe
+    /// invalid UTF-8; adapters backed by real files should read raw bytes.
+    fn read_file_head(
+        &self,
+        ctx: &RequestContext,
+        codebase_root: PathBuf,
+        file: SafeRelativePath,
+        max_bytes: usize,
+    ) -> BoxFuture<'_, Result<Vec<u8>>> {
+        let text = self.read_file_text(ctx, codebase_root, file);
+        Box::pin(async move {
+            let text = text.await?;
+            let bytes = text.as_bytes();
+            Ok(bytes.get(..max_bytes).unwrap_or(bytes).to_vec())
+        })
+    }
+
     /// Read file metadata (kind/size/mtime).
     fn stat(
         &self,
@@ -209,4 +257,24 @@ pub trait FileSystemPort: Send + Sync {
         codebase_root: PathBuf,
         path: SafeRelativePath,
     ) -> BoxFuture<'_, Result<FileSystemStat>>;
+
+    /// Check whether a path exists without reading its contents.
+    ///
+    /// The default derives the answer from [`Self::stat`], mapping
+    /// `not_found` to `false`; other errors are propagated.
+    fn exists(
+        &self,
+        ctx: &RequestContext,
+        codebase_root: PathBuf,
+        path: SafeRelativePath,
+    ) -> BoxFuture<'_, Result<bool>> {
+        let stat = self.stat(ctx, codebase_root, path);
+        Box::pin(async move {
+            match stat.await {
+                Ok(_) => Ok(true),
+                Err(error) if error.code == ErrorCode::not_found() => Ok(false),
+                Err(error) => Err(error),
+            }
+        })
+    }
 }
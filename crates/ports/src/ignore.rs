@@ -3,8 +3,12 @@
 /// Input to ignore matching.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IgnoreMatchInput {
-    /// Normalized ignore patterns (Phase 03 rules).
-    pub ignore_patterns: Vec<Box<str>>,
+    /// Normalized ignore pattern layers, lowest precedence first.
+    ///
+    /// A `!`-prefixed pattern re-includes matching paths. Within a layer,
+    /// negation wins over exclusion regardless of order; across layers, the
+    /// highest layer with any matching pattern decides.
+    pub ignore_layers: Vec<Vec<Box<str>>>,
     /// Candidate path relative to codebase root (normalized separators).
     pub relative_path: Box<str>,
 }
@@ -34,10 +34,11 @@ pub use calibration::CalibrationPort;
 pub use embedding::{
     DetectDimensionOptions, DetectDimensionRequest, EmbedBatchRequest, EmbedRequest, EmbeddingPort,
     EmbeddingPortLend, EmbeddingProviderInfo, EmbeddingVector, EmbeddingVectorFixed,
+    IndexedEmbedding,
 };
 pub use filesystem::{
     FileSystemDirEntry, FileSystemEntryKind, FileSystemPort, FileSystemPortExt, FileSystemSession,
-    FileSystemStat, PathPolicyPort, SafeRelativePath,
+    FileSystemStat, PathPolicyPort, SafeRelativePath, symlink_escape_code,
 };
 pub use ignore::{IgnoreMatchInput, IgnorePort};
 pub use logger::{LogEvent, LogFields, LogLevel, LoggerPort};
@@ -46,9 +47,11 @@ pub use sync::{FileChangeSet, FileSyncInitOptions, FileSyncOptions, FileSyncPort
 pub use telemetry::{TelemetryPort, TelemetryTags, TelemetryTimer};
 pub use vectordb::{
     HybridSearchBatchRequest, HybridSearchData, HybridSearchOptions, HybridSearchRequest,
-    HybridSearchResult, RerankStrategy, RerankStrategyKind, VectorDbPort, VectorDbPortLend,
-    VectorDbProviderInfo, VectorDbRow, VectorDocument, VectorDocumentForInsert,
-    VectorSearchOptions, VectorSearchRequest, VectorSearchResponse, VectorSearchResult,
+    HybridSearchResult, InsertOutcome, PageToken, QueryPage, RerankStrategy, RerankStrategyKind,
+    VectorDbPort, VectorDbPortLend, VectorDbProviderInfo, VectorDbRow, VectorDocument,
+    VectorDocumentForInsert, VectorSearchOptions, VectorSearchRequest, VectorSearchResponse,
+    VectorSearchResult, insert_outcomes_from_batch, sort_hybrid_score_ties_by_id,
+    sort_search_score_ties_by_id,
 };
 
 // Re-export selected domain types used in port signatures, so adapter crates
@@ -5,6 +5,8 @@ use std::collections::BTreeMap;
 /// Log level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogLevel {
+    /// Trace.
+    Trace,
     /// Debug.
     Debug,
     /// Info.
@@ -41,6 +43,17 @@ pub trait LoggerPort: Send + Sync {
     /// Create a child logger with base fields applied to every event.
     fn child(&self, fields: LogFields) -> Box<dyn LoggerPort>;
 
+    /// Convenience: trace event.
+    fn trace(&self, event: &str, message: &str, fields: Option<LogFields>) {
+        self.log(LogEvent {
+            event: event.to_owned().into_boxed_str(),
+            level: LogLevel::Trace,
+            message: message.to_owned().into_boxed_str(),
+            fields,
+            error: None,
+        });
+    }
+
     /// Convenience: debug event.
     fn debug(&self, event: &str, message: &str, fields: Option<LogFields>) {
         self.log(LogEvent {
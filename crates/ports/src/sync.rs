@@ -22,6 +22,9 @@ pub struct FileSyncInitOptions {
     pub codebase_root: PathBuf,
     /// Ignore patterns applied before file access.
     pub ignore_patterns: Option<Vec<Box<str>>>,
+    /// Highest-precedence ignore patterns, layered above `ignore_patterns`
+    /// and `.contextignore`.
+    pub additional_ignore_patterns: Vec<Box<str>>,
 }
 
 /// Options for sync operations.
@@ -44,6 +47,16 @@ pub trait FileSyncPort: Send + Sync {
         options: FileSyncOptions,
     ) -> BoxFuture<'_, Result<FileChangeSet>>;
 
+    /// Forget the recorded state of `relative_paths` so the next check reports
+    /// them as changed again (modified, or removed when gone from disk).
+    ///
+    /// Used for files that were only partly indexed.
+    fn invalidate_files(
+        &self,
+        ctx: &RequestContext,
+        relative_paths: Vec<Box<str>>,
+    ) -> BoxFuture<'_, Result<()>>;
+
     /// Delete any persisted snapshot state for the codebase root.
     fn delete_snapshot(
         &self,
@@ -1,13 +1,14 @@
 //! Vector DB boundary contract.
 
-use crate::BoxFuture;
+use crate::{BoxFuture, EmbeddingVector};
 use semantic_code_domain::{
-    CollectionName, SearchStats, VectorDbProviderId, VectorDocumentMetadata,
+    CollectionName, SearchExclusions, SearchStats, VectorDbProviderId, VectorDocumentMetadata,
 };
-use semantic_code_shared::{RequestContext, Result};
+use semantic_code_shared::{ErrorCode, ErrorEnvelope, RequestContext, Result};
 use serde_json::Value;
 use std::collections::BTreeMap;
 use std::future::Future;
+use std::num::NonZeroU32;
 use std::sync::Arc;
 
 /// Provider descriptor.
@@ -45,6 +46,82 @@ pub struct VectorDocumentForInsert {
     pub metadata: VectorDocumentMetadata,
 }
 
+/// Per-document result of an insert call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertOutcome {
+    /// The document was stored.
+    Inserted {
+        /// Document identifier.
+        id: Box<str>,
+    },
+    /// The document was rejected; other documents in the batch may still be stored.
+    Failed {
+        /// Document identifier.
+        id: Box<str>,
+        /// Rejection reason.
+        error: ErrorEnvelope,
+    },
+}
+
+impl InsertOutcome {
+    /// Identifier of the document this outcome refers to.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Inserted { id } | Self::Failed { id, .. } => id,
+        }
+    }
+
+    /// Return true when the document was rejected.
+    #[must_use]
+    pub const fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed { .. })
+    }
+}
+
+/// Map an all-or-nothing insert result onto per-document outcomes.
+///
+/// A batch error marks every document as failed; cancellation is returned as an
+/// error so callers stop instead of recording it per document.
+pub fn insert_outcomes_from_batch(
+    ids: Vec<Box<str>>,
+    result: Result<()>,
+) -> Result<Vec<InsertOutcome>> {
+    match result {
+        Ok(()) => Ok(ids
+            .into_iter()
+            .map(|id| InsertOutcome::Inserted { id })
+            .collect()),
+        Err(error) if error.is_cancelled() => Err(error),
+        Err(error) => Ok(ids
+            .into_iter()
+            .map(|id| InsertOutcome::Failed {
+                id,
+                error: error.clone(),
+            })
+            .collect()),
+    }
+}
+
+/// Order runs of equal-score dense results by id ascending.
+///
+/// The backend's ranking is kept as returned, so this holds for metrics that
+/// rank ascending (L2) as well as descending (IP, cosine); only ties are
+/// reordered, matching the local kernel's id tie-break on every backend.
+pub fn sort_search_score_ties_by_id(results: &mut [VectorSearchResult]) {
+    for run in results.chunk_by_mut(|a, b| a.score.total_cmp(&b.score).is_eq()) {
+        run.sort_by(|a, b| a.document.id.cmp(&b.document.id));
+    }
+}
+
+/// Order runs of equal-score hybrid results by id ascending (see
+/// [`sort_search_score_ties_by_id`]).
+pub fn sort_hybrid_score_ties_by_id(results: &mut [HybridSearchResult]) {
+    for run in results.chunk_by_mut(|a, b| a.score.total_cmp(&b.score).is_eq()) {
+        run.sort_by(|a, b| a.document.id.cmp(&b.document.id));
+    }
+}
+
 /// Options for dense vector search.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct VectorSearchOptions {
@@ -52,6 +129,8 @@ pub struct VectorSearchOptions {
     pub top_k: Option<u32>,
     /// Optional provider-specific filter expression.
     pub filter_expr: Option<Box<str>>,
+    /// Paths and languages excluded on top of `filter_expr`.
+    pub exclusions: SearchExclusions,
     /// Optional score threshold.
     pub threshold: Option<f32>,
 }
@@ -134,6 +213,8 @@ pub struct HybridSearchOptions {
     pub limit: Option<u32>,
     /// Optional provider-specific filter expression.
     pub filter_expr: Option<Box<str>>,
+    /// Paths and languages excluded on top of `filter_expr`.
+    pub exclusions: SearchExclusions,
 }
 
 /// Owned hybrid search batch request.
@@ -193,6 +274,28 @@ pub trait VectorDbPort: Send + Sync {
         collection_name: CollectionName,
     ) -> BoxFuture<'_, Result<bool>>;
 
+    /// Return the dense vector dimension of an existing collection.
+    ///
+    /// Default implementation returns `None` so providers can opt in.
+    fn collection_dimension(
+        &self,
+        _ctx: &RequestContext,
+        _collection_name: CollectionName,
+    ) -> BoxFuture<'_, Result<Option<u32>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// Return the number of documents stored in a collection.
+    ///
+    /// Default implementation returns `None` so providers can opt in.
+    fn count(
+        &self,
+        _ctx: &RequestContext,
+        _collection_name: CollectionName,
+    ) -> BoxFuture<'_, Result<Option<u64>>> {
+        Box::pin(async { Ok(None) })
+    }
+
     /// List available collections.
     fn list_collections(&self, ctx: &RequestContext) -> BoxFuture<'_, Result<Vec<CollectionName>>>;
 
@@ -212,6 +315,43 @@ pub trait VectorDbPort: Send + Sync {
         documents: Vec<VectorDocumentForInsert>,
     ) -> BoxFuture<'_, Result<()>>;
 
+    /// Insert documents into a dense collection, reporting per-document outcomes.
+    ///
+    /// Default implementation delegates to [`VectorDbPort::insert`] and maps a
+    /// batch error to every document failing. Providers that can identify
+    /// rejected rows should override this.
+    fn insert_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        let ids = documents
+            .iter()
+            .map(|document| document.id.clone())
+            .collect();
+        let insert = self.insert(ctx, collection_name, documents);
+        Box::pin(async move { insert_outcomes_from_batch(ids, insert.await) })
+    }
+
+    /// Insert documents into a hybrid collection, reporting per-document outcomes.
+    ///
+    /// Default implementation delegates to [`VectorDbPort::insert_hybrid`] and
+    /// maps a batch error to every document failing.
+    fn insert_hybrid_with_outcomes(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        documents: Vec<VectorDocumentForInsert>,
+    ) -> BoxFuture<'_, Result<Vec<InsertOutcome>>> {
+        let ids = documents
+            .iter()
+            .map(|document| document.id.clone())
+            .collect();
+        let insert = self.insert_hybrid(ctx, collection_name, documents);
+        Box::pin(async move { insert_outcomes_from_batch(ids, insert.await) })
+    }
+
     /// Ensure writes are durably visible for subsequent reads.
     ///
     /// Default implementation is a no-op so providers can opt in.
@@ -254,6 +394,101 @@ pub trait VectorDbPort: Send + Sync {
         output_fields: Vec<Box<str>>,
         limit: Option<u32>,
     ) -> BoxFuture<'_, Result<Vec<VectorDbRow>>>;
+
+    /// Query one page of documents, returning the rows and a token for the next
+    /// page (`None` once the scan is exhausted).
+    ///
+    /// Pass `page_token: None` to start a scan and feed each returned token back
+    /// to continue it. Default implementation emulates paging by re-running
+    /// [`VectorDbPort::query`] with a growing limit and skipping earlier rows,
+    /// so it assumes a stable row order and costs quadratic work; providers
+    /// with native offsets or cursors should override it.
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> BoxFuture<'_, Result<QueryPage>> {
+        let offset = match page_token.as_ref().map(PageToken::offset).transpose() {
+            Ok(offset) => offset.unwrap_or(0),
+            Err(error) => return Box::pin(async move { Err(error) }),
+        };
+        let limit = offset
+            .saturating_add(u64::from(page_size.get()))
+            .saturating_add(1);
+        let query = self.query(
+            ctx,
+            collection_name,
+            filter,
+            output_fields,
+            Some(u32::try_from(limit).unwrap_or(u32::MAX)),
+        );
+        Box::pin(async move {
+            let rows = query.await?;
+            Ok(page_from_rows(rows, offset, page_size))
+        })
+    }
+
+    /// Return the stored dense vector for a document id, or `None` when absent.
+    ///
+    /// Default implementation runs an `id == "<id>"` [`VectorDbPort::query`]
+    /// with the `vector` output field; providers with direct record access
+    /// should override it.
+    fn get_vector(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        id: Box<str>,
+    ) -> BoxFuture<'_, Result<Option<EmbeddingVector>>> {
+        let escaped = id.replace('\\', "\\\\").replace('"', "\\\"");
+        let query = self.query(
+            ctx,
+            collection_name,
+            format!("id == \"{escaped}\"").into_boxed_str(),
+            vec!["id".into(), "vector".into()],
+            Some(1),
+        );
+        Box::pin(async move {
+            let rows = query.await?;
+            Ok(rows
+                .into_iter()
+                .find(|row| row.get("id").and_then(Value::as_str) == Some(id.as_ref()))
+                .and_then(|mut row| embedding_vector_from_value(row.remove("vector")?)))
+        })
+    }
+}
+
+/// Slice the rows at `offset..offset + page_size` out of a prefix scan.
+fn page_from_rows(rows: Vec<VectorDbRow>, offset: u64, page_size: NonZeroU32) -> QueryPage {
+    let skip = usize::try_from(offset).unwrap_or(usize::MAX);
+    let take = page_size.get() as usize;
+    let has_more = rows.len() > skip.saturating_add(take);
+    let rows = rows.into_iter().skip(skip).take(take).collect::<Vec<_>>();
+    let next = has_more.then(|| PageToken::from_offset(offset.saturating_add(rows.len() as u64)));
+    (rows, next)
+}
+
+/// Parse a JSON number array (a `vector` output field) into an embedding vector.
+fn embedding_vector_from_value(value: Value) -> Option<EmbeddingVector> {
+    let Value::Array(values) = value else {
+        return None;
+    };
+    values
+        .iter()
+        .map(json_f32)
+        .collect::<Option<Vec<f32>>>()
+        .map(EmbeddingVector::from_vec)
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "vector components were f32 before JSON widened them to f64"
+)]
+fn json_f32(value: &Value) -> Option<f32> {
+    value.as_f64().map(|value| value as f32)
 }
 
 mod sealed {
@@ -306,6 +541,20 @@ pub trait VectorDbPortLend: sealed::Sealed + Send + Sync {
         collection_name: CollectionName,
     ) -> Self::Future<'_, bool>;
 
+    /// Return the dense vector dimension of an existing collection.
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> Self::Future<'_, Option<u32>>;
+
+    /// Return the number of documents stored in a collection.
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> Self::Future<'_, Option<u64>>;
+
     /// List available collections.
     fn list_collections(&self, ctx: &RequestContext) -> Self::Future<'_, Vec<CollectionName>>;
 
@@ -359,6 +608,25 @@ pub trait VectorDbPortLend: sealed::Sealed + Send + Sync {
         output_fields: Vec<Box<str>>,
         limit: Option<u32>,
     ) -> Self::Future<'_, Vec<VectorDbRow>>;
+
+    /// Query one page of documents and the token for the next page.
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> Self::Future<'_, QueryPage>;
+
+    /// Return the stored dense vector for a document id, or `None` when absent.
+    fn get_vector(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        id: Box<str>,
+    ) -> Self::Future<'_, Option<EmbeddingVector>>;
 }
 
 impl<T> sealed::Sealed for T where T: VectorDbPort + ?Sized {}
@@ -413,6 +681,22 @@ where
         VectorDbPort::has_collection(self, ctx, collection_name)
     }
 
+    fn collection_dimension(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> Self::Future<'_, Option<u32>> {
+        VectorDbPort::collection_dimension(self, ctx, collection_name)
+    }
+
+    fn count(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+    ) -> Self::Future<'_, Option<u64>> {
+        VectorDbPort::count(self, ctx, collection_name)
+    }
+
     fn list_collections(&self, ctx: &RequestContext) -> Self::Future<'_, Vec<CollectionName>> {
         VectorDbPort::list_collections(self, ctx)
     }
@@ -474,7 +758,74 @@ where
     ) -> Self::Future<'_, Vec<VectorDbRow>> {
         VectorDbPort::query(self, ctx, collection_name, filter, output_fields, limit)
     }
+
+    fn query_paged(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        filter: Box<str>,
+        output_fields: Vec<Box<str>>,
+        page_size: NonZeroU32,
+        page_token: Option<PageToken>,
+    ) -> Self::Future<'_, QueryPage> {
+        VectorDbPort::query_paged(
+            self,
+            ctx,
+            collection_name,
+            filter,
+            output_fields,
+            page_size,
+            page_token,
+        )
+    }
+
+    fn get_vector(
+        &self,
+        ctx: &RequestContext,
+        collection_name: CollectionName,
+        id: Box<str>,
+    ) -> Self::Future<'_, Option<EmbeddingVector>> {
+        VectorDbPort::get_vector(self, ctx, collection_name, id)
+    }
 }
 
 /// A row returned from a vector DB query.
 pub type VectorDbRow = BTreeMap<Box<str>, Value>;
+
+/// One page of [`VectorDbPort::query_paged`] rows plus the next-page token.
+pub type QueryPage = (Vec<VectorDbRow>, Option<PageToken>);
+
+/// Opaque continuation token for [`VectorDbPort::query_paged`].
+///
+/// Tokens are only meaningful to the provider that issued them; the local
+/// adapter encodes a row offset and the Milvus adapters the last row id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PageToken(Box<str>);
+
+impl PageToken {
+    /// Wrap a provider-issued token.
+    #[must_use]
+    pub fn new(token: impl Into<Box<str>>) -> Self {
+        Self(token.into())
+    }
+
+    /// Build an offset-based token.
+    #[must_use]
+    pub fn from_offset(offset: u64) -> Self {
+        Self(offset.to_string().into_boxed_str())
+    }
+
+    /// Raw token value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Decode an offset-based token.
+    pub fn offset(&self) -> Result<u64> {
+        self.0.parse().map_err(|_| {
+            ErrorEnvelope::expected(ErrorCode::invalid_input(), "invalid query page token")
+                .with_metadata("page_token", self.0.as_ref())
+        })
+    }
+}
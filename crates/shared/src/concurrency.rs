@@ -11,13 +11,14 @@
 //! - Cancellation is "best-effort": work that has not started is cancelled; in-flight
 //!   work may complete unless the task itself cooperates.
 
-use crate::{ErrorCode, ErrorEnvelope, Result};
-use std::collections::VecDeque;
+use crate::{ErrorCode, ErrorEnvelope, Result, RetryBudget, redact_if_secret};
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use tokio::sync::{Mutex, oneshot};
 use tokio_util::sync::{
     CancellationToken as TokioCancellationToken, DropGuard as TokioCancellationDropGuard,
@@ -76,16 +77,55 @@ fn next_scoped_id(counter: &AtomicU64, prefix: &'static str) -> CorrelationId {
     CorrelationId(Arc::<str>::from(id))
 }
 
+/// Why a [`CancellationToken`] was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CancelReason {
+    /// A caller asked for cancellation (e.g. `cancel()` or a cancel file).
+    UserRequested,
+    /// A deadline expired.
+    Timeout,
+    /// A shared budget (e.g. retries) ran out.
+    BudgetExhausted,
+    /// A parent token was cancelled.
+    Parent,
+}
+
+impl CancelReason {
+    /// Stable identifier used in error metadata.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UserRequested => "user_requested",
+            Self::Timeout => "timeout",
+            Self::BudgetExhausted => "budget_exhausted",
+            Self::Parent => "parent",
+        }
+    }
+}
+
+impl fmt::Display for CancelReason {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
 /// A clonable cancellation token that can be awaited.
+///
+/// The first reason passed to [`Self::cancel_with_reason`] wins; tokens
+/// cancelled without one (a parent, or a dropped guard) report a fallback.
 #[derive(Debug, Clone)]
 pub struct CancellationToken {
     inner: TokioCancellationToken,
+    reason: Arc<OnceLock<CancelReason>>,
+    fallback: CancelReason,
 }
 
 /// Owned drop guard that cancels the token on drop unless disarmed.
 #[derive(Debug)]
 pub struct CancellationDropGuard {
     inner: TokioCancellationDropGuard,
+    reason: Arc<OnceLock<CancelReason>>,
+    fallback: CancelReason,
 }
 
 impl CancellationDropGuard {
@@ -94,6 +134,8 @@ impl CancellationDropGuard {
     pub fn disarm(self) -> CancellationToken {
         CancellationToken {
             inner: self.inner.disarm(),
+            reason: self.reason,
+            fallback: self.fallback,
         }
     }
 }
@@ -104,6 +146,8 @@ impl CancellationToken {
     pub fn new() -> Self {
         Self {
             inner: TokioCancellationToken::new(),
+            reason: Arc::new(OnceLock::new()),
+            fallback: CancelReason::UserRequested,
         }
     }
 
@@ -112,11 +156,19 @@ impl CancellationToken {
     pub fn child_token(&self) -> Self {
         Self {
             inner: self.inner.child_token(),
+            reason: Arc::new(OnceLock::new()),
+            fallback: CancelReason::Parent,
         }
     }
 
     /// Cancel the token and wake all current/future waiters.
     pub fn cancel(&self) {
+        self.cancel_with_reason(CancelReason::UserRequested);
+    }
+
+    /// Cancel the token, recording `reason` unless one was already recorded.
+    pub fn cancel_with_reason(&self, reason: CancelReason) {
+        let _ = self.reason.set(reason);
         self.inner.cancel();
     }
 
@@ -126,6 +178,13 @@ impl CancellationToken {
         self.inner.is_cancelled()
     }
 
+    /// Return why the token was cancelled, or `None` while it is still live.
+    #[must_use]
+    pub fn reason(&self) -> Option<CancelReason> {
+        self.is_cancelled()
+            .then(|| self.reason.get().copied().unwrap_or(self.fallback))
+    }
+
     /// Wait until the token is cancelled.
     pub async fn cancelled(&self) {
         self.inner.cancelled().await;
@@ -136,6 +195,8 @@ impl CancellationToken {
     pub fn drop_guard(self) -> CancellationDropGuard {
         CancellationDropGuard {
             inner: self.inner.drop_guard(),
+            reason: self.reason,
+            fallback: self.fallback,
         }
     }
 
@@ -162,11 +223,50 @@ impl Default for CancellationToken {
     }
 }
 
+/// User-supplied metadata carried by a [`RequestContext`].
+pub type RequestAttributes = BTreeMap<String, String>;
+
+/// Seed for randomized behavior (e.g. retry jitter).
+///
+/// Production leaves the seed unset so randomness comes from the clock; tests
+/// and reproductions set one to make every random choice deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RandomSeed(u64);
+
+impl RandomSeed {
+    /// Wrap a raw seed value.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Return the raw seed value.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Derive an independent, deterministic value for `stream` (splitmix64).
+    #[must_use]
+    pub const fn derive(self, stream: u64) -> u64 {
+        let mut z = self
+            .0
+            .wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
 /// Request-scoped context passed across boundaries.
 #[derive(Debug, Clone)]
 pub struct RequestContext {
     correlation_id: CorrelationId,
     cancellation: CancellationToken,
+    attributes: RequestAttributes,
+    seed: Option<RandomSeed>,
+    retry_budget: Option<RetryBudget>,
+    read_only: bool,
 }
 
 impl RequestContext {
@@ -176,6 +276,10 @@ impl RequestContext {
         Self {
             correlation_id,
             cancellation: CancellationToken::new(),
+            attributes: RequestAttributes::new(),
+            seed: None,
+            retry_budget: None,
+            read_only: false,
         }
     }
 
@@ -194,7 +298,84 @@ impl RequestContext {
         Self {
             correlation_id,
             cancellation,
+            attributes: RequestAttributes::new(),
+            seed: None,
+            retry_budget: None,
+            read_only: false,
+        }
+    }
+
+    /// Attach user-supplied attributes, replacing any previously set.
+    ///
+    /// Values under secret-looking keys (see [`crate::is_secret_key`]) are
+    /// redacted on insertion so they never reach logs or telemetry.
+    #[must_use]
+    pub fn with_attributes(mut self, attributes: RequestAttributes) -> Self {
+        self.attributes = attributes
+            .into_iter()
+            .map(|(key, value)| {
+                let value = redact_if_secret(&key, &value);
+                (key, value)
+            })
+            .collect();
+        self
+    }
+
+    /// Make randomized behavior under this context deterministic.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: RandomSeed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Return the random seed, if one was set.
+    #[must_use]
+    pub const fn seed(&self) -> Option<RandomSeed> {
+        self.seed
+    }
+
+    /// Cap total retries for every operation run under this context (and its clones).
+    #[must_use]
+    pub fn with_retry_budget(mut self, budget: RetryBudget) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
+    /// Return the shared retry budget, if one was set.
+    #[must_use]
+    pub const fn retry_budget(&self) -> Option<&RetryBudget> {
+        self.retry_budget.as_ref()
+    }
+
+    /// Forbid mutations (index, insert, delete, clear) for operations run under this context.
+    #[must_use]
+    pub const fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Returns true when mutations are forbidden.
+    #[must_use]
+    pub const fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Return a `read_only` error when mutations are forbidden, including operation metadata.
+    pub fn ensure_writable(&self, operation: &'static str) -> Result<()> {
+        if self.read_only {
+            return Err(ErrorEnvelope::expected(
+                ErrorCode::read_only(),
+                "operation not allowed in read-only mode",
+            )
+            .with_metadata("operation", operation));
         }
+        Ok(())
+    }
+
+    /// Return the (redacted) user-supplied attributes.
+    #[must_use]
+    pub const fn attributes(&self) -> &RequestAttributes {
+        &self.attributes
     }
 
     /// Return the correlation id.
@@ -220,6 +401,17 @@ impl RequestContext {
         self.cancellation.cancel();
     }
 
+    /// Cancel this request, recording why.
+    pub fn cancel_with_reason(&self, reason: CancelReason) {
+        self.cancellation.cancel_with_reason(reason);
+    }
+
+    /// Return why the request was cancelled, or `None` while it is still live.
+    #[must_use]
+    pub fn cancel_reason(&self) -> Option<CancelReason> {
+        self.cancellation.reason()
+    }
+
     /// Await cancellation.
     pub async fn cancelled(&self) {
         self.cancellation.cancelled().await;
@@ -227,9 +419,10 @@ impl RequestContext {
 
     /// Return a cancellation error when cancelled, including operation metadata.
     pub fn ensure_not_cancelled(&self, operation: &'static str) -> Result<()> {
-        if self.is_cancelled() {
+        if let Some(reason) = self.cancel_reason() {
             return Err(ErrorEnvelope::cancelled("operation cancelled")
-                .with_metadata("operation", operation));
+                .with_metadata("operation", operation)
+                .with_metadata("cancelReason", reason.as_str()));
         }
         Ok(())
     }
@@ -506,6 +699,31 @@ impl WorkerPool {
         self.queue.close_and_clear().await;
     }
 
+    /// Stop the pool and wait up to `timeout` for in-flight tasks to finish.
+    ///
+    /// Workers still running when the timeout elapses are aborted, dropping
+    /// their tasks at the next await point. Returns `true` when every worker
+    /// drained gracefully. A forced stop can leave a task's side effects
+    /// half-applied (e.g. only part of an insert batch written).
+    pub async fn stop_with_timeout(&mut self, timeout: Duration) -> bool {
+        self.queue.close_and_clear().await;
+        let mut workers = std::mem::take(&mut self.workers);
+        let drained = tokio::time::timeout(timeout, async {
+            for handle in &mut workers {
+                // Worker panics were already surfaced to their submitters.
+                let _ = handle.await;
+            }
+        })
+        .await
+        .is_ok();
+        if !drained {
+            for handle in &workers {
+                handle.abort();
+            }
+        }
+        drained
+    }
+
     /// Stop the pool and await worker termination.
     pub async fn shutdown(mut self) -> Result<()> {
         self.queue.close_and_clear().await;
@@ -706,7 +924,23 @@ async fn worker_loop(queue: BoundedQueue<Task>, ctx: RequestContext) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
+
+    #[test]
+    fn request_attributes_redact_secret_keys() {
+        let mut attributes = RequestAttributes::new();
+        attributes.insert("team".to_string(), "search".to_string());
+        attributes.insert("api_token".to_string(), "sk-123".to_string());
+        let ctx = RequestContext::new_request().with_attributes(attributes);
+
+        assert_eq!(
+            ctx.attributes().get("team").map(String::as_str),
+            Some("search")
+        );
+        assert_eq!(
+            ctx.attributes().get("api_token").map(String::as_str),
+            Some(crate::REDACTED)
+        );
+    }
 
     #[tokio::test]
     async fn bounded_queue_applies_backpressure() -> Result<()> {
@@ -762,6 +996,30 @@ mod tests {
         assert!(child.is_cancelled());
     }
 
+    #[test]
+    fn cancelled_errors_report_the_cancel_reason() {
+        let reason_of = |ctx: &RequestContext| {
+            ctx.ensure_not_cancelled("test")
+                .err()
+                .and_then(|error| error.metadata.get("cancelReason").cloned())
+        };
+
+        let timed_out = RequestContext::new_request();
+        assert_eq!(timed_out.cancel_reason(), None);
+        timed_out.cancel_with_reason(CancelReason::Timeout);
+        // The first recorded reason wins.
+        timed_out.cancel();
+        assert_eq!(timed_out.cancel_reason(), Some(CancelReason::Timeout));
+        assert_eq!(reason_of(&timed_out).as_deref(), Some("timeout"));
+
+        let explicit = RequestContext::new_request();
+        let child = explicit.cancellation_token().child_token();
+        explicit.cancel();
+        assert_eq!(explicit.cancel_reason(), Some(CancelReason::UserRequested));
+        assert_eq!(reason_of(&explicit).as_deref(), Some("user_requested"));
+        assert_eq!(child.reason(), Some(CancelReason::Parent));
+    }
+
     #[tokio::test]
     async fn cancellation_drop_guard_cancels_on_drop() {
         let token = CancellationToken::new();
@@ -857,4 +1115,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn worker_pool_stop_with_timeout_aborts_stuck_tasks() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        let mut pool = WorkerPool::new(
+            ctx.clone(),
+            WorkerPoolOptions {
+                concurrency: 1,
+                queue_capacity: Some(1),
+            },
+        )?;
+
+        // The submitter gives up, but the worker keeps running the stuck task.
+        let stuck = pool.submit(std::future::pending::<Result<()>>);
+        let waited = tokio::time::timeout(Duration::from_millis(20), stuck).await;
+        assert!(waited.is_err(), "stuck task should not complete");
+
+        ctx.cancel();
+        let drained = tokio::time::timeout(
+            Duration::from_secs(2),
+            pool.stop_with_timeout(Duration::from_millis(50)),
+        )
+        .await;
+        assert_eq!(drained.ok(), Some(false), "stop should force-abort in time");
+
+        let mut idle = WorkerPool::new(
+            RequestContext::new_request(),
+            WorkerPoolOptions {
+                concurrency: 2,
+                queue_capacity: None,
+            },
+        )?;
+        assert!(idle.stop_with_timeout(Duration::from_millis(50)).await);
+
+        Ok(())
+    }
 }
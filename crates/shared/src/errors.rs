@@ -94,6 +94,11 @@ impl ErrorCode {
         Self::new("core", "permission_denied")
     }
 
+    /// Read-only mode violation code.
+    pub fn read_only() -> Self {
+        Self::new("core", "read_only")
+    }
+
     /// Timeout code.
     pub fn timeout() -> Self {
         Self::new("core", "timeout")
@@ -37,8 +37,8 @@ mod validation;
 // These modules will be implemented in Phase 04: Ports and Use-cases
 
 pub use concurrency::{
-    BoundedQueue, BoundedQueueClosedError, CancellationDropGuard, CancellationToken, CorrelationId,
-    RequestContext, WorkerPool, WorkerPoolOptions,
+    BoundedQueue, BoundedQueueClosedError, CancelReason, CancellationDropGuard, CancellationToken,
+    CorrelationId, RandomSeed, RequestAttributes, RequestContext, WorkerPool, WorkerPoolOptions,
 };
 pub use errors::{
     ErrorClass, ErrorCode, ErrorEnvelope, ErrorKind, ErrorMetadata, REDACTED_VALUE,
@@ -50,8 +50,11 @@ pub use invariants::{
 pub use merkle::{MerkleDag, MerkleDagDiff, MerkleDagNode, MerkleDagSerialized};
 pub use redaction::{REDACTED, Redacted, SecretString, is_secret_key, redact_if_secret};
 pub use result::{Result, ResultExt};
-pub use retry::{RetryPolicy, retry_async, retry_async_with_observer};
-pub use timeout::timeout_with_context;
+pub use retry::{
+    RETRY_LOG_MAX_METADATA_ENTRIES, RETRY_LOG_MAX_VALUE_CHARS, RetryBudget, RetryPolicy, log_retry,
+    retry_async, retry_async_with_observer, retry_log_metadata,
+};
+pub use timeout::{timeout_with_context, with_cancellation_grace};
 pub use validation::{Validate, ValidationError};
 
 /// Returns the shared crate version.
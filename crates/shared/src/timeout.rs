@@ -17,13 +17,38 @@ where
     ctx.ensure_not_cancelled(operation)?;
 
     tokio::select! {
-        () = ctx.cancelled() => Err(cancelled_error(operation)),
+        () = ctx.cancelled() => Err(cancelled_error(ctx, operation)),
         res = tokio::time::timeout(timeout, fut) => {
             res.unwrap_or_else(|_| Err(timeout_error(operation)))
         }
     }
 }
 
+/// Await a future, allowing it at most `grace` to finish once the request is cancelled.
+///
+/// Cancellation does not interrupt `fut` immediately, so in-flight work can
+/// wind down; if it is still pending when the grace period elapses, `fut` is
+/// dropped and a cancellation error is returned.
+pub async fn with_cancellation_grace<T, F>(
+    ctx: &RequestContext,
+    grace: Duration,
+    operation: &'static str,
+    fut: F,
+) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    tokio::pin!(fut);
+    tokio::select! {
+        res = &mut fut => return res,
+        () = ctx.cancelled() => {},
+    }
+
+    tokio::time::timeout(grace, fut)
+        .await
+        .unwrap_or_else(|_| Err(cancelled_error(ctx, operation)))
+}
+
 fn timeout_error(operation: &'static str) -> ErrorEnvelope {
     ErrorEnvelope::unexpected(
         ErrorCode::timeout(),
@@ -33,8 +58,13 @@ fn timeout_error(operation: &'static str) -> ErrorEnvelope {
     .with_metadata("operation", operation)
 }
 
-fn cancelled_error(operation: &'static str) -> ErrorEnvelope {
-    ErrorEnvelope::cancelled("operation cancelled").with_metadata("operation", operation)
+fn cancelled_error(ctx: &RequestContext, operation: &'static str) -> ErrorEnvelope {
+    let error =
+        ErrorEnvelope::cancelled("operation cancelled").with_metadata("operation", operation);
+    match ctx.cancel_reason() {
+        Some(reason) => error.with_metadata("cancelReason", reason.as_str()),
+        None => error,
+    }
 }
 
 #[cfg(test)]
@@ -85,4 +115,38 @@ mod tests {
         let result = task.await.expect("join");
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn cancellation_grace_lets_work_finish() -> Result<()> {
+        let ctx = RequestContext::new_request();
+        ctx.cancel();
+        let fut = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Ok::<_, ErrorEnvelope>(7u32)
+        };
+
+        let value =
+            with_cancellation_grace(&ctx, Duration::from_secs(2), "test_grace", fut).await?;
+        assert_eq!(value, 7);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancellation_grace_gives_up_on_hung_work() {
+        let ctx = RequestContext::new_request();
+        let token = ctx.cancellation_token();
+        let fut = std::future::pending::<Result<()>>();
+
+        let task = tokio::spawn(async move {
+            with_cancellation_grace(&ctx, Duration::from_millis(20), "test_hung", fut).await
+        });
+
+        tokio::task::yield_now().await;
+        token.cancel();
+        let result = tokio::time::timeout(Duration::from_secs(2), task).await;
+        assert!(
+            matches!(result, Ok(Ok(Err(ref error))) if error.is_cancelled()),
+            "hung work should be abandoned after the grace period"
+        );
+    }
 }
@@ -0,0 +1,57 @@
+//! # semantic-code-testkit
+//!
+//! Internal test doubles shared by cross-crate tests.
+//!
+//! Parity tests that exercise several adapters against each other live under
+//! `tests/`, so no single adapter crate has to depend on its siblings.
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Scratch directory under the system temp dir, removed on drop.
+#[derive(Debug)]
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Reserve a unique `<prefix>-<pid>-<nanos>` path; the directory itself is
+    /// created by whoever writes into it.
+    #[must_use]
+    pub fn new(prefix: &str) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        Self {
+            path: std::env::temp_dir().join(format!("{prefix}-{}-{nanos}", std::process::id())),
+        }
+    }
+
+    /// Path of the scratch directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temp_dir_is_removed_on_drop() -> std::io::Result<()> {
+        let temp = TempDir::new("sca-testkit");
+        std::fs::create_dir_all(temp.path().join("nested"))?;
+        let path = temp.path().to_path_buf();
+        drop(temp);
+        assert!(!path.exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Tie-break parity between the local vector DB and the Milvus REST adapter.
+
+use semantic_code_adapters::{
+    LocalVectorDbBuilder, MilvusIndexConfig, MilvusRestConfig, MilvusRestVectorDb,
+};
+use semantic_code_config::SnapshotStorageMode;
+use semantic_code_domain::{CollectionName, LineSpan, VectorDocumentMetadata};
+use semantic_code_ports::{
+    VectorDbPort, VectorDocumentForInsert, VectorSearchOptions, VectorSearchRequest,
+};
+use semantic_code_shared::{CancellationToken, ErrorCode, ErrorEnvelope, RequestContext, Result};
+use semantic_code_testkit::TempDir;
+use semantic_code_vector::HnswKernel;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Serve `/entities/search` with `rows` in the order given.
+async fn mock_search_server(rows: &[(&str, f32)]) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/vectordb/collections/get_load_state"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "data": { "loadState": "LoadStateLoaded" }
+        })))
+        .mount(&server)
+        .await;
+    let rows: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|(id, score)| {
+            serde_json::json!({
+                "id": id,
+                "content": id,
+                "relativePath": format!("src/{id}.rs"),
+                "startLine": 1,
+                "endLine": 1,
+                "fileExtension": "rs",
+                "score": score,
+            })
+        })
+        .collect();
+    Mock::given(method("POST"))
+        .and(path("/v2/vectordb/entities/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "data": { "data": rows }
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn rest_adapter(server: &MockServer) -> Result<MilvusRestVectorDb> {
+    MilvusRestVectorDb::new(MilvusRestConfig {
+        address: server.uri().into_boxed_str(),
+        token: None,
+        username: None,
+        password: None,
+        database: None,
+        timeout_ms: 5_000,
+        index_timeout_ms: 5_000,
+        index_config: MilvusIndexConfig::default(),
+    })
+}
+
+fn search_request(collection: CollectionName) -> VectorSearchRequest {
+    VectorSearchRequest {
+        collection_name: collection,
+        query_vector: Arc::from(vec![1.0, 0.0, 0.0]),
+        options: VectorSearchOptions {
+            top_k: Some(10),
+            ..VectorSearchOptions::default()
+        },
+    }
+}
+
+fn document(id: &str, vector: [f32; 3]) -> Result<VectorDocumentForInsert> {
+    Ok(VectorDocumentForInsert {
+        id: id.into(),
+        vector: Arc::from(vector.to_vec()),
+        content: id.into(),
+        metadata: VectorDocumentMetadata {
+            relative_path: format!("src/{id}.rs").into_boxed_str(),
+            language: None,
+            file_extension: Some("rs".into()),
+            span: LineSpan::new(1, 1).map_err(|error| {
+                ErrorEnvelope::expected(ErrorCode::invalid_input(), error.to_string())
+            })?,
+            fragment_start_byte: None,
+            fragment_end_byte: None,
+            node_kind: None,
+            extra: BTreeMap::new(),
+            mtime_ms: None,
+        },
+    })
+}
+
+fn ids(results: Vec<semantic_code_ports::VectorSearchResult>) -> Vec<Box<str>> {
+    results
+        .into_iter()
+        .map(|result| result.document.id)
+        .collect()
+}
+
+fn boxed(ids: &[&str]) -> Vec<Box<str>> {
+    ids.iter().copied().map(Box::from).collect()
+}
+
+#[tokio::test]
+async fn tied_scores_order_like_the_local_adapter() -> Result<()> {
+    let ctx = RequestContext::new_request();
+    let collection = CollectionName::parse("tie_parity").map_err(ErrorEnvelope::from)?;
+
+    let temp = TempDir::new("sca-rest-tie-parity");
+    let local = LocalVectorDbBuilder::new(
+        temp.path().to_path_buf(),
+        Arc::new(HnswKernel::with_ef_search(32)),
+        CancellationToken::new(),
+    )
+    .storage_mode(SnapshotStorageMode::Custom(temp.path().to_path_buf()))
+    .build()?;
+    local
+        .create_collection(&ctx, collection.clone(), 3, None)
+        .await?;
+    let documents = [
+        ("doc-c", [1.0, 0.0, 0.0]),
+        ("doc-d", [0.6, 0.8, 0.0]),
+        ("doc-a", [1.0, 0.0, 0.0]),
+        ("doc-b", [1.0, 0.0, 0.0]),
+    ]
+    .into_iter()
+    .map(|(id, vector)| document(id, vector))
+    .collect::<Result<Vec<_>>>()?;
+    local.insert(&ctx, collection.clone(), documents).await?;
+    let local_ids = ids(local
+        .search(&ctx, search_request(collection.clone()))
+        .await?
+        .results);
+
+    // Milvus ranks by score but returns equal scores in arbitrary order.
+    let server = mock_search_server(&[
+        ("doc-c", 1.0),
+        ("doc-a", 1.0),
+        ("doc-b", 1.0),
+        ("doc-d", 0.6),
+    ])
+    .await;
+    let remote_ids = ids(rest_adapter(&server)?
+        .search(&ctx, search_request(collection))
+        .await?
+        .results);
+
+    assert_eq!(local_ids, boxed(&["doc-a", "doc-b", "doc-c", "doc-d"]));
+    assert_eq!(remote_ids, local_ids);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ascending_distance_ranking_is_kept() -> Result<()> {
+    let ctx = RequestContext::new_request();
+    let collection = CollectionName::parse("l2_ranking").map_err(ErrorEnvelope::from)?;
+
+    // An L2 collection ranks the smallest distance first.
+    let server = mock_search_server(&[
+        ("doc-b", 0.1),
+        ("doc-a", 0.1),
+        ("doc-c", 0.5),
+        ("doc-d", 2.0),
+    ])
+    .await;
+    let remote_ids = ids(rest_adapter(&server)?
+        .search(&ctx, search_request(collection))
+        .await?
+        .results);
+
+    assert_eq!(remote_ids, boxed(&["doc-a", "doc-b", "doc-c", "doc-d"]));
+    Ok(())
+}
@@ -1,4 +1,9 @@
 //! Derive macro for `semantic_code_shared::Validate`.
+//!
+//! With the `schema` feature, the derive also emits an inherent
+//! `schema_fragment()` returning the field constraints as JSON Schema keywords
+//! (`minLength`/`pattern` for `non_empty`, `minimum`/`maximum` for `range`,
+//! `minLength`/`maxLength` for `length`). Custom validators are not described.
 
 use proc_macro::TokenStream;
 use quote::{ToTokens, quote};
@@ -36,6 +41,7 @@ fn expand_validate(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn:
     };
 
     let mut checks = Vec::new();
+    let mut fragments = Vec::new();
     for field in fields {
         let Some(ident) = field.ident.as_ref() else {
             continue;
@@ -45,7 +51,9 @@ fn expand_validate(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn:
             continue;
         }
         let (is_option, inner_ty) = unwrap_option(&field.ty);
+        let mut keywords = Vec::new();
         for validator in validators {
+            keywords.extend(schema_keywords(&validator));
             let check = match validator {
                 Validator::NonEmpty => {
                     expand_non_empty(ident, &field_name, inner_ty, is_option, &error_ty)?
@@ -59,13 +67,30 @@ fn expand_validate(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn:
                     &min,
                     &max,
                 )?,
+                Validator::Length { min, max } => expand_length(
+                    ident,
+                    &field_name,
+                    inner_ty,
+                    is_option,
+                    &error_ty,
+                    &min,
+                    &max,
+                )?,
                 Validator::Custom(path) => expand_custom(ident, &path, is_option),
             };
             checks.push(check);
         }
+        if !keywords.is_empty() {
+            fragments.push((field_name, keywords));
+        }
     }
 
     let name = &input.ident;
+    let schema_fragment = if cfg!(feature = "schema") {
+        Some(expand_schema_fragment(name, &fragments))
+    } else {
+        None
+    };
     Ok(quote! {
         impl semantic_code_shared::Validate for #name {
             type Error = #error_ty;
@@ -75,9 +100,51 @@ fn expand_validate(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn:
                 Ok(())
             }
         }
+
+        #schema_fragment
     })
 }
 
+/// JSON Schema keywords (name, literal value) describing one validator.
+fn schema_keywords(validator: &Validator) -> Vec<(&'static str, proc_macro2::TokenStream)> {
+    match validator {
+        Validator::NonEmpty => vec![("minLength", quote!(1)), ("pattern", quote!("\\S"))],
+        Validator::Range { min, max } => vec![("minimum", quote!(#min)), ("maximum", quote!(#max))],
+        Validator::Length { min, max } => {
+            vec![("minLength", quote!(#min)), ("maxLength", quote!(#max))]
+        },
+        Validator::Custom(_) => Vec::new(),
+    }
+}
+
+fn expand_schema_fragment(
+    name: &Ident,
+    fragments: &[(String, Vec<(&'static str, proc_macro2::TokenStream)>)],
+) -> proc_macro2::TokenStream {
+    let properties = fragments.iter().map(|(field_name, keywords)| {
+        // A later validator overrides an earlier one for the same keyword.
+        let mut merged: Vec<(&'static str, &proc_macro2::TokenStream)> = Vec::new();
+        for (keyword, value) in keywords {
+            merged.retain(|(existing, _)| existing != keyword);
+            merged.push((keyword, value));
+        }
+        let keys = merged.iter().map(|(keyword, _)| *keyword);
+        let values = merged.iter().map(|(_, value)| *value);
+        quote! { #field_name: { #(#keys: #values),* } }
+    });
+    quote! {
+        impl #name {
+            /// JSON Schema constraints derived from the `#[validate(...)]` attributes.
+            ///
+            /// Shape: `{"properties": {"<field>": {<keyword>: <value>, ...}}}`.
+            #[must_use]
+            pub fn schema_fragment() -> ::serde_json::Value {
+                ::serde_json::json!({ "properties": { #(#properties),* } })
+            }
+        }
+    }
+}
+
 fn parse_error_type(attrs: &[Attribute]) -> Result<Path, syn::Error> {
     let mut error_ty: Option<Path> = None;
     for attr in attrs {
@@ -135,28 +202,21 @@ fn parse_field_validators(
                 validators.push(Validator::Custom(path));
                 return Ok(());
             }
-            if meta.path.is_ident("range") {
-                let mut min: Option<Lit> = None;
-                let mut max: Option<Lit> = None;
-                meta.parse_nested_meta(|nested| {
-                    if nested.path.is_ident("min") {
-                        let lit: Lit = nested.value()?.parse()?;
-                        min = Some(lit);
-                        return Ok(());
+            if meta.path.is_ident("length") {
+                let (min, max) = parse_min_max(&meta, "length")?;
+                for bound in [&min, &max] {
+                    if !matches!(bound, Lit::Int(_)) {
+                        return Err(syn::Error::new_spanned(
+                            bound,
+                            "length bounds must be integers",
+                        ));
                     }
-                    if nested.path.is_ident("max") {
-                        let lit: Lit = nested.value()?.parse()?;
-                        max = Some(lit);
-                        return Ok(());
-                    }
-                    Err(nested.error("unsupported range attribute"))
-                })?;
-                let Some(min) = min else {
-                    return Err(meta.error("range requires min"));
-                };
-                let Some(max) = max else {
-                    return Err(meta.error("range requires max"));
-                };
+                }
+                validators.push(Validator::Length { min, max });
+                return Ok(());
+            }
+            if meta.path.is_ident("range") {
+                let (min, max) = parse_min_max(&meta, "range")?;
                 validators.push(Validator::Range { min, max });
                 return Ok(());
             }
@@ -167,10 +227,37 @@ fn parse_field_validators(
     Ok((name, validators))
 }
 
+fn parse_min_max(
+    meta: &syn::meta::ParseNestedMeta<'_>,
+    validator: &str,
+) -> Result<(Lit, Lit), syn::Error> {
+    let mut min: Option<Lit> = None;
+    let mut max: Option<Lit> = None;
+    meta.parse_nested_meta(|nested| {
+        if nested.path.is_ident("min") {
+            min = Some(nested.value()?.parse()?);
+            return Ok(());
+        }
+        if nested.path.is_ident("max") {
+            max = Some(nested.value()?.parse()?);
+            return Ok(());
+        }
+        Err(nested.error(format!("unsupported {validator} attribute")))
+    })?;
+    let Some(min) = min else {
+        return Err(meta.error(format!("{validator} requires min")));
+    };
+    let Some(max) = max else {
+        return Err(meta.error(format!("{validator} requires max")));
+    };
+    Ok((min, max))
+}
+
 #[derive(Debug)]
 enum Validator {
     NonEmpty,
     Range { min: Lit, max: Lit },
+    Length { min: Lit, max: Lit },
     Custom(Path),
 }
 
@@ -294,6 +381,51 @@ fn expand_range(
     }
 }
 
+fn expand_length(
+    ident: &Ident,
+    field_name: &str,
+    ty: &Type,
+    is_option: bool,
+    error_ty: &Path,
+    min: &Lit,
+    max: &Lit,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if !is_string_like(ty) {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "length can only be used on string-like fields",
+        ));
+    }
+    let field_name = syn::LitStr::new(field_name, proc_macro2::Span::call_site());
+    let min_str = syn::LitStr::new(&lit_to_string(min), proc_macro2::Span::call_site());
+    let max_str = syn::LitStr::new(&lit_to_string(max), proc_macro2::Span::call_site());
+    let check = quote! {
+        let length = value.chars().count();
+        if !(#min..=#max).contains(&length) {
+            return Err(<#error_ty as semantic_code_shared::ValidationError>::out_of_range(
+                #field_name,
+                length.to_string(),
+                #min_str.to_string(),
+                #max_str.to_string(),
+            ));
+        }
+    };
+    if is_option {
+        Ok(quote! {
+            if let Some(value) = self.#ident.as_ref() {
+                #check
+            }
+        })
+    } else {
+        Ok(quote! {
+            {
+                let value = &self.#ident;
+                #check
+            }
+        })
+    }
+}
+
 fn expand_custom(ident: &Ident, path: &Path, is_option: bool) -> proc_macro2::TokenStream {
     if is_option {
         quote! {
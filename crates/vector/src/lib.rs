@@ -48,7 +48,10 @@ pub use generation::{
     write_exact_generation,
 };
 pub use mmap::MmapBytes;
-pub use quantization::{QuantizationError, QuantizationParams, Quantizer, quantize_f32_to_u8};
+pub use quantization::{
+    QuantizationError, QuantizationParams, Quantizer, dequantize_i8_to_f32, quantize_f32_to_i8,
+    quantize_f32_to_u8,
+};
 pub use snapshot::{
     SNAPSHOT_V2_HNSW_GRAPH_BASENAME, SNAPSHOT_V2_META_FILE_NAME, SNAPSHOT_V2_VECTORS_FILE_NAME,
     SnapshotError, SnapshotResult, VectorSnapshotMeta, VectorSnapshotVersion, read_metadata,
@@ -105,6 +108,9 @@ pub struct HnswParams {
     /// are rejected at insertion with a descriptive error.
     #[serde(default = "default_min_norm_squared")]
     pub min_norm_squared: f32,
+    /// Vector encoding used when exporting v1 snapshots.
+    #[serde(default, skip_serializing_if = "VectorSnapshotEncoding::is_f32")]
+    pub snapshot_encoding: VectorSnapshotEncoding,
 }
 
 impl PartialEq for HnswParams {
@@ -115,6 +121,7 @@ impl PartialEq for HnswParams {
             && self.ef_search == other.ef_search
             && self.max_elements == other.max_elements
             && self.min_norm_squared.to_bits() == other.min_norm_squared.to_bits()
+            && self.snapshot_encoding == other.snapshot_encoding
     }
 }
 
@@ -129,6 +136,7 @@ impl Default for HnswParams {
             ef_search: 200,
             max_elements: 100_000,
             min_norm_squared: DEFAULT_MIN_NORM_SQUARED,
+            snapshot_encoding: VectorSnapshotEncoding::F32,
         }
     }
 }
@@ -181,6 +189,41 @@ impl PayloadSlot {
     }
 }
 
+/// Vector payload encoding for v1 JSON snapshots.
+///
+/// `Int8` stores each vector as symmetric int8 values plus one `f32` scale,
+/// roughly a quarter of the `F32` payload. Reconstructed vectors carry up to
+/// `scale / 2` error per component, which can reorder near-tied results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum VectorSnapshotEncoding {
+    /// Full-precision `f32` vectors in `records`.
+    #[default]
+    F32,
+    /// Per-vector scalar int8 vectors in `quantizedRecords`.
+    Int8,
+}
+
+impl VectorSnapshotEncoding {
+    /// Returns true for the full-precision encoding.
+    #[must_use]
+    pub const fn is_f32(&self) -> bool {
+        matches!(self, Self::F32)
+    }
+}
+
+/// Int8-quantized record stored inside an `Int8` snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuantizedVectorRecord {
+    /// Stable external identifier for this vector.
+    pub id: Box<str>,
+    /// Per-vector scale; `vector[i] ≈ values[i] * scale`.
+    pub scale: f32,
+    /// Quantized components.
+    pub values: Vec<i8>,
+}
+
 /// Serialized snapshot for local persistence.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -191,8 +234,61 @@ pub struct VectorSnapshot {
     pub dimension: u32,
     /// HNSW parameters.
     pub params: HnswParams,
-    /// Stored vector records.
+    /// Encoding of the vector payload.
+    #[serde(default, skip_serializing_if = "VectorSnapshotEncoding::is_f32")]
+    pub encoding: VectorSnapshotEncoding,
+    /// Stored vector records (`F32` encoding).
+    #[serde(default)]
     pub records: Vec<VectorRecord>,
+    /// Stored quantized records (`Int8` encoding).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quantized_records: Vec<QuantizedVectorRecord>,
+}
+
+impl VectorSnapshot {
+    /// Re-encode the payload as per-vector int8.
+    #[must_use]
+    pub fn into_int8(self) -> Self {
+        let snapshot = self.into_f32();
+        let quantized_records = snapshot
+            .records
+            .into_iter()
+            .map(|record| {
+                let (scale, values) = quantize_f32_to_i8(&record.vector);
+                QuantizedVectorRecord {
+                    id: record.id,
+                    scale,
+                    values,
+                }
+            })
+            .collect();
+        Self {
+            encoding: VectorSnapshotEncoding::Int8,
+            records: Vec::new(),
+            quantized_records,
+            ..snapshot
+        }
+    }
+
+    /// Reconstruct full-precision records, dequantizing `Int8` payloads.
+    #[must_use]
+    pub fn into_f32(self) -> Self {
+        let mut records = self.records;
+        records.extend(
+            self.quantized_records
+                .into_iter()
+                .map(|record| VectorRecord {
+                    vector: dequantize_i8_to_f32(record.scale, &record.values),
+                    id: record.id,
+                }),
+        );
+        Self {
+            encoding: VectorSnapshotEncoding::F32,
+            records,
+            quantized_records: Vec::new(),
+            ..self
+        }
+    }
 }
 
 /// Snapshot format selector for on-disk persistence.
@@ -1119,6 +1215,8 @@ pub struct VectorIndex {
     dimension: u32,
     params: HnswParams,
     hnsw: Hnsw<'static, f32, DistAccelerateCosine>,
+    /// Live HNSW allocation size; grows past `params.max_elements` on demand.
+    graph_capacity: usize,
     records: Vec<VectorRecord>,
     slot_origins: Vec<OriginId>,
     id_to_origin: HashMap<Box<str>, OriginId>,
@@ -1151,6 +1249,7 @@ impl VectorIndex {
             dimension,
             params,
             hnsw,
+            graph_capacity: max_elements,
             records: Vec::new(),
             slot_origins: Vec::new(),
             id_to_origin: HashMap::new(),
@@ -1341,6 +1440,8 @@ impl VectorIndex {
         fields(dimension = self.dimension, record_count = records.len())
     )]
     pub fn insert(&mut self, records: Vec<VectorRecord>) -> Result<()> {
+        self.ensure_graph_capacity(records.len());
+
         // Phase 1: sequential bookkeeping — validate dimensions, prepare
         // vectors for cosine distance, assign HNSW node indices, and
         // track upserts. This must be sequential because it mutates
@@ -1386,6 +1487,7 @@ impl VectorIndex {
         records: Vec<(OriginId, VectorRecord)>,
         cancellation: Option<&CancellationToken>,
     ) -> Result<()> {
+        self.ensure_graph_capacity(records.len());
         let mut hnsw_batch: Vec<(OriginId, PayloadSlot)> = Vec::with_capacity(records.len());
 
         for (origin, record) in records {
@@ -1430,6 +1532,55 @@ impl VectorIndex {
         Ok(())
     }
 
+    /// Grow the host HNSW graph before inserting `incoming` nodes past its capacity.
+    ///
+    /// The graph is rebuilt at double the capacity (repeatedly, until the batch
+    /// fits) by re-inserting every existing node in slot order, so tombstoned
+    /// nodes keep their origin ids. `params.max_elements` is left untouched so
+    /// snapshots stay identical. Graphs that only cover part of the records
+    /// (records-only loads followed by graph inserts) are left as-is.
+    fn ensure_graph_capacity(&mut self, incoming: usize) {
+        let nodes = self.hnsw.get_nb_point();
+        let required = nodes.saturating_add(incoming);
+        if required <= self.graph_capacity {
+            return;
+        }
+        if nodes != 0 && nodes != self.records.len() {
+            tracing::debug!(
+                nodes,
+                records = self.records.len(),
+                capacity = self.graph_capacity,
+                "skipping HNSW growth for partially materialized graph"
+            );
+            return;
+        }
+
+        let mut capacity = self.graph_capacity.max(1);
+        while capacity < required {
+            capacity = capacity.saturating_mul(2);
+        }
+        let grown = Hnsw::new(
+            self.params.max_nb_connection,
+            capacity,
+            self.params.max_layer,
+            self.params.ef_construction,
+            DistAccelerateCosine,
+        );
+        if nodes != 0 {
+            for (record, origin) in self.records.iter().zip(&self.slot_origins) {
+                grown.insert_slice((record.vector.as_slice(), origin.as_usize()));
+            }
+        }
+        tracing::debug!(
+            previous_capacity = self.graph_capacity,
+            capacity,
+            nodes,
+            "grew HNSW graph capacity"
+        );
+        self.hnsw = grown;
+        self.graph_capacity = capacity;
+    }
+
     /// Populate records, ID mappings, and origin tracking **without** building
     /// the HNSW graph.  The graph is left empty — only graph-agnostic kernels
     /// (e.g. `FlatScan`) can search the resulting index.
@@ -1685,11 +1836,17 @@ impl VectorIndex {
     #[must_use]
     pub fn snapshot(&self) -> VectorSnapshot {
         let (records, _origins) = self.snapshot_records_and_origins();
-        VectorSnapshot {
+        let snapshot = VectorSnapshot {
             version: VECTOR_SNAPSHOT_VERSION,
             dimension: self.dimension,
             params: self.params,
+            encoding: VectorSnapshotEncoding::F32,
             records,
+            quantized_records: Vec::new(),
+        };
+        match self.params.snapshot_encoding {
+            VectorSnapshotEncoding::F32 => snapshot,
+            VectorSnapshotEncoding::Int8 => snapshot.into_int8(),
         }
     }
 
@@ -1716,7 +1873,13 @@ impl VectorIndex {
             version: VECTOR_SNAPSHOT_VERSION,
             dimension: self.dimension,
             params: self.params,
+            encoding: VectorSnapshotEncoding::F32,
             records: records.to_vec(),
+            quantized_records: Vec::new(),
+        };
+        let snapshot = match self.params.snapshot_encoding {
+            VectorSnapshotEncoding::F32 => snapshot,
+            VectorSnapshotEncoding::Int8 => snapshot.into_int8(),
         };
         let payload = serde_json::to_vec(&snapshot).map_err(|source| {
             ErrorEnvelope::expected(
@@ -2405,6 +2568,7 @@ impl VectorIndex {
             .with_metadata("expected", VECTOR_SNAPSHOT_VERSION.to_string()));
         }
 
+        let snapshot = snapshot.into_f32();
         let mut params = snapshot.params;
         params.max_elements = params.max_elements.max(snapshot.records.len().max(1));
 
@@ -2662,6 +2826,7 @@ impl VectorIndex {
             dimension,
             params,
             hnsw,
+            graph_capacity: params.max_elements.max(records.len()).max(1),
             records,
             slot_origins,
             id_to_origin,
@@ -3013,6 +3178,7 @@ fn read_snapshot_v1_ids(snapshot_dir: &Path, expected_count: usize) -> Result<Ve
     })?;
 
     let ids = snapshot
+        .into_f32()
         .records
         .into_iter()
         .map(|record| record.id)
@@ -3428,6 +3594,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn int8_snapshot_roundtrip_stays_within_tolerance() -> Result<()> {
+        let params = HnswParams {
+            snapshot_encoding: VectorSnapshotEncoding::Int8,
+            ..HnswParams::default()
+        };
+        let records = vec![
+            VectorRecord {
+                id: "east".into(),
+                vector: vec![0.9, 0.1, -0.2],
+            },
+            VectorRecord {
+                id: "north".into(),
+                vector: vec![0.05, 0.95, 0.3],
+            },
+            VectorRecord {
+                id: "down".into(),
+                vector: vec![-0.1, 0.2, -0.8],
+            },
+        ];
+        let mut index = VectorIndex::new(3, params)?;
+        index.insert(records.clone())?;
+
+        let snapshot = index.snapshot();
+        assert_eq!(snapshot.encoding, VectorSnapshotEncoding::Int8);
+        assert!(snapshot.records.is_empty());
+        assert_eq!(snapshot.quantized_records.len(), records.len());
+
+        let payload = serde_json::to_vec(&snapshot).map_err(|error| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                error.to_string(),
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        let decoded: VectorSnapshot = serde_json::from_slice(&payload).map_err(|error| {
+            ErrorEnvelope::unexpected(
+                ErrorCode::internal(),
+                error.to_string(),
+                ErrorClass::NonRetriable,
+            )
+        })?;
+        let restored = VectorIndex::from_snapshot(decoded)?;
+
+        for original in &records {
+            let restored_record = restored
+                .record_for_id(&original.id)
+                .ok_or_else(|| ErrorEnvelope::expected(ErrorCode::not_found(), "missing record"))?;
+            for (expected, actual) in original.vector.iter().zip(&restored_record.vector) {
+                assert!((expected - actual).abs() <= 0.01, "{expected} vs {actual}");
+            }
+        }
+
+        let matches = restored.search(&[0.1, 0.9, 0.25], 1)?.matches;
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches.first().map(|hit| hit.id.as_ref()), Some("north"));
+        Ok(())
+    }
+
     #[test]
     fn search_prefers_closer_vectors() -> Result<()> {
         let mut index = VectorIndex::new(2, HnswParams::default())?;
@@ -4535,6 +4760,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn insert_beyond_max_elements_grows_graph_and_keeps_params() -> Result<()> {
+        let params = HnswParams {
+            max_elements: 2,
+            ..HnswParams::default()
+        };
+        let mut index = VectorIndex::new(2, params)?;
+        for batch in 0..4_u8 {
+            let records = (0..3_u8)
+                .map(|offset| {
+                    let angle = f32::from(batch * 3 + offset) * 0.1;
+                    VectorRecord {
+                        id: format!("doc_{batch}_{offset}").into(),
+                        vector: vec![angle.cos(), angle.sin()],
+                    }
+                })
+                .collect();
+            index.insert(records)?;
+        }
+
+        assert_eq!(index.host_hnsw_count(), 12);
+        assert_eq!(index.params().max_elements, 2);
+        let angle = 0.7_f32;
+        let matches = index.search(&[angle.cos(), angle.sin()], 1)?.matches;
+        assert_eq!(matches.first().map(|m| m.id.as_ref()), Some("doc_2_1"));
+        Ok(())
+    }
+
     #[test]
     fn insert_with_origins_respects_cancellation() -> Result<()> {
         let token = CancellationToken::new();
@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 const U8_MAX_F32: f32 = 255.0;
+/// Largest magnitude used by symmetric int8 quantization (`[-127, 127]`).
+const I8_SYMMETRIC_MAX: f32 = 127.0;
 
 /// Result type for quantization operations.
 pub type QuantizationResult<T> = Result<T, QuantizationError>;
@@ -293,6 +295,47 @@ pub fn dequantize_u8_to_f32(
     Ok(output)
 }
 
+/// Quantize one vector into symmetric int8 values with a per-vector scale.
+///
+/// Returns `(scale, values)` such that `value ≈ f32::from(q) * scale`. The
+/// scale maps the largest magnitude to 127; an all-zero vector yields a zero
+/// scale. Non-finite components are encoded as zero.
+#[must_use]
+pub fn quantize_f32_to_i8(values: &[f32]) -> (f32, Vec<i8>) {
+    let max_abs = values
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .fold(0.0_f32, |max, value| max.max(value.abs()));
+    if max_abs <= 0.0 {
+        return (0.0, vec![0; values.len()]);
+    }
+
+    let scale = max_abs / I8_SYMMETRIC_MAX;
+    let quantized = values
+        .iter()
+        .copied()
+        .map(|value| {
+            if !value.is_finite() {
+                return 0;
+            }
+            // Shift into the unsigned range so rounding reuses `round_to_u8`.
+            let shifted = (value / scale + I8_SYMMETRIC_MAX).clamp(0.0, 2.0 * I8_SYMMETRIC_MAX);
+            i8::try_from(i16::from(round_to_u8(shifted)) - 127).unwrap_or(0)
+        })
+        .collect();
+    (scale, quantized)
+}
+
+/// Dequantize one per-vector int8 payload back to floating-point values.
+#[must_use]
+pub fn dequantize_i8_to_f32(scale: f32, quantized: &[i8]) -> Vec<f32> {
+    quantized
+        .iter()
+        .map(|value| f32::from(*value) * scale)
+        .collect()
+}
+
 /// Safely decode one quantized byte into an `f32` component.
 #[must_use]
 pub const fn decode_u8_to_f32(value: u8) -> f32 {
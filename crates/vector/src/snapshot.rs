@@ -714,6 +714,8 @@ pub fn upgrade_v1_to_v2(snapshot_dir: impl AsRef<Path>) -> SnapshotResult<Vector
             source,
         }
     })?;
+    // v2 carries its own SQ8 payload; start from reconstructed f32 vectors.
+    let snapshot = snapshot.into_f32();
 
     if snapshot.version != u32::from(SNAPSHOT_VERSION_V1) {
         return Err(SnapshotError::LegacyVersionMismatch {
@@ -881,7 +883,7 @@ mod tests {
         read_snapshot_v2_with_options, upgrade_v1_to_v2, write_metadata, write_snapshot_v2,
     };
     use crate::quantization::QuantizationParams;
-    use crate::{HnswParams, VectorRecord, VectorSnapshot};
+    use crate::{HnswParams, VectorRecord, VectorSnapshot, VectorSnapshotEncoding};
     use std::io;
     use std::path::{Path, PathBuf};
     use std::sync::atomic::{AtomicU64, Ordering};
@@ -1105,6 +1107,8 @@ mod tests {
             version: 1,
             dimension: 2,
             params: HnswParams::default(),
+            encoding: VectorSnapshotEncoding::F32,
+            quantized_records: Vec::new(),
             records: vec![
                 VectorRecord {
                     id: "a".into(),
@@ -1154,6 +1158,8 @@ mod tests {
             version: 1,
             dimension: 2,
             params: HnswParams::default(),
+            encoding: VectorSnapshotEncoding::F32,
+            quantized_records: Vec::new(),
             records: vec![VectorRecord {
                 id: "z".into(),
                 vector: vec![5.0, -3.0],